@@ -9,6 +9,8 @@ import {
   productsRoutes,
   REALTIME_INTERNAL_ORIGIN_HEADER,
   REALTIME_INTERNAL_ORIGIN_WS_RPC,
+  recordHttpError,
+  recordHttpRequest,
   shouldBroadcastRouteMutation,
   systemRoutes,
   variantsRoutes,
@@ -49,14 +51,24 @@ export const buildApp = (): Elysia => {
         },
       }),
     )
+    .onRequest(() => {
+      recordHttpRequest();
+    })
+    .onError(() => {
+      recordHttpError();
+    })
     .onAfterHandle(({ request, response, set }) => {
+      const status = response instanceof Response ? response.status : resolveSetStatusCode(set.status);
+      if (status >= 400) {
+        recordHttpError();
+      }
+
       if (request.headers.get(REALTIME_INTERNAL_ORIGIN_HEADER) === REALTIME_INTERNAL_ORIGIN_WS_RPC) {
         return;
       }
 
       const method = request.method.toUpperCase();
       const path = new URL(request.url).pathname;
-      const status = response instanceof Response ? response.status : resolveSetStatusCode(set.status);
 
       if (!shouldBroadcastRouteMutation({ method, path, status })) {
         return;
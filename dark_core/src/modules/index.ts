@@ -30,6 +30,7 @@ export * from './realtime/realtime.controller';
 export * from './realtime/realtime.routes';
 export * from './system/system.config';
 export * from './system/system.controller';
+export * from './system/system.metrics';
 export * from './system/system.routes';
 export * from './variants/variants.controller';
 export * from './variants/variants.config';
@@ -1,4 +1,4 @@
-import { mkdir } from 'node:fs/promises';
+import { mkdir, stat } from 'node:fs/promises';
 import { basename, dirname, isAbsolute, resolve } from 'node:path';
 
 import { getConfig } from '../../config';
@@ -11,6 +11,8 @@ import {
   listTmuxSessions,
   startSshPortForward,
 } from '../ssh/ssh.controller';
+import { getActiveCloneCount } from '../variant_clones/variant_clones.controller';
+import { getHttpCounters } from './system.metrics';
 import Log, { formatLogMetadata } from '../../utils/logging';
 
 export interface ServiceInfo {
@@ -139,10 +141,52 @@ export const getApiInfo = async (): Promise<ServiceInfo> => {
   };
 };
 
+const resolveDatabaseSizeBytes = async (databaseUrl: string): Promise<number | null> => {
+  if (!databaseUrl.startsWith('file:')) {
+    return null;
+  }
+
+  try {
+    return (await stat(await resolveFileDatabasePath(databaseUrl))).size;
+  } catch {
+    return null;
+  }
+};
+
+/**
+ * Flat numeric metrics. Counters (`httpRequestsTotal`, `httpErrorsTotal`) only grow;
+ * `actorsByStatus.<status>` has one key per actor status currently in the database.
+ */
 export const getMetrics = async (): Promise<Record<string, number>> => {
-  return {
+  const prisma = getPrismaClient();
+  const [productsTotal, variantsTotal, actorStatusGroups, dbSizeBytes] = await Promise.all([
+    prisma.product.count(),
+    prisma.variant.count(),
+    prisma.actor.groupBy({ by: ['status'], _count: { _all: true } }),
+    resolveDatabaseSizeBytes(getConfig().prisma.databaseUrl),
+  ]);
+  const memory = process.memoryUsage();
+
+  const metrics: Record<string, number> = {
     uptimeSeconds: Math.floor(process.uptime()),
+    ...getHttpCounters(),
+    productsTotal,
+    variantsTotal,
+    actorsTotal: actorStatusGroups.reduce((total, group) => total + group._count._all, 0),
+    cloneQueueDepth: getActiveCloneCount(),
+    memoryRssBytes: memory.rss,
+    memoryHeapUsedBytes: memory.heapUsed,
   };
+
+  for (const group of actorStatusGroups) {
+    metrics[`actorsByStatus.${group.status}`] = group._count._all;
+  }
+
+  if (dbSizeBytes !== null) {
+    metrics.dbSizeBytes = dbSizeBytes;
+  }
+
+  return metrics;
 };
 
 export const getProvidersInfo = async (): Promise<ProvidersInfo> => {
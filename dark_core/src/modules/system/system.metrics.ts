@@ -0,0 +1,20 @@
+/**
+ * In-process HTTP counters reported by `/system/metrics`.
+ *
+ * Counters only grow; clients derive rates from the delta between samples.
+ */
+let httpRequestsTotal = 0;
+let httpErrorsTotal = 0;
+
+export const recordHttpRequest = (): void => {
+  httpRequestsTotal += 1;
+};
+
+/** Counts a response with status >= 400 or an unhandled route error. */
+export const recordHttpError = (): void => {
+  httpErrorsTotal += 1;
+};
+
+export const getHttpCounters = (): { httpRequestsTotal: number; httpErrorsTotal: number } => {
+  return { httpRequestsTotal, httpErrorsTotal };
+};
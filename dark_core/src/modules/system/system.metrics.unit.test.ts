@@ -0,0 +1,18 @@
+import { describe, expect, it } from 'bun:test';
+
+import { getHttpCounters, recordHttpError, recordHttpRequest } from './system.metrics';
+
+describe('system metrics counters', () => {
+  it('accumulates request and error counts', () => {
+    const before = getHttpCounters();
+
+    recordHttpRequest();
+    recordHttpRequest();
+    recordHttpError();
+
+    expect(getHttpCounters()).toEqual({
+      httpRequestsTotal: before.httpRequestsTotal + 2,
+      httpErrorsTotal: before.httpErrorsTotal + 1,
+    });
+  });
+});
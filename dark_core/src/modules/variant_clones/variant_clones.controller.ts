@@ -946,6 +946,22 @@ const cloneFromGitRemote = async (
   };
 };
 
+/** Clone lifecycles currently running, sync or async; reported by `/system/metrics`. */
+let activeCloneCount = 0;
+
+export const getActiveCloneCount = (): number => {
+  return activeCloneCount;
+};
+
+const trackActiveClone = async <T>(work: () => Promise<T>): Promise<T> => {
+  activeCloneCount += 1;
+  try {
+    return await work();
+  } finally {
+    activeCloneCount -= 1;
+  }
+};
+
 export const cloneVariantForProduct = async (
   input: CloneVariantForProductInput,
 ): Promise<VariantCloneResult> => {
@@ -1213,7 +1229,7 @@ export const cloneVariantForProduct = async (
   })();
 
   if (runAsync) {
-    void executeCloneLifecycle().catch((error) => {
+    void trackActiveClone(executeCloneLifecycle).catch((error) => {
       Log.error(
         `Core // Variant Clones Controller // Async clone failed ${formatLogMetadata({
           error: error instanceof Error ? error.message : String(error),
@@ -1242,7 +1258,7 @@ export const cloneVariantForProduct = async (
     };
   }
 
-  const variant = await executeCloneLifecycle();
+  const variant = await trackActiveClone(executeCloneLifecycle);
 
   return {
     variant,
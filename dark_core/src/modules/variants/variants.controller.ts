@@ -1,5 +1,6 @@
 import { Prisma, type Variant } from '../../../../generated/prisma/client';
 import { rm, stat } from 'node:fs/promises';
+import { resolve } from 'node:path';
 
 import { getPrismaClient } from '../prisma/prisma.client';
 import Log, { formatLogMetadata } from '../../utils/logging';
@@ -7,7 +8,7 @@ import { NotFoundError } from '../common/controller.errors';
 import type { CursorListQuery } from '../common/controller.types';
 import { scanVariantGitInfo } from '../git/git.scan';
 import { buildRandomVariantId } from '../../utils/id';
-import { isLocalLocator, locatorIdToHostPath } from '../../utils/locator';
+import { isLocalLocator, locatorIdToHostPath, parseLocatorId } from '../../utils/locator';
 
 const DEFAULT_LIST_LIMIT = 25;
 const MAX_LIST_LIMIT = 100;
@@ -47,6 +48,64 @@ export interface CheckoutVariantBranchInput {
   branchName: string;
 }
 
+export type IntegrateVariantStrategy = 'merge' | 'rebase' | 'squash';
+
+export type IntegrateVariantAfter = 'keep' | 'delete' | 'reset';
+
+/**
+ * Why a branch or integrate request was rejected; routes map each reason to an API error code.
+ */
+export type VariantGitErrorReason =
+  | 'invalid'
+  | 'unsupported'
+  | 'path_missing'
+  | 'not_git'
+  | 'dirty'
+  | 'delete_blocked';
+
+export class VariantGitError extends Error {
+  readonly reason: VariantGitErrorReason;
+
+  constructor(reason: VariantGitErrorReason, message: string) {
+    super(message);
+    this.name = 'VariantGitError';
+    this.reason = reason;
+  }
+}
+
+export const isVariantGitError = (error: unknown): error is VariantGitError => {
+  return error instanceof VariantGitError;
+};
+
+export interface IntegrateVariantInput {
+  strategy?: IntegrateVariantStrategy;
+  preview?: boolean;
+  after?: IntegrateVariantAfter;
+  baseBranch?: string;
+}
+
+export interface IntegrateVariantCommit {
+  sha: string;
+  author: string;
+  subject: string;
+}
+
+export type IntegrateVariantStatus = 'preview' | 'up_to_date' | 'integrated' | 'conflicts';
+
+export interface IntegrateVariantResult {
+  variantId: string;
+  status: IntegrateVariantStatus;
+  strategy: IntegrateVariantStrategy;
+  baseBranch: string;
+  sourceBranch: string;
+  targetPath: string;
+  commits: IntegrateVariantCommit[];
+  conflicts: string[];
+  integratedHead: string | null;
+  pushed: boolean;
+  after: IntegrateVariantAfter;
+}
+
 const normalizeLimit = (value?: number): number => {
   if (typeof value !== 'number' || Number.isNaN(value)) {
     return DEFAULT_LIST_LIMIT;
@@ -327,7 +386,8 @@ const runGit = async (args: string[], cwd: string): Promise<GitCommandResult> =>
 const ensureLocalGitVariantPath = async (id: string): Promise<string> => {
   const variant = await getVariantById(id, { poll: false });
   if (!isLocalLocator(variant.locator)) {
-    throw new Error(
+    throw new VariantGitError(
+      'unsupported',
       `Variants // Branch // Variant locator must be local ${formatLogMetadata({
         id,
         locator: variant.locator,
@@ -338,7 +398,8 @@ const ensureLocalGitVariantPath = async (id: string): Promise<string> => {
   const path = locatorIdToHostPath(variant.locator);
   const existing = await stat(path).catch(() => null);
   if (!existing || !existing.isDirectory()) {
-    throw new Error(
+    throw new VariantGitError(
+      'path_missing',
       `Variants // Branch // Variant path missing ${formatLogMetadata({
         id,
         path,
@@ -348,7 +409,8 @@ const ensureLocalGitVariantPath = async (id: string): Promise<string> => {
 
   const insideWorkTree = await runGit(['rev-parse', '--is-inside-work-tree'], path);
   if (!insideWorkTree.ok || !insideWorkTree.stdout.trim().includes('true')) {
-    throw new Error(
+    throw new VariantGitError(
+      'not_git',
       `Variants // Branch // Variant path is not a git worktree ${formatLogMetadata({
         id,
         path,
@@ -395,7 +457,7 @@ export const checkoutVariantBranchById = async (
 ): Promise<Variant> => {
   const branchName = input.branchName.trim();
   if (!branchName) {
-    throw new Error('Variants // Branch // Branch name is required');
+    throw new VariantGitError('invalid', 'Variants // Branch // Branch name is required');
   }
 
   const path = await ensureLocalGitVariantPath(id);
@@ -650,3 +712,600 @@ const undoVariantCloneIfRequested = async (variant: Variant, dry: boolean): Prom
     })}`,
   );
 };
+
+const INTEGRATE_STRATEGIES: IntegrateVariantStrategy[] = ['merge', 'rebase', 'squash'];
+const INTEGRATE_AFTER_ACTIONS: IntegrateVariantAfter[] = ['keep', 'delete', 'reset'];
+
+interface IntegrationPlan {
+  variantId: string;
+  variantPath: string;
+  sourceBranch: string;
+  sourceRef: string;
+  targetPath: string;
+  baseBranch: string;
+  baseRef: string;
+  workBranch: string;
+  originalTargetBranch: string;
+  remoteProduct: boolean;
+}
+
+interface IntegrationOutcome {
+  conflicts: string[];
+  integratedHead: string | null;
+  pushed: boolean;
+}
+
+const runIntegrateGit = async (
+  args: string[],
+  cwd: string,
+  message: string,
+  metadata: Record<string, unknown> = {},
+): Promise<string> => {
+  const result = await runGit(args, cwd);
+  if (!result.ok) {
+    throw new Error(
+      `Variants // Integrate // ${message} ${formatLogMetadata({
+        ...metadata,
+        command: `git ${args.join(' ')}`,
+        exitCode: result.exitCode,
+        path: cwd,
+        stderr: trimToNull(result.stderr),
+      })}`,
+    );
+  }
+
+  return result.stdout;
+};
+
+const readActiveBranch = async (path: string): Promise<string | null> => {
+  const result = await runGit(['rev-parse', '--abbrev-ref', 'HEAD'], path);
+  const branch = trimToNull(result.stdout);
+  if (!result.ok || !branch || branch === 'HEAD') {
+    return null;
+  }
+
+  return branch;
+};
+
+const ensureIntegrateWorktreeClean = async (path: string, role: string): Promise<void> => {
+  const status = await runIntegrateGit(['status', '--porcelain'], path, 'Unable to read git status', {
+    role,
+  });
+  if (trimToNull(status)) {
+    throw new VariantGitError(
+      'dirty',
+      `Variants // Integrate // Working tree has changes ${formatLogMetadata({ path, role })}`,
+    );
+  }
+};
+
+const resolveIntegrationPlan = async (
+  id: string,
+  requestedBaseBranch: string | null,
+): Promise<IntegrationPlan> => {
+  const variant = await getVariantById(id, { poll: false });
+  const variantPath = await ensureLocalGitVariantPath(id);
+  const sourceBranch = await readActiveBranch(variantPath);
+  if (!sourceBranch) {
+    throw new VariantGitError(
+      'not_git',
+      `Variants // Integrate // Variant has no active branch ${formatLogMetadata({ id, variantPath })}`,
+    );
+  }
+
+  const product = await getPrismaClient().product.findUnique({ where: { id: variant.productId } });
+  if (!product) {
+    throw new NotFoundError(`Product ${variant.productId} was not found`);
+  }
+
+  const productLocator = parseLocatorId(product.locator);
+  const workBranch = `darkfactory/integrate-${id}`;
+
+  if (productLocator.type === 'local') {
+    const targetPath = locatorIdToHostPath(productLocator.locator);
+    if (resolve(targetPath) === resolve(variantPath)) {
+      throw new VariantGitError(
+        'unsupported',
+        `Variants // Integrate // Variant is the product workspace ${formatLogMetadata({
+          id,
+          targetPath,
+        })}`,
+      );
+    }
+
+    const insideWorkTree = await runGit(['rev-parse', '--is-inside-work-tree'], targetPath).catch(
+      () => null,
+    );
+    if (!insideWorkTree?.ok || !insideWorkTree.stdout.trim().includes('true')) {
+      throw new VariantGitError(
+        'not_git',
+        `Variants // Integrate // Product path is not a git worktree ${formatLogMetadata({
+          productId: product.id,
+          targetPath,
+        })}`,
+      );
+    }
+
+    const originalTargetBranch = await readActiveBranch(targetPath);
+    if (!originalTargetBranch) {
+      throw new VariantGitError(
+        'not_git',
+        `Variants // Integrate // Product has no active branch ${formatLogMetadata({
+          productId: product.id,
+          targetPath,
+        })}`,
+      );
+    }
+
+    const baseBranch = requestedBaseBranch ?? originalTargetBranch;
+    if (!(await hasLocalBranch(targetPath, baseBranch))) {
+      throw new VariantGitError(
+        'invalid',
+        `Variants // Integrate // Base branch not found ${formatLogMetadata({
+          baseBranch,
+          targetPath,
+        })}`,
+      );
+    }
+
+    const sourceRef = `refs/darkfactory/integrate/${id}`;
+    await runIntegrateGit(
+      ['fetch', '--no-tags', variantPath, `+refs/heads/${sourceBranch}:${sourceRef}`],
+      targetPath,
+      'Fetching variant branch failed',
+      { sourceBranch },
+    );
+
+    return {
+      variantId: id,
+      variantPath,
+      sourceBranch,
+      sourceRef,
+      targetPath,
+      baseBranch,
+      baseRef: `refs/heads/${baseBranch}`,
+      workBranch: baseBranch,
+      originalTargetBranch,
+      remoteProduct: false,
+    };
+  }
+
+  if (productLocator.type === 'git') {
+    const baseBranch = requestedBaseBranch ?? productLocator.ref;
+    await runIntegrateGit(
+      ['fetch', '--no-tags', 'origin', `+refs/heads/${baseBranch}:refs/remotes/origin/${baseBranch}`],
+      variantPath,
+      'Fetching base branch failed',
+      { baseBranch },
+    );
+
+    return {
+      variantId: id,
+      variantPath,
+      sourceBranch,
+      sourceRef: `refs/heads/${sourceBranch}`,
+      targetPath: variantPath,
+      baseBranch,
+      baseRef: `refs/remotes/origin/${baseBranch}`,
+      workBranch,
+      originalTargetBranch: sourceBranch,
+      remoteProduct: true,
+    };
+  }
+
+  throw new VariantGitError(
+    'unsupported',
+    `Variants // Integrate // Product locator is not supported ${formatLogMetadata({
+      locator: product.locator,
+      productId: product.id,
+    })}`,
+  );
+};
+
+const readIntegrationCommits = async (plan: IntegrationPlan): Promise<IntegrateVariantCommit[]> => {
+  const stdout = await runIntegrateGit(
+    ['log', '--reverse', '--format=%H%x1f%an%x1f%s', `${plan.baseRef}..${plan.sourceRef}`],
+    plan.targetPath,
+    'Listing commits failed',
+  );
+
+  return stdout
+    .split('\n')
+    .map((line) => line.trim())
+    .filter((line) => line.length > 0)
+    .map((line) => {
+      const [sha = '', author = '', subject = ''] = line.split('\x1f');
+      return { sha, author, subject };
+    });
+};
+
+const predictIntegrationConflicts = async (plan: IntegrationPlan): Promise<string[]> => {
+  const result = await runGit(
+    ['merge-tree', '--write-tree', '--name-only', '--no-messages', plan.baseRef, plan.sourceRef],
+    plan.targetPath,
+  );
+  if (result.exitCode !== 1) {
+    if (!result.ok) {
+      Log.debug(
+        `Core // Variants Controller // Integrate conflict prediction unavailable ${formatLogMetadata({
+          exitCode: result.exitCode,
+          stderr: trimToNull(result.stderr),
+        })}`,
+      );
+    }
+    return [];
+  }
+
+  return result.stdout
+    .split('\n')
+    .slice(1)
+    .map((line) => line.trim())
+    .filter((line) => line.length > 0);
+};
+
+const readConflictedFiles = async (path: string): Promise<string[]> => {
+  const result = await runGit(['diff', '--name-only', '--diff-filter=U'], path);
+  return result.stdout
+    .split('\n')
+    .map((line) => line.trim())
+    .filter((line) => line.length > 0);
+};
+
+const failIntegrationStep = async (
+  path: string,
+  abortArgs: string[],
+  message: string,
+  stderr: string,
+): Promise<string[]> => {
+  const conflicts = await readConflictedFiles(path);
+  await runGit(abortArgs, path);
+  if (conflicts.length > 0) {
+    return conflicts;
+  }
+
+  throw new Error(
+    `Variants // Integrate // ${message} ${formatLogMetadata({ path, stderr: trimToNull(stderr) })}`,
+  );
+};
+
+const runIntegrationStrategy = async (
+  plan: IntegrationPlan,
+  strategy: IntegrateVariantStrategy,
+): Promise<string[]> => {
+  const path = plan.targetPath;
+  const message = `Integrate ${plan.sourceBranch} (variant ${plan.variantId})`;
+
+  if (strategy === 'merge') {
+    const mergeResult = await runGit(['merge', '--no-ff', '-m', message, plan.sourceRef], path);
+    if (!mergeResult.ok) {
+      return failIntegrationStep(path, ['merge', '--abort'], 'Merge failed', mergeResult.stderr);
+    }
+    return [];
+  }
+
+  if (strategy === 'squash') {
+    const squashResult = await runGit(['merge', '--squash', plan.sourceRef], path);
+    if (!squashResult.ok) {
+      return failIntegrationStep(path, ['reset', '--merge'], 'Squash failed', squashResult.stderr);
+    }
+
+    const commitResult = await runGit(['commit', '-m', message], path);
+    if (!commitResult.ok) {
+      await runGit(['reset', '--hard', 'HEAD'], path);
+      throw new Error(
+        `Variants // Integrate // Squash commit failed ${formatLogMetadata({
+          path,
+          stderr: trimToNull(commitResult.stderr),
+        })}`,
+      );
+    }
+    return [];
+  }
+
+  const rebaseBranch = `darkfactory/integrate-${plan.variantId}-rebase`;
+  await runIntegrateGit(['checkout', '-B', rebaseBranch, plan.sourceRef], path, 'Rebase setup failed');
+  const rebaseResult = await runGit(['rebase', plan.workBranch], path);
+  let conflicts: string[] = [];
+  try {
+    if (!rebaseResult.ok) {
+      conflicts = await failIntegrationStep(
+        path,
+        ['rebase', '--abort'],
+        'Rebase failed',
+        rebaseResult.stderr,
+      );
+    }
+    await runIntegrateGit(['checkout', plan.workBranch], path, 'Rebase checkout failed');
+    if (conflicts.length === 0) {
+      await runIntegrateGit(['merge', '--ff-only', rebaseBranch], path, 'Fast-forward failed');
+    }
+  } finally {
+    await runGit(['checkout', plan.workBranch], path);
+    await runGit(['branch', '-D', rebaseBranch], path);
+  }
+
+  return conflicts;
+};
+
+const restoreIntegrationTarget = async (plan: IntegrationPlan): Promise<void> => {
+  const activeBranch = await readActiveBranch(plan.targetPath);
+  if (activeBranch !== plan.originalTargetBranch) {
+    const checkoutResult = await runGit(['checkout', plan.originalTargetBranch], plan.targetPath);
+    if (!checkoutResult.ok) {
+      Log.warn(
+        `Core // Variants Controller // Integrate restore checkout failed ${formatLogMetadata({
+          branch: plan.originalTargetBranch,
+          path: plan.targetPath,
+          stderr: trimToNull(checkoutResult.stderr),
+        })}`,
+      );
+    }
+  }
+
+  if (plan.remoteProduct) {
+    await runGit(['branch', '-D', plan.workBranch], plan.targetPath);
+  } else {
+    await runGit(['update-ref', '-d', plan.sourceRef], plan.targetPath);
+  }
+};
+
+const applyIntegration = async (
+  plan: IntegrationPlan,
+  strategy: IntegrateVariantStrategy,
+): Promise<IntegrationOutcome> => {
+  await ensureIntegrateWorktreeClean(plan.variantPath, 'variant');
+  if (plan.targetPath !== plan.variantPath) {
+    await ensureIntegrateWorktreeClean(plan.targetPath, 'product');
+  }
+
+  if (plan.remoteProduct) {
+    await runIntegrateGit(
+      ['checkout', '-B', plan.workBranch, plan.baseRef],
+      plan.targetPath,
+      'Base checkout failed',
+    );
+  } else if (plan.originalTargetBranch !== plan.baseBranch) {
+    await runIntegrateGit(['checkout', plan.baseBranch], plan.targetPath, 'Base checkout failed');
+  }
+
+  const conflicts = await runIntegrationStrategy(plan, strategy);
+  if (conflicts.length > 0) {
+    return { conflicts, integratedHead: null, pushed: false };
+  }
+
+  const integratedHead = trimToNull(
+    await runIntegrateGit(['rev-parse', 'HEAD'], plan.targetPath, 'Reading integrated head failed'),
+  );
+
+  if (!plan.remoteProduct) {
+    return { conflicts: [], integratedHead, pushed: false };
+  }
+
+  await runIntegrateGit(
+    ['push', 'origin', `HEAD:refs/heads/${plan.baseBranch}`],
+    plan.targetPath,
+    'Pushing base branch failed',
+    { baseBranch: plan.baseBranch },
+  );
+  return { conflicts: [], integratedHead, pushed: true };
+};
+
+const applyIntegrateAfter = async (
+  plan: IntegrationPlan,
+  after: IntegrateVariantAfter,
+  integratedHead: string,
+): Promise<void> => {
+  if (after === 'reset') {
+    if (plan.targetPath !== plan.variantPath) {
+      await runIntegrateGit(
+        ['fetch', '--no-tags', plan.targetPath, `refs/heads/${plan.baseBranch}`],
+        plan.variantPath,
+        'Fetching integrated base failed',
+      );
+    }
+    await runIntegrateGit(
+      ['reset', '--hard', integratedHead],
+      plan.variantPath,
+      'Resetting variant failed',
+    );
+    await syncVariantGitInfo(plan.variantId);
+    return;
+  }
+
+  if (after === 'delete') {
+    try {
+      await pushVariantBranchForDelete(plan);
+      await deleteVariantById(plan.variantId);
+    } catch (error) {
+      throw new VariantGitError(
+        'delete_blocked',
+        `Variants // Integrate // Integrated, but the variant was not deleted ${formatLogMetadata({
+          error: error instanceof Error ? error.message : String(error),
+          id: plan.variantId,
+          integratedHead,
+        })}`,
+      );
+    }
+  }
+};
+
+/**
+ * Read-only check for `after: 'delete'`, run before anything is integrated: every commit in the
+ * clone that no remote has must be one of the commits being integrated. Unpushed work on any other
+ * branch blocks the delete.
+ */
+const ensureVariantDeletableAfterIntegrate = async (
+  plan: IntegrationPlan,
+  commits: IntegrateVariantCommit[],
+): Promise<void> => {
+  const branches = (
+    await runIntegrateGit(
+      ['for-each-ref', '--format=%(refname)', 'refs/heads'],
+      plan.variantPath,
+      'Listing branches failed',
+    )
+  )
+    .split('\n')
+    .map((line) => line.trim())
+    .filter((ref) => ref.length > 0);
+  if (branches.length === 0) {
+    return;
+  }
+
+  const integrating = new Set(commits.map((commit) => commit.sha));
+  const unpushed = (
+    await runIntegrateGit(
+      ['log', '--format=%H', ...branches, '--not', '--remotes'],
+      plan.variantPath,
+      'Checking unpushed branches failed',
+    )
+  )
+    .split('\n')
+    .map((line) => line.trim())
+    .filter((sha) => sha.length > 0 && !integrating.has(sha));
+  if (unpushed.length > 0) {
+    throw new VariantGitError(
+      'delete_blocked',
+      `Variants // Integrate // Delete blocked: clone has unpushed branches ${formatLogMetadata({
+        path: plan.variantPath,
+        unpushedCommits: unpushed.length,
+      })}`,
+    );
+  }
+};
+
+/** Publishes the integrated variant branch so the regular delete safety checks pass. */
+const pushVariantBranchForDelete = async (plan: IntegrationPlan): Promise<void> => {
+  const pushResult = await runGit(
+    ['push', 'origin', `refs/heads/${plan.sourceBranch}:refs/heads/${plan.sourceBranch}`],
+    plan.variantPath,
+  );
+  if (!pushResult.ok) {
+    throw new Error(
+      `pushing variant branch failed ${formatLogMetadata({
+        path: plan.variantPath,
+        sourceBranch: plan.sourceBranch,
+        stderr: trimToNull(pushResult.stderr),
+      })}`,
+    );
+  }
+};
+
+export const integrateVariantById = async (
+  id: string,
+  input: IntegrateVariantInput = {},
+): Promise<IntegrateVariantResult> => {
+  const strategy = input.strategy ?? 'merge';
+  const after = input.after ?? 'keep';
+  const preview = input.preview ?? false;
+
+  if (!INTEGRATE_STRATEGIES.includes(strategy)) {
+    throw new VariantGitError(
+      'invalid',
+      `Variants // Integrate // Unsupported strategy ${formatLogMetadata({ id, strategy })}`,
+    );
+  }
+
+  if (!INTEGRATE_AFTER_ACTIONS.includes(after)) {
+    throw new VariantGitError(
+      'invalid',
+      `Variants // Integrate // Unsupported after action ${formatLogMetadata({ after, id })}`,
+    );
+  }
+
+  Log.info(
+    `Core // Variants Controller // Integrate requested ${formatLogMetadata({
+      after,
+      baseBranch: input.baseBranch ?? null,
+      id,
+      preview,
+      strategy,
+    })}`,
+  );
+
+  const plan = await resolveIntegrationPlan(id, trimToNull(input.baseBranch));
+  const baseResult = {
+    variantId: id,
+    strategy,
+    baseBranch: plan.baseBranch,
+    sourceBranch: plan.sourceBranch,
+    targetPath: plan.targetPath,
+    after,
+  };
+
+  let commits: IntegrateVariantCommit[] = [];
+  let outcome: IntegrationOutcome = { conflicts: [], integratedHead: null, pushed: false };
+  try {
+    commits = await readIntegrationCommits(plan);
+    if (preview) {
+      const conflicts = commits.length > 0 ? await predictIntegrationConflicts(plan) : [];
+      return {
+        ...baseResult,
+        status: 'preview',
+        commits,
+        conflicts,
+        integratedHead: null,
+        pushed: false,
+      };
+    }
+
+    if (commits.length === 0) {
+      return {
+        ...baseResult,
+        status: 'up_to_date',
+        commits,
+        conflicts: [],
+        integratedHead: null,
+        pushed: false,
+      };
+    }
+
+    if (after === 'delete') {
+      await ensureVariantDeletableAfterIntegrate(plan, commits);
+    }
+    outcome = await applyIntegration(plan, strategy);
+  } finally {
+    await restoreIntegrationTarget(plan);
+  }
+
+  if (outcome.conflicts.length > 0 || !outcome.integratedHead) {
+    Log.warn(
+      `Core // Variants Controller // Integrate stopped on conflicts ${formatLogMetadata({
+        conflicts: outcome.conflicts,
+        id,
+        strategy,
+      })}`,
+    );
+    return {
+      ...baseResult,
+      status: 'conflicts',
+      commits,
+      conflicts: outcome.conflicts,
+      integratedHead: null,
+      pushed: false,
+    };
+  }
+
+  await applyIntegrateAfter(plan, after, outcome.integratedHead);
+
+  Log.info(
+    `Core // Variants Controller // Variant integrated ${formatLogMetadata({
+      after,
+      baseBranch: plan.baseBranch,
+      commits: commits.length,
+      id,
+      integratedHead: outcome.integratedHead,
+      pushed: outcome.pushed,
+      strategy,
+    })}`,
+  );
+
+  return {
+    ...baseResult,
+    status: 'integrated',
+    commits,
+    conflicts: [],
+    integratedHead: outcome.integratedHead,
+    pushed: outcome.pushed,
+  };
+};
@@ -515,4 +515,238 @@ describe('variants module integration', () => {
       await rm(workspace, { recursive: true, force: true });
     }
   });
+
+  const seedIntegrateWorkspace = async (
+    prefix: string,
+  ): Promise<{ workspace: string; productPath: string; variantPath: string }> => {
+    const workspace = await mkdtemp(join(tmpdir(), prefix));
+    const productPath = join(workspace, 'product');
+    const variantPath = join(workspace, 'variant-clone');
+
+    await mkdir(productPath, { recursive: true });
+    runGit(['init', '-b', 'main'], productPath);
+    runGit(['config', 'user.name', 'Dark Factory Test'], productPath);
+    runGit(['config', 'user.email', 'test@example.com'], productPath);
+    await writeFile(join(productPath, 'README.md'), '# main\n', 'utf8');
+    runGit(['add', '.'], productPath);
+    runGit(['commit', '-m', 'main init'], productPath);
+
+    runGit(['clone', productPath, variantPath], workspace);
+    runGit(['config', 'user.name', 'Dark Factory Test'], variantPath);
+    runGit(['config', 'user.email', 'test@example.com'], variantPath);
+    runGit(['checkout', '-b', 'df/demo-abc123'], variantPath);
+    await writeFile(join(variantPath, 'README.md'), '# variant\n', 'utf8');
+    runGit(['commit', '-am', 'variant change'], variantPath);
+
+    return { workspace, productPath, variantPath };
+  };
+
+  it('previews and squashes a variant branch into the local product base branch', async () => {
+    const { workspace, productPath, variantPath } = await seedIntegrateWorkspace(
+      'dark-factory-variants-integrate-',
+    );
+
+    try {
+      const product = await createProduct({ locator: `@local://${productPath}` });
+      const variant = await createVariant({
+        product: {
+          connect: {
+            id: product.id,
+          },
+        },
+        name: 'integrate-variant',
+        locator: `@local://${variantPath}`,
+      });
+
+      const app = buildApp();
+      const integrate = (body: Record<string, unknown>) =>
+        app.handle(
+          new Request(`http://localhost/variants/${variant.id}/integrate`, {
+            method: 'POST',
+            headers: {
+              'content-type': 'application/json',
+            },
+            body: JSON.stringify(body),
+          }),
+        );
+
+      const previewResponse = await integrate({ preview: true });
+      expect(previewResponse.status).toBe(200);
+      await expect(previewResponse.json()).resolves.toMatchObject({
+        ok: true,
+        data: {
+          status: 'preview',
+          baseBranch: 'main',
+          sourceBranch: 'df/demo-abc123',
+          commits: [{ subject: 'variant change' }],
+          conflicts: [],
+        },
+      });
+      expect(await readFile(join(productPath, 'README.md'), 'utf8')).toBe('# main\n');
+
+      const integrateResponse = await integrate({ strategy: 'squash', after: 'reset' });
+      expect(integrateResponse.status).toBe(200);
+      const integratePayload = (await integrateResponse.json()) as {
+        data: { status: string; integratedHead: string };
+      };
+      expect(integratePayload.data.status).toBe('integrated');
+      expect(await readFile(join(productPath, 'README.md'), 'utf8')).toBe('# variant\n');
+      expect(runGitOutput(['rev-parse', 'HEAD'], productPath)).toBe(
+        integratePayload.data.integratedHead,
+      );
+      expect(runGitOutput(['rev-parse', 'HEAD'], variantPath)).toBe(
+        integratePayload.data.integratedHead,
+      );
+      expect(runGitOutput(['for-each-ref', 'refs/darkfactory'], productPath)).toBe('');
+    } finally {
+      await rm(workspace, { recursive: true, force: true });
+    }
+  });
+
+  it('reports conflicting files and leaves the product base branch untouched', async () => {
+    const { workspace, productPath, variantPath } = await seedIntegrateWorkspace(
+      'dark-factory-variants-integrate-conflict-',
+    );
+
+    try {
+      await writeFile(join(productPath, 'README.md'), '# diverged\n', 'utf8');
+      runGit(['commit', '-am', 'main diverged'], productPath);
+      const baseHead = runGitOutput(['rev-parse', 'HEAD'], productPath);
+
+      const product = await createProduct({ locator: `@local://${productPath}` });
+      const variant = await createVariant({
+        product: {
+          connect: {
+            id: product.id,
+          },
+        },
+        name: 'integrate-conflict-variant',
+        locator: `@local://${variantPath}`,
+      });
+
+      const app = buildApp();
+      const response = await app.handle(
+        new Request(`http://localhost/variants/${variant.id}/integrate`, {
+          method: 'POST',
+          headers: {
+            'content-type': 'application/json',
+          },
+          body: JSON.stringify({ strategy: 'merge', after: 'delete' }),
+        }),
+      );
+
+      expect(response.status).toBe(200);
+      await expect(response.json()).resolves.toMatchObject({
+        ok: true,
+        data: {
+          status: 'conflicts',
+          conflicts: ['README.md'],
+          integratedHead: null,
+        },
+      });
+      expect(runGitOutput(['rev-parse', 'HEAD'], productPath)).toBe(baseHead);
+      expect(runGitOutput(['status', '--porcelain'], productPath)).toBe('');
+      // Nothing is published when the integration stops on conflicts.
+      expect(runGitOutput(['for-each-ref', 'refs/heads/df'], productPath)).toBe('');
+      await access(variantPath);
+      await expect(getVariantById(variant.id, { poll: false })).resolves.toMatchObject({
+        id: variant.id,
+      });
+    } finally {
+      await rm(workspace, { recursive: true, force: true });
+    }
+  });
+
+  it('pushes the variant branch and deletes the variant through the regular delete path', async () => {
+    const { workspace, productPath, variantPath } = await seedIntegrateWorkspace(
+      'dark-factory-variants-integrate-delete-',
+    );
+
+    try {
+      const product = await createProduct({ locator: `@local://${productPath}` });
+      const variant = await createVariant({
+        product: {
+          connect: {
+            id: product.id,
+          },
+        },
+        name: 'integrate-delete-variant',
+        locator: `@local://${variantPath}`,
+      });
+      const variantHead = runGitOutput(['rev-parse', 'HEAD'], variantPath);
+
+      const app = buildApp();
+      const response = await app.handle(
+        new Request(`http://localhost/variants/${variant.id}/integrate`, {
+          method: 'POST',
+          headers: {
+            'content-type': 'application/json',
+          },
+          body: JSON.stringify({ strategy: 'merge', after: 'delete' }),
+        }),
+      );
+
+      expect(response.status).toBe(200);
+      await expect(response.json()).resolves.toMatchObject({
+        ok: true,
+        data: { status: 'integrated', after: 'delete' },
+      });
+      expect(runGitOutput(['rev-parse', 'refs/heads/df/demo-abc123'], productPath)).toBe(variantHead);
+      await expect(access(variantPath)).rejects.toThrow();
+      await expect(getVariantById(variant.id, { poll: false })).rejects.toThrow();
+    } finally {
+      await rm(workspace, { recursive: true, force: true });
+    }
+  });
+
+  it('refuses to integrate with delete while the clone has other unpushed branches', async () => {
+    const { workspace, productPath, variantPath } = await seedIntegrateWorkspace(
+      'dark-factory-variants-integrate-unpushed-',
+    );
+
+    try {
+      runGit(['checkout', '-b', 'scratch'], variantPath);
+      await writeFile(join(variantPath, 'NOTES.md'), 'local only\n', 'utf8');
+      runGit(['add', '.'], variantPath);
+      runGit(['commit', '-m', 'scratch notes'], variantPath);
+      runGit(['checkout', 'df/demo-abc123'], variantPath);
+      const baseHead = runGitOutput(['rev-parse', 'HEAD'], productPath);
+
+      const product = await createProduct({ locator: `@local://${productPath}` });
+      const variant = await createVariant({
+        product: {
+          connect: {
+            id: product.id,
+          },
+        },
+        name: 'integrate-unpushed-variant',
+        locator: `@local://${variantPath}`,
+      });
+
+      const app = buildApp();
+      const response = await app.handle(
+        new Request(`http://localhost/variants/${variant.id}/integrate`, {
+          method: 'POST',
+          headers: {
+            'content-type': 'application/json',
+          },
+          body: JSON.stringify({ strategy: 'merge', after: 'delete' }),
+        }),
+      );
+
+      expect(response.status).toBe(400);
+      await expect(response.json()).resolves.toMatchObject({
+        ok: false,
+        error: { code: 'VARIANTS_INTEGRATE_DELETE_BLOCKED' },
+      });
+      expect(runGitOutput(['rev-parse', 'HEAD'], productPath)).toBe(baseHead);
+      expect(runGitOutput(['for-each-ref', 'refs/heads/df'], productPath)).toBe('');
+      await access(variantPath);
+      await expect(getVariantById(variant.id, { poll: false })).resolves.toMatchObject({
+        id: variant.id,
+      });
+    } finally {
+      await rm(workspace, { recursive: true, force: true });
+    }
+  });
 });
@@ -14,9 +14,12 @@ import {
   createVariant,
   deleteVariantById,
   getVariantById,
+  integrateVariantById,
+  isVariantGitError,
   listVariants,
   syncVariantGitInfo,
   updateVariantById,
+  type VariantGitErrorReason,
 } from './variants.controller';
 import { isNotFoundError } from '../common/controller.errors';
 import { failure, success, toErrorMessage } from '../../utils/api-response';
@@ -28,11 +31,28 @@ export interface VariantsRoutesDependencies {
   deleteVariantById: typeof deleteVariantById;
   getVariantById: typeof getVariantById;
   importVariantActors: typeof importVariantActors;
+  integrateVariantById: typeof integrateVariantById;
   listVariants: typeof listVariants;
   syncVariantGitInfo: typeof syncVariantGitInfo;
   updateVariantById: typeof updateVariantById;
 }
 
+const BRANCH_ERROR_CODES: Partial<Record<VariantGitErrorReason, string>> = {
+  invalid: 'VARIANTS_BRANCH_INVALID',
+  unsupported: 'VARIANTS_BRANCH_UNSUPPORTED',
+  path_missing: 'VARIANTS_BRANCH_PATH_MISSING',
+  not_git: 'VARIANTS_BRANCH_NOT_GIT',
+};
+
+const INTEGRATE_ERROR_CODES: Record<VariantGitErrorReason, string> = {
+  invalid: 'VARIANTS_INTEGRATE_INVALID',
+  unsupported: 'VARIANTS_INTEGRATE_UNSUPPORTED',
+  path_missing: 'VARIANTS_INTEGRATE_NOT_GIT',
+  not_git: 'VARIANTS_INTEGRATE_NOT_GIT',
+  dirty: 'VARIANTS_INTEGRATE_DIRTY',
+  delete_blocked: 'VARIANTS_INTEGRATE_DELETE_BLOCKED',
+};
+
 const variantsListResponse = t.Object({
   ok: t.Literal(true),
   data: t.Array(VariantPlain),
@@ -68,6 +88,34 @@ const variantBranchResponse = t.Object({
   data: VariantPlain,
 });
 
+const variantIntegrateResponse = t.Object({
+  ok: t.Literal(true),
+  data: t.Object({
+    variantId: t.String(),
+    status: t.Union([
+      t.Literal('preview'),
+      t.Literal('up_to_date'),
+      t.Literal('integrated'),
+      t.Literal('conflicts'),
+    ]),
+    strategy: t.String(),
+    baseBranch: t.String(),
+    sourceBranch: t.String(),
+    targetPath: t.String(),
+    commits: t.Array(
+      t.Object({
+        sha: t.String(),
+        author: t.String(),
+        subject: t.String(),
+      }),
+    ),
+    conflicts: t.Array(t.String()),
+    integratedHead: t.Union([t.String(), t.Null()]),
+    pushed: t.Boolean(),
+    after: t.String(),
+  }),
+});
+
 const variantImportActorsResponse = t.Object({
   ok: t.Literal(true),
   data: t.Object({
@@ -131,6 +179,7 @@ export const createVariantsRoutes = (
     deleteVariantById,
     getVariantById,
     importVariantActors,
+    integrateVariantById,
     listVariants,
     syncVariantGitInfo,
     updateVariantById,
@@ -317,44 +366,85 @@ export const createVariantsRoutes = (
             return failure('VARIANTS_NOT_FOUND', error.message);
           }
 
-          const message = toErrorMessage(error);
-          if (message.includes('Branch name is required')) {
-            set.status = 400;
-            return failure('VARIANTS_BRANCH_INVALID', message);
-          }
-
-          if (message.includes('Variant locator must be local')) {
+          const branchCode = isVariantGitError(error) ? BRANCH_ERROR_CODES[error.reason] : undefined;
+          if (branchCode) {
             set.status = 400;
-            return failure('VARIANTS_BRANCH_UNSUPPORTED', message);
+            return failure(branchCode, toErrorMessage(error));
           }
 
-          if (message.includes('Variant path missing')) {
-            set.status = 400;
-            return failure('VARIANTS_BRANCH_PATH_MISSING', message);
+          const message = toErrorMessage(error);
+          Log.error(
+            `Core // Variants Route // Branch switch failed ${formatLogMetadata({
+              error: message,
+              id: params.id,
+            })}`,
+          );
+          set.status = 500;
+          return failure('VARIANTS_BRANCH_FAILED', message);
+        }
+      },
+      {
+        params: t.Object({ id: t.String() }),
+        body: t.Object({
+          branchName: t.String(),
+        }),
+        response: {
+          200: variantBranchResponse,
+          400: apiFailureResponse,
+          404: notFoundResponse,
+          500: apiFailureResponse,
+        },
+      },
+    )
+    .post(
+      '/:id/integrate',
+      async ({ params, body, set }) => {
+        try {
+          return success(
+            await dependencies.integrateVariantById(params.id, {
+              strategy: body.strategy,
+              preview: body.preview,
+              after: body.after,
+              baseBranch: body.baseBranch,
+            }),
+          );
+        } catch (error) {
+          if (isNotFoundError(error)) {
+            set.status = 404;
+            Log.warn(
+              `Core // Variants Route // Integrate variant not found ${formatLogMetadata({ id: params.id })}`,
+            );
+            return failure('VARIANTS_NOT_FOUND', error.message);
           }
 
-          if (message.includes('not a git worktree')) {
+          const message = toErrorMessage(error);
+          if (isVariantGitError(error)) {
             set.status = 400;
-            return failure('VARIANTS_BRANCH_NOT_GIT', message);
+            return failure(INTEGRATE_ERROR_CODES[error.reason], message);
           }
 
           Log.error(
-            `Core // Variants Route // Branch switch failed ${formatLogMetadata({
+            `Core // Variants Route // Integrate failed ${formatLogMetadata({
               error: message,
               id: params.id,
             })}`,
           );
           set.status = 500;
-          return failure('VARIANTS_BRANCH_FAILED', message);
+          return failure('VARIANTS_INTEGRATE_FAILED', message);
         }
       },
       {
         params: t.Object({ id: t.String() }),
         body: t.Object({
-          branchName: t.String(),
+          strategy: t.Optional(
+            t.Union([t.Literal('merge'), t.Literal('rebase'), t.Literal('squash')]),
+          ),
+          preview: t.Optional(t.Boolean()),
+          after: t.Optional(t.Union([t.Literal('keep'), t.Literal('delete'), t.Literal('reset')])),
+          baseBranch: t.Optional(t.String()),
         }),
         response: {
-          200: variantBranchResponse,
+          200: variantIntegrateResponse,
           400: apiFailureResponse,
           404: notFoundResponse,
           500: apiFailureResponse,
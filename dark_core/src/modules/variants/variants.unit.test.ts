@@ -2,6 +2,7 @@ import { describe, expect, it } from 'bun:test';
 import { Elysia } from 'elysia';
 
 import { NotFoundError } from '../common/controller.errors';
+import { VariantGitError } from './variants.controller';
 import { createVariantsRoutes } from './variants.routes';
 
 const dependenciesBase = {
@@ -20,6 +21,9 @@ const dependenciesBase = {
   importVariantActors: async () => {
     throw new Error('not used in this test');
   },
+  integrateVariantById: async () => {
+    throw new Error('not used in this test');
+  },
   listVariants: async () => {
     throw new Error('not used in this test');
   },
@@ -249,6 +253,119 @@ describe('variants module unit', () => {
     });
   });
 
+  it('forwards integrate options and reports conflicts per file', async () => {
+    let received: unknown;
+
+    const app = new Elysia().use(
+      createVariantsRoutes({
+        ...dependenciesBase,
+        integrateVariantById: async (variantId, input) => {
+          received = { variantId, ...input };
+          return {
+            variantId,
+            status: 'conflicts',
+            strategy: 'rebase',
+            baseBranch: 'main',
+            sourceBranch: 'df/demo-abc123',
+            targetPath: '/tmp/demo',
+            commits: [{ sha: 'abc123', author: 'dev', subject: 'Add feature' }],
+            conflicts: ['src/lib.rs'],
+            integratedHead: null,
+            pushed: false,
+            after: 'keep',
+          };
+        },
+      }),
+    );
+
+    const response = await app.handle(
+      new Request('http://localhost/variants/v_1/integrate', {
+        method: 'POST',
+        headers: {
+          'content-type': 'application/json',
+        },
+        body: JSON.stringify({
+          strategy: 'rebase',
+          after: 'keep',
+        }),
+      }),
+    );
+
+    expect(response.status).toBe(200);
+    expect(received).toEqual({
+      variantId: 'v_1',
+      strategy: 'rebase',
+      preview: undefined,
+      after: 'keep',
+      baseBranch: undefined,
+    });
+    await expect(response.json()).resolves.toMatchObject({
+      ok: true,
+      data: {
+        status: 'conflicts',
+        conflicts: ['src/lib.rs'],
+      },
+    });
+  });
+
+  it('maps dirty worktree integrate errors to a client failure', async () => {
+    const app = new Elysia().use(
+      createVariantsRoutes({
+        ...dependenciesBase,
+        integrateVariantById: async () => {
+          throw new VariantGitError('dirty', 'Variants // Integrate // Working tree has changes');
+        },
+      }),
+    );
+
+    const response = await app.handle(
+      new Request('http://localhost/variants/v_1/integrate', {
+        method: 'POST',
+        headers: {
+          'content-type': 'application/json',
+        },
+        body: JSON.stringify({}),
+      }),
+    );
+
+    expect(response.status).toBe(400);
+    await expect(response.json()).resolves.toMatchObject({
+      ok: false,
+      error: {
+        code: 'VARIANTS_INTEGRATE_DIRTY',
+      },
+    });
+  });
+
+  it('maps blocked post-integrate deletes to a client failure', async () => {
+    const app = new Elysia().use(
+      createVariantsRoutes({
+        ...dependenciesBase,
+        integrateVariantById: async () => {
+          throw new VariantGitError('delete_blocked', 'Variants // Integrate // Variant clone has unpushed branches');
+        },
+      }),
+    );
+
+    const response = await app.handle(
+      new Request('http://localhost/variants/v_1/integrate', {
+        method: 'POST',
+        headers: {
+          'content-type': 'application/json',
+        },
+        body: JSON.stringify({ after: 'delete' }),
+      }),
+    );
+
+    expect(response.status).toBe(400);
+    await expect(response.json()).resolves.toMatchObject({
+      ok: false,
+      error: {
+        code: 'VARIANTS_INTEGRATE_DELETE_BLOCKED',
+      },
+    });
+  });
+
   it('forwards dry query on variant delete route', async () => {
     let receivedDry: boolean | undefined;
 
@@ -1,6 +1,122 @@
+import { createWriteStream, mkdirSync, renameSync, statSync, type WriteStream } from 'node:fs';
+import { dirname } from 'node:path';
+
 import adze from 'adze';
 
-export const logger = adze;
+/** Env var naming a file that receives one plain-text line per log entry. */
+export const LOG_FILE_ENV = 'DARKFACTORY_LOG_FILE';
+
+/** Size at which the log file is rotated to `<path>.1` and a fresh file is started. */
+export const LOG_FILE_MAX_BYTES = 10 * 1024 * 1024;
+
+export type LogLevel = 'debug' | 'info' | 'warn' | 'error';
+
+interface LogFileSink {
+  path: string;
+  stream: WriteStream;
+  bytes: number;
+}
+
+let logFileSink: LogFileSink | null = null;
+let logFileSinkDisabled = false;
+
+/** Formats a log file line as `<iso timestamp> <LEVEL> <message>`. */
+export const formatLogFileLine = (
+  level: LogLevel,
+  message: string,
+  at: Date = new Date(),
+): string => {
+  const singleLine = message.replace(/\r?\n/g, ' ');
+  return `${at.toISOString()} ${level.toUpperCase()} ${singleLine}\n`;
+};
+
+/** Whether writing `nextBytes` more would push a file of `currentBytes` past the cap. */
+export const shouldRotateLogFile = (
+  currentBytes: number,
+  nextBytes: number,
+  maxBytes: number = LOG_FILE_MAX_BYTES,
+): boolean => {
+  return currentBytes > 0 && currentBytes + nextBytes > maxBytes;
+};
+
+const disableLogFileSink = (path: string, error: unknown): void => {
+  if (logFileSinkDisabled) {
+    return;
+  }
+
+  // A broken sink must never take the server down; report once and stop writing.
+  logFileSinkDisabled = true;
+  logFileSink?.stream.end();
+  logFileSink = null;
+  const reason = error instanceof Error ? error.message : String(error);
+  adze.warn(`Core // Logging // Log file sink disabled ${formatLogMetadata({ path, reason })}`);
+};
+
+const openLogFileSink = (path: string): LogFileSink => {
+  let bytes = 0;
+  try {
+    bytes = statSync(path).size;
+  } catch {
+    // No file yet; the stream creates it.
+  }
+
+  const stream = createWriteStream(path, { flags: 'a', encoding: 'utf8' });
+  stream.on('error', (error) => disableLogFileSink(path, error));
+  return { path, stream, bytes };
+};
+
+const resolveLogFileSink = (path: string): LogFileSink => {
+  if (logFileSink?.path === path) {
+    return logFileSink;
+  }
+
+  logFileSink?.stream.end();
+  mkdirSync(dirname(path), { recursive: true });
+  logFileSink = openLogFileSink(path);
+  return logFileSink;
+};
+
+/** Moves the full file aside; the dark_tui tailer restarts when it sees the smaller file. */
+const rotateLogFileSink = (sink: LogFileSink): LogFileSink => {
+  sink.stream.end();
+  renameSync(sink.path, `${sink.path}.1`);
+  logFileSink = openLogFileSink(sink.path);
+  return logFileSink;
+};
+
+const writeLogFileLine = (level: LogLevel, message: string): void => {
+  const path = Bun.env[LOG_FILE_ENV]?.trim();
+  if (!path || logFileSinkDisabled) {
+    return;
+  }
+
+  try {
+    const line = formatLogFileLine(level, message);
+    const lineBytes = Buffer.byteLength(line, 'utf8');
+    let sink = resolveLogFileSink(path);
+    if (shouldRotateLogFile(sink.bytes, lineBytes)) {
+      sink = rotateLogFileSink(sink);
+    }
+
+    sink.stream.write(line);
+    sink.bytes += lineBytes;
+  } catch (error) {
+    disableLogFileSink(path, error);
+  }
+};
+
+const emit = (level: LogLevel, message: string): void => {
+  adze[level](message);
+  writeLogFileLine(level, message);
+};
+
+/** adze console logger that also mirrors entries to `DARKFACTORY_LOG_FILE` when set. */
+export const logger = {
+  debug: (message: string): void => emit('debug', message),
+  info: (message: string): void => emit('info', message),
+  warn: (message: string): void => emit('warn', message),
+  error: (message: string): void => emit('error', message),
+};
 
 export interface LogMetadataRecord {
   [key: string]: LogMetadataValue;
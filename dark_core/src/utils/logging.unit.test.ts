@@ -0,0 +1,23 @@
+import { describe, expect, it } from 'bun:test';
+
+import { formatLogFileLine, formatLogMetadata, shouldRotateLogFile } from './logging';
+
+describe('logging utilities', () => {
+  it('formats log file lines with timestamp, level, and a single-line message', () => {
+    const line = formatLogFileLine(
+      'warn',
+      `Core // Actors Route // Poll failed\nretrying ${formatLogMetadata({ actorId: 'act_1' })}`,
+      new Date('2026-01-02T03:04:05.006Z'),
+    );
+
+    expect(line).toBe(
+      '2026-01-02T03:04:05.006Z WARN Core // Actors Route // Poll failed retrying (meta={"actorId":"act_1"})\n',
+    );
+  });
+
+  it('rotates only once a non-empty log file would exceed the size cap', () => {
+    expect(shouldRotateLogFile(0, 200, 100)).toBe(false);
+    expect(shouldRotateLogFile(50, 50, 100)).toBe(false);
+    expect(shouldRotateLogFile(60, 50, 100)).toBe(true);
+  });
+});
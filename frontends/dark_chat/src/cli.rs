@@ -1,3 +1,5 @@
+use std::path::PathBuf;
+
 use clap::{Parser, ValueEnum};
 
 #[derive(Debug, Clone, Copy, ValueEnum)]
@@ -28,6 +30,15 @@ pub struct Cli {
     #[arg(long, env = "DARK_CHAT_SESSION_TITLE")]
     pub session_title: Option<String>,
 
+    /// Keymap TOML; defaults to ~/.darkfactory/keymap.toml when present.
+    #[arg(long, env = "DARK_CHAT_KEYMAP")]
+    pub keymap: Option<PathBuf>,
+
+    /// Theme preset (dark, light, high-contrast, solarized) or theme TOML path;
+    /// defaults to ~/.darkfactory/theme.toml when present. Files hot reload on save.
+    #[arg(long, env = "DARK_CHAT_THEME")]
+    pub theme: Option<String>,
+
     #[arg(
         long,
         env = "DARK_CHAT_PROVIDER",
@@ -0,0 +1,234 @@
+use std::fs;
+use std::path::Path;
+
+use anyhow::{Context, Result, bail};
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD;
+
+/// Largest single attachment accepted by the composer.
+pub const MAX_ATTACHMENT_BYTES: usize = 10 * 1024 * 1024;
+/// Combined payload cap for one prompt; OpenCode inlines attachments as data URLs.
+pub const MAX_TOTAL_ATTACHMENT_BYTES: usize = 25 * 1024 * 1024;
+pub const MAX_ATTACHMENTS: usize = 8;
+
+/// File sent alongside a prompt as an OpenCode `file` part.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChatAttachment {
+    pub filename: String,
+    pub mime: String,
+    pub bytes: Vec<u8>,
+}
+
+impl ChatAttachment {
+    /// Reads `path` from disk, rejecting directories and files over the size limit.
+    pub fn from_path(path: &Path) -> Result<Self> {
+        let metadata = fs::metadata(path).with_context(|| {
+            format!(
+                "Dark Chat // Attachment // File not found (path={})",
+                path.display()
+            )
+        })?;
+        if !metadata.is_file() {
+            bail!(
+                "Dark Chat // Attachment // Not a file (path={})",
+                path.display()
+            );
+        }
+        ensure_size(&path.display().to_string(), metadata.len() as usize)?;
+
+        let bytes = fs::read(path).with_context(|| {
+            format!(
+                "Dark Chat // Attachment // Failed to read file (path={})",
+                path.display()
+            )
+        })?;
+        let filename = path
+            .file_name()
+            .map(|name| name.to_string_lossy().to_string())
+            .unwrap_or_else(|| "attachment".to_string());
+
+        Self::from_bytes(filename, mime_for_path(path), bytes)
+    }
+
+    pub fn from_bytes(
+        filename: impl Into<String>,
+        mime: impl Into<String>,
+        bytes: Vec<u8>,
+    ) -> Result<Self> {
+        let filename = filename.into();
+        ensure_size(&filename, bytes.len())?;
+
+        Ok(Self {
+            filename,
+            mime: mime.into(),
+            bytes,
+        })
+    }
+
+    /// Captures the clipboard image (e.g. a screenshot) as a PNG attachment.
+    pub fn from_clipboard_image() -> Result<Self> {
+        let mut clipboard = arboard::Clipboard::new()
+            .context("Dark Chat // Attachment // Clipboard unavailable")?;
+        let image = clipboard
+            .get_image()
+            .context("Dark Chat // Attachment // Clipboard has no image")?;
+
+        let mut encoded = Vec::new();
+        {
+            let mut encoder =
+                png::Encoder::new(&mut encoded, image.width as u32, image.height as u32);
+            encoder.set_color(png::ColorType::Rgba);
+            encoder.set_depth(png::BitDepth::Eight);
+            encoder
+                .write_header()
+                .and_then(|mut writer| writer.write_image_data(&image.bytes))
+                .context("Dark Chat // Attachment // Failed to encode clipboard image")?;
+        }
+
+        let filename = format!("clipboard-{}.png", crate::framework::now_unix());
+        Self::from_bytes(filename, "image/png", encoded)
+    }
+
+    pub fn size_bytes(&self) -> usize {
+        self.bytes.len()
+    }
+
+    pub fn data_url(&self) -> String {
+        format!("data:{};base64,{}", self.mime, STANDARD.encode(&self.bytes))
+    }
+
+    /// Compact chip label for the composer (`shot.png 182K`).
+    pub fn chip_label(&self) -> String {
+        format!("{} {}", self.filename, format_size(self.size_bytes()))
+    }
+}
+
+/// Checks that adding `next` keeps the prompt within the count and total size caps.
+pub fn ensure_attachment_fits(existing: &[ChatAttachment], next: &ChatAttachment) -> Result<()> {
+    if existing.len() >= MAX_ATTACHMENTS {
+        bail!("Dark Chat // Attachment // At most {MAX_ATTACHMENTS} attachments per prompt");
+    }
+
+    let total = existing
+        .iter()
+        .map(ChatAttachment::size_bytes)
+        .sum::<usize>()
+        + next.size_bytes();
+    if total > MAX_TOTAL_ATTACHMENT_BYTES {
+        bail!(
+            "Dark Chat // Attachment // Attachments exceed {} in total (size={})",
+            format_size(MAX_TOTAL_ATTACHMENT_BYTES),
+            format_size(total)
+        );
+    }
+
+    Ok(())
+}
+
+/// MIME type inferred from the file extension; unknown extensions are sent as binary.
+pub fn mime_for_path(path: &Path) -> &'static str {
+    let extension = path
+        .extension()
+        .map(|value| value.to_string_lossy().to_ascii_lowercase())
+        .unwrap_or_default();
+
+    match extension.as_str() {
+        "png" => "image/png",
+        "jpg" | "jpeg" => "image/jpeg",
+        "gif" => "image/gif",
+        "webp" => "image/webp",
+        "bmp" => "image/bmp",
+        "svg" => "image/svg+xml",
+        "pdf" => "application/pdf",
+        "zip" => "application/zip",
+        "gz" | "tgz" => "application/gzip",
+        "json" => "application/json",
+        "md" | "markdown" => "text/markdown",
+        "csv" => "text/csv",
+        "html" | "htm" => "text/html",
+        "txt" | "log" | "rs" | "ts" | "tsx" | "js" | "py" | "toml" | "yaml" | "yml" | "sh" => {
+            "text/plain"
+        }
+        _ => "application/octet-stream",
+    }
+}
+
+/// True for files that should travel as attachments rather than inlined `@file` text.
+pub fn is_attachment_path(path: &Path) -> bool {
+    match mime_for_path(path) {
+        "application/octet-stream" => is_binary_file(path).unwrap_or(false),
+        "application/json" => false,
+        mime => !mime.starts_with("text/"),
+    }
+}
+
+/// Sniffs the first block for NUL bytes so unknown-but-text files still inline.
+fn is_binary_file(path: &Path) -> Result<bool> {
+    use std::io::Read;
+
+    let mut head = [0u8; 1024];
+    let read = fs::File::open(path)?.read(&mut head)?;
+    Ok(head[..read].contains(&0))
+}
+
+fn ensure_size(label: &str, size: usize) -> Result<()> {
+    if size > MAX_ATTACHMENT_BYTES {
+        bail!(
+            "Dark Chat // Attachment // {label} is {} (limit {})",
+            format_size(size),
+            format_size(MAX_ATTACHMENT_BYTES)
+        );
+    }
+    Ok(())
+}
+
+fn format_size(bytes: usize) -> String {
+    if bytes >= 1024 * 1024 {
+        format!("{:.1}M", bytes as f64 / (1024.0 * 1024.0))
+    } else if bytes >= 1024 {
+        format!("{}K", bytes.div_ceil(1024))
+    } else {
+        format!("{bytes}B")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use super::{
+        ChatAttachment, MAX_ATTACHMENT_BYTES, ensure_attachment_fits, is_attachment_path,
+        mime_for_path,
+    };
+
+    #[test]
+    fn infers_mime_and_attachment_kind_from_extension() {
+        assert_eq!(mime_for_path(Path::new("shot.PNG")), "image/png");
+        assert_eq!(mime_for_path(Path::new("spec.pdf")), "application/pdf");
+        assert_eq!(
+            mime_for_path(Path::new("blob.bin")),
+            "application/octet-stream"
+        );
+        assert!(is_attachment_path(Path::new("missing/shot.png")));
+        assert!(!is_attachment_path(Path::new("missing/notes.md")));
+        assert!(!is_attachment_path(Path::new("missing/Makefile")));
+    }
+
+    #[test]
+    fn rejects_oversized_attachments_with_clear_errors() {
+        let error =
+            ChatAttachment::from_bytes("huge.png", "image/png", vec![0; MAX_ATTACHMENT_BYTES + 1])
+                .expect_err("oversized attachment is rejected");
+        assert!(
+            error
+                .to_string()
+                .contains("huge.png is 10.0M (limit 10.0M)")
+        );
+
+        let small = ChatAttachment::from_bytes("a.png", "image/png", vec![1, 2, 3])
+            .expect("small attachment is accepted");
+        assert_eq!(small.data_url(), "data:image/png;base64,AQID");
+        assert_eq!(small.chip_label(), "a.png 3B");
+        assert!(ensure_attachment_fits(&[], &small).is_ok());
+    }
+}
@@ -1,12 +1,18 @@
 use std::sync::Arc;
 
 use anyhow::Result;
+use serde_json::json;
 use tokio::sync::mpsc::UnboundedReceiver;
 
+use crate::core::attachment::ChatAttachment;
+use crate::core::cache::ChatCache;
 use crate::core::systems::default_session_title;
 use crate::core::types::{
     ChatMessage, ChatRealtimeEvent, ChatSession, ProviderHealth, ProviderRuntimeStatus,
 };
+use crate::framework::{
+    Transcript, TranscriptFormat, TranscriptMessage, resolve_transcript_path, write_transcript,
+};
 use crate::providers::ChatProvider;
 
 #[derive(Debug, Clone)]
@@ -24,13 +30,16 @@ pub struct ChatSnapshot {
 pub struct ChatBackend {
     provider: Arc<dyn ChatProvider>,
     directory: String,
+    cache: ChatCache,
 }
 
 impl ChatBackend {
     pub fn new(provider: Arc<dyn ChatProvider>, directory: String) -> Self {
+        let cache = ChatCache::new(&directory, provider.provider_name());
         Self {
             provider,
             directory,
+            cache,
         }
     }
 
@@ -50,6 +59,12 @@ impl ChatBackend {
         &self.directory
     }
 
+    /// Last synced state from the on-disk cache, used to open instantly and to keep
+    /// history browsable while the provider is unreachable.
+    pub fn cached_snapshot(&self, active_session_id: Option<&str>) -> Result<Option<ChatSnapshot>> {
+        self.cache.load(active_session_id)
+    }
+
     pub async fn bootstrap(
         &self,
         preferred_session_id: Option<&str>,
@@ -96,7 +111,7 @@ impl ChatBackend {
             .await
             .unwrap_or_default();
 
-        Ok(ChatSnapshot {
+        let snapshot = ChatSnapshot {
             health,
             sessions,
             active_session_id,
@@ -104,7 +119,9 @@ impl ChatBackend {
             agents,
             models,
             runtime_status,
-        })
+        };
+        self.store_cache(&snapshot);
+        Ok(snapshot)
     }
 
     pub async fn refresh(&self, active_session_id: Option<&str>) -> Result<ChatSnapshot> {
@@ -135,7 +152,7 @@ impl ChatBackend {
             .await
             .unwrap_or_default();
 
-        Ok(ChatSnapshot {
+        let snapshot = ChatSnapshot {
             health,
             sessions,
             active_session_id: selected,
@@ -143,7 +160,9 @@ impl ChatBackend {
             agents,
             models,
             runtime_status,
-        })
+        };
+        self.store_cache(&snapshot);
+        Ok(snapshot)
     }
 
     pub async fn create_session(&self, title: Option<&str>) -> Result<ChatSession> {
@@ -162,11 +181,19 @@ impl ChatBackend {
         &self,
         session_id: &str,
         prompt: &str,
+        attachments: &[ChatAttachment],
         model: Option<&str>,
         agent: Option<&str>,
     ) -> Result<()> {
         self.provider
-            .send_prompt(&self.directory, session_id, prompt, model, agent)
+            .send_prompt(
+                &self.directory,
+                session_id,
+                prompt,
+                attachments,
+                model,
+                agent,
+            )
             .await
     }
 
@@ -175,6 +202,101 @@ impl ChatBackend {
             .run_command(&self.directory, session_id, command)
             .await
     }
+
+    fn store_cache(&self, snapshot: &ChatSnapshot) {
+        if let Err(error) = self.cache.store(snapshot) {
+            log::warn!(
+                "Dark Chat // Cache // Failed to store snapshot (meta={})",
+                json!({
+                    "session": snapshot.active_session_id,
+                    "path": self.cache.root().display().to_string(),
+                    "error": format!("{error:#}"),
+                })
+            );
+        }
+    }
+
+    /// Fetches the full history of each session for the local search index. A session
+    /// that fails to load falls back to its cached window; without one it is logged and
+    /// skipped so the next pass retries it.
+    pub async fn fetch_session_histories(
+        &self,
+        sessions: Vec<ChatSession>,
+    ) -> Result<Vec<(ChatSession, Vec<ChatMessage>)>> {
+        let mut histories = Vec::with_capacity(sessions.len());
+        for session in sessions {
+            let error = match self
+                .provider
+                .list_messages(&self.directory, &session.id, None)
+                .await
+            {
+                Ok(messages) => {
+                    histories.push((session, messages));
+                    continue;
+                }
+                Err(error) => error,
+            };
+            log::warn!(
+                "Dark Chat // Search // Failed to fetch session history (meta={})",
+                json!({ "session": session.id, "error": format!("{error:#}") })
+            );
+
+            match self.cache.load_messages(&session.id) {
+                Ok(messages) if !messages.is_empty() => histories.push((session, messages)),
+                Ok(_) => {}
+                Err(error) => log::warn!(
+                    "Dark Chat // Search // Failed to read cached session history (meta={})",
+                    json!({ "session": session.id, "error": format!("{error:#}") })
+                ),
+            }
+        }
+
+        Ok(histories)
+    }
+
+    /// Fetches the full session history and writes it to `target` (see
+    /// `resolve_transcript_path`). Returns a status line for the UI.
+    pub async fn export_session(
+        &self,
+        session: &ChatSession,
+        target: Option<&str>,
+    ) -> Result<String> {
+        let messages = self
+            .provider
+            .list_messages(&self.directory, &session.id, None)
+            .await?;
+
+        let mut transcript = Transcript::new(
+            session.id.clone(),
+            messages.iter().filter_map(transcript_message).collect(),
+        );
+        transcript.title = Some(session.title.clone());
+        transcript.directory = Some(self.directory.clone());
+        transcript.provider = Some(self.provider.provider_name().to_string());
+
+        let path = resolve_transcript_path(&self.directory, &session.id, target);
+        let format = TranscriptFormat::from_path(&path);
+        write_transcript(&transcript, &path, format)?;
+
+        Ok(format!(
+            "Exported {} messages to {}",
+            transcript.messages.len(),
+            path.display()
+        ))
+    }
+}
+
+fn transcript_message(message: &ChatMessage) -> Option<TranscriptMessage> {
+    if !message.raw.is_null() {
+        return TranscriptMessage::from_value(&message.raw);
+    }
+
+    TranscriptMessage::from_value(&json!({
+        "id": message.id,
+        "role": message.role,
+        "createdAt": message.created_at,
+        "text": message.text,
+    }))
 }
 
 fn pick_active_session_id(sessions: &[ChatSession], preferred: Option<&str>) -> Option<String> {
@@ -0,0 +1,276 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+
+use crate::core::backend::ChatSnapshot;
+use crate::core::types::{ChatMessage, ChatSession, ProviderHealth, ProviderRuntimeStatus};
+
+/// Last synced provider state for one workspace, stored as JSON under
+/// `<directory>/.darkfactory/cache/dark_chat/<provider>/`.
+///
+/// `sessions.json` holds the session list plus agent/model options; each session's
+/// message window lives in `messages/<session>.json` so switching sessions offline
+/// only reads what it needs.
+#[derive(Debug, Clone)]
+pub struct ChatCache {
+    root: PathBuf,
+}
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+#[serde(default, rename_all = "camelCase")]
+struct CachedSessions {
+    provider_version: Option<String>,
+    active_session_id: Option<String>,
+    sessions: Vec<ChatSession>,
+    agents: Vec<String>,
+    models: Vec<String>,
+}
+
+impl ChatCache {
+    pub fn new(directory: &str, provider_name: &str) -> Self {
+        let provider_slug = provider_name
+            .chars()
+            .map(|ch| if ch.is_ascii_alphanumeric() { ch } else { '_' })
+            .collect::<String>();
+
+        Self {
+            root: Path::new(directory)
+                .join(".darkfactory")
+                .join("cache")
+                .join("dark_chat")
+                .join(provider_slug),
+        }
+    }
+
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    /// Builds an offline snapshot from disk. Returns `None` when nothing was cached yet.
+    /// Health is always reported unhealthy since the data did not come from the server.
+    pub fn load(&self, preferred_session_id: Option<&str>) -> Result<Option<ChatSnapshot>> {
+        let Some(cached) = read_json::<CachedSessions>(&self.sessions_path())? else {
+            return Ok(None);
+        };
+
+        let active_session_id = preferred_session_id
+            .filter(|id| cached.sessions.iter().any(|session| session.id == *id))
+            .map(ToString::to_string)
+            .or(cached.active_session_id)
+            .or_else(|| cached.sessions.first().map(|session| session.id.clone()));
+        let messages = match active_session_id.as_deref() {
+            Some(session_id) => self.load_messages(session_id)?,
+            None => Vec::new(),
+        };
+
+        Ok(Some(ChatSnapshot {
+            health: ProviderHealth {
+                healthy: false,
+                version: cached.provider_version,
+            },
+            sessions: cached.sessions,
+            active_session_id,
+            messages,
+            agents: cached.agents,
+            models: cached.models,
+            runtime_status: ProviderRuntimeStatus::default(),
+        }))
+    }
+
+    pub fn load_messages(&self, session_id: &str) -> Result<Vec<ChatMessage>> {
+        Ok(read_json::<Vec<ChatMessage>>(&self.messages_path(session_id))?.unwrap_or_default())
+    }
+
+    /// Persists a live snapshot. Message files for sessions that disappeared upstream
+    /// are pruned so the cache does not grow without bound.
+    pub fn store(&self, snapshot: &ChatSnapshot) -> Result<()> {
+        let cached = CachedSessions {
+            provider_version: snapshot.health.version.clone(),
+            active_session_id: snapshot.active_session_id.clone(),
+            sessions: snapshot.sessions.clone(),
+            agents: snapshot.agents.clone(),
+            models: snapshot.models.clone(),
+        };
+        write_json(&self.sessions_path(), &cached)?;
+
+        if let Some(session_id) = snapshot.active_session_id.as_deref() {
+            write_json(&self.messages_path(session_id), &snapshot.messages)?;
+        }
+
+        self.prune_messages(&snapshot.sessions)
+    }
+
+    fn prune_messages(&self, sessions: &[ChatSession]) -> Result<()> {
+        let directory = self.root.join("messages");
+        let Ok(entries) = fs::read_dir(&directory) else {
+            return Ok(());
+        };
+
+        for entry in entries.flatten() {
+            let path = entry.path();
+            let Some(stem) = path.file_stem().and_then(|stem| stem.to_str()) else {
+                continue;
+            };
+            if sessions
+                .iter()
+                .all(|session| cache_file_stem(&session.id) != stem)
+            {
+                fs::remove_file(&path).with_context(|| {
+                    format!(
+                        "Dark Chat // Cache // Failed to prune messages (path={})",
+                        path.display()
+                    )
+                })?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn sessions_path(&self) -> PathBuf {
+        self.root.join("sessions.json")
+    }
+
+    fn messages_path(&self, session_id: &str) -> PathBuf {
+        self.root
+            .join("messages")
+            .join(format!("{}.json", cache_file_stem(session_id)))
+    }
+}
+
+fn cache_file_stem(session_id: &str) -> String {
+    session_id
+        .chars()
+        .map(|ch| {
+            if ch.is_ascii_alphanumeric() || ch == '-' || ch == '_' {
+                ch
+            } else {
+                '_'
+            }
+        })
+        .collect()
+}
+
+fn read_json<T: DeserializeOwned>(path: &Path) -> Result<Option<T>> {
+    if !path.exists() {
+        return Ok(None);
+    }
+
+    let raw = fs::read_to_string(path).with_context(|| {
+        format!(
+            "Dark Chat // Cache // Failed to read cache file (path={})",
+            path.display()
+        )
+    })?;
+    let decoded = serde_json::from_str(&raw).with_context(|| {
+        format!(
+            "Dark Chat // Cache // Failed to decode cache file (path={})",
+            path.display()
+        )
+    })?;
+    Ok(Some(decoded))
+}
+
+/// Writes through a sibling temp file so a crash mid-write never leaves a truncated cache.
+fn write_json<T: Serialize>(path: &Path, value: &T) -> Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).with_context(|| {
+            format!(
+                "Dark Chat // Cache // Failed to create directory (path={})",
+                parent.display()
+            )
+        })?;
+    }
+
+    let encoded =
+        serde_json::to_vec(value).context("Dark Chat // Cache // Failed to encode cache")?;
+    let temp_path = path.with_extension("json.tmp");
+    fs::write(&temp_path, encoded).with_context(|| {
+        format!(
+            "Dark Chat // Cache // Failed to write cache file (path={})",
+            temp_path.display()
+        )
+    })?;
+    fs::rename(&temp_path, path).with_context(|| {
+        format!(
+            "Dark Chat // Cache // Failed to replace cache file (path={})",
+            path.display()
+        )
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use std::time::{SystemTime, UNIX_EPOCH};
+
+    use super::ChatCache;
+    use crate::core::backend::ChatSnapshot;
+    use crate::core::types::{ChatMessage, ChatSession, ProviderHealth, ProviderRuntimeStatus};
+
+    fn snapshot(active: &str, sessions: &[&str]) -> ChatSnapshot {
+        ChatSnapshot {
+            health: ProviderHealth {
+                healthy: true,
+                version: Some("1.2.3".to_string()),
+            },
+            sessions: sessions
+                .iter()
+                .map(|id| ChatSession {
+                    id: id.to_string(),
+                    title: format!("title {id}"),
+                    ..ChatSession::default()
+                })
+                .collect(),
+            active_session_id: Some(active.to_string()),
+            messages: vec![ChatMessage {
+                id: format!("msg_{active}"),
+                role: "user".to_string(),
+                text: "hello".to_string(),
+                ..ChatMessage::default()
+            }],
+            agents: vec!["build".to_string()],
+            models: vec!["openai/gpt-5".to_string()],
+            runtime_status: ProviderRuntimeStatus::default(),
+        }
+    }
+
+    #[test]
+    fn store_and_load_round_trip_per_session() {
+        let nanos = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_nanos();
+        let directory = std::env::temp_dir().join(format!("dark_chat_cache_{nanos}"));
+        let cache = ChatCache::new(&directory.to_string_lossy(), "opencode/server");
+        assert!(cache.root().ends_with("opencode_server"));
+        assert!(cache.load(None).expect("empty cache loads").is_none());
+
+        cache
+            .store(&snapshot("ses_a", &["ses_a", "ses_b"]))
+            .expect("store a");
+        cache
+            .store(&snapshot("ses_b", &["ses_a", "ses_b"]))
+            .expect("store b");
+
+        let loaded = cache
+            .load(Some("ses_a"))
+            .expect("load")
+            .expect("cached snapshot");
+        assert!(!loaded.health.healthy);
+        assert_eq!(loaded.sessions.len(), 2);
+        assert_eq!(loaded.messages[0].id, "msg_ses_a");
+        assert_eq!(loaded.models, vec!["openai/gpt-5".to_string()]);
+
+        let fallback = cache.load(Some("missing")).expect("load").expect("cached");
+        assert_eq!(fallback.active_session_id.as_deref(), Some("ses_b"));
+
+        cache.store(&snapshot("ses_b", &["ses_b"])).expect("prune");
+        assert!(cache.load_messages("ses_a").expect("load").is_empty());
+
+        let _ = fs::remove_dir_all(directory);
+    }
+}
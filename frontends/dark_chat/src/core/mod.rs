@@ -1,8 +1,15 @@
+mod attachment;
 mod backend;
+mod cache;
 mod systems;
 mod types;
 
+pub use attachment::{
+    ChatAttachment, MAX_ATTACHMENT_BYTES, MAX_ATTACHMENTS, MAX_TOTAL_ATTACHMENT_BYTES,
+    ensure_attachment_fits, is_attachment_path, mime_for_path,
+};
 pub use backend::{ChatBackend, ChatSnapshot};
+pub use cache::ChatCache;
 pub use systems::default_session_title;
 pub use types::{
     ChatMessage, ChatRealtimeEvent, ChatSession, ProviderHealth, ProviderRuntimeStatus,
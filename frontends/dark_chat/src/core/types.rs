@@ -1,10 +1,14 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
 #[derive(Debug, Clone, Default)]
 pub struct ProviderHealth {
     pub healthy: bool,
     pub version: Option<String>,
 }
 
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[serde(default)]
 pub struct ChatSession {
     pub id: String,
     pub title: String,
@@ -14,12 +18,15 @@ pub struct ChatSession {
     pub updated_unix: Option<i64>,
 }
 
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[serde(default)]
 pub struct ChatMessage {
     pub id: String,
     pub role: String,
     pub text: String,
     pub created_at: Option<String>,
+    /// Provider payload for the message (`{info, parts}` for OpenCode).
+    pub raw: Value,
 }
 
 #[derive(Debug, Clone, Default)]
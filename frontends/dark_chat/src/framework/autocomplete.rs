@@ -25,6 +25,9 @@ pub const DEFAULT_SLASH_COMMANDS: &[(&str, &str)] = &[
     ("agent", "set agent"),
     ("model", "set model"),
     ("grep", "search workspace"),
+    ("export", "export transcript"),
+    ("search", "search messages"),
+    ("queue", "queue prompt"),
 ];
 
 #[derive(Debug, Clone)]
@@ -44,6 +44,7 @@ pub struct ConversationComposer<'a> {
     pub cursor_index: usize,
     pub idle_hint: &'a str,
     pub disabled_hint: &'a str,
+    pub attachments: &'a [String],
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -190,6 +191,7 @@ pub fn render_conversation_panel(
                 cursor_index: props.composer.cursor_index,
                 idle_hint: props.composer.idle_hint,
                 disabled_hint: props.composer.disabled_hint,
+                attachments: props.composer.attachments,
             },
         );
     } else {
@@ -204,6 +206,7 @@ pub fn render_conversation_panel(
                 cursor_index: props.composer.cursor_index,
                 idle_hint: props.composer.idle_hint,
                 disabled_hint: props.composer.disabled_hint,
+                attachments: props.composer.attachments,
             },
         );
     }
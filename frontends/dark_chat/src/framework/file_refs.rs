@@ -0,0 +1,126 @@
+use std::collections::BTreeSet;
+use std::path::{Path, PathBuf};
+
+use anyhow::Result;
+
+use crate::core::{ChatAttachment, ensure_attachment_fits, is_attachment_path};
+
+/// Loads `@file` references that point at images, PDFs or other binaries as
+/// attachments, on top of the ones already in the composer.
+pub fn collect_reference_attachments(
+    directory: &str,
+    prompt: &str,
+    existing: &[ChatAttachment],
+) -> Result<Vec<ChatAttachment>> {
+    let mut attachments = existing.to_vec();
+    for file_ref in extract_file_references(prompt) {
+        let Some(path) = resolve_reference_path(directory, &file_ref) else {
+            continue;
+        };
+        if !is_attachment_path(&path) {
+            continue;
+        }
+
+        let attachment = ChatAttachment::from_path(&path)?;
+        ensure_attachment_fits(&attachments, &attachment)?;
+        attachments.push(attachment);
+    }
+
+    Ok(attachments)
+}
+
+/// Resolves a typed or pasted path (quoted, `file://`, `~/`, or relative to the
+/// workspace) to an existing file. Unlike `@file` refs this may point outside the
+/// workspace, e.g. at a screenshot folder.
+pub fn resolve_attachment_path(directory: &str, raw: &str) -> Option<PathBuf> {
+    let trimmed = raw.trim().trim_matches(|ch| ch == '\'' || ch == '"');
+    let trimmed = trimmed.strip_prefix("file://").unwrap_or(trimmed);
+    if trimmed.is_empty() || trimmed.contains('\n') {
+        return None;
+    }
+
+    let unescaped = trimmed.replace("%20", " ").replace("\\ ", " ");
+    let candidate = match unescaped.strip_prefix("~/") {
+        Some(rest) => PathBuf::from(std::env::var_os("HOME")?).join(rest),
+        None => PathBuf::from(&unescaped),
+    };
+    let resolved = if candidate.is_absolute() {
+        candidate
+    } else {
+        Path::new(directory).join(candidate)
+    };
+
+    resolved.is_file().then_some(resolved)
+}
+
+pub(crate) fn extract_file_references(prompt: &str) -> Vec<String> {
+    let mut refs = BTreeSet::<String>::new();
+
+    for token in prompt.split_whitespace() {
+        let trimmed = token.trim_matches(|char: char| {
+            matches!(
+                char,
+                ',' | '.' | ';' | ':' | ')' | '(' | '[' | ']' | '{' | '}' | '"' | '\''
+            )
+        });
+
+        if !trimmed.starts_with('@') {
+            continue;
+        }
+
+        let path = trimmed.trim_start_matches('@').trim();
+        if path.is_empty() {
+            continue;
+        }
+
+        refs.insert(path.to_string());
+    }
+
+    refs.into_iter().collect()
+}
+
+pub(crate) fn resolve_reference_path(directory: &str, reference: &str) -> Option<PathBuf> {
+    let candidate = PathBuf::from(reference);
+    let resolved = if candidate.is_absolute() {
+        candidate
+    } else {
+        Path::new(directory).join(candidate)
+    };
+
+    let canonical = resolved.canonicalize().ok()?;
+    let workspace = Path::new(directory).canonicalize().ok()?;
+
+    if !canonical.starts_with(&workspace) {
+        return None;
+    }
+
+    if !canonical.is_file() {
+        return None;
+    }
+
+    Some(canonical)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{extract_file_references, resolve_attachment_path};
+
+    #[test]
+    fn resolves_pasted_paths_relative_to_workspace() {
+        let directory = env!("CARGO_MANIFEST_DIR");
+        assert!(resolve_attachment_path(directory, "'Cargo.toml'").is_some());
+        assert!(
+            resolve_attachment_path(directory, &format!("file://{directory}/Cargo.toml")).is_some()
+        );
+        assert!(resolve_attachment_path(directory, "missing.png").is_none());
+        assert!(resolve_attachment_path(directory, "src").is_none());
+    }
+
+    #[test]
+    fn extracts_unique_file_references() {
+        assert_eq!(
+            extract_file_references("see @shots/error.png, and (@notes.md) @shots/error.png"),
+            vec!["notes.md".to_string(), "shots/error.png".to_string()]
+        );
+    }
+}
@@ -0,0 +1,422 @@
+use std::collections::HashMap;
+
+/// Message text handed to [`MessageIndex::index_session`].
+#[derive(Debug, Clone, Default)]
+pub struct IndexedMessage {
+    pub message_id: String,
+    pub role: String,
+    pub created_at: Option<String>,
+    pub text: String,
+}
+
+/// Ranked search result with a one-line snippet around the first match.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MessageSearchHit {
+    pub session_id: String,
+    pub session_title: String,
+    pub message_id: String,
+    pub role: String,
+    pub created_at: Option<String>,
+    pub snippet: String,
+    pub score: f64,
+}
+
+#[derive(Debug, Clone)]
+struct IndexedDocument {
+    session_id: String,
+    message: IndexedMessage,
+}
+
+#[derive(Debug, Clone, Default)]
+struct IndexedSession {
+    title: String,
+    version: Option<i64>,
+    documents: Vec<usize>,
+}
+
+/// In-memory inverted index over chat messages, updated one session at a time.
+///
+/// Sessions carry a version (typically the provider's `updated` timestamp) so callers
+/// only re-fetch and re-index sessions that changed since the last pass.
+#[derive(Debug, Clone, Default)]
+pub struct MessageIndex {
+    documents: Vec<Option<IndexedDocument>>,
+    postings: HashMap<String, HashMap<usize, u32>>,
+    sessions: HashMap<String, IndexedSession>,
+}
+
+const SNIPPET_CONTEXT_BEFORE: usize = 32;
+const SNIPPET_MAX_CHARS: usize = 120;
+
+impl MessageIndex {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn session_count(&self) -> usize {
+        self.sessions.len()
+    }
+
+    pub fn message_count(&self) -> usize {
+        self.sessions
+            .values()
+            .map(|session| session.documents.len())
+            .sum()
+    }
+
+    /// True when the session is unknown or its version moved since it was indexed.
+    /// Sessions without a version are indexed once.
+    pub fn needs_update(&self, session_id: &str, version: Option<i64>) -> bool {
+        self.sessions
+            .get(session_id)
+            .is_none_or(|session| session.version != version)
+    }
+
+    /// Replaces everything indexed for `session_id` with `messages`.
+    pub fn index_session(
+        &mut self,
+        session_id: &str,
+        title: &str,
+        version: Option<i64>,
+        messages: Vec<IndexedMessage>,
+    ) {
+        self.remove_session(session_id);
+        self.compact();
+
+        let mut documents = Vec::with_capacity(messages.len());
+        for message in messages {
+            let document_id = self.documents.len();
+            for (token, count) in token_counts(&message.text) {
+                self.postings
+                    .entry(token)
+                    .or_default()
+                    .insert(document_id, count);
+            }
+            self.documents.push(Some(IndexedDocument {
+                session_id: session_id.to_string(),
+                message,
+            }));
+            documents.push(document_id);
+        }
+
+        self.sessions.insert(
+            session_id.to_string(),
+            IndexedSession {
+                title: title.to_string(),
+                version,
+                documents,
+            },
+        );
+    }
+
+    /// Drops a session and its messages from the index.
+    pub fn remove_session(&mut self, session_id: &str) {
+        let Some(session) = self.sessions.remove(session_id) else {
+            return;
+        };
+
+        for document_id in session.documents {
+            let Some(document) = self.documents.get_mut(document_id).and_then(Option::take) else {
+                continue;
+            };
+            for (token, _) in token_counts(&document.message.text) {
+                if let Some(posting) = self.postings.get_mut(&token) {
+                    posting.remove(&document_id);
+                    if posting.is_empty() {
+                        self.postings.remove(&token);
+                    }
+                }
+            }
+        }
+    }
+
+    /// Drops the slots left behind by removed sessions once they outnumber live
+    /// messages, renumbering postings and sessions to the packed ids.
+    fn compact(&mut self) {
+        let live = self.message_count();
+        if self.documents.len() - live <= live {
+            return;
+        }
+
+        let mut remap = HashMap::with_capacity(live);
+        let mut documents = Vec::with_capacity(live);
+        for (old_id, document) in std::mem::take(&mut self.documents).into_iter().enumerate() {
+            if let Some(document) = document {
+                remap.insert(old_id, documents.len());
+                documents.push(Some(document));
+            }
+        }
+        self.documents = documents;
+
+        for posting in self.postings.values_mut() {
+            *posting = posting
+                .drain()
+                .filter_map(|(document_id, count)| Some((*remap.get(&document_id)?, count)))
+                .collect();
+        }
+        for session in self.sessions.values_mut() {
+            for document_id in &mut session.documents {
+                *document_id = remap[document_id];
+            }
+        }
+    }
+
+    /// Removes sessions that are no longer present upstream.
+    pub fn retain_sessions(&mut self, live_session_ids: &[&str]) {
+        let stale = self
+            .sessions
+            .keys()
+            .filter(|id| !live_session_ids.contains(&id.as_str()))
+            .cloned()
+            .collect::<Vec<_>>();
+        for session_id in stale {
+            self.remove_session(&session_id);
+        }
+    }
+
+    /// Ranks messages containing every query term. The last term matches as a prefix
+    /// so results update while typing; a verbatim phrase match gets a boost.
+    pub fn search(&self, query: &str, limit: usize) -> Vec<MessageSearchHit> {
+        let terms = tokenize(query);
+        if terms.is_empty() || limit == 0 {
+            return Vec::new();
+        }
+
+        let total_documents = self.message_count().max(1) as f64;
+        let mut scores: HashMap<usize, f64> = HashMap::new();
+
+        for (term_index, term) in terms.iter().enumerate() {
+            let is_last = term_index + 1 == terms.len();
+            let mut term_scores: HashMap<usize, f64> = HashMap::new();
+
+            for (token, posting) in &self.postings {
+                let matches = if is_last {
+                    token.starts_with(term.as_str())
+                } else {
+                    token == term
+                };
+                if !matches {
+                    continue;
+                }
+
+                let idf = (1.0 + total_documents / posting.len() as f64).ln();
+                for (document_id, count) in posting {
+                    let weight = (1.0 + f64::from(*count).ln()) * idf;
+                    let entry = term_scores.entry(*document_id).or_default();
+                    *entry = entry.max(weight);
+                }
+            }
+
+            if term_index == 0 {
+                scores = term_scores;
+            } else {
+                scores.retain(|document_id, _| term_scores.contains_key(document_id));
+                for (document_id, score) in scores.iter_mut() {
+                    *score += term_scores[document_id];
+                }
+            }
+
+            if scores.is_empty() {
+                return Vec::new();
+            }
+        }
+
+        let phrase = query.trim().to_ascii_lowercase();
+        let mut hits = scores
+            .into_iter()
+            .filter_map(|(document_id, score)| {
+                let document = self.documents.get(document_id)?.as_ref()?;
+                let session = self.sessions.get(&document.session_id)?;
+                let lowered = document.message.text.to_ascii_lowercase();
+                let score = if terms.len() > 1 && lowered.contains(&phrase) {
+                    score * 1.5
+                } else {
+                    score
+                };
+
+                Some(MessageSearchHit {
+                    session_id: document.session_id.clone(),
+                    session_title: session.title.clone(),
+                    message_id: document.message.message_id.clone(),
+                    role: document.message.role.clone(),
+                    created_at: document.message.created_at.clone(),
+                    snippet: build_snippet(&document.message.text, &terms),
+                    score,
+                })
+            })
+            .collect::<Vec<_>>();
+
+        hits.sort_by(|left, right| {
+            right
+                .score
+                .total_cmp(&left.score)
+                .then_with(|| right.created_at.cmp(&left.created_at))
+        });
+        hits.truncate(limit);
+        hits
+    }
+}
+
+fn tokenize(text: &str) -> Vec<String> {
+    text.split(|ch: char| !ch.is_alphanumeric() && ch != '_')
+        .filter(|token| token.chars().count() >= 2)
+        .map(str::to_lowercase)
+        .collect()
+}
+
+fn token_counts(text: &str) -> HashMap<String, u32> {
+    let mut counts = HashMap::new();
+    for token in tokenize(text) {
+        *counts.entry(token).or_default() += 1;
+    }
+    counts
+}
+
+/// Collapses whitespace and cuts a window around the earliest term occurrence.
+fn build_snippet(text: &str, terms: &[String]) -> String {
+    let flattened = text.split_whitespace().collect::<Vec<_>>().join(" ");
+    let lowered = flattened.to_ascii_lowercase();
+    let match_byte = terms
+        .iter()
+        .filter_map(|term| lowered.find(term.as_str()))
+        .min()
+        .unwrap_or(0);
+
+    let match_char = flattened[..match_byte].chars().count();
+    let start_char = match_char.saturating_sub(SNIPPET_CONTEXT_BEFORE);
+    let total_chars = flattened.chars().count();
+
+    let mut snippet = flattened
+        .chars()
+        .skip(start_char)
+        .take(SNIPPET_MAX_CHARS)
+        .collect::<String>();
+    if start_char > 0 {
+        snippet.insert(0, '…');
+    }
+    if start_char + SNIPPET_MAX_CHARS < total_chars {
+        snippet.push('…');
+    }
+    snippet
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{IndexedMessage, MessageIndex};
+
+    fn message(id: &str, text: &str) -> IndexedMessage {
+        IndexedMessage {
+            message_id: id.to_string(),
+            role: "assistant".to_string(),
+            created_at: None,
+            text: text.to_string(),
+        }
+    }
+
+    fn sample_index() -> MessageIndex {
+        let mut index = MessageIndex::new();
+        index.index_session(
+            "ses_a",
+            "Flaky tests",
+            Some(10),
+            vec![
+                message("m1", "Fixed the flaky websocket reconnect test."),
+                message("m2", "Unrelated chatter about lunch."),
+            ],
+        );
+        index.index_session(
+            "ses_b",
+            "Websocket refactor",
+            Some(20),
+            vec![message(
+                "m3",
+                "Websocket websocket websocket transport rewrite.",
+            )],
+        );
+        index
+    }
+
+    #[test]
+    fn requires_all_terms_and_ranks_by_frequency() {
+        let index = sample_index();
+
+        let hits = index.search("websocket", 10);
+        assert_eq!(hits.len(), 2);
+        assert_eq!(hits[0].message_id, "m3");
+
+        let hits = index.search("flaky websocket", 10);
+        assert_eq!(hits.len(), 1);
+        assert_eq!(hits[0].session_title, "Flaky tests");
+    }
+
+    #[test]
+    fn last_term_matches_as_prefix() {
+        let index = sample_index();
+        let hits = index.search("reconn", 10);
+        assert_eq!(hits.len(), 1);
+        assert_eq!(hits[0].message_id, "m1");
+    }
+
+    #[test]
+    fn reindexing_a_session_replaces_its_messages() {
+        let mut index = sample_index();
+        assert!(!index.needs_update("ses_a", Some(10)));
+        assert!(index.needs_update("ses_a", Some(11)));
+
+        index.index_session(
+            "ses_a",
+            "Flaky tests",
+            Some(11),
+            vec![message("m4", "lunch")],
+        );
+
+        assert!(index.search("flaky", 10).is_empty());
+        assert_eq!(index.search("lunch", 10)[0].message_id, "m4");
+        assert_eq!(index.message_count(), 2);
+
+        index.retain_sessions(&["ses_b"]);
+        assert_eq!(index.session_count(), 1);
+        assert!(index.search("lunch", 10).is_empty());
+    }
+
+    #[test]
+    fn sessions_without_version_are_indexed_once() {
+        let mut index = MessageIndex::new();
+        assert!(index.needs_update("ses", None));
+
+        index.index_session("ses", "Unversioned", None, vec![message("m", "hello")]);
+        assert!(!index.needs_update("ses", None));
+        assert!(index.needs_update("ses", Some(1)));
+    }
+
+    #[test]
+    fn reindexing_compacts_removed_documents() {
+        let mut index = sample_index();
+        for version in 11..20 {
+            index.index_session(
+                "ses_a",
+                "Flaky tests",
+                Some(version),
+                vec![message("m1", "flaky websocket")],
+            );
+        }
+
+        assert!(index.documents.len() <= 2 * index.message_count());
+        let hits = index.search("websocket", 10);
+        assert_eq!(hits.len(), 2);
+        assert_eq!(hits[0].message_id, "m3");
+        assert_eq!(index.search("flaky", 10)[0].message_id, "m1");
+    }
+
+    #[test]
+    fn snippet_centers_on_first_match() {
+        let mut index = MessageIndex::new();
+        let long_text = format!("{} needle {}", "lead ".repeat(30), "tail ".repeat(40));
+        index.index_session("ses", "Long", None, vec![message("m", &long_text)]);
+
+        let snippet = &index.search("needle", 1)[0].snippet;
+        assert!(snippet.starts_with('…'));
+        assert!(snippet.ends_with('…'));
+        assert!(snippet.contains("needle"));
+    }
+}
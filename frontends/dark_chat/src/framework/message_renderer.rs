@@ -16,7 +16,7 @@ pub fn extract_message_text(parts: &[Value]) -> String {
     }
 }
 
-fn format_message_part(part: &Value) -> Option<String> {
+pub(crate) fn format_message_part(part: &Value) -> Option<String> {
     let map = part.as_object()?;
     let part_type = map
         .get("type")
@@ -73,16 +73,44 @@ fn format_thinking(content: &str) -> String {
     format!("### Thinking\n> {}", block.replace('\n', "\n> "))
 }
 
+/// Returns the tool name when `part` is a tool call part.
+pub(crate) fn tool_part_name(part: &Value) -> Option<&str> {
+    let map = part.as_object()?;
+    let part_type = map
+        .get("type")
+        .and_then(Value::as_str)
+        .map(|value| value.trim().to_ascii_lowercase())?;
+    if !matches!(part_type.as_str(), "tool" | "tool_call" | "toolcall") {
+        return None;
+    }
+
+    Some(tool_name(map))
+}
+
+/// Renders a tool call part without its `### Tool // name` heading.
+pub(crate) fn format_tool_body(part: &Value) -> Option<String> {
+    let map = part.as_object()?;
+    Some(tool_sections(map).join("\n\n"))
+}
+
 fn format_tool_call(map: &serde_json::Map<String, Value>) -> String {
-    let name = map
-        .get("tool")
+    let mut sections = vec![format!("### Tool // {}", tool_name(map))];
+    sections.extend(tool_sections(map));
+    sections.join("\n\n")
+}
+
+fn tool_name(map: &Map<String, Value>) -> &str {
+    map.get("tool")
         .or_else(|| map.get("name"))
         .and_then(Value::as_str)
-        .unwrap_or("tool");
+        .unwrap_or("tool")
+}
 
+fn tool_sections(map: &Map<String, Value>) -> Vec<String> {
+    let name = tool_name(map);
     let input = tool_input_value(map);
     let output = tool_output_value(map);
-    let mut sections = vec![format!("### Tool // {name}")];
+    let mut sections = Vec::new();
 
     // For todowrite, produce a compact semantic summary instead of raw JSON.
     if name.eq_ignore_ascii_case("todowrite") {
@@ -118,7 +146,7 @@ fn format_tool_call(map: &serde_json::Map<String, Value>) -> String {
             sections.push(format!("#### TODOS\n{}", lines.join("\n")));
         }
 
-        return sections.join("\n\n");
+        return sections;
     }
 
     // Task/sub-agent calls: render metadata + compact result preview.
@@ -135,7 +163,7 @@ fn format_tool_call(map: &serde_json::Map<String, Value>) -> String {
             sections.push(format!("#### OUT\n{output_block}"));
         }
 
-        return sections.join("\n\n");
+        return sections;
     }
 
     if let Some(summary) = tool_summary_line(name, input.as_ref(), output.as_ref(), Some(map)) {
@@ -152,7 +180,7 @@ fn format_tool_call(map: &serde_json::Map<String, Value>) -> String {
         sections.push(format!("#### OUT\n```json\n{body}\n```"));
     }
 
-    sections.join("\n\n")
+    sections
 }
 
 fn tool_summary_line(
@@ -1,10 +1,14 @@
 mod autocomplete;
 mod composer;
 mod conversation_panel;
+mod file_refs;
+mod message_index;
 mod message_renderer;
 mod message_types;
 mod model_selector;
+mod prompt_queue;
 mod session_tree;
+mod transcript;
 
 pub use autocomplete::{
     AutocompleteItem, AutocompleteMode, ChatAutocomplete, DEFAULT_SLASH_COMMANDS,
@@ -15,7 +19,16 @@ pub use conversation_panel::{
     ConversationPanelProps, ConversationStatusTone, render_conversation_panel,
     status_tone_for_status,
 };
+pub use file_refs::{collect_reference_attachments, resolve_attachment_path};
+pub(crate) use file_refs::{extract_file_references, resolve_reference_path};
+pub use message_index::{IndexedMessage, MessageIndex, MessageSearchHit};
 pub use message_renderer::extract_message_text;
 pub use message_types::{AgentMessage, AgentMessagePart, AgentMessageRole};
 pub use model_selector::{ItemSelector, SelectorKind};
+pub use prompt_queue::{
+    PromptQueue, PromptQueuePanel, QueuedPrompt, now_unix, parse_queue_directive,
+};
 pub use session_tree::{SessionLike, SessionTreeRow, tree_prefix, walk_session_tree};
+pub use transcript::{
+    Transcript, TranscriptFormat, TranscriptMessage, resolve_transcript_path, write_transcript,
+};
@@ -0,0 +1,636 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use anyhow::{Context, Result};
+use dark_tui_components::{next_index, previous_index};
+use serde::{Deserialize, Serialize};
+
+/// Seconds the queue holds automatic sends after a queued prompt failed to send.
+pub const QUEUE_RETRY_SECONDS: u64 = 30;
+
+/// Prompt waiting for its target (a session or actor) to become idle.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct QueuedPrompt {
+    pub id: String,
+    pub target: String,
+    pub prompt: String,
+    #[serde(default)]
+    pub model: Option<String>,
+    #[serde(default)]
+    pub agent: Option<String>,
+    pub queued_at_unix: u64,
+    /// Earliest time the prompt may be sent; `None` sends as soon as the target is idle.
+    #[serde(default)]
+    pub send_after_unix: Option<u64>,
+}
+
+impl QueuedPrompt {
+    pub fn is_due(&self, now_unix: u64) -> bool {
+        self.send_after_unix.is_none_or(|at| at <= now_unix)
+    }
+
+    /// Short schedule label for queue listings (`ready`, `in 25m`, ...).
+    pub fn schedule_label(&self, now_unix: u64) -> String {
+        match self.send_after_unix {
+            Some(at) if at > now_unix => format!("in {}", format_delay(at - now_unix)),
+            _ => "ready".to_string(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+struct PersistedQueue {
+    #[serde(default)]
+    next_id: u64,
+    #[serde(default)]
+    prompts: Vec<QueuedPrompt>,
+}
+
+/// Ordered prompt queue shared by every target, persisted as JSON so queued
+/// follow-ups survive restarts. Order is global; per-target views keep the
+/// relative order of that target's entries.
+#[derive(Debug, Clone, Default)]
+pub struct PromptQueue {
+    path: Option<PathBuf>,
+    next_id: u64,
+    prompts: Vec<QueuedPrompt>,
+}
+
+impl PromptQueue {
+    /// Loads the queue stored at `path`, starting empty when the file does not exist.
+    pub fn load(path: impl Into<PathBuf>) -> Result<Self> {
+        let path = path.into();
+        let persisted = if path.exists() {
+            let raw = fs::read_to_string(&path).with_context(|| {
+                format!(
+                    "Dark Chat // Queue // Failed to read prompt queue (path={})",
+                    path.display()
+                )
+            })?;
+            serde_json::from_str::<PersistedQueue>(&raw).with_context(|| {
+                format!(
+                    "Dark Chat // Queue // Failed to decode prompt queue (path={})",
+                    path.display()
+                )
+            })?
+        } else {
+            PersistedQueue::default()
+        };
+
+        Ok(Self {
+            path: Some(path),
+            next_id: persisted.next_id,
+            prompts: persisted.prompts,
+        })
+    }
+
+    pub fn path(&self) -> Option<&Path> {
+        self.path.as_deref()
+    }
+
+    pub fn save(&self) -> Result<()> {
+        let Some(path) = self.path.as_deref() else {
+            return Ok(());
+        };
+
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).with_context(|| {
+                format!(
+                    "Dark Chat // Queue // Failed to create directory (path={})",
+                    parent.display()
+                )
+            })?;
+        }
+
+        let encoded = serde_json::to_string_pretty(&PersistedQueue {
+            next_id: self.next_id,
+            prompts: self.prompts.clone(),
+        })
+        .context("Dark Chat // Queue // Failed to encode prompt queue")?;
+        fs::write(path, encoded).with_context(|| {
+            format!(
+                "Dark Chat // Queue // Failed to write prompt queue (path={})",
+                path.display()
+            )
+        })
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.prompts.is_empty()
+    }
+
+    pub fn items(&self) -> &[QueuedPrompt] {
+        &self.prompts
+    }
+
+    pub fn items_for<'a>(&'a self, target: &'a str) -> impl Iterator<Item = &'a QueuedPrompt> {
+        self.prompts
+            .iter()
+            .filter(move |prompt| prompt.target == target)
+    }
+
+    pub fn count_for(&self, target: &str) -> usize {
+        self.items_for(target).count()
+    }
+
+    pub fn get(&self, id: &str) -> Option<&QueuedPrompt> {
+        self.prompts.iter().find(|prompt| prompt.id == id)
+    }
+
+    /// Appends a prompt for `target` and returns its id.
+    pub fn push(
+        &mut self,
+        target: &str,
+        prompt: &str,
+        model: Option<&str>,
+        agent: Option<&str>,
+        send_after_unix: Option<u64>,
+    ) -> String {
+        self.next_id += 1;
+        let id = format!("q{}", self.next_id);
+        self.prompts.push(QueuedPrompt {
+            id: id.clone(),
+            target: target.to_string(),
+            prompt: prompt.to_string(),
+            model: model.map(ToString::to_string),
+            agent: agent.map(ToString::to_string),
+            queued_at_unix: now_unix(),
+            send_after_unix,
+        });
+        id
+    }
+
+    /// Puts a prompt back at the head of the queue, e.g. after its send failed.
+    pub fn push_front(&mut self, prompt: QueuedPrompt) {
+        self.prompts.insert(0, prompt);
+    }
+
+    pub fn remove(&mut self, id: &str) -> Option<QueuedPrompt> {
+        let index = self.prompts.iter().position(|prompt| prompt.id == id)?;
+        Some(self.prompts.remove(index))
+    }
+
+    pub fn update_prompt(&mut self, id: &str, prompt: &str) -> bool {
+        match self.prompts.iter_mut().find(|entry| entry.id == id) {
+            Some(entry) => {
+                entry.prompt = prompt.to_string();
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Swaps the prompt with the previous entry for the same target.
+    pub fn move_up(&mut self, id: &str) -> bool {
+        let Some(index) = self.prompts.iter().position(|prompt| prompt.id == id) else {
+            return false;
+        };
+        let target = self.prompts[index].target.clone();
+        let Some(previous) = self.prompts[..index]
+            .iter()
+            .rposition(|prompt| prompt.target == target)
+        else {
+            return false;
+        };
+
+        self.prompts.swap(previous, index);
+        true
+    }
+
+    /// Swaps the prompt with the next entry for the same target.
+    pub fn move_down(&mut self, id: &str) -> bool {
+        let Some(index) = self.prompts.iter().position(|prompt| prompt.id == id) else {
+            return false;
+        };
+        let target = self.prompts[index].target.clone();
+        let Some(offset) = self.prompts[index + 1..]
+            .iter()
+            .position(|prompt| prompt.target == target)
+        else {
+            return false;
+        };
+
+        self.prompts.swap(index, index + 1 + offset);
+        true
+    }
+
+    /// First due prompt whose target passes `is_ready`. Scheduled prompts that are not
+    /// due yet do not hold back later entries for the same target.
+    pub fn next_due(
+        &self,
+        now_unix: u64,
+        is_ready: impl Fn(&str) -> bool,
+    ) -> Option<&QueuedPrompt> {
+        self.prompts
+            .iter()
+            .find(|prompt| prompt.is_due(now_unix) && is_ready(&prompt.target))
+    }
+}
+
+/// Prompt queue plus the queue panel state both chat frontends share: the selected
+/// row and the queued prompt being edited in the composer. Per-target calls take the
+/// session or actor the panel is showing.
+#[derive(Debug, Clone, Default)]
+pub struct PromptQueuePanel {
+    queue: PromptQueue,
+    selected: usize,
+    editing: Option<String>,
+    retry_after_unix: Option<u64>,
+}
+
+impl PromptQueuePanel {
+    /// Loads the queue stored at `path`. When the file cannot be read the panel starts
+    /// empty and in memory only, leaving the file untouched, and the error is returned
+    /// for the caller to report.
+    pub fn load(path: impl Into<PathBuf>) -> (Self, Option<anyhow::Error>) {
+        match PromptQueue::load(path) {
+            Ok(queue) => (Self::new(queue), None),
+            Err(error) => (Self::default(), Some(error)),
+        }
+    }
+
+    pub fn new(queue: PromptQueue) -> Self {
+        Self {
+            queue,
+            ..Self::default()
+        }
+    }
+
+    /// Queued prompts for `target`, in send order.
+    pub fn items_for(&self, target: Option<&str>) -> Vec<&QueuedPrompt> {
+        match target {
+            Some(target) => self
+                .queue
+                .items()
+                .iter()
+                .filter(|prompt| prompt.target == target)
+                .collect(),
+            None => Vec::new(),
+        }
+    }
+
+    /// Queues `prompt` for `target` and persists the queue. Returns the prompt's
+    /// 1-based position in that target's queue.
+    pub fn enqueue(
+        &mut self,
+        target: &str,
+        prompt: &str,
+        model: Option<&str>,
+        agent: Option<&str>,
+        delay_seconds: Option<u64>,
+    ) -> Result<usize> {
+        self.queue.push(
+            target,
+            prompt,
+            model,
+            agent,
+            delay_seconds.map(|delay| now_unix() + delay),
+        );
+        self.queue.save()?;
+        Ok(self.queue.count_for(target))
+    }
+
+    /// Pops the next due prompt whose target passes `is_ready`. The queue is persisted
+    /// before the prompt is handed back so a crash never sends it twice; hand it to
+    /// [`Self::requeue_failed`] when the send fails.
+    pub fn take_due(
+        &mut self,
+        shown_target: Option<&str>,
+        is_ready: impl Fn(&str) -> bool,
+    ) -> Result<Option<QueuedPrompt>> {
+        let now = now_unix();
+        if self.retry_after_unix.is_some_and(|at| now < at) {
+            return Ok(None);
+        }
+        self.retry_after_unix = None;
+
+        let Some(id) = self
+            .queue
+            .next_due(now, is_ready)
+            .map(|prompt| prompt.id.clone())
+        else {
+            return Ok(None);
+        };
+
+        let taken = self.queue.remove(&id);
+        self.clamp_selection(shown_target);
+        self.queue.save()?;
+        Ok(taken)
+    }
+
+    /// Puts a prompt whose send failed back at the head of the queue and holds
+    /// automatic sends for [`QUEUE_RETRY_SECONDS`] so a failing target is not retried
+    /// in a tight loop.
+    pub fn requeue_failed(&mut self, prompt: QueuedPrompt) -> Result<()> {
+        self.queue.push_front(prompt);
+        self.retry_after_unix = Some(now_unix() + QUEUE_RETRY_SECONDS);
+        self.queue.save()
+    }
+
+    pub fn selected(&self) -> usize {
+        self.selected
+    }
+
+    /// Selects the first row, as the panel opens or closes.
+    pub fn reset_selection(&mut self) {
+        self.selected = 0;
+    }
+
+    pub fn select_previous(&mut self, target: Option<&str>) {
+        self.selected = previous_index(self.selected, self.items_for(target).len());
+    }
+
+    pub fn select_next(&mut self, target: Option<&str>) {
+        self.selected = next_index(self.selected, self.items_for(target).len());
+    }
+
+    pub fn set_selected(&mut self, target: Option<&str>, index: usize) {
+        if index < self.items_for(target).len() {
+            self.selected = index;
+        }
+    }
+
+    /// Moves the selected prompt one slot earlier (`up`) or later in the target queue.
+    pub fn reorder_selected(&mut self, target: Option<&str>, up: bool) -> Result<bool> {
+        let Some(id) = self.selected_id(target) else {
+            return Ok(false);
+        };
+
+        let moved = if up {
+            self.queue.move_up(&id)
+        } else {
+            self.queue.move_down(&id)
+        };
+        if moved {
+            if up {
+                self.selected = self.selected.saturating_sub(1);
+            } else {
+                self.selected += 1;
+            }
+            self.queue.save()?;
+        }
+        Ok(moved)
+    }
+
+    pub fn cancel_selected(&mut self, target: Option<&str>) -> Result<Option<QueuedPrompt>> {
+        let Some(id) = self.selected_id(target) else {
+            return Ok(None);
+        };
+
+        let removed = self.queue.remove(&id);
+        if self.editing.as_deref() == Some(id.as_str()) {
+            self.editing = None;
+        }
+        self.clamp_selection(target);
+        self.queue.save()?;
+        Ok(removed)
+    }
+
+    /// Starts editing the selected prompt and resets the selection. Returns its text for
+    /// the composer; the next send goes through [`Self::finish_edit`].
+    pub fn edit_selected(&mut self, target: Option<&str>) -> Option<String> {
+        let id = self.selected_id(target)?;
+        let prompt = self.queue.get(&id)?.prompt.clone();
+        self.reset_selection();
+        self.editing = Some(id);
+        Some(prompt)
+    }
+
+    pub fn is_editing(&self) -> bool {
+        self.editing.is_some()
+    }
+
+    /// Abandons the edit in progress. Returns true when one was in progress.
+    pub fn cancel_edit(&mut self) -> bool {
+        self.editing.take().is_some()
+    }
+
+    /// Stores the edited text of the prompt being edited. Returns false when no edit
+    /// was in progress or the prompt was sent or cancelled meanwhile.
+    pub fn finish_edit(&mut self, prompt: &str) -> Result<bool> {
+        let Some(id) = self.editing.take() else {
+            return Ok(false);
+        };
+
+        let updated = self.queue.update_prompt(&id, prompt);
+        if updated {
+            self.queue.save()?;
+        }
+        Ok(updated)
+    }
+
+    fn selected_id(&self, target: Option<&str>) -> Option<String> {
+        self.items_for(target)
+            .get(self.selected)
+            .map(|prompt| prompt.id.clone())
+    }
+
+    fn clamp_selection(&mut self, target: Option<&str>) {
+        self.selected = self
+            .selected
+            .min(self.items_for(target).len().saturating_sub(1));
+    }
+}
+
+/// Splits `/queue [+delay] <prompt>` input into an optional delay (seconds) and the
+/// prompt text. Delays accept `s`, `m`, `h` and `d` units, combined like `1h30m`.
+pub fn parse_queue_directive(input: &str) -> (Option<u64>, String) {
+    let trimmed = input.trim();
+    if let Some(rest) = trimmed.strip_prefix('+') {
+        let mut parts = rest.splitn(2, char::is_whitespace);
+        let delay = parts.next().and_then(parse_delay);
+        if let Some(delay) = delay {
+            let prompt = parts.next().unwrap_or_default().trim().to_string();
+            return (Some(delay), prompt);
+        }
+    }
+
+    (None, trimmed.to_string())
+}
+
+fn parse_delay(value: &str) -> Option<u64> {
+    let mut total = 0u64;
+    let mut digits = String::new();
+    for ch in value.chars() {
+        if ch.is_ascii_digit() {
+            digits.push(ch);
+            continue;
+        }
+
+        let unit = match ch.to_ascii_lowercase() {
+            's' => 1,
+            'm' => 60,
+            'h' => 3_600,
+            'd' => 86_400,
+            _ => return None,
+        };
+        total = total.checked_add(digits.parse::<u64>().ok()?.checked_mul(unit)?)?;
+        digits.clear();
+    }
+
+    // A bare number means minutes.
+    if !digits.is_empty() {
+        total = total.checked_add(digits.parse::<u64>().ok()?.checked_mul(60)?)?;
+    }
+
+    (total > 0).then_some(total)
+}
+
+fn format_delay(seconds: u64) -> String {
+    if seconds >= 86_400 {
+        format!("{}d{}h", seconds / 86_400, (seconds % 86_400) / 3_600)
+    } else if seconds >= 3_600 {
+        format!("{}h{}m", seconds / 3_600, (seconds % 3_600) / 60)
+    } else if seconds >= 60 {
+        format!("{}m", seconds.div_ceil(60))
+    } else {
+        format!("{seconds}s")
+    }
+}
+
+pub fn now_unix() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use super::{PromptQueue, PromptQueuePanel, parse_queue_directive};
+
+    #[test]
+    fn reorders_within_target_and_skips_other_targets() {
+        let mut queue = PromptQueue::default();
+        let first = queue.push("ses_a", "one", None, None, None);
+        let _other = queue.push("ses_b", "other", None, None, None);
+        let second = queue.push("ses_a", "two", None, None, None);
+
+        assert!(queue.move_up(&second));
+        let order = queue
+            .items_for("ses_a")
+            .map(|prompt| prompt.prompt.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(order, vec!["two", "one"]);
+        assert!(!queue.move_up(&second));
+        assert!(queue.move_down(&second));
+        assert!(!queue.move_down(&second));
+        assert_eq!(queue.count_for("ses_b"), 1);
+
+        assert!(queue.update_prompt(&first, "one (edited)"));
+        assert_eq!(
+            queue.remove(&first).map(|prompt| prompt.prompt),
+            Some("one (edited)".to_string())
+        );
+    }
+
+    #[test]
+    fn next_due_respects_schedule_and_readiness() {
+        let mut queue = PromptQueue::default();
+        queue.push("ses_a", "later", None, None, Some(1_000));
+        queue.push("ses_a", "now", None, None, None);
+        queue.push("ses_b", "busy", None, None, None);
+
+        let due = queue.next_due(500, |target| target == "ses_a");
+        assert_eq!(due.map(|prompt| prompt.prompt.as_str()), Some("now"));
+
+        let due = queue.next_due(1_000, |target| target == "ses_a");
+        assert_eq!(due.map(|prompt| prompt.prompt.as_str()), Some("later"));
+        assert_eq!(queue.items()[0].schedule_label(400), "in 10m");
+        assert_eq!(queue.items()[0].schedule_label(1_200), "ready");
+    }
+
+    #[test]
+    fn parses_optional_delay_prefix() {
+        assert_eq!(
+            parse_queue_directive("+1h30m run the nightly checks"),
+            (Some(5_400), "run the nightly checks".to_string())
+        );
+        assert_eq!(
+            parse_queue_directive("+15 rebase"),
+            (Some(900), "rebase".to_string())
+        );
+        assert_eq!(
+            parse_queue_directive("+one fix it"),
+            (None, "+one fix it".to_string())
+        );
+        assert_eq!(
+            parse_queue_directive(" plain "),
+            (None, "plain".to_string())
+        );
+    }
+
+    #[test]
+    fn failed_send_goes_back_to_the_head_and_holds_the_queue() {
+        let mut panel = PromptQueuePanel::default();
+        panel
+            .enqueue("ses_a", "first", None, None, None)
+            .expect("in-memory queue saves");
+        panel
+            .enqueue("ses_a", "second", None, None, None)
+            .expect("in-memory queue saves");
+
+        let taken = panel
+            .take_due(Some("ses_a"), |_| true)
+            .expect("in-memory queue saves")
+            .expect("first prompt is due");
+        assert_eq!(taken.prompt, "first");
+
+        panel.requeue_failed(taken).expect("in-memory queue saves");
+        let order = panel
+            .items_for(Some("ses_a"))
+            .into_iter()
+            .map(|prompt| prompt.prompt.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(order, vec!["first", "second"]);
+        assert!(
+            panel
+                .take_due(Some("ses_a"), |_| true)
+                .expect("in-memory queue saves")
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn unreadable_queue_file_is_reported_and_left_untouched() {
+        let path = std::env::temp_dir().join(format!(
+            "dark_chat_prompt_queue_corrupt_{}.json",
+            super::now_unix() ^ u64::from(std::process::id())
+        ));
+        fs::write(&path, "not json").expect("fixture writes");
+
+        let (mut panel, error) = PromptQueuePanel::load(&path);
+        assert!(error.is_some());
+        panel
+            .enqueue("ses_a", "kept in memory", None, None, None)
+            .expect("in-memory queue saves");
+        assert_eq!(
+            fs::read_to_string(&path).expect("fixture reads"),
+            "not json"
+        );
+
+        let _ = fs::remove_file(path);
+    }
+
+    #[test]
+    fn persists_across_loads() {
+        let path = std::env::temp_dir().join(format!(
+            "dark_chat_prompt_queue_{}.json",
+            super::now_unix() ^ u64::from(std::process::id())
+        ));
+        let mut queue = PromptQueue::load(&path).expect("empty queue loads");
+        queue.push("ses_a", "follow up", Some("openai/gpt-5"), None, Some(42));
+        queue.save().expect("queue saves");
+
+        let mut reloaded = PromptQueue::load(&path).expect("queue reloads");
+        assert_eq!(reloaded.items(), queue.items());
+        let id = reloaded.push("ses_a", "next", None, None, None);
+        assert_eq!(id, "q2");
+
+        let _ = fs::remove_file(path);
+    }
+}
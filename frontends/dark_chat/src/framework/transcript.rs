@@ -0,0 +1,459 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use anyhow::{Context, Result};
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::framework::message_renderer::{format_message_part, format_tool_body, tool_part_name};
+
+/// Output format for an exported conversation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TranscriptFormat {
+    Markdown,
+    Json,
+}
+
+impl TranscriptFormat {
+    /// Picks the format from a file extension, defaulting to Markdown.
+    pub fn from_path(path: &Path) -> Self {
+        match path
+            .extension()
+            .and_then(|value| value.to_str())
+            .map(str::to_ascii_lowercase)
+            .as_deref()
+        {
+            Some("json") => Self::Json,
+            _ => Self::Markdown,
+        }
+    }
+
+    pub fn extension(self) -> &'static str {
+        match self {
+            Self::Markdown => "md",
+            Self::Json => "json",
+        }
+    }
+}
+
+/// A session transcript ready to be written as Markdown or lossless JSON.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Transcript {
+    pub session_id: String,
+    pub title: Option<String>,
+    pub directory: Option<String>,
+    pub provider: Option<String>,
+    pub exported_at: String,
+    pub messages: Vec<TranscriptMessage>,
+}
+
+/// One message of a transcript. `info` and `parts` keep the provider payload verbatim.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TranscriptMessage {
+    pub id: String,
+    pub role: String,
+    pub created_at: Option<String>,
+    pub model: Option<String>,
+    pub agent: Option<String>,
+    pub info: Value,
+    pub parts: Vec<Value>,
+}
+
+impl TranscriptMessage {
+    /// Builds a message from an OpenCode `{info, parts}` record or a dark_core
+    /// actor message (`{id, role, createdAt, text, raw: {info, parts}}`).
+    pub fn from_value(value: &Value) -> Option<Self> {
+        let record = value
+            .get("raw")
+            .filter(|raw| raw.is_object())
+            .unwrap_or(value);
+        let info = record.get("info").cloned().unwrap_or(Value::Null);
+
+        let id = str_field(&info, "id")
+            .or_else(|| str_field(value, "id"))?
+            .to_string();
+        let role = str_field(&info, "role")
+            .or_else(|| str_field(value, "role"))
+            .unwrap_or("unknown")
+            .to_string();
+
+        let created_at = info
+            .get("time")
+            .and_then(|time| time.get("created"))
+            .and_then(Value::as_i64)
+            .and_then(format_utc_timestamp)
+            .or_else(|| {
+                str_field(&info, "createdAt")
+                    .or_else(|| str_field(value, "createdAt"))
+                    .map(ToString::to_string)
+            });
+
+        let model = info
+            .get("model")
+            .and_then(|model| {
+                let provider = str_field(model, "providerID")?;
+                let model_id = str_field(model, "modelID")?;
+                Some(format!("{provider}/{model_id}"))
+            })
+            .or_else(
+                || match (str_field(&info, "providerID"), str_field(&info, "modelID")) {
+                    (Some(provider), Some(model_id)) => Some(format!("{provider}/{model_id}")),
+                    (None, Some(model_id)) => Some(model_id.to_string()),
+                    _ => None,
+                },
+            );
+        let agent = str_field(&info, "agent")
+            .or_else(|| str_field(&info, "mode"))
+            .map(ToString::to_string);
+
+        let parts = match record.get("parts").and_then(Value::as_array) {
+            Some(parts) => parts.clone(),
+            None => str_field(value, "text")
+                .map(|text| vec![serde_json::json!({ "type": "text", "text": text })])
+                .unwrap_or_default(),
+        };
+
+        Some(Self {
+            id,
+            role,
+            created_at,
+            model,
+            agent,
+            info,
+            parts,
+        })
+    }
+}
+
+impl Transcript {
+    pub fn new(session_id: impl Into<String>, messages: Vec<TranscriptMessage>) -> Self {
+        Self {
+            session_id: session_id.into(),
+            title: None,
+            directory: None,
+            provider: None,
+            exported_at: now_utc_label(),
+            messages,
+        }
+    }
+
+    pub fn render(&self, format: TranscriptFormat) -> Result<String> {
+        match format {
+            TranscriptFormat::Markdown => Ok(self.to_markdown()),
+            TranscriptFormat::Json => serde_json::to_string_pretty(self)
+                .context("Dark Chat // Export // Failed to serialize transcript"),
+        }
+    }
+
+    pub fn to_markdown(&self) -> String {
+        let title = self
+            .title
+            .as_deref()
+            .filter(|value| !value.trim().is_empty())
+            .unwrap_or(self.session_id.as_str());
+
+        let mut out = format!("# {title}\n\n");
+        out.push_str(&format!("- **Session:** `{}`\n", self.session_id));
+        if let Some(directory) = self.directory.as_deref() {
+            out.push_str(&format!("- **Directory:** `{directory}`\n"));
+        }
+        if let Some(provider) = self.provider.as_deref() {
+            out.push_str(&format!("- **Provider:** {provider}\n"));
+        }
+        out.push_str(&format!("- **Exported:** {}\n", self.exported_at));
+        out.push_str(&format!("- **Messages:** {}\n", self.messages.len()));
+
+        for message in &self.messages {
+            out.push_str("\n---\n\n");
+            out.push_str(&render_message_markdown(message));
+        }
+
+        out
+    }
+}
+
+/// Writes the transcript to `path`, creating parent directories as needed.
+pub fn write_transcript(
+    transcript: &Transcript,
+    path: &Path,
+    format: TranscriptFormat,
+) -> Result<()> {
+    let rendered = transcript.render(format)?;
+    if let Some(parent) = path
+        .parent()
+        .filter(|parent| !parent.as_os_str().is_empty())
+    {
+        fs::create_dir_all(parent).with_context(|| {
+            format!(
+                "Dark Chat // Export // Failed to create directory (path={})",
+                parent.display()
+            )
+        })?;
+    }
+
+    fs::write(path, rendered).with_context(|| {
+        format!(
+            "Dark Chat // Export // Failed to write transcript (path={})",
+            path.display()
+        )
+    })
+}
+
+/// Resolves an `/export [path]` target. Relative paths are anchored at `directory`;
+/// no path means `.darkfactory/exports/<session>.md`.
+pub fn resolve_transcript_path(directory: &str, session_id: &str, target: Option<&str>) -> PathBuf {
+    match target.map(str::trim).filter(|value| !value.is_empty()) {
+        Some(value) => {
+            let candidate = PathBuf::from(value);
+            if candidate.is_absolute() {
+                candidate
+            } else {
+                Path::new(directory).join(candidate)
+            }
+        }
+        None => Path::new(directory)
+            .join(".darkfactory")
+            .join("exports")
+            .join(format!(
+                "{session_id}.{}",
+                TranscriptFormat::Markdown.extension()
+            )),
+    }
+}
+
+fn render_message_markdown(message: &TranscriptMessage) -> String {
+    let mut heading = format!("## {}", role_label(&message.role));
+    if let Some(created_at) = message.created_at.as_deref() {
+        heading.push_str(&format!(" // {created_at}"));
+    }
+
+    let mut out = format!("{heading}\n\n");
+
+    let meta = [
+        message
+            .model
+            .as_deref()
+            .map(|model| format!("model: `{model}`")),
+        message
+            .agent
+            .as_deref()
+            .map(|agent| format!("agent: `{agent}`")),
+    ]
+    .into_iter()
+    .flatten()
+    .collect::<Vec<_>>();
+    if !meta.is_empty() {
+        out.push_str(&format!("_{}_\n\n", meta.join(" // ")));
+    }
+
+    let sections = message
+        .parts
+        .iter()
+        .filter_map(render_part_markdown)
+        .collect::<Vec<_>>();
+    if sections.is_empty() {
+        out.push_str("_(no text content)_\n");
+    } else {
+        out.push_str(&sections.join("\n\n"));
+        out.push('\n');
+    }
+
+    out
+}
+
+fn render_part_markdown(part: &Value) -> Option<String> {
+    let Some(name) = tool_part_name(part) else {
+        return format_message_part(part);
+    };
+
+    let status = part
+        .get("state")
+        .and_then(|state| state.get("status"))
+        .and_then(Value::as_str)
+        .map(|status| format!(" ({status})"))
+        .unwrap_or_default();
+    let body = format_tool_body(part).unwrap_or_default();
+
+    Some(format!(
+        "<details>\n<summary>Tool // {name}{status}</summary>\n\n{body}\n\n</details>"
+    ))
+}
+
+fn role_label(role: &str) -> String {
+    let mut chars = role.trim().chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => "Unknown".to_string(),
+    }
+}
+
+fn str_field<'a>(value: &'a Value, key: &str) -> Option<&'a str> {
+    value
+        .get(key)
+        .and_then(Value::as_str)
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+}
+
+fn now_utc_label() -> String {
+    let seconds = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_secs() as i64)
+        .unwrap_or_default();
+    format_utc_timestamp(seconds).unwrap_or_else(|| format!("unix:{seconds}"))
+}
+
+/// Formats a unix timestamp (seconds or milliseconds) as `YYYY-MM-DD HH:MM:SS UTC`.
+fn format_utc_timestamp(value: i64) -> Option<String> {
+    if value <= 0 {
+        return None;
+    }
+
+    let seconds = if value > 1_000_000_000_000 {
+        value / 1_000
+    } else {
+        value
+    };
+
+    let days = seconds.div_euclid(86_400);
+    let second_of_day = seconds.rem_euclid(86_400);
+
+    // Civil-from-days (Howard Hinnant), valid for the proleptic Gregorian calendar.
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1_460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+
+    Some(format!(
+        "{year:04}-{month:02}-{day:02} {:02}:{:02}:{:02} UTC",
+        second_of_day / 3_600,
+        (second_of_day % 3_600) / 60,
+        second_of_day % 60
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use serde_json::json;
+
+    use super::{
+        Transcript, TranscriptFormat, TranscriptMessage, format_utc_timestamp,
+        resolve_transcript_path,
+    };
+
+    fn sample_record() -> serde_json::Value {
+        json!({
+            "info": {
+                "id": "msg_1",
+                "role": "assistant",
+                "providerID": "anthropic",
+                "modelID": "claude-sonnet",
+                "mode": "build",
+                "time": { "created": 1_760_000_000_000i64 }
+            },
+            "parts": [
+                { "type": "text", "text": "Ran the tests." },
+                {
+                    "type": "tool",
+                    "tool": "bash",
+                    "state": {
+                        "status": "completed",
+                        "input": { "command": "cargo test" },
+                        "output": "ok"
+                    }
+                }
+            ]
+        })
+    }
+
+    #[test]
+    fn reads_metadata_from_opencode_record() {
+        let message = TranscriptMessage::from_value(&sample_record()).expect("message");
+
+        assert_eq!(message.id, "msg_1");
+        assert_eq!(message.model.as_deref(), Some("anthropic/claude-sonnet"));
+        assert_eq!(message.agent.as_deref(), Some("build"));
+        assert_eq!(
+            message.created_at.as_deref(),
+            Some("2025-10-09 08:53:20 UTC")
+        );
+    }
+
+    #[test]
+    fn reads_dark_core_actor_message_shape() {
+        let payload = json!({
+            "id": "msg_1",
+            "role": "assistant",
+            "createdAt": "2025-10-09T08:53:20.000Z",
+            "raw": sample_record()
+        });
+        let message = TranscriptMessage::from_value(&payload).expect("message");
+
+        assert_eq!(message.parts.len(), 2);
+        assert_eq!(message.agent.as_deref(), Some("build"));
+    }
+
+    #[test]
+    fn markdown_collapses_tool_calls() {
+        let message = TranscriptMessage::from_value(&sample_record()).expect("message");
+        let mut transcript = Transcript::new("ses_1", vec![message]);
+        transcript.title = Some("Fix flaky test".to_string());
+
+        let markdown = transcript.to_markdown();
+
+        assert!(markdown.starts_with("# Fix flaky test\n"));
+        assert!(markdown.contains("## Assistant // 2025-10-09 08:53:20 UTC"));
+        assert!(markdown.contains("_model: `anthropic/claude-sonnet` // agent: `build`_"));
+        assert!(markdown.contains("Ran the tests."));
+        assert!(markdown.contains("<summary>Tool // bash (completed)</summary>"));
+        assert!(!markdown.contains("### Tool // bash"));
+    }
+
+    #[test]
+    fn json_keeps_parts_verbatim() {
+        let record = sample_record();
+        let message = TranscriptMessage::from_value(&record).expect("message");
+        let transcript = Transcript::new("ses_1", vec![message]);
+
+        let rendered = transcript.render(TranscriptFormat::Json).expect("json");
+        let parsed: serde_json::Value = serde_json::from_str(&rendered).expect("valid json");
+
+        assert_eq!(parsed["messages"][0]["parts"], record["parts"]);
+        assert_eq!(parsed["messages"][0]["info"], record["info"]);
+    }
+
+    #[test]
+    fn resolves_export_paths() {
+        assert_eq!(
+            TranscriptFormat::from_path(Path::new("out/review.JSON")),
+            TranscriptFormat::Json
+        );
+        assert_eq!(
+            resolve_transcript_path("/repo", "ses_1", None),
+            Path::new("/repo/.darkfactory/exports/ses_1.md")
+        );
+        assert_eq!(
+            resolve_transcript_path("/repo", "ses_1", Some("notes/t.md")),
+            Path::new("/repo/notes/t.md")
+        );
+    }
+
+    #[test]
+    fn formats_utc_timestamps() {
+        assert_eq!(format_utc_timestamp(0), None);
+        assert_eq!(
+            format_utc_timestamp(951_782_400).as_deref(),
+            Some("2000-02-29 00:00:00 UTC")
+        );
+    }
+}
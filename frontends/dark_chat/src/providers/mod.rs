@@ -6,4 +6,5 @@ mod opencode_wire;
 mod provider;
 
 pub use opencode_server::OpenCodeProvider;
+pub use opencode_transport::PromptOptions;
 pub use provider::ChatProvider;
@@ -7,7 +7,8 @@ use tokio::sync::mpsc::{UnboundedReceiver, unbounded_channel};
 use dark_tui_components::compact_timestamp;
 
 use crate::core::{
-    ChatMessage, ChatRealtimeEvent, ChatSession, ProviderHealth, ProviderRuntimeStatus,
+    ChatAttachment, ChatMessage, ChatRealtimeEvent, ChatSession, ProviderHealth,
+    ProviderRuntimeStatus,
 };
 use crate::framework::extract_message_text;
 use crate::providers::provider::ChatProvider;
@@ -17,6 +18,7 @@ use super::opencode_extract::{
     extract_string_options, format_unix_timestamp, normalize_unix_timestamp, unwrap_data,
 };
 use super::opencode_realtime::stream_realtime_events;
+use super::opencode_transport::PromptOptions;
 use super::opencode_wire::{MessageWire, SessionWire};
 
 #[derive(Debug, Clone)]
@@ -207,23 +209,28 @@ impl ChatProvider for OpenCodeProvider {
             .await?;
 
         let data = unwrap_data(payload);
-        let records: Vec<MessageWire> = serde_json::from_value(data)
+        let raw_records: Vec<Value> = serde_json::from_value(data)
             .context("OpenCode // Session // failed to decode messages")?;
 
-        let mut mapped = records
+        let mut mapped = raw_records
             .into_iter()
-            .map(|record| ChatMessage {
-                id: record.info.id,
-                role: record.info.role,
-                text: extract_message_text(&record.parts),
-                created_at: record
-                    .info
-                    .time
-                    .created
-                    .and_then(format_unix_timestamp)
-                    .or_else(|| record.info.created_at.as_deref().map(compact_timestamp)),
+            .map(|raw| {
+                let record: MessageWire = serde_json::from_value(raw.clone())
+                    .context("OpenCode // Session // failed to decode message")?;
+                Ok(ChatMessage {
+                    id: record.info.id,
+                    role: record.info.role,
+                    text: extract_message_text(&record.parts),
+                    created_at: record
+                        .info
+                        .time
+                        .created
+                        .and_then(format_unix_timestamp)
+                        .or_else(|| record.info.created_at.as_deref().map(compact_timestamp)),
+                    raw,
+                })
             })
-            .collect::<Vec<_>>();
+            .collect::<Result<Vec<_>>>()?;
 
         mapped.sort_by(|left, right| left.created_at.cmp(&right.created_at));
         Ok(mapped)
@@ -385,11 +392,22 @@ impl ChatProvider for OpenCodeProvider {
         directory: &str,
         session_id: &str,
         prompt: &str,
+        attachments: &[ChatAttachment],
         model: Option<&str>,
         agent: Option<&str>,
     ) -> Result<()> {
-        self.send_prompt_with_options(directory, session_id, prompt, model, agent, false)
-            .await
+        self.send_prompt_with_options(
+            directory,
+            session_id,
+            prompt,
+            attachments,
+            PromptOptions {
+                model,
+                agent,
+                no_reply: false,
+            },
+        )
+        .await
     }
 
     async fn run_command(&self, directory: &str, session_id: &str, command: &str) -> Result<()> {
@@ -410,6 +428,16 @@ impl ChatProvider for OpenCodeProvider {
 
         Ok(())
     }
+
+    async fn abort_session(&self, directory: &str, session_id: &str) -> Result<()> {
+        let query = vec![("directory".to_string(), directory.to_string())];
+        let path = format!("/session/{session_id}/abort");
+        let _ = self
+            .request_json_with_fallback(Method::POST, &[path.as_str()], &query, None)
+            .await?;
+
+        Ok(())
+    }
 }
 
 #[cfg(test)]
@@ -2,6 +2,8 @@ use anyhow::{Context, Result, anyhow, bail};
 use reqwest::Method;
 use serde_json::{Value, json};
 
+use crate::core::ChatAttachment;
+
 use super::opencode_extract::{
     append_query, ensure_success, normalize_path, parse_model_selector, parse_response_body,
 };
@@ -14,6 +16,15 @@ struct RawResponse {
     body: Value,
 }
 
+/// Per-prompt settings for [`OpenCodeProvider::send_prompt_with_options`].
+#[derive(Debug, Clone, Copy, Default)]
+pub struct PromptOptions<'a> {
+    pub model: Option<&'a str>,
+    pub agent: Option<&'a str>,
+    /// Adds the prompt to the session without asking the model to answer it.
+    pub no_reply: bool,
+}
+
 impl OpenCodeProvider {
     fn is_html_payload(value: &Value) -> bool {
         let Some(text) = value.as_str() else {
@@ -110,35 +121,47 @@ impl OpenCodeProvider {
         directory: &str,
         session_id: &str,
         prompt: &str,
-        model: Option<&str>,
-        agent: Option<&str>,
-        no_reply: bool,
+        attachments: &[ChatAttachment],
+        options: PromptOptions<'_>,
     ) -> Result<()> {
         let trimmed = prompt.trim();
-        if trimmed.is_empty() {
+        if trimmed.is_empty() && attachments.is_empty() {
             bail!("OpenCode // Session // prompt cannot be empty");
         }
 
         let query = vec![("directory".to_string(), directory.to_string())];
-        let mut body = json!({
-            "parts": [{
+        let mut parts = Vec::with_capacity(attachments.len() + 1);
+        if !trimmed.is_empty() {
+            parts.push(json!({
                 "type": "text",
                 "text": trimmed,
-            }],
+            }));
+        }
+        for attachment in attachments {
+            parts.push(json!({
+                "type": "file",
+                "mime": attachment.mime,
+                "filename": attachment.filename,
+                "url": attachment.data_url(),
+            }));
+        }
+        let mut body = json!({
+            "parts": parts,
         });
 
-        if no_reply {
+        if options.no_reply {
             body["noReply"] = Value::Bool(true);
         }
 
-        if let Some(model) = model.and_then(parse_model_selector) {
+        if let Some(model) = options.model.and_then(parse_model_selector) {
             body["model"] = json!({
                 "providerID": model.0,
                 "modelID": model.1,
             });
         }
 
-        if let Some(agent) = agent
+        if let Some(agent) = options
+            .agent
             .map(str::trim)
             .filter(|value| !value.is_empty())
             .map(ToString::to_string)
@@ -1,9 +1,10 @@
-use anyhow::Result;
+use anyhow::{Result, bail};
 use async_trait::async_trait;
 use tokio::sync::mpsc::UnboundedReceiver;
 
 use crate::core::{
-    ChatMessage, ChatRealtimeEvent, ChatSession, ProviderHealth, ProviderRuntimeStatus,
+    ChatAttachment, ChatMessage, ChatRealtimeEvent, ChatSession, ProviderHealth,
+    ProviderRuntimeStatus,
 };
 
 #[async_trait]
@@ -51,6 +52,7 @@ pub trait ChatProvider: Send + Sync {
         directory: &str,
         session_id: &str,
         prompt: &str,
+        attachments: &[ChatAttachment],
         model: Option<&str>,
         agent: Option<&str>,
     ) -> Result<()>;
@@ -58,4 +60,12 @@ pub trait ChatProvider: Send + Sync {
     async fn run_command(&self, _directory: &str, _session_id: &str, _command: &str) -> Result<()> {
         Ok(())
     }
+
+    /// Stops the session's in-flight generation without deleting the session.
+    async fn abort_session(&self, _directory: &str, _session_id: &str) -> Result<()> {
+        bail!(
+            "{} does not support aborting sessions",
+            self.provider_name()
+        )
+    }
 }
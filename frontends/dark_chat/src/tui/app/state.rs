@@ -3,11 +3,29 @@ use std::io;
 use std::path::{Path, PathBuf};
 use std::time::{SystemTime, UNIX_EPOCH};
 
-use dark_tui_components::{ComponentTheme, HorizontalSplit, next_index, previous_index};
+use dark_tui_components::{
+    Action, ComponentRuntime, ComponentTheme, HorizontalSplit, Keymap, ThemeWatcher, ToastStack,
+    next_index, previous_index,
+};
 use serde::{Deserialize, Serialize};
 use tui_textarea::{CursorMove, TextArea};
 
-use crate::core::{ChatMessage, ChatSession, ChatSnapshot, ProviderHealth, ProviderRuntimeStatus};
+use crate::core::{
+    ChatAttachment, ChatMessage, ChatSession, ChatSnapshot, ProviderHealth, ProviderRuntimeStatus,
+    ensure_attachment_fits,
+};
+use crate::framework::{
+    IndexedMessage, MessageIndex, MessageSearchHit, PromptQueuePanel, QueuedPrompt,
+};
+use crate::tui::keymap::{ChatCommand, default_keymap};
+use crate::tui::overlays::{
+    AGENT_SELECTOR, MESSAGE_DETAIL_OVERLAY, MESSAGE_LOG_OVERLAY, MODEL_SELECTOR,
+    MessageDetailContent, QUEUE_OVERLAY, SEARCH_OVERLAY, mount_overlays,
+};
+use crate::tui::panes::{COMPOSER_PANE, ComposerPane, MAIN_PANE, MainPane, mount_panes};
+
+const SEARCH_RESULT_LIMIT: usize = 40;
+const SEARCH_INDEX_BATCH: usize = 8;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum FocusPane {
@@ -51,6 +69,7 @@ pub struct App {
     preferences_path: PathBuf,
     refresh_seconds: u64,
     theme: ComponentTheme,
+    theme_watcher: Option<ThemeWatcher>,
     health: ProviderHealth,
     sessions: Vec<ChatSession>,
     selected_session: usize,
@@ -63,11 +82,12 @@ pub struct App {
     runtime_status: ProviderRuntimeStatus,
     focus: FocusPane,
     status_message: String,
+    /// Error toasts; failures stay visible after later status messages.
+    toasts: ToastStack,
+    /// Scroll offset while the message log is open.
     draft: String,
     draft_cursor: usize,
     composer: TextArea<'static>,
-    composing: bool,
-    composer_autocomplete_open: bool,
     composer_autocomplete_mode: Option<ComposerAutocompleteMode>,
     composer_autocomplete_query: String,
     composer_autocomplete_selected: usize,
@@ -75,16 +95,21 @@ pub struct App {
     composer_autocomplete_items: Vec<ComposerAutocompleteItem>,
     workspace_file_cache: Vec<String>,
     workspace_file_cache_loaded: bool,
-    model_selector_open: bool,
     model_selector_raw_mode: bool,
     model_selector_query: String,
     model_selector_raw_input: String,
     model_selector_selected: usize,
     model_selector_anchor_col: Option<u16>,
-    agent_selector_open: bool,
     agent_selector_query: String,
     agent_selector_selected: usize,
     agent_selector_anchor_col: Option<u16>,
+    search_query: String,
+    search_selected: usize,
+    search_results: Vec<MessageSearchHit>,
+    message_index: MessageIndex,
+    pending_message_jump: Option<String>,
+    prompt_queue: PromptQueuePanel,
+    attachments: Vec<ChatAttachment>,
     sessions_scroll_index: usize,
     chat_scroll_lines: u16,
     runtime_scroll_lines: u16,
@@ -94,14 +119,15 @@ pub struct App {
     refresh_in_flight: bool,
     send_in_flight: bool,
     create_in_flight: bool,
+    offline: bool,
     realtime_supported: bool,
     realtime_connected: bool,
     realtime_last_event: Option<String>,
     realtime_event_count: u64,
     show_help: bool,
+    keymap: Keymap<ChatCommand>,
     message_detail_expanded: bool,
-    message_detail_popup_open: bool,
-    message_detail_popup_scroll_lines: u16,
+    overlays: ComponentRuntime,
     last_synced: String,
 }
 
@@ -118,14 +144,20 @@ impl App {
         let preferences_path = Path::new(&directory)
             .join(".darkfactory")
             .join("darkchat.toml");
+        let (prompt_queue, prompt_queue_error) = PromptQueuePanel::load(
+            Path::new(&directory)
+                .join(".darkfactory")
+                .join("darkchat_queue.json"),
+        );
 
-        Self {
+        let mut app = Self {
             base_url,
             directory,
             provider_name,
             preferences_path,
             refresh_seconds,
             theme: ComponentTheme::default(),
+            theme_watcher: None,
             health: ProviderHealth {
                 healthy: false,
                 version: None,
@@ -141,11 +173,10 @@ impl App {
             runtime_status: ProviderRuntimeStatus::default(),
             focus: FocusPane::Chat,
             status_message: "Booting dark_chat".to_string(),
+            toasts: ToastStack::new(),
             draft,
             draft_cursor,
             composer,
-            composing: false,
-            composer_autocomplete_open: false,
             composer_autocomplete_mode: None,
             composer_autocomplete_query: String::new(),
             composer_autocomplete_selected: 0,
@@ -153,16 +184,21 @@ impl App {
             composer_autocomplete_items: Vec::new(),
             workspace_file_cache: Vec::new(),
             workspace_file_cache_loaded: false,
-            model_selector_open: false,
             model_selector_raw_mode: false,
             model_selector_query: String::new(),
             model_selector_raw_input: String::new(),
             model_selector_selected: 0,
             model_selector_anchor_col: None,
-            agent_selector_open: false,
             agent_selector_query: String::new(),
             agent_selector_selected: 0,
             agent_selector_anchor_col: None,
+            search_query: String::new(),
+            search_selected: 0,
+            search_results: Vec::new(),
+            message_index: MessageIndex::new(),
+            pending_message_jump: None,
+            prompt_queue,
+            attachments: Vec::new(),
             sessions_scroll_index: 0,
             chat_scroll_lines: 0,
             runtime_scroll_lines: 0,
@@ -172,16 +208,32 @@ impl App {
             refresh_in_flight: false,
             send_in_flight: false,
             create_in_flight: false,
+            offline: false,
             realtime_supported: false,
             realtime_connected: false,
             realtime_last_event: None,
             realtime_event_count: 0,
             show_help: true,
+            keymap: default_keymap(),
             message_detail_expanded: false,
-            message_detail_popup_open: false,
-            message_detail_popup_scroll_lines: 0,
+            overlays: ComponentRuntime::new(),
             last_synced: "-".to_string(),
+        };
+        if let Err(error) =
+            mount_overlays(&mut app.overlays).and_then(|()| mount_panes(&mut app.overlays))
+        {
+            app.status_message = format!("Overlay mount failed: {error}");
         }
+        if let Some(error) = prompt_queue_error {
+            log::warn!(
+                "Dark Chat // Queue // Prompt queue not loaded (meta={})",
+                serde_json::json!({ "error": format!("{error:#}") })
+            );
+            app.set_error_message(format!(
+                "Prompt queue not loaded, queued prompts are kept in memory only: {error:#}"
+            ));
+        }
+        app
     }
 
     pub fn refresh_seconds(&self) -> u64 {
@@ -204,6 +256,29 @@ impl App {
         &self.theme
     }
 
+    pub fn set_theme(&mut self, theme: ComponentTheme) {
+        self.theme = theme;
+    }
+
+    pub fn set_theme_watcher(&mut self, watcher: Option<ThemeWatcher>) {
+        self.theme_watcher = watcher;
+    }
+
+    /// Hot reloads the theme file after it changes; a broken file keeps the
+    /// current theme and reports the error.
+    pub fn poll_theme(&mut self) {
+        let Some(reloaded) = self.theme_watcher.as_mut().and_then(ThemeWatcher::poll) else {
+            return;
+        };
+        match reloaded {
+            Ok(theme) => {
+                self.set_theme(theme);
+                self.set_status_message("Theme reloaded.");
+            }
+            Err(error) => self.set_error_message(format!("Theme reload failed: {error}")),
+        }
+    }
+
     pub fn health(&self) -> &ProviderHealth {
         &self.health
     }
@@ -277,7 +352,9 @@ impl App {
     }
 
     pub fn composer_autocomplete_open(&self) -> bool {
-        self.composer_autocomplete_open
+        self.overlays
+            .component::<ComposerPane>(COMPOSER_PANE)
+            .is_some_and(ComposerPane::is_completing)
     }
 
     pub fn composer_autocomplete_mode(&self) -> Option<ComposerAutocompleteMode> {
@@ -297,7 +374,7 @@ impl App {
     }
 
     pub fn composer_autocomplete_anchor_position(&self) -> Option<(usize, usize)> {
-        if !self.composer_autocomplete_open {
+        if !self.composer_autocomplete_open() {
             return None;
         }
 
@@ -333,6 +410,67 @@ impl App {
         self.status_message = value.into();
     }
 
+    /// Shows a failure in the status line and keeps it as an error toast.
+    pub fn set_error_message(&mut self, value: impl Into<String>) {
+        let value = value.into();
+        self.toasts.error(value.clone());
+        self.status_message = value;
+        if self.is_message_log_open() {
+            self.sync_message_log();
+        }
+    }
+
+    pub fn toasts(&self) -> &ToastStack {
+        &self.toasts
+    }
+
+    pub fn toasts_mut(&mut self) -> &mut ToastStack {
+        &mut self.toasts
+    }
+
+    pub fn is_message_log_open(&self) -> bool {
+        self.overlays.is_visible(MESSAGE_LOG_OVERLAY)
+    }
+
+    pub fn open_message_log(&mut self) {
+        self.sync_message_log();
+        self.overlays.show(MESSAGE_LOG_OVERLAY);
+    }
+
+    pub fn clear_message_log(&mut self) {
+        self.toasts.clear_history();
+        self.sync_message_log();
+    }
+
+    /// Hands the log overlay a copy of the toasts. Failures are logged rather
+    /// than toasted, since a toast would land back here.
+    fn sync_message_log(&mut self) {
+        if let Err(error) = self.overlays.dispatch(Action::custom(self.toasts.clone())) {
+            log::error!(
+                "Dark Chat // Overlays // Message log update failed (meta={})",
+                serde_json::json!({ "error": error.to_string() })
+            );
+            self.status_message = format!("Overlay error: {error}");
+        }
+    }
+
+    pub fn keymap(&self) -> &Keymap<ChatCommand> {
+        &self.keymap
+    }
+
+    /// Installs `keymap` here and in the overlays that resolve their own keys.
+    pub fn set_keymap(&mut self, keymap: Keymap<ChatCommand>) {
+        self.dispatch_overlay_action(Action::custom(keymap.clone()));
+        self.keymap = keymap;
+    }
+
+    /// Keys typed so far toward a `main` mode binding, for the footer.
+    pub fn pending_key_sequence(&self) -> Option<String> {
+        self.overlays
+            .component::<MainPane>(MAIN_PANE)
+            .and_then(MainPane::pending)
+    }
+
     pub fn apply_snapshot(&mut self, snapshot: ChatSnapshot) {
         let previous_session_id = self.active_session_id().map(ToString::to_string);
         let previous_agent = self.active_agent().map(ToString::to_string);
@@ -350,6 +488,8 @@ impl App {
         self.messages = snapshot.messages;
         if self.messages.is_empty() {
             self.close_message_detail_popup();
+        } else {
+            self.sync_message_detail_popup();
         }
         self.runtime_status = snapshot.runtime_status;
         self.agents = normalize_options(snapshot.agents);
@@ -460,7 +600,7 @@ impl App {
     }
 
     pub fn is_model_selector_open(&self) -> bool {
-        self.model_selector_open
+        self.overlays.is_visible(MODEL_SELECTOR)
     }
 
     pub fn model_selector_raw_mode(&self) -> bool {
@@ -484,13 +624,13 @@ impl App {
     }
 
     pub fn open_model_selector(&mut self) {
-        self.model_selector_open = true;
+        self.overlays.show(MODEL_SELECTOR);
         self.model_selector_raw_mode = false;
         self.model_selector_query.clear();
         self.model_selector_raw_input = self.active_model().unwrap_or_default().to_string();
         self.model_selector_selected = 0;
         self.model_selector_anchor_col = None;
-        self.agent_selector_open = false;
+        self.overlays.hide(AGENT_SELECTOR);
     }
 
     pub fn open_model_selector_at(&mut self, anchor_col: u16) {
@@ -499,7 +639,7 @@ impl App {
     }
 
     pub fn close_model_selector(&mut self) {
-        self.model_selector_open = false;
+        self.overlays.hide(MODEL_SELECTOR);
         self.model_selector_raw_mode = false;
         self.model_selector_query.clear();
         self.model_selector_raw_input.clear();
@@ -508,7 +648,7 @@ impl App {
     }
 
     pub fn is_agent_selector_open(&self) -> bool {
-        self.agent_selector_open
+        self.overlays.is_visible(AGENT_SELECTOR)
     }
 
     pub fn agent_selector_query(&self) -> &str {
@@ -524,7 +664,7 @@ impl App {
     }
 
     pub fn open_agent_selector(&mut self) {
-        self.agent_selector_open = true;
+        self.overlays.show(AGENT_SELECTOR);
         self.agent_selector_query.clear();
         self.agent_selector_selected = 0;
         self.agent_selector_anchor_col = None;
@@ -537,7 +677,7 @@ impl App {
     }
 
     pub fn close_agent_selector(&mut self) {
-        self.agent_selector_open = false;
+        self.overlays.hide(AGENT_SELECTOR);
         self.agent_selector_query.clear();
         self.agent_selector_selected = 0;
         self.agent_selector_anchor_col = None;
@@ -607,6 +747,298 @@ impl App {
         Some(selected)
     }
 
+    pub fn is_search_open(&self) -> bool {
+        self.overlays.is_visible(SEARCH_OVERLAY)
+    }
+
+    pub fn search_query(&self) -> &str {
+        &self.search_query
+    }
+
+    pub fn search_selected(&self) -> usize {
+        self.search_selected
+    }
+
+    pub fn search_results(&self) -> &[MessageSearchHit] {
+        &self.search_results
+    }
+
+    pub fn indexed_message_count(&self) -> usize {
+        self.message_index.message_count()
+    }
+
+    pub fn open_search(&mut self, query: Option<&str>) {
+        self.overlays.show(SEARCH_OVERLAY);
+        self.search_query = query.unwrap_or_default().trim().to_string();
+        self.close_model_selector();
+        self.close_agent_selector();
+        self.close_message_detail_popup();
+        self.run_search();
+    }
+
+    pub fn close_search(&mut self) {
+        self.overlays.hide(SEARCH_OVERLAY);
+        self.search_query.clear();
+        self.search_selected = 0;
+        self.search_results.clear();
+    }
+
+    pub fn search_insert_char(&mut self, value: char) {
+        self.search_query.push(value);
+        self.run_search();
+    }
+
+    pub fn search_backspace(&mut self) {
+        self.search_query.pop();
+        self.run_search();
+    }
+
+    pub fn search_clear(&mut self) {
+        self.search_query.clear();
+        self.run_search();
+    }
+
+    pub fn search_move_up(&mut self) {
+        self.search_selected = previous_index(self.search_selected, self.search_results.len());
+    }
+
+    pub fn search_move_down(&mut self) {
+        self.search_selected = next_index(self.search_selected, self.search_results.len());
+    }
+
+    pub fn search_set_selected(&mut self, index: usize) {
+        if index < self.search_results.len() {
+            self.search_selected = index;
+        }
+    }
+
+    /// Sessions whose history is missing from the search index or changed since the
+    /// last pass, capped so each background fetch stays small.
+    pub fn sessions_needing_index(&self) -> Vec<ChatSession> {
+        self.sessions
+            .iter()
+            .filter(|session| {
+                self.message_index
+                    .needs_update(&session.id, session.updated_unix)
+            })
+            .take(SEARCH_INDEX_BATCH)
+            .cloned()
+            .collect()
+    }
+
+    pub fn apply_index_updates(&mut self, updates: Vec<(ChatSession, Vec<ChatMessage>)>) {
+        for (session, messages) in updates {
+            let documents = messages
+                .into_iter()
+                .filter(|message| !message.text.trim().is_empty())
+                .map(|message| IndexedMessage {
+                    message_id: message.id,
+                    role: message.role,
+                    created_at: message.created_at,
+                    text: message.text,
+                })
+                .collect();
+            self.message_index.index_session(
+                &session.id,
+                &session.title,
+                session.updated_unix,
+                documents,
+            );
+        }
+
+        let live = self
+            .sessions
+            .iter()
+            .map(|session| session.id.as_str())
+            .collect::<Vec<_>>();
+        self.message_index.retain_sessions(&live);
+        self.run_search();
+    }
+
+    /// Closes search and selects the hit's session. Returns the hit and whether the
+    /// active session changed; the message jump stays pending until it is rendered.
+    pub fn confirm_search_selection(&mut self) -> Option<(MessageSearchHit, bool)> {
+        let hit = self.search_results.get(self.search_selected)?.clone();
+        let previous = self.active_session_id().map(ToString::to_string);
+        self.close_search();
+
+        self.set_active_session_id(&hit.session_id);
+        let changed = previous.as_deref() != Some(hit.session_id.as_str());
+        if changed {
+            self.clear_messages();
+        }
+        self.pending_message_jump = Some(hit.message_id.clone());
+        self.focus = FocusPane::Chat;
+        Some((hit, changed))
+    }
+
+    pub fn pending_message_jump(&self) -> Option<&str> {
+        self.pending_message_jump.as_deref()
+    }
+
+    pub fn clear_pending_message_jump(&mut self) {
+        self.pending_message_jump = None;
+    }
+
+    pub fn message_position(&self, message_id: &str) -> Option<usize> {
+        self.messages
+            .iter()
+            .position(|message| message.id == message_id)
+    }
+
+    fn run_search(&mut self) {
+        self.search_results = self
+            .message_index
+            .search(&self.search_query, SEARCH_RESULT_LIMIT);
+        self.search_selected = self
+            .search_selected
+            .min(self.search_results.len().saturating_sub(1));
+    }
+
+    /// Queued prompts for the active session, in send order.
+    pub fn queued_prompts(&self) -> Vec<&QueuedPrompt> {
+        self.prompt_queue.items_for(self.active_session_id())
+    }
+
+    /// Queues `prompt` for the active session with the current model/agent selection.
+    /// Returns the prompt's 1-based position in that session's queue.
+    pub fn enqueue_prompt(
+        &mut self,
+        prompt: &str,
+        delay_seconds: Option<u64>,
+    ) -> anyhow::Result<usize> {
+        let Some(session_id) = self.active_session_id().map(ToString::to_string) else {
+            anyhow::bail!("no active session");
+        };
+
+        let model = self.active_model().map(ToString::to_string);
+        let agent = self.active_agent().map(ToString::to_string);
+        self.prompt_queue.enqueue(
+            &session_id,
+            prompt,
+            model.as_deref(),
+            agent.as_deref(),
+            delay_seconds,
+        )
+    }
+
+    /// True when the active session is still working on a previous prompt.
+    pub fn active_session_busy(&self) -> bool {
+        self.active_session()
+            .is_some_and(|session| !session_status_is_idle(&session.status))
+    }
+
+    /// Pops the next due prompt whose session is idle; see [`PromptQueuePanel::take_due`].
+    pub fn take_due_prompt(&mut self) -> anyhow::Result<Option<QueuedPrompt>> {
+        let active_session_id = self.active_session_id().map(ToString::to_string);
+        let sessions = &self.sessions;
+        self.prompt_queue
+            .take_due(active_session_id.as_deref(), |target| {
+                sessions
+                    .iter()
+                    .any(|session| session.id == target && session_status_is_idle(&session.status))
+            })
+    }
+
+    /// Puts a queued prompt whose send failed back at the head of the queue.
+    pub fn requeue_failed_prompt(&mut self, prompt: QueuedPrompt) -> anyhow::Result<()> {
+        self.prompt_queue.requeue_failed(prompt)
+    }
+
+    pub fn is_queue_panel_open(&self) -> bool {
+        self.overlays.is_visible(QUEUE_OVERLAY)
+    }
+
+    pub fn queue_selected(&self) -> usize {
+        self.prompt_queue.selected()
+    }
+
+    pub fn open_queue_panel(&mut self) {
+        self.prompt_queue.reset_selection();
+        self.close_model_selector();
+        self.close_agent_selector();
+        self.close_message_detail_popup();
+        self.overlays.show(QUEUE_OVERLAY);
+    }
+
+    pub fn close_queue_panel(&mut self) {
+        self.prompt_queue.reset_selection();
+        self.overlays.hide(QUEUE_OVERLAY);
+    }
+
+    pub fn queue_move_selection_up(&mut self) {
+        let session_id = self.active_session_id().map(ToString::to_string);
+        self.prompt_queue.select_previous(session_id.as_deref());
+    }
+
+    pub fn queue_move_selection_down(&mut self) {
+        let session_id = self.active_session_id().map(ToString::to_string);
+        self.prompt_queue.select_next(session_id.as_deref());
+    }
+
+    pub fn queue_set_selected(&mut self, index: usize) {
+        let session_id = self.active_session_id().map(ToString::to_string);
+        self.prompt_queue.set_selected(session_id.as_deref(), index);
+    }
+
+    /// Moves the selected prompt one slot earlier (`up`) or later in the session queue.
+    pub fn queue_reorder_selected(&mut self, up: bool) -> anyhow::Result<bool> {
+        let session_id = self.active_session_id().map(ToString::to_string);
+        self.prompt_queue
+            .reorder_selected(session_id.as_deref(), up)
+    }
+
+    pub fn queue_cancel_selected(&mut self) -> anyhow::Result<Option<QueuedPrompt>> {
+        let session_id = self.active_session_id().map(ToString::to_string);
+        self.prompt_queue.cancel_selected(session_id.as_deref())
+    }
+
+    /// Loads the selected queued prompt into the composer; the next send updates it
+    /// in place instead of sending.
+    pub fn queue_edit_selected(&mut self) -> bool {
+        let session_id = self.active_session_id().map(ToString::to_string);
+        let Some(prompt) = self.prompt_queue.edit_selected(session_id.as_deref()) else {
+            return false;
+        };
+
+        self.overlays.hide(QUEUE_OVERLAY);
+        self.draft = prompt;
+        self.open_composer();
+        true
+    }
+
+    pub fn is_editing_queued_prompt(&self) -> bool {
+        self.prompt_queue.is_editing()
+    }
+
+    pub fn finish_queued_prompt_edit(&mut self, prompt: &str) -> anyhow::Result<bool> {
+        let updated = self.prompt_queue.finish_edit(prompt);
+        self.clear_draft_after_send();
+        updated
+    }
+
+    /// Attachments staged for the next prompt; they survive compose cancel and are
+    /// cleared once a send succeeds or via `/detach`.
+    pub fn attachments(&self) -> &[ChatAttachment] {
+        &self.attachments
+    }
+
+    /// Stages `attachment`, enforcing the per-prompt count and size caps. Returns the
+    /// number of staged attachments.
+    pub fn add_attachment(&mut self, attachment: ChatAttachment) -> anyhow::Result<usize> {
+        ensure_attachment_fits(&self.attachments, &attachment)?;
+        self.attachments.push(attachment);
+        Ok(self.attachments.len())
+    }
+
+    pub fn remove_last_attachment(&mut self) -> Option<ChatAttachment> {
+        self.attachments.pop()
+    }
+
+    pub fn clear_attachments(&mut self) {
+        self.attachments.clear();
+    }
+
     pub fn model_selector_toggle_mode(&mut self) {
         self.model_selector_raw_mode = !self.model_selector_raw_mode;
         if self.model_selector_raw_mode && self.model_selector_raw_input.is_empty() {
@@ -712,7 +1144,7 @@ impl App {
     }
 
     pub fn close_composer_autocomplete(&mut self) {
-        self.composer_autocomplete_open = false;
+        self.set_composer_completing(false);
         self.composer_autocomplete_mode = None;
         self.composer_autocomplete_query.clear();
         self.composer_autocomplete_selected = 0;
@@ -769,7 +1201,7 @@ impl App {
     }
 
     pub fn is_composing(&self) -> bool {
-        self.composing
+        self.overlays.base_focus() == Some(COMPOSER_PANE)
     }
 
     pub fn open_composer(&mut self) {
@@ -779,19 +1211,27 @@ impl App {
 
         self.ensure_workspace_file_cache();
         self.focus = FocusPane::Composer;
-        self.composing = true;
+        self.overlays.focus(COMPOSER_PANE);
         self.draft_cursor = self.draft.chars().count();
         self.sync_composer_from_draft();
     }
 
     pub fn cancel_composer(&mut self) {
-        self.composing = false;
+        if self.prompt_queue.cancel_edit() {
+            self.draft.clear();
+            self.draft_cursor = 0;
+        }
+        self.overlays.focus(MAIN_PANE);
         self.focus = FocusPane::Chat;
         self.close_composer_autocomplete();
     }
 
+    pub fn draft(&self) -> &str {
+        &self.draft
+    }
+
     pub fn insert_draft_char(&mut self, value: char) {
-        if !self.composing {
+        if !self.is_composing() {
             return;
         }
 
@@ -801,7 +1241,7 @@ impl App {
     }
 
     pub fn backspace_draft(&mut self) {
-        if !self.composing {
+        if !self.is_composing() {
             return;
         }
 
@@ -815,7 +1255,7 @@ impl App {
     }
 
     pub fn delete_draft_char(&mut self) {
-        if !self.composing {
+        if !self.is_composing() {
             return;
         }
 
@@ -824,7 +1264,7 @@ impl App {
     }
 
     pub fn move_draft_cursor_left(&mut self) {
-        if !self.composing {
+        if !self.is_composing() {
             return;
         }
 
@@ -833,7 +1273,7 @@ impl App {
     }
 
     pub fn move_draft_cursor_right(&mut self) {
-        if !self.composing {
+        if !self.is_composing() {
             return;
         }
 
@@ -843,7 +1283,7 @@ impl App {
     }
 
     pub fn move_draft_cursor_home(&mut self) {
-        if !self.composing {
+        if !self.is_composing() {
             return;
         }
 
@@ -852,7 +1292,7 @@ impl App {
     }
 
     pub fn move_draft_cursor_end(&mut self) {
-        if !self.composing {
+        if !self.is_composing() {
             return;
         }
 
@@ -861,7 +1301,7 @@ impl App {
     }
 
     pub fn clear_draft(&mut self) {
-        if !self.composing {
+        if !self.is_composing() {
             return;
         }
 
@@ -878,6 +1318,10 @@ impl App {
         self.chat_scroll_lines = self.chat_scroll_lines.saturating_sub(amount.max(1));
     }
 
+    pub fn set_chat_scroll_lines(&mut self, value: u16) {
+        self.chat_scroll_lines = value;
+    }
+
     pub fn reset_chat_scroll(&mut self) {
         self.chat_scroll_lines = 0;
     }
@@ -909,7 +1353,7 @@ impl App {
     }
 
     pub fn take_prompt(&self) -> Option<String> {
-        if !self.composing {
+        if !self.is_composing() {
             return None;
         }
 
@@ -925,7 +1369,7 @@ impl App {
         self.draft.clear();
         self.draft_cursor = 0;
         self.sync_composer_from_draft();
-        self.composing = false;
+        self.overlays.focus(MAIN_PANE);
         self.focus = FocusPane::Chat;
         self.chat_scroll_lines = 0;
         self.close_composer_autocomplete();
@@ -943,6 +1387,16 @@ impl App {
         self.create_in_flight = value;
     }
 
+    /// Marks the session state as served from the on-disk cache. While offline the
+    /// history stays browsable but sends and session creation are refused.
+    pub fn set_offline(&mut self, value: bool) {
+        self.offline = value;
+    }
+
+    pub fn is_offline(&self) -> bool {
+        self.offline
+    }
+
     pub fn set_realtime_supported(&mut self, value: bool) {
         self.realtime_supported = value;
     }
@@ -984,6 +1438,9 @@ impl App {
         if self.create_in_flight {
             tags.push("new-session");
         }
+        if self.offline {
+            tags.push("offline");
+        }
 
         if tags.is_empty() {
             "idle".to_string()
@@ -998,7 +1455,7 @@ impl App {
     }
 
     fn refresh_composer_autocomplete(&mut self) {
-        if !self.composing {
+        if !self.is_composing() {
             self.close_composer_autocomplete();
             return;
         }
@@ -1024,7 +1481,7 @@ impl App {
             return;
         }
 
-        self.composer_autocomplete_open = true;
+        self.set_composer_completing(true);
         self.composer_autocomplete_mode = match trigger {
             '/' => Some(ComposerAutocompleteMode::Slash),
             '@' => Some(ComposerAutocompleteMode::File),
@@ -1069,39 +1526,54 @@ impl App {
         if self.message_detail_expanded { 96 } else { 30 }
     }
 
-    pub fn message_detail_popup_open(&self) -> bool {
-        self.message_detail_popup_open
+    pub fn overlays(&self) -> &ComponentRuntime {
+        &self.overlays
     }
 
-    pub fn open_message_detail_popup(&mut self) -> bool {
-        if self.messages.is_empty() {
-            return false;
+    pub fn overlays_mut(&mut self) -> &mut ComponentRuntime {
+        &mut self.overlays
+    }
+
+    fn set_composer_completing(&mut self, completing: bool) {
+        if let Some(pane) = self.overlays.component_mut::<ComposerPane>(COMPOSER_PANE) {
+            pane.set_completing(completing);
         }
+    }
 
-        self.message_detail_popup_open = true;
-        self.message_detail_popup_scroll_lines = 0;
-        true
+    /// Posts an action to the overlays; they only answer with redraw requests.
+    pub fn dispatch_overlay_action(&mut self, action: Action) {
+        if let Err(error) = self.overlays.dispatch(action) {
+            self.set_error_message(format!("Overlay error: {error}"));
+        }
     }
 
-    pub fn close_message_detail_popup(&mut self) {
-        self.message_detail_popup_open = false;
-        self.message_detail_popup_scroll_lines = 0;
+    pub fn message_detail_popup_open(&self) -> bool {
+        self.overlays.is_visible(MESSAGE_DETAIL_OVERLAY)
     }
 
-    pub fn message_detail_popup_scroll_lines(&self) -> u16 {
-        self.message_detail_popup_scroll_lines
+    pub fn open_message_detail_popup(&mut self) -> bool {
+        let Some(text) = self.message_detail_popup_text() else {
+            return false;
+        };
+
+        self.dispatch_overlay_action(Action::custom(MessageDetailContent(text)));
+        self.dispatch_overlay_action(Action::ScrollToTop);
+        self.overlays.show(MESSAGE_DETAIL_OVERLAY);
+        true
     }
 
-    pub fn scroll_message_detail_up(&mut self, amount: u16) {
-        self.message_detail_popup_scroll_lines = self
-            .message_detail_popup_scroll_lines
-            .saturating_sub(amount);
+    pub fn close_message_detail_popup(&mut self) {
+        self.overlays.hide(MESSAGE_DETAIL_OVERLAY);
     }
 
-    pub fn scroll_message_detail_down(&mut self, amount: u16) {
-        self.message_detail_popup_scroll_lines = self
-            .message_detail_popup_scroll_lines
-            .saturating_add(amount);
+    /// Keeps an open detail viewer on the latest rich message after a refresh.
+    fn sync_message_detail_popup(&mut self) {
+        if !self.message_detail_popup_open() {
+            return;
+        }
+        if let Some(text) = self.message_detail_popup_text() {
+            self.dispatch_overlay_action(Action::custom(MessageDetailContent(text)));
+        }
     }
 
     pub fn message_detail_popup_text(&self) -> Option<String> {
@@ -1152,6 +1624,13 @@ impl App {
     }
 }
 
+fn session_status_is_idle(status: &str) -> bool {
+    matches!(
+        status.trim().to_ascii_lowercase().as_str(),
+        "" | "idle" | "ready"
+    )
+}
+
 fn resolve_session_index(sessions: &[ChatSession], preferred_id: Option<&str>) -> usize {
     if sessions.is_empty() {
         return 0;
@@ -1253,7 +1732,7 @@ fn current_composer_trigger(value: &str, cursor_index: usize) -> Option<(char, S
 }
 
 fn slash_autocomplete_items(query: &str) -> Vec<ComposerAutocompleteItem> {
-    const COMMANDS: [(&str, &str); 9] = [
+    const COMMANDS: [(&str, &str); 12] = [
         ("help", "toggle help"),
         ("refresh", "refresh snapshot"),
         ("new", "create session"),
@@ -1262,6 +1741,9 @@ fn slash_autocomplete_items(query: &str) -> Vec<ComposerAutocompleteItem> {
         ("agent", "set agent"),
         ("model", "set model"),
         ("grep", "search workspace"),
+        ("export", "export transcript"),
+        ("search", "search messages"),
+        ("queue", "queue prompt (+30m delays)"),
         ("expand", "toggle detail expansion"),
     ];
 
@@ -1271,7 +1753,7 @@ fn slash_autocomplete_items(query: &str) -> Vec<ComposerAutocompleteItem> {
         .filter(|(name, _)| needle.is_empty() || name.contains(&needle))
         .map(|(name, desc)| ComposerAutocompleteItem {
             label: format!("/{name}"),
-            insert: if matches!(name, "agent" | "model" | "grep") {
+            insert: if matches!(name, "agent" | "model" | "grep" | "search" | "queue") {
                 format!("/{name} ")
             } else {
                 format!("/{name}")
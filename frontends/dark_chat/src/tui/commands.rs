@@ -1,9 +1,10 @@
-use std::collections::BTreeSet;
-use std::path::{Path, PathBuf};
 use std::process::Command;
 
 use anyhow::{Context, Result, anyhow};
 
+use crate::core::is_attachment_path;
+use crate::framework::{extract_file_references, resolve_reference_path};
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum LocalSlashCommand {
     ToggleHelp,
@@ -14,7 +15,13 @@ pub enum LocalSlashCommand {
     SetAgent(String),
     SetModel(String),
     Grep(String),
+    Export(Option<String>),
+    Search(Option<String>),
+    Queue(Option<String>),
+    Attach(Option<String>),
+    Detach,
     ToggleDetailExpansion,
+    Messages,
 }
 
 pub fn parse_local_slash_command(prompt: &str) -> Option<LocalSlashCommand> {
@@ -37,7 +44,21 @@ pub fn parse_local_slash_command(prompt: &str) -> Option<LocalSlashCommand> {
         "agent" if !arg.is_empty() => Some(LocalSlashCommand::SetAgent(arg.to_string())),
         "model" if !arg.is_empty() => Some(LocalSlashCommand::SetModel(arg.to_string())),
         "grep" if !arg.is_empty() => Some(LocalSlashCommand::Grep(arg.to_string())),
+        "export" => Some(LocalSlashCommand::Export(
+            Some(arg.to_string()).filter(|value| !value.is_empty()),
+        )),
+        "search" | "find" => Some(LocalSlashCommand::Search(
+            Some(arg.to_string()).filter(|value| !value.is_empty()),
+        )),
+        "queue" => Some(LocalSlashCommand::Queue(
+            Some(arg.to_string()).filter(|value| !value.is_empty()),
+        )),
+        "attach" => Some(LocalSlashCommand::Attach(
+            Some(arg.to_string()).filter(|value| !value.is_empty()),
+        )),
+        "detach" => Some(LocalSlashCommand::Detach),
         "expand" | "detail" | "details" => Some(LocalSlashCommand::ToggleDetailExpansion),
+        "messages" | "log" => Some(LocalSlashCommand::Messages),
         _ => None,
     }
 }
@@ -113,6 +134,9 @@ pub fn build_prompt_with_file_context(directory: &str, prompt: &str) -> (String,
         let Some(path) = resolve_reference_path(directory, &file_ref) else {
             continue;
         };
+        if is_attachment_path(&path) {
+            continue;
+        }
 
         let Ok(content) = std::fs::read_to_string(&path) else {
             continue;
@@ -136,54 +160,6 @@ pub fn build_prompt_with_file_context(directory: &str, prompt: &str) -> (String,
     )
 }
 
-fn extract_file_references(prompt: &str) -> Vec<String> {
-    let mut refs = BTreeSet::<String>::new();
-
-    for token in prompt.split_whitespace() {
-        let trimmed = token.trim_matches(|char: char| {
-            matches!(
-                char,
-                ',' | '.' | ';' | ':' | ')' | '(' | '[' | ']' | '{' | '}' | '"' | '\''
-            )
-        });
-
-        if !trimmed.starts_with('@') {
-            continue;
-        }
-
-        let path = trimmed.trim_start_matches('@').trim();
-        if path.is_empty() {
-            continue;
-        }
-
-        refs.insert(path.to_string());
-    }
-
-    refs.into_iter().collect()
-}
-
-fn resolve_reference_path(directory: &str, reference: &str) -> Option<PathBuf> {
-    let candidate = PathBuf::from(reference);
-    let resolved = if candidate.is_absolute() {
-        candidate
-    } else {
-        Path::new(directory).join(candidate)
-    };
-
-    let canonical = resolved.canonicalize().ok()?;
-    let workspace = Path::new(directory).canonicalize().ok()?;
-
-    if !canonical.starts_with(&workspace) {
-        return None;
-    }
-
-    if !canonical.is_file() {
-        return None;
-    }
-
-    Some(canonical)
-}
-
 fn clip_text(value: &str, max_chars: usize) -> String {
     let trimmed = value.trim();
     if trimmed.chars().count() <= max_chars {
@@ -193,3 +169,37 @@ fn clip_text(value: &str, max_chars: usize) -> String {
     let clipped = trimmed.chars().take(max_chars).collect::<String>();
     format!("{clipped}\n...[truncated]")
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{LocalSlashCommand, parse_local_slash_command};
+
+    #[test]
+    fn parses_attach_and_detach_commands() {
+        assert_eq!(
+            parse_local_slash_command("/attach shots/error.png"),
+            Some(LocalSlashCommand::Attach(Some(
+                "shots/error.png".to_string()
+            )))
+        );
+        assert_eq!(
+            parse_local_slash_command("/attach"),
+            Some(LocalSlashCommand::Attach(None))
+        );
+        assert_eq!(
+            parse_local_slash_command("/detach"),
+            Some(LocalSlashCommand::Detach)
+        );
+    }
+    #[test]
+    fn parses_message_log_command_and_alias() {
+        assert_eq!(
+            parse_local_slash_command("/messages"),
+            Some(LocalSlashCommand::Messages)
+        );
+        assert_eq!(
+            parse_local_slash_command("/log"),
+            Some(LocalSlashCommand::Messages)
+        );
+    }
+}
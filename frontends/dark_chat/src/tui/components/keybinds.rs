@@ -1,17 +1,44 @@
-use dark_tui_components::KeyBind;
+use dark_tui_components::{KeyBind, Keymap};
 
-pub const KEY_BINDS: [KeyBind; 13] = [
-    KeyBind::new("q", "quit"),
-    KeyBind::new("tab", "focus panel"),
-    KeyBind::new("j/k", "focus nav/scroll"),
-    KeyBind::new("r", "refresh"),
-    KeyBind::new("n", "new session"),
-    KeyBind::new("a", "agent"),
-    KeyBind::new("m", "model picker"),
-    KeyBind::new("c", "compose"),
-    KeyBind::new("v", "detail popup"),
-    KeyBind::new("z", "detail mode"),
-    KeyBind::new("enter", "send; S-enter nl"),
-    KeyBind::new("esc", "cancel"),
-    KeyBind::new("h", "help"),
+use crate::tui::keymap::{ChatCommand, MAIN_MODE, pair_hint};
+
+/// Main-mode hints in key bar order; unbound commands are left out.
+const MAIN_HINTS: [(ChatCommand, &str); 12] = [
+    (ChatCommand::Quit, "quit"),
+    (ChatCommand::FocusNext, "focus panel"),
+    (ChatCommand::Down, "focus nav/scroll"),
+    (ChatCommand::Refresh, "refresh"),
+    (ChatCommand::NewSession, "new session"),
+    (ChatCommand::NextAgent, "agent"),
+    (ChatCommand::ModelPicker, "model picker"),
+    (ChatCommand::Compose, "compose"),
+    (ChatCommand::Search, "search"),
+    (ChatCommand::PromptQueue, "prompt queue"),
+    (ChatCommand::DetailPopup, "detail popup"),
+    (ChatCommand::DetailMode, "detail mode"),
 ];
+
+/// Key bar entries generated from the effective keymap.
+pub fn key_binds(keymap: &Keymap<ChatCommand>) -> Vec<KeyBind> {
+    let mut binds: Vec<KeyBind> = MAIN_HINTS
+        .iter()
+        .filter_map(|(command, action)| {
+            let key = if *command == ChatCommand::Down {
+                pair_hint(keymap, MAIN_MODE, ChatCommand::Down, ChatCommand::Up)
+            } else {
+                keymap.hint(MAIN_MODE, *command)
+            }?;
+            Some(KeyBind::owned(key, action))
+        })
+        .collect();
+
+    // Composer keys are fixed text-editing keys, not keymap commands.
+    binds.push(KeyBind::new("enter", "send; S-enter nl"));
+    for (command, action) in [(ChatCommand::Cancel, "cancel"), (ChatCommand::Help, "help")] {
+        if let Some(key) = keymap.hint(MAIN_MODE, command) {
+            binds.push(KeyBind::owned(key, action));
+        }
+    }
+
+    binds
+}
@@ -1,5 +1,5 @@
 mod keybinds;
 mod message_adapter;
 
-pub use keybinds::KEY_BINDS;
+pub use keybinds::key_binds;
 pub use message_adapter::to_component_messages;
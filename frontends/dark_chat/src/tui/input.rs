@@ -1,6 +1,15 @@
 use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 
+use dark_tui_components::{ComponentId, Event as ComponentEvent};
+
 use crate::tui::app::{App, FocusPane};
+use crate::tui::apply_overlay_actions;
+use crate::tui::keymap::{COMPOSE_MODE, ChatCommand};
+use crate::tui::overlays::{
+    AGENT_SELECTOR, MESSAGE_DETAIL_OVERLAY, MODEL_SELECTOR, QUEUE_OVERLAY, QueueRequest,
+    SEARCH_OVERLAY, SearchRequest, SelectorEdit, SelectorRequest,
+};
+use crate::tui::panes::{ComposeRequest, RunCommand};
 
 pub enum LoopAction {
     None,
@@ -19,249 +28,266 @@ pub enum LoopAction {
     ToggleHelp,
     ToggleMessageDetails,
     OpenMessageDetailPopup,
-    CloseMessageDetailPopup,
-    ScrollMessageDetailUp,
-    ScrollMessageDetailDown,
     OpenModelSelector,
+    OpenSearch,
+    JumpToSearchResult,
+    OpenPromptQueue,
+    AttachClipboardImage,
 }
 
-pub fn handle_key(app: &mut App, key: KeyEvent) -> LoopAction {
+/// Routes a key press through the component runtime: the modal overlay if
+/// one is open, else the focused pane. Ctrl+C quits from anywhere.
+pub fn route_key(app: &mut App, key: KeyEvent) -> LoopAction {
     if key.modifiers.contains(KeyModifiers::CONTROL) && key.code == KeyCode::Char('c') {
         return LoopAction::Quit;
     }
 
-    if app.message_detail_popup_open() {
-        return handle_message_detail_popup_key(key);
-    }
-
-    if app.is_model_selector_open() {
-        return handle_model_selector_key(app, key);
-    }
-
-    if app.is_agent_selector_open() {
-        return handle_agent_selector_key(app, key);
-    }
-
-    if app.is_composing() {
-        return handle_compose_key(app, key);
+    let modal = app.overlays().modal();
+    let actions = match app.overlays_mut().handle_event(&ComponentEvent::Key(key)) {
+        Ok(actions) => actions,
+        Err(error) => {
+            app.set_error_message(format!("Overlay error: {error}"));
+            return LoopAction::None;
+        }
+    };
+    if let Some(modal) = modal {
+        report_closed(app, modal);
     }
 
-    if key.code == KeyCode::Tab {
-        cycle_focus_forward(app);
-        return LoopAction::None;
+    let mut result = LoopAction::None;
+    let mut unhandled = Vec::new();
+    for action in actions {
+        if let Some(&RunCommand(command)) = action.downcast_ref() {
+            result = run_main_command(app, command);
+        } else if let Some(&request) = action.downcast_ref::<ComposeRequest>() {
+            result = apply_compose_request(app, request);
+        } else if let Some(&request) = action.downcast_ref::<SearchRequest>() {
+            result = apply_search_request(app, request);
+        } else if let Some(&request) = action.downcast_ref::<SelectorRequest>() {
+            apply_selector_request(app, request);
+        } else if let Some(&request) = action.downcast_ref::<QueueRequest>() {
+            apply_queue_request(app, request);
+        } else {
+            unhandled.push(action);
+        }
     }
+    apply_overlay_actions(app, Ok(unhandled));
+    result
+}
 
-    if key.code == KeyCode::BackTab {
-        cycle_focus_backward(app);
-        return LoopAction::None;
+/// Tidies up after the key closed `overlay` and reports it.
+fn report_closed(app: &mut App, overlay: ComponentId) {
+    if app.overlays().is_visible(overlay) {
+        return;
     }
+    let status = match overlay {
+        MESSAGE_DETAIL_OVERLAY => "Detail viewer closed.",
+        SEARCH_OVERLAY => {
+            app.close_search();
+            "Search closed."
+        }
+        QUEUE_OVERLAY => {
+            app.close_queue_panel();
+            "Prompt queue closed."
+        }
+        MODEL_SELECTOR => {
+            app.close_model_selector();
+            "Model selector closed."
+        }
+        AGENT_SELECTOR => {
+            app.close_agent_selector();
+            "Agent selector closed."
+        }
+        _ => return,
+    };
+    app.set_status_message(status);
+}
 
-    match key.code {
-        KeyCode::Char('q') => LoopAction::Quit,
-        KeyCode::Down | KeyCode::Char('j') => match app.focus() {
+fn run_main_command(app: &mut App, command: ChatCommand) -> LoopAction {
+    match command {
+        ChatCommand::Quit => LoopAction::Quit,
+        ChatCommand::FocusNext => {
+            cycle_focus_forward(app);
+            LoopAction::None
+        }
+        ChatCommand::FocusPrevious => {
+            cycle_focus_backward(app);
+            LoopAction::None
+        }
+        ChatCommand::Down => match app.focus() {
             FocusPane::Sessions => LoopAction::SelectNextSession,
             FocusPane::Chat | FocusPane::Composer => LoopAction::ScrollChatDown,
             FocusPane::Runtime => LoopAction::ScrollRuntimeDown,
         },
-        KeyCode::Up | KeyCode::Char('k') => match app.focus() {
+        ChatCommand::Up => match app.focus() {
             FocusPane::Sessions => LoopAction::SelectPreviousSession,
             FocusPane::Chat | FocusPane::Composer => LoopAction::ScrollChatUp,
             FocusPane::Runtime => LoopAction::ScrollRuntimeUp,
         },
-        KeyCode::Char('r') => LoopAction::Refresh,
-        KeyCode::Char('n') => LoopAction::CreateSession,
-        KeyCode::Char('a') => LoopAction::SelectNextAgent,
-        KeyCode::Char('m') => LoopAction::OpenModelSelector,
-        KeyCode::Char('c') => LoopAction::OpenCompose,
-        KeyCode::Esc => {
+        ChatCommand::Refresh => LoopAction::Refresh,
+        ChatCommand::NewSession => LoopAction::CreateSession,
+        ChatCommand::NextAgent => LoopAction::SelectNextAgent,
+        ChatCommand::ModelPicker => LoopAction::OpenModelSelector,
+        ChatCommand::Compose => LoopAction::OpenCompose,
+        ChatCommand::Search => LoopAction::OpenSearch,
+        ChatCommand::PromptQueue => LoopAction::OpenPromptQueue,
+        ChatCommand::Cancel => {
             app.set_focus(FocusPane::Chat);
             app.reset_chat_scroll();
             app.reset_runtime_scroll();
             LoopAction::None
         }
-        KeyCode::Char('h') => LoopAction::ToggleHelp,
-        KeyCode::Char('z') => LoopAction::ToggleMessageDetails,
-        KeyCode::Char('v') => LoopAction::OpenMessageDetailPopup,
-        KeyCode::Enter if app.is_focus(FocusPane::Chat) => LoopAction::OpenMessageDetailPopup,
-        _ => LoopAction::None,
+        ChatCommand::Help => LoopAction::ToggleHelp,
+        ChatCommand::DetailMode => LoopAction::ToggleMessageDetails,
+        ChatCommand::DetailPopup => LoopAction::OpenMessageDetailPopup,
+        ChatCommand::OpenMessage if app.is_focus(FocusPane::Chat) => {
+            LoopAction::OpenMessageDetailPopup
+        }
+        ChatCommand::OpenMessage
+        | ChatCommand::Close
+        | ChatCommand::MoveUp
+        | ChatCommand::MoveDown
+        | ChatCommand::Remove
+        | ChatCommand::Edit
+        | ChatCommand::Confirm
+        | ChatCommand::Clear
+        | ChatCommand::PageDown
+        | ChatCommand::PageUp
+        | ChatCommand::RawMode
+        | ChatCommand::Paste => LoopAction::None,
     }
 }
 
-fn handle_message_detail_popup_key(key: KeyEvent) -> LoopAction {
-    match key.code {
-        KeyCode::Esc | KeyCode::Enter | KeyCode::Char('v') => LoopAction::CloseMessageDetailPopup,
-        KeyCode::Up | KeyCode::Char('k') => LoopAction::ScrollMessageDetailUp,
-        KeyCode::Down | KeyCode::Char('j') => LoopAction::ScrollMessageDetailDown,
-        _ => LoopAction::None,
+fn apply_search_request(app: &mut App, request: SearchRequest) -> LoopAction {
+    match request {
+        SearchRequest::Insert(value) => app.search_insert_char(value),
+        SearchRequest::Backspace => app.search_backspace(),
+        SearchRequest::Clear => app.search_clear(),
+        SearchRequest::Up => app.search_move_up(),
+        SearchRequest::Down => app.search_move_down(),
+        SearchRequest::Jump => return LoopAction::JumpToSearchResult,
     }
+    LoopAction::None
 }
 
-fn handle_model_selector_key(app: &mut App, key: KeyEvent) -> LoopAction {
-    match key.code {
-        KeyCode::Esc => {
-            app.close_model_selector();
-            app.set_status_message("Model selector closed.");
-            LoopAction::None
+fn apply_queue_request(app: &mut App, request: QueueRequest) {
+    let result = match request {
+        QueueRequest::Up => {
+            app.queue_move_selection_up();
+            return;
+        }
+        QueueRequest::Down => {
+            app.queue_move_selection_down();
+            return;
         }
-        KeyCode::Tab => {
+        QueueRequest::Reorder(up) => app.queue_reorder_selected(up).map(|moved| {
+            let direction = if up { "up" } else { "down" };
+            moved.then(|| format!("Queued prompt moved {direction}."))
+        }),
+        QueueRequest::Cancel => app
+            .queue_cancel_selected()
+            .map(|removed| removed.map(|_| "Queued prompt cancelled.".to_string())),
+        QueueRequest::Edit => {
+            if app.queue_edit_selected() {
+                let keymap = app.keymap();
+                let save = keymap.hint(COMPOSE_MODE, ChatCommand::Confirm);
+                let discard = keymap.hint(COMPOSE_MODE, ChatCommand::Close);
+                app.set_status_message(format!(
+                    "Editing queued prompt. {} saves, {} discards.",
+                    save.as_deref().unwrap_or("confirm"),
+                    discard.as_deref().unwrap_or("close"),
+                ));
+            }
+            return;
+        }
+    };
+
+    match result {
+        Ok(Some(message)) => app.set_status_message(message),
+        Ok(None) => {}
+        Err(error) => app.set_error_message(format!("Prompt queue save failed: {error}")),
+    }
+}
+
+fn apply_selector_request(app: &mut App, request: SelectorRequest) {
+    if request.selector == AGENT_SELECTOR {
+        apply_agent_selector_edit(app, request.edit);
+        return;
+    }
+
+    match request.edit {
+        SelectorEdit::RawMode => {
             app.model_selector_toggle_mode();
             if app.model_selector_raw_mode() {
                 app.set_status_message("Model selector: raw input mode.");
             } else {
                 app.set_status_message("Model selector: filter mode.");
             }
-            LoopAction::None
-        }
-        KeyCode::Up | KeyCode::Char('k') => {
-            app.model_selector_move_up();
-            LoopAction::None
         }
-        KeyCode::Down | KeyCode::Char('j') => {
-            app.model_selector_move_down();
-            LoopAction::None
-        }
-        KeyCode::Enter => {
+        SelectorEdit::Up => app.model_selector_move_up(),
+        SelectorEdit::Down => app.model_selector_move_down(),
+        SelectorEdit::Confirm => {
             if let Some(model) = app.confirm_model_selector() {
                 app.set_status_message(format!("Model selected: {model}"));
             } else {
                 app.close_model_selector();
                 app.set_status_message("No model selected; model selector closed.");
             }
-            LoopAction::None
-        }
-        KeyCode::Backspace => {
-            app.model_selector_backspace();
-            LoopAction::None
-        }
-        KeyCode::Char('u') if key.modifiers.contains(KeyModifiers::CONTROL) => {
-            app.model_selector_clear();
-            LoopAction::None
-        }
-        KeyCode::Char(value)
-            if !key.modifiers.contains(KeyModifiers::CONTROL)
-                && !key.modifiers.contains(KeyModifiers::ALT) =>
-        {
-            app.model_selector_insert_char(value);
-            LoopAction::None
         }
-        _ => LoopAction::None,
+        SelectorEdit::Clear => app.model_selector_clear(),
+        SelectorEdit::Backspace => app.model_selector_backspace(),
+        SelectorEdit::Insert(value) => app.model_selector_insert_char(value),
     }
 }
 
-fn handle_agent_selector_key(app: &mut App, key: KeyEvent) -> LoopAction {
-    match key.code {
-        KeyCode::Esc => {
-            app.close_agent_selector();
-            app.set_status_message("Agent selector closed.");
-            LoopAction::None
-        }
-        KeyCode::Up | KeyCode::Char('k') => {
-            app.agent_selector_move_up();
-            LoopAction::None
-        }
-        KeyCode::Down | KeyCode::Char('j') => {
-            app.agent_selector_move_down();
-            LoopAction::None
-        }
-        KeyCode::Enter => {
+fn apply_agent_selector_edit(app: &mut App, edit: SelectorEdit) {
+    match edit {
+        SelectorEdit::Up => app.agent_selector_move_up(),
+        SelectorEdit::Down => app.agent_selector_move_down(),
+        SelectorEdit::Confirm => {
             if let Some(agent) = app.confirm_agent_selector() {
                 app.set_status_message(format!("Agent selected: {agent}"));
             } else {
                 app.close_agent_selector();
                 app.set_status_message("No agent selected; agent selector closed.");
             }
-            LoopAction::None
-        }
-        KeyCode::Backspace => {
-            app.agent_selector_backspace();
-            LoopAction::None
-        }
-        KeyCode::Char('u') if key.modifiers.contains(KeyModifiers::CONTROL) => {
-            app.agent_selector_clear();
-            LoopAction::None
-        }
-        KeyCode::Char(value)
-            if !key.modifiers.contains(KeyModifiers::CONTROL)
-                && !key.modifiers.contains(KeyModifiers::ALT) =>
-        {
-            app.agent_selector_insert_char(value);
-            LoopAction::None
         }
-        _ => LoopAction::None,
+        SelectorEdit::Clear => app.agent_selector_clear(),
+        SelectorEdit::Backspace => app.agent_selector_backspace(),
+        SelectorEdit::Insert(value) => app.agent_selector_insert_char(value),
+        SelectorEdit::RawMode => {}
     }
 }
 
-fn handle_compose_key(app: &mut App, key: KeyEvent) -> LoopAction {
-    if app.composer_autocomplete_open() {
-        match key.code {
-            KeyCode::Esc => {
-                app.close_composer_autocomplete();
-                return LoopAction::None;
-            }
-            KeyCode::Up | KeyCode::Char('k') => {
-                app.composer_autocomplete_move_up();
-                return LoopAction::None;
-            }
-            KeyCode::Down | KeyCode::Char('j') => {
-                app.composer_autocomplete_move_down();
-                return LoopAction::None;
-            }
-            KeyCode::Tab | KeyCode::Enter => {
-                let _ = app.apply_composer_autocomplete_selection();
-                return LoopAction::None;
+fn apply_compose_request(app: &mut App, request: ComposeRequest) -> LoopAction {
+    match request {
+        ComposeRequest::Insert(value) => app.insert_draft_char(value),
+        ComposeRequest::Backspace if app.draft().is_empty() && !app.attachments().is_empty() => {
+            if let Some(removed) = app.remove_last_attachment() {
+                app.set_status_message(format!("Removed attachment {}.", removed.filename));
             }
-            _ => {}
         }
-    }
-
-    match key.code {
-        KeyCode::Esc => {
+        ComposeRequest::Backspace => app.backspace_draft(),
+        ComposeRequest::Delete => app.delete_draft_char(),
+        ComposeRequest::Left => app.move_draft_cursor_left(),
+        ComposeRequest::Right => app.move_draft_cursor_right(),
+        ComposeRequest::Home => app.move_draft_cursor_home(),
+        ComposeRequest::End => app.move_draft_cursor_end(),
+        ComposeRequest::Clear => app.clear_draft(),
+        ComposeRequest::AttachClipboardImage => return LoopAction::AttachClipboardImage,
+        ComposeRequest::Send => return LoopAction::SendPrompt,
+        ComposeRequest::Cancel => {
             app.cancel_composer();
             app.set_status_message("Compose cancelled.");
-            LoopAction::None
         }
-        KeyCode::Delete => {
-            app.delete_draft_char();
-            LoopAction::None
-        }
-        KeyCode::Left => {
-            app.move_draft_cursor_left();
-            LoopAction::None
+        ComposeRequest::PreviousCompletion => app.composer_autocomplete_move_up(),
+        ComposeRequest::NextCompletion => app.composer_autocomplete_move_down(),
+        ComposeRequest::AcceptCompletion => {
+            let _ = app.apply_composer_autocomplete_selection();
         }
-        KeyCode::Right => {
-            app.move_draft_cursor_right();
-            LoopAction::None
-        }
-        KeyCode::Home => {
-            app.move_draft_cursor_home();
-            LoopAction::None
-        }
-        KeyCode::End => {
-            app.move_draft_cursor_end();
-            LoopAction::None
-        }
-        KeyCode::Enter if key.modifiers.contains(KeyModifiers::SHIFT) => {
-            app.insert_draft_char('\n');
-            LoopAction::None
-        }
-        KeyCode::Enter => LoopAction::SendPrompt,
-        KeyCode::Backspace => {
-            app.backspace_draft();
-            LoopAction::None
-        }
-        KeyCode::Char('u') if key.modifiers.contains(KeyModifiers::CONTROL) => {
-            app.clear_draft();
-            LoopAction::None
-        }
-        KeyCode::Char(value)
-            if !key.modifiers.contains(KeyModifiers::CONTROL)
-                && !key.modifiers.contains(KeyModifiers::ALT) =>
-        {
-            app.insert_draft_char(value);
-            LoopAction::None
-        }
-        _ => LoopAction::None,
+        ComposeRequest::CloseCompletions => app.close_composer_autocomplete(),
     }
+    LoopAction::None
 }
 
 fn cycle_focus_forward(app: &mut App) {
@@ -283,3 +309,39 @@ fn cycle_focus_backward(app: &mut App) {
     };
     app.set_focus(next);
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tui::panes::MAIN_PANE;
+
+    fn press(app: &mut App, code: KeyCode) -> LoopAction {
+        route_key(app, KeyEvent::new(code, KeyModifiers::NONE))
+    }
+
+    #[test]
+    fn search_popup_takes_keys_until_closed() {
+        let mut app = App::new(
+            "http://127.0.0.1:4150".to_string(),
+            "/tmp".to_string(),
+            "opencode_server".to_string(),
+            5,
+        );
+        app.open_search(None);
+
+        for code in [KeyCode::Char('a'), KeyCode::Char('b'), KeyCode::Backspace] {
+            press(&mut app, code);
+        }
+        assert_eq!(app.search_query(), "a");
+        assert!(matches!(
+            press(&mut app, KeyCode::Enter),
+            LoopAction::JumpToSearchResult
+        ));
+
+        press(&mut app, KeyCode::Esc);
+        assert!(!app.is_search_open());
+        assert_eq!(app.search_query(), "");
+        assert_eq!(app.status_message(), "Search closed.");
+        assert_eq!(app.overlays().focused(), Some(MAIN_PANE));
+    }
+}
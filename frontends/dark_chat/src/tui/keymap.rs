@@ -0,0 +1,293 @@
+use dark_tui_components::{Keymap, KeymapCommand, KeymapProfile};
+
+/// Section name of dark_chat bindings in the shared keymap file.
+pub const KEYMAP_SECTION: &str = "dark_chat";
+
+/// Sessions, chat, and runtime panes while nothing else has focus.
+pub const MAIN_MODE: &str = "main";
+/// The message detail popup.
+pub const DETAIL_MODE: &str = "detail";
+/// The prompt queue popup.
+pub const QUEUE_MODE: &str = "queue";
+/// The message search popup; unbound keys type into the query.
+pub const SEARCH_MODE: &str = "search";
+/// The model and agent selectors; unbound keys type into the filter.
+pub const PICKER_MODE: &str = "picker";
+/// The prompt composer; unbound keys edit the draft.
+pub const COMPOSE_MODE: &str = "compose";
+/// Composer completions while their list is open.
+pub const COMPLETE_MODE: &str = "complete";
+/// The message log popup.
+pub const MESSAGES_MODE: &str = "messages";
+
+const MODES: &[&str] = &[
+    MAIN_MODE,
+    DETAIL_MODE,
+    QUEUE_MODE,
+    SEARCH_MODE,
+    PICKER_MODE,
+    COMPOSE_MODE,
+    COMPLETE_MODE,
+    MESSAGES_MODE,
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChatCommand {
+    Quit,
+    FocusNext,
+    FocusPrevious,
+    Down,
+    Up,
+    Refresh,
+    NewSession,
+    NextAgent,
+    ModelPicker,
+    Compose,
+    Search,
+    PromptQueue,
+    Cancel,
+    Help,
+    DetailMode,
+    DetailPopup,
+    OpenMessage,
+    Close,
+    MoveUp,
+    MoveDown,
+    Remove,
+    Edit,
+    Confirm,
+    Clear,
+    PageDown,
+    PageUp,
+    RawMode,
+    Paste,
+}
+
+impl ChatCommand {
+    pub const ALL: &'static [ChatCommand] = &[
+        Self::Quit,
+        Self::FocusNext,
+        Self::FocusPrevious,
+        Self::Down,
+        Self::Up,
+        Self::Refresh,
+        Self::NewSession,
+        Self::NextAgent,
+        Self::ModelPicker,
+        Self::Compose,
+        Self::Search,
+        Self::PromptQueue,
+        Self::Cancel,
+        Self::Help,
+        Self::DetailMode,
+        Self::DetailPopup,
+        Self::OpenMessage,
+        Self::Close,
+        Self::MoveUp,
+        Self::MoveDown,
+        Self::Remove,
+        Self::Edit,
+        Self::Confirm,
+        Self::Clear,
+        Self::PageDown,
+        Self::PageUp,
+        Self::RawMode,
+        Self::Paste,
+    ];
+}
+
+impl KeymapCommand for ChatCommand {
+    fn name(self) -> &'static str {
+        match self {
+            Self::Quit => "quit",
+            Self::FocusNext => "focus-next",
+            Self::FocusPrevious => "focus-previous",
+            Self::Down => "down",
+            Self::Up => "up",
+            Self::Refresh => "refresh",
+            Self::NewSession => "new-session",
+            Self::NextAgent => "agent",
+            Self::ModelPicker => "model",
+            Self::Compose => "compose",
+            Self::Search => "search",
+            Self::PromptQueue => "queue",
+            Self::Cancel => "cancel",
+            Self::Help => "help",
+            Self::DetailMode => "detail-mode",
+            Self::DetailPopup => "detail-popup",
+            Self::OpenMessage => "open-message",
+            Self::Close => "close",
+            Self::MoveUp => "move-up",
+            Self::MoveDown => "move-down",
+            Self::Remove => "remove",
+            Self::Edit => "edit",
+            Self::Confirm => "confirm",
+            Self::Clear => "clear",
+            Self::PageDown => "page-down",
+            Self::PageUp => "page-up",
+            Self::RawMode => "raw-mode",
+            Self::Paste => "paste",
+        }
+    }
+
+    fn from_name(name: &str) -> Option<Self> {
+        Self::ALL
+            .iter()
+            .copied()
+            .find(|command| command.name() == name)
+    }
+}
+
+/// The defaults already use j/k wherever nothing is typed, so `vim` changes
+/// nothing; `emacs` adds C-n/C-p movement to those modes.
+impl KeymapProfile for ChatCommand {
+    const SECTION: &'static str = KEYMAP_SECTION;
+    const MODES: &'static [&'static str] = MODES;
+    const DEFAULT_BINDINGS: &'static [(&'static str, Self, &'static [&'static str])] = &[
+        (MAIN_MODE, ChatCommand::Quit, &["q"]),
+        (MAIN_MODE, ChatCommand::FocusNext, &["tab"]),
+        (MAIN_MODE, ChatCommand::FocusPrevious, &["shift+tab"]),
+        (MAIN_MODE, ChatCommand::Down, &["j", "down"]),
+        (MAIN_MODE, ChatCommand::Up, &["k", "up"]),
+        (MAIN_MODE, ChatCommand::Refresh, &["r"]),
+        (MAIN_MODE, ChatCommand::NewSession, &["n"]),
+        (MAIN_MODE, ChatCommand::NextAgent, &["a"]),
+        (MAIN_MODE, ChatCommand::ModelPicker, &["m"]),
+        (MAIN_MODE, ChatCommand::Compose, &["c"]),
+        (MAIN_MODE, ChatCommand::Search, &["/"]),
+        (MAIN_MODE, ChatCommand::PromptQueue, &["p"]),
+        (MAIN_MODE, ChatCommand::Cancel, &["esc"]),
+        (MAIN_MODE, ChatCommand::Help, &["h"]),
+        (MAIN_MODE, ChatCommand::DetailMode, &["z"]),
+        (MAIN_MODE, ChatCommand::DetailPopup, &["v"]),
+        (MAIN_MODE, ChatCommand::OpenMessage, &["enter"]),
+        (DETAIL_MODE, ChatCommand::Close, &["esc", "enter", "v"]),
+        (DETAIL_MODE, ChatCommand::Down, &["j", "down"]),
+        (DETAIL_MODE, ChatCommand::Up, &["k", "up"]),
+        (QUEUE_MODE, ChatCommand::Close, &["esc", "p"]),
+        (QUEUE_MODE, ChatCommand::Down, &["j", "down"]),
+        (QUEUE_MODE, ChatCommand::Up, &["k", "up"]),
+        (QUEUE_MODE, ChatCommand::MoveUp, &["K"]),
+        (QUEUE_MODE, ChatCommand::MoveDown, &["J"]),
+        (QUEUE_MODE, ChatCommand::Remove, &["d", "delete"]),
+        (QUEUE_MODE, ChatCommand::Edit, &["e", "enter"]),
+        (SEARCH_MODE, ChatCommand::Close, &["esc"]),
+        (SEARCH_MODE, ChatCommand::Confirm, &["enter"]),
+        (SEARCH_MODE, ChatCommand::Down, &["down"]),
+        (SEARCH_MODE, ChatCommand::Up, &["up"]),
+        (SEARCH_MODE, ChatCommand::Clear, &["ctrl+u"]),
+        (PICKER_MODE, ChatCommand::Close, &["esc"]),
+        (PICKER_MODE, ChatCommand::Confirm, &["enter"]),
+        (PICKER_MODE, ChatCommand::Down, &["down", "j"]),
+        (PICKER_MODE, ChatCommand::Up, &["up", "k"]),
+        (PICKER_MODE, ChatCommand::Clear, &["ctrl+u"]),
+        (PICKER_MODE, ChatCommand::RawMode, &["tab"]),
+        (COMPOSE_MODE, ChatCommand::Close, &["esc"]),
+        (COMPOSE_MODE, ChatCommand::Confirm, &["enter"]),
+        (COMPOSE_MODE, ChatCommand::Clear, &["ctrl+u"]),
+        (COMPOSE_MODE, ChatCommand::Paste, &["ctrl+v"]),
+        (COMPLETE_MODE, ChatCommand::Close, &["esc"]),
+        (COMPLETE_MODE, ChatCommand::Confirm, &["tab", "enter"]),
+        (COMPLETE_MODE, ChatCommand::Down, &["down", "j"]),
+        (COMPLETE_MODE, ChatCommand::Up, &["up", "k"]),
+        (MESSAGES_MODE, ChatCommand::Close, &["esc", "q"]),
+        (MESSAGES_MODE, ChatCommand::Down, &["down", "j"]),
+        (MESSAGES_MODE, ChatCommand::Up, &["up", "k"]),
+        (MESSAGES_MODE, ChatCommand::PageDown, &["pagedown"]),
+        (MESSAGES_MODE, ChatCommand::PageUp, &["pageup"]),
+        (MESSAGES_MODE, ChatCommand::Clear, &["x"]),
+    ];
+    const NAVIGATION: &'static [(&'static str, Self, Self)] = &[
+        (MAIN_MODE, ChatCommand::Down, ChatCommand::Up),
+        (DETAIL_MODE, ChatCommand::Down, ChatCommand::Up),
+        (QUEUE_MODE, ChatCommand::Down, ChatCommand::Up),
+        (PICKER_MODE, ChatCommand::Down, ChatCommand::Up),
+        (COMPLETE_MODE, ChatCommand::Down, ChatCommand::Up),
+        (MESSAGES_MODE, ChatCommand::Down, ChatCommand::Up),
+    ];
+}
+
+pub fn default_keymap() -> Keymap<ChatCommand> {
+    ChatCommand::default_keymap()
+}
+
+/// Hint for a pair of commands sharing one label, e.g. `j/k`.
+pub fn pair_hint(
+    keymap: &Keymap<ChatCommand>,
+    mode: &str,
+    first: ChatCommand,
+    second: ChatCommand,
+) -> Option<String> {
+    match (keymap.hint(mode, first), keymap.hint(mode, second)) {
+        (Some(first), Some(second)) => Some(format!("{first}/{second}")),
+        (first, second) => first.or(second),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use dark_tui_components::{KeymapCommand, parse_keymap};
+
+    use super::{
+        ChatCommand, MAIN_MODE, PICKER_MODE, QUEUE_MODE, SEARCH_MODE, default_keymap, pair_hint,
+    };
+
+    #[test]
+    fn defaults_are_conflict_free_and_names_round_trip() {
+        assert!(default_keymap().validate().is_ok());
+        for command in ChatCommand::ALL {
+            assert_eq!(ChatCommand::from_name(command.name()), Some(*command));
+        }
+    }
+
+    #[test]
+    fn overrides_are_scoped_to_their_mode_and_drive_hints() {
+        let keymap = parse_keymap::<ChatCommand>(
+            "preset = \"emacs\"\n[dark_chat.main]\nsearch = \"g /\"\n[dark_chat.queue]\nremove = \"x\"\n",
+        )
+        .expect("keymap");
+
+        assert_eq!(
+            keymap.hint(MAIN_MODE, ChatCommand::Search).as_deref(),
+            Some("g /")
+        );
+        assert_eq!(
+            keymap.hint(QUEUE_MODE, ChatCommand::Remove).as_deref(),
+            Some("x")
+        );
+        assert_eq!(
+            pair_hint(&keymap, MAIN_MODE, ChatCommand::Down, ChatCommand::Up).as_deref(),
+            Some("j/k")
+        );
+        assert!(
+            keymap
+                .sequences(QUEUE_MODE, ChatCommand::Down)
+                .any(|sequence| sequence.to_string() == "C-n")
+        );
+
+        assert!(parse_keymap::<ChatCommand>("[dark_chat.main]\nquit = \"c\"\n").is_err());
+        assert!(parse_keymap::<ChatCommand>("[dark_chat.sidebar]\nquit = \"x\"\n").is_err());
+    }
+
+    #[test]
+    fn popup_modes_rebind_and_presets_leave_typed_modes_alone() {
+        let keymap = parse_keymap::<ChatCommand>(
+            "preset = \"vim\"\n[dark_chat.picker]\nraw-mode = \"ctrl+r\"\n[dark_chat.search]\nclear = \"ctrl+w\"\n",
+        )
+        .expect("keymap");
+
+        assert_eq!(
+            keymap.hint(PICKER_MODE, ChatCommand::RawMode).as_deref(),
+            Some("C-r")
+        );
+        assert_eq!(
+            keymap.hint(SEARCH_MODE, ChatCommand::Clear).as_deref(),
+            Some("C-w")
+        );
+        assert!(
+            keymap
+                .sequences(SEARCH_MODE, ChatCommand::Down)
+                .all(|sequence| sequence.to_string() != "j")
+        );
+    }
+}
@@ -2,7 +2,10 @@ mod app;
 mod commands;
 mod components;
 mod input;
+mod keymap;
+mod overlays;
 mod panels;
+mod panes;
 mod realtime;
 mod views;
 
@@ -15,34 +18,48 @@ use std::time::{Duration, Instant};
 
 use anyhow::{Context, Result, anyhow};
 use crossterm::event::{
-    self, DisableMouseCapture, EnableMouseCapture, Event, KeyEventKind, MouseButton, MouseEventKind,
+    self, DisableBracketedPaste, DisableMouseCapture, EnableBracketedPaste, EnableMouseCapture,
+    Event, KeyEventKind, MouseButton, MouseEventKind,
 };
 use crossterm::execute;
 use crossterm::terminal::{
     EnterAlternateScreen, LeaveAlternateScreen, disable_raw_mode, enable_raw_mode,
 };
+use dark_tui_components::{
+    Action as ComponentAction, ClearToastHistory, ComponentResult, Event as ComponentEvent, Keymap,
+    ThemeSource, TickScheduler,
+};
 use ratatui::Terminal;
 use ratatui::backend::CrosstermBackend;
 use tokio::sync::mpsc::error::TryRecvError;
 
 use crate::cli::{Cli, ProviderKind};
-use crate::core::{ChatBackend, ChatSnapshot};
+use crate::core::{
+    ChatAttachment, ChatBackend, ChatMessage, ChatSession, ChatSnapshot, is_attachment_path,
+};
+use crate::framework::{
+    QueuedPrompt, collect_reference_attachments, parse_queue_directive, resolve_attachment_path,
+};
 use crate::providers::OpenCodeProvider;
 use crate::tui::app::{App, FocusPane, ResizeTarget};
 use crate::tui::commands::{
     LocalSlashCommand, build_prompt_with_file_context, parse_local_slash_command,
     parse_remote_slash_command, run_local_grep_summary,
 };
-use crate::tui::input::{LoopAction, handle_key};
+use crate::tui::input::{LoopAction, route_key};
+use crate::tui::keymap::{ChatCommand, MESSAGES_MODE, PICKER_MODE};
 use crate::tui::panels::{
-    AgentSelectorHit, ComposerAutocompleteHit, ComposerMetaHit, MessageDetailPopupHit,
-    ModelSelectorHit, SessionsPanel,
+    AgentSelectorHit, ComposerAutocompleteHit, ComposerMetaHit, ModelSelectorHit, QueuePopupHit,
+    SearchPopupHit, SessionsPanel,
 };
 use crate::tui::realtime::event_requires_refresh;
 use crate::tui::views::{MainView, PanelHit};
 
 type TuiTerminal = Terminal<CrosstermBackend<Stdout>>;
+type SessionHistories = Vec<(ChatSession, Vec<ChatMessage>)>;
 const API_TIMEOUT_SECONDS: u64 = 20;
+const OVERLAY_TICK_MILLIS: u64 = 250;
+const FRAME_MILLIS: u64 = 120;
 
 pub async fn run(cli: Cli) -> Result<()> {
     let directory = resolve_directory(cli.directory.as_deref())?;
@@ -51,35 +68,85 @@ pub async fn run(cli: Cli) -> Result<()> {
     };
     let backend = ChatBackend::new(provider, directory.clone());
 
-    let bootstrap_snapshot = run_with_api_timeout(
-        backend.bootstrap(cli.session.as_deref(), cli.session_title.as_deref()),
-    )
-    .await?;
-
     let mut app = App::new(
         cli.base_url.clone(),
         directory,
         backend.provider_name().to_string(),
         cli.refresh_seconds,
     );
-    app.apply_snapshot(bootstrap_snapshot);
+
+    // Open from the on-disk cache when possible and reconcile in the background;
+    // only a cold start has to wait for the provider.
+    let cached_snapshot = backend
+        .cached_snapshot(cli.session.as_deref())
+        .ok()
+        .flatten();
+    let (initial_refresh, connected_label) = match cached_snapshot {
+        Some(snapshot) => {
+            app.apply_snapshot(snapshot);
+            app.set_refresh_in_flight(true);
+            let backend = backend.clone();
+            let session = cli.session.clone();
+            let session_title = cli.session_title.clone();
+            let task = tokio::spawn(async move {
+                run_with_api_timeout(
+                    backend.bootstrap(session.as_deref(), session_title.as_deref()),
+                )
+                .await
+            });
+            (
+                Some(task),
+                "Opened cached session state; reconciling with OpenCode",
+            )
+        }
+        None => {
+            let bootstrap_snapshot = run_with_api_timeout(
+                backend.bootstrap(cli.session.as_deref(), cli.session_title.as_deref()),
+            )
+            .await?;
+            app.apply_snapshot(bootstrap_snapshot);
+            (None, "Connected to OpenCode")
+        }
+    };
+
     match app.restore_selection_from_disk() {
         Ok(true) => {
-            app.set_status_message("Connected to OpenCode and restored saved chat selection.");
+            app.set_status_message(format!(
+                "{connected_label} and restored saved chat selection."
+            ));
         }
         Ok(false) => {
-            app.set_status_message("Connected to OpenCode and loaded session state.");
+            app.set_status_message(format!("{connected_label} and loaded session state."));
         }
         Err(error) => {
-            app.set_status_message(format!(
-                "Connected to OpenCode; chat selection restore failed: {error}"
+            app.set_error_message(format!(
+                "{connected_label}; chat selection restore failed: {error}"
             ));
         }
     }
     app.set_realtime_supported(backend.supports_realtime());
+    match dark_tui_components::load_keymap::<ChatCommand>(cli.keymap.as_deref()) {
+        Ok(keymap) => app.set_keymap(keymap),
+        Err(error) => {
+            app.set_error_message(format!(
+                "Keymap ignored, using default keys: Dark Chat // Keymap // {error}"
+            ));
+        }
+    }
+    let theme_source = ThemeSource::resolve(cli.theme.as_deref());
+    match theme_source
+        .load()
+        .context("Dark Chat // Theme // Invalid theme")
+    {
+        Ok(theme) => app.set_theme(theme),
+        Err(error) => {
+            app.set_error_message(format!("Theme ignored, using default theme: {error:#}"));
+        }
+    }
+    app.set_theme_watcher(theme_source.watcher());
 
     let mut terminal = setup_terminal()?;
-    let run_result = run_loop(&mut terminal, &backend, &mut app).await;
+    let run_result = run_loop(&mut terminal, &backend, &mut app, initial_refresh).await;
     let restore_result = restore_terminal(&mut terminal);
 
     if let Err(error) = restore_result {
@@ -91,15 +158,33 @@ pub async fn run(cli: Cli) -> Result<()> {
     run_result
 }
 
-async fn run_loop(terminal: &mut TuiTerminal, backend: &ChatBackend, app: &mut App) -> Result<()> {
+async fn run_loop(
+    terminal: &mut TuiTerminal,
+    backend: &ChatBackend,
+    app: &mut App,
+    initial_refresh: Option<tokio::task::JoinHandle<Result<ChatSnapshot>>>,
+) -> Result<()> {
     let refresh_interval = Duration::from_secs(app.refresh_seconds().max(1));
     let mut force_refresh = false;
     let mut next_refresh_at = Instant::now() + refresh_interval;
     let mut next_realtime_retry_at = Instant::now();
+    let mut scheduler = TickScheduler::new(
+        Duration::from_millis(OVERLAY_TICK_MILLIS),
+        Duration::from_millis(FRAME_MILLIS),
+    );
 
-    let mut refresh_task: Option<tokio::task::JoinHandle<Result<ChatSnapshot>>> = None;
+    let mut refresh_task = initial_refresh;
     let mut send_task: Option<tokio::task::JoinHandle<Result<String>>> = None;
+    // Queued prompts go out only once a refresh started after the last send has
+    // landed, so the session status they depend on is never stale.
+    // Queued prompt being sent; it goes back to the queue when the send fails.
+    let mut sending_queued: Option<QueuedPrompt> = None;
+    let mut sends_completed: u64 = 0;
+    let mut sends_synced: u64 = 0;
+    let mut refresh_generation: u64 = 0;
     let mut create_task: Option<tokio::task::JoinHandle<Result<String>>> = None;
+    let mut export_task: Option<tokio::task::JoinHandle<Result<String>>> = None;
+    let mut index_task: Option<tokio::task::JoinHandle<Result<SessionHistories>>> = None;
     let mut realtime_events = backend.start_realtime_stream();
 
     loop {
@@ -131,7 +216,7 @@ async fn run_loop(terminal: &mut TuiTerminal, backend: &ChatBackend, app: &mut A
 
                         if event.event_type.starts_with("stream.error:") {
                             app.set_realtime_connected(false);
-                            app.set_status_message(format!("Realtime error: {}", event.event_type));
+                            app.set_error_message(format!("Realtime error: {}", event.event_type));
                             continue;
                         }
 
@@ -159,13 +244,30 @@ async fn run_loop(terminal: &mut TuiTerminal, backend: &ChatBackend, app: &mut A
             match task.await {
                 Ok(Ok(snapshot)) => {
                     app.apply_snapshot(snapshot);
-                    app.set_status_message("Session state refreshed.");
-                }
-                Ok(Err(error)) => {
-                    app.set_status_message(format!("Refresh failed: {error}"));
+                    sends_synced = refresh_generation;
+                    if app.is_offline() {
+                        app.set_offline(false);
+                        app.set_status_message("Reconnected; session state refreshed.");
+                    } else {
+                        app.set_status_message("Session state refreshed.");
+                    }
+                    resolve_pending_message_jump(terminal, app)?;
                 }
+                Ok(Err(error)) => match backend.cached_snapshot(app.active_session_id()) {
+                    Ok(Some(snapshot)) => {
+                        app.apply_snapshot(snapshot);
+                        app.set_offline(true);
+                        app.set_status_message(format!(
+                            "Offline (read-only, cached history): {error}"
+                        ));
+                        resolve_pending_message_jump(terminal, app)?;
+                    }
+                    _ => {
+                        app.set_error_message(format!("Refresh failed: {error}"));
+                    }
+                },
                 Err(error) => {
-                    app.set_status_message(format!("Refresh task failed: {error}"));
+                    app.set_error_message(format!("Refresh task failed: {error}"));
                 }
             }
 
@@ -177,18 +279,30 @@ async fn run_loop(terminal: &mut TuiTerminal, backend: &ChatBackend, app: &mut A
                 unreachable!("send task should exist when finished");
             };
             app.set_send_in_flight(false);
+            sends_completed += 1;
+            let queued = sending_queued.take();
+            let from_queue = queued.is_some();
 
             match task.await {
                 Ok(Ok(message)) => {
-                    app.clear_draft_after_send();
+                    if !from_queue {
+                        app.clear_draft_after_send();
+                        app.clear_attachments();
+                    }
                     app.set_status_message(message);
                     force_refresh = true;
                 }
                 Ok(Err(error)) => {
-                    app.set_status_message(format!("Prompt send failed: {error}"));
+                    app.set_error_message(format!("Prompt send failed: {error}"));
+                    if let Some(queued) = queued {
+                        requeue_failed_prompt(app, queued);
+                    }
                 }
                 Err(error) => {
-                    app.set_status_message(format!("Prompt task failed: {error}"));
+                    app.set_error_message(format!("Prompt task failed: {error}"));
+                    if let Some(queued) = queued {
+                        requeue_failed_prompt(app, queued);
+                    }
                 }
             }
         }
@@ -206,10 +320,81 @@ async fn run_loop(terminal: &mut TuiTerminal, backend: &ChatBackend, app: &mut A
                     force_refresh = true;
                 }
                 Ok(Err(error)) => {
-                    app.set_status_message(format!("Session create failed: {error}"));
+                    app.set_error_message(format!("Session create failed: {error}"));
+                }
+                Err(error) => {
+                    app.set_error_message(format!("Session create task failed: {error}"));
+                }
+            }
+        }
+
+        if export_task.as_ref().is_some_and(|task| task.is_finished()) {
+            let Some(task) = export_task.take() else {
+                unreachable!("export task should exist when finished");
+            };
+
+            match task.await {
+                Ok(Ok(message)) => app.set_status_message(message),
+                Ok(Err(error)) => {
+                    app.set_error_message(format!("Export failed: {error}"));
+                }
+                Err(error) => {
+                    app.set_error_message(format!("Export task failed: {error}"));
+                }
+            }
+        }
+
+        if index_task.as_ref().is_some_and(|task| task.is_finished()) {
+            let Some(task) = index_task.take() else {
+                unreachable!("index task should exist when finished");
+            };
+
+            match task.await {
+                Ok(Ok(histories)) => app.apply_index_updates(histories),
+                Ok(Err(error)) => {
+                    app.set_error_message(format!("Search indexing failed: {error}"));
+                }
+                Err(error) => {
+                    app.set_error_message(format!("Search index task failed: {error}"));
+                }
+            }
+        }
+
+        if index_task.is_none() && app.is_search_open() && !app.is_offline() {
+            let pending = app.sessions_needing_index();
+            if !pending.is_empty() {
+                let backend = backend.clone();
+                index_task = Some(tokio::spawn(async move {
+                    run_with_api_timeout(backend.fetch_session_histories(pending)).await
+                }));
+            }
+        }
+
+        if send_task.is_none()
+            && refresh_task.is_none()
+            && sends_synced == sends_completed
+            && !app.is_offline()
+        {
+            match app.take_due_prompt() {
+                Ok(Some(queued)) => {
+                    app.set_send_in_flight(true);
+                    app.set_status_message(format!(
+                        "Sending queued prompt to {}...",
+                        queued.target
+                    ));
+                    send_task = Some(spawn_prompt_send(
+                        backend,
+                        queued.target.clone(),
+                        queued.prompt.clone(),
+                        Vec::new(),
+                        queued.model.clone(),
+                        queued.agent.clone(),
+                    ));
+                    sending_queued = Some(queued);
                 }
+                Ok(None) => {}
                 Err(error) => {
-                    app.set_status_message(format!("Session create task failed: {error}"));
+                    app.set_error_message(format!("Prompt queue save failed: {error}"));
                 }
             }
         }
@@ -217,6 +402,7 @@ async fn run_loop(terminal: &mut TuiTerminal, backend: &ChatBackend, app: &mut A
         if refresh_task.is_none() && (force_refresh || Instant::now() >= next_refresh_at) {
             let backend = backend.clone();
             let active_session_id = app.active_session_id().map(ToString::to_string);
+            refresh_generation = sends_completed;
             app.set_refresh_in_flight(true);
             refresh_task = Some(tokio::spawn(async move {
                 run_with_api_timeout(backend.refresh(active_session_id.as_deref())).await
@@ -224,9 +410,19 @@ async fn run_loop(terminal: &mut TuiTerminal, backend: &ChatBackend, app: &mut A
             force_refresh = false;
         }
 
-        terminal.draw(|frame| MainView::render(frame, app))?;
+        if scheduler.advance(Instant::now()).tick {
+            app.toasts_mut().tick(Instant::now());
+            app.poll_theme();
+            let actions = app.overlays_mut().handle_event(&ComponentEvent::Tick);
+            apply_overlay_actions(app, actions);
+        }
 
-        if !event::poll(Duration::from_millis(120))? {
+        terminal.draw(|frame| {
+            MainView::place_overlays(frame.area(), app);
+            MainView::render(frame, app);
+        })?;
+
+        if !event::poll(scheduler.poll_timeout(Instant::now()))? {
             continue;
         }
 
@@ -244,6 +440,13 @@ async fn run_loop(terminal: &mut TuiTerminal, backend: &ChatBackend, app: &mut A
                 app,
             );
 
+            // Toasts draw above panels and popups, so they take clicks first.
+            if matches!(mouse.kind, MouseEventKind::Down(MouseButton::Left))
+                && MainView::toast_click(layout.root, app, mouse.column, mouse.row)
+            {
+                continue;
+            }
+
             if let Some(target) = app.resizing_target() {
                 match mouse.kind {
                     MouseEventKind::Drag(MouseButton::Left) => {
@@ -272,6 +475,67 @@ async fn run_loop(terminal: &mut TuiTerminal, backend: &ChatBackend, app: &mut A
                 continue;
             }
 
+            if app.is_search_open() {
+                let hit = crate::tui::panels::ChatPanel::search_popup_hit(
+                    layout.chat,
+                    app,
+                    mouse.column,
+                    mouse.row,
+                );
+
+                match mouse.kind {
+                    MouseEventKind::Down(MouseButton::Left) => match hit {
+                        SearchPopupHit::ListItem(index) => {
+                            app.search_set_selected(index);
+                            force_refresh |= jump_to_search_result(terminal, app)?;
+                        }
+                        SearchPopupHit::Popup => {}
+                        SearchPopupHit::Outside => {
+                            app.close_search();
+                            app.set_status_message("Search closed.");
+                        }
+                    },
+                    MouseEventKind::ScrollUp if hit != SearchPopupHit::Outside => {
+                        app.search_move_up();
+                    }
+                    MouseEventKind::ScrollDown if hit != SearchPopupHit::Outside => {
+                        app.search_move_down();
+                    }
+                    _ => {}
+                }
+
+                continue;
+            }
+
+            if app.is_queue_panel_open() {
+                let hit = crate::tui::panels::ChatPanel::queue_popup_hit(
+                    layout.chat,
+                    app,
+                    mouse.column,
+                    mouse.row,
+                );
+
+                match mouse.kind {
+                    MouseEventKind::Down(MouseButton::Left) => match hit {
+                        QueuePopupHit::ListItem(index) => app.queue_set_selected(index),
+                        QueuePopupHit::Popup => {}
+                        QueuePopupHit::Outside => {
+                            app.close_queue_panel();
+                            app.set_status_message("Prompt queue closed.");
+                        }
+                    },
+                    MouseEventKind::ScrollUp if hit != QueuePopupHit::Outside => {
+                        app.queue_move_selection_up();
+                    }
+                    MouseEventKind::ScrollDown if hit != QueuePopupHit::Outside => {
+                        app.queue_move_selection_down();
+                    }
+                    _ => {}
+                }
+
+                continue;
+            }
+
             if app.is_model_selector_open() {
                 let hit = crate::tui::panels::ChatPanel::model_selector_hit(
                     layout.chat,
@@ -318,33 +582,21 @@ async fn run_loop(terminal: &mut TuiTerminal, backend: &ChatBackend, app: &mut A
             }
 
             if app.message_detail_popup_open() {
-                let hit = crate::tui::panels::ChatPanel::message_detail_popup_hit(
-                    layout.chat,
-                    app,
-                    mouse.column,
-                    mouse.row,
-                );
-
-                match mouse.kind {
-                    MouseEventKind::Down(MouseButton::Left) => {
-                        if hit == MessageDetailPopupHit::Outside {
-                            app.close_message_detail_popup();
-                            app.set_status_message("Detail viewer closed.");
-                        }
-                    }
-                    MouseEventKind::ScrollUp => {
-                        if hit == MessageDetailPopupHit::Popup {
-                            app.scroll_message_detail_up(2);
-                        }
-                    }
-                    MouseEventKind::ScrollDown => {
-                        if hit == MessageDetailPopupHit::Popup {
-                            app.scroll_message_detail_down(2);
-                        }
-                    }
-                    _ => {}
+                let actions = app
+                    .overlays_mut()
+                    .handle_event(&ComponentEvent::Mouse(mouse));
+                apply_overlay_actions(app, actions);
+                if !app.message_detail_popup_open() {
+                    app.set_status_message("Detail viewer closed.");
                 }
+                continue;
+            }
 
+            if app.is_message_log_open() {
+                let actions = app
+                    .overlays_mut()
+                    .handle_event(&ComponentEvent::Mouse(mouse));
+                apply_overlay_actions(app, actions);
                 continue;
             }
 
@@ -471,9 +723,9 @@ async fn run_loop(terminal: &mut TuiTerminal, backend: &ChatBackend, app: &mut A
                             ) {
                                 ComposerMetaHit::Model => {
                                     app.open_model_selector_at(mouse.column);
-                                    app.set_status_message(
-                                        "Model selector opened. Type to filter, Tab for raw.",
-                                    );
+                                    app.set_status_message(model_selector_opened_message(
+                                        app.keymap(),
+                                    ));
                                 }
                                 ComposerMetaHit::Agent => {
                                     app.open_agent_selector_at(mouse.column);
@@ -543,6 +795,11 @@ async fn run_loop(terminal: &mut TuiTerminal, backend: &ChatBackend, app: &mut A
             continue;
         }
 
+        if let Event::Paste(text) = &ev {
+            paste_into_composer(app, text);
+            continue;
+        }
+
         let Event::Key(key) = ev else {
             continue;
         };
@@ -551,7 +808,7 @@ async fn run_loop(terminal: &mut TuiTerminal, backend: &ChatBackend, app: &mut A
             continue;
         }
 
-        let action = handle_key(app, key);
+        let action = route_key(app, key);
         match action {
             LoopAction::None => {}
             LoopAction::Quit => break,
@@ -569,6 +826,11 @@ async fn run_loop(terminal: &mut TuiTerminal, backend: &ChatBackend, app: &mut A
                 force_refresh = true;
             }
             LoopAction::CreateSession => {
+                if app.is_offline() {
+                    app.set_status_message("Offline: session create unavailable.");
+                    continue;
+                }
+
                 if create_task.is_some() {
                     app.set_status_message("Session create already in progress.");
                     continue;
@@ -591,7 +853,22 @@ async fn run_loop(terminal: &mut TuiTerminal, backend: &ChatBackend, app: &mut A
             }
             LoopAction::OpenModelSelector => {
                 app.open_model_selector();
-                app.set_status_message("Model selector opened. Type to filter, Tab for raw.");
+                app.set_status_message(model_selector_opened_message(app.keymap()));
+            }
+            LoopAction::OpenPromptQueue => {
+                if app.active_session().is_none() {
+                    app.set_status_message("Prompt queue unavailable: no active session.");
+                } else {
+                    app.open_queue_panel();
+                    app.set_status_message("Prompt queue opened.");
+                }
+            }
+            LoopAction::OpenSearch => {
+                app.open_search(None);
+                app.set_status_message("Search opened. Type to search all sessions.");
+            }
+            LoopAction::JumpToSearchResult => {
+                force_refresh |= jump_to_search_result(terminal, app)?;
             }
             LoopAction::OpenCompose => {
                 if app.active_session().is_none() {
@@ -618,7 +895,10 @@ async fn run_loop(terminal: &mut TuiTerminal, backend: &ChatBackend, app: &mut A
                     app.set_status_message("Send skipped: no active session.");
                     continue;
                 };
-                let Some(prompt) = app.take_prompt() else {
+                // Attachments alone make a valid prompt.
+                let Some(prompt) = app.take_prompt().or_else(|| {
+                    (app.is_composing() && !app.attachments().is_empty()).then(String::new)
+                }) else {
                     app.set_status_message("Send skipped: prompt is empty.");
                     continue;
                 };
@@ -636,7 +916,9 @@ async fn run_loop(terminal: &mut TuiTerminal, backend: &ChatBackend, app: &mut A
                             app.set_status_message("Refresh requested.");
                         }
                         LocalSlashCommand::CreateSession => {
-                            if create_task.is_some() {
+                            if app.is_offline() {
+                                app.set_status_message("Offline: session create unavailable.");
+                            } else if create_task.is_some() {
                                 app.set_status_message("Session create already in progress.");
                             } else {
                                 app.set_status_message("Creating session...");
@@ -677,10 +959,66 @@ async fn run_loop(terminal: &mut TuiTerminal, backend: &ChatBackend, app: &mut A
                             match run_local_grep_summary(app.directory(), &pattern) {
                                 Ok(summary) => app.set_status_message(summary),
                                 Err(error) => {
-                                    app.set_status_message(format!("Grep failed: {error}"));
+                                    app.set_error_message(format!("Grep failed: {error}"));
                                 }
                             }
                         }
+                        LocalSlashCommand::Export(target) => {
+                            if export_task.is_some() {
+                                app.set_status_message("Export already in progress.");
+                            } else if let Some(session) = app.active_session().cloned() {
+                                app.set_status_message("Exporting session transcript...");
+                                let backend = backend.clone();
+                                export_task = Some(tokio::spawn(async move {
+                                    run_with_api_timeout(
+                                        backend.export_session(&session, target.as_deref()),
+                                    )
+                                    .await
+                                }));
+                            } else {
+                                app.set_status_message("Export skipped: no active session.");
+                            }
+                        }
+                        LocalSlashCommand::Search(query) => {
+                            app.open_search(query.as_deref());
+                            app.set_status_message("Search opened. Type to search all sessions.");
+                        }
+                        LocalSlashCommand::Queue(None) => {
+                            app.open_queue_panel();
+                            app.set_status_message("Prompt queue opened.");
+                        }
+                        LocalSlashCommand::Queue(Some(directive)) => {
+                            let (delay, queued_prompt) = parse_queue_directive(&directive);
+                            if queued_prompt.is_empty() {
+                                app.set_status_message("Queue skipped: prompt is empty.");
+                            } else {
+                                match app.enqueue_prompt(&queued_prompt, delay) {
+                                    Ok(position) => app.set_status_message(format!(
+                                        "Prompt queued (#{position}) for {session_id}."
+                                    )),
+                                    Err(error) => app
+                                        .set_error_message(format!("Prompt queue failed: {error}")),
+                                }
+                            }
+                        }
+                        LocalSlashCommand::Attach(None) => {
+                            stage_attachment(app, ChatAttachment::from_clipboard_image());
+                        }
+                        LocalSlashCommand::Attach(Some(path)) => {
+                            match resolve_attachment_path(app.directory(), &path) {
+                                Some(resolved) => {
+                                    stage_attachment(app, ChatAttachment::from_path(&resolved));
+                                }
+                                None => app.set_error_message(format!(
+                                    "Attachment failed: file not found ({path})."
+                                )),
+                            }
+                        }
+                        LocalSlashCommand::Detach => {
+                            let count = app.attachments().len();
+                            app.clear_attachments();
+                            app.set_status_message(format!("Removed {count} attachments."));
+                        }
                         LocalSlashCommand::ToggleDetailExpansion => {
                             app.toggle_message_detail_expanded();
                             let mode = if app.message_detail_expanded() {
@@ -690,49 +1028,74 @@ async fn run_loop(terminal: &mut TuiTerminal, backend: &ChatBackend, app: &mut A
                             };
                             app.set_status_message(format!("Message detail mode: {mode}."));
                         }
+                        LocalSlashCommand::Messages => {
+                            app.open_message_log();
+                            let keymap = app.keymap();
+                            let close = keymap.hint(MESSAGES_MODE, ChatCommand::Close);
+                            let clear = keymap.hint(MESSAGES_MODE, ChatCommand::Clear);
+                            app.set_status_message(format!(
+                                "Message log open. {} closes, {} clears.",
+                                close.as_deref().unwrap_or("close"),
+                                clear.as_deref().unwrap_or("clear"),
+                            ));
+                        }
                     }
                     continue;
                 }
 
-                let remote_command = parse_remote_slash_command(&prompt);
+                if app.is_offline() {
+                    app.set_status_message("Offline: showing cached history; draft kept.");
+                    continue;
+                }
 
-                if send_task.is_some() {
-                    app.set_status_message("Send already in progress.");
+                if app.is_editing_queued_prompt() {
+                    match app.finish_queued_prompt_edit(&prompt) {
+                        Ok(true) => app.set_status_message("Queued prompt updated."),
+                        Ok(false) => app.set_status_message("Queued prompt no longer exists."),
+                        Err(error) => {
+                            app.set_error_message(format!("Prompt queue save failed: {error}"));
+                        }
+                    }
+                    continue;
+                }
+
+                if send_task.is_some() || app.active_session_busy() {
+                    if !app.attachments().is_empty() {
+                        app.set_status_message(
+                            "Session busy; attachments are not queued. Send again once idle.",
+                        );
+                        continue;
+                    }
+                    match app.enqueue_prompt(&prompt, None) {
+                        Ok(position) => {
+                            app.clear_draft_after_send();
+                            app.set_status_message(format!(
+                                "Session busy; prompt queued (#{position}). Press p to manage."
+                            ));
+                        }
+                        Err(error) => {
+                            app.set_error_message(format!("Prompt queue failed: {error}"));
+                        }
+                    }
                     continue;
                 }
 
                 let model = app.active_model().map(ToString::to_string);
                 let agent = app.active_agent().map(ToString::to_string);
-                let workspace_directory = backend.directory().to_string();
                 app.set_send_in_flight(true);
+                let attachments = app.attachments().to_vec();
                 app.set_status_message(format!("Sending prompt to {session_id}..."));
-                let backend = backend.clone();
-                send_task = Some(tokio::spawn(async move {
-                    if let Some(command) = remote_command {
-                        run_with_api_timeout(backend.run_command(&session_id, &command))
-                            .await
-                            .map(|_| format!("Command sent to {session_id}: /{command}"))
-                    } else {
-                        let (enriched_prompt, referenced_files) =
-                            build_prompt_with_file_context(&workspace_directory, &prompt);
-                        run_with_api_timeout(backend.send_prompt(
-                            &session_id,
-                            &enriched_prompt,
-                            model.as_deref(),
-                            agent.as_deref(),
-                        ))
-                        .await
-                        .map(|_| {
-                            if referenced_files == 0 {
-                                format!("Prompt sent to {session_id}.")
-                            } else {
-                                format!(
-                                    "Prompt sent to {session_id} with {referenced_files} @file context refs."
-                                )
-                            }
-                        })
-                    }
-                }));
+                send_task = Some(spawn_prompt_send(
+                    backend,
+                    session_id,
+                    prompt,
+                    attachments,
+                    model,
+                    agent,
+                ));
+            }
+            LoopAction::AttachClipboardImage => {
+                stage_attachment(app, ChatAttachment::from_clipboard_image());
             }
             LoopAction::ToggleHelp => {
                 app.toggle_help();
@@ -753,16 +1116,180 @@ async fn run_loop(terminal: &mut TuiTerminal, backend: &ChatBackend, app: &mut A
                     app.set_status_message("Detail viewer unavailable: no messages.");
                 }
             }
-            LoopAction::CloseMessageDetailPopup => {
-                app.close_message_detail_popup();
-                app.set_status_message("Detail viewer closed.");
-            }
-            LoopAction::ScrollMessageDetailUp => {
-                app.scroll_message_detail_up(2);
-            }
-            LoopAction::ScrollMessageDetailDown => {
-                app.scroll_message_detail_down(2);
+        }
+    }
+
+    Ok(())
+}
+
+/// Surfaces status and error actions the overlay runtime hands back.
+fn apply_overlay_actions(app: &mut App, actions: ComponentResult<Vec<ComponentAction>>) {
+    let actions = match actions {
+        Ok(actions) => actions,
+        Err(error) => {
+            app.set_error_message(format!("Overlay error: {error}"));
+            return;
+        }
+    };
+
+    for action in actions {
+        match action {
+            ComponentAction::StatusMessage(message) => app.set_status_message(message),
+            ComponentAction::Error(message) => app.set_error_message(format!("Error: {message}")),
+            ComponentAction::Custom(_) if action.downcast_ref::<ClearToastHistory>().is_some() => {
+                app.clear_message_log();
+                app.set_status_message("Message log cleared.");
             }
+            _ => {}
+        }
+    }
+}
+
+/// Stages an attachment for the next prompt and reports the outcome.
+fn model_selector_opened_message(keymap: &Keymap<ChatCommand>) -> String {
+    match keymap.hint(PICKER_MODE, ChatCommand::RawMode) {
+        Some(key) => format!("Model selector opened. Type to filter, {key} for raw."),
+        None => "Model selector opened. Type to filter.".to_string(),
+    }
+}
+
+fn stage_attachment(app: &mut App, attachment: Result<ChatAttachment>) {
+    let staged = attachment.and_then(|attachment| {
+        let label = attachment.chip_label();
+        app.add_attachment(attachment).map(|count| (label, count))
+    });
+
+    match staged {
+        Ok((label, count)) => {
+            app.set_status_message(format!("Attached {label} ({count} staged)."));
+        }
+        Err(error) => app.set_error_message(format!("Attachment failed: {error}")),
+    }
+}
+
+/// Bracketed paste: a pasted or dropped path to an image/PDF/binary becomes an
+/// attachment; anything else is inserted into the draft as text.
+fn paste_into_composer(app: &mut App, text: &str) {
+    if !app.is_composing() {
+        return;
+    }
+
+    if let Some(path) =
+        resolve_attachment_path(app.directory(), text).filter(|path| is_attachment_path(path))
+    {
+        stage_attachment(app, ChatAttachment::from_path(&path));
+        return;
+    }
+
+    for ch in text.replace("\r\n", "\n").replace('\r', "\n").chars() {
+        app.insert_draft_char(ch);
+    }
+}
+
+fn requeue_failed_prompt(app: &mut App, queued: QueuedPrompt) {
+    let queued_id = queued.id.clone();
+    match app.requeue_failed_prompt(queued) {
+        Ok(()) => log::warn!(
+            "Dark Chat // Queue // Queued prompt send failed, retrying later (meta={})",
+            serde_json::json!({ "queued": queued_id })
+        ),
+        Err(error) => app.set_error_message(format!("Prompt queue save failed: {error}")),
+    }
+}
+
+fn spawn_prompt_send(
+    backend: &ChatBackend,
+    session_id: String,
+    prompt: String,
+    staged_attachments: Vec<ChatAttachment>,
+    model: Option<String>,
+    agent: Option<String>,
+) -> tokio::task::JoinHandle<Result<String>> {
+    let remote_command = parse_remote_slash_command(&prompt);
+    let workspace_directory = backend.directory().to_string();
+    let backend = backend.clone();
+    tokio::spawn(async move {
+        if let Some(command) = remote_command {
+            run_with_api_timeout(backend.run_command(&session_id, &command))
+                .await
+                .map(|_| format!("Command sent to {session_id}: /{command}"))
+        } else {
+            let attachments =
+                collect_reference_attachments(&workspace_directory, &prompt, &staged_attachments)?;
+            let (enriched_prompt, referenced_files) =
+                build_prompt_with_file_context(&workspace_directory, &prompt);
+            run_with_api_timeout(backend.send_prompt(
+                &session_id,
+                &enriched_prompt,
+                &attachments,
+                model.as_deref(),
+                agent.as_deref(),
+            ))
+            .await
+            .map(|_| {
+                let mut extras = Vec::new();
+                if referenced_files > 0 {
+                    extras.push(format!("{referenced_files} @file context refs"));
+                }
+                if !attachments.is_empty() {
+                    extras.push(format!("{} attachments", attachments.len()));
+                }
+                if extras.is_empty() {
+                    format!("Prompt sent to {session_id}.")
+                } else {
+                    format!("Prompt sent to {session_id} with {}.", extras.join(", "))
+                }
+            })
+        }
+    })
+}
+
+/// Selects the highlighted search hit. Returns true when the hit lives in another
+/// session and a refresh is needed before the message can be scrolled into view.
+fn jump_to_search_result(terminal: &TuiTerminal, app: &mut App) -> Result<bool> {
+    let Some((hit, session_changed)) = app.confirm_search_selection() else {
+        app.set_status_message("Search: no result selected.");
+        return Ok(false);
+    };
+
+    app.set_status_message(format!("Jumping to match in {}...", hit.session_title));
+    if session_changed || app.message_position(&hit.message_id).is_none() {
+        return Ok(true);
+    }
+
+    resolve_pending_message_jump(terminal, app)?;
+    Ok(false)
+}
+
+fn resolve_pending_message_jump(terminal: &TuiTerminal, app: &mut App) -> Result<()> {
+    let Some(message_id) = app.pending_message_jump().map(ToString::to_string) else {
+        return Ok(());
+    };
+    app.clear_pending_message_jump();
+
+    let size = terminal.size()?;
+    let layout = MainView::layout(
+        ratatui::layout::Rect {
+            x: 0,
+            y: 0,
+            width: size.width,
+            height: size.height,
+        },
+        app,
+    );
+
+    let offset = app.message_position(&message_id).and_then(|index| {
+        crate::tui::panels::ChatPanel::message_scroll_offset(layout.chat, app, index)
+    });
+    match offset {
+        Some(offset) => {
+            app.set_chat_scroll_lines(offset);
+            app.set_status_message("Jumped to search match.");
+        }
+        None => {
+            app.set_status_message(
+                "Search match is older than the loaded history; use /export for the full log.",
+            );
         }
     }
 
@@ -806,8 +1333,13 @@ fn setup_terminal() -> Result<TuiTerminal> {
     enable_raw_mode().context("Dark Chat // Terminal // failed to enable raw mode")?;
 
     let mut stdout = io::stdout();
-    execute!(stdout, EnterAlternateScreen, EnableMouseCapture)
-        .context("Dark Chat // Terminal // failed to enter alternate screen")?;
+    execute!(
+        stdout,
+        EnterAlternateScreen,
+        EnableMouseCapture,
+        EnableBracketedPaste
+    )
+    .context("Dark Chat // Terminal // failed to enter alternate screen")?;
 
     let backend = CrosstermBackend::new(stdout);
     Terminal::new(backend).context("Dark Chat // Terminal // failed to create terminal")
@@ -817,6 +1349,7 @@ fn restore_terminal(terminal: &mut TuiTerminal) -> Result<()> {
     disable_raw_mode().context("Dark Chat // Terminal // failed to disable raw mode")?;
     execute!(
         terminal.backend_mut(),
+        DisableBracketedPaste,
         DisableMouseCapture,
         LeaveAlternateScreen
     )
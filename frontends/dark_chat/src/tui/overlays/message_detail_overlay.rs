@@ -0,0 +1,231 @@
+use crossterm::event::{KeyEvent, MouseEvent, MouseEventKind};
+use ratatui::Frame;
+use ratatui::layout::Rect;
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Clear, Paragraph, Wrap};
+
+use dark_tui_components::{
+    Action, Component, ComponentResult, ComponentThemeLike, KeyMatch, Keymap, PaneBlockComponent,
+    StatusPill,
+};
+
+use super::mode_keys::ModeKeys;
+use crate::tui::keymap::{ChatCommand, DETAIL_MODE, default_keymap, pair_hint};
+
+pub const MESSAGE_DETAIL_OVERLAY: &str = "message-detail";
+
+const SCROLL_STEP: u16 = 2;
+
+/// Replaces the text shown by the detail viewer.
+#[derive(Debug, Clone)]
+pub struct MessageDetailContent(pub String);
+
+/// Scrollable full view of the latest rich message.
+///
+/// Keys resolve through the configurable `detail` keymap into
+/// `ScrollUp`/`ScrollDown` actions, as wheel events do; the app resets the
+/// scroll with `ScrollToTop`. The header hints follow the dispatched keymap.
+#[derive(Debug)]
+pub struct MessageDetailOverlay {
+    content: String,
+    scroll: u16,
+    keys: ModeKeys,
+    focused: bool,
+    close_key: Option<String>,
+    scroll_keys: Option<String>,
+}
+
+impl MessageDetailOverlay {
+    pub fn new() -> Self {
+        let mut overlay = Self {
+            content: String::new(),
+            scroll: 0,
+            keys: ModeKeys::new(DETAIL_MODE),
+            focused: false,
+            close_key: None,
+            scroll_keys: None,
+        };
+        overlay.set_keys(&default_keymap());
+        overlay
+    }
+
+    fn set_keys(&mut self, keymap: &Keymap<ChatCommand>) {
+        self.close_key = keymap.hint(DETAIL_MODE, ChatCommand::Close);
+        self.scroll_keys = pair_hint(keymap, DETAIL_MODE, ChatCommand::Down, ChatCommand::Up);
+    }
+}
+
+impl Component for MessageDetailOverlay {
+    fn handle_key_event(&mut self, key: KeyEvent) -> ComponentResult<Option<Action>> {
+        Ok(match self.keys.feed(key) {
+            KeyMatch::Command(ChatCommand::Close) => Some(Action::Cancel),
+            KeyMatch::Command(ChatCommand::Up) => Some(Action::ScrollUp),
+            KeyMatch::Command(ChatCommand::Down) => Some(Action::ScrollDown),
+            _ => None,
+        })
+    }
+
+    fn handle_mouse_event(&mut self, mouse: MouseEvent) -> ComponentResult<Option<Action>> {
+        match mouse.kind {
+            MouseEventKind::ScrollUp => Ok(Some(Action::ScrollUp)),
+            MouseEventKind::ScrollDown => Ok(Some(Action::ScrollDown)),
+            _ => Ok(None),
+        }
+    }
+
+    fn update(&mut self, action: &Action) -> ComponentResult<Option<Action>> {
+        match action {
+            Action::ScrollUp => self.scroll = self.scroll.saturating_sub(SCROLL_STEP),
+            Action::ScrollDown => self.scroll = self.scroll.saturating_add(SCROLL_STEP),
+            Action::ScrollToTop => self.scroll = 0,
+            _ => {
+                if let Some(keymap) = action.downcast_ref::<Keymap<ChatCommand>>() {
+                    self.keys.update(action);
+                    self.set_keys(keymap);
+                    return Ok(Some(Action::Render));
+                }
+                let Some(MessageDetailContent(text)) = action.downcast_ref() else {
+                    return Ok(None);
+                };
+                if *text == self.content {
+                    return Ok(None);
+                }
+                self.content.clone_from(text);
+            }
+        }
+        Ok(Some(Action::Render))
+    }
+
+    fn draw(
+        &self,
+        frame: &mut Frame,
+        area: Rect,
+        theme: &dyn ComponentThemeLike,
+    ) -> ComponentResult {
+        if area.width == 0 || area.height == 0 {
+            return Ok(());
+        }
+
+        frame.render_widget(Clear, area);
+        let block = PaneBlockComponent::build("Detail Viewer", self.focused, theme);
+        let inner = block.inner(area);
+        frame.render_widget(block, area);
+
+        if inner.width < 4 || inner.height < 2 {
+            return Ok(());
+        }
+
+        let hints = [
+            (&self.close_key, " close  "),
+            (&self.scroll_keys, " scroll"),
+        ];
+        let mut lines = vec![Line::from(
+            hints
+                .into_iter()
+                .filter_map(|(key, label)| Some((key.as_deref()?, label)))
+                .flat_map(|(key, label)| {
+                    [
+                        StatusPill::muted(key, theme).span_compact(),
+                        Span::raw(label),
+                    ]
+                })
+                .collect::<Vec<_>>(),
+        )];
+        lines.push(Line::raw(""));
+        lines.extend(
+            self.content
+                .lines()
+                .map(|line| Line::from(line.to_string())),
+        );
+
+        frame.render_widget(
+            Paragraph::new(lines)
+                .wrap(Wrap { trim: false })
+                .scroll((self.scroll, 0)),
+            inner,
+        );
+        Ok(())
+    }
+
+    fn wants_focus(&self) -> bool {
+        true
+    }
+
+    fn is_focused(&self) -> bool {
+        self.focused
+    }
+
+    fn set_focused(&mut self, focused: bool) {
+        self.focused = focused;
+    }
+}
+
+/// Popup rect inside the conversation pane, or `None` when it is too small.
+pub fn message_detail_area(conversation_area: Rect) -> Option<Rect> {
+    if conversation_area.width < 24 || conversation_area.height < 10 {
+        return None;
+    }
+
+    let width = (conversation_area.width.saturating_mul(9) / 10).max(24);
+    let height = (conversation_area.height.saturating_mul(8) / 10).max(10);
+    let width = width.min(conversation_area.width.saturating_sub(2));
+    let height = height.min(conversation_area.height.saturating_sub(2));
+
+    Some(Rect {
+        x: conversation_area
+            .x
+            .saturating_add((conversation_area.width.saturating_sub(width)) / 2),
+        y: conversation_area
+            .y
+            .saturating_add((conversation_area.height.saturating_sub(height)) / 2),
+        width,
+        height,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use dark_tui_components::{ComponentRuntime, Layer};
+
+    use super::*;
+
+    #[test]
+    fn detail_overlay_takes_content_and_scrolls_from_the_bus() {
+        let mut runtime = ComponentRuntime::new();
+        runtime
+            .mount(
+                MESSAGE_DETAIL_OVERLAY,
+                Layer::Overlay,
+                Box::new(MessageDetailOverlay::new()),
+            )
+            .expect("mount detail overlay");
+        runtime.show(MESSAGE_DETAIL_OVERLAY);
+        assert_eq!(runtime.modal(), Some(MESSAGE_DETAIL_OVERLAY));
+        assert!(runtime.take_render_request());
+
+        runtime
+            .dispatch(Action::custom(MessageDetailContent(
+                "### Tool // read".into(),
+            )))
+            .expect("content dispatched");
+        assert!(runtime.take_render_request());
+
+        // Unchanged content does not force a redraw.
+        runtime
+            .dispatch(Action::custom(MessageDetailContent(
+                "### Tool // read".into(),
+            )))
+            .expect("content dispatched");
+        assert!(!runtime.take_render_request());
+
+        runtime.dispatch(Action::ScrollDown).expect("scrolled");
+        assert!(runtime.take_render_request());
+    }
+
+    #[test]
+    fn detail_area_centers_inside_the_conversation() {
+        let area = message_detail_area(Rect::new(0, 0, 100, 40)).expect("fits");
+        assert_eq!(area, Rect::new(5, 4, 90, 32));
+        assert!(message_detail_area(Rect::new(0, 0, 20, 8)).is_none());
+    }
+}
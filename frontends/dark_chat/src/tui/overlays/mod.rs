@@ -0,0 +1,77 @@
+//! Popups driven by the shared component runtime.
+//!
+//! Each overlay is a `dark_tui_components::Component` mounted on the app's
+//! `ComponentRuntime`. The app shows and hides it, the run loop places it and
+//! routes keys and mouse events to it while it is modal, and `MainView` draws
+//! the runtime last so overlays sit above every panel. Keys resolve through
+//! the overlay's keymap mode (see `ModeKeys`); `close` answers `Action::Cancel`,
+//! which hides it. The search, selector and queue popups are drawn by the chat
+//! panel from app state, so they only answer requests the run loop applies.
+
+mod message_detail_overlay;
+mod mode_keys;
+mod queue_overlay;
+mod search_overlay;
+mod selector_overlay;
+
+use dark_tui_components::{
+    ComponentResult, ComponentRuntime, KeymapProfile, Layer, ToastHistoryCommand,
+    ToastHistoryOverlay,
+};
+
+use crate::tui::keymap::{ChatCommand, MESSAGES_MODE};
+
+pub use message_detail_overlay::{
+    MESSAGE_DETAIL_OVERLAY, MessageDetailContent, MessageDetailOverlay, message_detail_area,
+};
+pub use mode_keys::{ModeKeys, typed_char};
+pub use queue_overlay::{QUEUE_OVERLAY, QueueOverlay, QueueRequest};
+pub use search_overlay::{SEARCH_OVERLAY, SearchOverlay, SearchRequest};
+pub use selector_overlay::{
+    AGENT_SELECTOR, MODEL_SELECTOR, SelectorEdit, SelectorOverlay, SelectorRequest,
+};
+
+/// Toast history, fed the app's `ToastStack` while it is open.
+pub const MESSAGE_LOG_OVERLAY: &str = "message-log";
+
+/// Message log actions for the commands bound in the `messages` mode.
+const MESSAGE_LOG_COMMANDS: &[(ChatCommand, ToastHistoryCommand)] = &[
+    (ChatCommand::Close, ToastHistoryCommand::Close),
+    (ChatCommand::Down, ToastHistoryCommand::Down),
+    (ChatCommand::Up, ToastHistoryCommand::Up),
+    (ChatCommand::PageDown, ToastHistoryCommand::PageDown),
+    (ChatCommand::PageUp, ToastHistoryCommand::PageUp),
+    (ChatCommand::Clear, ToastHistoryCommand::Clear),
+];
+
+/// Mounts every runtime overlay; they start hidden.
+pub fn mount_overlays(runtime: &mut ComponentRuntime) -> ComponentResult {
+    runtime.mount(
+        MESSAGE_DETAIL_OVERLAY,
+        Layer::Overlay,
+        Box::new(MessageDetailOverlay::new()),
+    )?;
+    runtime.mount(
+        MESSAGE_LOG_OVERLAY,
+        Layer::Overlay,
+        Box::new(ToastHistoryOverlay::new(
+            ChatCommand::default_keymap(),
+            MESSAGES_MODE,
+            MESSAGE_LOG_COMMANDS,
+        )),
+    )?;
+    runtime.mount(
+        SEARCH_OVERLAY,
+        Layer::Overlay,
+        Box::new(SearchOverlay::new()),
+    )?;
+    runtime.mount(QUEUE_OVERLAY, Layer::Overlay, Box::new(QueueOverlay::new()))?;
+    for selector in [MODEL_SELECTOR, AGENT_SELECTOR] {
+        runtime.mount(
+            selector,
+            Layer::Overlay,
+            Box::new(SelectorOverlay::new(selector)),
+        )?;
+    }
+    Ok(())
+}
@@ -0,0 +1,57 @@
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use dark_tui_components::{Action, KeyMatch, KeySequenceMatcher, Keymap};
+
+use crate::tui::keymap::{ChatCommand, default_keymap};
+
+/// A component's keymap mode. Starts on the default keys; the app dispatches
+/// the loaded `Keymap<ChatCommand>` to every component, which hands it here.
+#[derive(Debug)]
+pub struct ModeKeys {
+    keymap: Keymap<ChatCommand>,
+    mode: &'static str,
+    matcher: KeySequenceMatcher,
+}
+
+impl ModeKeys {
+    pub fn new(mode: &'static str) -> Self {
+        Self {
+            keymap: default_keymap(),
+            mode,
+            matcher: KeySequenceMatcher::default(),
+        }
+    }
+
+    /// Resolves `key`; unbound keys come back as `NoMatch` so text fields can
+    /// type them, and a pending sequence prefix is neither run nor typed.
+    pub fn feed(&mut self, key: KeyEvent) -> KeyMatch<ChatCommand> {
+        self.matcher.feed(&self.keymap, self.mode, key)
+    }
+
+    /// Keys typed so far toward a multi-key binding.
+    pub fn pending(&self) -> Option<String> {
+        self.matcher.pending()
+    }
+
+    /// Takes the keymap if `action` carries one.
+    pub fn update(&mut self, action: &Action) -> bool {
+        let Some(keymap) = action.downcast_ref::<Keymap<ChatCommand>>() else {
+            return false;
+        };
+        self.keymap = keymap.clone();
+        self.matcher.reset();
+        true
+    }
+}
+
+/// Character a key types into a text field; modified chords type nothing.
+pub fn typed_char(key: KeyEvent) -> Option<char> {
+    match key.code {
+        KeyCode::Char(value)
+            if !key.modifiers.contains(KeyModifiers::CONTROL)
+                && !key.modifiers.contains(KeyModifiers::ALT) =>
+        {
+            Some(value)
+        }
+        _ => None,
+    }
+}
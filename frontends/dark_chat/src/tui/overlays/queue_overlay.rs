@@ -0,0 +1,84 @@
+use crossterm::event::KeyEvent;
+use ratatui::Frame;
+use ratatui::layout::Rect;
+
+use dark_tui_components::{Action, Component, ComponentResult, ComponentThemeLike, KeyMatch};
+
+use super::mode_keys::ModeKeys;
+use crate::tui::keymap::{ChatCommand, QUEUE_MODE};
+
+pub const QUEUE_OVERLAY: &str = "prompt-queue";
+
+/// Change the app makes to the active session's prompt queue.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum QueueRequest {
+    Up,
+    Down,
+    /// Moves the selected prompt one slot earlier (`true`) or later.
+    Reorder(bool),
+    Cancel,
+    /// Loads the selected prompt into the composer.
+    Edit,
+}
+
+/// Prompt queue popup while it is open. The chat panel draws the queue; this
+/// resolves the `queue` keys.
+#[derive(Debug)]
+pub struct QueueOverlay {
+    keys: ModeKeys,
+    focused: bool,
+}
+
+impl QueueOverlay {
+    pub fn new() -> Self {
+        Self {
+            keys: ModeKeys::new(QUEUE_MODE),
+            focused: false,
+        }
+    }
+}
+
+impl Component for QueueOverlay {
+    fn handle_key_event(&mut self, key: KeyEvent) -> ComponentResult<Option<Action>> {
+        let KeyMatch::Command(command) = self.keys.feed(key) else {
+            return Ok(None);
+        };
+        let request = match command {
+            ChatCommand::Close => return Ok(Some(Action::Cancel)),
+            ChatCommand::Up => QueueRequest::Up,
+            ChatCommand::Down => QueueRequest::Down,
+            ChatCommand::MoveUp => QueueRequest::Reorder(true),
+            ChatCommand::MoveDown => QueueRequest::Reorder(false),
+            ChatCommand::Remove => QueueRequest::Cancel,
+            ChatCommand::Edit => QueueRequest::Edit,
+            _ => return Ok(None),
+        };
+        Ok(Some(Action::custom(request)))
+    }
+
+    fn update(&mut self, action: &Action) -> ComponentResult<Option<Action>> {
+        self.keys.update(action);
+        Ok(None)
+    }
+
+    fn draw(
+        &self,
+        _frame: &mut Frame,
+        _area: Rect,
+        _theme: &dyn ComponentThemeLike,
+    ) -> ComponentResult {
+        Ok(())
+    }
+
+    fn wants_focus(&self) -> bool {
+        true
+    }
+
+    fn is_focused(&self) -> bool {
+        self.focused
+    }
+
+    fn set_focused(&mut self, focused: bool) {
+        self.focused = focused;
+    }
+}
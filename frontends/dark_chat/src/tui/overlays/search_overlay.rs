@@ -0,0 +1,84 @@
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::Frame;
+use ratatui::layout::Rect;
+
+use dark_tui_components::{Action, Component, ComponentResult, ComponentThemeLike, KeyMatch};
+
+use super::mode_keys::{ModeKeys, typed_char};
+use crate::tui::keymap::{ChatCommand, SEARCH_MODE};
+
+pub const SEARCH_OVERLAY: &str = "message-search";
+
+/// Edit the app makes to the search it owns, which indexes every session.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SearchRequest {
+    Insert(char),
+    Backspace,
+    Clear,
+    Up,
+    Down,
+    /// Opens the selected hit's session at the message.
+    Jump,
+}
+
+/// Message search popup while it is open. The chat panel draws the query and
+/// hits; this resolves the `search` keys and closes on `close`.
+#[derive(Debug)]
+pub struct SearchOverlay {
+    keys: ModeKeys,
+    focused: bool,
+}
+
+impl SearchOverlay {
+    pub fn new() -> Self {
+        Self {
+            keys: ModeKeys::new(SEARCH_MODE),
+            focused: false,
+        }
+    }
+}
+
+impl Component for SearchOverlay {
+    fn handle_key_event(&mut self, key: KeyEvent) -> ComponentResult<Option<Action>> {
+        let request = match self.keys.feed(key) {
+            KeyMatch::Command(ChatCommand::Close) => return Ok(Some(Action::Cancel)),
+            KeyMatch::Command(ChatCommand::Up) => SearchRequest::Up,
+            KeyMatch::Command(ChatCommand::Down) => SearchRequest::Down,
+            KeyMatch::Command(ChatCommand::Confirm) => SearchRequest::Jump,
+            KeyMatch::Command(ChatCommand::Clear) => SearchRequest::Clear,
+            KeyMatch::Command(_) | KeyMatch::Pending => return Ok(None),
+            KeyMatch::NoMatch if key.code == KeyCode::Backspace => SearchRequest::Backspace,
+            KeyMatch::NoMatch => match typed_char(key) {
+                Some(value) => SearchRequest::Insert(value),
+                None => return Ok(None),
+            },
+        };
+        Ok(Some(Action::custom(request)))
+    }
+
+    fn update(&mut self, action: &Action) -> ComponentResult<Option<Action>> {
+        self.keys.update(action);
+        Ok(None)
+    }
+
+    fn draw(
+        &self,
+        _frame: &mut Frame,
+        _area: Rect,
+        _theme: &dyn ComponentThemeLike,
+    ) -> ComponentResult {
+        Ok(())
+    }
+
+    fn wants_focus(&self) -> bool {
+        true
+    }
+
+    fn is_focused(&self) -> bool {
+        self.focused
+    }
+
+    fn set_focused(&mut self, focused: bool) {
+        self.focused = focused;
+    }
+}
@@ -0,0 +1,102 @@
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::Frame;
+use ratatui::layout::Rect;
+
+use dark_tui_components::{
+    Action, Component, ComponentId, ComponentResult, ComponentThemeLike, KeyMatch,
+};
+
+use super::mode_keys::{ModeKeys, typed_char};
+use crate::tui::keymap::{ChatCommand, PICKER_MODE};
+
+pub const MODEL_SELECTOR: &str = "model-selector";
+pub const AGENT_SELECTOR: &str = "agent-selector";
+
+/// Edit the app makes to the filter and selection of `selector`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SelectorRequest {
+    pub selector: ComponentId,
+    pub edit: SelectorEdit,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SelectorEdit {
+    Insert(char),
+    Backspace,
+    Clear,
+    Up,
+    Down,
+    Confirm,
+    /// Switches the model selector between filtering and raw input.
+    RawMode,
+}
+
+/// Model or agent selector while it is open, anchored to the composer. The
+/// chat panel draws the list; this resolves the `picker` keys.
+#[derive(Debug)]
+pub struct SelectorOverlay {
+    id: ComponentId,
+    keys: ModeKeys,
+    focused: bool,
+}
+
+impl SelectorOverlay {
+    pub fn new(id: ComponentId) -> Self {
+        Self {
+            id,
+            keys: ModeKeys::new(PICKER_MODE),
+            focused: false,
+        }
+    }
+}
+
+impl Component for SelectorOverlay {
+    fn handle_key_event(&mut self, key: KeyEvent) -> ComponentResult<Option<Action>> {
+        let edit = match self.keys.feed(key) {
+            KeyMatch::Command(ChatCommand::Close) => return Ok(Some(Action::Cancel)),
+            KeyMatch::Command(ChatCommand::Up) => SelectorEdit::Up,
+            KeyMatch::Command(ChatCommand::Down) => SelectorEdit::Down,
+            KeyMatch::Command(ChatCommand::Confirm) => SelectorEdit::Confirm,
+            KeyMatch::Command(ChatCommand::Clear) => SelectorEdit::Clear,
+            KeyMatch::Command(ChatCommand::RawMode) if self.id == MODEL_SELECTOR => {
+                SelectorEdit::RawMode
+            }
+            KeyMatch::Command(_) | KeyMatch::Pending => return Ok(None),
+            KeyMatch::NoMatch if key.code == KeyCode::Backspace => SelectorEdit::Backspace,
+            KeyMatch::NoMatch => match typed_char(key) {
+                Some(value) => SelectorEdit::Insert(value),
+                None => return Ok(None),
+            },
+        };
+        Ok(Some(Action::custom(SelectorRequest {
+            selector: self.id,
+            edit,
+        })))
+    }
+
+    fn update(&mut self, action: &Action) -> ComponentResult<Option<Action>> {
+        self.keys.update(action);
+        Ok(None)
+    }
+
+    fn draw(
+        &self,
+        _frame: &mut Frame,
+        _area: Rect,
+        _theme: &dyn ComponentThemeLike,
+    ) -> ComponentResult {
+        Ok(())
+    }
+
+    fn wants_focus(&self) -> bool {
+        true
+    }
+
+    fn is_focused(&self) -> bool {
+        self.focused
+    }
+
+    fn set_focused(&mut self, focused: bool) {
+        self.focused = focused;
+    }
+}
@@ -1,18 +1,19 @@
 use ratatui::Frame;
 use ratatui::layout::{Constraint, Direction, Layout, Rect};
-use ratatui::style::Color;
 use ratatui::text::{Line, Span};
-use ratatui::widgets::{Clear, Paragraph, Wrap};
+use ratatui::widgets::{Paragraph, Wrap};
 
 use dark_tui_components::{
-    ChatConversationHeaderComponent, ChatConversationHeaderProps, ChatMessageListComponent,
-    ChatMessageListProps, ChatPalette, ChatStatusTone, ComponentThemeLike, KeyBind,
-    PaneBlockComponent, PopupAnchor, PopupHit, PopupItem, PopupOverlay, PopupOverlayProps,
-    StatusPill, compact_text, rect_contains,
+    ChatComposerComponent, ChatConversationHeaderComponent, ChatConversationHeaderProps,
+    ChatMessageEntry, ChatMessageListComponent, ChatMessageListProps, ChatPalette, ChatStatusTone,
+    ComponentThemeLike, KeyBind, PaneBlockComponent, PopupAnchor, PopupHit, PopupItem,
+    PopupOverlay, PopupOverlayProps, StatusPill, compact_text, rect_contains,
 };
 
+use crate::framework::now_unix;
 use crate::tui::app::{App, FocusPane};
 use crate::tui::components::to_component_messages;
+use crate::tui::keymap::{ChatCommand, MAIN_MODE};
 
 pub struct ChatPanel;
 
@@ -47,9 +48,17 @@ pub enum ComposerMetaHit {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub enum MessageDetailPopupHit {
+pub enum SearchPopupHit {
     Outside,
     Popup,
+    ListItem(usize),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum QueuePopupHit {
+    Outside,
+    Popup,
+    ListItem(usize),
 }
 
 const COMPOSER_PANEL_HEIGHT: u16 = 5;
@@ -69,14 +78,7 @@ impl ChatPanel {
             return;
         }
 
-        let rows = Layout::default()
-            .direction(Direction::Vertical)
-            .constraints([
-                Constraint::Length(3),
-                Constraint::Min(4),
-                Constraint::Length(COMPOSER_PANEL_HEIGHT),
-            ])
-            .split(inner);
+        let rows = conversation_rows(inner);
 
         let header = match app.active_session() {
             Some(session) => ChatConversationHeaderProps {
@@ -99,27 +101,71 @@ impl ChatPanel {
         render_model_selector_popup(frame, inner, rows[2], app, theme);
         render_agent_selector_popup(frame, inner, rows[2], app, theme);
         render_composer_autocomplete_popup(frame, inner, rows[2], app, theme);
-        render_message_detail_popup(frame, inner, app, theme);
+        render_search_popup(frame, inner, app, theme);
+        render_queue_popup(frame, inner, app, theme);
+    }
+
+    /// Scroll offset that brings `message_index` to the top of the messages pane, or
+    /// `None` when the message is outside the rendered window.
+    pub fn message_scroll_offset(
+        conversation_area: Rect,
+        app: &App,
+        message_index: usize,
+    ) -> Option<u16> {
+        let area = messages_inner_area(conversation_area, app)?;
+        let component_messages = to_component_messages(app.messages());
+        let props = message_list_props(&component_messages, app, app.theme());
+
+        ChatMessageListComponent::scroll_offset_for_message(
+            app.theme(),
+            &props,
+            area.width as usize,
+            area.height as usize,
+            message_index,
+        )
     }
 
-    pub fn message_detail_popup_hit(
+    pub fn search_popup_hit(
         conversation_area: Rect,
         app: &App,
         col: u16,
         row: u16,
-    ) -> MessageDetailPopupHit {
-        if !app.message_detail_popup_open() {
-            return MessageDetailPopupHit::Outside;
+    ) -> SearchPopupHit {
+        let Some(inner) = conversation_inner_area(conversation_area, app) else {
+            return SearchPopupHit::Outside;
+        };
+        let Some(props) = search_popup_props(inner, app) else {
+            return SearchPopupHit::Outside;
+        };
+
+        match PopupOverlay::hit_test(inner, &props, col, row) {
+            PopupHit::Outside => SearchPopupHit::Outside,
+            PopupHit::ListItem(index) if index < app.search_results().len() => {
+                SearchPopupHit::ListItem(index)
+            }
+            PopupHit::ListItem(_) | PopupHit::Popup | PopupHit::Query => SearchPopupHit::Popup,
         }
+    }
 
-        let Some(popup) = message_detail_popup_area(conversation_area) else {
-            return MessageDetailPopupHit::Outside;
+    pub fn queue_popup_hit(
+        conversation_area: Rect,
+        app: &App,
+        col: u16,
+        row: u16,
+    ) -> QueuePopupHit {
+        let Some(inner) = conversation_inner_area(conversation_area, app) else {
+            return QueuePopupHit::Outside;
+        };
+        let Some(props) = queue_popup_props(inner, app) else {
+            return QueuePopupHit::Outside;
         };
 
-        if rect_contains(popup, col, row) {
-            MessageDetailPopupHit::Popup
-        } else {
-            MessageDetailPopupHit::Outside
+        match PopupOverlay::hit_test(inner, &props, col, row) {
+            PopupHit::Outside => QueuePopupHit::Outside,
+            PopupHit::ListItem(index) if index < app.queued_prompts().len() => {
+                QueuePopupHit::ListItem(index)
+            }
+            PopupHit::ListItem(_) | PopupHit::Popup | PopupHit::Query => QueuePopupHit::Popup,
         }
     }
 
@@ -251,22 +297,66 @@ fn render_messages(frame: &mut Frame, area: Rect, app: &App, theme: &impl Compon
     }
 
     let component_messages = to_component_messages(app.messages());
-    let message_list = ChatMessageListProps {
-        messages: &component_messages,
+    let message_list = message_list_props(&component_messages, app, theme);
+    ChatMessageListComponent::render(frame, inner, theme, message_list);
+}
+
+fn message_list_props<'a>(
+    messages: &'a [ChatMessageEntry],
+    app: &App,
+    theme: &impl ComponentThemeLike,
+) -> ChatMessageListProps<'a> {
+    ChatMessageListProps {
+        messages,
         empty_label: "No messages yet. Send a prompt to begin.",
         max_messages: 80,
         max_body_lines_per_message: app.chat_message_body_line_limit(),
         scroll_offset_lines: app.chat_scroll_lines(),
         palette: ChatPalette {
-            text_primary: Color::White,
+            text_primary: theme.text_primary(),
             role_user: theme.pill_info_fg(),
             role_assistant: theme.pill_accent_fg(),
             role_system: theme.pill_warn_fg(),
             role_tool: theme.pill_ok_fg(),
             role_other: theme.text_secondary(),
         },
-    };
-    ChatMessageListComponent::render(frame, inner, theme, message_list);
+    }
+}
+
+fn conversation_rows(inner: Rect) -> std::rc::Rc<[Rect]> {
+    Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(3),
+            Constraint::Min(4),
+            Constraint::Length(COMPOSER_PANEL_HEIGHT),
+        ])
+        .split(inner)
+}
+
+fn conversation_inner_area(conversation_area: Rect, app: &App) -> Option<Rect> {
+    let block = PaneBlockComponent::build("Conversation", false, app.theme());
+    let inner = block.inner(conversation_area);
+    if inner.width < 18 || inner.height < 8 {
+        return None;
+    }
+
+    Some(inner)
+}
+
+fn messages_inner_area(conversation_area: Rect, app: &App) -> Option<Rect> {
+    let inner = conversation_inner_area(conversation_area, app)?;
+    let area = conversation_rows(inner)[1];
+    if area.width < 6 || area.height < 2 {
+        return None;
+    }
+
+    let messages = PaneBlockComponent::build("Messages", false, app.theme()).inner(area);
+    if messages.width < 4 || messages.height < 1 {
+        return None;
+    }
+
+    Some(messages)
 }
 
 fn render_composer_panel(
@@ -290,25 +380,34 @@ fn render_composer_panel(
 
     // ── Meta pills: model + agent with tinted backgrounds ──────────
     frame.render_widget(
-        Paragraph::new(Line::from(vec![
-            StatusPill::accent(
-                format!(
-                    "model:{}",
-                    compact_text(app.active_model().unwrap_or("-"), 28)
-                ),
-                theme,
-            )
-            .span(),
-            Span::raw("  "),
-            StatusPill::info(
-                format!(
-                    "agent:{}",
-                    compact_text(app.active_agent().unwrap_or("-"), 20)
-                ),
+        Paragraph::new(Line::from(
+            vec![
+                StatusPill::accent(
+                    format!(
+                        "model:{}",
+                        compact_text(app.active_model().unwrap_or("-"), 28)
+                    ),
+                    theme,
+                )
+                .span(),
+                Span::raw("  "),
+                StatusPill::info(
+                    format!(
+                        "agent:{}",
+                        compact_text(app.active_agent().unwrap_or("-"), 20)
+                    ),
+                    theme,
+                )
+                .span(),
+                Span::raw("  "),
+            ]
+            .into_iter()
+            .chain(ChatComposerComponent::attachment_spans(
                 theme,
-            )
-            .span(),
-        ]))
+                &attachment_labels(app),
+            ))
+            .collect::<Vec<_>>(),
+        ))
         .wrap(Wrap { trim: true }),
         rows[0],
     );
@@ -326,14 +425,23 @@ fn render_composer_panel(
 
     if ready {
         spans.push(StatusPill::ok("ready", theme).span());
-        spans.push(Span::raw("  "));
-        spans.extend(KeyBind::new("c", "compose").spans(theme));
+        let queued = app.queued_prompts().len();
+        if queued > 0 {
+            spans.push(Span::raw("  "));
+            spans.push(StatusPill::info(format!("queued:{queued}"), theme).span());
+        }
+        if let Some(key) = app.keymap().hint(MAIN_MODE, ChatCommand::Compose) {
+            spans.push(Span::raw("  "));
+            spans.extend(KeyBind::owned(key, "compose").spans(theme));
+        }
         spans.push(Span::raw("  "));
         spans.extend(KeyBind::new("enter", "send").spans(theme));
     } else {
         spans.push(StatusPill::warn("no session", theme).span());
-        spans.push(Span::raw("  "));
-        spans.extend(KeyBind::new("n", "new session").spans(theme));
+        if let Some(key) = app.keymap().hint(MAIN_MODE, ChatCommand::NewSession) {
+            spans.push(Span::raw("  "));
+            spans.extend(KeyBind::owned(key, "new session").spans(theme));
+        }
     }
 
     frame.render_widget(
@@ -342,6 +450,13 @@ fn render_composer_panel(
     );
 }
 
+fn attachment_labels(app: &App) -> Vec<String> {
+    app.attachments()
+        .iter()
+        .map(|attachment| compact_text(&attachment.chip_label(), 28))
+        .collect()
+}
+
 fn render_model_selector_popup(
     frame: &mut Frame,
     conversation_area: Rect,
@@ -371,69 +486,126 @@ fn render_composer_autocomplete_popup(
     PopupOverlay::render(frame, conversation_area, &props, theme);
 }
 
-fn render_message_detail_popup(
+fn render_search_popup(
     frame: &mut Frame,
     conversation_area: Rect,
     app: &App,
     theme: &impl ComponentThemeLike,
 ) {
-    if !app.message_detail_popup_open() {
+    let Some(props) = search_popup_props(conversation_area, app) else {
         return;
+    };
+
+    PopupOverlay::render(frame, conversation_area, &props, theme);
+}
+
+fn search_popup_props(conversation_area: Rect, app: &App) -> Option<PopupOverlayProps> {
+    if !app.is_search_open() {
+        return None;
     }
 
-    let Some(content) = app.message_detail_popup_text() else {
-        return;
-    };
+    if conversation_area.width < 28 || conversation_area.height < 8 {
+        return None;
+    }
 
-    let Some(area) = message_detail_popup_area(conversation_area) else {
-        return;
+    let max_width = conversation_area.width.saturating_sub(2);
+    let label_width = (max_width as usize).saturating_sub(28).clamp(16, 72);
+
+    let items = if app.search_results().is_empty() {
+        let label = if app.search_query().trim().is_empty() {
+            format!("type to search {} messages", app.indexed_message_count())
+        } else {
+            "no matches".to_string()
+        };
+        vec![PopupItem {
+            label,
+            tag: None,
+            active: false,
+        }]
+    } else {
+        app.search_results()
+            .iter()
+            .map(|hit| PopupItem {
+                label: compact_text(&format!("{}: {}", hit.role, hit.snippet), label_width),
+                tag: Some(compact_text(&hit.session_title, 20)),
+                active: app.active_session_id() == Some(hit.session_id.as_str()),
+            })
+            .collect()
     };
 
-    frame.render_widget(Clear, area);
-    let block = PaneBlockComponent::build("Detail Viewer", true, theme);
-    let inner = block.inner(area);
-    frame.render_widget(block, area);
+    Some(PopupOverlayProps {
+        title: format!("Search // {} results", app.search_results().len()),
+        items,
+        selected: app.search_selected(),
+        query: Some(app.search_query().to_string()),
+        query_label: Some("FIND".to_string()),
+        hint: Some("enter jump  up/down move  esc close".to_string()),
+        anchor: PopupAnchor::Center,
+        max_visible: 10,
+        min_width: 36,
+        max_width,
+    })
+}
 
-    if inner.width < 4 || inner.height < 2 {
+fn render_queue_popup(
+    frame: &mut Frame,
+    conversation_area: Rect,
+    app: &App,
+    theme: &impl ComponentThemeLike,
+) {
+    let Some(props) = queue_popup_props(conversation_area, app) else {
         return;
-    }
-
-    let mut lines = vec![Line::from(vec![
-        StatusPill::muted("esc", theme).span_compact(),
-        Span::raw(" close  "),
-        StatusPill::muted("j/k", theme).span_compact(),
-        Span::raw(" scroll"),
-    ])];
-    lines.push(Line::raw(""));
-    lines.extend(content.lines().map(|line| Line::from(line.to_string())));
+    };
 
-    frame.render_widget(
-        Paragraph::new(lines)
-            .wrap(Wrap { trim: false })
-            .scroll((app.message_detail_popup_scroll_lines(), 0)),
-        inner,
-    );
+    PopupOverlay::render(frame, conversation_area, &props, theme);
 }
 
-fn message_detail_popup_area(conversation_area: Rect) -> Option<Rect> {
-    if conversation_area.width < 24 || conversation_area.height < 10 {
+fn queue_popup_props(conversation_area: Rect, app: &App) -> Option<PopupOverlayProps> {
+    if !app.is_queue_panel_open() {
+        return None;
+    }
+
+    if conversation_area.width < 28 || conversation_area.height < 8 {
         return None;
     }
 
-    let width = (conversation_area.width.saturating_mul(9) / 10).max(24);
-    let height = (conversation_area.height.saturating_mul(8) / 10).max(10);
-    let width = width.min(conversation_area.width.saturating_sub(2));
-    let height = height.min(conversation_area.height.saturating_sub(2));
-
-    Some(Rect {
-        x: conversation_area
-            .x
-            .saturating_add((conversation_area.width.saturating_sub(width)) / 2),
-        y: conversation_area
-            .y
-            .saturating_add((conversation_area.height.saturating_sub(height)) / 2),
-        width,
-        height,
+    let max_width = conversation_area.width.saturating_sub(2);
+    let label_width = (max_width as usize).saturating_sub(16).clamp(16, 72);
+    let now = now_unix();
+    let queued = app.queued_prompts();
+
+    let items = if queued.is_empty() {
+        vec![PopupItem {
+            label: "queue empty: /queue [+30m] <prompt>".to_string(),
+            tag: None,
+            active: false,
+        }]
+    } else {
+        queued
+            .iter()
+            .enumerate()
+            .map(|(index, prompt)| PopupItem {
+                label: compact_text(
+                    &format!("{}. {}", index + 1, prompt.prompt.replace('\n', " ")),
+                    label_width,
+                ),
+                tag: Some(prompt.schedule_label(now)),
+                active: false,
+            })
+            .collect()
+    };
+
+    Some(PopupOverlayProps {
+        title: format!("Prompt Queue // {}", queued.len()),
+        items,
+        selected: app.queue_selected(),
+        query: None,
+        query_label: None,
+        hint: Some("e edit  K/J move  d cancel  esc close".to_string()),
+        anchor: PopupAnchor::Center,
+        max_visible: 10,
+        min_width: 40,
+        max_width,
     })
 }
 
@@ -710,7 +882,9 @@ mod tests {
     use ratatui::layout::Rect;
 
     use super::ChatPanel;
-    use crate::core::{ChatSession, ChatSnapshot, ProviderHealth, ProviderRuntimeStatus};
+    use crate::core::{
+        ChatMessage, ChatSession, ChatSnapshot, ProviderHealth, ProviderRuntimeStatus,
+    };
     use crate::tui::app::App;
 
     #[test]
@@ -736,6 +910,24 @@ mod tests {
         assert_snapshot!("chat_panel_agent_selector_open", output);
     }
 
+    #[test]
+    fn chat_panel_search_snapshot() {
+        let mut app = build_test_app();
+        let session = app.sessions()[0].clone();
+        app.apply_index_updates(vec![(
+            session,
+            vec![ChatMessage {
+                id: "msg_1".to_string(),
+                role: "assistant".to_string(),
+                text: "Reconnect logic now retries the websocket with backoff.".to_string(),
+                ..ChatMessage::default()
+            }],
+        )]);
+        app.open_search(Some("websocket"));
+        let output = render_panel(&app, 84, 24);
+        assert_snapshot!("chat_panel_search_open", output);
+    }
+
     fn build_test_app() -> App {
         let mut app = App::new(
             "http://127.0.0.1:4150".to_string(),
@@ -24,29 +24,37 @@ impl FooterPanel {
             StatusPill::info(app.activity_label(), theme)
         };
 
+        let mut segments = vec![
+            Span::styled(
+                app.status_message().to_string(),
+                Style::default().fg(theme.text_secondary),
+            ),
+            StatusPill::accent(
+                format!(
+                    "model:{}",
+                    compact_text(app.active_model().unwrap_or("-"), 28)
+                ),
+                theme,
+            )
+            .span_compact(),
+            activity_pill.span(),
+            Span::styled(
+                format!("last-sync:{}", app.last_synced()),
+                Style::default().fg(theme.text_muted),
+            ),
+        ];
+        if let Some(pending) = app.pending_key_sequence() {
+            segments.insert(
+                1,
+                StatusPill::accent(format!("keys:{pending} …"), theme).span(),
+            );
+        }
+
         FooterBar::render(
             frame,
             inner,
             FooterBarProps {
-                segments: vec![
-                    Span::styled(
-                        app.status_message().to_string(),
-                        Style::default().fg(theme.text_secondary),
-                    ),
-                    StatusPill::accent(
-                        format!(
-                            "model:{}",
-                            compact_text(app.active_model().unwrap_or("-"), 28)
-                        ),
-                        theme,
-                    )
-                    .span_compact(),
-                    activity_pill.span(),
-                    Span::styled(
-                        format!("last-sync:{}", app.last_synced()),
-                        Style::default().fg(theme.text_muted),
-                    ),
-                ],
+                segments,
                 separator: "  ",
             },
             theme,
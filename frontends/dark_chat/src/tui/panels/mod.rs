@@ -6,8 +6,8 @@ mod sessions_panel;
 mod status_panel;
 
 pub use chat_panel::{
-    AgentSelectorHit, ChatPanel, ComposerAutocompleteHit, ComposerMetaHit, MessageDetailPopupHit,
-    ModelSelectorHit,
+    AgentSelectorHit, ChatPanel, ComposerAutocompleteHit, ComposerMetaHit, ModelSelectorHit,
+    QueuePopupHit, SearchPopupHit,
 };
 pub use footer_panel::FooterPanel;
 pub use header_panel::HeaderPanel;
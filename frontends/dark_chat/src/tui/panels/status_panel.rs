@@ -7,6 +7,7 @@ use ratatui::widgets::{Paragraph, Wrap};
 use dark_tui_components::{KeyBind, PaneBlockComponent, StatusPill, compact_tail, compact_text};
 
 use crate::tui::app::{App, FocusPane};
+use crate::tui::keymap::{COMPOSE_MODE, ChatCommand, MAIN_MODE, pair_hint};
 
 pub struct StatusPanel;
 
@@ -40,19 +41,58 @@ impl StatusPanel {
                 Span::raw(" "),
                 Span::styled("navigation", Style::default().fg(theme.text_secondary)),
             ]));
-            lines.push(help_line_styled("j/k", "sessions or scroll focus", theme));
-            lines.push(help_line_styled("n", "new session", theme));
-            lines.push(help_line_styled("a/m", "cycle agent/model", theme));
-            lines.push(help_line_styled("c", "open composer", theme));
-            lines.push(help_line_styled("v", "open/close detail popup", theme));
-            lines.push(help_line_styled("Enter", "send prompt", theme));
-            lines.push(help_line_styled("z", "toggle detail expansion", theme));
-            lines.push(help_line_styled("h", "toggle help", theme));
+            let keymap = app.keymap();
+            let help = [
+                (
+                    pair_hint(keymap, MAIN_MODE, ChatCommand::Down, ChatCommand::Up),
+                    "sessions or scroll focus",
+                ),
+                (
+                    keymap.hint(MAIN_MODE, ChatCommand::NewSession),
+                    "new session",
+                ),
+                (
+                    pair_hint(
+                        keymap,
+                        MAIN_MODE,
+                        ChatCommand::NextAgent,
+                        ChatCommand::ModelPicker,
+                    ),
+                    "cycle agent/model",
+                ),
+                (
+                    keymap.hint(MAIN_MODE, ChatCommand::Compose),
+                    "open composer",
+                ),
+                (
+                    keymap.hint(MAIN_MODE, ChatCommand::DetailPopup),
+                    "open/close detail popup",
+                ),
+                (
+                    keymap.hint(COMPOSE_MODE, ChatCommand::Confirm),
+                    "send prompt",
+                ),
+                (
+                    keymap.hint(MAIN_MODE, ChatCommand::DetailMode),
+                    "toggle detail expansion",
+                ),
+                (keymap.hint(MAIN_MODE, ChatCommand::Help), "toggle help"),
+            ];
+            for (key, action) in help {
+                if let Some(key) = key {
+                    lines.push(help_line_styled(key, action, theme));
+                }
+            }
         } else {
+            let press = app
+                .keymap()
+                .hint(MAIN_MODE, ChatCommand::Help)
+                .map(|key| format!("press {key}"))
+                .unwrap_or_else(|| "unbound".to_string());
             lines.push(Line::from(vec![
                 StatusPill::muted("help", theme).span_compact(),
                 Span::raw(" "),
-                Span::styled("press h", Style::default().fg(theme.text_muted)),
+                Span::styled(press, Style::default().fg(theme.text_muted)),
             ]));
         }
 
@@ -64,7 +104,9 @@ impl StatusPanel {
 }
 
 fn health_line(app: &App, theme: &dark_tui_components::ComponentTheme) -> Line<'static> {
-    let health = if app.health().healthy {
+    let health = if app.is_offline() {
+        StatusPill::warn("offline:cache", theme)
+    } else if app.health().healthy {
         StatusPill::ok("healthy", theme)
     } else {
         StatusPill::error("unhealthy", theme)
@@ -191,9 +233,9 @@ fn config_line(app: &App, theme: &dark_tui_components::ComponentTheme) -> Line<'
 }
 
 fn help_line_styled(
-    key: &'static str,
+    key: String,
     action: &'static str,
     theme: &dark_tui_components::ComponentTheme,
 ) -> Line<'static> {
-    Line::from(KeyBind::new(key, action).spans(theme))
+    Line::from(KeyBind::owned(key, action).spans(theme))
 }
@@ -0,0 +1,134 @@
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use ratatui::Frame;
+use ratatui::layout::Rect;
+
+use dark_tui_components::{Action, Component, ComponentResult, ComponentThemeLike, KeyMatch};
+
+use crate::tui::keymap::{COMPLETE_MODE, COMPOSE_MODE, ChatCommand};
+use crate::tui::overlays::{ModeKeys, typed_char};
+
+pub const COMPOSER_PANE: &str = "composer";
+
+/// Edit the app makes to the draft, its cursor or its completions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ComposeRequest {
+    Insert(char),
+    /// Deletes before the cursor, or the last attachment once the draft is empty.
+    Backspace,
+    Delete,
+    Left,
+    Right,
+    Home,
+    End,
+    Clear,
+    AttachClipboardImage,
+    Send,
+    Cancel,
+    PreviousCompletion,
+    NextCompletion,
+    AcceptCompletion,
+    CloseCompletions,
+}
+
+/// Prompt composer while it has focus; the chat panel draws the draft.
+///
+/// While the app has completions open, keys resolve in `complete` mode first
+/// and fall through to `compose` mode when unbound there.
+#[derive(Debug)]
+pub struct ComposerPane {
+    keys: ModeKeys,
+    complete_keys: ModeKeys,
+    completing: bool,
+    focused: bool,
+}
+
+impl ComposerPane {
+    pub fn new() -> Self {
+        Self {
+            keys: ModeKeys::new(COMPOSE_MODE),
+            complete_keys: ModeKeys::new(COMPLETE_MODE),
+            completing: false,
+            focused: false,
+        }
+    }
+
+    pub fn is_completing(&self) -> bool {
+        self.completing
+    }
+
+    pub fn set_completing(&mut self, completing: bool) {
+        self.completing = completing;
+    }
+
+    fn compose(&mut self, key: KeyEvent) -> Option<ComposeRequest> {
+        // Shift+Enter inserts a newline ahead of the keymap so a plain `enter`
+        // send binding never swallows it.
+        if key.code == KeyCode::Enter && key.modifiers.contains(KeyModifiers::SHIFT) {
+            return Some(ComposeRequest::Insert('\n'));
+        }
+
+        match self.keys.feed(key) {
+            KeyMatch::Command(ChatCommand::Close) => Some(ComposeRequest::Cancel),
+            KeyMatch::Command(ChatCommand::Confirm) => Some(ComposeRequest::Send),
+            KeyMatch::Command(ChatCommand::Clear) => Some(ComposeRequest::Clear),
+            KeyMatch::Command(ChatCommand::Paste) => Some(ComposeRequest::AttachClipboardImage),
+            KeyMatch::Command(_) | KeyMatch::Pending => None,
+            KeyMatch::NoMatch => match key.code {
+                KeyCode::Delete => Some(ComposeRequest::Delete),
+                KeyCode::Left => Some(ComposeRequest::Left),
+                KeyCode::Right => Some(ComposeRequest::Right),
+                KeyCode::Home => Some(ComposeRequest::Home),
+                KeyCode::End => Some(ComposeRequest::End),
+                KeyCode::Backspace => Some(ComposeRequest::Backspace),
+                _ => typed_char(key).map(ComposeRequest::Insert),
+            },
+        }
+    }
+}
+
+impl Component for ComposerPane {
+    fn handle_key_event(&mut self, key: KeyEvent) -> ComponentResult<Option<Action>> {
+        if self.completing {
+            let request = match self.complete_keys.feed(key) {
+                KeyMatch::Command(ChatCommand::Close) => Some(ComposeRequest::CloseCompletions),
+                KeyMatch::Command(ChatCommand::Up) => Some(ComposeRequest::PreviousCompletion),
+                KeyMatch::Command(ChatCommand::Down) => Some(ComposeRequest::NextCompletion),
+                KeyMatch::Command(ChatCommand::Confirm) => Some(ComposeRequest::AcceptCompletion),
+                KeyMatch::Pending => return Ok(None),
+                KeyMatch::Command(_) | KeyMatch::NoMatch => None,
+            };
+            if request.is_some() {
+                return Ok(request.map(Action::custom));
+            }
+        }
+        Ok(self.compose(key).map(Action::custom))
+    }
+
+    fn update(&mut self, action: &Action) -> ComponentResult<Option<Action>> {
+        if self.keys.update(action) {
+            self.complete_keys.update(action);
+        }
+        Ok(None)
+    }
+
+    fn draw(
+        &self,
+        _frame: &mut Frame,
+        _area: Rect,
+        _theme: &dyn ComponentThemeLike,
+    ) -> ComponentResult {
+        Ok(())
+    }
+
+    fn wants_focus(&self) -> bool {
+        true
+    }
+
+    fn is_focused(&self) -> bool {
+        self.focused
+    }
+
+    fn set_focused(&mut self, focused: bool) {
+        self.focused = focused;
+    }
+}
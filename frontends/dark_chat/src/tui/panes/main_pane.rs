@@ -0,0 +1,71 @@
+use crossterm::event::KeyEvent;
+use ratatui::Frame;
+use ratatui::layout::Rect;
+
+use dark_tui_components::{Action, Component, ComponentResult, ComponentThemeLike, KeyMatch};
+
+use crate::tui::keymap::{ChatCommand, MAIN_MODE};
+use crate::tui::overlays::ModeKeys;
+
+pub const MAIN_PANE: &str = "main";
+
+/// A `main` mode command for the run loop.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RunCommand(pub ChatCommand);
+
+/// Sessions, chat and runtime panes while the composer is closed. The panels
+/// are drawn by `MainView`; this resolves the `main` keys.
+#[derive(Debug)]
+pub struct MainPane {
+    keys: ModeKeys,
+    focused: bool,
+}
+
+impl MainPane {
+    pub fn new() -> Self {
+        Self {
+            keys: ModeKeys::new(MAIN_MODE),
+            focused: false,
+        }
+    }
+
+    /// Keys typed so far toward a multi-key binding, for the footer.
+    pub fn pending(&self) -> Option<String> {
+        self.keys.pending()
+    }
+}
+
+impl Component for MainPane {
+    fn handle_key_event(&mut self, key: KeyEvent) -> ComponentResult<Option<Action>> {
+        Ok(match self.keys.feed(key) {
+            KeyMatch::Command(command) => Some(Action::custom(RunCommand(command))),
+            KeyMatch::Pending | KeyMatch::NoMatch => None,
+        })
+    }
+
+    fn update(&mut self, action: &Action) -> ComponentResult<Option<Action>> {
+        self.keys.update(action);
+        Ok(None)
+    }
+
+    fn draw(
+        &self,
+        _frame: &mut Frame,
+        _area: Rect,
+        _theme: &dyn ComponentThemeLike,
+    ) -> ComponentResult {
+        Ok(())
+    }
+
+    fn wants_focus(&self) -> bool {
+        true
+    }
+
+    fn is_focused(&self) -> bool {
+        self.focused
+    }
+
+    fn set_focused(&mut self, focused: bool) {
+        self.focused = focused;
+    }
+}
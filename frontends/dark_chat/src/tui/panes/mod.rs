@@ -0,0 +1,21 @@
+//! Panes that take keys, mounted on the app's `ComponentRuntime` under the
+//! overlays (see `tui::overlays`).
+//!
+//! Each pane is a `Layer::Base` component. The app focuses the composer while
+//! a prompt is being written and the main pane otherwise; whichever has focus
+//! gets the keys no modal overlay takes and answers with a request the run
+//! loop applies.
+
+mod composer_pane;
+mod main_pane;
+
+use dark_tui_components::{ComponentResult, ComponentRuntime, Layer};
+
+pub use composer_pane::{COMPOSER_PANE, ComposeRequest, ComposerPane};
+pub use main_pane::{MAIN_PANE, MainPane, RunCommand};
+
+/// Mounts both panes; the main pane is mounted first, so it starts focused.
+pub fn mount_panes(runtime: &mut ComponentRuntime) -> ComponentResult {
+    runtime.mount(MAIN_PANE, Layer::Base, Box::new(MainPane::new()))?;
+    runtime.mount(COMPOSER_PANE, Layer::Base, Box::new(ComposerPane::new()))
+}
@@ -1,7 +1,10 @@
 use ratatui::Frame;
 use ratatui::layout::{Constraint, Direction, Layout, Rect};
 
+use dark_tui_components::inner_rect;
+
 use crate::tui::app::{App, ResizeTarget};
+use crate::tui::overlays::{MESSAGE_DETAIL_OVERLAY, MESSAGE_LOG_OVERLAY, message_detail_area};
 use crate::tui::panels::{
     ChatPanel, FooterPanel, HeaderPanel, KeyBarPanel, SessionsPanel, StatusPanel,
 };
@@ -45,6 +48,38 @@ impl MainView {
         KeyBarPanel::render(frame, rows[1], app);
         Self::render_body(frame, layout, app);
         FooterPanel::render(frame, rows[3], app);
+
+        // A failing overlay only skips itself; the panels are already drawn.
+        let _ = app.overlays().draw(frame, frame.area(), app.theme());
+        app.toasts().render(frame, rows[2], app.theme());
+    }
+
+    /// Expands or dismisses the toast under a click.
+    pub fn toast_click(root: Rect, app: &mut App, col: u16, row: u16) -> bool {
+        app.toasts_mut().click(Self::body(root), col, row)
+    }
+
+    /// Row between the key bar and the footer.
+    fn body(root: Rect) -> Rect {
+        Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(2),
+                Constraint::Length(2),
+                Constraint::Min(10),
+                Constraint::Length(3),
+            ])
+            .split(root)[2]
+    }
+
+    /// Positions runtime overlays for the frame about to be drawn.
+    pub fn place_overlays(root: Rect, app: &mut App) {
+        let layout = Self::layout(root, app);
+        let detail = message_detail_area(inner_rect(layout.chat)).unwrap_or_default();
+        app.overlays_mut().place(MESSAGE_DETAIL_OVERLAY, detail);
+        let body = Self::body(root);
+        app.overlays_mut()
+            .place(MESSAGE_LOG_OVERLAY, message_log_area(body));
     }
 
     pub fn layout(root: Rect, app: &App) -> ViewLayout {
@@ -194,3 +229,15 @@ fn chat_composer_area(chat_area: Rect) -> Rect {
 
     chunks[2]
 }
+
+/// Centered popup for the message log, three quarters of the body.
+fn message_log_area(body: Rect) -> Rect {
+    let width = (body.width * 3 / 4).max(20).min(body.width);
+    let height = (body.height * 3 / 4).max(6).min(body.height);
+    Rect::new(
+        body.x + (body.width - width) / 2,
+        body.y + (body.height - height) / 2,
+        width,
+        height,
+    )
+}
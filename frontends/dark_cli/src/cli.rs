@@ -7,12 +7,32 @@ pub enum OutputFormat {
     Toml,
 }
 
+#[derive(Debug, Clone, Copy, ValueEnum)]
+pub enum TranscriptFormatArg {
+    Markdown,
+    Json,
+}
+
 #[derive(Debug, Clone, Copy, ValueEnum)]
 pub enum IncludeLevel {
     Minimal,
     Full,
 }
 
+#[derive(Debug, Clone, Copy, ValueEnum)]
+pub enum IntegrateStrategyArg {
+    Merge,
+    Rebase,
+    Squash,
+}
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+pub enum IntegrateAfterArg {
+    Keep,
+    Delete,
+    Reset,
+}
+
 #[derive(Debug, Parser)]
 #[command(name = "dark_cli", about = "Dark Factory CLI frontend")]
 pub struct Cli {
@@ -193,6 +213,18 @@ pub enum VariantsAction {
         #[arg(long)]
         branch_name: String,
     },
+    Integrate {
+        #[arg(long)]
+        id: String,
+        #[arg(long, value_enum, default_value_t = IntegrateStrategyArg::Merge)]
+        strategy: IntegrateStrategyArg,
+        #[arg(long)]
+        preview: bool,
+        #[arg(long, value_enum, default_value_t = IntegrateAfterArg::Keep)]
+        after: IntegrateAfterArg,
+        #[arg(long)]
+        base_branch: Option<String>,
+    },
 }
 
 #[derive(Debug, Args)]
@@ -297,4 +329,14 @@ pub enum ActorMessagesAction {
         #[arg(long)]
         n_last_messages: Option<u32>,
     },
+    Export {
+        #[arg(long)]
+        id: String,
+        #[arg(long, short)]
+        output: Option<String>,
+        #[arg(long, value_enum)]
+        transcript_format: Option<TranscriptFormatArg>,
+        #[arg(long)]
+        n_last_messages: Option<u32>,
+    },
 }
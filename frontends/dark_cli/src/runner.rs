@@ -4,19 +4,22 @@ use std::path::Path;
 use std::process::Command as ProcessCommand;
 
 use anyhow::{Context, Result};
+use dark_chat::framework::{Transcript, TranscriptFormat, TranscriptMessage, write_transcript};
 use dark_rust::types::{
     ActorAttachQuery, ActorCommandInput, ActorCreateInput, ActorDeleteQuery, ActorListQuery,
     ActorMessageInput, ActorMessagesQuery, ActorUpdateInput, ProductCreateInput,
     ProductIncludeQuery, ProductListQuery, ProductUpdateInput, ProductVariantCloneInput,
     VariantBranchSwitchInput, VariantCreateInput, VariantDeleteQuery, VariantImportActorsInput,
-    VariantListQuery, VariantProductConnectInput, VariantProductRelationInput, VariantUpdateInput,
+    VariantIntegrateInput, VariantListQuery, VariantProductConnectInput,
+    VariantProductRelationInput, VariantUpdateInput,
 };
 use dark_rust::{DarkCoreClient, DarkRustError, LocatorId, LocatorKind, RawApiResponse};
 use serde_json::{Value, json};
 
 use crate::cli::{
-    ActorMessagesAction, ActorsAction, Cli, Command, IncludeLevel, ProductsAction, ServiceAction,
-    SystemAction, VariantsAction,
+    ActorMessagesAction, ActorsAction, Cli, Command, IncludeLevel, IntegrateAfterArg,
+    IntegrateStrategyArg, ProductsAction, ServiceAction, SystemAction, TranscriptFormatArg,
+    VariantsAction,
 };
 
 const PRODUCTS_PAGE_LIMIT: u32 = 100;
@@ -36,6 +39,30 @@ pub async fn run(cli: Cli, api: &DarkCoreClient) -> Result<()> {
         return Ok(());
     }
 
+    if (200..300).contains(&response.status)
+        && let Command::Actors(crate::cli::ActorsCommand {
+            action:
+                ActorsAction::Messages {
+                    action:
+                        ActorMessagesAction::Export {
+                            id,
+                            output,
+                            transcript_format,
+                            ..
+                        },
+                },
+        }) = &cli.command
+    {
+        return export_transcript_from_response(
+            api,
+            id,
+            output.as_deref(),
+            *transcript_format,
+            &response.body,
+        )
+        .await;
+    }
+
     let output = crate::output::render(cli.format, &cli.command, &response.body)?;
 
     if (200..300).contains(&response.status) {
@@ -78,6 +105,60 @@ fn run_tmux_attach_from_response(body: &Value) -> Result<()> {
     ))
 }
 
+async fn export_transcript_from_response(
+    api: &DarkCoreClient,
+    actor_id: &str,
+    output: Option<&str>,
+    format: Option<TranscriptFormatArg>,
+    body: &Value,
+) -> Result<()> {
+    let messages = extract_data_rows(body)
+        .iter()
+        .filter_map(TranscriptMessage::from_value)
+        .collect::<Vec<_>>();
+
+    // Actor metadata only enriches the header; a failed lookup still exports messages.
+    let actor = api
+        .actors_get(actor_id)
+        .await
+        .ok()
+        .filter(|response| (200..300).contains(&response.status))
+        .and_then(|response| response.body.get("data").cloned())
+        .unwrap_or(Value::Null);
+    let actor_field = |key: &str| {
+        actor
+            .get(key)
+            .and_then(Value::as_str)
+            .map(str::trim)
+            .filter(|value| !value.is_empty())
+            .map(ToString::to_string)
+    };
+
+    let mut transcript = Transcript::new(
+        actor_field("providerSessionId").unwrap_or_else(|| actor_id.to_string()),
+        messages,
+    );
+    transcript.title = actor_field("title");
+    transcript.directory = actor_field("workingLocator");
+    transcript.provider = actor_field("provider");
+
+    let format = match (format, output) {
+        (Some(TranscriptFormatArg::Markdown), _) => TranscriptFormat::Markdown,
+        (Some(TranscriptFormatArg::Json), _) => TranscriptFormat::Json,
+        (None, Some(path)) => TranscriptFormat::from_path(Path::new(path)),
+        (None, None) => TranscriptFormat::Markdown,
+    };
+
+    let Some(path) = output else {
+        println!("{}", transcript.render(format)?);
+        return Ok(());
+    };
+
+    write_transcript(&transcript, Path::new(path), format)?;
+    println!("Exported {} messages to {path}", transcript.messages.len());
+    Ok(())
+}
+
 fn extract_attach_command(body: &Value) -> Result<&str> {
     body.get("data")
         .and_then(|value| value.get("attachCommand"))
@@ -301,6 +382,24 @@ async fn dispatch(cli: &Cli, api: &DarkCoreClient) -> Result<RawApiResponse> {
                 )
                 .await
                 .map_err(Into::into),
+            VariantsAction::Integrate {
+                id,
+                strategy,
+                preview,
+                after,
+                base_branch,
+            } => api
+                .variants_integrate(
+                    id,
+                    &VariantIntegrateInput {
+                        strategy: Some(integrate_strategy_value(*strategy).to_string()),
+                        preview: Some(*preview),
+                        after: Some(integrate_after_value(*after).to_string()),
+                        base_branch: base_branch.clone(),
+                    },
+                )
+                .await
+                .map_err(Into::into),
         },
         Command::Actors(command) => match &command.action {
             ActorsAction::List {
@@ -402,6 +501,11 @@ async fn dispatch(cli: &Cli, api: &DarkCoreClient) -> Result<RawApiResponse> {
                 ActorMessagesAction::List {
                     id,
                     n_last_messages,
+                }
+                | ActorMessagesAction::Export {
+                    id,
+                    n_last_messages,
+                    ..
                 } => api
                     .actors_list_messages(
                         id,
@@ -502,6 +606,22 @@ fn normalize_locator_input(locator: &str) -> Result<String> {
         .map_err(Into::into)
 }
 
+fn integrate_strategy_value(strategy: IntegrateStrategyArg) -> &'static str {
+    match strategy {
+        IntegrateStrategyArg::Merge => "merge",
+        IntegrateStrategyArg::Rebase => "rebase",
+        IntegrateStrategyArg::Squash => "squash",
+    }
+}
+
+fn integrate_after_value(after: IntegrateAfterArg) -> &'static str {
+    match after {
+        IntegrateAfterArg::Keep => "keep",
+        IntegrateAfterArg::Delete => "delete",
+        IntegrateAfterArg::Reset => "reset",
+    }
+}
+
 fn map_include(include: Option<IncludeLevel>) -> Option<ProductIncludeQuery> {
     match include {
         Some(IncludeLevel::Minimal) => Some(ProductIncludeQuery::Minimal),
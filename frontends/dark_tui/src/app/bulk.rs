@@ -0,0 +1,140 @@
+/// Operation fanned out over the marked catalog nodes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BulkOperation {
+    Poll,
+    DeleteVariants { dry: bool },
+    Prompt,
+    TerminateActors,
+}
+
+impl BulkOperation {
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Poll => "Poll",
+            Self::DeleteVariants { dry: true } => "Delete (keep clones)",
+            Self::DeleteVariants { dry: false } => "Delete (remove clones)",
+            Self::Prompt => "Prompt",
+            Self::TerminateActors => "Terminate",
+        }
+    }
+
+    /// Whether a finished run changes catalog rows and should trigger a refresh.
+    pub fn refreshes_catalog(self) -> bool {
+        !matches!(self, Self::Prompt)
+    }
+}
+
+/// One target of a bulk run; `outcome` stays `None` until its request returns.
+#[derive(Debug, Clone)]
+pub struct BulkEntry {
+    pub target_id: String,
+    pub label: String,
+    pub outcome: Option<Result<String, String>>,
+}
+
+/// Progress and per-target results of the latest bulk run.
+#[derive(Debug, Clone)]
+pub struct BulkRun {
+    pub operation: BulkOperation,
+    pub entries: Vec<BulkEntry>,
+}
+
+impl BulkRun {
+    pub fn new(operation: BulkOperation, targets: Vec<(String, String)>) -> Self {
+        Self {
+            operation,
+            entries: targets
+                .into_iter()
+                .map(|(target_id, label)| BulkEntry {
+                    target_id,
+                    label,
+                    outcome: None,
+                })
+                .collect(),
+        }
+    }
+
+    /// Records the result for the first pending entry with `target_id`.
+    pub fn record(&mut self, target_id: &str, outcome: Result<String, String>) -> bool {
+        let Some(entry) = self
+            .entries
+            .iter_mut()
+            .find(|entry| entry.target_id == target_id && entry.outcome.is_none())
+        else {
+            return false;
+        };
+
+        entry.outcome = Some(outcome);
+        true
+    }
+
+    pub fn completed(&self) -> usize {
+        self.entries
+            .iter()
+            .filter(|entry| entry.outcome.is_some())
+            .count()
+    }
+
+    pub fn failed(&self) -> usize {
+        self.entries
+            .iter()
+            .filter(|entry| matches!(entry.outcome, Some(Err(_))))
+            .count()
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.completed() == self.entries.len()
+    }
+
+    /// One-line progress or result summary for the status bar.
+    pub fn summary(&self) -> String {
+        let total = self.entries.len();
+        let label = self.operation.label();
+        if !self.is_finished() {
+            return format!("{label}: {}/{total} done...", self.completed());
+        }
+
+        let failed = self.failed();
+        if failed == 0 {
+            return format!("{label}: all {total} succeeded.");
+        }
+
+        let first_error = self
+            .entries
+            .iter()
+            .find_map(|entry| match &entry.outcome {
+                Some(Err(error)) => Some(format!("{}: {error}", entry.label)),
+                _ => None,
+            })
+            .unwrap_or_default();
+        format!(
+            "{label}: {} ok, {failed} failed ({first_error}).",
+            total - failed
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{BulkOperation, BulkRun};
+
+    #[test]
+    fn bulk_run_tracks_progress_and_summarizes_failures() {
+        let mut run = BulkRun::new(
+            BulkOperation::Poll,
+            vec![
+                ("v1".to_string(), "variant one".to_string()),
+                ("a1".to_string(), "actor one".to_string()),
+            ],
+        );
+        assert_eq!(run.summary(), "Poll: 0/2 done...");
+
+        assert!(run.record("v1", Ok("polled".to_string())));
+        assert!(!run.record("v1", Ok("again".to_string())));
+        assert!(!run.is_finished());
+
+        assert!(run.record("a1", Err("timeout".to_string())));
+        assert!(run.is_finished());
+        assert_eq!(run.summary(), "Poll: 1 ok, 1 failed (actor one: timeout).");
+    }
+}
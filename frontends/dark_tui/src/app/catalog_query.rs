@@ -0,0 +1,481 @@
+use std::collections::HashSet;
+
+use crate::models::{ActorRow, ProductRow, VariantRow};
+
+const FIELD_NAMES: &str = "status, branch, dirty, provider, name, id";
+
+/// Field addressed by a `field:value` term in a catalog query.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum QueryField {
+    Status,
+    Branch,
+    Dirty,
+    Provider,
+    Name,
+    Id,
+}
+
+impl QueryField {
+    fn parse(name: &str) -> Option<Self> {
+        match name.to_ascii_lowercase().as_str() {
+            "status" | "state" => Some(Self::Status),
+            "branch" => Some(Self::Branch),
+            "dirty" => Some(Self::Dirty),
+            "provider" => Some(Self::Provider),
+            "name" | "title" => Some(Self::Name),
+            "id" => Some(Self::Id),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct QueryTerm {
+    field: Option<QueryField>,
+    /// Lowercased value; `*` and `?` act as globs.
+    pattern: String,
+    negated: bool,
+}
+
+/// Parsed catalog search, e.g. `status:busy branch:feat/* dirty:true api`.
+///
+/// Terms are ANDed. Bare words match names, ids, branches and descriptions as
+/// substrings; a leading `-` negates a term.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CatalogQuery {
+    text: String,
+    terms: Vec<QueryTerm>,
+}
+
+impl CatalogQuery {
+    /// Parses `text`; an empty or whitespace-only query yields `Ok(None)`.
+    pub fn parse(text: &str) -> Result<Option<Self>, String> {
+        let mut terms = Vec::new();
+        for token in tokenize(text)? {
+            let (negated, token) = match token.strip_prefix('-') {
+                Some(rest) if !rest.is_empty() => (true, rest.to_string()),
+                _ => (false, token),
+            };
+
+            let field_split = token
+                .split_once(':')
+                .filter(|(name, _)| !name.is_empty() && name.chars().all(char::is_alphabetic));
+            let term = match field_split {
+                Some((name, value)) => {
+                    let Some(field) = QueryField::parse(name) else {
+                        return Err(format!("unknown filter `{name}:` (use {FIELD_NAMES})"));
+                    };
+                    let value = value.trim_matches('"');
+                    if value.is_empty() {
+                        return Err(format!("`{name}:` needs a value"));
+                    }
+                    if field == QueryField::Dirty && parse_bool(value).is_none() {
+                        return Err(format!("`dirty:` expects true or false, got `{value}`"));
+                    }
+                    QueryTerm {
+                        field: Some(field),
+                        pattern: value.to_lowercase(),
+                        negated,
+                    }
+                }
+                None => QueryTerm {
+                    field: None,
+                    pattern: token.to_lowercase(),
+                    negated,
+                },
+            };
+            terms.push(term);
+        }
+
+        if terms.is_empty() {
+            return Ok(None);
+        }
+
+        Ok(Some(Self {
+            text: text.trim().to_string(),
+            terms,
+        }))
+    }
+
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// Literal fragments worth highlighting inside matched names.
+    pub fn highlight_terms(&self) -> Vec<&str> {
+        self.terms
+            .iter()
+            .filter(|term| !term.negated)
+            .filter(|term| matches!(term.field, None | Some(QueryField::Name)))
+            .map(|term| term.pattern.as_str())
+            .filter(|pattern| !has_wildcard(pattern))
+            .collect()
+    }
+
+    fn matches(&self, target: &QueryTarget<'_>) -> bool {
+        self.terms
+            .iter()
+            .all(|term| target.matches(term) != term.negated)
+    }
+}
+
+/// Fields a catalog row exposes to queries. Actors and variants inherit the
+/// branch and dirty state of their parents.
+struct QueryTarget<'a> {
+    name: &'a str,
+    id: &'a str,
+    statuses: Vec<&'a str>,
+    branch: Option<&'a str>,
+    dirty: Option<bool>,
+    provider: Option<&'a str>,
+    text: Vec<&'a str>,
+}
+
+impl<'a> QueryTarget<'a> {
+    fn product(product: &'a ProductRow) -> Self {
+        Self {
+            name: &product.display_name,
+            id: &product.id,
+            statuses: vec![&product.status],
+            branch: Some(&product.branch),
+            dirty: Some(product.variant_dirty > 0),
+            provider: None,
+            text: vec![&product.repo_name, &product.locator],
+        }
+    }
+
+    fn variant(variant: &'a VariantRow) -> Self {
+        Self {
+            name: &variant.name,
+            id: &variant.id,
+            statuses: vec![&variant.git_state, &variant.clone_status],
+            branch: Some(&variant.branch),
+            dirty: Some(variant.is_dirty),
+            provider: None,
+            text: vec![&variant.worktree],
+        }
+    }
+
+    fn actor(actor: &'a ActorRow, variant: Option<&'a VariantRow>) -> Self {
+        Self {
+            name: &actor.title,
+            id: &actor.id,
+            statuses: vec![&actor.status],
+            branch: variant.map(|variant| variant.branch.as_str()),
+            dirty: variant.map(|variant| variant.is_dirty),
+            provider: Some(&actor.provider),
+            text: vec![&actor.description, &actor.provider],
+        }
+    }
+
+    fn matches(&self, term: &QueryTerm) -> bool {
+        let pattern = term.pattern.as_str();
+        match term.field {
+            None => [self.name, self.id]
+                .into_iter()
+                .chain(self.branch)
+                .chain(self.text.iter().copied())
+                .any(|value| value.to_lowercase().contains(pattern)),
+            Some(QueryField::Name) => text_matches(pattern, self.name),
+            Some(QueryField::Id) => text_matches(pattern, self.id),
+            Some(QueryField::Branch) => self
+                .branch
+                .is_some_and(|branch| text_matches(pattern, branch)),
+            Some(QueryField::Provider) => self
+                .provider
+                .is_some_and(|provider| text_matches(pattern, provider)),
+            Some(QueryField::Dirty) => self.dirty == parse_bool(pattern),
+            Some(QueryField::Status) => self
+                .statuses
+                .iter()
+                .any(|status| status_matches(pattern, status)),
+        }
+    }
+}
+
+/// Ids of catalog rows left visible by a query, plus the rows that matched it
+/// directly (ancestors of matches stay visible for context).
+#[derive(Debug, Default, Clone)]
+pub struct CatalogVisibility {
+    pub products: HashSet<String>,
+    pub variants: HashSet<String>,
+    pub actors: HashSet<String>,
+    pub matched_products: HashSet<String>,
+    pub matched_variants: HashSet<String>,
+    pub matched_actors: HashSet<String>,
+}
+
+impl CatalogVisibility {
+    pub fn compute(
+        query: &CatalogQuery,
+        products: &[ProductRow],
+        variants: &[VariantRow],
+        actors: &[ActorRow],
+    ) -> Self {
+        let mut visibility = Self::default();
+
+        for actor in actors {
+            let variant = variants
+                .iter()
+                .find(|variant| variant.id == actor.variant_id);
+            if query.matches(&QueryTarget::actor(actor, variant)) {
+                visibility.matched_actors.insert(actor.id.clone());
+                visibility.actors.insert(actor.id.clone());
+                visibility.variants.insert(actor.variant_id.clone());
+            }
+        }
+
+        for variant in variants {
+            if query.matches(&QueryTarget::variant(variant)) {
+                visibility.matched_variants.insert(variant.id.clone());
+                visibility.variants.insert(variant.id.clone());
+            }
+            if visibility.variants.contains(&variant.id) {
+                visibility.products.insert(variant.product_id.clone());
+            }
+        }
+
+        for product in products {
+            if query.matches(&QueryTarget::product(product)) {
+                visibility.matched_products.insert(product.id.clone());
+                visibility.products.insert(product.id.clone());
+            }
+        }
+
+        visibility
+    }
+}
+
+/// Splits on whitespace, keeping double-quoted spans together.
+fn tokenize(text: &str) -> Result<Vec<String>, String> {
+    let mut tokens = Vec::new();
+    let mut current = String::new();
+    let mut quoted = false;
+
+    for ch in text.chars() {
+        match ch {
+            '"' => {
+                quoted = !quoted;
+                current.push(ch);
+            }
+            ch if ch.is_whitespace() && !quoted => {
+                if !current.is_empty() {
+                    tokens.push(std::mem::take(&mut current));
+                }
+            }
+            ch => current.push(ch),
+        }
+    }
+
+    if quoted {
+        return Err("unterminated quote".to_string());
+    }
+    if !current.is_empty() {
+        tokens.push(current);
+    }
+
+    Ok(tokens
+        .into_iter()
+        .map(|token| {
+            if token.starts_with('"') {
+                token.trim_matches('"').to_string()
+            } else {
+                token
+            }
+        })
+        .filter(|token| !token.is_empty())
+        .collect())
+}
+
+fn parse_bool(value: &str) -> Option<bool> {
+    match value.to_ascii_lowercase().as_str() {
+        "true" | "yes" | "1" => Some(true),
+        "false" | "no" | "0" => Some(false),
+        _ => None,
+    }
+}
+
+fn has_wildcard(pattern: &str) -> bool {
+    pattern.contains(['*', '?'])
+}
+
+/// Globs match the whole value; plain patterns match as substrings.
+fn text_matches(pattern: &str, value: &str) -> bool {
+    let value = value.to_lowercase();
+    if has_wildcard(pattern) {
+        glob_matches(pattern, &value)
+    } else {
+        value.contains(pattern)
+    }
+}
+
+/// Statuses compare exactly so `idle` does not match `not-idle`; `busy` and
+/// `idle` also cover the provider-specific spellings of those states.
+pub(crate) fn status_matches(pattern: &str, status: &str) -> bool {
+    let status = status.trim().to_ascii_lowercase();
+    if has_wildcard(pattern) {
+        return glob_matches(pattern, &status);
+    }
+
+    match pattern {
+        "busy" => matches!(
+            status.as_str(),
+            "busy" | "running" | "active" | "working" | "retry" | "retrying"
+        ),
+        "idle" => matches!(status.as_str(), "idle" | "waiting" | "ready"),
+        _ => status == pattern,
+    }
+}
+
+fn glob_matches(pattern: &str, value: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let value: Vec<char> = value.chars().collect();
+
+    let (mut p, mut v) = (0, 0);
+    let mut backtrack: Option<(usize, usize)> = None;
+    while v < value.len() {
+        if p < pattern.len() && (pattern[p] == '?' || pattern[p] == value[v]) {
+            p += 1;
+            v += 1;
+        } else if p < pattern.len() && pattern[p] == '*' {
+            backtrack = Some((p, v));
+            p += 1;
+        } else if let Some((star, matched)) = backtrack {
+            p = star + 1;
+            v = matched + 1;
+            backtrack = Some((star, matched + 1));
+        } else {
+            return false;
+        }
+    }
+
+    pattern[p..].iter().all(|ch| *ch == '*')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CatalogQuery, CatalogVisibility, glob_matches};
+    use crate::models::{ActorRow, ProductRow, VariantRow};
+
+    fn product(id: &str) -> ProductRow {
+        ProductRow {
+            id: id.to_string(),
+            display_name: id.to_string(),
+            locator: "-".to_string(),
+            workspace_locator: "-".to_string(),
+            product_type: "-".to_string(),
+            is_git_repo: true,
+            branch: "main".to_string(),
+            branches: "-".to_string(),
+            repo_name: "-".to_string(),
+            updated_at: "-".to_string(),
+            status: "ready".to_string(),
+            variant_total: 0,
+            variant_dirty: 0,
+            variant_drift: 0,
+        }
+    }
+
+    fn variant(id: &str, product_id: &str, branch: &str, is_dirty: bool) -> VariantRow {
+        VariantRow {
+            id: id.to_string(),
+            product_id: product_id.to_string(),
+            locator: "-".to_string(),
+            name: id.to_string(),
+            branch: branch.to_string(),
+            git_state: if is_dirty { "dirty" } else { "clean" }.to_string(),
+            clone_status: "ready".to_string(),
+            clone_last_line: "-".to_string(),
+            has_git: true,
+            is_dirty,
+            ahead: 0,
+            behind: 0,
+            worktree: "-".to_string(),
+            last_polled_at: "-".to_string(),
+            updated_at: "-".to_string(),
+        }
+    }
+
+    fn actor(id: &str, variant_id: &str, provider: &str, status: &str) -> ActorRow {
+        ActorRow {
+            id: id.to_string(),
+            variant_id: variant_id.to_string(),
+            title: format!("{id} title"),
+            description: "-".to_string(),
+            provider: provider.to_string(),
+            provider_session_id: None,
+            status: status.to_string(),
+            directory: "-".to_string(),
+            connection_info: serde_json::Value::Null,
+            sub_agents: Vec::new(),
+            created_at: "-".to_string(),
+            updated_at: "-".to_string(),
+        }
+    }
+
+    #[test]
+    fn parse_rejects_unknown_fields_and_bad_values() {
+        assert_eq!(CatalogQuery::parse("   "), Ok(None));
+        assert!(
+            CatalogQuery::parse("colour:red")
+                .unwrap_err()
+                .contains("unknown filter")
+        );
+        assert!(CatalogQuery::parse("dirty:maybe").is_err());
+        assert!(CatalogQuery::parse("status:").is_err());
+        assert!(CatalogQuery::parse("name:\"open").is_err());
+
+        let query = CatalogQuery::parse("name:\"my api\" git@host:repo")
+            .unwrap()
+            .unwrap();
+        assert_eq!(query.highlight_terms(), vec!["my api", "git@host:repo"]);
+    }
+
+    #[test]
+    fn glob_matches_whole_value() {
+        assert!(glob_matches("feat/*", "feat/search"));
+        assert!(glob_matches("f?at/*h", "feat/search"));
+        assert!(!glob_matches("feat/*", "fix/feat/search"));
+        assert!(glob_matches("*", ""));
+    }
+
+    #[test]
+    fn visibility_keeps_ancestors_of_matches_and_inherits_branch() {
+        let products = vec![product("p1"), product("p2")];
+        let variants = vec![
+            variant("v1", "p1", "feat/search", true),
+            variant("v2", "p1", "main", false),
+            variant("v3", "p2", "feat/ui", false),
+        ];
+        let actors = vec![
+            actor("a1", "v1", "opencode", "running"),
+            actor("a2", "v2", "opencode", "idle"),
+            actor("a3", "v3", "codex", "busy"),
+        ];
+
+        let query = CatalogQuery::parse("status:busy provider:opencode")
+            .unwrap()
+            .unwrap();
+        let visibility = CatalogVisibility::compute(&query, &products, &variants, &actors);
+        assert!(visibility.matched_actors.contains("a1"));
+        assert_eq!(visibility.actors.len(), 1);
+        assert!(visibility.variants.contains("v1"));
+        assert!(!visibility.matched_variants.contains("v1"));
+        assert!(visibility.products.contains("p1"));
+        assert!(!visibility.products.contains("p2"));
+
+        let query = CatalogQuery::parse("branch:feat/* dirty:true")
+            .unwrap()
+            .unwrap();
+        let visibility = CatalogVisibility::compute(&query, &products, &variants, &actors);
+        assert!(visibility.matched_variants.contains("v1"));
+        assert!(visibility.matched_actors.contains("a1"));
+        assert!(!visibility.variants.contains("v3"));
+        assert!(visibility.matched_products.is_empty());
+
+        let query = CatalogQuery::parse("-provider:opencode").unwrap().unwrap();
+        let visibility = CatalogVisibility::compute(&query, &products, &variants, &actors);
+        assert!(visibility.matched_actors.contains("a3"));
+        assert!(!visibility.actors.contains("a1"));
+    }
+}
@@ -1,5 +1,7 @@
+mod bulk;
+mod catalog_query;
 mod state;
 
-pub use state::{
-    App, ChatPickerKind, FocusPane, ResizeTarget, ResultsViewMode, VizDensity, VizSelection,
-};
+pub use bulk::{BulkOperation, BulkRun};
+pub(crate) use catalog_query::{CatalogQuery, status_matches};
+pub use state::{App, FocusPane, ResizeTarget, ResultsViewMode, VizDensity, VizSelection};
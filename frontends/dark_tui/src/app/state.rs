@@ -1,17 +1,51 @@
-use std::collections::HashMap;
+use std::any::Any;
+use std::collections::{BTreeSet, HashMap};
 use std::fs;
 use std::io;
 use std::path::{Path, PathBuf};
 
 use serde::{Deserialize, Serialize};
 
-use dark_tui_components::{HorizontalSplit, next_index, previous_index};
+use dark_chat::core::{ChatAttachment, ensure_attachment_fits};
+use dark_chat::framework::{PromptQueuePanel, QueuedPrompt, now_unix};
+use dark_tui_components::{
+    Action as ComponentAction, Component, ComponentId, ComponentResult, ComponentRuntime,
+    Event as ComponentEvent, Form, FormField, HorizontalSplit, Keymap, PopupAnchor, SelectOption,
+    ThemeWatcher, ToastStack, inner_rect, next_index, previous_index,
+};
+use ratatui::layout::Rect;
+use tracing::{error, warn};
 
+use super::bulk::{BulkOperation, BulkRun};
+use super::catalog_query::{CatalogQuery, CatalogVisibility};
+use crate::core_logs::CoreLogView;
 use crate::models::{
     ActorChatMessageRow, ActorRow, DashboardSnapshot, ProductRow, SshHostRow, SshPortForwardRow,
-    TmuxSessionRow, VariantRow, compact_id, compact_locator, compact_timestamp,
+    SubAgentRow, SubAgentStatusChange, TmuxSessionRow, VariantRow, compact_id, compact_locator,
+    compact_timestamp,
 };
+use crate::notify::{Notification, NotificationCenter, NotificationConfig, detect_events};
+use crate::system_metrics::CoreHealth;
+use crate::terminal_pane::TerminalPane;
 use crate::theme::Theme;
+use crate::ui::command_palette::{ContextMenuState, PaletteItem, command_key_hint, palette_items};
+use crate::ui::keymap::{
+    FORM_MODE, KeyCommand, PopupCommand, SSH_MODE, default_keymap, popup_hint, with_form_keys,
+};
+use crate::ui::overlays::{
+    BRANCH_FORM, BULK_PROMPT_FORM, BULK_SUMMARY_OVERLAY, BulkPromptOverlay, CATALOG_SEARCH,
+    CHAT_AGENT_PICKER, CHAT_DETAIL, CHAT_MODEL_PICKER, CHAT_QUEUE, CLONE_FORM, CLONE_HOST_PICKER,
+    COMMAND_PALETTE, CONTEXT_MENU, CatalogSearchOverlay, ChatDetailOverlay, ChatQueueOverlay,
+    ContextMenuOverlay, DELETE_VARIANT_FORM, FormOverlay, FormSignal, GIT_PANEL, GitPanelOverlay,
+    INIT_PRODUCT_FORM, INTEGRATE_FORM, IntegrateOverlay, LoadedVariantDiff, MESSAGE_LOG_OVERLAY,
+    METRICS_OVERLAY, MOVE_ACTOR_FORM, NOTIFICATIONS_OVERLAY, NotificationHistory, PaletteOverlay,
+    Picked, PickerOption, PickerOverlay, SPAWN_FORM, SSH_PANEL, VARIANT_DIFF_OVERLAY,
+    mount_overlays,
+};
+use crate::ui::panes::{
+    CHAT_COMPOSER, CORE_LOGS, CoreLogsPane, DASHBOARD, DashboardPane, TERMINAL_PANE, mount_panes,
+};
+use crate::variant_diff::VariantDiff;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum FocusPane {
@@ -19,13 +53,15 @@ pub enum FocusPane {
     Variants,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
 pub enum ResultsViewMode {
     Table,
     Viz,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
 pub enum VizDensity {
     Compact,
     Normal,
@@ -48,8 +84,17 @@ pub enum VizSelection {
         variant_id: String,
         actor_id: String,
     },
+    SubAgent {
+        product_index: usize,
+        variant_id: String,
+        actor_id: String,
+        sub_agent_id: String,
+    },
 }
 
+/// Most status changes kept per sub-agent.
+const SUB_AGENT_HISTORY_LIMIT: usize = 16;
+
 #[derive(Debug, Clone)]
 pub struct SpawnRequest {
     pub variant_id: String,
@@ -74,7 +119,7 @@ pub struct BranchVariantRequest {
 
 #[derive(Debug, Clone)]
 pub struct DeleteVariantRequest {
-    pub variant_id: String,
+    pub variant_ids: Vec<String>,
     pub dry: bool,
 }
 
@@ -96,41 +141,39 @@ pub struct StartSshPortForwardRequest {
     pub preset_name: String,
 }
 
-#[derive(Debug, Clone)]
-struct SpawnFormState {
-    variant_id: String,
-    providers: Vec<String>,
-    selected_provider: usize,
-    initial_prompt: String,
-}
-
-#[derive(Debug, Clone)]
+/// Clone form context; `name` and `remote_host` are the values the target
+/// path template was last derived from.
+#[derive(Debug, Default)]
 struct CloneFormState {
-    selected_field: usize,
     name: String,
-    target_path: String,
-    remote_host: String,
-    selected_remote_host: usize,
-    host_picker_open: bool,
-    host_picker_query: String,
-    host_picker_selected: usize,
-    branch_name: String,
-    clone_type: String,
-    source_variant_id: String,
+    remote_host: Option<String>,
 }
 
-#[derive(Debug, Clone)]
+/// Branch form context; `branch` and `choice` are the form values as of the
+/// last sync, so an edit can tell typing from picking a suggestion.
+#[derive(Debug)]
 struct BranchFormState {
     variant_id: String,
-    branch_name: String,
     suggestions: Vec<String>,
-    selected_suggestion: usize,
+    branch: String,
+    choice: Option<String>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug)]
 struct DeleteVariantFormState {
-    variant_id: String,
-    remove_clone_directory: bool,
+    variant_ids: Vec<String>,
+    remove_clone: bool,
+}
+
+const COMMAND_HISTORY_LIMIT: usize = 50;
+const SSH_PANEL_FIELDS: [&str; 3] = ["host", "forward", "tmux"];
+const DELETE_FORM_LISTED_VARIANTS: usize = 8;
+
+/// Provider and prompt typed after `:spawn`, applied once spawn options arrive.
+#[derive(Debug, Clone)]
+struct SpawnPrefill {
+    provider: Option<String>,
+    prompt: Option<String>,
 }
 
 #[derive(Debug, Clone)]
@@ -140,40 +183,11 @@ struct MoveActorOption {
     product_name: String,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug)]
 struct MoveActorFormState {
     actor_id: String,
-    actor_title: String,
     source_variant_id: String,
-    source_variant_name: String,
     options: Vec<MoveActorOption>,
-    selected_option: usize,
-}
-
-#[derive(Debug, Clone)]
-struct InitProductFormState {
-    directory: String,
-}
-
-#[derive(Debug, Clone)]
-struct SshPanelState {
-    selected_host: usize,
-    selected_forward: usize,
-    selected_tmux_session: usize,
-    focus: SshPanelFocus,
-}
-
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-enum SshPanelFocus {
-    Hosts,
-    Presets,
-    TmuxSessions,
-}
-
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub enum ChatPickerKind {
-    Model,
-    Agent,
 }
 
 #[derive(Debug, Default, Deserialize, Serialize)]
@@ -184,6 +198,43 @@ struct PersistedChatSelection {
     agent: Option<String>,
 }
 
+/// UI layout and session state saved per directory in `darktui_layout.toml`.
+///
+/// Every field is optional so older or hand-edited files still load; missing
+/// values keep the built-in defaults.
+#[derive(Debug, Default, Deserialize, Serialize)]
+struct PersistedLayout {
+    #[serde(default)]
+    results_view_mode: Option<ResultsViewMode>,
+    #[serde(default)]
+    viz_density: Option<VizDensity>,
+    #[serde(default)]
+    viz_offset_x: Option<i32>,
+    #[serde(default)]
+    viz_offset_y: Option<i32>,
+    #[serde(default)]
+    body_split_with_chat: Option<Vec<u16>>,
+    #[serde(default)]
+    body_split_without_chat: Option<Vec<u16>>,
+    #[serde(default)]
+    inspector_visible: Option<bool>,
+    #[serde(default)]
+    chat_visible: Option<bool>,
+    #[serde(default)]
+    filter_variants_to_product: Option<bool>,
+    #[serde(default)]
+    selection: Option<PersistedSelection>,
+}
+
+/// Last catalog selection, stored by id so it survives reordering.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(tag = "kind", content = "id", rename_all = "lowercase")]
+enum PersistedSelection {
+    Product(String),
+    Variant(String),
+    Actor(String),
+}
+
 impl ResultsViewMode {
     pub fn toggle(self) -> Self {
         match self {
@@ -239,13 +290,6 @@ impl FocusPane {
         }
     }
 
-    pub fn previous(self) -> Self {
-        match self {
-            Self::Products => Self::Variants,
-            Self::Variants => Self::Products,
-        }
-    }
-
     pub fn label(self) -> &'static str {
         match self {
             Self::Products => "products",
@@ -271,6 +315,9 @@ pub enum ResizeTarget {
 pub struct App {
     directory: String,
     chat_preferences_path: PathBuf,
+    layout_path: PathBuf,
+    /// Saved selection waiting for the first snapshot to resolve against.
+    pending_layout_selection: Option<PersistedSelection>,
     refresh_seconds: u64,
     focus: FocusPane,
     results_view_mode: ResultsViewMode,
@@ -286,9 +333,6 @@ pub struct App {
     status_message: String,
     core_runtime_hint: String,
     core_logs_visible: bool,
-    core_logs_session: String,
-    core_logs_status: String,
-    core_logs_lines: Vec<String>,
     actor_last_message_previews: HashMap<String, String>,
     command_message: String,
     runtime_status: String,
@@ -302,18 +346,43 @@ pub struct App {
     resizing_target: Option<ResizeTarget>,
     /// Active drag anchor (set on mouse-down, cleared on mouse-up).
     drag_anchor: Option<DragAnchor>,
-    /// Color theme — loaded once at startup.
+    /// Color theme; replaced when the watched theme file changes.
     theme: Theme,
-    spawn_form: Option<SpawnFormState>,
-    init_product_form: Option<InitProductFormState>,
-    ssh_panel: Option<SshPanelState>,
+    theme_watcher: Option<ThemeWatcher>,
+    spawn_variant_id: Option<String>,
     clone_form: Option<CloneFormState>,
     branch_form: Option<BranchFormState>,
     delete_variant_form: Option<DeleteVariantFormState>,
     move_actor_form: Option<MoveActorFormState>,
+    /// Variants and actors marked for bulk actions.
+    marked_variants: BTreeSet<String>,
+    marked_actors: BTreeSet<String>,
+    /// Actor ids awaiting a second terminate press.
+    bulk_terminate_armed: Option<Vec<String>>,
+    bulk_run: Option<BulkRun>,
+    /// Active catalog search and the rows it leaves visible.
+    catalog_query: Option<CatalogQuery>,
+    catalog_visibility: Option<CatalogVisibility>,
+    notifications: NotificationCenter,
+    /// Error and status toasts; failures land here so later messages cannot hide them.
+    toasts: ToastStack,
+    /// Latest `/system/health` result, shown in the header badge.
+    core_health: CoreHealth,
+    /// Overlays mounted on the shared component runtime.
+    overlays: ComponentRuntime,
+    /// Embedded PTY in the chat column; keys pass through while focused.
+    terminal_pane: Option<TerminalPane>,
+    command_history: Vec<String>,
+    command_history_path: Option<PathBuf>,
+    spawn_prefill: Option<SpawnPrefill>,
+    keymap: Keymap<KeyCommand>,
     inspector_visible: bool,
     chat_visible: bool,
     chat_actor_id: Option<String>,
+    /// Sub-agent of the chat actor whose session the chat shows instead.
+    chat_sub_agent_id: Option<String>,
+    /// Status changes per sub-agent id, oldest first.
+    sub_agent_history: HashMap<String, Vec<SubAgentStatusChange>>,
     chat_messages: Vec<ActorChatMessageRow>,
     chat_history_limit: usize,
     chat_render_limit: usize,
@@ -321,26 +390,21 @@ pub struct App {
     chat_message_max_chars: usize,
     chat_scroll_lines: u16,
     chat_draft: String,
-    chat_composing: bool,
     chat_model_options: Vec<String>,
     chat_agent_options: Vec<String>,
     chat_selected_model: Option<String>,
     chat_selected_agent: Option<String>,
     chat_preferred_model: Option<String>,
     chat_preferred_agent: Option<String>,
-    chat_picker_open: Option<ChatPickerKind>,
-    chat_picker_query: String,
-    chat_picker_selected: usize,
     chat_autocomplete_open: bool,
     chat_autocomplete_mode: Option<char>,
     chat_autocomplete_query: String,
     chat_autocomplete_selected: usize,
     chat_autocomplete_items: Vec<String>,
-    chat_detail_popup_open: bool,
-    chat_detail_popup_scroll_lines: u16,
-    chat_detail_popup_message_index: Option<usize>,
     chat_workspace_file_cache: Vec<String>,
     chat_workspace_file_cache_loaded: bool,
+    chat_prompt_queue: PromptQueuePanel,
+    chat_attachments: Vec<ChatAttachment>,
     ssh_hosts: Vec<SshHostRow>,
     ssh_port_forwards: Vec<SshPortForwardRow>,
     ssh_active_forwards: Vec<TmuxSessionRow>,
@@ -354,13 +418,27 @@ pub struct App {
 
 impl App {
     pub fn new(directory: String, refresh_seconds: u64, theme: Theme) -> Self {
+        let mut app = Self::without_persisted_state(directory, refresh_seconds, theme);
+        app.load_persisted_state();
+        app
+    }
+
+    /// An app that reads nothing from disk: the prompt queue and command
+    /// history start empty and are kept in memory only. Headless snapshots use
+    /// it so their output does not depend on the machine they run on.
+    pub fn without_persisted_state(directory: String, refresh_seconds: u64, theme: Theme) -> Self {
         let chat_preferences_path = Path::new(&directory)
             .join(".darkfactory")
             .join("darktui.toml");
+        let layout_path = Path::new(&directory)
+            .join(".darkfactory")
+            .join("darktui_layout.toml");
 
         Self {
             directory,
             chat_preferences_path,
+            layout_path,
+            pending_layout_selection: None,
             refresh_seconds,
             focus: FocusPane::Products,
             results_view_mode: ResultsViewMode::Table,
@@ -375,9 +453,6 @@ impl App {
             status_message: "Booting dashboard".to_string(),
             core_runtime_hint: "core:unknown".to_string(),
             core_logs_visible: false,
-            core_logs_session: "dark-core".to_string(),
-            core_logs_status: "idle".to_string(),
-            core_logs_lines: Vec::new(),
             actor_last_message_previews: HashMap::new(),
             command_message: String::new(),
             runtime_status: "unknown".to_string(),
@@ -390,16 +465,37 @@ impl App {
             resizing_target: None,
             drag_anchor: None,
             theme,
-            spawn_form: None,
-            init_product_form: None,
-            ssh_panel: None,
+            theme_watcher: None,
+            spawn_variant_id: None,
             clone_form: None,
             branch_form: None,
             delete_variant_form: None,
             move_actor_form: None,
+            marked_variants: BTreeSet::new(),
+            marked_actors: BTreeSet::new(),
+            bulk_terminate_armed: None,
+            bulk_run: None,
+            catalog_query: None,
+            catalog_visibility: None,
+            notifications: NotificationCenter::default(),
+            toasts: ToastStack::new(),
+            core_health: CoreHealth::Unknown,
+            overlays: {
+                let mut overlays = ComponentRuntime::new();
+                mount_overlays(&mut overlays);
+                mount_panes(&mut overlays);
+                overlays
+            },
+            terminal_pane: None,
+            command_history: Vec::new(),
+            command_history_path: None,
+            spawn_prefill: None,
+            keymap: default_keymap(),
             inspector_visible: true,
             chat_visible: false,
             chat_actor_id: None,
+            chat_sub_agent_id: None,
+            sub_agent_history: HashMap::new(),
             chat_messages: Vec::new(),
             chat_history_limit: 80,
             chat_render_limit: 40,
@@ -407,26 +503,21 @@ impl App {
             chat_message_max_chars: 12_000,
             chat_scroll_lines: 0,
             chat_draft: String::new(),
-            chat_composing: false,
             chat_model_options: Vec::new(),
             chat_agent_options: Vec::new(),
             chat_selected_model: None,
             chat_selected_agent: None,
             chat_preferred_model: None,
             chat_preferred_agent: None,
-            chat_picker_open: None,
-            chat_picker_query: String::new(),
-            chat_picker_selected: 0,
             chat_autocomplete_open: false,
             chat_autocomplete_mode: None,
             chat_autocomplete_query: String::new(),
             chat_autocomplete_selected: 0,
             chat_autocomplete_items: Vec::new(),
-            chat_detail_popup_open: false,
-            chat_detail_popup_scroll_lines: 0,
-            chat_detail_popup_message_index: None,
             chat_workspace_file_cache: Vec::new(),
             chat_workspace_file_cache_loaded: false,
+            chat_prompt_queue: PromptQueuePanel::default(),
+            chat_attachments: Vec::new(),
             ssh_hosts: Vec::new(),
             ssh_port_forwards: Vec::new(),
             ssh_active_forwards: Vec::new(),
@@ -439,10 +530,58 @@ impl App {
         }
     }
 
+    /// Loads the directory's prompt queue and the user's command history.
+    /// Unreadable files are reported and left untouched.
+    fn load_persisted_state(&mut self) {
+        let (chat_prompt_queue, chat_prompt_queue_error) = PromptQueuePanel::load(
+            Path::new(&self.directory)
+                .join(".darkfactory")
+                .join("darktui_queue.json"),
+        );
+        self.chat_prompt_queue = chat_prompt_queue;
+        if let Some(error) = chat_prompt_queue_error {
+            warn!(
+                error = %format!("{error:#}"),
+                "Dark TUI // Chat // Prompt queue not loaded"
+            );
+            self.set_error(format!(
+                "Prompt queue not loaded, queued prompts are kept in memory only: {error:#}"
+            ));
+        }
+
+        let Some(path) = std::env::var_os("HOME").map(|home| {
+            PathBuf::from(home)
+                .join(".darkfactory")
+                .join("darktui_history.json")
+        }) else {
+            return;
+        };
+        match load_command_history(&path) {
+            Ok(history) => {
+                self.command_history = history;
+                self.command_history_path = Some(path);
+            }
+            // Keep the unreadable file as it is: with no path nothing overwrites it.
+            Err(error) => {
+                warn!(
+                    error = %format!("{error:#}"),
+                    "Dark TUI // Palette // Command history not loaded"
+                );
+                self.set_error(format!(
+                    "Command history not loaded, new commands are kept in memory only: {error:#}"
+                ));
+            }
+        }
+    }
+
     pub fn refresh_seconds(&self) -> u64 {
         self.refresh_seconds
     }
 
+    pub fn directory(&self) -> &str {
+        &self.directory
+    }
+
     /// Compact directory display: last 2 path components or full path if short.
     pub fn directory_display(&self) -> &str {
         let d = self.directory.as_str();
@@ -489,16 +628,20 @@ impl App {
         self.core_logs_visible
     }
 
-    pub fn core_logs_session(&self) -> &str {
-        &self.core_logs_session
+    pub fn is_core_logs_focused(&self) -> bool {
+        self.core_logs_visible && self.overlays.base_focus() == Some(CORE_LOGS)
     }
 
-    pub fn core_logs_status(&self) -> &str {
-        &self.core_logs_status
+    pub fn core_logs(&self) -> Option<&CoreLogView> {
+        self.overlays
+            .component::<CoreLogsPane>(CORE_LOGS)
+            .map(CoreLogsPane::view)
     }
 
-    pub fn core_logs_lines(&self) -> &[String] {
-        &self.core_logs_lines
+    pub fn core_logs_mut(&mut self) -> Option<&mut CoreLogView> {
+        self.overlays
+            .component_mut::<CoreLogsPane>(CORE_LOGS)
+            .map(CoreLogsPane::view_mut)
     }
 
     pub fn actor_last_message_preview(&self, actor_id: &str) -> Option<&str> {
@@ -538,6 +681,29 @@ impl App {
         &self.theme
     }
 
+    pub fn set_theme(&mut self, theme: Theme) {
+        self.theme = theme;
+    }
+
+    pub fn set_theme_watcher(&mut self, watcher: Option<ThemeWatcher>) {
+        self.theme_watcher = watcher;
+    }
+
+    /// Hot reloads the theme file after it changes; a broken file keeps the
+    /// current theme and reports the error.
+    pub fn poll_theme(&mut self) {
+        let Some(reloaded) = self.theme_watcher.as_mut().and_then(ThemeWatcher::poll) else {
+            return;
+        };
+        match reloaded {
+            Ok(theme) => {
+                self.set_theme(theme);
+                self.set_status("Theme reloaded");
+            }
+            Err(error) => self.set_error(format!("Theme reload failed: {error}")),
+        }
+    }
+
     pub fn body_split_with_chat(&self) -> &HorizontalSplit {
         &self.body_split_with_chat
     }
@@ -590,54 +756,78 @@ impl App {
     }
 
     pub fn is_spawn_form_open(&self) -> bool {
-        self.spawn_form.is_some()
+        self.overlays.is_visible(SPAWN_FORM)
     }
 
     pub fn is_clone_form_open(&self) -> bool {
-        self.clone_form.is_some()
+        self.overlays.is_visible(CLONE_FORM)
     }
 
     pub fn is_branch_form_open(&self) -> bool {
-        self.branch_form.is_some()
+        self.overlays.is_visible(BRANCH_FORM)
     }
 
     pub fn is_delete_variant_form_open(&self) -> bool {
-        self.delete_variant_form.is_some()
+        self.overlays.is_visible(DELETE_VARIANT_FORM)
     }
 
-    pub fn is_move_actor_form_open(&self) -> bool {
-        self.move_actor_form.is_some()
+    pub fn is_integrate_form_open(&self) -> bool {
+        self.overlays.is_visible(INTEGRATE_FORM)
     }
 
-    pub fn is_init_product_form_open(&self) -> bool {
-        self.init_product_form.is_some()
+    pub fn is_git_panel_open(&self) -> bool {
+        self.overlays.is_visible(GIT_PANEL)
     }
 
-    pub fn is_ssh_panel_open(&self) -> bool {
-        self.ssh_panel.is_some()
+    pub fn is_move_actor_form_open(&self) -> bool {
+        self.overlays.is_visible(MOVE_ACTOR_FORM)
     }
 
-    pub fn open_init_product_form(&mut self) {
-        self.init_product_form = Some(InitProductFormState {
-            directory: self.directory.clone(),
-        });
+    pub fn is_init_product_form_open(&self) -> bool {
+        self.overlays.is_visible(INIT_PRODUCT_FORM)
     }
 
-    pub fn close_init_product_form(&mut self) {
-        self.init_product_form = None;
+    pub fn open_init_product_form(&mut self) {
+        let form = Form::new("Init Product")
+            .field(
+                FormField::text("directory", "Directory")
+                    .value(self.directory.clone())
+                    .hint("Defaults to current working directory.")
+                    .required(),
+            )
+            .submit_label("initialize");
+        let form = with_form_keys(&self.keymap, FORM_MODE, form);
+        self.open_form(INIT_PRODUCT_FORM, form);
     }
 
     pub fn open_ssh_panel(&mut self) {
-        self.ssh_panel = Some(SshPanelState {
-            selected_host: 0,
-            selected_forward: 0,
-            selected_tmux_session: 0,
-            focus: SshPanelFocus::Hosts,
-        });
+        let panel = self.build_ssh_panel();
+        self.open_form(SSH_PANEL, panel);
+    }
+
+    /// Shows `diff`; reloading the open variant keeps its layout and selected file.
+    pub fn open_variant_diff(&mut self, diff: VariantDiff) {
+        let variant_name = self
+            .variants
+            .iter()
+            .find(|variant| variant.id == diff.variant_id)
+            .map(|variant| variant.name.clone())
+            .unwrap_or_else(|| diff.variant_id.clone());
+        self.feed_overlays(LoadedVariantDiff { variant_name, diff });
+        self.show_overlay(VARIANT_DIFF_OVERLAY);
     }
 
-    pub fn close_ssh_panel(&mut self) {
-        self.ssh_panel = None;
+    /// Branch of the product that owns `variant_id`, used as the diff base.
+    pub fn product_branch_for_variant(&self, variant_id: &str) -> Option<String> {
+        let variant = self
+            .variants
+            .iter()
+            .find(|variant| variant.id == variant_id)?;
+        self.products
+            .iter()
+            .find(|product| product.id == variant.product_id)
+            .map(|product| product.branch.clone())
+            .filter(|branch| branch != "-")
     }
 
     pub fn set_ssh_info(
@@ -651,156 +841,138 @@ impl App {
         self.ssh_port_forwards = port_forwards;
         self.ssh_active_forwards = active_forwards;
         self.tmux_sessions = tmux_sessions;
-        if let Some(panel) = self.ssh_panel.as_mut() {
-            panel.selected_host = panel
-                .selected_host
-                .min(self.ssh_hosts.len().saturating_sub(1));
-            let len = self.ssh_port_forwards.len();
-            panel.selected_forward = panel.selected_forward.min(len.saturating_sub(1));
-            panel.selected_tmux_session = panel
-                .selected_tmux_session
-                .min(self.tmux_sessions.len().saturating_sub(1));
-        }
-    }
-
-    pub fn ssh_hosts(&self) -> &[SshHostRow] {
-        &self.ssh_hosts
-    }
-
-    pub fn ssh_port_forwards(&self) -> &[SshPortForwardRow] {
-        &self.ssh_port_forwards
-    }
-
-    pub fn ssh_active_forwards(&self) -> &[TmuxSessionRow] {
-        &self.ssh_active_forwards
-    }
-
-    pub fn tmux_sessions(&self) -> &[TmuxSessionRow] {
-        &self.tmux_sessions
-    }
-
-    pub fn ssh_panel_selected_forward_index(&self) -> Option<usize> {
-        self.ssh_panel.as_ref().map(|panel| panel.selected_forward)
-    }
-
-    pub fn ssh_panel_selected_tmux_index(&self) -> Option<usize> {
-        self.ssh_panel
-            .as_ref()
-            .map(|panel| panel.selected_tmux_session)
-    }
-
-    pub fn ssh_panel_selected_host_index(&self) -> Option<usize> {
-        self.ssh_panel.as_ref().map(|panel| panel.selected_host)
-    }
-
-    pub fn ssh_panel_focus_is_tmux(&self) -> bool {
-        self.ssh_panel
-            .as_ref()
-            .map(|panel| panel.focus == SshPanelFocus::TmuxSessions)
-            .unwrap_or(false)
-    }
-
-    pub fn ssh_panel_focus_is_hosts(&self) -> bool {
-        self.ssh_panel
-            .as_ref()
-            .map(|panel| panel.focus == SshPanelFocus::Hosts)
-            .unwrap_or(false)
-    }
-
-    pub fn ssh_panel_toggle_focus(&mut self) {
-        let Some(panel) = self.ssh_panel.as_mut() else {
-            return;
-        };
-
-        panel.focus = match panel.focus {
-            SshPanelFocus::Hosts => SshPanelFocus::Presets,
-            SshPanelFocus::Presets => SshPanelFocus::TmuxSessions,
-            SshPanelFocus::TmuxSessions => SshPanelFocus::Hosts,
-        };
-    }
-
-    pub fn ssh_panel_move_up(&mut self) {
-        let Some(panel) = self.ssh_panel.as_mut() else {
+        let Some(previous) = self.form(SSH_PANEL) else {
             return;
         };
-
-        match panel.focus {
-            SshPanelFocus::Hosts => {
-                panel.selected_host = previous_index(panel.selected_host, self.ssh_hosts.len());
-            }
-            SshPanelFocus::Presets => {
-                panel.selected_forward =
-                    previous_index(panel.selected_forward, self.ssh_port_forwards.len());
-            }
-            SshPanelFocus::TmuxSessions => {
-                panel.selected_tmux_session =
-                    previous_index(panel.selected_tmux_session, self.tmux_sessions.len());
+        // Rebuild over the new rows, keeping the choices that still exist.
+        let mut panel = self.build_ssh_panel();
+        for key in SSH_PANEL_FIELDS {
+            if let Some(value) = previous.choice(key) {
+                panel.select_value(key, value);
             }
         }
+        if let Some(key) = previous.focused_key() {
+            panel.focus_field(key);
+        }
+        if let Some(overlay) = self.overlays.component_mut::<FormOverlay>(SSH_PANEL) {
+            overlay.set_form(panel);
+        }
     }
 
-    pub fn ssh_panel_move_down(&mut self) {
-        let Some(panel) = self.ssh_panel.as_mut() else {
-            return;
+    fn build_ssh_panel(&self) -> Form {
+        let key = |commands: &[PopupCommand]| {
+            popup_hint(&self.keymap, SSH_MODE, commands).unwrap_or_else(|| "-".to_string())
         };
-
-        match panel.focus {
-            SshPanelFocus::Hosts => {
-                panel.selected_host = next_index(panel.selected_host, self.ssh_hosts.len());
-            }
-            SshPanelFocus::Presets => {
-                panel.selected_forward =
-                    next_index(panel.selected_forward, self.ssh_port_forwards.len());
-            }
-            SshPanelFocus::TmuxSessions => {
-                panel.selected_tmux_session =
-                    next_index(panel.selected_tmux_session, self.tmux_sessions.len());
+        let mut form = Form::new("SSH Hosts + Port Forwards")
+            .note(format!(
+                "{}: local tmux copy/attach   {}: ensure remote agent tmux",
+                key(&[PopupCommand::CopyAttach, PopupCommand::Attach]),
+                key(&[PopupCommand::EnsureTmux]),
+            ))
+            .note(format!(
+                "{}: copy remote attach   {}: remote attach",
+                key(&[PopupCommand::CopyAgentAttach]),
+                key(&[PopupCommand::AgentAttach]),
+            ))
+            .note("")
+            .note("Active SSH forwards:");
+        if self.ssh_active_forwards.is_empty() {
+            form = form.note("  (no active tunnel sessions)");
+        }
+        for session in &self.ssh_active_forwards {
+            form = form.note(format!("  - {}", tmux_session_label(session)));
+        }
+
+        let hosts = self.ssh_hosts.iter().map(|host| {
+            SelectOption::new(
+                host.key.clone(),
+                format!(
+                    "{} [{}] key={} host={} user={} port={} path={}",
+                    host.label,
+                    host.source,
+                    host.key,
+                    host.host,
+                    host.user,
+                    host.port,
+                    host.default_path
+                ),
+            )
+        });
+        let forwards = self.ssh_port_forwards.iter().map(|preset| {
+            let host = if preset.host.trim().is_empty() {
+                "(host required)"
+            } else {
+                preset.host.as_str()
+            };
+            let mut label = format!(
+                "{} {} -> {}:{} (host={host})",
+                preset.name, preset.local_port, preset.remote_host, preset.remote_port
+            );
+            if preset.description != "-" {
+                label.push_str(&format!(" - {}", preset.description));
             }
-        }
+            SelectOption::new(preset.name.clone(), label)
+        });
+        let sessions = self
+            .tmux_sessions
+            .iter()
+            .map(|session| SelectOption::new(session.name.clone(), tmux_session_label(session)));
+
+        let confirm = key(&[PopupCommand::Confirm]);
+        let form = form
+            .field(FormField::select("host", "Hosts").options(hosts).hint(
+                if self.ssh_hosts.is_empty() {
+                    "No hosts discovered.".to_string()
+                } else {
+                    format!("{confirm}: ensure the remote agent tmux session.")
+                },
+            ))
+            .field(
+                FormField::select("forward", "Port forward presets")
+                    .options(forwards)
+                    .hint(if self.ssh_port_forwards.is_empty() {
+                        "Configure [ssh].portForwards in config.toml.".to_string()
+                    } else {
+                        format!("{confirm}: start the forward.")
+                    }),
+            )
+            .field(
+                FormField::select("tmux", "tmux sessions")
+                    .options(sessions)
+                    .hint(if self.tmux_sessions.is_empty() {
+                        "tmux not running or no sessions.".to_string()
+                    } else {
+                        format!("{confirm}: attach.")
+                    }),
+            )
+            .submit_label("run");
+        with_form_keys(&self.keymap, SSH_MODE, form)
     }
 
     pub fn take_start_ssh_port_forward_request(&self) -> Option<StartSshPortForwardRequest> {
-        let panel = self.ssh_panel.as_ref()?;
-        let preset = self.ssh_port_forwards.get(panel.selected_forward)?;
+        let name = self.form(SSH_PANEL)?.choice("forward")?;
+        let preset = self
+            .ssh_port_forwards
+            .iter()
+            .find(|preset| preset.name == name)?;
         Some(StartSshPortForwardRequest {
             preset_name: preset.name.clone(),
         })
     }
 
     pub fn ssh_panel_attach_command(&self) -> Option<String> {
-        let panel = self.ssh_panel.as_ref()?;
-        let session = self.tmux_sessions.get(panel.selected_tmux_session)?;
-        Some(format!("tmux attach-session -t {}", session.name))
+        let session = self.form(SSH_PANEL)?.choice("tmux")?;
+        Some(format!("tmux attach-session -t {session}"))
     }
 
     pub fn ssh_panel_selected_host(&self) -> Option<&SshHostRow> {
-        let panel = self.ssh_panel.as_ref()?;
-        self.ssh_hosts.get(panel.selected_host)
-    }
-
-    pub fn init_product_form_directory(&self) -> Option<&str> {
-        self.init_product_form
-            .as_ref()
-            .map(|form| form.directory.as_str())
-    }
-
-    pub fn init_product_form_insert_char(&mut self, value: char) {
-        let Some(form) = self.init_product_form.as_mut() else {
-            return;
-        };
-        form.directory.push(value);
-    }
-
-    pub fn init_product_form_backspace(&mut self) {
-        let Some(form) = self.init_product_form.as_mut() else {
-            return;
-        };
-        form.directory.pop();
+        let key = self.form(SSH_PANEL)?.choice("host")?;
+        self.ssh_hosts.iter().find(|host| host.key == key)
     }
 
     pub fn take_init_product_request(&mut self) -> Option<InitProductRequest> {
-        let form = self.init_product_form.take()?;
-        let directory = form.directory.trim().to_string();
+        let form = self.take_form(INIT_PRODUCT_FORM)?;
+        let directory = form.text("directory").trim().to_string();
         if directory.is_empty() {
             return None;
         }
@@ -808,28 +980,35 @@ impl App {
         Some(InitProductRequest { directory })
     }
 
-    pub fn spawn_form_providers(&self) -> Option<&[String]> {
-        self.spawn_form
-            .as_ref()
-            .map(|form| form.providers.as_slice())
-    }
-
-    pub fn spawn_form_selected_provider_index(&self) -> Option<usize> {
-        self.spawn_form.as_ref().map(|form| form.selected_provider)
-    }
-
-    pub fn spawn_form_prompt(&self) -> Option<&str> {
-        self.spawn_form
-            .as_ref()
-            .map(|form| form.initial_prompt.as_str())
-    }
-
-    pub fn open_spawn_form(
+    /// Opens the spawn form right away; providers arrive through
+    /// [`Self::set_spawn_form_providers`].
+    pub fn open_spawn_form(&mut self, variant_id: &str) {
+        let form = Form::new("Spawn in TUI")
+            .field(
+                FormField::select("provider", "Provider")
+                    .loading()
+                    .required(),
+            )
+            .field(
+                FormField::multiline("prompt", "Initial prompt")
+                    .hint("Optional; sent as the first message."),
+            )
+            .submit_label("spawn")
+            .focus_on("prompt");
+        let form = with_form_keys(&self.keymap, FORM_MODE, form);
+        self.spawn_variant_id = Some(variant_id.to_string());
+        self.open_form(SPAWN_FORM, form);
+    }
+
+    pub fn set_spawn_form_providers(
         &mut self,
-        variant_id: &str,
         mut providers: Vec<String>,
         default_provider: Option<&str>,
     ) {
+        let Some(form) = self.form_mut(SPAWN_FORM) else {
+            return;
+        };
+
         providers.retain(|provider| !provider.trim().is_empty());
         providers.sort();
         providers.dedup();
@@ -838,42 +1017,50 @@ impl App {
             providers.push("mock".to_string());
         }
 
-        let selected_provider = default_provider
-            .and_then(|default| providers.iter().position(|provider| provider == default))
-            .unwrap_or(0);
-
-        self.spawn_form = Some(SpawnFormState {
-            variant_id: variant_id.to_string(),
-            providers,
-            selected_provider,
-            initial_prompt: String::new(),
-        });
+        form.set_options("provider", providers, default_provider);
     }
 
     pub fn close_spawn_form(&mut self) {
-        self.spawn_form = None;
+        self.spawn_variant_id = None;
+        self.overlays.hide(SPAWN_FORM);
     }
 
     pub fn open_clone_form(&mut self) {
+        let hosts = std::iter::once(SelectOption::new("", "local (no remote host)")).chain(
+            self.ssh_hosts
+                .iter()
+                .map(|host| SelectOption::new(host.key.clone(), clone_host_label(host))),
+        );
         let default_remote_host = self
             .ssh_hosts
             .first()
             .map(|host| host.key.clone())
             .unwrap_or_default();
-        self.clone_form = Some(CloneFormState {
-            selected_field: 0,
-            name: String::new(),
-            target_path: String::new(),
-            remote_host: default_remote_host,
-            selected_remote_host: 0,
-            host_picker_open: false,
-            host_picker_query: String::new(),
-            host_picker_selected: 0,
-            branch_name: String::new(),
-            clone_type: String::new(),
-            source_variant_id: String::new(),
-        });
-
+        let form = Form::new("Clone Variant")
+            .note("Leave fields blank to use automatic defaults.")
+            .field(FormField::text("name", "Name"))
+            .field(
+                FormField::text("target_path", "Target path")
+                    .hint("Remote example: @ssh://devbox/srv/workspace/clone-01"),
+            )
+            .field(
+                FormField::select("remote_host", "Remote host")
+                    .options(hosts)
+                    .selected(&default_remote_host)
+                    .hint(format!(
+                        "{} opens the host picker.",
+                        popup_hint(&self.keymap, FORM_MODE, &[PopupCommand::Confirm])
+                            .unwrap_or_else(|| "Confirm".to_string())
+                    )),
+            )
+            .field(FormField::text("branch_name", "Branch name"))
+            .field(FormField::text("clone_type", "Clone type"))
+            .field(FormField::text("source_variant_id", "Source variant"))
+            .submit_label("clone");
+        let form = with_form_keys(&self.keymap, FORM_MODE, form);
+
+        self.clone_form = Some(CloneFormState::default());
+        self.open_form(CLONE_FORM, form);
         self.clone_form_apply_remote_host_template(false);
     }
 
@@ -895,196 +1082,367 @@ impl App {
         suggestions.sort();
         suggestions.dedup();
 
+        let form = Form::new("Switch Variant Branch")
+            .field(
+                FormField::text("branch", "Branch")
+                    .value(variant.branch)
+                    .required(),
+            )
+            .field(
+                FormField::select("suggestion", "Suggestions")
+                    .hint("Arrows copy the suggestion into Branch."),
+            )
+            .submit_label("switch branch");
+        let form = with_form_keys(&self.keymap, FORM_MODE, form);
+
         self.branch_form = Some(BranchFormState {
             variant_id: variant.id,
-            branch_name: variant.branch,
             suggestions,
-            selected_suggestion: 0,
+            branch: String::new(),
+            choice: None,
         });
+        self.open_form(BRANCH_FORM, form);
+        self.refresh_branch_suggestions();
 
         true
     }
 
-    pub fn close_branch_form(&mut self) {
-        self.branch_form = None;
-    }
-
-    pub fn close_clone_form(&mut self) {
-        self.clone_form = None;
-    }
-
-    pub fn branch_form_branch_name(&self) -> Option<&str> {
-        self.branch_form
-            .as_ref()
-            .map(|form| form.branch_name.as_str())
-    }
-
-    pub fn branch_form_suggestions(&self) -> Option<Vec<&str>> {
-        self.branch_form.as_ref().map(branch_suggestions_for)
-    }
-
-    pub fn branch_form_selected_suggestion_index(&self) -> Option<usize> {
-        self.branch_form
-            .as_ref()
-            .map(|form| form.selected_suggestion)
-    }
-
-    pub fn branch_form_move_up(&mut self) {
-        let Some(form) = self.branch_form.as_mut() else {
-            return;
-        };
-
-        let len = branch_suggestions_for(form).len();
-        form.selected_suggestion = previous_index(form.selected_suggestion, len);
-    }
-
-    pub fn branch_form_move_down(&mut self) {
-        let Some(form) = self.branch_form.as_mut() else {
-            return;
-        };
-
-        let len = branch_suggestions_for(form).len();
-        form.selected_suggestion = next_index(form.selected_suggestion, len);
-    }
-
-    pub fn branch_form_set_selected(&mut self, index: usize) {
-        let Some(form) = self.branch_form.as_mut() else {
+    /// Syncs the branch form after an edit: typing refilters the
+    /// suggestions, picking a suggestion copies it into the branch input.
+    fn sync_branch_form(&mut self) {
+        let (Some(state), Some(form)) = (self.branch_form.as_ref(), self.form(BRANCH_FORM)) else {
             return;
         };
+        let choice = form.choice("suggestion").map(str::to_string);
+        let picked = choice != state.choice;
+        let typed = form.text("branch") != state.branch;
 
-        let len = branch_suggestions_for(form).len();
-        if len == 0 {
-            form.selected_suggestion = 0;
-            return;
+        if picked {
+            if let (Some(choice), Some(form)) = (choice, self.form_mut(BRANCH_FORM)) {
+                form.set_text("branch", choice);
+            }
+            self.remember_branch_form();
+        } else if typed {
+            self.refresh_branch_suggestions();
         }
-
-        form.selected_suggestion = index.min(len.saturating_sub(1));
-    }
-
-    pub fn branch_form_insert_char(&mut self, value: char) {
-        let Some(form) = self.branch_form.as_mut() else {
-            return;
-        };
-
-        form.branch_name.push(value);
-        form.selected_suggestion = 0;
     }
 
-    pub fn branch_form_backspace(&mut self) {
-        let Some(form) = self.branch_form.as_mut() else {
+    fn refresh_branch_suggestions(&mut self) {
+        let (Some(state), Some(form)) = (self.branch_form.as_ref(), self.form(BRANCH_FORM)) else {
             return;
         };
 
-        form.branch_name.pop();
-        form.selected_suggestion = 0;
+        let suggestions: Vec<String> =
+            branch_suggestions_for(&state.suggestions, form.text("branch"))
+                .into_iter()
+                .map(ToString::to_string)
+                .collect();
+        if let Some(form) = self.form_mut(BRANCH_FORM) {
+            form.set_options("suggestion", suggestions, None);
+        }
+        self.remember_branch_form();
     }
 
-    pub fn branch_form_apply_suggestion(&mut self) {
-        let Some(form) = self.branch_form.as_mut() else {
+    fn remember_branch_form(&mut self) {
+        let Some(form) = self.form(BRANCH_FORM) else {
             return;
         };
-
-        let suggestions = branch_suggestions_for(form);
-        if suggestions.is_empty() {
-            return;
+        let branch = form.text("branch").to_string();
+        let choice = form.choice("suggestion").map(str::to_string);
+        if let Some(state) = self.branch_form.as_mut() {
+            state.branch = branch;
+            state.choice = choice;
         }
-
-        let index = form
-            .selected_suggestion
-            .min(suggestions.len().saturating_sub(1));
-        form.branch_name = suggestions[index].to_string();
     }
 
     pub fn take_branch_request(&mut self) -> Option<BranchVariantRequest> {
-        let form = self.branch_form.take()?;
-        let branch_name = form.branch_name.trim().to_string();
+        let state = self.branch_form.take()?;
+        let form = self.take_form(BRANCH_FORM)?;
+        let branch_name = form.text("branch").trim().to_string();
         if branch_name.is_empty() {
             return None;
         }
 
         Some(BranchVariantRequest {
-            variant_id: form.variant_id,
+            variant_id: state.variant_id,
             branch_name,
         })
     }
 
     pub fn open_delete_variant_form(&mut self, variant_id: &str) {
-        self.delete_variant_form = Some(DeleteVariantFormState {
-            variant_id: variant_id.to_string(),
-            remove_clone_directory: false,
-        });
+        self.open_bulk_delete_variant_form(vec![variant_id.to_string()]);
     }
 
-    pub fn close_delete_variant_form(&mut self) {
-        self.delete_variant_form = None;
-    }
+    pub fn open_bulk_delete_variant_form(&mut self, variant_ids: Vec<String>) {
+        let heading = if variant_ids.len() > 1 {
+            format!("Confirm delete for {} variants:", variant_ids.len())
+        } else {
+            "Confirm delete for variant:".to_string()
+        };
+        let mut form = Form::new("Delete Variant").note(heading);
+        for variant_id in variant_ids.iter().take(DELETE_FORM_LISTED_VARIANTS) {
+            let label = self
+                .variants
+                .iter()
+                .find(|variant| &variant.id == variant_id)
+                .map(|variant| format!("  {} ({variant_id})", variant.name))
+                .unwrap_or_else(|| format!("  {variant_id}"));
+            form = form.note(label);
+        }
+        if variant_ids.len() > DELETE_FORM_LISTED_VARIANTS {
+            form = form.note(format!(
+                "  +{} more",
+                variant_ids.len() - DELETE_FORM_LISTED_VARIANTS
+            ));
+        }
 
-    pub fn delete_variant_form_variant_id(&self) -> Option<&str> {
-        self.delete_variant_form
-            .as_ref()
-            .map(|form| form.variant_id.as_str())
-    }
+        let form = form
+            .field(
+                FormField::toggle("remove_clone", "Remove clone directory (destructive)")
+                    .hint("Off keeps the clone directory and deletes the row only."),
+            )
+            .submit_label("delete");
+        let form = with_form_keys(&self.keymap, FORM_MODE, form);
 
-    pub fn delete_variant_form_remove_clone_directory(&self) -> bool {
-        self.delete_variant_form
-            .as_ref()
-            .map(|form| form.remove_clone_directory)
-            .unwrap_or(false)
+        self.delete_variant_form = Some(DeleteVariantFormState {
+            variant_ids,
+            remove_clone: false,
+        });
+        self.open_form(DELETE_VARIANT_FORM, form);
     }
 
-    pub fn toggle_delete_variant_remove_clone_directory(&mut self) {
-        let Some(form) = self.delete_variant_form.as_mut() else {
+    /// Reports a flip of the remove-clone toggle in the status line.
+    fn sync_delete_variant_form(&mut self) {
+        let Some(remove) = self
+            .form(DELETE_VARIANT_FORM)
+            .map(|form| form.flag("remove_clone"))
+        else {
             return;
         };
-
-        form.remove_clone_directory = !form.remove_clone_directory;
+        let Some(state) = self.delete_variant_form.as_mut() else {
+            return;
+        };
+        if remove == state.remove_clone {
+            return;
+        }
+        state.remove_clone = remove;
+        self.set_status(format!(
+            "Delete mode: {}",
+            if remove {
+                "remove clone directory"
+            } else {
+                "keep clone directory"
+            }
+        ));
     }
 
     pub fn take_delete_variant_request(&mut self) -> Option<DeleteVariantRequest> {
-        let form = self.delete_variant_form.take()?;
+        let state = self.delete_variant_form.take()?;
+        let form = self.take_form(DELETE_VARIANT_FORM)?;
         Some(DeleteVariantRequest {
-            variant_id: form.variant_id,
-            dry: !form.remove_clone_directory,
+            dry: !form.flag("remove_clone"),
+            variant_ids: state.variant_ids,
         })
     }
 
-    pub fn move_actor_form_actor_title(&self) -> Option<&str> {
-        self.move_actor_form
-            .as_ref()
-            .map(|form| form.actor_title.as_str())
+    pub fn open_integrate_form(&mut self, variant_id: &str) {
+        if let Some(form) = self
+            .overlays
+            .component_mut::<IntegrateOverlay>(INTEGRATE_FORM)
+        {
+            form.open(variant_id);
+        }
+        self.show_overlay(INTEGRATE_FORM);
     }
 
-    pub fn move_actor_form_source_variant_id(&self) -> Option<&str> {
-        self.move_actor_form
-            .as_ref()
-            .map(|form| form.source_variant_id.as_str())
+    pub fn close_integrate_form(&mut self) {
+        self.overlays.hide(INTEGRATE_FORM);
     }
 
-    pub fn move_actor_form_source_variant_name(&self) -> Option<&str> {
-        self.move_actor_form
-            .as_ref()
-            .map(|form| form.source_variant_name.as_str())
+    pub(crate) fn integrate_form_mut(&mut self) -> Option<&mut IntegrateOverlay> {
+        self.open_overlay_mut(INTEGRATE_FORM)
     }
 
-    pub fn move_actor_form_options(&self) -> Option<Vec<(&str, &str, &str)>> {
-        self.move_actor_form.as_ref().map(|form| {
-            form.options
-                .iter()
-                .map(|option| {
-                    (
-                        option.variant_id.as_str(),
-                        option.variant_name.as_str(),
-                        option.product_name.as_str(),
-                    )
-                })
-                .collect()
-        })
+    pub fn open_git_panel(&mut self, variant_id: &str, directory: PathBuf) {
+        if let Some(panel) = self.overlays.component_mut::<GitPanelOverlay>(GIT_PANEL) {
+            panel.open(variant_id, directory);
+        }
+        self.show_overlay(GIT_PANEL);
     }
 
-    pub fn move_actor_form_selected_option_index(&self) -> Option<usize> {
-        self.move_actor_form
-            .as_ref()
-            .map(|form| form.selected_option)
+    pub(crate) fn git_panel(&self) -> Option<&GitPanelOverlay> {
+        self.open_overlay(GIT_PANEL)
+    }
+
+    pub(crate) fn git_panel_mut(&mut self) -> Option<&mut GitPanelOverlay> {
+        self.open_overlay_mut(GIT_PANEL)
+    }
+
+    pub fn is_command_palette_open(&self) -> bool {
+        self.overlays.is_visible(COMMAND_PALETTE)
+    }
+
+    pub fn open_command_palette(&mut self) {
+        if let Some(palette) = self
+            .overlays
+            .component_mut::<PaletteOverlay>(COMMAND_PALETTE)
+        {
+            palette.open();
+        }
+        self.show_overlay(COMMAND_PALETTE);
+        self.refresh_command_palette();
+    }
+
+    pub fn close_command_palette(&mut self) {
+        self.overlays.hide(COMMAND_PALETTE);
+    }
+
+    pub(crate) fn command_palette(&self) -> Option<&PaletteOverlay> {
+        self.open_overlay(COMMAND_PALETTE)
+    }
+
+    /// Refills the palette rows for its input, with each command's key.
+    fn refresh_command_palette(&mut self) {
+        let Some(input) = self
+            .command_palette()
+            .map(|palette| palette.input().to_string())
+        else {
+            return;
+        };
+        let rows = palette_items(self, &input, &self.command_history)
+            .into_iter()
+            .map(|item| {
+                let key = match &item {
+                    PaletteItem::Command { id, .. } => {
+                        command_key_hint(self, *id).unwrap_or_default()
+                    }
+                    PaletteItem::Recent(_) => String::new(),
+                };
+                (item, key)
+            })
+            .collect();
+        if let Some(palette) = self.open_overlay_mut::<PaletteOverlay>(COMMAND_PALETTE) {
+            palette.set_rows(rows);
+        }
+    }
+
+    /// Opens the commands that apply to `target` at the clicked cell;
+    /// `false` when none does.
+    pub(crate) fn open_context_menu(&mut self, target: VizSelection, col: u16, row: u16) -> bool {
+        let Some(menu) = ContextMenuState::open(self, target, col, row) else {
+            return false;
+        };
+        if let Some(overlay) = self
+            .overlays
+            .component_mut::<ContextMenuOverlay>(CONTEXT_MENU)
+        {
+            overlay.open(menu);
+        }
+        self.show_overlay(CONTEXT_MENU);
+        true
+    }
+
+    pub fn close_context_menu(&mut self) {
+        self.overlays.hide(CONTEXT_MENU);
+    }
+
+    /// Node the open context menu acts on.
+    pub fn context_menu_target(&self) -> Option<&VizSelection> {
+        self.open_overlay::<ContextMenuOverlay>(CONTEXT_MENU)
+            .and_then(ContextMenuOverlay::target)
+    }
+
+    /// Moves `line` to the front of the history and persists it for the user.
+    pub fn record_command_history(&mut self, line: &str) -> io::Result<()> {
+        let line = line.trim();
+        if line.is_empty() {
+            return Ok(());
+        }
+
+        self.command_history.retain(|existing| existing != line);
+        self.command_history.insert(0, line.to_string());
+        self.command_history.truncate(COMMAND_HISTORY_LIMIT);
+
+        let Some(path) = self.command_history_path.as_deref() else {
+            return Ok(());
+        };
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let encoded = serde_json::to_string_pretty(&self.command_history)
+            .map_err(|error| io::Error::other(error.to_string()))?;
+        fs::write(path, encoded)
+    }
+
+    pub fn keymap(&self) -> &Keymap<KeyCommand> {
+        &self.keymap
+    }
+
+    /// Replaces the keymap here and in the runtime overlays, which resolve their own keys.
+    pub fn set_keymap(&mut self, keymap: Keymap<KeyCommand>) {
+        self.feed_overlays(keymap.clone());
+        self.keymap = keymap;
+    }
+
+    /// Keys typed so far toward a multi-key binding, for the footer.
+    pub fn pending_key_sequence(&self) -> Option<String> {
+        self.overlays
+            .component::<DashboardPane>(DASHBOARD)
+            .and_then(DashboardPane::pending)
+    }
+
+    pub fn set_spawn_prefill(&mut self, provider: Option<String>, prompt: Option<String>) {
+        self.spawn_prefill = Some(SpawnPrefill { provider, prompt });
+    }
+
+    pub fn clear_spawn_prefill(&mut self) {
+        self.spawn_prefill = None;
+    }
+
+    /// Applies a pending `:spawn` prefill to the freshly opened spawn form.
+    ///
+    /// Returns `None` when nothing was pending, or an error naming an unknown provider.
+    pub fn apply_spawn_prefill(&mut self) -> Option<Result<(), String>> {
+        let prefill = self.spawn_prefill.take()?;
+        let form = self.form_mut(SPAWN_FORM)?;
+
+        if let Some(prompt) = prefill.prompt {
+            form.set_text("prompt", prompt);
+        }
+
+        if let Some(provider) = prefill.provider {
+            let matched = form
+                .options("provider")
+                .iter()
+                .find(|candidate| candidate.value.eq_ignore_ascii_case(&provider))
+                .map(|candidate| candidate.value.clone());
+            match matched {
+                Some(value) => {
+                    form.select_value("provider", &value);
+                }
+                None => {
+                    let available: Vec<&str> = form
+                        .options("provider")
+                        .iter()
+                        .map(|candidate| candidate.value.as_str())
+                        .collect();
+                    return Some(Err(format!(
+                        "Unknown provider `{provider}`; available: {}.",
+                        available.join(", ")
+                    )));
+                }
+            }
+        }
+
+        Some(Ok(()))
+    }
+
+    /// Replaces the branch form input, e.g. with a `:branch <name>` argument.
+    pub fn set_branch_form_branch(&mut self, branch_name: &str) {
+        if let Some(form) = self.form_mut(BRANCH_FORM) {
+            form.set_text("branch", branch_name);
+        }
+        self.refresh_branch_suggestions();
     }
 
     pub fn open_move_actor_form(&mut self) -> bool {
@@ -1156,577 +1514,294 @@ impl App {
                 .then_with(|| left.variant_id.cmp(&right.variant_id))
         });
 
+        let destinations: Vec<SelectOption> = options
+            .iter()
+            .map(|option| {
+                SelectOption::new(
+                    option.variant_id.clone(),
+                    format!(
+                        "{} ({}) [{}]",
+                        option.variant_name, option.variant_id, option.product_name
+                    ),
+                )
+            })
+            .collect();
+        let form = Form::new("Move Actor")
+            .note(format!("Actor: {}", actor.title))
+            .note(format!(
+                "From: {source_variant_name} ({})",
+                actor.variant_id
+            ))
+            .field(
+                FormField::select("destination", "Destination")
+                    .options(destinations)
+                    .required(),
+            )
+            .submit_label("move actor");
+        let form = with_form_keys(&self.keymap, FORM_MODE, form);
+
         self.move_actor_form = Some(MoveActorFormState {
             actor_id: actor.id,
-            actor_title: actor.title,
             source_variant_id: actor.variant_id,
-            source_variant_name,
             options,
-            selected_option: 0,
         });
+        self.open_form(MOVE_ACTOR_FORM, form);
 
         true
     }
 
-    pub fn close_move_actor_form(&mut self) {
-        self.move_actor_form = None;
-    }
-
-    pub fn move_actor_form_move_up(&mut self) {
-        let Some(form) = self.move_actor_form.as_mut() else {
-            return;
-        };
-
-        form.selected_option = previous_index(form.selected_option, form.options.len());
-    }
-
-    pub fn move_actor_form_move_down(&mut self) {
-        let Some(form) = self.move_actor_form.as_mut() else {
-            return;
-        };
-
-        form.selected_option = next_index(form.selected_option, form.options.len());
-    }
-
     pub fn take_move_actor_request(&mut self) -> Option<MoveActorRequest> {
-        let form = self.move_actor_form.take()?;
-        let destination = form.options.get(form.selected_option)?;
+        let state = self.move_actor_form.take()?;
+        let form = self.take_form(MOVE_ACTOR_FORM)?;
+        let target = form.choice("destination")?;
+        let destination = state
+            .options
+            .iter()
+            .find(|option| option.variant_id == target)?;
 
         Some(MoveActorRequest {
-            actor_id: form.actor_id,
-            source_variant_id: form.source_variant_id,
+            actor_id: state.actor_id.clone(),
+            source_variant_id: state.source_variant_id.clone(),
             target_variant_id: destination.variant_id.clone(),
             target_variant_name: destination.variant_name.clone(),
         })
     }
 
-    pub fn clone_form_selected_field(&self) -> Option<usize> {
-        self.clone_form.as_ref().map(|form| form.selected_field)
+    /// Re-derives a remote target path after an edit changed the name or the
+    /// remote host.
+    fn sync_clone_form(&mut self) {
+        let (Some(state), Some(form)) = (self.clone_form.as_ref(), self.form(CLONE_FORM)) else {
+            return;
+        };
+        if form.text("name") != state.name
+            || form.choice("remote_host") != state.remote_host.as_deref()
+        {
+            self.clone_form_apply_remote_host_template(true);
+        }
     }
 
-    pub fn clone_form_name(&self) -> Option<&str> {
-        self.clone_form.as_ref().map(|form| form.name.as_str())
-    }
+    /// Opens the host picker over the clone form's remote host field; returns
+    /// `false` when there are no hosts to pick.
+    pub fn open_clone_host_picker(&mut self) -> bool {
+        if self.ssh_hosts.is_empty() {
+            return false;
+        }
+        let Some(form) = self.form(CLONE_FORM) else {
+            return false;
+        };
+        let Some(overlay) = self.overlays.component::<FormOverlay>(CLONE_FORM) else {
+            return false;
+        };
 
-    pub fn clone_form_target_path(&self) -> Option<&str> {
-        self.clone_form
-            .as_ref()
-            .map(|form| form.target_path.as_str())
-    }
+        let size = self.overlays.size();
+        let popup = overlay.popup(Rect::new(0, 0, size.width, size.height));
+        let inner = inner_rect(popup);
+        // The picker opens from the remote host field, which has focus.
+        let focus_line = form.lines(self.theme()).1 as u16;
+        let anchor = PopupAnchor::At {
+            x: inner.x.saturating_add(2),
+            y: inner.y.saturating_add(focus_line + 1),
+        };
+        let current = form.choice("remote_host").map(str::to_string);
+        let hosts = self
+            .ssh_hosts
+            .iter()
+            .map(|host| {
+                PickerOption::new(host.key.clone(), clone_host_label(host)).searching(&host.host)
+            })
+            .collect();
 
-    pub fn clone_form_remote_host(&self) -> Option<&str> {
-        self.clone_form
-            .as_ref()
-            .map(|form| form.remote_host.as_str())
+        let Some(picker) = self
+            .overlays
+            .component_mut::<PickerOverlay>(CLONE_HOST_PICKER)
+        else {
+            return false;
+        };
+        picker.open(hosts, current.as_deref(), popup, anchor);
+        self.show_overlay(CLONE_HOST_PICKER);
+        true
     }
 
-    pub fn clone_host_picker_open(&self) -> bool {
-        self.clone_form
-            .as_ref()
-            .map(|form| form.host_picker_open)
-            .unwrap_or(false)
+    fn apply_clone_host(&mut self, host: &str) {
+        if let Some(form) = self.form_mut(CLONE_FORM) {
+            form.select_value("remote_host", host);
+        }
+        self.clone_form_apply_remote_host_template(true);
     }
 
-    pub fn clone_host_picker_query(&self) -> &str {
-        self.clone_form
-            .as_ref()
-            .map(|form| form.host_picker_query.as_str())
-            .unwrap_or("")
-    }
+    pub fn take_clone_request(&mut self) -> Option<CloneVariantRequest> {
+        self.clone_form = None;
+        let form = self.take_form(CLONE_FORM)?;
 
-    pub fn clone_host_picker_selected(&self) -> usize {
-        self.clone_form
-            .as_ref()
-            .map(|form| form.host_picker_selected)
-            .unwrap_or(0)
+        Some(CloneVariantRequest {
+            name: normalize_optional_input(form.text("name")),
+            target_path: normalize_optional_input(form.text("target_path")),
+            branch_name: normalize_optional_input(form.text("branch_name")),
+            clone_type: normalize_optional_input(form.text("clone_type")),
+            source_variant_id: normalize_optional_input(form.text("source_variant_id")),
+        })
     }
 
-    pub fn clone_host_picker_items(&self) -> Vec<String> {
-        let Some(form) = self.clone_form.as_ref() else {
-            return Vec::new();
-        };
-
-        let query = form.host_picker_query.trim().to_ascii_lowercase();
-
-        self.ssh_hosts
-            .iter()
-            .filter(|host| {
-                if query.is_empty() {
-                    return true;
-                }
-
-                host.key.to_ascii_lowercase().contains(&query)
-                    || host.label.to_ascii_lowercase().contains(&query)
-                    || host.host.to_ascii_lowercase().contains(&query)
-                    || host.default_path.to_ascii_lowercase().contains(&query)
-            })
-            .map(|host| {
-                if host.default_path == "-" {
-                    format!("{}  [{}]", host.key, host.source)
-                } else {
-                    format!(
-                        "{}  [{}]  path:{}",
-                        host.key, host.source, host.default_path
-                    )
-                }
-            })
-            .collect::<Vec<_>>()
-    }
+    fn clone_form_apply_remote_host_template(&mut self, preserve_local_custom_path: bool) {
+        let fallback_repo_slug = self
+            .selected_product()
+            .map(|product| clone_name_slug(&product.display_name))
+            .unwrap_or_else(|| "clone".to_string());
 
-    pub fn open_clone_host_picker(&mut self) {
-        let Some(form) = self.clone_form.as_mut() else {
+        self.remember_clone_form();
+        let Some(form) = self.form(CLONE_FORM) else {
             return;
         };
 
-        if self.ssh_hosts.is_empty() {
+        let host = form.choice("remote_host").unwrap_or("").trim().to_string();
+        let current = form.text("target_path").trim();
+        if host.is_empty() {
+            // A local clone must not keep the previous host's remote target.
+            let remote_target = current.starts_with("@ssh://");
+            if let Some(form) = self.form_mut(CLONE_FORM).filter(|_| remote_target) {
+                form.set_text("target_path", "");
+            }
             return;
         }
 
-        form.host_picker_open = true;
-        form.host_picker_query.clear();
-        form.host_picker_selected = form.selected_remote_host;
-        self.clamp_clone_host_picker_selection();
-    }
-
-    pub fn close_clone_host_picker(&mut self) {
-        let Some(form) = self.clone_form.as_mut() else {
+        if preserve_local_custom_path && !current.is_empty() && !current.starts_with("@ssh://") {
             return;
+        }
+
+        let name = form.text("name");
+        let clone_slug = if name.trim().is_empty() {
+            fallback_repo_slug
+        } else {
+            clone_name_slug(name)
         };
 
-        form.host_picker_open = false;
-        form.host_picker_query.clear();
-        form.host_picker_selected = 0;
-    }
+        let host_row = self
+            .ssh_hosts
+            .iter()
+            .find(|candidate| candidate.key == host);
 
-    pub fn clone_host_picker_insert_char(&mut self, value: char) {
-        let Some(form) = self.clone_form.as_mut() else {
-            return;
+        let default_path = if let Some(path) = host_row
+            .map(|candidate| candidate.default_path.as_str())
+            .filter(|value| !value.is_empty() && *value != "-")
+        {
+            let base = path.trim_end_matches('/');
+            format!("{base}/{clone_slug}")
+        } else if let Some(user) = host_row
+            .map(|candidate| candidate.user.as_str())
+            .filter(|value| !value.is_empty() && *value != "-")
+        {
+            format!("/home/{user}/github/{clone_slug}")
+        } else {
+            format!("/tmp/df-{clone_slug}")
         };
-        if !form.host_picker_open {
-            return;
-        }
 
-        form.host_picker_query.push(value);
-        self.clamp_clone_host_picker_selection();
+        if let Some(form) = self.form_mut(CLONE_FORM) {
+            form.set_text("target_path", format!("@ssh://{host}{default_path}"));
+        }
     }
 
-    pub fn clone_host_picker_backspace(&mut self) {
-        let Some(form) = self.clone_form.as_mut() else {
+    fn remember_clone_form(&mut self) {
+        let Some(form) = self.form(CLONE_FORM) else {
             return;
         };
-        if !form.host_picker_open {
-            return;
+        let name = form.text("name").to_string();
+        let remote_host = form.choice("remote_host").map(str::to_string);
+        if let Some(state) = self.clone_form.as_mut() {
+            state.name = name;
+            state.remote_host = remote_host;
         }
-
-        form.host_picker_query.pop();
-        self.clamp_clone_host_picker_selection();
     }
 
-    pub fn clone_host_picker_move_up(&mut self) {
-        if !self.clone_host_picker_open() {
-            return;
-        }
+    pub fn take_spawn_request(&mut self) -> Option<SpawnRequest> {
+        let variant_id = self.spawn_variant_id.take()?;
+        let form = self.take_form(SPAWN_FORM)?;
+        let provider = form.choice("provider")?.to_string();
+        let trimmed_prompt = form.text("prompt").trim();
 
-        let len = self.clone_host_picker_items().len();
-        let Some(form) = self.clone_form.as_mut() else {
-            return;
+        let initial_prompt = if trimmed_prompt.is_empty() {
+            None
+        } else {
+            Some(trimmed_prompt.to_string())
         };
-        if len == 0 {
-            form.host_picker_selected = 0;
-            return;
-        }
 
-        form.host_picker_selected = previous_index(form.host_picker_selected, len);
+        Some(SpawnRequest {
+            variant_id,
+            provider,
+            initial_prompt,
+        })
     }
 
-    pub fn clone_host_picker_move_down(&mut self) {
-        if !self.clone_host_picker_open() {
-            return;
-        }
-
-        let len = self.clone_host_picker_items().len();
-        let Some(form) = self.clone_form.as_mut() else {
-            return;
-        };
-        if len == 0 {
-            form.host_picker_selected = 0;
-            return;
-        }
+    pub fn is_chat_visible(&self) -> bool {
+        self.chat_visible
+    }
 
-        form.host_picker_selected = next_index(form.host_picker_selected, len);
+    pub fn is_inspector_visible(&self) -> bool {
+        self.inspector_visible
     }
 
-    pub fn clone_host_picker_set_selected(&mut self, index: usize) {
-        if !self.clone_host_picker_open() {
-            return;
+    pub fn toggle_inspector_visibility(&mut self) {
+        self.inspector_visible = !self.inspector_visible;
+        if !self.inspector_visible {
+            self.resizing_target = None;
         }
+    }
 
-        let len = self.clone_host_picker_items().len();
-        let Some(form) = self.clone_form.as_mut() else {
-            return;
-        };
+    pub fn toggle_chat_visibility(&mut self) {
+        self.chat_visible = !self.chat_visible;
 
-        if len == 0 {
-            form.host_picker_selected = 0;
+        if !self.chat_visible {
+            self.release_pane(CHAT_COMPOSER);
+            self.chat_scroll_lines = 0;
+            self.close_chat_detail_popup();
             return;
         }
 
-        form.host_picker_selected = index.min(len.saturating_sub(1));
-    }
-
-    pub fn apply_clone_host_picker_selection(&mut self) -> Option<String> {
-        let selected_index = {
-            let form = self.clone_form.as_ref()?;
-            if !form.host_picker_open {
-                return None;
-            }
-            form.host_picker_selected
-        };
-
-        let selected_entry = self
-            .clone_host_picker_items()
-            .get(selected_index)?
-            .to_string();
-        let host = selected_entry
-            .split_whitespace()
-            .next()
-            .map(ToString::to_string)?;
-
-        if let Some(form) = self.clone_form.as_mut() {
-            form.remote_host = host.clone();
-            if let Some(position) = self
-                .ssh_hosts
-                .iter()
-                .position(|candidate| candidate.key == host)
-            {
-                form.selected_remote_host = position;
-            }
-            form.host_picker_open = false;
-            form.host_picker_query.clear();
-            form.host_picker_selected = 0;
+        if self.chat_actor_id.is_some() {
+            self.chat_needs_refresh = true;
         }
-
-        self.clone_form_apply_remote_host_template(true);
-        Some(host)
     }
 
-    pub fn clone_form_branch_name(&self) -> Option<&str> {
-        self.clone_form
-            .as_ref()
-            .map(|form| form.branch_name.as_str())
+    pub fn chat_actor(&self) -> Option<&ActorRow> {
+        let actor_id = self.chat_actor_id.as_deref()?;
+        self.actors.iter().find(|actor| actor.id == actor_id)
     }
 
-    pub fn clone_form_clone_type(&self) -> Option<&str> {
-        self.clone_form
-            .as_ref()
-            .map(|form| form.clone_type.as_str())
+    /// Sub-agent whose transcript the chat shows in place of its actor's.
+    pub fn chat_sub_agent(&self) -> Option<&SubAgentRow> {
+        let sub_agent_id = self.chat_sub_agent_id.as_deref()?;
+        self.chat_actor()?.sub_agent(sub_agent_id)
     }
 
-    pub fn clone_form_source_variant_id(&self) -> Option<&str> {
-        self.clone_form
-            .as_ref()
-            .map(|form| form.source_variant_id.as_str())
+    /// Session shown in chat: the selected sub-agent's, else the actor id.
+    fn chat_transcript_id(&self) -> Option<String> {
+        self.chat_sub_agent_id
+            .clone()
+            .or_else(|| self.chat_actor_id.clone())
     }
 
-    pub fn clone_form_move_up(&mut self) {
-        let Some(form) = self.clone_form.as_mut() else {
-            return;
-        };
-
-        form.selected_field = previous_index(form.selected_field, 6);
+    pub fn chat_messages(&self) -> &[ActorChatMessageRow] {
+        &self.chat_messages
     }
 
-    pub fn clone_form_set_selected_field(&mut self, field_index: usize) {
-        let Some(form) = self.clone_form.as_mut() else {
-            return;
-        };
+    pub fn configure_chat_performance(
+        &mut self,
+        history_limit: u32,
+        render_limit: usize,
+        max_body_lines: usize,
+        message_max_chars: usize,
+    ) {
+        self.chat_history_limit = history_limit as usize;
+        self.chat_render_limit = render_limit.max(1);
+        self.chat_max_body_lines = max_body_lines.max(1);
+        self.chat_message_max_chars = message_max_chars;
 
-        form.selected_field = field_index.min(5);
-    }
-
-    pub fn clone_form_move_down(&mut self) {
-        let Some(form) = self.clone_form.as_mut() else {
-            return;
-        };
-
-        form.selected_field = next_index(form.selected_field, 6);
-    }
-
-    pub fn clone_form_insert_char(&mut self, value: char) {
-        let Some(form) = self.clone_form.as_mut() else {
-            return;
-        };
-
-        match form.selected_field {
-            0 => form.name.push(value),
-            1 => form.target_path.push(value),
-            2 => form.remote_host.push(value),
-            3 => form.branch_name.push(value),
-            4 => form.clone_type.push(value),
-            _ => form.source_variant_id.push(value),
-        }
-
-        if matches!(form.selected_field, 0 | 2) {
-            self.clone_form_apply_remote_host_template(true);
-        }
-    }
-
-    pub fn clone_form_backspace(&mut self) {
-        let Some(form) = self.clone_form.as_mut() else {
-            return;
-        };
-
-        match form.selected_field {
-            0 => {
-                form.name.pop();
-            }
-            1 => {
-                form.target_path.pop();
-            }
-            2 => {
-                form.remote_host.pop();
-            }
-            3 => {
-                form.branch_name.pop();
-            }
-            4 => {
-                form.clone_type.pop();
-            }
-            _ => {
-                form.source_variant_id.pop();
-            }
-        }
-
-        if matches!(form.selected_field, 0 | 2) {
-            self.clone_form_apply_remote_host_template(true);
-        }
-    }
-
-    pub fn clone_form_select_previous_remote_host(&mut self) {
-        let Some(form) = self.clone_form.as_mut() else {
-            return;
-        };
-
-        if self.ssh_hosts.is_empty() {
-            return;
-        }
-
-        form.selected_remote_host = previous_index(form.selected_remote_host, self.ssh_hosts.len());
-        if let Some(host) = self.ssh_hosts.get(form.selected_remote_host) {
-            form.remote_host = host.key.clone();
-            self.clone_form_apply_remote_host_template(true);
-        }
-    }
-
-    pub fn clone_form_select_next_remote_host(&mut self) {
-        let Some(form) = self.clone_form.as_mut() else {
-            return;
-        };
-
-        if self.ssh_hosts.is_empty() {
-            return;
-        }
-
-        form.selected_remote_host = next_index(form.selected_remote_host, self.ssh_hosts.len());
-        if let Some(host) = self.ssh_hosts.get(form.selected_remote_host) {
-            form.remote_host = host.key.clone();
-            self.clone_form_apply_remote_host_template(true);
-        }
-    }
-
-    pub fn take_clone_request(&mut self) -> Option<CloneVariantRequest> {
-        let form = self.clone_form.take()?;
-
-        Some(CloneVariantRequest {
-            name: normalize_optional_input(&form.name),
-            target_path: normalize_optional_input(&form.target_path),
-            branch_name: normalize_optional_input(&form.branch_name),
-            clone_type: normalize_optional_input(&form.clone_type),
-            source_variant_id: normalize_optional_input(&form.source_variant_id),
-        })
-    }
-
-    fn clone_form_apply_remote_host_template(&mut self, preserve_local_custom_path: bool) {
-        let fallback_repo_slug = self
-            .selected_product()
-            .map(|product| clone_name_slug(&product.display_name))
-            .unwrap_or_else(|| "clone".to_string());
-
-        let Some(form) = self.clone_form.as_ref() else {
-            return;
-        };
-
-        let host = form.remote_host.trim().to_string();
-        if host.is_empty() {
-            return;
-        }
-
-        if preserve_local_custom_path {
-            let current = form.target_path.trim();
-            if !current.is_empty() && !current.starts_with("@ssh://") {
-                return;
-            }
-        }
-
-        let clone_slug = if form.name.trim().is_empty() {
-            fallback_repo_slug
-        } else {
-            clone_name_slug(&form.name)
-        };
-
-        let host_row = self
-            .ssh_hosts
-            .iter()
-            .find(|candidate| candidate.key == host)
-            .cloned();
-
-        let default_path = if let Some(path) = host_row
-            .as_ref()
-            .map(|candidate| candidate.default_path.as_str())
-            .filter(|value| !value.is_empty() && *value != "-")
-        {
-            let base = path.trim_end_matches('/');
-            format!("{base}/{clone_slug}")
-        } else if let Some(user) = host_row
-            .as_ref()
-            .map(|candidate| candidate.user.as_str())
-            .filter(|value| !value.is_empty() && *value != "-")
-        {
-            format!("/home/{user}/github/{clone_slug}")
-        } else {
-            format!("/tmp/df-{clone_slug}")
-        };
-
-        if let Some(form) = self.clone_form.as_mut() {
-            form.target_path = format!("@ssh://{host}{default_path}");
-        }
-    }
-
-    fn clamp_clone_host_picker_selection(&mut self) {
-        if !self.clone_host_picker_open() {
-            return;
-        }
-
-        let len = self.clone_host_picker_items().len();
-        let Some(form) = self.clone_form.as_mut() else {
-            return;
-        };
-        if len == 0 {
-            form.host_picker_selected = 0;
-            return;
-        }
-
-        form.host_picker_selected = form.host_picker_selected.min(len.saturating_sub(1));
-    }
-
-    pub fn spawn_form_move_provider_up(&mut self) {
-        let Some(form) = self.spawn_form.as_mut() else {
-            return;
-        };
-
-        form.selected_provider = previous_index(form.selected_provider, form.providers.len());
-    }
-
-    pub fn spawn_form_move_provider_down(&mut self) {
-        let Some(form) = self.spawn_form.as_mut() else {
-            return;
-        };
-
-        form.selected_provider = next_index(form.selected_provider, form.providers.len());
-    }
-
-    pub fn spawn_form_insert_char(&mut self, value: char) {
-        let Some(form) = self.spawn_form.as_mut() else {
-            return;
-        };
-
-        form.initial_prompt.push(value);
-    }
-
-    pub fn spawn_form_backspace(&mut self) {
-        let Some(form) = self.spawn_form.as_mut() else {
-            return;
-        };
-
-        form.initial_prompt.pop();
-    }
-
-    pub fn take_spawn_request(&mut self) -> Option<SpawnRequest> {
-        let form = self.spawn_form.take()?;
-        let provider = form.providers.get(form.selected_provider)?.to_string();
-        let trimmed_prompt = form.initial_prompt.trim();
-
-        let initial_prompt = if trimmed_prompt.is_empty() {
-            None
-        } else {
-            Some(trimmed_prompt.to_string())
-        };
-
-        Some(SpawnRequest {
-            variant_id: form.variant_id,
-            provider,
-            initial_prompt,
-        })
-    }
-
-    pub fn is_chat_visible(&self) -> bool {
-        self.chat_visible
-    }
-
-    pub fn is_inspector_visible(&self) -> bool {
-        self.inspector_visible
-    }
-
-    pub fn toggle_inspector_visibility(&mut self) {
-        self.inspector_visible = !self.inspector_visible;
-        if !self.inspector_visible {
-            self.resizing_target = None;
-        }
-    }
-
-    pub fn toggle_chat_visibility(&mut self) {
-        self.chat_visible = !self.chat_visible;
-
-        if !self.chat_visible {
-            self.chat_composing = false;
-            self.chat_scroll_lines = 0;
-            self.close_chat_detail_popup();
-            return;
-        }
-
-        if self.chat_actor_id.is_some() {
-            self.chat_needs_refresh = true;
-        }
-    }
-
-    pub fn chat_actor(&self) -> Option<&ActorRow> {
-        let actor_id = self.chat_actor_id.as_deref()?;
-        self.actors.iter().find(|actor| actor.id == actor_id)
-    }
-
-    pub fn chat_messages(&self) -> &[ActorChatMessageRow] {
-        &self.chat_messages
-    }
-
-    pub fn configure_chat_performance(
-        &mut self,
-        history_limit: u32,
-        render_limit: usize,
-        max_body_lines: usize,
-        message_max_chars: usize,
-    ) {
-        self.chat_history_limit = history_limit as usize;
-        self.chat_render_limit = render_limit.max(1);
-        self.chat_max_body_lines = max_body_lines.max(1);
-        self.chat_message_max_chars = message_max_chars;
-
-        if self.chat_history_limit > 0 && self.chat_messages.len() > self.chat_history_limit {
-            let keep_from = self
-                .chat_messages
-                .len()
-                .saturating_sub(self.chat_history_limit);
-            self.chat_messages = self.chat_messages.split_off(keep_from);
-        }
+        if self.chat_history_limit > 0 && self.chat_messages.len() > self.chat_history_limit {
+            let keep_from = self
+                .chat_messages
+                .len()
+                .saturating_sub(self.chat_history_limit);
+            self.chat_messages = self.chat_messages.split_off(keep_from);
+        }
     }
 
     pub fn chat_history_limit_query(&self) -> Option<u32> {
@@ -1758,7 +1833,7 @@ impl App {
     }
 
     pub fn is_chat_composing(&self) -> bool {
-        self.chat_composing
+        self.overlays.base_focus() == Some(CHAT_COMPOSER)
     }
 
     pub fn is_chat_refresh_in_flight(&self) -> bool {
@@ -1781,38 +1856,8 @@ impl App {
         self.chat_selected_agent.as_deref()
     }
 
-    pub fn chat_model_options(&self) -> &[String] {
-        &self.chat_model_options
-    }
-
-    pub fn chat_agent_options(&self) -> &[String] {
-        &self.chat_agent_options
-    }
-
-    pub fn chat_picker_open(&self) -> Option<ChatPickerKind> {
-        self.chat_picker_open
-    }
-
-    pub fn chat_picker_query(&self) -> &str {
-        &self.chat_picker_query
-    }
-
-    pub fn chat_picker_items(&self) -> Vec<String> {
-        let items = self.current_chat_picker_items();
-        if self.chat_picker_query.is_empty() {
-            return items.to_vec();
-        }
-
-        let needle = self.chat_picker_query.to_ascii_lowercase();
-        items
-            .iter()
-            .filter(|item| item.to_ascii_lowercase().contains(&needle))
-            .cloned()
-            .collect()
-    }
-
-    pub fn chat_picker_selected(&self) -> usize {
-        self.chat_picker_selected
+    pub fn is_chat_picker_open(&self) -> bool {
+        self.overlays.is_visible(CHAT_MODEL_PICKER) || self.overlays.is_visible(CHAT_AGENT_PICKER)
     }
 
     pub fn chat_autocomplete_open(&self) -> bool {
@@ -1836,42 +1881,39 @@ impl App {
     }
 
     pub fn is_chat_detail_popup_open(&self) -> bool {
-        self.chat_detail_popup_open
+        self.overlays.is_visible(CHAT_DETAIL)
     }
 
+    /// Opens the detail popup on the latest message with tool, shell or
+    /// thinking output, else on the last message.
     pub fn open_chat_detail_popup(&mut self) -> bool {
-        if self.chat_messages.is_empty() {
-            return false;
-        }
-
-        self.chat_detail_popup_message_index = self.latest_rich_chat_message_index();
-        if self.chat_detail_popup_message_index.is_none() {
-            self.chat_detail_popup_message_index = Some(self.chat_messages.len().saturating_sub(1));
-        }
-        self.chat_detail_popup_open = true;
-        self.chat_detail_popup_scroll_lines = 0;
-        true
+        let index = self
+            .latest_rich_chat_message_index()
+            .unwrap_or(self.chat_messages.len().saturating_sub(1));
+        self.open_chat_detail_popup_for_message(index)
     }
 
     pub fn open_chat_detail_popup_for_message(&mut self, message_index: usize) -> bool {
         if message_index >= self.chat_messages.len() {
             return false;
         }
-
-        self.chat_detail_popup_message_index = Some(message_index);
-        self.chat_detail_popup_open = true;
-        self.chat_detail_popup_scroll_lines = 0;
+        let Some(detail) = self
+            .overlays
+            .component_mut::<ChatDetailOverlay>(CHAT_DETAIL)
+        else {
+            return false;
+        };
+        detail.open(message_index);
+        self.show_overlay(CHAT_DETAIL);
         true
     }
 
     pub fn close_chat_detail_popup(&mut self) {
-        self.chat_detail_popup_open = false;
-        self.chat_detail_popup_scroll_lines = 0;
-        self.chat_detail_popup_message_index = None;
+        self.overlays.hide(CHAT_DETAIL);
     }
 
     pub fn toggle_chat_detail_popup(&mut self) -> bool {
-        if self.chat_detail_popup_open {
+        if self.is_chat_detail_popup_open() {
             self.close_chat_detail_popup();
             false
         } else {
@@ -1880,28 +1922,35 @@ impl App {
     }
 
     pub fn chat_detail_popup_scroll_lines(&self) -> u16 {
-        self.chat_detail_popup_scroll_lines
-    }
-
-    pub fn scroll_chat_detail_popup_up(&mut self, amount: u16) {
-        self.chat_detail_popup_scroll_lines =
-            self.chat_detail_popup_scroll_lines.saturating_sub(amount);
-    }
-
-    pub fn scroll_chat_detail_popup_down(&mut self, amount: u16) {
-        self.chat_detail_popup_scroll_lines =
-            self.chat_detail_popup_scroll_lines.saturating_add(amount);
+        self.open_overlay::<ChatDetailOverlay>(CHAT_DETAIL)
+            .map_or(0, ChatDetailOverlay::scroll_lines)
     }
 
     /// Returns the full message row for the detail popup (text + role + timestamp).
     pub fn chat_detail_popup_message(&self) -> Option<&ActorChatMessageRow> {
-        if let Some(index) = self.chat_detail_popup_message_index {
-            return self.chat_messages.get(index);
-        }
+        let detail = self.open_overlay::<ChatDetailOverlay>(CHAT_DETAIL)?;
+        self.chat_messages.get(detail.message_index())
+    }
 
-        self.latest_rich_chat_message_index()
-            .and_then(|index| self.chat_messages.get(index))
-            .or_else(|| self.chat_messages.last())
+    /// Hands the chat popups the chat panel area and the detail popup box
+    /// they hit-test against, and refreshes the queued prompts.
+    pub fn sync_chat_popups(&mut self, chat_area: Option<Rect>, detail_popup: Option<Rect>) {
+        if let Some(detail) = self.open_overlay_mut::<ChatDetailOverlay>(CHAT_DETAIL) {
+            detail.set_popup(detail_popup);
+        }
+        let Some(chat_area) = chat_area else {
+            return;
+        };
+        if !self.overlays.is_visible(CHAT_QUEUE) {
+            return;
+        }
+        let selected = self.chat_prompt_queue.selected();
+        let prompts = self
+            .chat_prompt_queue
+            .items_for(self.chat_actor_id.as_deref());
+        if let Some(queue) = self.overlays.component_mut::<ChatQueueOverlay>(CHAT_QUEUE) {
+            queue.set_prompts(&prompts, selected, chat_area);
+        }
     }
 
     fn latest_rich_chat_message_index(&self) -> Option<usize> {
@@ -1915,32 +1964,36 @@ impl App {
         })
     }
 
+    /// Opens the composer for the chat actor; sub-agent transcripts are read-only.
     pub fn open_chat_composer(&mut self) -> bool {
-        if self.chat_actor_id.is_none() {
+        if self.chat_actor_id.is_none() || self.chat_sub_agent_id.is_some() {
             return false;
         }
 
         self.chat_visible = true;
         self.chat_needs_refresh = true;
-        self.chat_composing = true;
+        self.overlays.focus(CHAT_COMPOSER);
         self.ensure_chat_workspace_file_cache();
         true
     }
 
     pub fn cancel_chat_composer(&mut self) {
-        self.chat_composing = false;
+        if self.chat_prompt_queue.cancel_edit() {
+            self.chat_draft.clear();
+        }
+        self.release_pane(CHAT_COMPOSER);
         self.close_chat_picker();
         self.close_chat_autocomplete();
     }
 
     pub fn commit_sent_chat_prompt(&mut self) {
         self.chat_draft.clear();
-        self.chat_composing = false;
+        self.release_pane(CHAT_COMPOSER);
         self.close_chat_autocomplete();
     }
 
     pub fn current_chat_prompt(&self) -> Option<String> {
-        if !self.chat_composing {
+        if !self.is_chat_composing() {
             return None;
         }
 
@@ -1952,164 +2005,250 @@ impl App {
         Some(trimmed.to_string())
     }
 
-    pub fn chat_insert_char(&mut self, value: char) {
-        if !self.chat_composing {
-            return;
-        }
+    /// Attachments staged for the next chat prompt; cleared once a send succeeds.
+    pub fn chat_attachments(&self) -> &[ChatAttachment] {
+        &self.chat_attachments
+    }
 
-        self.chat_draft.push(value);
-        self.refresh_chat_autocomplete();
+    pub fn add_chat_attachment(&mut self, attachment: ChatAttachment) -> anyhow::Result<usize> {
+        ensure_attachment_fits(&self.chat_attachments, &attachment)?;
+        self.chat_attachments.push(attachment);
+        Ok(self.chat_attachments.len())
     }
 
-    pub fn chat_backspace(&mut self) {
-        if !self.chat_composing {
-            return;
-        }
+    pub fn remove_last_chat_attachment(&mut self) -> Option<ChatAttachment> {
+        self.chat_attachments.pop()
+    }
 
-        self.chat_draft.pop();
-        self.refresh_chat_autocomplete();
+    pub fn clear_chat_attachments(&mut self) {
+        self.chat_attachments.clear();
     }
 
-    pub fn set_chat_options(&mut self, models: Vec<String>, agents: Vec<String>) {
-        self.chat_model_options = normalize_string_options(models);
-        self.chat_agent_options = normalize_string_options(agents);
+    /// Queued prompts for the chat actor, in send order.
+    pub fn chat_queued_prompts(&self) -> Vec<&QueuedPrompt> {
+        self.chat_prompt_queue
+            .items_for(self.chat_actor_id.as_deref())
+    }
 
-        self.chat_selected_model = resolve_selected_option(
-            &self.chat_model_options,
-            self.chat_selected_model.as_deref(),
-            self.chat_preferred_model.as_deref(),
-        );
+    /// Queues `prompt` for the chat actor with the current model/agent selection.
+    /// Returns the prompt's 1-based position in that actor's queue.
+    pub fn enqueue_chat_prompt(
+        &mut self,
+        prompt: &str,
+        delay_seconds: Option<u64>,
+    ) -> anyhow::Result<usize> {
+        let Some(actor_id) = self.chat_actor_id.as_deref() else {
+            anyhow::bail!("no chat actor selected");
+        };
 
-        self.chat_selected_agent = resolve_selected_option(
-            &self.chat_agent_options,
+        self.chat_prompt_queue.enqueue(
+            actor_id,
+            prompt,
+            self.chat_selected_model.as_deref(),
             self.chat_selected_agent.as_deref(),
-            self.chat_preferred_agent.as_deref(),
-        );
+            delay_seconds,
+        )
     }
 
-    pub fn open_chat_model_picker(&mut self) {
-        if self.chat_model_options.is_empty() {
-            return;
-        }
+    /// True when the chat actor is still working on a previous prompt.
+    pub fn chat_actor_busy(&self) -> bool {
+        self.chat_actor()
+            .is_some_and(|actor| !actor_accepts_queued_prompt(&actor.status))
+    }
 
-        self.chat_picker_open = Some(ChatPickerKind::Model);
-        self.chat_picker_query.clear();
-        let selected = self
-            .chat_selected_model
-            .as_deref()
-            .and_then(|value| {
-                self.chat_model_options
-                    .iter()
-                    .position(|item| item == value)
-            })
-            .unwrap_or(0);
-        self.chat_picker_selected = selected;
-        self.clamp_chat_picker_selection();
+    /// Pops the next due prompt whose actor can take it, with that actor; see
+    /// [`PromptQueuePanel::take_due`].
+    pub fn take_due_chat_prompt(&mut self) -> anyhow::Result<Option<(ActorRow, QueuedPrompt)>> {
+        let actors = &self.actors;
+        let Some(taken) =
+            self.chat_prompt_queue
+                .take_due(self.chat_actor_id.as_deref(), |target| {
+                    actors.iter().any(|actor| {
+                        actor.id == target && actor_accepts_queued_prompt(&actor.status)
+                    })
+                })?
+        else {
+            return Ok(None);
+        };
+
+        let Some(actor) = self
+            .actors
+            .iter()
+            .find(|actor| actor.id == taken.target)
+            .cloned()
+        else {
+            self.chat_prompt_queue.requeue_failed(taken)?;
+            return Ok(None);
+        };
+        Ok(Some((actor, taken)))
     }
 
-    pub fn open_chat_agent_picker(&mut self) {
-        if self.chat_agent_options.is_empty() {
-            return;
-        }
+    /// Puts a queued prompt whose send failed back at the head of the queue.
+    pub fn requeue_failed_chat_prompt(&mut self, prompt: QueuedPrompt) -> anyhow::Result<()> {
+        self.chat_prompt_queue.requeue_failed(prompt)
+    }
 
-        self.chat_picker_open = Some(ChatPickerKind::Agent);
-        self.chat_picker_query.clear();
-        let selected = self
-            .chat_selected_agent
-            .as_deref()
-            .and_then(|value| {
-                self.chat_agent_options
-                    .iter()
-                    .position(|item| item == value)
-            })
-            .unwrap_or(0);
-        self.chat_picker_selected = selected;
-        self.clamp_chat_picker_selection();
+    pub fn open_chat_queue(&mut self) {
+        self.chat_prompt_queue.reset_selection();
+        self.close_chat_picker();
+        self.close_chat_autocomplete();
+        self.show_overlay(CHAT_QUEUE);
     }
 
-    pub fn close_chat_picker(&mut self) {
-        self.chat_picker_open = None;
-        self.chat_picker_query.clear();
-        self.chat_picker_selected = 0;
+    pub fn chat_queue_move_up(&mut self) {
+        self.chat_prompt_queue
+            .select_previous(self.chat_actor_id.as_deref());
     }
 
-    pub fn chat_picker_insert_char(&mut self, value: char) {
-        if self.chat_picker_open.is_none() {
-            return;
-        }
+    pub fn chat_queue_move_down(&mut self) {
+        self.chat_prompt_queue
+            .select_next(self.chat_actor_id.as_deref());
+    }
 
-        self.chat_picker_query.push(value);
-        self.clamp_chat_picker_selection();
+    pub fn chat_queue_set_selected(&mut self, index: usize) {
+        self.chat_prompt_queue
+            .set_selected(self.chat_actor_id.as_deref(), index);
     }
 
-    pub fn chat_picker_backspace(&mut self) {
-        if self.chat_picker_open.is_none() {
-            return;
-        }
+    /// Moves the selected prompt one slot earlier (`up`) or later in the actor queue.
+    pub fn chat_queue_reorder_selected(&mut self, up: bool) -> anyhow::Result<bool> {
+        self.chat_prompt_queue
+            .reorder_selected(self.chat_actor_id.as_deref(), up)
+    }
 
-        self.chat_picker_query.pop();
-        self.clamp_chat_picker_selection();
+    pub fn chat_queue_cancel_selected(&mut self) -> anyhow::Result<Option<QueuedPrompt>> {
+        self.chat_prompt_queue
+            .cancel_selected(self.chat_actor_id.as_deref())
     }
 
-    pub fn clear_chat_picker_query(&mut self) {
-        if self.chat_picker_open.is_none() {
-            return;
-        }
+    /// Loads the selected queued prompt into the composer; the next send updates it
+    /// in place instead of sending.
+    pub fn chat_queue_edit_selected(&mut self) -> bool {
+        let Some(prompt) = self
+            .chat_prompt_queue
+            .edit_selected(self.chat_actor_id.as_deref())
+        else {
+            return false;
+        };
 
-        self.chat_picker_query.clear();
-        self.clamp_chat_picker_selection();
+        self.overlays.hide(CHAT_QUEUE);
+        self.chat_draft = prompt;
+        self.overlays.focus(CHAT_COMPOSER);
+        true
     }
 
-    pub fn chat_picker_move_up(&mut self) {
-        let len = self.chat_picker_items().len();
-        if len == 0 {
-            self.chat_picker_selected = 0;
-            return;
-        }
+    pub fn is_editing_queued_chat_prompt(&self) -> bool {
+        self.chat_prompt_queue.is_editing()
+    }
 
-        self.chat_picker_selected = previous_index(self.chat_picker_selected, len);
+    pub fn finish_queued_chat_prompt_edit(&mut self, prompt: &str) -> anyhow::Result<bool> {
+        let updated = self.chat_prompt_queue.finish_edit(prompt);
+        self.commit_sent_chat_prompt();
+        updated
     }
 
-    pub fn chat_picker_move_down(&mut self) {
-        let len = self.chat_picker_items().len();
-        if len == 0 {
-            self.chat_picker_selected = 0;
+    pub fn chat_insert_char(&mut self, value: char) {
+        if !self.is_chat_composing() {
             return;
         }
 
-        self.chat_picker_selected = next_index(self.chat_picker_selected, len);
+        self.chat_draft.push(value);
+        self.refresh_chat_autocomplete();
     }
 
-    pub fn chat_picker_set_selected(&mut self, index: usize) {
-        let len = self.chat_picker_items().len();
-        if len == 0 {
-            self.chat_picker_selected = 0;
+    pub fn chat_backspace(&mut self) {
+        if !self.is_chat_composing() {
             return;
         }
 
-        self.chat_picker_selected = index.min(len.saturating_sub(1));
+        self.chat_draft.pop();
+        self.refresh_chat_autocomplete();
     }
 
-    pub fn apply_chat_picker_selection(&mut self) -> Option<String> {
-        let index = self.chat_picker_selected;
-        let kind = self.chat_picker_open?;
-        let selected = self.chat_picker_items().get(index)?.clone();
+    pub fn set_chat_options(&mut self, models: Vec<String>, agents: Vec<String>) {
+        self.chat_model_options = normalize_string_options(models);
+        self.chat_agent_options = normalize_string_options(agents);
+
+        self.chat_selected_model = resolve_selected_option(
+            &self.chat_model_options,
+            self.chat_selected_model.as_deref(),
+            self.chat_preferred_model.as_deref(),
+        );
 
-        match kind {
-            ChatPickerKind::Model => {
-                self.chat_selected_model = Some(selected.clone());
-                self.chat_preferred_model = Some(selected.clone());
+        self.chat_selected_agent = resolve_selected_option(
+            &self.chat_agent_options,
+            self.chat_selected_agent.as_deref(),
+            self.chat_preferred_agent.as_deref(),
+        );
+    }
+
+    /// Opens the model picker from its composer `label` inside the chat `area`.
+    pub fn open_chat_model_picker(&mut self, area: Rect, label: Rect) {
+        let options = self.chat_model_options.clone();
+        let current = self.chat_selected_model.clone();
+        self.open_chat_picker(CHAT_MODEL_PICKER, options, current, area, label);
+    }
+
+    /// Opens the agent picker from its composer `label` inside the chat `area`.
+    pub fn open_chat_agent_picker(&mut self, area: Rect, label: Rect) {
+        let options = self.chat_agent_options.clone();
+        let current = self.chat_selected_agent.clone();
+        self.open_chat_picker(CHAT_AGENT_PICKER, options, current, area, label);
+    }
+
+    fn open_chat_picker(
+        &mut self,
+        id: ComponentId,
+        options: Vec<String>,
+        current: Option<String>,
+        area: Rect,
+        label: Rect,
+    ) {
+        if options.is_empty() {
+            return;
+        }
+        let Some(picker) = self.overlays.component_mut::<PickerOverlay>(id) else {
+            return;
+        };
+        let options = options
+            .into_iter()
+            .map(|option| PickerOption::new(option.clone(), option))
+            .collect();
+        let anchor = PopupAnchor::At {
+            x: label.x,
+            y: label.y,
+        };
+        picker.open(options, current.as_deref(), area, anchor);
+        self.show_overlay(id);
+    }
+
+    pub fn close_chat_picker(&mut self) {
+        self.overlays.hide(CHAT_MODEL_PICKER);
+        self.overlays.hide(CHAT_AGENT_PICKER);
+    }
+
+    /// Applies the value a picker was confirmed on and closes it; returns the
+    /// status to show.
+    fn apply_pick(&mut self, picked: &Picked) -> String {
+        self.overlays.hide(picked.picker);
+        let value = picked.value.clone();
+        match picked.picker {
+            CLONE_HOST_PICKER => {
+                self.apply_clone_host(&value);
+                return format!("Clone host selected: {value}");
             }
-            ChatPickerKind::Agent => {
-                self.chat_selected_agent = Some(selected.clone());
-                self.chat_preferred_agent = Some(selected.clone());
+            CHAT_MODEL_PICKER => {
+                self.chat_selected_model = Some(value.clone());
+                self.chat_preferred_model = Some(value.clone());
             }
+            CHAT_AGENT_PICKER => {
+                self.chat_selected_agent = Some(value.clone());
+                self.chat_preferred_agent = Some(value.clone());
+            }
+            _ => {}
         }
-
         let _ = self.persist_chat_selection();
-
-        self.close_chat_picker();
-        Some(selected)
+        format!("Chat option selected: {value}")
     }
 
     pub fn restore_chat_selection_from_disk(&mut self) -> io::Result<bool> {
@@ -2134,14 +2273,126 @@ impl App {
         Ok(true)
     }
 
-    fn clamp_chat_picker_selection(&mut self) {
-        let len = self.chat_picker_items().len();
-        if len == 0 {
-            self.chat_picker_selected = 0;
-            return;
+    /// Restore the saved layout for this directory. The saved selection is
+    /// applied once the first snapshot arrives.
+    pub fn restore_layout_from_disk(&mut self) -> io::Result<bool> {
+        let Some(saved) = self.load_layout_from_disk()? else {
+            return Ok(false);
+        };
+
+        if let Some(mode) = saved.results_view_mode {
+            self.results_view_mode = mode;
+        }
+        if let Some(density) = saved.viz_density {
+            self.viz_density = density;
+        }
+        if let Some(offset_x) = saved.viz_offset_x {
+            self.viz_offset_x = offset_x;
+        }
+        if let Some(offset_y) = saved.viz_offset_y {
+            self.viz_offset_y = offset_y;
+        }
+        if let Some(percents) = saved.body_split_with_chat.as_deref() {
+            self.body_split_with_chat.set_percents(percents);
+        }
+        if let Some(percents) = saved.body_split_without_chat.as_deref() {
+            self.body_split_without_chat.set_percents(percents);
+        }
+        if let Some(visible) = saved.inspector_visible {
+            self.inspector_visible = visible;
+        }
+        if let Some(visible) = saved.chat_visible {
+            self.chat_visible = visible;
+        }
+        if let Some(filter) = saved.filter_variants_to_product {
+            self.filter_variants_to_product = filter;
+        }
+        self.pending_layout_selection = saved.selection;
+
+        Ok(true)
+    }
+
+    pub fn persist_layout(&self) -> io::Result<()> {
+        let parent = self
+            .layout_path
+            .parent()
+            .ok_or_else(|| io::Error::other("missing darktui_layout.toml parent"))?;
+        fs::create_dir_all(parent)?;
+
+        let payload = PersistedLayout {
+            results_view_mode: Some(self.results_view_mode),
+            viz_density: Some(self.viz_density),
+            viz_offset_x: Some(self.viz_offset_x),
+            viz_offset_y: Some(self.viz_offset_y),
+            body_split_with_chat: Some(self.body_split_with_chat.percents().to_vec()),
+            body_split_without_chat: Some(self.body_split_without_chat.percents().to_vec()),
+            inspector_visible: Some(self.inspector_visible),
+            chat_visible: Some(self.chat_visible),
+            filter_variants_to_product: Some(self.filter_variants_to_product),
+            selection: self.persisted_selection(),
+        };
+        let encoded = toml::to_string_pretty(&payload)
+            .map_err(|error| io::Error::other(error.to_string()))?;
+        fs::write(&self.layout_path, encoded)
+    }
+
+    /// Delete the saved layout so the next start uses the defaults.
+    pub fn reset_persisted_layout(&mut self) -> io::Result<bool> {
+        self.pending_layout_selection = None;
+        if !self.layout_path.exists() {
+            return Ok(false);
+        }
+
+        fs::remove_file(&self.layout_path)?;
+        Ok(true)
+    }
+
+    fn persisted_selection(&self) -> Option<PersistedSelection> {
+        // No snapshot arrived yet: keep whatever was saved last time.
+        if let Some(pending) = &self.pending_layout_selection {
+            return Some(pending.clone());
         }
 
-        self.chat_picker_selected = self.chat_picker_selected.min(len.saturating_sub(1));
+        match self.viz_selection.as_ref() {
+            Some(VizSelection::Product { product_index }) => self
+                .products
+                .get(*product_index)
+                .map(|row| PersistedSelection::Product(row.id.clone())),
+            Some(VizSelection::Variant { variant_id, .. }) => {
+                Some(PersistedSelection::Variant(variant_id.clone()))
+            }
+            // Sub-agents come and go between runs; restore their actor instead.
+            Some(
+                VizSelection::Actor { actor_id, .. } | VizSelection::SubAgent { actor_id, .. },
+            ) => Some(PersistedSelection::Actor(actor_id.clone())),
+            None => self
+                .products
+                .get(self.selected_product)
+                .map(|row| PersistedSelection::Product(row.id.clone())),
+        }
+    }
+
+    fn restore_persisted_selection(&mut self, saved: &PersistedSelection) {
+        let target = self
+            .viz_node_list()
+            .into_iter()
+            .find(|node| match (node, saved) {
+                (VizSelection::Product { product_index }, PersistedSelection::Product(id)) => self
+                    .products
+                    .get(*product_index)
+                    .is_some_and(|row| row.id == *id),
+                (VizSelection::Variant { variant_id, .. }, PersistedSelection::Variant(id)) => {
+                    variant_id == id
+                }
+                (VizSelection::Actor { actor_id, .. }, PersistedSelection::Actor(id)) => {
+                    actor_id == id
+                }
+                _ => false,
+            });
+
+        if let Some(target) = target {
+            self.set_viz_selection(target);
+        }
     }
 
     pub fn close_chat_autocomplete(&mut self) {
@@ -2195,16 +2446,8 @@ impl App {
         Some(selected)
     }
 
-    fn current_chat_picker_items(&self) -> &[String] {
-        match self.chat_picker_open {
-            Some(ChatPickerKind::Model) => &self.chat_model_options,
-            Some(ChatPickerKind::Agent) => &self.chat_agent_options,
-            None => &[],
-        }
-    }
-
     fn refresh_chat_autocomplete(&mut self) {
-        if !self.chat_composing {
+        if !self.is_chat_composing() {
             self.close_chat_autocomplete();
             return;
         }
@@ -2298,18 +2541,25 @@ impl App {
         }
     }
 
+    /// Id of the transcript to fetch: a sub-agent session id when one is
+    /// selected, otherwise the chat actor id.
     pub fn take_chat_refresh_request(&mut self) -> Option<String> {
         if !self.chat_visible || !self.chat_needs_refresh {
             return None;
         }
 
-        let actor_id = self.chat_actor_id.clone()?;
+        let transcript_id = self.chat_transcript_id()?;
         self.chat_needs_refresh = false;
-        Some(actor_id)
+        Some(transcript_id)
     }
 
-    pub fn apply_chat_messages(&mut self, actor_id: &str, mut messages: Vec<ActorChatMessageRow>) {
-        if self.chat_actor_id.as_deref() != Some(actor_id) {
+    /// Applies messages fetched for `transcript_id`; stale results are dropped.
+    pub fn apply_chat_messages(
+        &mut self,
+        transcript_id: &str,
+        mut messages: Vec<ActorChatMessageRow>,
+    ) {
+        if self.chat_transcript_id().as_deref() != Some(transcript_id) {
             return;
         }
 
@@ -2362,9 +2612,11 @@ impl App {
         self.chat_messages = messages;
         if self.chat_messages.is_empty() {
             self.close_chat_detail_popup();
-        } else if let Some(index) = self.chat_detail_popup_message_index {
-            let last = self.chat_messages.len().saturating_sub(1);
-            self.chat_detail_popup_message_index = Some(index.min(last));
+        } else if let Some(detail) = self
+            .overlays
+            .component_mut::<ChatDetailOverlay>(CHAT_DETAIL)
+        {
+            detail.clamp_message(self.chat_messages.len().saturating_sub(1));
         }
 
         // Keep scroll offsets bounded so repeated background refreshes cannot
@@ -2389,11 +2641,23 @@ impl App {
             .get(self.selected_actor)
             .map(|row| row.id.clone());
 
+        let events = detect_events(
+            &self.actors,
+            &self.variants,
+            &snapshot.actors,
+            &snapshot.variants,
+        );
+        if self.notifications.record(events, now_unix()) > 0 {
+            self.sync_notifications_overlay();
+        }
+
         self.products = snapshot.products;
         self.variants = snapshot.variants;
         self.actors = snapshot.actors;
+        self.record_sub_agent_history();
         self.runtime_status = snapshot.runtime_status;
         self.last_updated = snapshot.last_updated;
+        self.refresh_catalog_visibility();
 
         self.selected_product =
             resolve_index_by_id(&self.products, previous_product_id.as_deref(), |row| {
@@ -2414,8 +2678,13 @@ impl App {
             previous_actor_id.as_deref(),
         );
 
+        if let Some(saved) = self.pending_layout_selection.take() {
+            self.restore_persisted_selection(&saved);
+        }
+
         self.prune_chat_actor();
         self.prune_actor_last_message_previews();
+        self.prune_marks();
         if self.chat_visible && self.chat_actor_id.is_some() {
             self.chat_needs_refresh = true;
         }
@@ -2425,23 +2694,70 @@ impl App {
         self.status_message = status.into();
     }
 
+    /// Shows a failure in the status line and keeps it as an error toast.
+    pub fn set_error(&mut self, error: impl Into<String>) {
+        let error = error.into();
+        self.toasts.error(error.clone());
+        self.status_message = error;
+        if self.is_message_log_open() {
+            self.feed_overlays(self.toasts.clone());
+        }
+    }
+
     pub fn set_core_runtime_hint(&mut self, value: impl Into<String>) {
         self.core_runtime_hint = value.into();
     }
 
+    /// Shows the core log viewer focused, or hides it.
     pub fn toggle_core_logs_visibility(&mut self) {
         self.core_logs_visible = !self.core_logs_visible;
+        if self.core_logs_visible {
+            self.focus_core_logs();
+        } else {
+            self.release_pane(CORE_LOGS);
+        }
     }
 
-    pub fn set_core_logs_snapshot(
-        &mut self,
-        session: impl Into<String>,
-        lines: Vec<String>,
-        status: impl Into<String>,
-    ) {
-        self.core_logs_session = session.into();
-        self.core_logs_lines = lines;
-        self.core_logs_status = status.into();
+    /// Points the core log viewer at `path`, dropping buffered lines.
+    pub fn set_core_log_path(&mut self, path: Option<PathBuf>) {
+        if let Some(pane) = self.overlays.component_mut::<CoreLogsPane>(CORE_LOGS) {
+            pane.set_path(path);
+        }
+    }
+
+    pub fn focus_core_logs(&mut self) {
+        self.overlays.focus(CORE_LOGS);
+    }
+
+    pub fn unfocus_core_logs(&mut self) {
+        self.release_pane(CORE_LOGS);
+        if let Some(logs) = self.core_logs_mut() {
+            logs.finish_search();
+        }
+    }
+
+    /// Selects the first catalog node mentioned by the selected log line.
+    pub fn jump_to_core_log_entity(&mut self) -> Option<String> {
+        let entity_ids = self.core_logs()?.selected()?.entity_ids.clone();
+        let nodes = self.viz_node_list();
+        let (entity_id, target) = entity_ids.into_iter().find_map(|entity_id| {
+            nodes
+                .iter()
+                .find(|node| match node {
+                    VizSelection::Product { product_index } => self
+                        .products
+                        .get(*product_index)
+                        .is_some_and(|row| row.id == entity_id),
+                    VizSelection::Variant { variant_id, .. } => *variant_id == entity_id,
+                    VizSelection::Actor { actor_id, .. } => *actor_id == entity_id,
+                    VizSelection::SubAgent { sub_agent_id, .. } => *sub_agent_id == entity_id,
+                })
+                .cloned()
+                .map(|node| (entity_id, node))
+        })?;
+
+        self.set_viz_selection(target);
+        Some(entity_id)
     }
 
     pub fn set_command_message(&mut self, command: impl Into<String>) {
@@ -2452,10 +2768,6 @@ impl App {
         self.focus = self.focus.next();
     }
 
-    pub fn focus_previous(&mut self) {
-        self.focus = self.focus.previous();
-    }
-
     pub fn move_selection_down(&mut self) {
         self.viz_select_next();
     }
@@ -2499,7 +2811,7 @@ impl App {
         if let Some(idx) = self.actors.iter().position(|a| a.id == actor_id) {
             self.selected_actor = idx;
         }
-        self.set_chat_actor(actor_id);
+        self.set_chat_target(actor_id, None);
         self.focus = FocusPane::Variants;
         self.viz_selection = Some(VizSelection::Actor {
             product_index,
@@ -2568,7 +2880,22 @@ impl App {
                 if let Some(idx) = self.actors.iter().position(|a| a.id == *actor_id) {
                     self.selected_actor = idx;
                 }
-                self.set_chat_actor(actor_id);
+                self.set_chat_target(actor_id, None);
+                self.focus = FocusPane::Variants;
+            }
+            VizSelection::SubAgent {
+                product_index,
+                variant_id,
+                actor_id,
+                sub_agent_id,
+            } => {
+                self.selected_product = *product_index;
+                self.ensure_variant_selection(Some(variant_id));
+                // Actor actions keep targeting the sub-agent's parent actor.
+                if let Some(idx) = self.actors.iter().position(|a| a.id == *actor_id) {
+                    self.selected_actor = idx;
+                }
+                self.set_chat_target(actor_id, Some(sub_agent_id));
                 self.focus = FocusPane::Variants;
             }
         }
@@ -2576,103 +2903,750 @@ impl App {
     }
 
     /// Build the flattened node list for viz navigation.
-    /// Order: for each product, its variants, then each variant's actors.
+    /// Order: for each product, its variants, then each variant's actors,
+    /// each followed by its most recent sub-agents.
     fn viz_node_list(&self) -> Vec<VizSelection> {
         let mut nodes = Vec::new();
         for (pi, product) in self.products.iter().enumerate() {
+            if !self.is_product_visible(&product.id) {
+                continue;
+            }
             nodes.push(VizSelection::Product { product_index: pi });
             let variants: Vec<&VariantRow> = self
                 .variants
                 .iter()
-                .filter(|v| v.product_id == product.id)
+                .filter(|v| v.product_id == product.id && self.is_variant_visible(&v.id))
                 .collect();
             for variant in &variants {
                 nodes.push(VizSelection::Variant {
                     product_index: pi,
                     variant_id: variant.id.clone(),
                 });
-                let actors: Vec<&ActorRow> = self
-                    .actors
-                    .iter()
-                    .filter(|a| a.variant_id == variant.id)
-                    .collect();
-                for actor in &actors {
+                for actor in self.visible_actors_for_variant(&variant.id) {
                     nodes.push(VizSelection::Actor {
                         product_index: pi,
                         variant_id: variant.id.clone(),
                         actor_id: actor.id.clone(),
                     });
+                    for sub_agent in actor.recent_sub_agents() {
+                        nodes.push(VizSelection::SubAgent {
+                            product_index: pi,
+                            variant_id: variant.id.clone(),
+                            actor_id: actor.id.clone(),
+                            sub_agent_id: sub_agent.id.clone(),
+                        });
+                    }
                 }
             }
         }
-        nodes
+        nodes
+    }
+
+    /// Move viz selection to next node in the flattened list.
+    pub fn viz_select_next(&mut self) {
+        let nodes = self.viz_node_list();
+        if nodes.is_empty() {
+            return;
+        }
+        let current_pos = self
+            .viz_selection
+            .as_ref()
+            .and_then(|sel| nodes.iter().position(|n| n == sel))
+            .unwrap_or(0);
+        let next = (current_pos + 1) % nodes.len();
+        let sel = nodes[next].clone();
+        self.set_viz_selection(sel);
+    }
+
+    /// Move viz selection to previous node in the flattened list.
+    pub fn viz_select_prev(&mut self) {
+        let nodes = self.viz_node_list();
+        if nodes.is_empty() {
+            return;
+        }
+        let current_pos = self
+            .viz_selection
+            .as_ref()
+            .and_then(|sel| nodes.iter().position(|n| n == sel))
+            .unwrap_or(0);
+        let prev = (current_pos + nodes.len() - 1) % nodes.len();
+        let sel = nodes[prev].clone();
+        self.set_viz_selection(sel);
+    }
+
+    /// Sync viz selection from the current table selection state.
+    fn sync_viz_selection_from_table(&mut self) {
+        if self.products.is_empty() {
+            self.viz_selection = None;
+            return;
+        }
+        match self.focus {
+            FocusPane::Products => {
+                self.viz_selection = Some(VizSelection::Product {
+                    product_index: self.selected_product,
+                });
+            }
+            FocusPane::Variants => {
+                if let Some(variant) = self.selected_variant() {
+                    self.viz_selection = Some(VizSelection::Variant {
+                        product_index: self.selected_product,
+                        variant_id: variant.id.clone(),
+                    });
+                } else {
+                    self.viz_selection = Some(VizSelection::Product {
+                        product_index: self.selected_product,
+                    });
+                }
+            }
+        }
+    }
+
+    // --- Multi-select marks and bulk runs ---
+
+    pub fn is_marked(&self, node: &VizSelection) -> bool {
+        match node {
+            VizSelection::Product { .. } | VizSelection::SubAgent { .. } => false,
+            VizSelection::Variant { variant_id, .. } => self.is_variant_marked(variant_id),
+            VizSelection::Actor { actor_id, .. } => self.is_actor_marked(actor_id),
+        }
+    }
+
+    pub fn is_variant_marked(&self, variant_id: &str) -> bool {
+        self.marked_variants.contains(variant_id)
+    }
+
+    pub fn is_actor_marked(&self, actor_id: &str) -> bool {
+        self.marked_actors.contains(actor_id)
+    }
+
+    pub fn marked_count(&self) -> usize {
+        self.marked_variants.len() + self.marked_actors.len()
+    }
+
+    /// Marked variant ids in catalog order.
+    pub fn marked_variant_ids(&self) -> Vec<String> {
+        self.variants
+            .iter()
+            .filter(|variant| self.marked_variants.contains(&variant.id))
+            .map(|variant| variant.id.clone())
+            .collect()
+    }
+
+    /// Marked actors in catalog order.
+    pub fn marked_actors(&self) -> Vec<&ActorRow> {
+        self.actors
+            .iter()
+            .filter(|actor| self.marked_actors.contains(&actor.id))
+            .collect()
+    }
+
+    /// Toggles the mark on the selected variant or actor; products cannot be marked.
+    pub fn toggle_mark_selected(&mut self) -> bool {
+        let Some(node) = self.viz_selection.clone() else {
+            return false;
+        };
+        if matches!(node, VizSelection::Product { .. }) {
+            return false;
+        }
+
+        let marked = self.is_marked(&node);
+        self.set_marked(&node, !marked);
+        true
+    }
+
+    /// Marks every variant and actor between the current selection and `target`,
+    /// then selects `target` (shift-click).
+    pub fn mark_range_to(&mut self, target: VizSelection) -> usize {
+        let nodes = self.viz_node_list();
+        let end = nodes.iter().position(|node| *node == target);
+        let start = self
+            .viz_selection
+            .as_ref()
+            .and_then(|selection| nodes.iter().position(|node| node == selection))
+            .or(end);
+
+        let mut count = 0;
+        if let (Some(start), Some(end)) = (start, end) {
+            for node in &nodes[start.min(end)..=start.max(end)] {
+                if matches!(
+                    node,
+                    VizSelection::Variant { .. } | VizSelection::Actor { .. }
+                ) {
+                    self.set_marked(node, true);
+                    count += 1;
+                }
+            }
+        }
+
+        self.set_viz_selection(target);
+        count
+    }
+
+    /// Marks every variant left by the variant filter and catalog search,
+    /// together with their visible actors.
+    pub fn mark_all_visible(&mut self) -> usize {
+        let variant_ids: Vec<String> = self
+            .visible_variants()
+            .into_iter()
+            .filter(|variant| self.is_variant_visible(&variant.id))
+            .map(|variant| variant.id.clone())
+            .collect();
+        let actor_ids: Vec<String> = self
+            .actors
+            .iter()
+            .filter(|actor| {
+                variant_ids.contains(&actor.variant_id) && self.is_actor_visible(&actor.id)
+            })
+            .map(|actor| actor.id.clone())
+            .collect();
+
+        let count = variant_ids.len() + actor_ids.len();
+        self.marked_variants.extend(variant_ids);
+        self.marked_actors.extend(actor_ids);
+        self.bulk_terminate_armed = None;
+        count
+    }
+
+    pub fn clear_marks(&mut self) {
+        self.marked_variants.clear();
+        self.marked_actors.clear();
+        self.bulk_terminate_armed = None;
+    }
+
+    fn set_marked(&mut self, node: &VizSelection, marked: bool) {
+        let (set, id) = match node {
+            VizSelection::Product { .. } | VizSelection::SubAgent { .. } => return,
+            VizSelection::Variant { variant_id, .. } => (&mut self.marked_variants, variant_id),
+            VizSelection::Actor { actor_id, .. } => (&mut self.marked_actors, actor_id),
+        };
+        if marked {
+            set.insert(id.clone());
+        } else {
+            set.remove(id);
+        }
+        self.bulk_terminate_armed = None;
+    }
+
+    fn prune_marks(&mut self) {
+        let variants = &self.variants;
+        let actors = &self.actors;
+        self.marked_variants
+            .retain(|id| variants.iter().any(|variant| &variant.id == id));
+        self.marked_actors
+            .retain(|id| actors.iter().any(|actor| &actor.id == id));
+    }
+
+    /// First press arms termination of the marked actors; returns their ids on
+    /// the second press while the marks are unchanged.
+    pub fn confirm_bulk_terminate(&mut self) -> Option<Vec<String>> {
+        let actor_ids: Vec<String> = self
+            .marked_actors()
+            .into_iter()
+            .map(|actor| actor.id.clone())
+            .collect();
+        if self.bulk_terminate_armed.as_ref() == Some(&actor_ids) {
+            self.bulk_terminate_armed = None;
+            return Some(actor_ids);
+        }
+
+        self.bulk_terminate_armed = Some(actor_ids);
+        None
+    }
+
+    pub fn disarm_bulk_terminate(&mut self) {
+        self.bulk_terminate_armed = None;
+    }
+
+    pub fn is_bulk_prompt_form_open(&self) -> bool {
+        self.overlays.is_visible(BULK_PROMPT_FORM)
+    }
+
+    /// Opens the prompt for the marked actors, starting from `draft`.
+    pub fn open_bulk_prompt_form(&mut self, draft: String) {
+        let recipients = self
+            .marked_actors()
+            .iter()
+            .map(|actor| format!("{} [{}]", actor.title, actor.status))
+            .collect();
+        if let Some(form) = self
+            .overlays
+            .component_mut::<BulkPromptOverlay>(BULK_PROMPT_FORM)
+        {
+            form.open(recipients, draft);
+        }
+        self.show_overlay(BULK_PROMPT_FORM);
+    }
+
+    /// Closes the form and returns the trimmed prompt, or `None` when it is empty.
+    pub fn take_bulk_prompt(&mut self) -> Option<String> {
+        let prompt = self
+            .open_overlay_mut::<BulkPromptOverlay>(BULK_PROMPT_FORM)?
+            .take_prompt();
+        self.overlays.hide(BULK_PROMPT_FORM);
+        prompt
+    }
+
+    pub fn bulk_run(&self) -> Option<&BulkRun> {
+        self.bulk_run.as_ref()
+    }
+
+    pub fn is_bulk_run_active(&self) -> bool {
+        self.bulk_run.as_ref().is_some_and(|run| !run.is_finished())
+    }
+
+    /// Starts tracking a bulk run and opens its summary popup.
+    pub fn start_bulk_run(&mut self, operation: BulkOperation, targets: Vec<(String, String)>) {
+        let run = BulkRun::new(operation, targets);
+        self.status_message = run.summary();
+        self.feed_overlays(run.clone());
+        self.bulk_run = Some(run);
+        self.show_overlay(BULK_SUMMARY_OVERLAY);
+    }
+
+    /// Records one target's result; returns the finished run's operation once
+    /// every target has reported.
+    pub fn record_bulk_result(
+        &mut self,
+        target_id: &str,
+        outcome: Result<String, String>,
+    ) -> Option<BulkOperation> {
+        let run = self.bulk_run.as_mut()?;
+        if !run.record(target_id, outcome) {
+            return None;
+        }
+
+        self.status_message = run.summary();
+        let run = run.clone();
+        let finished = run.is_finished().then_some(run.operation);
+        self.feed_overlays(run);
+        finished
+    }
+
+    pub fn is_bulk_summary_open(&self) -> bool {
+        self.overlays.is_visible(BULK_SUMMARY_OVERLAY)
+    }
+
+    /// Shows the latest bulk run; false when nothing has run yet.
+    pub fn open_bulk_summary(&mut self) -> bool {
+        if self.bulk_run.is_none() {
+            return false;
+        }
+        self.show_overlay(BULK_SUMMARY_OVERLAY);
+        true
+    }
+
+    // --- Component overlays ---
+
+    pub fn overlays(&self) -> &ComponentRuntime {
+        &self.overlays
+    }
+
+    pub fn overlays_mut(&mut self) -> &mut ComponentRuntime {
+        &mut self.overlays
+    }
+
+    pub fn show_overlay(&mut self, id: ComponentId) {
+        self.overlays.show(id);
+    }
+
+    /// Visible overlay that currently owns keyboard and mouse input.
+    pub fn modal_overlay(&self) -> Option<ComponentId> {
+        self.overlays.modal()
+    }
+
+    /// Routes a key or mouse event through the overlays. Form edits and
+    /// picked options are applied here; everything else goes back to the host.
+    pub fn overlay_event(
+        &mut self,
+        event: &ComponentEvent,
+    ) -> ComponentResult<Vec<ComponentAction>> {
+        let mut actions = Vec::new();
+        for action in self.overlays.handle_event(event)? {
+            if let Some(&FormSignal::Edited(id)) = action.downcast_ref::<FormSignal>() {
+                self.form_edited(id);
+            } else if let Some(picked) = action.downcast_ref::<Picked>() {
+                let status = self.apply_pick(picked);
+                actions.push(ComponentAction::StatusMessage(status));
+            } else {
+                actions.push(action);
+            }
+        }
+        Ok(actions)
+    }
+
+    /// Open form of the form overlay `id`.
+    pub fn form(&self, id: ComponentId) -> Option<&Form> {
+        self.open_overlay::<FormOverlay>(id).map(FormOverlay::form)
+    }
+
+    pub fn form_mut(&mut self, id: ComponentId) -> Option<&mut Form> {
+        self.open_overlay_mut::<FormOverlay>(id)
+            .map(FormOverlay::form_mut)
+    }
+
+    /// Overlay `id` while it is shown.
+    fn open_overlay<T: Component>(&self, id: ComponentId) -> Option<&T> {
+        self.overlays
+            .component::<T>(id)
+            .filter(|_| self.overlays.is_visible(id))
+    }
+
+    fn open_overlay_mut<T: Component>(&mut self, id: ComponentId) -> Option<&mut T> {
+        if !self.overlays.is_visible(id) {
+            return None;
+        }
+        self.overlays.component_mut::<T>(id)
+    }
+
+    fn open_form(&mut self, id: ComponentId, form: Form) {
+        if let Some(overlay) = self.overlays.component_mut::<FormOverlay>(id) {
+            overlay.set_form(form);
+        }
+        self.show_overlay(id);
+    }
+
+    /// Closes the form overlay `id` and hands back its form for submission.
+    fn take_form(&mut self, id: ComponentId) -> Option<Form> {
+        let form = self.open_overlay_mut::<FormOverlay>(id)?.take_form();
+        self.overlays.hide(id);
+        Some(form)
+    }
+
+    /// Re-derives the fields of form `id` that depend on the edited ones.
+    fn form_edited(&mut self, id: ComponentId) {
+        match id {
+            BRANCH_FORM => self.sync_branch_form(),
+            CLONE_FORM => self.sync_clone_form(),
+            DELETE_VARIANT_FORM => self.sync_delete_variant_form(),
+            CATALOG_SEARCH => self.apply_catalog_search_draft(),
+            COMMAND_PALETTE => self.refresh_command_palette(),
+            _ => {}
+        }
+    }
+
+    /// Hands the keys back to the dashboard if pane `id` has them.
+    fn release_pane(&mut self, id: ComponentId) {
+        if self.overlays.base_focus() == Some(id) {
+            self.overlays.focus(DASHBOARD);
+        }
+    }
+
+    /// Hands a data payload to the overlays that render it.
+    fn feed_overlays(&mut self, payload: impl Any + Send + Sync) {
+        if let Err(error) = self.overlays.dispatch(ComponentAction::custom(payload)) {
+            error!(error = %error, "Dark TUI // Overlays // Update failed");
+            self.status_message = format!("Overlay error: {error}");
+        }
+    }
+
+    // --- System metrics ---
+
+    pub fn core_health(&self) -> &CoreHealth {
+        &self.core_health
+    }
+
+    /// Stores the latest health and returns it when it changed. The samples
+    /// themselves go to the metrics overlay through the component runtime.
+    pub fn record_core_health(&mut self, health: &CoreHealth) -> Option<CoreHealth> {
+        if self.core_health == *health {
+            return None;
+        }
+        self.core_health = health.clone();
+        Some(health.clone())
+    }
+
+    pub fn is_metrics_open(&self) -> bool {
+        self.overlays.is_visible(METRICS_OVERLAY)
+    }
+
+    pub fn open_metrics(&mut self) {
+        self.show_overlay(METRICS_OVERLAY);
+    }
+
+    // --- Notifications ---
+
+    pub fn set_notification_config(&mut self, config: NotificationConfig) {
+        self.notifications.set_config(config);
+    }
+
+    pub fn notifications(&self) -> &NotificationCenter {
+        &self.notifications
+    }
+
+    /// Notifications routed since the last call, for bell/desktop/hook delivery.
+    pub fn take_pending_notifications(&mut self) -> Vec<Notification> {
+        self.notifications.take_pending()
+    }
+
+    pub fn is_notifications_open(&self) -> bool {
+        self.overlays.is_visible(NOTIFICATIONS_OVERLAY)
+    }
+
+    pub fn open_notifications(&mut self) {
+        self.notifications.mark_read();
+        self.sync_notifications_overlay();
+        self.show_overlay(NOTIFICATIONS_OVERLAY);
+    }
+
+    pub fn clear_notifications(&mut self) {
+        self.notifications.clear();
+        self.sync_notifications_overlay();
+    }
+
+    fn sync_notifications_overlay(&mut self) {
+        let history = self.notifications.history().cloned().collect();
+        self.feed_overlays(NotificationHistory(history));
+    }
+
+    /// Selects the actor or variant with `target_id` and closes the history.
+    pub fn jump_to_notification_target(&mut self, target_id: &str) -> bool {
+        let Some(target) = self.viz_node_list().into_iter().find(|node| match node {
+            VizSelection::Product { .. } | VizSelection::SubAgent { .. } => false,
+            VizSelection::Variant { variant_id, .. } => variant_id == target_id,
+            VizSelection::Actor { actor_id, .. } => actor_id == target_id,
+        }) else {
+            return false;
+        };
+
+        self.set_viz_selection(target);
+        self.overlays.hide(NOTIFICATIONS_OVERLAY);
+        true
+    }
+
+    // --- Toasts ---
+
+    pub fn toasts(&self) -> &ToastStack {
+        &self.toasts
+    }
+
+    pub fn toasts_mut(&mut self) -> &mut ToastStack {
+        &mut self.toasts
+    }
+
+    pub fn is_message_log_open(&self) -> bool {
+        self.overlays.is_visible(MESSAGE_LOG_OVERLAY)
+    }
+
+    pub fn open_message_log(&mut self) {
+        self.feed_overlays(self.toasts.clone());
+        self.show_overlay(MESSAGE_LOG_OVERLAY);
+    }
+
+    pub fn clear_message_log(&mut self) {
+        self.toasts.clear_history();
+        self.feed_overlays(self.toasts.clone());
+    }
+
+    // --- Embedded terminal ---
+
+    pub fn terminal_pane(&self) -> Option<&TerminalPane> {
+        self.terminal_pane.as_ref()
+    }
+
+    pub fn terminal_pane_mut(&mut self) -> Option<&mut TerminalPane> {
+        self.terminal_pane.as_mut()
+    }
+
+    pub fn is_terminal_pane_open(&self) -> bool {
+        self.terminal_pane.is_some()
+    }
+
+    pub fn is_terminal_pane_focused(&self) -> bool {
+        self.terminal_pane.is_some() && self.overlays.base_focus() == Some(TERMINAL_PANE)
+    }
+
+    /// Show `pane` in the chat column and focus it, replacing any open pane.
+    pub fn open_terminal_pane(&mut self, pane: TerminalPane) {
+        self.terminal_pane = Some(pane);
+        self.overlays.focus(TERMINAL_PANE);
+        self.resizing_target = None;
+    }
+
+    pub fn focus_terminal_pane(&mut self) -> bool {
+        if self.terminal_pane.is_none() {
+            return false;
+        }
+        self.overlays.focus(TERMINAL_PANE);
+        true
+    }
+
+    /// Return keys to the dashboard; the pane keeps running.
+    pub fn detach_terminal_pane(&mut self) {
+        self.release_pane(TERMINAL_PANE);
+    }
+
+    /// Close the pane, ending its process. Returns the pane title.
+    pub fn close_terminal_pane(&mut self) -> Option<String> {
+        self.release_pane(TERMINAL_PANE);
+        self.terminal_pane.take().map(|pane| pane.title())
+    }
+
+    /// Close the pane once its process has exited and describe why.
+    pub fn reap_terminal_pane(&mut self) -> Option<String> {
+        let exit = self.terminal_pane.as_mut()?.try_exit()?;
+        let title = self.close_terminal_pane().unwrap_or_default();
+        Some(format!("Terminal {title} closed ({exit})."))
+    }
+
+    /// Return actors belonging to a specific variant.
+    pub fn actors_for_variant(&self, variant_id: &str) -> Vec<&ActorRow> {
+        self.actors
+            .iter()
+            .filter(|a| a.variant_id == variant_id)
+            .collect()
+    }
+
+    /// Actors of a variant that the catalog search leaves visible.
+    pub fn visible_actors_for_variant(&self, variant_id: &str) -> Vec<&ActorRow> {
+        self.actors
+            .iter()
+            .filter(|a| a.variant_id == variant_id && self.is_actor_visible(&a.id))
+            .collect()
+    }
+
+    // --- Catalog search ---
+
+    pub fn catalog_query(&self) -> Option<&CatalogQuery> {
+        self.catalog_query.as_ref()
+    }
+
+    /// Number of rows matching the active search directly.
+    pub fn catalog_match_count(&self) -> usize {
+        self.catalog_visibility.as_ref().map_or(0, |visibility| {
+            visibility.matched_products.len()
+                + visibility.matched_variants.len()
+                + visibility.matched_actors.len()
+        })
+    }
+
+    pub fn is_product_visible(&self, product_id: &str) -> bool {
+        self.catalog_visibility
+            .as_ref()
+            .is_none_or(|visibility| visibility.products.contains(product_id))
+    }
+
+    pub fn is_variant_visible(&self, variant_id: &str) -> bool {
+        self.catalog_visibility
+            .as_ref()
+            .is_none_or(|visibility| visibility.variants.contains(variant_id))
+    }
+
+    pub fn is_actor_visible(&self, actor_id: &str) -> bool {
+        self.catalog_visibility
+            .as_ref()
+            .is_none_or(|visibility| visibility.actors.contains(actor_id))
+    }
+
+    /// Whether `node` matched the active search itself rather than being shown
+    /// as the parent of a match.
+    pub fn is_catalog_match(&self, node: &VizSelection) -> bool {
+        let Some(visibility) = self.catalog_visibility.as_ref() else {
+            return false;
+        };
+        match node {
+            VizSelection::Product { product_index } => self
+                .products
+                .get(*product_index)
+                .is_some_and(|product| visibility.matched_products.contains(&product.id)),
+            VizSelection::Variant { variant_id, .. } => {
+                visibility.matched_variants.contains(variant_id)
+            }
+            VizSelection::Actor { actor_id, .. } => visibility.matched_actors.contains(actor_id),
+            // Search matches actors; sub-agents are only listed beneath them.
+            VizSelection::SubAgent { .. } => false,
+        }
+    }
+
+    /// Parses and applies `text` as the catalog search; empty text clears it.
+    pub fn set_catalog_query(&mut self, text: &str) -> Result<(), String> {
+        let query = CatalogQuery::parse(text)?;
+        self.apply_catalog_query(query);
+        Ok(())
+    }
+
+    pub fn is_catalog_search_open(&self) -> bool {
+        self.overlays.is_visible(CATALOG_SEARCH)
+    }
+
+    pub fn open_catalog_search(&mut self) {
+        let previous = self.catalog_query.clone();
+        if let Some(search) = self
+            .overlays
+            .component_mut::<CatalogSearchOverlay>(CATALOG_SEARCH)
+        {
+            search.open(previous);
+        }
+        self.show_overlay(CATALOG_SEARCH);
     }
 
-    /// Move viz selection to next node in the flattened list.
-    pub fn viz_select_next(&mut self) {
-        let nodes = self.viz_node_list();
-        if nodes.is_empty() {
+    pub fn catalog_search_draft(&self) -> &str {
+        self.open_overlay::<CatalogSearchOverlay>(CATALOG_SEARCH)
+            .map_or("", CatalogSearchOverlay::draft)
+    }
+
+    pub fn catalog_search_error(&self) -> Option<&str> {
+        self.open_overlay::<CatalogSearchOverlay>(CATALOG_SEARCH)
+            .and_then(CatalogSearchOverlay::error)
+    }
+
+    /// Closes the search bar keeping the last query that parsed.
+    pub fn close_catalog_search(&mut self) {
+        self.overlays.hide(CATALOG_SEARCH);
+    }
+
+    /// Closes the search bar and restores the query active before editing.
+    pub fn cancel_catalog_search(&mut self) {
+        let Some(search) = self.open_overlay_mut::<CatalogSearchOverlay>(CATALOG_SEARCH) else {
             return;
-        }
-        let current_pos = self
-            .viz_selection
-            .as_ref()
-            .and_then(|sel| nodes.iter().position(|n| n == sel))
-            .unwrap_or(0);
-        let next = (current_pos + 1) % nodes.len();
-        let sel = nodes[next].clone();
-        self.set_viz_selection(sel);
+        };
+        let previous = search.take_previous();
+        self.overlays.hide(CATALOG_SEARCH);
+        self.apply_catalog_query(previous);
     }
 
-    /// Move viz selection to previous node in the flattened list.
-    pub fn viz_select_prev(&mut self) {
-        let nodes = self.viz_node_list();
-        if nodes.is_empty() {
+    /// Live-filters while typing; an unparsable draft keeps the last good query.
+    fn apply_catalog_search_draft(&mut self) {
+        let Some(search) = self.open_overlay_mut::<CatalogSearchOverlay>(CATALOG_SEARCH) else {
             return;
+        };
+        let parsed = CatalogQuery::parse(search.draft());
+        search.set_error(parsed.as_ref().err().cloned());
+        if let Ok(query) = parsed {
+            self.apply_catalog_query(query);
         }
-        let current_pos = self
-            .viz_selection
-            .as_ref()
-            .and_then(|sel| nodes.iter().position(|n| n == sel))
-            .unwrap_or(0);
-        let prev = (current_pos + nodes.len() - 1) % nodes.len();
-        let sel = nodes[prev].clone();
-        self.set_viz_selection(sel);
     }
 
-    /// Sync viz selection from the current table selection state.
-    fn sync_viz_selection_from_table(&mut self) {
-        if self.products.is_empty() {
-            self.viz_selection = None;
+    fn apply_catalog_query(&mut self, query: Option<CatalogQuery>) {
+        self.catalog_query = query;
+        self.refresh_catalog_visibility();
+        self.keep_viz_selection_visible();
+    }
+
+    fn refresh_catalog_visibility(&mut self) {
+        self.catalog_visibility = self.catalog_query.as_ref().map(|query| {
+            CatalogVisibility::compute(query, &self.products, &self.variants, &self.actors)
+        });
+    }
+
+    /// Moves the selection to the first search match when it was filtered out.
+    fn keep_viz_selection_visible(&mut self) {
+        let nodes = self.viz_node_list();
+        if self
+            .viz_selection
+            .as_ref()
+            .is_some_and(|selection| nodes.contains(selection))
+        {
             return;
         }
-        match self.focus {
-            FocusPane::Products => {
-                self.viz_selection = Some(VizSelection::Product {
-                    product_index: self.selected_product,
-                });
-            }
-            FocusPane::Variants => {
-                if let Some(variant) = self.selected_variant() {
-                    self.viz_selection = Some(VizSelection::Variant {
-                        product_index: self.selected_product,
-                        variant_id: variant.id.clone(),
-                    });
-                } else {
-                    self.viz_selection = Some(VizSelection::Product {
-                        product_index: self.selected_product,
-                    });
-                }
-            }
-        }
-    }
 
-    /// Return actors belonging to a specific variant.
-    pub fn actors_for_variant(&self, variant_id: &str) -> Vec<&ActorRow> {
-        self.actors
+        let target = nodes
             .iter()
-            .filter(|a| a.variant_id == variant_id)
-            .collect()
+            .find(|node| self.is_catalog_match(node))
+            .or_else(|| nodes.first())
+            .cloned();
+        if let Some(target) = target {
+            self.set_viz_selection(target);
+        }
     }
 
     // --- Viz-mode 2D pan / drag ---
@@ -2737,7 +3711,6 @@ impl App {
         self.selected_actor().map(|row| row.id.as_str())
     }
 
-    #[allow(dead_code)]
     pub fn visible_variants(&self) -> Vec<&VariantRow> {
         self.visible_variant_indices()
             .into_iter()
@@ -2751,7 +3724,7 @@ impl App {
             return match sel {
                 VizSelection::Product { .. } => self.product_detail_lines(),
                 VizSelection::Variant { .. } => self.variant_detail_lines(),
-                VizSelection::Actor { actor_id, .. } => {
+                VizSelection::Actor { actor_id, .. } | VizSelection::SubAgent { actor_id, .. } => {
                     if let Some(actor) = self.actors.iter().find(|a| a.id == *actor_id) {
                         self.actor_detail_lines(actor)
                     } else {
@@ -2931,9 +3904,11 @@ impl App {
         self.selected_variant = self.selected_variant.min(max_index);
     }
 
-    fn set_chat_actor(&mut self, actor_id: &str) {
-        let changed = self.chat_actor_id.as_deref() != Some(actor_id);
+    fn set_chat_target(&mut self, actor_id: &str, sub_agent_id: Option<&str>) {
+        let changed = self.chat_actor_id.as_deref() != Some(actor_id)
+            || self.chat_sub_agent_id.as_deref() != sub_agent_id;
         self.chat_actor_id = Some(actor_id.to_string());
+        self.chat_sub_agent_id = sub_agent_id.map(ToString::to_string);
         if self.chat_visible {
             self.chat_needs_refresh = true;
         }
@@ -2942,25 +3917,86 @@ impl App {
             self.chat_messages.clear();
             self.chat_scroll_lines = 0;
             self.chat_draft.clear();
-            self.chat_composing = false;
+            self.release_pane(CHAT_COMPOSER);
             self.close_chat_detail_popup();
         }
     }
 
+    /// Appends a history entry for every sub-agent whose status changed and
+    /// forgets sub-agents that left the tree.
+    fn record_sub_agent_history(&mut self) {
+        let mut present = BTreeSet::new();
+        for sub_agent in self.actors.iter().flat_map(|actor| &actor.sub_agents) {
+            present.insert(sub_agent.id.as_str());
+            let history = self
+                .sub_agent_history
+                .entry(sub_agent.id.clone())
+                .or_default();
+            if history
+                .last()
+                .is_some_and(|last| last.status == sub_agent.status)
+            {
+                continue;
+            }
+
+            let at = sub_agent.updated_at.trim();
+            history.push(SubAgentStatusChange {
+                status: sub_agent.status.clone(),
+                at: if at.is_empty() { "-" } else { at }.to_string(),
+            });
+            if history.len() > SUB_AGENT_HISTORY_LIMIT {
+                history.remove(0);
+            }
+        }
+        self.sub_agent_history
+            .retain(|id, _| present.contains(id.as_str()));
+    }
+
+    pub fn sub_agent_status_history(&self, sub_agent_id: &str) -> &[SubAgentStatusChange] {
+        self.sub_agent_history
+            .get(sub_agent_id)
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+    }
+
+    /// Sub-agent selected in the catalog, with its actor.
+    pub fn selected_sub_agent(&self) -> Option<(&ActorRow, &SubAgentRow)> {
+        let Some(VizSelection::SubAgent {
+            actor_id,
+            sub_agent_id,
+            ..
+        }) = &self.viz_selection
+        else {
+            return None;
+        };
+        let actor = self.actors.iter().find(|actor| actor.id == *actor_id)?;
+        Some((actor, actor.sub_agent(sub_agent_id)?))
+    }
+
     fn prune_chat_actor(&mut self) {
         let Some(chat_actor_id) = self.chat_actor_id.as_deref() else {
             return;
         };
 
-        if self.actors.iter().any(|actor| actor.id == chat_actor_id) {
+        if let Some(actor) = self.actors.iter().find(|actor| actor.id == chat_actor_id) {
+            // A finished sub-agent that dropped out of the tree falls back to its actor.
+            let sub_agent_gone = self
+                .chat_sub_agent_id
+                .as_deref()
+                .is_some_and(|sub_agent_id| actor.sub_agent(sub_agent_id).is_none());
+            if sub_agent_gone {
+                let actor_id = actor.id.clone();
+                self.set_chat_target(&actor_id, None);
+            }
             return;
         }
 
         self.chat_actor_id = None;
+        self.chat_sub_agent_id = None;
         self.chat_messages.clear();
         self.chat_scroll_lines = 0;
         self.chat_draft.clear();
-        self.chat_composing = false;
+        self.release_pane(CHAT_COMPOSER);
         self.close_chat_detail_popup();
         self.chat_needs_refresh = false;
     }
@@ -3030,6 +4066,17 @@ impl App {
                         }
                     }
                 }
+                VizSelection::SubAgent { sub_agent_id, .. } => {
+                    // Keep the sub-agent while it is still listed; otherwise
+                    // the previous actor id below selects its actor.
+                    let node = self.viz_node_list().into_iter().find(|node| {
+                        matches!(node, VizSelection::SubAgent { sub_agent_id: id, .. } if id == sub_agent_id)
+                    });
+                    if let Some(node) = node {
+                        self.set_viz_selection(node);
+                        return;
+                    }
+                }
             }
         }
 
@@ -3104,6 +4151,17 @@ impl App {
         fs::write(&self.chat_preferences_path, encoded)
     }
 
+    fn load_layout_from_disk(&self) -> io::Result<Option<PersistedLayout>> {
+        if !self.layout_path.exists() {
+            return Ok(None);
+        }
+
+        let raw = fs::read_to_string(&self.layout_path)?;
+        let decoded = toml::from_str::<PersistedLayout>(&raw)
+            .map_err(|error| io::Error::other(error.to_string()))?;
+        Ok(Some(decoded))
+    }
+
     fn load_chat_selection_from_disk(&self) -> io::Result<Option<PersistedChatSelection>> {
         if !self.chat_preferences_path.exists() {
             return Ok(None);
@@ -3116,6 +4174,25 @@ impl App {
     }
 }
 
+/// Palette history saved by `record_command_history`; a missing file is empty.
+fn load_command_history(path: &Path) -> anyhow::Result<Vec<String>> {
+    if !path.exists() {
+        return Ok(Vec::new());
+    }
+    let raw = fs::read_to_string(path).map_err(|error| {
+        anyhow::anyhow!(
+            "Dark TUI // Palette // Failed to read command history (path={}): {error}",
+            path.display()
+        )
+    })?;
+    serde_json::from_str(&raw).map_err(|error| {
+        anyhow::anyhow!(
+            "Dark TUI // Palette // Failed to decode command history (path={}): {error}",
+            path.display()
+        )
+    })
+}
+
 fn resolve_index_by_id<T>(rows: &[T], id: Option<&str>, id_accessor: impl Fn(&T) -> &str) -> usize {
     if rows.is_empty() {
         return 0;
@@ -3166,11 +4243,32 @@ fn clone_name_slug(value: &str) -> String {
     }
 }
 
-fn branch_suggestions_for(form: &BranchFormState) -> Vec<&str> {
-    let query = form.branch_name.trim().to_ascii_lowercase();
+/// Remote host row as listed by the clone form and its host picker.
+fn clone_host_label(host: &SshHostRow) -> String {
+    if host.default_path == "-" {
+        format!("{}  [{}]", host.key, host.source)
+    } else {
+        format!(
+            "{}  [{}]  path:{}",
+            host.key, host.source, host.default_path
+        )
+    }
+}
+
+fn tmux_session_label(session: &TmuxSessionRow) -> String {
+    format!(
+        "{} cmd={} windows={} attached={}",
+        session.name,
+        session.current_command,
+        session.windows,
+        if session.attached { "yes" } else { "no" }
+    )
+}
+
+fn branch_suggestions_for<'a>(suggestions: &'a [String], branch_name: &str) -> Vec<&'a str> {
+    let query = branch_name.trim().to_ascii_lowercase();
 
-    let mut scored = form
-        .suggestions
+    let mut scored = suggestions
         .iter()
         .map(String::as_str)
         .filter_map(|value| fuzzy_branch_score(&query, value).map(|score| (value, score)))
@@ -3269,8 +4367,16 @@ fn chat_token_start(value: &str) -> usize {
         .map_or(0, |index| index + 1)
 }
 
+/// Actors that are mid-turn or not running hold their queued prompts back.
+fn actor_accepts_queued_prompt(status: &str) -> bool {
+    !matches!(
+        status.trim().to_ascii_lowercase().as_str(),
+        "busy" | "running" | "retry" | "retrying" | "stopped" | "offline"
+    )
+}
+
 fn slash_suggestions(query: &str) -> Vec<String> {
-    const COMMANDS: [&str; 8] = [
+    const COMMANDS: [&str; 12] = [
         "/help",
         "/refresh",
         "/new",
@@ -3279,6 +4385,10 @@ fn slash_suggestions(query: &str) -> Vec<String> {
         "/agent ",
         "/model ",
         "/grep ",
+        "/export",
+        "/queue ",
+        "/attach ",
+        "/detach",
     ];
 
     let needle = query.to_ascii_lowercase();
@@ -3359,9 +4469,13 @@ fn collect_workspace_files(root: &str, limit: usize, max_depth: usize) -> Vec<St
 
 #[cfg(test)]
 mod tests {
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+    use dark_tui_components::Event;
     use serde_json::json;
 
     use super::*;
+    use crate::ui::overlays::NotificationsRequest;
+    use crate::ui::panes::CoreLogsRequest;
 
     #[test]
     fn refresh_keeps_product_selection_after_actor_was_selected() {
@@ -3396,14 +4510,22 @@ mod tests {
         ));
     }
 
+    /// Presses `code` on the overlay that has focus, e.g. an open form.
+    fn press(app: &mut App, code: KeyCode) {
+        let key = KeyEvent::new(code, KeyModifiers::NONE);
+        app.overlay_event(&ComponentEvent::Key(key))
+            .expect("overlay should take the key");
+    }
+
     #[test]
     fn spawn_request_uses_opened_variant_target() {
         let mut app = App::new(".".to_string(), 5, Theme::default());
-        app.open_spawn_form("var_2", vec!["mock".to_string()], Some("mock"));
-        app.spawn_form_insert_char(' ');
-        app.spawn_form_insert_char('h');
-        app.spawn_form_insert_char('i');
-        app.spawn_form_insert_char(' ');
+        app.open_spawn_form("var_2");
+        assert!(app.form(SPAWN_FORM).is_some_and(Form::is_loading));
+        app.set_spawn_form_providers(vec!["mock".to_string()], Some("mock"));
+        for ch in " hi ".chars() {
+            press(&mut app, KeyCode::Char(ch));
+        }
 
         let request = app
             .take_spawn_request()
@@ -3448,7 +4570,9 @@ mod tests {
             vec![],
         );
         app.open_ssh_panel();
-        app.ssh_panel_move_down();
+        let panel = app.form_mut(SSH_PANEL).expect("ssh panel should be open");
+        panel.focus_field("forward");
+        panel.handle_key(KeyEvent::new(KeyCode::Down, KeyModifiers::NONE));
 
         let request = app
             .take_start_ssh_port_forward_request()
@@ -3456,6 +4580,40 @@ mod tests {
         assert_eq!(request.preset_name, "api");
     }
 
+    #[test]
+    fn branch_form_filters_suggestions_and_copies_the_picked_one() {
+        let mut app = App::new(".".to_string(), 5, Theme::default());
+        let mut data = snapshot();
+        for (id, branch) in [("var_3", "feature/login"), ("var_4", "feature/logout")] {
+            let mut row = variant(id, "prd_1");
+            row.branch = branch.to_string();
+            row.worktree = branch.to_string();
+            data.variants.push(row);
+        }
+        app.apply_snapshot(data);
+        app.select_variant_in_product(0, "var_1");
+        assert!(app.open_branch_form());
+
+        for _ in 0.."main".len() {
+            press(&mut app, KeyCode::Backspace);
+        }
+        for ch in "feat".chars() {
+            press(&mut app, KeyCode::Char(ch));
+        }
+        press(&mut app, KeyCode::Tab);
+        press(&mut app, KeyCode::Down);
+
+        let form = app.form(BRANCH_FORM).expect("branch form should be open");
+        assert_eq!(form.options("suggestion").len(), 2);
+        assert_eq!(form.text("branch"), "feature/logout");
+
+        let request = app
+            .take_branch_request()
+            .expect("branch request should exist");
+        assert_eq!(request.variant_id, "var_1");
+        assert_eq!(request.branch_name, "feature/logout");
+    }
+
     #[test]
     fn clone_form_can_autofill_remote_target_from_host() {
         let mut app = App::new(".".to_string(), 5, Theme::default());
@@ -3486,13 +4644,18 @@ mod tests {
         );
 
         app.open_clone_form();
-        app.clone_form_select_next_remote_host();
+        for code in [KeyCode::Tab, KeyCode::Tab, KeyCode::Down] {
+            press(&mut app, code);
+        }
 
-        let target = app
-            .clone_form_target_path()
-            .expect("clone target should be populated")
-            .to_string();
-        assert!(target.starts_with("@ssh://staging/"));
+        let form = app.form(CLONE_FORM).expect("clone form should be open");
+        assert_eq!(form.choice("remote_host"), Some("staging"));
+        assert!(form.text("target_path").starts_with("@ssh://staging/"));
+
+        press(&mut app, KeyCode::Char('l'));
+        let form = app.form(CLONE_FORM).expect("clone form should be open");
+        assert_eq!(form.choice("remote_host"), Some(""));
+        assert_eq!(form.text("target_path"), "");
     }
 
     #[test]
@@ -3525,18 +4688,18 @@ mod tests {
         );
 
         app.open_clone_form();
-        app.open_clone_host_picker();
-        app.clone_host_picker_insert_char('s');
-        app.clone_host_picker_insert_char('t');
-        app.clone_host_picker_insert_char('a');
-        app.clone_host_picker_insert_char('g');
+        assert!(app.open_clone_host_picker());
+        for code in "stag".chars().map(KeyCode::Char).chain([KeyCode::Enter]) {
+            press(&mut app, code);
+        }
 
-        let selected = app
-            .apply_clone_host_picker_selection()
-            .expect("picker selection should exist");
-        assert_eq!(selected, "staging");
-        assert_eq!(app.clone_form_remote_host(), Some("staging"));
-        assert_eq!(app.clone_host_picker_open(), false);
+        assert_eq!(
+            app.form(CLONE_FORM)
+                .and_then(|form| form.choice("remote_host")),
+            Some("staging")
+        );
+        assert!(!app.overlays().is_visible(CLONE_HOST_PICKER));
+        assert!(app.is_clone_form_open());
     }
 
     #[test]
@@ -3559,11 +4722,30 @@ mod tests {
 
         app.open_clone_form();
 
-        let target = app
-            .clone_form_target_path()
-            .expect("clone target should be populated")
-            .to_string();
-        assert_eq!(target, "@ssh://devbox/home/alex/github/clone");
+        assert_eq!(
+            app.form(CLONE_FORM).map(|form| form.text("target_path")),
+            Some("@ssh://devbox/home/alex/github/clone")
+        );
+    }
+
+    #[test]
+    fn variant_diff_opens_over_the_owning_products_branch() {
+        use crate::variant_diff::DiffBase;
+
+        let mut app = App::new(".".to_string(), 5, Theme::default());
+        app.apply_snapshot(snapshot());
+        assert_eq!(
+            app.product_branch_for_variant("var_2").as_deref(),
+            Some("main")
+        );
+
+        app.open_variant_diff(VariantDiff {
+            variant_id: "var_1".to_string(),
+            base: DiffBase::Head,
+            base_label: "HEAD".to_string(),
+            files: Vec::new(),
+        });
+        assert_eq!(app.modal_overlay(), Some(VARIANT_DIFF_OVERLAY));
     }
 
     fn snapshot() -> DashboardSnapshot {
@@ -3576,6 +4758,90 @@ mod tests {
         }
     }
 
+    fn snapshot_with_sub_agent(status: &str) -> DashboardSnapshot {
+        let mut snapshot = snapshot();
+        snapshot.actors[0].sub_agents = vec![SubAgentRow {
+            id: "ses_child".to_string(),
+            parent_id: None,
+            title: "explore".to_string(),
+            status: status.to_string(),
+            summary: "-".to_string(),
+            updated_at: format!("unix:{status}"),
+            depth: 0,
+        }];
+        snapshot
+    }
+
+    fn sub_agent_selection() -> VizSelection {
+        VizSelection::SubAgent {
+            product_index: 0,
+            variant_id: "var_1".to_string(),
+            actor_id: "act_1".to_string(),
+            sub_agent_id: "ses_child".to_string(),
+        }
+    }
+
+    #[test]
+    fn catalog_nodes_list_sub_agents_after_their_actor() {
+        let mut app = App::new(".".to_string(), 5, Theme::default());
+        app.apply_snapshot(snapshot_with_sub_agent("busy"));
+
+        let nodes = app.catalog_nodes();
+        let actor_index = nodes
+            .iter()
+            .position(
+                |node| matches!(node, VizSelection::Actor { actor_id, .. } if actor_id == "act_1"),
+            )
+            .expect("actor node should exist");
+        assert_eq!(nodes.get(actor_index + 1), Some(&sub_agent_selection()));
+    }
+
+    #[test]
+    fn selecting_sub_agent_targets_its_transcript_and_blocks_compose() {
+        let mut app = App::new(".".to_string(), 5, Theme::default());
+        app.apply_snapshot(snapshot_with_sub_agent("busy"));
+        if !app.is_chat_visible() {
+            app.toggle_chat_visibility();
+        }
+
+        app.set_viz_selection(sub_agent_selection());
+        assert_eq!(
+            app.chat_actor().map(|actor| actor.id.as_str()),
+            Some("act_1")
+        );
+        assert_eq!(
+            app.take_chat_refresh_request().as_deref(),
+            Some("ses_child")
+        );
+        assert!(!app.open_chat_composer());
+
+        app.apply_snapshot(snapshot());
+        assert!(app.chat_sub_agent().is_none());
+        assert_eq!(
+            app.chat_actor().map(|actor| actor.id.as_str()),
+            Some("act_1")
+        );
+    }
+
+    #[test]
+    fn sub_agent_history_records_changes_and_forgets_removed_sub_agents() {
+        let mut app = App::new(".".to_string(), 5, Theme::default());
+        app.apply_snapshot(snapshot_with_sub_agent("busy"));
+        app.apply_snapshot(snapshot_with_sub_agent("busy"));
+        app.apply_snapshot(snapshot_with_sub_agent("idle"));
+
+        let statuses: Vec<&str> = app
+            .sub_agent_status_history("ses_child")
+            .iter()
+            .map(|change| change.status.as_str())
+            .collect();
+        assert_eq!(statuses, vec!["busy", "idle"]);
+        assert_eq!(app.sub_agent_status_history("ses_child")[1].at, "unix:idle");
+
+        app.apply_snapshot(snapshot());
+        assert!(app.sub_agent_status_history("ses_child").is_empty());
+    }
+
     fn product(id: &str) -> ProductRow {
         ProductRow {
             id: id.to_string(),
@@ -3595,6 +4861,306 @@ mod tests {
         }
     }
 
+    #[test]
+    fn marks_cover_ranges_and_filter_and_drop_rows_that_disappear() {
+        let mut app = App::new(".".to_string(), 5, Theme::default());
+        let mut fanout = snapshot();
+        fanout.actors.push(actor("act_2", "var_1"));
+        app.apply_snapshot(fanout.clone());
+
+        app.set_viz_selection(VizSelection::Product { product_index: 0 });
+        assert!(!app.toggle_mark_selected());
+        let added = app.mark_range_to(VizSelection::Actor {
+            product_index: 0,
+            variant_id: "var_1".to_string(),
+            actor_id: "act_2".to_string(),
+        });
+        assert_eq!(added, 3);
+        assert_eq!(app.marked_variant_ids(), vec!["var_1".to_string()]);
+        assert_eq!(app.marked_actors().len(), 2);
+
+        assert!(app.toggle_mark_selected());
+        assert!(!app.is_actor_marked("act_2"));
+
+        assert!(app.confirm_bulk_terminate().is_none());
+        assert_eq!(
+            app.confirm_bulk_terminate(),
+            Some(vec!["act_1".to_string()])
+        );
+
+        app.clear_marks();
+        app.toggle_variant_filter();
+        assert_eq!(app.mark_all_visible(), 4);
+
+        fanout.actors.retain(|actor| actor.id != "act_1");
+        app.apply_snapshot(fanout);
+        assert_eq!(app.marked_count(), 3);
+        assert!(!app.is_actor_marked("act_1"));
+    }
+
+    #[test]
+    fn catalog_search_filters_navigation_and_restores_on_cancel() {
+        let mut app = App::new(".".to_string(), 5, Theme::default());
+        let mut fanout = snapshot();
+        fanout.variants[1].branch = "feat/search".to_string();
+        fanout.actors.push(actor("act_2", "var_2"));
+        app.apply_snapshot(fanout.clone());
+        app.set_viz_selection(VizSelection::Product { product_index: 0 });
+
+        app.open_catalog_search();
+        for ch in "branch:feat/*".chars() {
+            press(&mut app, KeyCode::Char(ch));
+        }
+        assert_eq!(app.catalog_nodes().len(), 3);
+        assert_eq!(
+            app.viz_selection(),
+            Some(&VizSelection::Variant {
+                product_index: 1,
+                variant_id: "var_2".to_string(),
+            })
+        );
+        assert!(!app.is_catalog_match(&VizSelection::Product { product_index: 1 }));
+
+        press(&mut app, KeyCode::Char(' '));
+        press(&mut app, KeyCode::Char('"'));
+        assert!(app.catalog_search_error().is_some());
+        assert_eq!(app.catalog_nodes().len(), 3);
+
+        app.close_catalog_search();
+        fanout.actors.push(actor("act_3", "var_2"));
+        app.apply_snapshot(fanout);
+        assert_eq!(app.catalog_nodes().len(), 4);
+
+        app.open_catalog_search();
+        assert_eq!(app.catalog_search_draft(), "branch:feat/*");
+        let clear = KeyEvent::new(KeyCode::Char('u'), KeyModifiers::CONTROL);
+        app.overlay_event(&ComponentEvent::Key(clear))
+            .expect("search should take the key");
+        assert_eq!(app.catalog_nodes().len(), 7);
+        app.cancel_catalog_search();
+        assert_eq!(
+            app.catalog_query().map(|query| query.text()),
+            Some("branch:feat/*")
+        );
+    }
+
+    #[test]
+    fn chat_detail_popup_opens_on_rich_message_and_scrolls_until_closed() {
+        let mut app = App::new(".".to_string(), 5, Theme::default());
+        app.chat_messages = ["hello", "### Tool // bash", "done"]
+            .into_iter()
+            .map(|text| ActorChatMessageRow {
+                role: "assistant".to_string(),
+                text: text.to_string(),
+                created_at: String::new(),
+            })
+            .collect();
+
+        assert!(app.open_chat_detail_popup());
+        assert_eq!(
+            app.chat_detail_popup_message()
+                .map(|message| message.text.as_str()),
+            Some("### Tool // bash")
+        );
+        press(&mut app, KeyCode::Down);
+        press(&mut app, KeyCode::Down);
+        press(&mut app, KeyCode::Up);
+        assert_eq!(app.chat_detail_popup_scroll_lines(), 3);
+
+        press(&mut app, KeyCode::Esc);
+        assert!(!app.is_chat_detail_popup_open());
+        assert!(app.chat_detail_popup_message().is_none());
+    }
+
+    #[test]
+    fn core_logs_pane_takes_keys_while_focused() {
+        let mut app = App::new(".".to_string(), 5, Theme::default());
+        app.toggle_core_logs_visibility();
+        assert!(app.is_core_logs_focused());
+
+        for code in [KeyCode::Char('/'), KeyCode::Char('e'), KeyCode::Char('r')] {
+            press(&mut app, code);
+        }
+        press(&mut app, KeyCode::Enter);
+        let logs = app.core_logs().expect("core logs pane is mounted");
+        assert!(!logs.is_search_editing());
+        assert_eq!(logs.search(), "er");
+
+        let esc = Event::Key(KeyEvent::new(KeyCode::Esc, KeyModifiers::NONE));
+        let actions = app.overlay_event(&esc).expect("pane should take the key");
+        assert!(matches!(
+            actions[..],
+            [ref action] if action.downcast_ref() == Some(&CoreLogsRequest::Unfocus)
+        ));
+        app.unfocus_core_logs();
+        assert!(!app.is_core_logs_focused());
+        assert_eq!(app.overlays().base_focus(), Some(DASHBOARD));
+    }
+
+    #[test]
+    fn snapshot_transitions_feed_notification_history_and_jump() {
+        let mut app = App::new(".".to_string(), 5, Theme::default());
+        let mut fanout = snapshot();
+        app.apply_snapshot(fanout.clone());
+        assert!(app.take_pending_notifications().is_empty());
+
+        fanout.actors[0].status = "idle".to_string();
+        app.apply_snapshot(fanout);
+        assert_eq!(app.take_pending_notifications().len(), 1);
+        assert_eq!(app.notifications().unread(), 1);
+
+        app.open_notifications();
+        assert_eq!(app.notifications().unread(), 0);
+        assert!(app.is_notifications_open());
+
+        let enter = Event::Key(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE));
+        let outbound = app
+            .overlays_mut()
+            .handle_event(&enter)
+            .expect("enter handled");
+        let jump = outbound
+            .iter()
+            .find_map(|action| action.downcast_ref::<NotificationsRequest>());
+        assert_eq!(jump, Some(&NotificationsRequest::Jump("act_1".to_string())));
+        assert!(app.jump_to_notification_target("act_1"));
+        assert!(!app.is_notifications_open());
+        assert_eq!(app.selected_actor_id(), Some("act_1"));
+    }
+
+    #[test]
+    fn errors_outlive_later_status_messages_as_toasts() {
+        let mut app = App::new(".".to_string(), 5, Theme::default());
+        app.set_error("Clone failed: remote rejected");
+        app.set_status("Refreshed 3 products");
+
+        assert_eq!(app.status_message(), "Refreshed 3 products");
+        assert_eq!(app.toasts().active().len(), 1);
+        assert_eq!(
+            app.toasts().active()[0].message,
+            "Clone failed: remote rejected"
+        );
+
+        app.set_error("Spawn failed: provider offline");
+        app.open_message_log();
+        assert_eq!(app.modal_overlay(), Some(MESSAGE_LOG_OVERLAY));
+        app.clear_message_log();
+        assert_eq!(app.toasts().history().len(), 0);
+    }
+
+    #[test]
+    fn theme_file_changes_hot_reload_or_report_errors() {
+        let path = std::env::temp_dir().join(format!("dark_tui-theme-{}.toml", now_unix()));
+        let touch = |text: &str, offset: u64| {
+            std::fs::write(&path, text).expect("write theme");
+            std::fs::File::options()
+                .write(true)
+                .open(&path)
+                .and_then(|file| {
+                    file.set_modified(
+                        std::time::SystemTime::now() + std::time::Duration::from_secs(offset),
+                    )
+                })
+                .expect("touch theme");
+        };
+        touch("preset = \"dark\"\n", 0);
+
+        let mut app = App::new(".".to_string(), 5, Theme::default());
+        app.set_theme_watcher(Some(ThemeWatcher::new(&path)));
+        app.poll_theme();
+        assert_eq!(app.theme(), &Theme::default());
+
+        touch("colors = \"truecolor\"\n[text]\nprimary = [1, 2, 3]\n", 5);
+        app.poll_theme();
+        assert_eq!(
+            app.theme().text_primary,
+            ratatui::style::Color::Rgb(1, 2, 3)
+        );
+        assert_eq!(app.status_message(), "Theme reloaded");
+
+        touch("[text]\nprimary = 7\n", 10);
+        app.poll_theme();
+        assert_eq!(
+            app.theme().text_primary,
+            ratatui::style::Color::Rgb(1, 2, 3)
+        );
+        assert!(app.status_message().starts_with("Theme reload failed:"));
+        assert_eq!(app.toasts().active().len(), 1);
+
+        std::fs::remove_file(&path).expect("cleanup");
+    }
+
+    #[test]
+    fn command_history_load_reports_corrupt_files_instead_of_emptying_them() {
+        let directory =
+            std::env::temp_dir().join(format!("dark_tui-history-{}", std::process::id()));
+        fs::create_dir_all(&directory).expect("history dir");
+        let path = directory.join("darktui_history.json");
+        let _ = fs::remove_file(&path);
+        assert!(
+            load_command_history(&path)
+                .expect("missing file")
+                .is_empty()
+        );
+
+        fs::write(&path, "[\"refresh\", \"git\"]").expect("write history");
+        assert_eq!(
+            load_command_history(&path).expect("history"),
+            vec!["refresh".to_string(), "git".to_string()]
+        );
+
+        fs::write(&path, "{not json").expect("write corrupt history");
+        let error = load_command_history(&path).expect_err("corrupt history");
+        assert!(
+            error
+                .to_string()
+                .contains("Failed to decode command history")
+        );
+        assert_eq!(fs::read_to_string(&path).expect("read"), "{not json");
+        let _ = fs::remove_dir_all(&directory);
+    }
+
+    #[test]
+    fn layout_round_trips_through_disk_and_restores_selection() {
+        let directory = std::env::temp_dir().join(format!("dark_tui-layout-{}", now_unix()));
+        let directory = directory.to_string_lossy().to_string();
+
+        let mut app = App::new(directory.clone(), 5, Theme::default());
+        app.apply_snapshot(snapshot());
+        app.toggle_results_view_mode();
+        app.cycle_viz_density();
+        app.toggle_inspector_visibility();
+        assert!(app.body_split_without_chat_mut().set_percents(&[60, 40]));
+        let target = app
+            .catalog_nodes()
+            .into_iter()
+            .find(|node| matches!(node, VizSelection::Variant { variant_id, .. } if variant_id == "var_2"))
+            .expect("var_2 node");
+        app.set_viz_selection(target);
+        app.persist_layout().expect("persist layout");
+
+        let mut restored = App::new(directory.clone(), 5, Theme::default());
+        assert!(restored.restore_layout_from_disk().expect("restore layout"));
+        assert_eq!(restored.results_view_mode(), ResultsViewMode::Viz);
+        assert_eq!(restored.viz_density(), VizDensity::Wide);
+        assert!(!restored.is_inspector_visible());
+        assert_eq!(restored.body_split_without_chat().percents(), &[60, 40]);
+
+        restored.apply_snapshot(snapshot());
+        assert_eq!(
+            restored.selected_variant().map(|row| row.id.as_str()),
+            Some("var_2")
+        );
+
+        assert!(restored.reset_persisted_layout().expect("reset layout"));
+        let mut fresh = App::new(directory.clone(), 5, Theme::default());
+        assert!(
+            !fresh
+                .restore_layout_from_disk()
+                .expect("restore after reset")
+        );
+        let _ = fs::remove_dir_all(&directory);
+    }
+
     fn variant(id: &str, product_id: &str) -> VariantRow {
         VariantRow {
             id: id.to_string(),
@@ -1,4 +1,6 @@
-use clap::{ArgAction, Parser};
+use std::path::PathBuf;
+
+use clap::{ArgAction, Args, Parser, Subcommand, ValueEnum};
 
 #[derive(Debug, Parser)]
 #[command(name = "dark_tui", about = "Dark Factory TUI frontend")]
@@ -31,6 +33,28 @@ pub struct Cli {
     #[arg(long, env = "DARK_TUI_CHAT_MESSAGE_MAX_CHARS", default_value_t = 12000)]
     pub chat_message_max_chars: usize,
 
+    /// Keymap TOML; defaults to ~/.darkfactory/keymap.toml when present.
+    #[arg(long, env = "DARK_TUI_KEYMAP")]
+    pub keymap: Option<PathBuf>,
+
+    /// Theme preset (dark, light, high-contrast, solarized) or theme TOML path;
+    /// defaults to ~/.darkfactory/theme.toml when present. Files hot reload on save.
+    #[arg(long, env = "DARK_TUI_THEME")]
+    pub theme: Option<String>,
+
+    /// Notification rules TOML; defaults to ~/.darkfactory/notifications.toml when present.
+    #[arg(long, env = "DARK_TUI_NOTIFICATIONS")]
+    pub notifications: Option<PathBuf>,
+
+    /// dark_core log file for the core log viewer; defaults to .darkfactory/logs/dark_core.log
+    /// beside the dark_core workdir. A locally launched dark_core writes here.
+    #[arg(long, env = "DARK_TUI_CORE_LOG_FILE")]
+    pub core_log_file: Option<PathBuf>,
+
+    /// Ignore and delete the saved per-directory layout (.darkfactory/darktui_layout.toml).
+    #[arg(long, env = "DARK_TUI_RESET_LAYOUT")]
+    pub reset_layout: bool,
+
     #[arg(
         long,
         env = "DARK_TUI_POLL_VARIANTS",
@@ -38,4 +62,60 @@ pub struct Cli {
         action = ArgAction::Set
     )]
     pub poll_variants: bool,
+
+    #[command(subcommand)]
+    pub command: Option<Command>,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum Command {
+    /// Render a view headlessly to text, ANSI, SVG or HTML instead of starting the TUI.
+    Snapshot(SnapshotArgs),
+}
+
+#[derive(Debug, Args)]
+pub struct SnapshotArgs {
+    /// JSON `DashboardSnapshot` to render instead of fetching from dark_core.
+    #[arg(long)]
+    pub fixture: Option<PathBuf>,
+
+    #[arg(long, value_enum, default_value_t = SnapshotView::Dashboard)]
+    pub view: SnapshotView,
+
+    /// `json` writes the fetched state as a fixture for later `--fixture` runs.
+    #[arg(long, value_enum, default_value_t = SnapshotFormat::Text)]
+    pub format: SnapshotFormat,
+
+    #[arg(long, default_value_t = 160)]
+    pub width: u16,
+
+    #[arg(long, default_value_t = 48)]
+    pub height: u16,
+
+    /// Output file; defaults to stdout.
+    #[arg(long, short)]
+    pub output: Option<PathBuf>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum SnapshotView {
+    /// Full dashboard with the catalog tree.
+    Dashboard,
+    /// Full dashboard with the spatial catalog.
+    Viz,
+    /// Catalog tree only.
+    Tree,
+    /// Spatial catalog only.
+    Catalog,
+    /// Details panel for the selected product.
+    Details,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum SnapshotFormat {
+    Text,
+    Ansi,
+    Svg,
+    Html,
+    Json,
 }
@@ -0,0 +1,669 @@
+use std::collections::{BTreeSet, VecDeque};
+use std::fs::File;
+use std::io::{self, Read, Seek, SeekFrom};
+use std::path::{Path, PathBuf};
+
+/// Entries kept in memory; older lines fall off the front.
+pub const CORE_LOG_CAPACITY: usize = 5_000;
+/// On first open only this much of an existing log file is loaded.
+const INITIAL_TAIL_BYTES: u64 = 256 * 1024;
+const ENTITY_ID_PREFIXES: [&str; 3] = ["prd_", "var_", "act_"];
+/// Shortest id body accepted after a prefix, so prose like `var_x` is ignored.
+const MIN_ENTITY_ID_BODY: usize = 4;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum LogLevel {
+    Debug,
+    Info,
+    Warn,
+    Error,
+}
+
+impl LogLevel {
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Debug => "DEBUG",
+            Self::Info => "INFO",
+            Self::Warn => "WARN",
+            Self::Error => "ERROR",
+        }
+    }
+
+    /// Accepts the dark_core file sink levels and adze's console labels.
+    fn parse(token: &str) -> Option<Self> {
+        let token = token
+            .trim_matches(|ch: char| !ch.is_ascii_alphanumeric())
+            .to_ascii_lowercase();
+        match token.as_str() {
+            "debug" | "verbose" | "trace" => Some(Self::Debug),
+            "info" | "log" | "success" => Some(Self::Info),
+            "warn" | "warning" => Some(Self::Warn),
+            "error" | "fail" | "fatal" | "alert" => Some(Self::Error),
+            _ => None,
+        }
+    }
+
+    /// Next minimum level for the viewer's level filter; `None` shows everything.
+    fn cycle_filter(filter: Option<Self>) -> Option<Self> {
+        match filter {
+            None => Some(Self::Info),
+            Some(Self::Debug | Self::Info) => Some(Self::Warn),
+            Some(Self::Warn) => Some(Self::Error),
+            Some(Self::Error) => None,
+        }
+    }
+}
+
+/// One parsed dark_core log line.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LogEntry {
+    pub seq: u64,
+    pub timestamp: Option<String>,
+    pub level: Option<LogLevel>,
+    /// Subsystem from the `Core // Module // event` message convention.
+    pub module: Option<String>,
+    pub message: String,
+    /// `prd_`/`var_`/`act_` ids mentioned in the message, in order of appearance.
+    pub entity_ids: Vec<String>,
+}
+
+impl LogEntry {
+    /// `HH:MM:SS` part of an ISO timestamp.
+    pub fn short_time(&self) -> Option<&str> {
+        self.timestamp
+            .as_deref()
+            .map(|value| value.get(11..19).unwrap_or(value))
+    }
+}
+
+/// Parses a line written by the dark_core file sink (`<iso> <LEVEL> <message>`).
+/// Raw console output (ANSI colors, adze icons, no timestamp) is accepted too.
+pub fn parse_log_line(line: &str) -> LogEntry {
+    let clean = strip_ansi(line);
+    let mut rest = clean.trim();
+
+    let mut timestamp = None;
+    let (first, after) = split_first_token(rest);
+    if looks_like_timestamp(first) {
+        timestamp = Some(first.to_string());
+        rest = after;
+    }
+
+    let mut level = None;
+    let (first, after) = split_first_token(rest);
+    if let Some(parsed) = LogLevel::parse(first) {
+        level = Some(parsed);
+        rest = after;
+    } else if !first.chars().any(char::is_alphanumeric) {
+        // adze prefixes console lines with an icon before the level label.
+        let (second, after_second) = split_first_token(after);
+        if let Some(parsed) = LogLevel::parse(second) {
+            level = Some(parsed);
+            rest = after_second;
+        }
+    }
+
+    let message = rest.to_string();
+    LogEntry {
+        seq: 0,
+        timestamp,
+        level,
+        module: module_of(&message),
+        entity_ids: entity_ids_in(&message),
+        message,
+    }
+}
+
+fn split_first_token(text: &str) -> (&str, &str) {
+    let text = text.trim_start();
+    match text.split_once(char::is_whitespace) {
+        Some((token, rest)) => (token, rest.trim_start()),
+        None => (text, ""),
+    }
+}
+
+fn looks_like_timestamp(token: &str) -> bool {
+    let bytes = token.as_bytes();
+    bytes.len() >= 19
+        && bytes[..4].iter().all(u8::is_ascii_digit)
+        && bytes[4] == b'-'
+        && bytes[7] == b'-'
+        && matches!(bytes[10], b'T' | b' ')
+        && bytes[13] == b':'
+}
+
+fn module_of(message: &str) -> Option<String> {
+    let segments: Vec<&str> = message.split(" // ").map(str::trim).collect();
+    let module = match segments.as_slice() {
+        ["Core", module, _, ..] => *module,
+        [module, _, ..] => *module,
+        _ => return None,
+    };
+    (!module.is_empty()).then(|| module.to_string())
+}
+
+fn entity_ids_in(message: &str) -> Vec<String> {
+    let mut ids: Vec<String> = Vec::new();
+    for prefix in ENTITY_ID_PREFIXES {
+        for (start, _) in message.match_indices(prefix) {
+            let boundary = message[..start]
+                .chars()
+                .next_back()
+                .is_none_or(|ch| !ch.is_ascii_alphanumeric() && ch != '_');
+            let body_len = message[start + prefix.len()..]
+                .chars()
+                .take_while(char::is_ascii_alphanumeric)
+                .count();
+            if boundary && body_len >= MIN_ENTITY_ID_BODY {
+                ids.push(message[start..start + prefix.len() + body_len].to_string());
+            }
+        }
+    }
+
+    ids.sort_by_key(|id| message.find(id.as_str()));
+    ids.dedup();
+    ids
+}
+
+/// Drops ANSI escape sequences and control characters other than tabs.
+fn strip_ansi(line: &str) -> String {
+    let mut out = String::with_capacity(line.len());
+    let mut chars = line.chars().peekable();
+    while let Some(ch) = chars.next() {
+        if ch != '\u{1b}' {
+            if !ch.is_control() || ch == '\t' {
+                out.push(ch);
+            }
+            continue;
+        }
+
+        match chars.next() {
+            Some('[') => {
+                for next in chars.by_ref() {
+                    if ('@'..='~').contains(&next) {
+                        break;
+                    }
+                }
+            }
+            Some(']') => {
+                while let Some(next) = chars.next() {
+                    if next == '\u{7}' || (next == '\u{1b}' && chars.next_if_eq(&'\\').is_some()) {
+                        break;
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+    out
+}
+
+/// Incrementally reads lines appended to a log file.
+#[derive(Debug)]
+pub struct LogFileTail {
+    path: PathBuf,
+    offset: Option<u64>,
+    partial: Vec<u8>,
+}
+
+impl LogFileTail {
+    pub fn new(path: PathBuf) -> Self {
+        Self {
+            path,
+            offset: None,
+            partial: Vec::new(),
+        }
+    }
+
+    /// Complete lines appended since the previous read. The first read only
+    /// loads the end of the file; a truncated or rotated file restarts at 0.
+    pub fn read_new_lines(&mut self) -> io::Result<Vec<String>> {
+        let mut file = File::open(&self.path)?;
+        let len = file.metadata()?.len();
+        let start = match self.offset {
+            None => len.saturating_sub(INITIAL_TAIL_BYTES),
+            Some(offset) if offset > len => {
+                self.partial.clear();
+                0
+            }
+            Some(offset) => offset,
+        };
+        let skip_partial_first_line = self.offset.is_none() && start > 0;
+
+        file.seek(SeekFrom::Start(start))?;
+        let mut bytes = Vec::new();
+        file.take(len - start).read_to_end(&mut bytes)?;
+        self.offset = Some(start + bytes.len() as u64);
+
+        let mut data = std::mem::take(&mut self.partial);
+        data.extend_from_slice(&bytes);
+        let mut parts: Vec<&[u8]> = data.split(|byte| *byte == b'\n').collect();
+        self.partial = parts.pop().unwrap_or_default().to_vec();
+        if skip_partial_first_line && !parts.is_empty() {
+            parts.remove(0);
+        }
+
+        Ok(parts
+            .into_iter()
+            .map(|line| {
+                String::from_utf8_lossy(line)
+                    .trim_end_matches('\r')
+                    .to_string()
+            })
+            .collect())
+    }
+}
+
+/// Buffered entries plus the core log viewer's filter, search and scroll state.
+#[derive(Debug)]
+pub struct CoreLogView {
+    path: Option<PathBuf>,
+    status: String,
+    entries: VecDeque<LogEntry>,
+    next_seq: u64,
+    modules: BTreeSet<String>,
+    min_level: Option<LogLevel>,
+    module_filter: Option<String>,
+    search: String,
+    search_editing: bool,
+    /// `None` follows the newest line; otherwise the bottom row is pinned to this entry.
+    anchor: Option<u64>,
+    unseen_while_paused: usize,
+}
+
+impl CoreLogView {
+    pub fn new(path: Option<PathBuf>) -> Self {
+        let status = if path.is_some() {
+            "idle"
+        } else {
+            "no log file"
+        };
+        Self {
+            path,
+            status: status.to_string(),
+            entries: VecDeque::new(),
+            next_seq: 0,
+            modules: BTreeSet::new(),
+            min_level: None,
+            module_filter: None,
+            search: String::new(),
+            search_editing: false,
+            anchor: None,
+            unseen_while_paused: 0,
+        }
+    }
+
+    pub fn path(&self) -> Option<&Path> {
+        self.path.as_deref()
+    }
+
+    pub fn status(&self) -> &str {
+        &self.status
+    }
+
+    pub fn set_status(&mut self, status: impl Into<String>) {
+        self.status = status.into();
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Parses and appends raw lines. Lines without timestamp or level (stack
+    /// traces, wrapped output) inherit the previous entry's level and module.
+    pub fn ingest(&mut self, lines: Vec<String>) {
+        for line in lines {
+            if line.trim().is_empty() {
+                continue;
+            }
+            let mut entry = parse_log_line(&line);
+            let continuation =
+                entry.timestamp.is_none() && entry.level.is_none() && entry.module.is_none();
+            if let Some(previous) = self.entries.back().filter(|_| continuation) {
+                entry.level = previous.level;
+                entry.module = previous.module.clone();
+            }
+            entry.seq = self.next_seq;
+            self.next_seq += 1;
+
+            if let Some(module) = entry.module.as_ref() {
+                self.modules.insert(module.clone());
+            }
+            if self.anchor.is_some() && self.is_visible(&entry) {
+                self.unseen_while_paused += 1;
+            }
+            self.entries.push_back(entry);
+        }
+
+        while self.entries.len() > CORE_LOG_CAPACITY {
+            self.entries.pop_front();
+        }
+    }
+
+    pub fn clear(&mut self) {
+        self.entries.clear();
+        self.anchor = None;
+        self.unseen_while_paused = 0;
+    }
+
+    pub fn is_following(&self) -> bool {
+        self.anchor.is_none()
+    }
+
+    /// Visible lines that arrived since the view was paused.
+    pub fn unseen_while_paused(&self) -> usize {
+        self.unseen_while_paused
+    }
+
+    pub fn toggle_follow(&mut self) {
+        if self.anchor.is_some() {
+            self.follow();
+        } else {
+            self.anchor = self.selected().map(|entry| entry.seq);
+        }
+    }
+
+    pub fn follow(&mut self) {
+        self.anchor = None;
+        self.unseen_while_paused = 0;
+    }
+
+    pub fn min_level(&self) -> Option<LogLevel> {
+        self.min_level
+    }
+
+    pub fn cycle_level_filter(&mut self) {
+        self.min_level = LogLevel::cycle_filter(self.min_level);
+    }
+
+    pub fn module_filter(&self) -> Option<&str> {
+        self.module_filter.as_deref()
+    }
+
+    /// Steps through every module seen so far, then back to all modules.
+    pub fn cycle_module_filter(&mut self) {
+        self.module_filter = match self.module_filter.as_ref() {
+            None => self.modules.iter().next().cloned(),
+            Some(current) => self
+                .modules
+                .range::<String, _>((
+                    std::ops::Bound::Excluded(current),
+                    std::ops::Bound::Unbounded,
+                ))
+                .next()
+                .cloned(),
+        };
+    }
+
+    pub fn search(&self) -> &str {
+        &self.search
+    }
+
+    pub fn is_search_editing(&self) -> bool {
+        self.search_editing
+    }
+
+    pub fn begin_search(&mut self) {
+        self.search.clear();
+        self.search_editing = true;
+    }
+
+    pub fn search_insert_char(&mut self, ch: char) {
+        self.search.push(ch);
+        self.seek_match(true, true);
+    }
+
+    pub fn search_backspace(&mut self) {
+        self.search.pop();
+        self.seek_match(true, true);
+    }
+
+    pub fn finish_search(&mut self) {
+        self.search_editing = false;
+    }
+
+    pub fn cancel_search(&mut self) {
+        self.search.clear();
+        self.search_editing = false;
+    }
+
+    pub fn is_visible(&self, entry: &LogEntry) -> bool {
+        let level_ok = match self.min_level {
+            None => true,
+            Some(min) => entry.level.is_some_and(|level| level >= min),
+        };
+        let module_ok = self
+            .module_filter
+            .as_ref()
+            .is_none_or(|module| entry.module.as_ref() == Some(module));
+        level_ok && module_ok
+    }
+
+    pub fn is_search_match(&self, entry: &LogEntry) -> bool {
+        !self.search.is_empty()
+            && entry
+                .message
+                .to_lowercase()
+                .contains(&self.search.to_lowercase())
+    }
+
+    pub fn visible_entries(&self) -> Vec<&LogEntry> {
+        self.entries
+            .iter()
+            .filter(|entry| self.is_visible(entry))
+            .collect()
+    }
+
+    /// Up to `rows` visible entries ending at the selected line.
+    pub fn window(&self, rows: usize) -> Vec<&LogEntry> {
+        let visible = self.visible_entries();
+        let end = self.selected_index(&visible).map_or(0, |index| index + 1);
+        visible[end.saturating_sub(rows)..end].to_vec()
+    }
+
+    /// The bottom row of the view: the newest line while following, else the pinned one.
+    pub fn selected(&self) -> Option<&LogEntry> {
+        let visible = self.visible_entries();
+        self.selected_index(&visible).map(|index| visible[index])
+    }
+
+    fn selected_index(&self, visible: &[&LogEntry]) -> Option<usize> {
+        let last = visible.len().checked_sub(1)?;
+        match self.anchor {
+            None => Some(last),
+            Some(anchor) => Some(
+                visible
+                    .iter()
+                    .rposition(|entry| entry.seq <= anchor)
+                    .unwrap_or(0),
+            ),
+        }
+    }
+
+    /// Moves the selection by `delta` lines (negative is older) and pauses following.
+    pub fn scroll(&mut self, delta: isize) {
+        let visible = self.visible_entries();
+        let Some(index) = self.selected_index(&visible) else {
+            return;
+        };
+        let target = index
+            .saturating_add_signed(delta)
+            .min(visible.len().saturating_sub(1));
+        let seq = visible[target].seq;
+        self.pin(seq);
+    }
+
+    pub fn scroll_to_top(&mut self) {
+        if let Some(seq) = self.visible_entries().first().map(|entry| entry.seq) {
+            self.pin(seq);
+        }
+    }
+
+    /// Selects the nearest older (or newer) search match; false when there is none.
+    pub fn jump_to_match(&mut self, older: bool) -> bool {
+        self.seek_match(older, false)
+    }
+
+    fn seek_match(&mut self, older: bool, include_selected: bool) -> bool {
+        if self.search.is_empty() {
+            return false;
+        }
+        let visible = self.visible_entries();
+        let Some(index) = self.selected_index(&visible) else {
+            return false;
+        };
+
+        let found = if older {
+            let end = if include_selected { index + 1 } else { index };
+            visible[..end]
+                .iter()
+                .rev()
+                .find(|entry| self.is_search_match(entry))
+        } else {
+            visible[index + 1..]
+                .iter()
+                .find(|entry| self.is_search_match(entry))
+        }
+        .or_else(|| {
+            // Incremental search falls back to the newest match anywhere.
+            include_selected
+                .then(|| {
+                    visible
+                        .iter()
+                        .rev()
+                        .find(|entry| self.is_search_match(entry))
+                })
+                .flatten()
+        })
+        .map(|entry| entry.seq);
+
+        match found {
+            Some(seq) => {
+                self.pin(seq);
+                true
+            }
+            None => false,
+        }
+    }
+
+    fn pin(&mut self, seq: u64) {
+        if self.anchor.is_none() {
+            self.unseen_while_paused = 0;
+        }
+        self.anchor = Some(seq);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs::{self, OpenOptions};
+    use std::io::Write;
+    use std::time::{SystemTime, UNIX_EPOCH};
+
+    use super::{CoreLogView, LogFileTail, LogLevel, parse_log_line};
+
+    #[test]
+    fn parses_file_sink_and_console_lines() {
+        let entry = parse_log_line(
+            "2026-01-02T03:04:05.006Z WARN Core // Actors Route // Poll failed (meta={\"actorId\":\"act_00000000abcde\",\"variantId\":\"var_0000000012345\"})",
+        );
+        assert_eq!(entry.level, Some(LogLevel::Warn));
+        assert_eq!(entry.short_time(), Some("03:04:05"));
+        assert_eq!(entry.module.as_deref(), Some("Actors Route"));
+        assert_eq!(
+            entry.entity_ids,
+            vec!["act_00000000abcde", "var_0000000012345"]
+        );
+
+        let console = parse_log_line(
+            "\u{1b}[31m\u{2716} Error\u{1b}[0m Config // CLI // Unknown subcommand var_x",
+        );
+        assert_eq!(console.level, Some(LogLevel::Error));
+        assert_eq!(console.timestamp, None);
+        assert_eq!(console.module.as_deref(), Some("Config"));
+        assert!(console.entity_ids.is_empty());
+        assert_eq!(console.message, "Config // CLI // Unknown subcommand var_x");
+    }
+
+    #[test]
+    fn view_filters_searches_and_pauses() {
+        let mut view = CoreLogView::new(None);
+        view.ingest(vec![
+            "2026-01-01T00:00:00.000Z INFO Core // Route // Products list started".to_string(),
+            "2026-01-01T00:00:01.000Z ERROR Core // Actors Route // Spawn failed".to_string(),
+            "    at spawn (actors.ts:10)".to_string(),
+            "2026-01-01T00:00:02.000Z DEBUG Core // Variants Controller // Getting variant"
+                .to_string(),
+        ]);
+
+        assert_eq!(view.len(), 4);
+        assert_eq!(view.visible_entries()[2].level, Some(LogLevel::Error));
+
+        view.cycle_level_filter();
+        view.cycle_level_filter();
+        assert_eq!(view.min_level(), Some(LogLevel::Warn));
+        assert_eq!(view.visible_entries().len(), 2);
+        view.cycle_level_filter();
+        view.cycle_level_filter();
+        assert_eq!(view.min_level(), None);
+
+        view.cycle_module_filter();
+        assert_eq!(view.module_filter(), Some("Actors Route"));
+        assert_eq!(view.visible_entries().len(), 2);
+        view.cycle_module_filter();
+        view.cycle_module_filter();
+        view.cycle_module_filter();
+        assert_eq!(view.module_filter(), None);
+
+        view.begin_search();
+        for ch in "products".chars() {
+            view.search_insert_char(ch);
+        }
+        assert!(!view.is_following());
+        assert_eq!(view.selected().map(|entry| entry.seq), Some(0));
+        assert_eq!(view.window(10).len(), 1);
+
+        view.ingest(vec![
+            "2026-01-01T00:00:03.000Z INFO Core // Route // Products list started".to_string(),
+        ]);
+        assert_eq!(view.unseen_while_paused(), 1);
+        assert!(view.jump_to_match(false));
+        assert_eq!(view.selected().map(|entry| entry.seq), Some(4));
+        assert!(!view.jump_to_match(false));
+
+        view.follow();
+        view.scroll(-2);
+        assert_eq!(view.selected().map(|entry| entry.seq), Some(2));
+    }
+
+    #[test]
+    fn tail_reads_appended_lines_and_restarts_after_truncation() {
+        let unique = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|elapsed| elapsed.as_nanos())
+            .unwrap_or_default();
+        let dir = std::env::temp_dir().join(format!("dark-tui-core-logs-{unique}"));
+        fs::create_dir_all(&dir).expect("create temp dir");
+        let path = dir.join("dark_core.log");
+        fs::write(&path, "first\nsec").expect("write log");
+
+        let mut tail = LogFileTail::new(path.clone());
+        assert_eq!(tail.read_new_lines().expect("read"), vec!["first"]);
+
+        let mut file = OpenOptions::new()
+            .append(true)
+            .open(&path)
+            .expect("open log");
+        file.write_all(b"ond\r\nthird\n").expect("append log");
+        assert_eq!(
+            tail.read_new_lines().expect("read"),
+            vec!["second", "third"]
+        );
+        assert!(tail.read_new_lines().expect("read").is_empty());
+
+        fs::write(&path, "fresh\n").expect("truncate log");
+        assert_eq!(tail.read_new_lines().expect("read"), vec!["fresh"]);
+
+        fs::remove_dir_all(&dir).ok();
+    }
+}
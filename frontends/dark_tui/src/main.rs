@@ -1,12 +1,18 @@
 mod app;
 mod cli;
+mod core_logs;
 mod logging;
 mod models;
+mod notify;
 mod service;
 mod service_convert;
 mod service_wire;
+mod system_metrics;
+mod terminal_pane;
 pub(crate) mod theme;
 mod ui;
+mod variant_diff;
+mod variant_git;
 
 use std::env;
 use std::path::PathBuf;
@@ -18,14 +24,18 @@ use dark_rust::{
     is_local_dark_core_url,
 };
 
-use crate::cli::Cli;
+use crate::cli::{Cli, Command};
 
 #[tokio::main]
 async fn main() -> Result<()> {
     let cli = Cli::parse();
+    if let Some(Command::Snapshot(args)) = &cli.command {
+        return ui::snapshot::run(&cli, args).await;
+    }
+
     let manage_local_dark_core = should_manage_local_dark_core();
     let core_runtime_hint = if manage_local_dark_core && is_local_dark_core_url(&cli.base_url) {
-        let launch_config = compiled_launch_config();
+        let launch_config = compiled_launch_config(&cli);
         let launch_state = ensure_dark_core_in_tmux_if_needed(&cli.base_url, launch_config).await?;
         match launch_state {
             EnsureDarkCoreState::AlreadyRunning => "core:running".to_string(),
@@ -42,9 +52,10 @@ async fn main() -> Result<()> {
     ui::run(cli, core_runtime_hint).await
 }
 
-fn compiled_launch_config() -> DarkCoreLaunchConfig {
+fn compiled_launch_config(cli: &Cli) -> DarkCoreLaunchConfig {
     let mut config = DarkCoreLaunchConfig::default();
     config.restart_existing_session = false;
+    config.log_file = cli.core_log_file.clone();
 
     if let Some(path) = option_env!("DARKFACTORY_DARK_CORE_EXECUTABLE") {
         config.executable_path = Some(PathBuf::from(path));
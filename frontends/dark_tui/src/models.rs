@@ -1,4 +1,9 @@
-#[derive(Debug, Clone, Default)]
+use serde::{Deserialize, Serialize};
+
+/// Catalog state rendered by the dashboard; also the JSON fixture format of
+/// `dark_tui snapshot --fixture`.
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[serde(default)]
 pub struct DashboardSnapshot {
     pub products: Vec<ProductRow>,
     pub variants: Vec<VariantRow>,
@@ -7,7 +12,8 @@ pub struct DashboardSnapshot {
     pub last_updated: String,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[serde(default)]
 pub struct ProductRow {
     pub id: String,
     pub display_name: String,
@@ -25,7 +31,8 @@ pub struct ProductRow {
     pub variant_drift: usize,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[serde(default)]
 pub struct VariantRow {
     pub id: String,
     pub product_id: String,
@@ -44,7 +51,8 @@ pub struct VariantRow {
     pub updated_at: String,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[serde(default)]
 pub struct ActorRow {
     pub id: String,
     pub variant_id: String,
@@ -60,31 +68,53 @@ pub struct ActorRow {
     pub updated_at: String,
 }
 
+/// Most sub-agents listed per actor in the catalog and inspector.
+pub const MAX_SUB_AGENT_ROWS: usize = 12;
+
 impl ActorRow {
     /// Returns the total number of flattened sub-agent entries.
     pub fn sub_agent_count(&self) -> usize {
         self.sub_agents.len()
     }
+
+    /// The last [`MAX_SUB_AGENT_ROWS`] sub-agents, in tree order.
+    pub fn recent_sub_agents(&self) -> &[SubAgentRow] {
+        let start = self.sub_agents.len().saturating_sub(MAX_SUB_AGENT_ROWS);
+        &self.sub_agents[start..]
+    }
+
+    pub fn sub_agent(&self, sub_agent_id: &str) -> Option<&SubAgentRow> {
+        self.sub_agents
+            .iter()
+            .find(|sub_agent| sub_agent.id == sub_agent_id)
+    }
 }
 
 /// A flattened sub-agent entry derived from recursive wire `subAgents` trees.
 ///
 /// Depth tracks nesting level: 0 = top-level sub-agent, 1 = child of a
-/// sub-agent, etc. Non-interactive and read-only in the TUI.
-#[derive(Debug, Clone)]
+/// sub-agent, etc. The id is the sub-agent's own provider session, so its
+/// transcript can be opened in chat and its run aborted.
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[serde(default)]
 pub struct SubAgentRow {
-    #[allow(dead_code)]
     pub id: String,
-    #[allow(dead_code)]
     pub parent_id: Option<String>,
     pub title: String,
     pub status: String,
     pub summary: String,
-    #[allow(dead_code)]
     pub updated_at: String,
     pub depth: usize,
 }
 
+/// A sub-agent status observed while the TUI was running.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SubAgentStatusChange {
+    pub status: String,
+    /// Provider `updatedAt` when the change was seen, else `-`.
+    pub at: String,
+}
+
 #[derive(Debug, Clone)]
 pub struct ActorChatMessageRow {
     pub role: String,
@@ -0,0 +1,582 @@
+use std::collections::{HashMap, VecDeque};
+use std::fs;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+
+use anyhow::{Context, Result, bail};
+use serde::Deserialize;
+
+use crate::app::status_matches;
+use crate::models::{ActorRow, VariantRow};
+
+const HISTORY_LIMIT: usize = 200;
+const EVENT_KINDS: [&str; 3] = ["actor.status", "clone.ready", "clone.failed"];
+
+/// Desktop notification escape sequence understood by the host terminal.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DesktopProtocol {
+    Off,
+    /// `OSC 9` (iTerm2, WezTerm, Windows Terminal, kitty).
+    Osc9,
+    /// `OSC 777;notify` (rxvt-unicode, foot, Ghostty, VTE terminals).
+    Osc777,
+}
+
+/// One `[[rule]]` entry: which events notify, and through which channels.
+///
+/// Unset channels fall back to the file-wide `bell`/`desktop`/`command`.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct NotificationRule {
+    pub event: String,
+    /// Status patterns the actor left / entered; empty matches any status.
+    #[serde(default)]
+    pub from: Vec<String>,
+    #[serde(default)]
+    pub to: Vec<String>,
+    pub bell: Option<bool>,
+    pub desktop: Option<bool>,
+    pub command: Option<bool>,
+}
+
+impl NotificationRule {
+    fn new(event: &str, from: &[&str], to: &[&str]) -> Self {
+        Self {
+            event: event.to_string(),
+            from: from.iter().map(|value| value.to_string()).collect(),
+            to: to.iter().map(|value| value.to_string()).collect(),
+            bell: None,
+            desktop: None,
+            command: None,
+        }
+    }
+
+    fn matches(&self, event: &NotificationEvent) -> bool {
+        if self.event != event.kind() {
+            return false;
+        }
+
+        let NotificationEvent::ActorStatus { from, to, .. } = event else {
+            return true;
+        };
+        let any = |patterns: &[String], status: &str| {
+            patterns.is_empty()
+                || patterns
+                    .iter()
+                    .any(|pattern| status_matches(&pattern.to_ascii_lowercase(), status))
+        };
+        any(&self.from, from) && any(&self.to, to)
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct NotificationFile {
+    bell: Option<bool>,
+    desktop: Option<DesktopProtocol>,
+    command: Option<String>,
+    #[serde(default, rename = "rule")]
+    rules: Vec<NotificationRule>,
+}
+
+/// Channel defaults plus the rules deciding which events notify.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NotificationConfig {
+    pub bell: bool,
+    pub desktop: DesktopProtocol,
+    /// Shell command run for each notification, with `DARK_NOTIFY_*` env vars.
+    pub command: Option<String>,
+    pub rules: Vec<NotificationRule>,
+}
+
+impl Default for NotificationConfig {
+    fn default() -> Self {
+        Self {
+            bell: true,
+            desktop: DesktopProtocol::Off,
+            command: None,
+            rules: vec![
+                NotificationRule::new("actor.status", &["busy"], &["idle"]),
+                NotificationRule::new("actor.status", &[], &["error", "failed", "dead"]),
+                NotificationRule::new("clone.ready", &[], &[]),
+                NotificationRule::new("clone.failed", &[], &[]),
+            ],
+        }
+    }
+}
+
+impl NotificationConfig {
+    /// Resolves the channels of the first rule matching `event`.
+    fn route(&self, event: &NotificationEvent) -> Option<Channels> {
+        let rule = self.rules.iter().find(|rule| rule.matches(event))?;
+        Some(Channels {
+            bell: rule.bell.unwrap_or(self.bell),
+            desktop: match rule.desktop {
+                Some(false) => DesktopProtocol::Off,
+                Some(true) if self.desktop == DesktopProtocol::Off => DesktopProtocol::Osc9,
+                _ => self.desktop,
+            },
+            command: self
+                .command
+                .clone()
+                .filter(|_| rule.command.unwrap_or(true)),
+        })
+    }
+}
+
+/// `$HOME/.darkfactory/notifications.toml`.
+pub(crate) fn default_notifications_path() -> Option<PathBuf> {
+    std::env::var_os("HOME").map(|home| {
+        PathBuf::from(home)
+            .join(".darkfactory")
+            .join("notifications.toml")
+    })
+}
+
+/// Loads notification rules; a missing default file yields the defaults.
+pub(crate) fn load_notification_config(explicit: Option<&Path>) -> Result<NotificationConfig> {
+    let path = match explicit {
+        Some(path) => path.to_path_buf(),
+        None => match default_notifications_path() {
+            Some(path) if path.exists() => path,
+            _ => return Ok(NotificationConfig::default()),
+        },
+    };
+
+    let text = fs::read_to_string(&path).with_context(|| {
+        format!(
+            "Dark TUI // Notifications // Failed to read {}",
+            path.display()
+        )
+    })?;
+    parse_notification_config(&text)
+        .with_context(|| format!("Dark TUI // Notifications // Invalid {}", path.display()))
+}
+
+/// Parses a rules file; without `[[rule]]` entries the default rules apply.
+pub(crate) fn parse_notification_config(text: &str) -> Result<NotificationConfig> {
+    let file: NotificationFile = toml::from_str(text)?;
+    let defaults = NotificationConfig::default();
+
+    for rule in &file.rules {
+        if !EVENT_KINDS.contains(&rule.event.as_str()) {
+            bail!(
+                "unknown event `{}` (expected one of {})",
+                rule.event,
+                EVENT_KINDS.join(", ")
+            );
+        }
+        if rule.event != "actor.status" && !(rule.from.is_empty() && rule.to.is_empty()) {
+            bail!("`from`/`to` only apply to `actor.status` rules");
+        }
+    }
+
+    Ok(NotificationConfig {
+        bell: file.bell.unwrap_or(defaults.bell),
+        desktop: file.desktop.unwrap_or(defaults.desktop),
+        command: file
+            .command
+            .map(|command| command.trim().to_string())
+            .filter(|command| !command.is_empty()),
+        rules: if file.rules.is_empty() {
+            defaults.rules
+        } else {
+            file.rules
+        },
+    })
+}
+
+/// State change between two catalog snapshots worth telling the user about.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum NotificationEvent {
+    ActorStatus {
+        actor_id: String,
+        title: String,
+        from: String,
+        to: String,
+    },
+    CloneFinished {
+        variant_id: String,
+        name: String,
+        failed: bool,
+        last_line: String,
+    },
+}
+
+impl NotificationEvent {
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Self::ActorStatus { .. } => "actor.status",
+            Self::CloneFinished { failed: false, .. } => "clone.ready",
+            Self::CloneFinished { failed: true, .. } => "clone.failed",
+        }
+    }
+
+    pub fn target_id(&self) -> &str {
+        match self {
+            Self::ActorStatus { actor_id, .. } => actor_id,
+            Self::CloneFinished { variant_id, .. } => variant_id,
+        }
+    }
+
+    pub fn is_failure(&self) -> bool {
+        match self {
+            Self::ActorStatus { to, .. } => {
+                matches!(to.as_str(), "error" | "failed" | "dead")
+            }
+            Self::CloneFinished { failed, .. } => *failed,
+        }
+    }
+
+    pub fn message(&self) -> String {
+        match self {
+            Self::ActorStatus {
+                title, from, to, ..
+            } => format!("Actor {title}: {from} -> {to}"),
+            Self::CloneFinished {
+                name,
+                failed: false,
+                ..
+            } => format!("Clone ready: {name}"),
+            Self::CloneFinished {
+                name, last_line, ..
+            } if last_line != "-" => format!("Clone failed: {name} ({last_line})"),
+            Self::CloneFinished { name, .. } => format!("Clone failed: {name}"),
+        }
+    }
+}
+
+/// Actor status transitions and finished clones between two snapshots.
+///
+/// Rows missing from the previous snapshot never fire, so the first load and
+/// newly created actors stay quiet.
+pub fn detect_events(
+    previous_actors: &[ActorRow],
+    previous_variants: &[VariantRow],
+    actors: &[ActorRow],
+    variants: &[VariantRow],
+) -> Vec<NotificationEvent> {
+    let actor_statuses: HashMap<&str, &str> = previous_actors
+        .iter()
+        .map(|actor| (actor.id.as_str(), actor.status.as_str()))
+        .collect();
+    let clone_statuses: HashMap<&str, &str> = previous_variants
+        .iter()
+        .map(|variant| (variant.id.as_str(), variant.clone_status.as_str()))
+        .collect();
+
+    let mut events = Vec::new();
+    for actor in actors {
+        let Some(from) = actor_statuses.get(actor.id.as_str()) else {
+            continue;
+        };
+        if !from.eq_ignore_ascii_case(&actor.status) {
+            events.push(NotificationEvent::ActorStatus {
+                actor_id: actor.id.clone(),
+                title: actor.title.clone(),
+                from: from.to_ascii_lowercase(),
+                to: actor.status.to_ascii_lowercase(),
+            });
+        }
+    }
+
+    for variant in variants {
+        if clone_statuses.get(variant.id.as_str()) != Some(&"cloning") {
+            continue;
+        }
+        let failed = match variant.clone_status.as_str() {
+            "ready" => false,
+            "failed" => true,
+            _ => continue,
+        };
+        events.push(NotificationEvent::CloneFinished {
+            variant_id: variant.id.clone(),
+            name: variant.name.clone(),
+            failed,
+            last_line: variant.clone_last_line.clone(),
+        });
+    }
+
+    events
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Channels {
+    bell: bool,
+    desktop: DesktopProtocol,
+    command: Option<String>,
+}
+
+/// A routed event kept in the history panel.
+#[derive(Debug, Clone)]
+pub struct Notification {
+    pub at: u64,
+    pub event: NotificationEvent,
+    channels: Channels,
+}
+
+/// Routes events through the rules, keeps the history, and queues deliveries.
+#[derive(Debug, Default)]
+pub struct NotificationCenter {
+    config: NotificationConfig,
+    history: VecDeque<Notification>,
+    pending: Vec<Notification>,
+    unread: usize,
+}
+
+impl NotificationCenter {
+    pub fn set_config(&mut self, config: NotificationConfig) {
+        self.config = config;
+    }
+
+    /// Records events matching a rule; returns how many notified.
+    pub fn record(&mut self, events: Vec<NotificationEvent>, at: u64) -> usize {
+        let mut count = 0;
+        for event in events {
+            let Some(channels) = self.config.route(&event) else {
+                continue;
+            };
+            let notification = Notification {
+                at,
+                event,
+                channels,
+            };
+            self.pending.push(notification.clone());
+            self.history.push_front(notification);
+            count += 1;
+        }
+        self.history.truncate(HISTORY_LIMIT);
+        self.unread = (self.unread + count).min(HISTORY_LIMIT);
+        count
+    }
+
+    /// Newest first.
+    pub fn history(&self) -> impl ExactSizeIterator<Item = &Notification> {
+        self.history.iter()
+    }
+
+    pub fn unread(&self) -> usize {
+        self.unread
+    }
+
+    pub fn mark_read(&mut self) {
+        self.unread = 0;
+    }
+
+    pub fn clear(&mut self) {
+        self.history.clear();
+        self.unread = 0;
+    }
+
+    pub fn take_pending(&mut self) -> Vec<Notification> {
+        std::mem::take(&mut self.pending)
+    }
+}
+
+/// Writes the bell / desktop escape sequences for `notification` to `out`.
+pub fn write_terminal_alert(notification: &Notification, out: &mut impl Write) -> Result<()> {
+    let title = "Dark Factory";
+    let message = sanitize(&notification.event.message());
+    if notification.channels.bell {
+        out.write_all(b"\x07")?;
+    }
+    match notification.channels.desktop {
+        DesktopProtocol::Off => {}
+        DesktopProtocol::Osc9 => write!(out, "\x1b]9;{title}: {message}\x07")?,
+        DesktopProtocol::Osc777 => write!(
+            out,
+            "\x1b]777;notify;{title};{}\x07",
+            message.replace(';', ",")
+        )?,
+    }
+    out.flush()?;
+    Ok(())
+}
+
+/// Spawns the configured command hook without waiting on it.
+pub fn run_command_hook(notification: &Notification) -> Result<()> {
+    let Some(command_line) = notification.channels.command.as_deref() else {
+        return Ok(());
+    };
+
+    let mut command = if cfg!(target_os = "windows") {
+        let mut command = Command::new("cmd");
+        command.arg("/C").arg(command_line);
+        command
+    } else {
+        let mut command = Command::new("sh");
+        command.arg("-c").arg(command_line);
+        command
+    };
+    let mut child = command
+        .env("DARK_NOTIFY_EVENT", notification.event.kind())
+        .env("DARK_NOTIFY_TARGET", notification.event.target_id())
+        .env("DARK_NOTIFY_MESSAGE", notification.event.message())
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()
+        .with_context(|| {
+            format!("Dark TUI // Notifications // Failed to run hook `{command_line}`")
+        })?;
+    // Reap the hook in the background so finished hooks do not linger.
+    std::thread::spawn(move || child.wait());
+    Ok(())
+}
+
+/// Control characters would end or corrupt the escape sequence.
+fn sanitize(value: &str) -> String {
+    value
+        .chars()
+        .map(|ch| if ch.is_control() { ' ' } else { ch })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        DesktopProtocol, NotificationCenter, NotificationEvent, detect_events,
+        parse_notification_config, write_terminal_alert,
+    };
+    use crate::models::{ActorRow, VariantRow};
+
+    fn actor(id: &str, status: &str) -> ActorRow {
+        ActorRow {
+            id: id.to_string(),
+            variant_id: "var_1".to_string(),
+            title: id.to_string(),
+            description: "-".to_string(),
+            provider: "mock".to_string(),
+            provider_session_id: None,
+            status: status.to_string(),
+            directory: "-".to_string(),
+            connection_info: serde_json::Value::Null,
+            sub_agents: Vec::new(),
+            created_at: "-".to_string(),
+            updated_at: "-".to_string(),
+        }
+    }
+
+    fn variant(id: &str, clone_status: &str) -> VariantRow {
+        VariantRow {
+            id: id.to_string(),
+            product_id: "prd_1".to_string(),
+            locator: "-".to_string(),
+            name: id.to_string(),
+            branch: "main".to_string(),
+            git_state: "clean".to_string(),
+            clone_status: clone_status.to_string(),
+            clone_last_line: "-".to_string(),
+            has_git: true,
+            is_dirty: false,
+            ahead: 0,
+            behind: 0,
+            worktree: "main".to_string(),
+            last_polled_at: "-".to_string(),
+            updated_at: "-".to_string(),
+        }
+    }
+
+    #[test]
+    fn detect_events_reports_transitions_of_known_rows_only() {
+        let events = detect_events(
+            &[actor("act_1", "running"), actor("act_2", "idle")],
+            &[variant("var_1", "cloning"), variant("var_2", "ready")],
+            &[
+                actor("act_1", "idle"),
+                actor("act_2", "idle"),
+                actor("act_3", "error"),
+            ],
+            &[
+                variant("var_1", "failed"),
+                variant("var_2", "ready"),
+                variant("var_3", "ready"),
+            ],
+        );
+
+        let kinds: Vec<(&str, &str)> = events
+            .iter()
+            .map(|event| (event.kind(), event.target_id()))
+            .collect();
+        assert_eq!(
+            kinds,
+            vec![("actor.status", "act_1"), ("clone.failed", "var_1")]
+        );
+    }
+
+    #[test]
+    fn default_rules_route_finished_and_failed_actors() {
+        let mut center = NotificationCenter::default();
+        let event = |from: &str, to: &str| NotificationEvent::ActorStatus {
+            actor_id: "act_1".to_string(),
+            title: "builder".to_string(),
+            from: from.to_string(),
+            to: to.to_string(),
+        };
+
+        let count = center.record(
+            vec![
+                event("running", "waiting"),
+                event("idle", "running"),
+                event("idle", "error"),
+            ],
+            10,
+        );
+        assert_eq!(count, 2);
+        assert_eq!(center.unread(), 2);
+        assert_eq!(center.take_pending().len(), 2);
+        assert!(center.take_pending().is_empty());
+
+        let newest = center.history().next().expect("history entry");
+        assert!(newest.event.is_failure());
+
+        let mut out = Vec::new();
+        write_terminal_alert(newest, &mut out).expect("write alert");
+        assert_eq!(out, b"\x07");
+    }
+
+    #[test]
+    fn parse_config_overrides_channels_and_rejects_unknown_events() {
+        let config = parse_notification_config(
+            r#"
+            bell = false
+            desktop = "osc777"
+            command = "notify-send \"$DARK_NOTIFY_MESSAGE\""
+
+            [[rule]]
+            event = "clone.ready"
+            bell = true
+            "#,
+        )
+        .expect("valid config");
+        assert_eq!(config.desktop, DesktopProtocol::Osc777);
+        assert_eq!(config.rules.len(), 1);
+
+        let mut center = NotificationCenter::default();
+        center.set_config(config);
+        center.record(
+            vec![NotificationEvent::CloneFinished {
+                variant_id: "var_1".to_string(),
+                name: "a;b".to_string(),
+                failed: false,
+                last_line: "-".to_string(),
+            }],
+            1,
+        );
+        let mut out = Vec::new();
+        write_terminal_alert(center.history().next().expect("entry"), &mut out)
+            .expect("write alert");
+        assert_eq!(
+            String::from_utf8(out).expect("utf8"),
+            "\x07\x1b]777;notify;Dark Factory;Clone ready: a,b\x07"
+        );
+
+        assert!(parse_notification_config("[[rule]]\nevent = \"actor.done\"").is_err());
+        assert!(
+            parse_notification_config("[[rule]]\nevent = \"clone.ready\"\nto = [\"idle\"]")
+                .is_err()
+        );
+    }
+}
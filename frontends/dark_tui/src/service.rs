@@ -1,7 +1,13 @@
-use std::path::Path;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
 
 use anyhow::{Context, Result, anyhow};
-use dark_chat::providers::{ChatProvider, OpenCodeProvider};
+use dark_chat::core::ChatAttachment;
+use dark_chat::framework::{
+    Transcript, TranscriptFormat, TranscriptMessage, resolve_transcript_path, write_transcript,
+};
+use dark_chat::providers::{ChatProvider, OpenCodeProvider, PromptOptions};
+use dark_rust::types::VariantIntegrateResult;
 use dark_rust::{DarkCoreClient, LocatorId, LocatorKind, RawApiResponse};
 use serde_json::{Value, json};
 use tokio::task::JoinSet;
@@ -17,8 +23,15 @@ use crate::service_convert::{
 use crate::service_wire::{
     ActorRecord, ApiListEnvelope, ProductRecord, SshInfoEnvelope, VariantRecord,
 };
+use crate::system_metrics::{CoreHealth, SystemSample};
+use crate::variant_git::{
+    GitOperation, VariantGitSummary, commit_draft_prompt, extract_commit_draft,
+    read_variant_git_summary, run_git_operation,
+};
 
 const PAGE_LIMIT: u32 = 100;
+const COMMIT_DRAFT_POLL_INTERVAL: Duration = Duration::from_secs(2);
+const COMMIT_DRAFT_TIMEOUT: Duration = Duration::from_secs(120);
 
 #[derive(Debug, Clone)]
 pub struct DashboardService {
@@ -139,6 +152,72 @@ impl DashboardService {
         Ok(format!("Variant polled: {variant_id}"))
     }
 
+    /// Runs a git operation (or only reads status when `operation` is `None`) in the variant
+    /// worktree, then polls the variant so core's git metadata catches up.
+    pub async fn run_variant_git(
+        &self,
+        variant_id: &str,
+        directory: PathBuf,
+        operation: Option<GitOperation>,
+    ) -> Result<(Option<String>, VariantGitSummary)> {
+        let (message, summary) = tokio::task::spawn_blocking(move || {
+            let message = operation
+                .as_ref()
+                .map(|operation| run_git_operation(&directory, operation))
+                .transpose()?;
+            let summary = read_variant_git_summary(&directory)?;
+            Ok::<_, anyhow::Error>((message, summary))
+        })
+        .await
+        .map_err(|error| anyhow!("Dark TUI // Git // Task failed: {error}"))??;
+
+        if message.is_some() {
+            let _ = self.poll_variant(variant_id).await;
+        }
+
+        Ok((message, summary))
+    }
+
+    /// Asks the actor to draft a commit message and waits until its reply stops changing.
+    pub async fn draft_commit_message(
+        &self,
+        actor: &ActorRow,
+        directory: PathBuf,
+    ) -> Result<String> {
+        let prompt = tokio::task::spawn_blocking(move || commit_draft_prompt(&directory))
+            .await
+            .map_err(|error| anyhow!("Dark TUI // Git // Task failed: {error}"))??;
+
+        let baseline = self.fetch_actor_messages(actor, None).await?.len();
+        self.send_actor_prompt(actor, &prompt, &[], None, None)
+            .await?;
+
+        let started = Instant::now();
+        let mut previous: Option<String> = None;
+        while started.elapsed() < COMMIT_DRAFT_TIMEOUT {
+            tokio::time::sleep(COMMIT_DRAFT_POLL_INTERVAL).await;
+            let messages = self.fetch_actor_messages(actor, None).await?;
+            let reply = messages
+                .iter()
+                .skip(baseline)
+                .rev()
+                .find(|message| message.role == "assistant" && !message.text.trim().is_empty())
+                .map(|message| extract_commit_draft(&message.text));
+
+            if let Some(reply) = reply {
+                if previous.as_deref() == Some(reply.as_str()) {
+                    return Ok(reply);
+                }
+                previous = Some(reply);
+            }
+        }
+
+        Err(anyhow!(
+            "Dark TUI // Git // Actor {} did not draft a commit message in time",
+            actor.id
+        ))
+    }
+
     pub async fn switch_variant_branch(
         &self,
         variant_id: &str,
@@ -159,6 +238,34 @@ impl DashboardService {
         ))
     }
 
+    pub async fn integrate_variant(
+        &self,
+        variant_id: &str,
+        strategy: &str,
+        after: &str,
+        preview: bool,
+    ) -> Result<VariantIntegrateResult> {
+        let response = self
+            .request(
+                "POST",
+                &format!("/variants/{variant_id}/integrate"),
+                None,
+                Some(json!({
+                    "strategy": strategy,
+                    "after": after,
+                    "preview": preview,
+                })),
+            )
+            .await?;
+        let body = ensure_success(response)?;
+        let data = body
+            .get("data")
+            .cloned()
+            .context("Dark TUI // Integrate // Missing integrate result data")?;
+
+        serde_json::from_value(data).context("Dark TUI // Integrate // Unable to decode result")
+    }
+
     pub async fn import_variant_actors(
         &self,
         variant_id: &str,
@@ -213,6 +320,17 @@ impl DashboardService {
         Ok(format!("Actor polled: {actor_id}"))
     }
 
+    /// Terminates the actor's provider session and removes the actor row.
+    pub async fn terminate_actor(&self, actor_id: &str) -> Result<String> {
+        let query = [("terminate".to_string(), "true".to_string())];
+        let response = self
+            .request("DELETE", &format!("/actors/{actor_id}"), Some(&query), None)
+            .await?;
+        let _ = ensure_success(response)?;
+
+        Ok(format!("Actor terminated: {actor_id}"))
+    }
+
     pub async fn move_actor(
         &self,
         actor_id: &str,
@@ -479,7 +597,7 @@ impl DashboardService {
             .filter(|value| !value.is_empty())
         {
             let actor = self.fetch_actor_row(&actor_id).await?;
-            self.send_actor_prompt(&actor, prompt, None, None)
+            self.send_actor_prompt(&actor, prompt, &[], None, None)
                 .await
                 .with_context(|| {
                     format!(
@@ -560,6 +678,45 @@ impl DashboardService {
         })
     }
 
+    /// Polls `/system/health` and `/system/metrics` together. Never fails: request
+    /// errors become `CoreHealth::Down` and an `Err` metrics payload.
+    pub async fn fetch_system_sample(&self) -> SystemSample {
+        let (health, metrics) = tokio::join!(self.api.system_health(), self.api.system_metrics());
+
+        let health = match health {
+            Ok(response) if (200..300).contains(&response.status) => {
+                match response
+                    .body
+                    .pointer("/data/status")
+                    .and_then(Value::as_str)
+                {
+                    Some("ok") => CoreHealth::Healthy,
+                    Some(status) => CoreHealth::Degraded(format!("status={status}")),
+                    None => CoreHealth::Degraded("missing health status".to_string()),
+                }
+            }
+            Ok(response) => CoreHealth::Degraded(format!("HTTP {}", response.status)),
+            Err(error) => CoreHealth::Down(summarize_error(&error.into())),
+        };
+
+        let metrics = metrics
+            .map_err(anyhow::Error::from)
+            .and_then(ensure_success)
+            .and_then(|body| {
+                let data = body
+                    .get("data")
+                    .and_then(Value::as_object)
+                    .context("Dark TUI // Metrics // Missing metrics data")?;
+                Ok(data
+                    .iter()
+                    .filter_map(|(key, value)| value.as_f64().map(|value| (key.clone(), value)))
+                    .collect())
+            })
+            .map_err(|error| summarize_error(&error));
+
+        SystemSample { health, metrics }
+    }
+
     pub async fn start_ssh_port_forward(&self, preset_name: &str) -> Result<String> {
         let response = self
             .request(
@@ -602,9 +759,54 @@ impl DashboardService {
         n_last_messages: Option<u32>,
     ) -> Result<Vec<ActorChatMessageRow>> {
         let context = required_actor_opencode_context(actor, "fetch messages")?;
+        Self::fetch_session_messages(
+            &context.base_url,
+            &context.directory,
+            &context.session_id,
+            n_last_messages,
+        )
+        .await
+    }
+
+    /// Fetches a sub-agent's transcript; sub-agent ids are OpenCode child
+    /// sessions on the actor's server.
+    pub async fn fetch_sub_agent_messages(
+        &self,
+        actor: &ActorRow,
+        sub_agent_id: &str,
+        n_last_messages: Option<u32>,
+    ) -> Result<Vec<ActorChatMessageRow>> {
+        let context = required_actor_opencode_context(actor, "fetch sub-agent messages")?;
+        Self::fetch_session_messages(
+            &context.base_url,
+            &context.directory,
+            sub_agent_id,
+            n_last_messages,
+        )
+        .await
+    }
+
+    /// Stops a sub-agent's current run; the session and its transcript stay.
+    pub async fn abort_sub_agent(&self, actor: &ActorRow, sub_agent_id: &str) -> Result<String> {
+        let context = required_actor_opencode_context(actor, "abort sub-agent")?;
         let provider = OpenCodeProvider::new(context.base_url);
+        provider
+            .abort_session(&context.directory, sub_agent_id)
+            .await
+            .context("Dark TUI // Actors // Failed to abort OpenCode sub-agent session")?;
+
+        Ok(format!("Sub-agent aborted: {sub_agent_id}"))
+    }
+
+    async fn fetch_session_messages(
+        base_url: &str,
+        directory: &str,
+        session_id: &str,
+        n_last_messages: Option<u32>,
+    ) -> Result<Vec<ActorChatMessageRow>> {
+        let provider = OpenCodeProvider::new(base_url.to_string());
         let messages = provider
-            .list_messages(&context.directory, &context.session_id, n_last_messages)
+            .list_messages(directory, session_id, n_last_messages)
             .await
             .context("Dark TUI // Chat // Failed to fetch OpenCode session messages")?;
 
@@ -661,11 +863,12 @@ impl DashboardService {
         &self,
         actor: &ActorRow,
         prompt: &str,
+        attachments: &[ChatAttachment],
         model: Option<&str>,
         agent: Option<&str>,
     ) -> Result<()> {
         let trimmed = prompt.trim();
-        if trimmed.is_empty() {
+        if trimmed.is_empty() && attachments.is_empty() {
             return Err(anyhow!("Dark TUI // Actors // Prompt cannot be empty"));
         }
 
@@ -676,9 +879,12 @@ impl DashboardService {
                 &context.directory,
                 &context.session_id,
                 trimmed,
-                model,
-                agent,
-                false,
+                attachments,
+                PromptOptions {
+                    model,
+                    agent,
+                    no_reply: false,
+                },
             )
             .await
             .context("Dark TUI // Chat // Failed to send OpenCode session prompt")?;
@@ -686,6 +892,39 @@ impl DashboardService {
         Ok(())
     }
 
+    pub async fn export_actor_transcript(
+        &self,
+        actor: &ActorRow,
+        target: Option<&str>,
+    ) -> Result<String> {
+        let context = required_actor_opencode_context(actor, "export transcript")?;
+        let provider = OpenCodeProvider::new(context.base_url);
+        let messages = provider
+            .list_messages(&context.directory, &context.session_id, None)
+            .await
+            .context("Dark TUI // Chat // Failed to fetch OpenCode session messages")?;
+
+        let mut transcript = Transcript::new(
+            context.session_id.clone(),
+            messages
+                .iter()
+                .filter_map(|message| TranscriptMessage::from_value(&message.raw))
+                .collect(),
+        );
+        transcript.title = Some(actor.title.clone());
+        transcript.directory = Some(context.directory.clone());
+        transcript.provider = Some(actor.provider.clone());
+
+        let path = resolve_transcript_path(&context.directory, &context.session_id, target);
+        write_transcript(&transcript, &path, TranscriptFormat::from_path(&path))?;
+
+        Ok(format!(
+            "Exported {} messages to {}",
+            transcript.messages.len(),
+            path.display()
+        ))
+    }
+
     pub async fn fetch_actor_chat_options(
         &self,
         actor: &ActorRow,
@@ -0,0 +1,235 @@
+use std::collections::{BTreeMap, VecDeque};
+use std::time::Instant;
+
+/// Samples kept for sparklines; at the default cadence this is ~6 minutes.
+pub const METRICS_HISTORY_CAPACITY: usize = 120;
+const ACTOR_STATUS_PREFIX: &str = "actorsByStatus.";
+
+/// dark_core health as seen from `/system/health`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CoreHealth {
+    Unknown,
+    Healthy,
+    /// Reachable but reporting a non-ok status.
+    Degraded(String),
+    /// Request failed or timed out.
+    Down(String),
+}
+
+impl CoreHealth {
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Unknown => "core ?",
+            Self::Healthy => "core ok",
+            Self::Degraded(_) => "core degraded",
+            Self::Down(_) => "core down",
+        }
+    }
+
+    pub fn reason(&self) -> Option<&str> {
+        match self {
+            Self::Degraded(reason) | Self::Down(reason) => Some(reason),
+            Self::Unknown | Self::Healthy => None,
+        }
+    }
+}
+
+/// One `/system/health` + `/system/metrics` poll.
+#[derive(Debug, Clone)]
+pub struct SystemSample {
+    pub health: CoreHealth,
+    pub metrics: Result<BTreeMap<String, f64>, String>,
+}
+
+#[derive(Debug, Clone)]
+struct MetricsPoint {
+    at: Instant,
+    values: BTreeMap<String, f64>,
+}
+
+/// Rolling in-memory history of metrics samples.
+#[derive(Debug)]
+pub struct MetricsHistory {
+    points: VecDeque<MetricsPoint>,
+    health: CoreHealth,
+    last_error: Option<String>,
+}
+
+impl Default for MetricsHistory {
+    fn default() -> Self {
+        Self {
+            points: VecDeque::new(),
+            health: CoreHealth::Unknown,
+            last_error: None,
+        }
+    }
+}
+
+impl MetricsHistory {
+    pub fn record(&mut self, sample: SystemSample) {
+        self.record_at(sample, Instant::now());
+    }
+
+    fn record_at(&mut self, sample: SystemSample, at: Instant) {
+        self.health = sample.health;
+        match sample.metrics {
+            Ok(values) => {
+                self.last_error = None;
+                self.points.push_back(MetricsPoint { at, values });
+                while self.points.len() > METRICS_HISTORY_CAPACITY {
+                    self.points.pop_front();
+                }
+            }
+            Err(error) => self.last_error = Some(error),
+        }
+    }
+
+    pub fn health(&self) -> &CoreHealth {
+        &self.health
+    }
+
+    pub fn last_error(&self) -> Option<&str> {
+        self.last_error.as_deref()
+    }
+
+    pub fn len(&self) -> usize {
+        self.points.len()
+    }
+
+    pub fn latest(&self, key: &str) -> Option<f64> {
+        self.points.back()?.values.get(key).copied()
+    }
+
+    /// Gauge values over time; missing samples read as 0.
+    pub fn series(&self, key: &str) -> Vec<u64> {
+        self.points
+            .iter()
+            .map(|point| point.values.get(key).copied().unwrap_or(0.0).max(0.0) as u64)
+            .collect()
+    }
+
+    /// Per-second rate of a monotonically growing counter between consecutive
+    /// samples. A counter that went backwards (core restart) reads as 0.
+    pub fn rate_series(&self, key: &str) -> Vec<f64> {
+        self.points
+            .iter()
+            .zip(self.points.iter().skip(1))
+            .map(|(previous, current)| {
+                let elapsed = current.at.duration_since(previous.at).as_secs_f64();
+                let delta = current.values.get(key).copied().unwrap_or(0.0)
+                    - previous.values.get(key).copied().unwrap_or(0.0);
+                if elapsed <= 0.0 || delta < 0.0 {
+                    0.0
+                } else {
+                    delta / elapsed
+                }
+            })
+            .collect()
+    }
+
+    /// Latest actor counts by status, largest first.
+    pub fn actor_status_counts(&self) -> Vec<(String, u64)> {
+        let Some(point) = self.points.back() else {
+            return Vec::new();
+        };
+        let mut counts: Vec<(String, u64)> = point
+            .values
+            .iter()
+            .filter_map(|(key, value)| {
+                key.strip_prefix(ACTOR_STATUS_PREFIX)
+                    .map(|status| (status.to_string(), value.max(0.0) as u64))
+            })
+            .collect();
+        counts.sort_by(|left, right| right.1.cmp(&left.1).then_with(|| left.0.cmp(&right.0)));
+        counts
+    }
+}
+
+/// Human-readable byte size (`12.3 MiB`).
+pub fn format_bytes(bytes: f64) -> String {
+    const UNITS: [&str; 5] = ["B", "KiB", "MiB", "GiB", "TiB"];
+    let mut value = bytes.max(0.0);
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+    if unit == 0 {
+        format!("{value:.0} {}", UNITS[unit])
+    } else {
+        format!("{value:.1} {}", UNITS[unit])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+    use std::time::{Duration, Instant};
+
+    use super::{CoreHealth, MetricsHistory, SystemSample, format_bytes};
+
+    fn sample(pairs: &[(&str, f64)]) -> SystemSample {
+        SystemSample {
+            health: CoreHealth::Healthy,
+            metrics: Ok(pairs
+                .iter()
+                .map(|(key, value)| (key.to_string(), *value))
+                .collect::<BTreeMap<_, _>>()),
+        }
+    }
+
+    #[test]
+    fn history_derives_rates_gauges_and_status_counts() {
+        let start = Instant::now();
+        let mut history = MetricsHistory::default();
+        history.record_at(
+            sample(&[("httpRequestsTotal", 10.0), ("actorsByStatus.idle", 2.0)]),
+            start,
+        );
+        history.record_at(
+            sample(&[
+                ("httpRequestsTotal", 30.0),
+                ("actorsByStatus.busy", 3.0),
+                ("actorsByStatus.idle", 1.0),
+            ]),
+            start + Duration::from_secs(2),
+        );
+        history.record_at(
+            sample(&[("httpRequestsTotal", 4.0)]),
+            start + Duration::from_secs(4),
+        );
+
+        assert_eq!(history.rate_series("httpRequestsTotal"), vec![10.0, 0.0]);
+        assert_eq!(history.series("httpRequestsTotal"), vec![10, 30, 4]);
+        assert_eq!(history.latest("httpRequestsTotal"), Some(4.0));
+
+        history.record_at(
+            SystemSample {
+                health: CoreHealth::Down("connection refused".to_string()),
+                metrics: Err("connection refused".to_string()),
+            },
+            start + Duration::from_secs(6),
+        );
+        assert_eq!(history.len(), 3);
+        assert_eq!(history.health().label(), "core down");
+        assert_eq!(history.last_error(), Some("connection refused"));
+        assert!(history.actor_status_counts().is_empty());
+
+        history.record_at(
+            sample(&[("actorsByStatus.busy", 3.0), ("actorsByStatus.idle", 1.0)]),
+            start + Duration::from_secs(8),
+        );
+        assert_eq!(
+            history.actor_status_counts(),
+            vec![("busy".to_string(), 3), ("idle".to_string(), 1)]
+        );
+        assert_eq!(history.last_error(), None);
+    }
+
+    #[test]
+    fn format_bytes_uses_binary_units() {
+        assert_eq!(format_bytes(512.0), "512 B");
+        assert_eq!(format_bytes(1536.0), "1.5 KiB");
+        assert_eq!(format_bytes(5.0 * 1024.0 * 1024.0), "5.0 MiB");
+    }
+}
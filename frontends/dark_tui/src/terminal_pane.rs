@@ -0,0 +1,400 @@
+use std::fmt;
+use std::io::{self, Read, Write};
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+use anyhow::{Context, Result};
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use portable_pty::{Child, CommandBuilder, MasterPty, PtySize, native_pty_system};
+
+const SCROLLBACK_LINES: usize = 2_000;
+const READ_BUFFER_BYTES: usize = 8 * 1024;
+
+/// What runs inside the embedded terminal pane.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum TerminalTarget {
+    /// `tmux attach-session` to an actor's session.
+    TmuxSession { session: String },
+    /// The user's shell started in a variant checkout.
+    Shell { directory: PathBuf },
+}
+
+impl TerminalTarget {
+    pub(crate) fn title(&self) -> String {
+        match self {
+            Self::TmuxSession { session } => format!("tmux: {session}"),
+            Self::Shell { directory } => format!("shell: {}", directory.display()),
+        }
+    }
+
+    fn command(&self) -> CommandBuilder {
+        let mut command = match self {
+            Self::TmuxSession { session } => {
+                let mut command = CommandBuilder::new("tmux");
+                // tmux refuses to attach while `TMUX` is set, so drop it but
+                // keep talking to the same server through its socket.
+                let nested = std::env::var("TMUX").ok();
+                if let Some(socket) = nested.as_deref().and_then(tmux_socket) {
+                    command.args(["-S", socket]);
+                }
+                command.args(["attach-session", "-t", session.as_str()]);
+                command.env_remove("TMUX");
+                command
+            }
+            Self::Shell { directory } => {
+                let mut command = CommandBuilder::new_default_prog();
+                command.cwd(directory);
+                command
+            }
+        };
+        command.env("TERM", "xterm-256color");
+        command
+    }
+}
+
+/// A child process on a PTY, rendered through a vt100 parser.
+///
+/// A reader thread feeds PTY output into the parser; the dashboard redraws
+/// from the parsed screen on its regular tick. Dropping the pane kills the
+/// child, which for tmux only detaches the client.
+pub(crate) struct TerminalPane {
+    target: TerminalTarget,
+    parser: Arc<Mutex<vt100::Parser>>,
+    master: Box<dyn MasterPty + Send>,
+    writer: Box<dyn Write + Send>,
+    child: Box<dyn Child + Send + Sync>,
+    size: (u16, u16),
+}
+
+impl fmt::Debug for TerminalPane {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TerminalPane")
+            .field("target", &self.target)
+            .field("size", &self.size)
+            .finish_non_exhaustive()
+    }
+}
+
+impl TerminalPane {
+    pub(crate) fn spawn(target: TerminalTarget, rows: u16, cols: u16) -> Result<Self> {
+        let size = (rows.max(1), cols.max(1));
+        let pair = native_pty_system()
+            .openpty(pty_size(size))
+            .context("Dark TUI // Terminal // failed to open pty")?;
+        let child = pair
+            .slave
+            .spawn_command(target.command())
+            .with_context(|| {
+                format!("Dark TUI // Terminal // failed to start {}", target.title())
+            })?;
+        // Keep only the master side open so EOF arrives when the child exits.
+        drop(pair.slave);
+
+        let mut reader = pair
+            .master
+            .try_clone_reader()
+            .context("Dark TUI // Terminal // failed to read pty")?;
+        let writer = pair
+            .master
+            .take_writer()
+            .context("Dark TUI // Terminal // failed to write pty")?;
+
+        let parser = Arc::new(Mutex::new(vt100::Parser::new(
+            size.0,
+            size.1,
+            SCROLLBACK_LINES,
+        )));
+        let sink = Arc::clone(&parser);
+        thread::spawn(move || {
+            let mut buffer = [0u8; READ_BUFFER_BYTES];
+            loop {
+                match reader.read(&mut buffer) {
+                    Ok(0) | Err(_) => break,
+                    Ok(read) => {
+                        if let Ok(mut parser) = sink.lock() {
+                            parser.process(&buffer[..read]);
+                        }
+                    }
+                }
+            }
+        });
+
+        Ok(Self {
+            target,
+            parser,
+            master: pair.master,
+            writer,
+            child,
+            size,
+        })
+    }
+
+    pub(crate) fn title(&self) -> String {
+        self.target.title()
+    }
+
+    /// Match the PTY and parser to the pane's inner area.
+    ///
+    /// The new size is recorded even when the PTY rejects it, so a failure is
+    /// reported once per size change rather than on every frame.
+    pub(crate) fn resize(&mut self, rows: u16, cols: u16) -> io::Result<()> {
+        let size = (rows.max(1), cols.max(1));
+        if size == self.size {
+            return Ok(());
+        }
+
+        self.size = size;
+        if let Ok(mut parser) = self.parser.lock() {
+            parser.set_size(size.0, size.1);
+        }
+        self.master
+            .resize(pty_size(size))
+            .map_err(|error| io::Error::other(format!("{error:#}")))
+    }
+
+    /// Forward a key press. Returns `false` for keys with no terminal encoding.
+    pub(crate) fn send_key(&mut self, key: KeyEvent) -> io::Result<bool> {
+        let application_cursor = self.with_screen(|screen| screen.application_cursor());
+        let Some(bytes) = encode_key(key, application_cursor) else {
+            return Ok(false);
+        };
+
+        self.send(&bytes)?;
+        Ok(true)
+    }
+
+    pub(crate) fn send_paste(&mut self, text: &str) -> io::Result<()> {
+        let bracketed = self.with_screen(|screen| screen.bracketed_paste());
+        let mut bytes = Vec::with_capacity(text.len() + 12);
+        if bracketed {
+            bytes.extend_from_slice(b"\x1b[200~");
+        }
+        bytes.extend_from_slice(text.as_bytes());
+        if bracketed {
+            bytes.extend_from_slice(b"\x1b[201~");
+        }
+        self.send(&bytes)
+    }
+
+    fn send(&mut self, bytes: &[u8]) -> io::Result<()> {
+        // Typing snaps back to the live screen, like a regular terminal.
+        self.scroll_to_bottom();
+        self.writer.write_all(bytes)?;
+        self.writer.flush()
+    }
+
+    /// Scroll into the scrollback; positive `lines` moves up (older output).
+    pub(crate) fn scroll(&mut self, lines: isize) {
+        if let Ok(mut parser) = self.parser.lock() {
+            let current = parser.screen().scrollback();
+            parser.set_scrollback(current.saturating_add_signed(lines));
+        }
+    }
+
+    pub(crate) fn scroll_to_bottom(&mut self) {
+        if let Ok(mut parser) = self.parser.lock() {
+            parser.set_scrollback(0);
+        }
+    }
+
+    /// Lines scrolled back from the live screen.
+    pub(crate) fn scroll_offset(&self) -> usize {
+        self.with_screen(|screen| screen.scrollback())
+    }
+
+    pub(crate) fn rows(&self) -> u16 {
+        self.size.0
+    }
+
+    pub(crate) fn with_screen<R>(&self, read: impl FnOnce(&vt100::Screen) -> R) -> R {
+        let parser = self
+            .parser
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        read(parser.screen())
+    }
+
+    /// Exit description once the child has finished, e.g. `exit=0`.
+    pub(crate) fn try_exit(&mut self) -> Option<String> {
+        let status = self.child.try_wait().ok().flatten()?;
+        Some(match status.signal() {
+            Some(signal) => format!("signal={signal}"),
+            None => format!("exit={}", status.exit_code()),
+        })
+    }
+}
+
+impl Drop for TerminalPane {
+    fn drop(&mut self) {
+        if self.child.try_wait().ok().flatten().is_none() {
+            let _ = self.child.kill();
+        }
+    }
+}
+
+/// Server socket from a `TMUX` value (`<socket>,<pid>,<session>`).
+fn tmux_socket(value: &str) -> Option<&str> {
+    value
+        .split(',')
+        .next()
+        .map(str::trim)
+        .filter(|socket| !socket.is_empty())
+}
+
+fn pty_size((rows, cols): (u16, u16)) -> PtySize {
+    PtySize {
+        rows,
+        cols,
+        pixel_width: 0,
+        pixel_height: 0,
+    }
+}
+
+/// Bytes an xterm-compatible terminal sends for `key`.
+pub(crate) fn encode_key(key: KeyEvent, application_cursor: bool) -> Option<Vec<u8>> {
+    let ctrl = key.modifiers.contains(KeyModifiers::CONTROL);
+    let alt = key.modifiers.contains(KeyModifiers::ALT);
+    let shift = key.modifiers.contains(KeyModifiers::SHIFT);
+    // xterm modifier parameter: 1 + shift + 2*alt + 4*ctrl.
+    let modifier = 1 + u8::from(shift) + 2 * u8::from(alt) + 4 * u8::from(ctrl);
+
+    let mut bytes = match key.code {
+        KeyCode::Char(ch) if ctrl => match ch.to_ascii_lowercase() {
+            lower @ 'a'..='z' => vec![lower as u8 - b'a' + 1],
+            ' ' | '@' | '2' => vec![0x00],
+            '[' | '3' => vec![0x1b],
+            '\\' | '4' => vec![0x1c],
+            ']' | '5' => vec![0x1d],
+            '^' | '6' => vec![0x1e],
+            '_' | '/' | '7' => vec![0x1f],
+            other => other.to_string().into_bytes(),
+        },
+        KeyCode::Char(ch) => ch.to_string().into_bytes(),
+        KeyCode::Enter => vec![b'\r'],
+        KeyCode::Tab => vec![b'\t'],
+        KeyCode::BackTab => b"\x1b[Z".to_vec(),
+        KeyCode::Backspace => vec![0x7f],
+        KeyCode::Esc => vec![0x1b],
+        KeyCode::Up => return Some(cursor_key(b'A', modifier, application_cursor)),
+        KeyCode::Down => return Some(cursor_key(b'B', modifier, application_cursor)),
+        KeyCode::Right => return Some(cursor_key(b'C', modifier, application_cursor)),
+        KeyCode::Left => return Some(cursor_key(b'D', modifier, application_cursor)),
+        KeyCode::Home => return Some(cursor_key(b'H', modifier, application_cursor)),
+        KeyCode::End => return Some(cursor_key(b'F', modifier, application_cursor)),
+        KeyCode::Insert => return Some(tilde_key(2, modifier)),
+        KeyCode::Delete => return Some(tilde_key(3, modifier)),
+        KeyCode::PageUp => return Some(tilde_key(5, modifier)),
+        KeyCode::PageDown => return Some(tilde_key(6, modifier)),
+        KeyCode::F(number @ 1..=4) => {
+            let final_byte = b'P' + (number - 1);
+            if modifier == 1 {
+                return Some(vec![0x1b, b'O', final_byte]);
+            }
+            return Some(format!("\x1b[1;{modifier}{}", final_byte as char).into_bytes());
+        }
+        KeyCode::F(number @ 5..=12) => {
+            let code = match number {
+                5 => 15,
+                6 => 17,
+                7 => 18,
+                8 => 19,
+                9 => 20,
+                10 => 21,
+                11 => 23,
+                _ => 24,
+            };
+            return Some(tilde_key(code, modifier));
+        }
+        _ => return None,
+    };
+
+    if alt {
+        bytes.insert(0, 0x1b);
+    }
+    Some(bytes)
+}
+
+fn cursor_key(final_byte: u8, modifier: u8, application_cursor: bool) -> Vec<u8> {
+    if modifier > 1 {
+        return format!("\x1b[1;{modifier}{}", final_byte as char).into_bytes();
+    }
+    if application_cursor {
+        vec![0x1b, b'O', final_byte]
+    } else {
+        vec![0x1b, b'[', final_byte]
+    }
+}
+
+fn tilde_key(code: u8, modifier: u8) -> Vec<u8> {
+    if modifier > 1 {
+        format!("\x1b[{code};{modifier}~").into_bytes()
+    } else {
+        format!("\x1b[{code}~").into_bytes()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key(code: KeyCode, modifiers: KeyModifiers) -> KeyEvent {
+        KeyEvent::new(code, modifiers)
+    }
+
+    #[test]
+    fn encode_key_matches_xterm_sequences() {
+        let plain = KeyModifiers::NONE;
+        assert_eq!(
+            encode_key(key(KeyCode::Char('a'), plain), false),
+            Some(b"a".to_vec())
+        );
+        assert_eq!(
+            encode_key(key(KeyCode::Char('c'), KeyModifiers::CONTROL), false),
+            Some(vec![0x03])
+        );
+        assert_eq!(
+            encode_key(key(KeyCode::Char('x'), KeyModifiers::ALT), false),
+            Some(b"\x1bx".to_vec())
+        );
+        assert_eq!(
+            encode_key(key(KeyCode::Up, plain), false),
+            Some(b"\x1b[A".to_vec())
+        );
+        assert_eq!(
+            encode_key(key(KeyCode::Up, plain), true),
+            Some(b"\x1bOA".to_vec())
+        );
+        assert_eq!(
+            encode_key(key(KeyCode::Left, KeyModifiers::CONTROL), true),
+            Some(b"\x1b[1;5D".to_vec())
+        );
+        assert_eq!(
+            encode_key(key(KeyCode::PageUp, plain), false),
+            Some(b"\x1b[5~".to_vec())
+        );
+        assert_eq!(
+            encode_key(key(KeyCode::F(5), plain), false),
+            Some(b"\x1b[15~".to_vec())
+        );
+    }
+
+    #[test]
+    fn shell_pane_runs_in_variant_directory() {
+        let directory = std::env::temp_dir();
+        let target = TerminalTarget::Shell {
+            directory: directory.clone(),
+        };
+        let command = target.command();
+        assert_eq!(
+            command.get_cwd().map(PathBuf::from),
+            Some(directory.clone())
+        );
+        assert_eq!(target.title(), format!("shell: {}", directory.display()));
+        assert_eq!(
+            tmux_socket("/tmp/tmux-0/default,5194,0"),
+            Some("/tmp/tmux-0/default")
+        );
+        assert_eq!(tmux_socket(""), None);
+    }
+}
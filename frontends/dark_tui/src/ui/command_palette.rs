@@ -1,5 +1,8 @@
-use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use crossterm::event::KeyEvent;
 
+use dark_tui_components::{KeyChord, Keymap};
+
+use super::keymap::{KeyCommand, MAIN_MODE, PopupCommand, TERMINAL_MODE, popup_hint};
 use crate::app::{App, FocusPane, VizSelection};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -10,12 +13,14 @@ pub(crate) enum CommandId {
     MoveUp,
     Refresh,
     ToggleFilter,
+    Search,
     ToggleView,
     CycleVizDensity,
     ToggleInspector,
     PollVariant,
     OpenBranchForm,
     PollActor,
+    AbortSubAgent,
     OpenMoveActorForm,
     OpenCloneForm,
     OpenDeleteVariantForm,
@@ -24,21 +29,263 @@ pub(crate) enum CommandId {
     OpenSpawnForm,
     OpenVariantInExplorer,
     OpenVariantInTerminal,
+    OpenVariantDiff,
+    OpenIntegrateForm,
+    OpenGitPanel,
     BuildAttach,
     RunAttach,
+    TerminalAttach,
+    TerminalShell,
+    FocusTerminal,
+    CloseTerminal,
     ToggleChat,
     ToggleCoreLogs,
     OpenLastLogInPager,
     OpenSshPanel,
     OpenChatCompose,
     ResetPan,
+    ToggleMark,
+    MarkAllVisible,
+    ClearMarks,
+    BulkPoll,
+    BulkDelete,
+    BulkPrompt,
+    BulkTerminate,
+    OpenBulkSummary,
+    OpenNotifications,
+    OpenMessageLog,
+    OpenMetrics,
+    OpenCommandPalette,
 }
 
-#[derive(Debug, Clone, Copy)]
-pub(crate) struct CommandBinding {
-    pub(crate) id: CommandId,
-    pub(crate) key: &'static str,
-    pub(crate) label: &'static str,
+impl CommandId {
+    pub(crate) const ALL: &'static [CommandId] = &[
+        Self::Quit,
+        Self::ToggleFocus,
+        Self::MoveDown,
+        Self::MoveUp,
+        Self::Refresh,
+        Self::ToggleFilter,
+        Self::Search,
+        Self::ToggleView,
+        Self::CycleVizDensity,
+        Self::ToggleInspector,
+        Self::PollVariant,
+        Self::OpenBranchForm,
+        Self::PollActor,
+        Self::AbortSubAgent,
+        Self::OpenMoveActorForm,
+        Self::OpenCloneForm,
+        Self::OpenDeleteVariantForm,
+        Self::ImportVariantActors,
+        Self::InitProduct,
+        Self::OpenSpawnForm,
+        Self::OpenVariantInExplorer,
+        Self::OpenVariantInTerminal,
+        Self::OpenVariantDiff,
+        Self::OpenIntegrateForm,
+        Self::OpenGitPanel,
+        Self::BuildAttach,
+        Self::RunAttach,
+        Self::TerminalAttach,
+        Self::TerminalShell,
+        Self::FocusTerminal,
+        Self::CloseTerminal,
+        Self::ToggleChat,
+        Self::ToggleCoreLogs,
+        Self::OpenLastLogInPager,
+        Self::OpenSshPanel,
+        Self::OpenChatCompose,
+        Self::ResetPan,
+        Self::ToggleMark,
+        Self::MarkAllVisible,
+        Self::ClearMarks,
+        Self::BulkPoll,
+        Self::BulkDelete,
+        Self::BulkPrompt,
+        Self::BulkTerminate,
+        Self::OpenBulkSummary,
+        Self::OpenNotifications,
+        Self::OpenMessageLog,
+        Self::OpenMetrics,
+        Self::OpenCommandPalette,
+    ];
+
+    /// Stable name used by the palette and the keymap file.
+    pub(crate) fn name(self) -> &'static str {
+        match self {
+            Self::Quit => "quit",
+            Self::ToggleFocus => "focus",
+            Self::MoveDown => "down",
+            Self::MoveUp => "up",
+            Self::Refresh => "refresh",
+            Self::ToggleFilter => "filter",
+            Self::Search => "search",
+            Self::ToggleView => "view",
+            Self::CycleVizDensity => "density",
+            Self::ToggleInspector => "sidebar",
+            Self::PollVariant => "poll",
+            Self::OpenBranchForm => "branch",
+            Self::PollActor => "poll-actor",
+            Self::AbortSubAgent => "abort-sub-agent",
+            Self::OpenMoveActorForm => "move-actor",
+            Self::OpenCloneForm => "clone",
+            Self::OpenDeleteVariantForm => "delete",
+            Self::ImportVariantActors => "import",
+            Self::InitProduct => "init",
+            Self::OpenSpawnForm => "spawn",
+            Self::OpenVariantInExplorer => "explore",
+            Self::OpenVariantInTerminal => "terminal",
+            Self::OpenVariantDiff => "diff",
+            Self::OpenIntegrateForm => "integrate",
+            Self::OpenGitPanel => "git",
+            Self::BuildAttach => "copy-attach",
+            Self::RunAttach => "attach",
+            Self::TerminalAttach => "term-attach",
+            Self::TerminalShell => "term-shell",
+            Self::FocusTerminal => "term-focus",
+            Self::CloseTerminal => "term-close",
+            Self::ToggleChat => "chat",
+            Self::ToggleCoreLogs => "logs",
+            Self::OpenLastLogInPager => "last-log",
+            Self::OpenSshPanel => "ssh",
+            Self::OpenChatCompose => "compose",
+            Self::ResetPan => "reset-pan",
+            Self::ToggleMark => "mark",
+            Self::MarkAllVisible => "mark-all",
+            Self::ClearMarks => "clear-marks",
+            Self::BulkPoll => "bulk-poll",
+            Self::BulkDelete => "bulk-delete",
+            Self::BulkPrompt => "broadcast",
+            Self::BulkTerminate => "terminate",
+            Self::OpenBulkSummary => "bulk-results",
+            Self::OpenNotifications => "notifications",
+            Self::OpenMessageLog => "messages",
+            Self::OpenMetrics => "metrics",
+            Self::OpenCommandPalette => "palette",
+        }
+    }
+
+    /// Short label shown in the key bar and context menus.
+    pub(crate) fn label(self) -> &'static str {
+        match self {
+            Self::Quit => "Quit",
+            Self::ToggleFocus => "Focus",
+            Self::MoveDown => "Select",
+            Self::MoveUp => "Select up",
+            Self::Refresh => "Refresh",
+            Self::ToggleFilter => "Filter",
+            Self::Search => "Search",
+            Self::ToggleView => "View",
+            Self::CycleVizDensity => "Density",
+            Self::ToggleInspector => "Sidebar",
+            Self::PollVariant => "Poll variant",
+            Self::OpenBranchForm => "Switch branch",
+            Self::PollActor => "Poll actor",
+            Self::AbortSubAgent => "Abort sub-agent",
+            Self::OpenMoveActorForm => "Move actor",
+            Self::OpenCloneForm => "Clone variant",
+            Self::OpenDeleteVariantForm => "Delete variant",
+            Self::ImportVariantActors => "Import actors",
+            Self::InitProduct => "Init product",
+            Self::OpenSpawnForm => "Spawn actor",
+            Self::OpenVariantInExplorer => "Open in explorer",
+            Self::OpenVariantInTerminal => "Open in terminal",
+            Self::OpenVariantDiff => "Diff",
+            Self::OpenIntegrateForm => "Integrate",
+            Self::OpenGitPanel => "Git",
+            Self::BuildAttach => "Copy attach cmd",
+            Self::RunAttach => "Attach",
+            Self::TerminalAttach => "Attach in pane",
+            Self::TerminalShell => "Shell in pane",
+            Self::FocusTerminal => "Terminal",
+            Self::CloseTerminal => "Close terminal",
+            Self::ToggleChat => "Toggle chat",
+            Self::ToggleCoreLogs => "Core logs",
+            Self::OpenLastLogInPager => "Last log",
+            Self::OpenSshPanel => "SSH panel",
+            Self::OpenChatCompose => "Compose",
+            Self::ResetPan => "Reset pan",
+            Self::ToggleMark => "Mark",
+            Self::MarkAllVisible => "Mark all",
+            Self::ClearMarks => "Clear marks",
+            Self::BulkPoll => "Poll marked",
+            Self::BulkDelete => "Delete marked",
+            Self::BulkPrompt => "Prompt marked",
+            Self::BulkTerminate => "Terminate marked",
+            Self::OpenBulkSummary => "Bulk results",
+            Self::OpenNotifications => "Notifications",
+            Self::OpenMessageLog => "Messages",
+            Self::OpenMetrics => "Metrics",
+            Self::OpenCommandPalette => "Commands",
+        }
+    }
+
+    /// Longer description shown next to the command in the palette.
+    pub(crate) fn description(self) -> &'static str {
+        match self {
+            Self::Quit => "Quit dark_tui",
+            Self::ToggleFocus => "Cycle pane focus",
+            Self::MoveDown => "Select next row",
+            Self::MoveUp => "Select previous row",
+            Self::Refresh => "Refresh all panes",
+            Self::ToggleFilter => "Toggle variant filter",
+            Self::Search => "Search the catalog (status: branch: dirty: provider: name: id:)",
+            Self::ToggleView => "Toggle table / viz view",
+            Self::CycleVizDensity => "Cycle viz density",
+            Self::ToggleInspector => "Toggle inspector sidebar",
+            Self::PollVariant => "Poll selected variant",
+            Self::OpenBranchForm => "Switch variant branch",
+            Self::PollActor => "Poll selected actor",
+            Self::AbortSubAgent => "Abort selected sub-agent",
+            Self::OpenMoveActorForm => "Move actor to another variant",
+            Self::OpenCloneForm => "Clone variant from product",
+            Self::OpenDeleteVariantForm => "Delete variant",
+            Self::ImportVariantActors => "Import variant actors",
+            Self::InitProduct => "Init product",
+            Self::OpenSpawnForm => "Spawn actor on variant",
+            Self::OpenVariantInExplorer => "Open variant in file explorer",
+            Self::OpenVariantInTerminal => "Open variant in terminal",
+            Self::OpenVariantDiff => "Show variant diff",
+            Self::OpenIntegrateForm => "Integrate variant into base",
+            Self::OpenGitPanel => "Variant git operations",
+            Self::BuildAttach => "Copy actor attach command",
+            Self::RunAttach => "Attach to actor tmux session",
+            Self::TerminalAttach => "Attach to actor tmux session in an embedded pane",
+            Self::TerminalShell => "Open a shell in the variant directory in an embedded pane",
+            Self::FocusTerminal => "Focus the embedded terminal pane",
+            Self::CloseTerminal => "Close the embedded terminal pane",
+            Self::ToggleChat => "Toggle chat panel",
+            Self::ToggleCoreLogs => "Toggle dark_core log panel",
+            Self::OpenLastLogInPager => "Open latest log in pager",
+            Self::OpenSshPanel => "SSH hosts and port forwards",
+            Self::OpenChatCompose => "Compose chat prompt",
+            Self::ResetPan => "Reset viz pan",
+            Self::ToggleMark => "Mark / unmark selected variant or actor",
+            Self::MarkAllVisible => "Mark all variants matching the filter and their actors",
+            Self::ClearMarks => "Clear all marks",
+            Self::BulkPoll => "Poll marked variants and actors",
+            Self::BulkDelete => "Delete marked variants",
+            Self::BulkPrompt => "Send one prompt to every marked actor",
+            Self::BulkTerminate => "Terminate marked actors",
+            Self::OpenBulkSummary => "Show last bulk action results",
+            Self::OpenNotifications => "Show notification history",
+            Self::OpenMessageLog => "Show status and error message log",
+            Self::OpenMetrics => "Show dark_core metrics and health",
+            Self::OpenCommandPalette => "Open the command palette",
+        }
+    }
+
+    /// Argument synopsis for the palette; empty when the command takes no arguments.
+    pub(crate) fn usage(self) -> &'static str {
+        match self {
+            Self::OpenBranchForm => "[name]",
+            Self::OpenSpawnForm => "[provider] [\"prompt\"]",
+            Self::BulkPrompt => "[\"prompt\"]",
+            Self::Search => "[\"query\"]",
+            _ => "",
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -46,156 +293,81 @@ pub(crate) struct ContextMenuState {
     pub(crate) anchor_col: u16,
     pub(crate) anchor_row: u16,
     pub(crate) target: VizSelection,
-    pub(crate) entries: Vec<CommandBinding>,
+    pub(crate) entries: Vec<ContextMenuEntry>,
     pub(crate) selected: usize,
 }
 
+/// A context menu row with the key hint resolved from the active keymap.
+#[derive(Debug, Clone)]
+pub(crate) struct ContextMenuEntry {
+    pub(crate) id: CommandId,
+    pub(crate) key: String,
+    pub(crate) label: &'static str,
+}
+
 #[allow(dead_code)]
-const TOOLBAR_COMMON_COMMANDS: &[CommandBinding] = &[
-    CommandBinding {
-        id: CommandId::Quit,
-        key: "q",
-        label: "Quit",
-    },
-    CommandBinding {
-        id: CommandId::ToggleFocus,
-        key: "Tab",
-        label: "Focus",
-    },
-    CommandBinding {
-        id: CommandId::MoveDown,
-        key: "↑/↓",
-        label: "Select",
-    },
-    CommandBinding {
-        id: CommandId::Refresh,
-        key: "r",
-        label: "Refresh",
-    },
-    CommandBinding {
-        id: CommandId::ToggleView,
-        key: "v",
-        label: "View",
-    },
-    CommandBinding {
-        id: CommandId::CycleVizDensity,
-        key: "z",
-        label: "Density",
-    },
-    CommandBinding {
-        id: CommandId::ToggleFilter,
-        key: "f",
-        label: "Filter",
-    },
-    CommandBinding {
-        id: CommandId::ToggleInspector,
-        key: "s",
-        label: "Sidebar",
-    },
-    CommandBinding {
-        id: CommandId::ToggleChat,
-        key: "t",
-        label: "Toggle chat",
-    },
-    CommandBinding {
-        id: CommandId::ToggleCoreLogs,
-        key: "l",
-        label: "Core logs",
-    },
-    CommandBinding {
-        id: CommandId::OpenLastLogInPager,
-        key: "L",
-        label: "Last log",
-    },
-    CommandBinding {
-        id: CommandId::OpenSshPanel,
-        key: "h",
-        label: "SSH panel",
-    },
+const TOOLBAR_COMMON_COMMANDS: &[CommandId] = &[
+    CommandId::Quit,
+    CommandId::ToggleFocus,
+    CommandId::MoveDown,
+    CommandId::Refresh,
+    CommandId::ToggleView,
+    CommandId::CycleVizDensity,
+    CommandId::ToggleFilter,
+    CommandId::Search,
+    CommandId::ToggleInspector,
+    CommandId::ToggleChat,
+    CommandId::ToggleCoreLogs,
+    CommandId::OpenLastLogInPager,
+    CommandId::OpenSshPanel,
+    CommandId::OpenNotifications,
+    CommandId::OpenMessageLog,
+    CommandId::OpenMetrics,
+    CommandId::FocusTerminal,
+    CommandId::CloseTerminal,
+    CommandId::OpenCommandPalette,
 ];
 
-const TOOLBAR_PRODUCT_COMMANDS: &[CommandBinding] = &[
-    CommandBinding {
-        id: CommandId::InitProduct,
-        key: "i",
-        label: "Init product",
-    },
-    CommandBinding {
-        id: CommandId::OpenCloneForm,
-        key: "x",
-        label: "Clone variant",
-    },
+const TOOLBAR_PRODUCT_COMMANDS: &[CommandId] = &[CommandId::InitProduct, CommandId::OpenCloneForm];
+
+const TOOLBAR_VARIANT_COMMANDS: &[CommandId] = &[
+    CommandId::OpenBranchForm,
+    CommandId::PollVariant,
+    CommandId::OpenVariantDiff,
+    CommandId::OpenIntegrateForm,
+    CommandId::OpenGitPanel,
+    CommandId::ImportVariantActors,
+    CommandId::OpenDeleteVariantForm,
+    CommandId::OpenSpawnForm,
+    CommandId::ToggleMark,
 ];
 
-const TOOLBAR_VARIANT_COMMANDS: &[CommandBinding] = &[
-    CommandBinding {
-        id: CommandId::OpenBranchForm,
-        key: "w",
-        label: "Switch branch",
-    },
-    CommandBinding {
-        id: CommandId::PollVariant,
-        key: "p",
-        label: "Poll variant",
-    },
-    CommandBinding {
-        id: CommandId::ImportVariantActors,
-        key: "m",
-        label: "Import actors",
-    },
-    CommandBinding {
-        id: CommandId::OpenDeleteVariantForm,
-        key: "d",
-        label: "Delete variant",
-    },
-    CommandBinding {
-        id: CommandId::OpenSpawnForm,
-        key: "n",
-        label: "Spawn actor",
-    },
+const TOOLBAR_SUB_AGENT_COMMANDS: &[CommandId] = &[CommandId::AbortSubAgent];
+
+const TOOLBAR_ACTOR_COMMANDS: &[CommandId] = &[
+    CommandId::RunAttach,
+    CommandId::TerminalAttach,
+    CommandId::BuildAttach,
+    CommandId::PollActor,
+    CommandId::OpenMoveActorForm,
+    CommandId::OpenChatCompose,
+    CommandId::OpenSpawnForm,
+    CommandId::ToggleMark,
 ];
 
-const TOOLBAR_ACTOR_COMMANDS: &[CommandBinding] = &[
-    CommandBinding {
-        id: CommandId::RunAttach,
-        key: "a",
-        label: "Attach",
-    },
-    CommandBinding {
-        id: CommandId::BuildAttach,
-        key: "A",
-        label: "Copy attach cmd",
-    },
-    CommandBinding {
-        id: CommandId::PollActor,
-        key: "o",
-        label: "Poll actor",
-    },
-    CommandBinding {
-        id: CommandId::OpenMoveActorForm,
-        key: "g",
-        label: "Move actor",
-    },
-    CommandBinding {
-        id: CommandId::OpenChatCompose,
-        key: "c",
-        label: "Compose",
-    },
-    CommandBinding {
-        id: CommandId::OpenSpawnForm,
-        key: "n",
-        label: "New actor",
-    },
+/// Shown while anything is marked.
+const TOOLBAR_MARKED_COMMANDS: &[CommandId] = &[
+    CommandId::BulkPoll,
+    CommandId::BulkPrompt,
+    CommandId::BulkDelete,
+    CommandId::BulkTerminate,
+    CommandId::ClearMarks,
 ];
 
-const TOOLBAR_VIZ_COMMANDS: &[CommandBinding] = &[CommandBinding {
-    id: CommandId::ResetPan,
-    key: "0",
-    label: "Reset pan",
-}];
+const TOOLBAR_VIZ_COMMANDS: &[CommandId] = &[CommandId::ResetPan];
 
-pub(crate) fn toolbar_bindings(app: &App) -> Vec<CommandBinding> {
-    let mut commands: Vec<CommandBinding> = TOOLBAR_COMMON_COMMANDS.to_vec();
+pub(crate) fn toolbar_bindings(app: &App) -> Vec<CommandId> {
+    let mut commands: Vec<CommandId> = TOOLBAR_COMMON_COMMANDS.to_vec();
 
     if app.results_view_mode().is_spatial() {
         commands.extend_from_slice(TOOLBAR_VIZ_COMMANDS);
@@ -205,67 +377,47 @@ pub(crate) fn toolbar_bindings(app: &App) -> Vec<CommandBinding> {
         ToolbarSelectionContext::Product => commands.extend_from_slice(TOOLBAR_PRODUCT_COMMANDS),
         ToolbarSelectionContext::Variant => commands.extend_from_slice(TOOLBAR_VARIANT_COMMANDS),
         ToolbarSelectionContext::Actor => commands.extend_from_slice(TOOLBAR_ACTOR_COMMANDS),
+        ToolbarSelectionContext::SubAgent => {
+            commands.extend_from_slice(TOOLBAR_SUB_AGENT_COMMANDS);
+            commands.extend_from_slice(TOOLBAR_ACTOR_COMMANDS);
+        }
+    }
+
+    if app.marked_count() > 0 {
+        commands.extend_from_slice(TOOLBAR_MARKED_COMMANDS);
     }
 
-    let mut deduped: Vec<CommandBinding> = Vec::new();
-    for binding in commands {
-        if deduped.iter().any(|existing| existing.id == binding.id) {
+    let mut deduped: Vec<CommandId> = Vec::new();
+    for command in commands {
+        if deduped.contains(&command) {
             continue;
         }
 
-        if is_command_enabled(app, binding.id) {
-            deduped.push(binding);
+        if is_command_enabled(app, command) {
+            deduped.push(command);
         }
     }
 
     deduped
 }
 
-pub(crate) fn resolve_key_command(app: &App, key: KeyEvent) -> Option<CommandId> {
-    if key.modifiers.contains(KeyModifiers::CONTROL) && key.code == KeyCode::Char('c') {
-        return Some(CommandId::Quit);
-    }
-
-    let command = match key.code {
-        KeyCode::Char('q') | KeyCode::Esc => CommandId::Quit,
-        KeyCode::Tab | KeyCode::BackTab => CommandId::ToggleFocus,
-        KeyCode::Down => CommandId::MoveDown,
-        KeyCode::Up => CommandId::MoveUp,
-        KeyCode::Char('r') => CommandId::Refresh,
-        KeyCode::Char('f') => CommandId::ToggleFilter,
-        KeyCode::Char('s') | KeyCode::Char('b') => CommandId::ToggleInspector,
-        KeyCode::Char('v') | KeyCode::Char(' ') => CommandId::ToggleView,
-        KeyCode::Char('z') => CommandId::CycleVizDensity,
-        KeyCode::Char('p') => CommandId::PollVariant,
-        KeyCode::Char('w') => CommandId::OpenBranchForm,
-        KeyCode::Char('o') => CommandId::PollActor,
-        KeyCode::Char('g') => CommandId::OpenMoveActorForm,
-        KeyCode::Char('x') => CommandId::OpenCloneForm,
-        KeyCode::Char('d') => CommandId::OpenDeleteVariantForm,
-        KeyCode::Char('m') => CommandId::ImportVariantActors,
-        KeyCode::Char('i') => CommandId::InitProduct,
-        KeyCode::Char('n') => CommandId::OpenSpawnForm,
-        KeyCode::Char('E') => CommandId::OpenVariantInExplorer,
-        KeyCode::Char('T') => CommandId::OpenVariantInTerminal,
-        KeyCode::Char('a') => CommandId::RunAttach,
-        KeyCode::Char('A') => CommandId::BuildAttach,
-        KeyCode::Char('t') => CommandId::ToggleChat,
-        KeyCode::Char('l') => CommandId::ToggleCoreLogs,
-        KeyCode::Char('L') => CommandId::OpenLastLogInPager,
-        KeyCode::Char('h') => CommandId::OpenSshPanel,
-        KeyCode::Char('c') => CommandId::OpenChatCompose,
-        KeyCode::Char('0') => CommandId::ResetPan,
-        _ => return None,
-    };
+/// Primary key for `command` in the active keymap, formatted for hints.
+pub(crate) fn command_key_hint(app: &App, command: CommandId) -> Option<String> {
+    app.keymap().hint(MAIN_MODE, KeyCommand::Dashboard(command))
+}
 
-    if is_command_enabled(app, command) {
-        Some(command)
-    } else {
-        None
-    }
+/// Key that hands focus from the embedded terminal back to the dashboard.
+pub(crate) fn detach_key_hint(app: &App) -> String {
+    popup_hint(app.keymap(), TERMINAL_MODE, &[PopupCommand::Detach])
+        .unwrap_or_else(|| PopupCommand::Detach.name().to_string())
 }
 
 pub(crate) fn is_command_enabled(app: &App, command: CommandId) -> bool {
+    command_disabled_reason(app, command).is_none()
+}
+
+/// Why a command cannot run right now, or `None` when it is available.
+pub(crate) fn command_disabled_reason(app: &App, command: CommandId) -> Option<&'static str> {
     match command {
         CommandId::Quit
         | CommandId::ToggleFocus
@@ -273,114 +425,148 @@ pub(crate) fn is_command_enabled(app: &App, command: CommandId) -> bool {
         | CommandId::MoveUp
         | CommandId::Refresh
         | CommandId::ToggleFilter
+        | CommandId::Search
         | CommandId::ToggleInspector
         | CommandId::ToggleView
         | CommandId::InitProduct
         | CommandId::ToggleChat
         | CommandId::ToggleCoreLogs
         | CommandId::OpenLastLogInPager
-        | CommandId::OpenSshPanel => true,
-        CommandId::CycleVizDensity => app.results_view_mode().is_spatial(),
-        CommandId::ResetPan => app.results_view_mode().is_spatial(),
+        | CommandId::OpenSshPanel
+        | CommandId::OpenNotifications
+        | CommandId::OpenMessageLog
+        | CommandId::OpenMetrics
+        | CommandId::OpenCommandPalette => None,
+        CommandId::CycleVizDensity | CommandId::ResetPan => {
+            (!app.results_view_mode().is_spatial()).then_some("switch to the viz view first")
+        }
         CommandId::PollVariant
         | CommandId::OpenBranchForm
         | CommandId::OpenDeleteVariantForm
         | CommandId::ImportVariantActors
         | CommandId::OpenSpawnForm
         | CommandId::OpenVariantInExplorer
-        | CommandId::OpenVariantInTerminal => app.selected_variant_id().is_some(),
-        CommandId::PollActor | CommandId::OpenMoveActorForm => app.selected_actor_id().is_some(),
-        CommandId::OpenCloneForm => app.selected_product().is_some(),
-        CommandId::BuildAttach | CommandId::RunAttach | CommandId::OpenChatCompose => {
-            app.selected_actor_id().is_some()
+        | CommandId::OpenVariantInTerminal
+        | CommandId::OpenVariantDiff
+        | CommandId::OpenIntegrateForm
+        | CommandId::OpenGitPanel => app
+            .selected_variant_id()
+            .is_none()
+            .then_some("select a variant first"),
+        CommandId::PollActor
+        | CommandId::OpenMoveActorForm
+        | CommandId::BuildAttach
+        | CommandId::RunAttach => app
+            .selected_actor_id()
+            .is_none()
+            .then_some("select an actor first"),
+        CommandId::OpenChatCompose => {
+            if app.selected_actor_id().is_none() {
+                Some("select an actor first")
+            } else {
+                app.selected_sub_agent()
+                    .is_some()
+                    .then_some("sub-agent transcripts are read-only")
+            }
+        }
+        CommandId::AbortSubAgent => app
+            .selected_sub_agent()
+            .is_none()
+            .then_some("select a sub-agent first"),
+        CommandId::TerminalAttach => app
+            .selected_actor_id()
+            .is_none()
+            .then_some("select an actor first"),
+        CommandId::TerminalShell => app
+            .selected_variant_id()
+            .is_none()
+            .then_some("select a variant first"),
+        CommandId::FocusTerminal | CommandId::CloseTerminal => {
+            (!app.is_terminal_pane_open()).then_some("no embedded terminal is open")
         }
+        CommandId::OpenCloneForm => app
+            .selected_product()
+            .is_none()
+            .then_some("select a product first"),
+        CommandId::ToggleMark => match app.viz_selection() {
+            Some(VizSelection::Variant { .. } | VizSelection::Actor { .. }) => None,
+            _ => Some("select a variant or actor first"),
+        },
+        CommandId::MarkAllVisible => app
+            .visible_variants()
+            .is_empty()
+            .then_some("no variants match the filter"),
+        CommandId::ClearMarks => (app.marked_count() == 0).then_some("nothing is marked"),
+        CommandId::BulkPoll => {
+            if app.marked_count() == 0 {
+                Some("mark variants or actors first")
+            } else {
+                bulk_run_busy(app)
+            }
+        }
+        CommandId::BulkDelete => {
+            if app.marked_variant_ids().is_empty() {
+                Some("mark variants first")
+            } else {
+                bulk_run_busy(app)
+            }
+        }
+        CommandId::BulkPrompt | CommandId::BulkTerminate => {
+            if app.marked_actors().is_empty() {
+                Some("mark actors first")
+            } else {
+                bulk_run_busy(app)
+            }
+        }
+        CommandId::OpenBulkSummary => app
+            .bulk_run()
+            .is_none()
+            .then_some("no bulk action has run yet"),
     }
 }
 
-pub(crate) fn context_menu_commands(app: &App, target: &VizSelection) -> Vec<CommandBinding> {
-    let entries: &[CommandBinding] = match target {
-        VizSelection::Product { .. } => &[
-            CommandBinding {
-                id: CommandId::InitProduct,
-                key: "i",
-                label: "Init product",
-            },
-            CommandBinding {
-                id: CommandId::OpenCloneForm,
-                key: "x",
-                label: "Clone variant",
-            },
-        ],
+fn bulk_run_busy(app: &App) -> Option<&'static str> {
+    app.is_bulk_run_active()
+        .then_some("a bulk action is still running")
+}
+
+pub(crate) fn context_menu_commands(app: &App, target: &VizSelection) -> Vec<CommandId> {
+    let commands: &[CommandId] = match target {
+        VizSelection::Product { .. } => &[CommandId::InitProduct, CommandId::OpenCloneForm],
         VizSelection::Variant { .. } => &[
-            CommandBinding {
-                id: CommandId::OpenBranchForm,
-                key: "w",
-                label: "Switch branch",
-            },
-            CommandBinding {
-                id: CommandId::OpenSpawnForm,
-                key: "n",
-                label: "Spawn actor",
-            },
-            CommandBinding {
-                id: CommandId::OpenVariantInExplorer,
-                key: "E",
-                label: "Open in explorer",
-            },
-            CommandBinding {
-                id: CommandId::OpenVariantInTerminal,
-                key: "T",
-                label: "Open in terminal",
-            },
-            CommandBinding {
-                id: CommandId::PollVariant,
-                key: "p",
-                label: "Poll variant",
-            },
-            CommandBinding {
-                id: CommandId::ImportVariantActors,
-                key: "m",
-                label: "Import actors",
-            },
-            CommandBinding {
-                id: CommandId::OpenDeleteVariantForm,
-                key: "d",
-                label: "Delete variant",
-            },
+            CommandId::OpenBranchForm,
+            CommandId::OpenSpawnForm,
+            CommandId::OpenVariantInExplorer,
+            CommandId::OpenVariantInTerminal,
+            CommandId::TerminalShell,
+            CommandId::OpenVariantDiff,
+            CommandId::OpenIntegrateForm,
+            CommandId::OpenGitPanel,
+            CommandId::PollVariant,
+            CommandId::ImportVariantActors,
+            CommandId::OpenDeleteVariantForm,
+            CommandId::ToggleMark,
         ],
         VizSelection::Actor { .. } => &[
-            CommandBinding {
-                id: CommandId::RunAttach,
-                key: "a",
-                label: "Attach",
-            },
-            CommandBinding {
-                id: CommandId::PollActor,
-                key: "o",
-                label: "Poll actor",
-            },
-            CommandBinding {
-                id: CommandId::OpenMoveActorForm,
-                key: "g",
-                label: "Move actor",
-            },
-            CommandBinding {
-                id: CommandId::OpenChatCompose,
-                key: "c",
-                label: "Compose",
-            },
-            CommandBinding {
-                id: CommandId::OpenSpawnForm,
-                key: "n",
-                label: "Spawn actor",
-            },
+            CommandId::RunAttach,
+            CommandId::TerminalAttach,
+            CommandId::PollActor,
+            CommandId::OpenMoveActorForm,
+            CommandId::OpenChatCompose,
+            CommandId::OpenSpawnForm,
+            CommandId::ToggleMark,
+        ],
+        VizSelection::SubAgent { .. } => &[
+            CommandId::ToggleChat,
+            CommandId::AbortSubAgent,
+            CommandId::PollActor,
         ],
     };
 
-    entries
+    commands
         .iter()
         .copied()
-        .filter(|entry| is_command_enabled(app, entry.id))
+        .filter(|command| is_command_enabled(app, *command))
         .collect()
 }
 
@@ -391,7 +577,14 @@ impl ContextMenuState {
         anchor_col: u16,
         anchor_row: u16,
     ) -> Option<Self> {
-        let entries = context_menu_commands(app, &target);
+        let entries: Vec<ContextMenuEntry> = context_menu_commands(app, &target)
+            .into_iter()
+            .map(|command| ContextMenuEntry {
+                id: command,
+                key: command_key_hint(app, command).unwrap_or_default(),
+                label: command.label(),
+            })
+            .collect();
         if entries.is_empty() {
             return None;
         }
@@ -440,15 +633,20 @@ impl ContextMenuState {
         self.entries.get(self.selected).map(|entry| entry.id)
     }
 
-    pub(crate) fn shortcut_command(&self, key: KeyEvent) -> Option<CommandId> {
-        let KeyCode::Char(pressed) = key.code else {
-            return None;
-        };
-
-        let pressed = pressed.to_ascii_lowercase();
+    /// Entry bound to exactly this key press; sequences are not used inside the menu.
+    pub(crate) fn shortcut_command(
+        &self,
+        keymap: &Keymap<KeyCommand>,
+        key: KeyEvent,
+    ) -> Option<CommandId> {
+        let pressed = KeyChord::from_event(key);
         self.entries
             .iter()
-            .find(|entry| entry.key.eq_ignore_ascii_case(&pressed.to_string()))
+            .find(|entry| {
+                keymap
+                    .sequences(MAIN_MODE, KeyCommand::Dashboard(entry.id))
+                    .any(|sequence| sequence.chords() == [pressed])
+            })
             .map(|entry| entry.id)
     }
 }
@@ -458,6 +656,7 @@ enum ToolbarSelectionContext {
     Product,
     Variant,
     Actor,
+    SubAgent,
 }
 
 fn toolbar_selection_context(app: &App) -> ToolbarSelectionContext {
@@ -467,6 +666,7 @@ fn toolbar_selection_context(app: &App) -> ToolbarSelectionContext {
                 VizSelection::Product { .. } => ToolbarSelectionContext::Product,
                 VizSelection::Variant { .. } => ToolbarSelectionContext::Variant,
                 VizSelection::Actor { .. } => ToolbarSelectionContext::Actor,
+                VizSelection::SubAgent { .. } => ToolbarSelectionContext::SubAgent,
             };
         }
     }
@@ -476,3 +676,295 @@ fn toolbar_selection_context(app: &App) -> ToolbarSelectionContext {
         FocusPane::Variants => ToolbarSelectionContext::Variant,
     }
 }
+
+/// Every command except the palette itself, in catalog order.
+fn palette_commands() -> impl Iterator<Item = CommandId> {
+    CommandId::ALL
+        .iter()
+        .copied()
+        .filter(|command| *command != CommandId::OpenCommandPalette)
+}
+
+/// One row in the palette: a recent command line or a catalog command.
+#[derive(Debug, Clone)]
+pub(crate) enum PaletteItem {
+    Recent(String),
+    Command {
+        id: CommandId,
+        disabled_reason: Option<&'static str>,
+    },
+}
+
+impl PaletteItem {
+    /// Text placed in the input when the item is completed with Tab.
+    pub(crate) fn completion(&self) -> String {
+        match self {
+            Self::Recent(line) => line.clone(),
+            Self::Command { id, .. } if id.usage().is_empty() => id.name().to_string(),
+            Self::Command { id, .. } => format!("{} ", id.name()),
+        }
+    }
+}
+
+/// A resolved palette command plus its positional arguments.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct PaletteInvocation {
+    pub(crate) command: CommandId,
+    pub(crate) args: Vec<String>,
+}
+
+/// Rows shown for the current palette input.
+///
+/// An empty query lists recent command lines first, then the whole catalog.
+/// Otherwise commands are ranked by fuzzy score against the first word, so
+/// disabled commands still show up together with the reason they are disabled.
+pub(crate) fn palette_items(app: &App, input: &str, history: &[String]) -> Vec<PaletteItem> {
+    let query = input.split_whitespace().next().unwrap_or("");
+    let command_item = |id: CommandId| PaletteItem::Command {
+        id,
+        disabled_reason: command_disabled_reason(app, id),
+    };
+
+    if query.is_empty() {
+        return history
+            .iter()
+            .map(|line| PaletteItem::Recent(line.clone()))
+            .chain(palette_commands().map(command_item))
+            .collect();
+    }
+
+    let recency = |name: &str| {
+        history
+            .iter()
+            .position(|line| line.split_whitespace().next() == Some(name))
+            .unwrap_or(usize::MAX)
+    };
+
+    let mut scored: Vec<(i32, usize, CommandId)> = palette_commands()
+        .filter_map(|id| {
+            let name_score = fuzzy_score(query, id.name()).map(|score| score + 10);
+            let label_score = fuzzy_score(query, id.description());
+            name_score
+                .max(label_score)
+                .map(|score| (score, recency(id.name()), id))
+        })
+        .collect();
+    scored.sort_by(|left, right| right.0.cmp(&left.0).then(left.1.cmp(&right.1)));
+
+    scored
+        .into_iter()
+        .map(|(_, _, id)| command_item(id))
+        .collect()
+}
+
+/// Resolves a command line typed into the palette.
+///
+/// The first word names the command: an exact name wins, otherwise the
+/// highlighted fuzzy match is used. Remaining words are arguments, with
+/// double quotes grouping words that contain spaces.
+pub(crate) fn resolve_palette_line(
+    app: &App,
+    line: &str,
+    highlighted: Option<&PaletteItem>,
+) -> Result<PaletteInvocation, String> {
+    let mut words = split_command_line(line)?;
+    if words.is_empty() {
+        return match highlighted {
+            Some(PaletteItem::Recent(recent)) => resolve_palette_line(app, recent, None),
+            Some(PaletteItem::Command { id, .. }) => finish_invocation(app, *id, Vec::new()),
+            None => Err("Type a command name.".to_string()),
+        };
+    }
+
+    let name = words.remove(0);
+    let id = palette_commands()
+        .find(|id| id.name().eq_ignore_ascii_case(&name))
+        .or(match highlighted {
+            Some(PaletteItem::Command { id, .. }) => Some(*id),
+            _ => None,
+        })
+        .ok_or_else(|| format!("Unknown command `{name}`."))?;
+
+    finish_invocation(app, id, words)
+}
+
+fn finish_invocation(
+    app: &App,
+    id: CommandId,
+    args: Vec<String>,
+) -> Result<PaletteInvocation, String> {
+    if let Some(reason) = command_disabled_reason(app, id) {
+        return Err(format!("`{}` unavailable: {reason}.", id.name()));
+    }
+
+    let max_args = match id {
+        CommandId::OpenBranchForm => 1,
+        CommandId::OpenSpawnForm => 2,
+        CommandId::BulkPrompt => 1,
+        CommandId::Search => 1,
+        _ => 0,
+    };
+    if args.len() > max_args {
+        return Err(if max_args == 0 {
+            format!("`{}` takes no arguments.", id.name())
+        } else {
+            format!("Usage: {} {}", id.name(), id.usage())
+        });
+    }
+
+    Ok(PaletteInvocation { command: id, args })
+}
+
+/// Splits a command line on whitespace, keeping double-quoted text together.
+pub(crate) fn split_command_line(line: &str) -> Result<Vec<String>, String> {
+    let mut words = Vec::new();
+    let mut current = String::new();
+    let mut in_quotes = false;
+    let mut has_word = false;
+
+    for ch in line.chars() {
+        match ch {
+            '"' => {
+                in_quotes = !in_quotes;
+                has_word = true;
+            }
+            ch if ch.is_whitespace() && !in_quotes => {
+                if has_word {
+                    words.push(std::mem::take(&mut current));
+                    has_word = false;
+                }
+            }
+            ch => {
+                current.push(ch);
+                has_word = true;
+            }
+        }
+    }
+
+    if in_quotes {
+        return Err("Unterminated quote in command line.".to_string());
+    }
+    if has_word {
+        words.push(current);
+    }
+
+    Ok(words)
+}
+
+/// Subsequence match score; `None` when `query` is not a subsequence of `candidate`.
+///
+/// Consecutive characters and matches at word starts score higher so `gp`
+/// prefers `poll-actor` over scattered hits.
+pub(crate) fn fuzzy_score(query: &str, candidate: &str) -> Option<i32> {
+    let candidate: Vec<char> = candidate.to_lowercase().chars().collect();
+    let mut score = 0;
+    let mut position = 0;
+    let mut previous_match: Option<usize> = None;
+
+    for wanted in query.to_lowercase().chars() {
+        let offset = candidate[position..].iter().position(|ch| *ch == wanted)?;
+        let index = position + offset;
+
+        score += 1;
+        if previous_match.is_some_and(|previous| previous + 1 == index) {
+            score += 5;
+        }
+        if index == 0 || matches!(candidate[index - 1], ' ' | '-' | '_' | '/') {
+            score += 8;
+        }
+        score -= offset.min(5) as i32;
+
+        previous_match = Some(index);
+        position = index + 1;
+    }
+
+    Some(score)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        CommandId, PaletteInvocation, PaletteItem, fuzzy_score, palette_items,
+        resolve_palette_line, split_command_line,
+    };
+    use crate::app::App;
+    use crate::theme::Theme;
+
+    #[test]
+    fn palette_lists_disabled_commands_with_reason_and_rejects_running_them() {
+        let app = App::new(".".to_string(), 5, Theme::default());
+        let items = palette_items(&app, "bra", &[]);
+
+        let Some(PaletteItem::Command {
+            id,
+            disabled_reason,
+        }) = items.first()
+        else {
+            panic!("expected a command match");
+        };
+        assert_eq!(*id, CommandId::OpenBranchForm);
+        assert_eq!(*disabled_reason, Some("select a variant first"));
+
+        assert_eq!(
+            resolve_palette_line(&app, "branch feature/x", None),
+            Err("`branch` unavailable: select a variant first.".to_string())
+        );
+    }
+
+    #[test]
+    fn palette_resolves_fuzzy_names_history_and_argument_counts() {
+        let app = App::new(".".to_string(), 5, Theme::default());
+        let history = vec!["refresh".to_string()];
+
+        let items = palette_items(&app, "rfsh", &history);
+        assert_eq!(
+            resolve_palette_line(&app, "rfsh", items.first()),
+            Ok(PaletteInvocation {
+                command: CommandId::Refresh,
+                args: Vec::new(),
+            })
+        );
+
+        let items = palette_items(&app, "", &history);
+        assert!(matches!(items.first(), Some(PaletteItem::Recent(line)) if line == "refresh"));
+        assert_eq!(
+            resolve_palette_line(&app, "", items.first()).map(|found| found.command),
+            Ok(CommandId::Refresh)
+        );
+
+        assert_eq!(
+            resolve_palette_line(&app, "refresh now", None),
+            Err("`refresh` takes no arguments.".to_string())
+        );
+        assert_eq!(
+            resolve_palette_line(&app, "nope", None),
+            Err("Unknown command `nope`.".to_string())
+        );
+    }
+
+    #[test]
+    fn fuzzy_score_prefers_prefix_and_word_start_matches() {
+        assert!(fuzzy_score("xyz", "integrate").is_none());
+        let prefix = fuzzy_score("int", "integrate").expect("prefix match");
+        let scattered = fuzzy_score("int", "switch variant branch").expect("scattered match");
+        assert!(prefix > scattered);
+        assert!(fuzzy_score("pa", "poll-actor") > fuzzy_score("pa", "sidebar"));
+    }
+
+    #[test]
+    fn split_command_line_groups_quoted_arguments() {
+        assert_eq!(
+            split_command_line("spawn opencode \"fix tests\""),
+            Ok(vec![
+                "spawn".to_string(),
+                "opencode".to_string(),
+                "fix tests".to_string()
+            ])
+        );
+        assert_eq!(
+            split_command_line("  branch   feature/x "),
+            Ok(vec!["branch".to_string(), "feature/x".to_string()])
+        );
+        assert!(split_command_line("spawn \"open").is_err());
+    }
+}
@@ -0,0 +1,655 @@
+use dark_tui_components::{
+    Form, FormCommand, KeyMatch, Keymap, KeymapCommand, KeymapError, KeymapPreset, KeymapProfile,
+};
+
+use super::command_palette::CommandId;
+
+/// Section name of dark_tui bindings in the shared keymap file.
+pub(crate) const KEYMAP_SECTION: &str = "dark_tui";
+
+/// Dashboard keys: palette commands while no popup, form or focused pane takes keys.
+pub(crate) const MAIN_MODE: &str = "main";
+/// Spawn, init product, clone, branch, delete and move actor forms.
+pub(crate) const FORM_MODE: &str = "form";
+/// The SSH hosts and port forwards panel (a form with letter shortcuts).
+pub(crate) const SSH_MODE: &str = "ssh";
+pub(crate) const PALETTE_MODE: &str = "palette";
+/// Typing a catalog or core log search.
+pub(crate) const SEARCH_MODE: &str = "search";
+/// Single-line prompts: the bulk prompt and the git commit message.
+pub(crate) const PROMPT_MODE: &str = "prompt";
+/// Composing a chat message.
+pub(crate) const COMPOSE_MODE: &str = "compose";
+/// Filterable pickers: clone hosts and chat models/agents.
+pub(crate) const PICKER_MODE: &str = "picker";
+/// Right-click context menu; other keys run the entry bound to them in `main`.
+pub(crate) const MENU_MODE: &str = "menu";
+pub(crate) const INTEGRATE_MODE: &str = "integrate";
+pub(crate) const GIT_MODE: &str = "git";
+/// Focused core log viewer.
+pub(crate) const LOGS_MODE: &str = "logs";
+/// Focused embedded terminal; unbound keys go to the PTY.
+pub(crate) const TERMINAL_MODE: &str = "terminal";
+/// Chat message detail popup.
+pub(crate) const DETAIL_MODE: &str = "detail";
+/// Chat prompt queue popup.
+pub(crate) const QUEUE_MODE: &str = "queue";
+pub(crate) const NOTIFICATIONS_MODE: &str = "notifications";
+pub(crate) const MESSAGES_MODE: &str = "messages";
+pub(crate) const BULK_RESULTS_MODE: &str = "bulk-results";
+pub(crate) const METRICS_MODE: &str = "metrics";
+pub(crate) const DIFF_MODE: &str = "diff";
+
+const MODES: &[&str] = &[
+    MAIN_MODE,
+    FORM_MODE,
+    SSH_MODE,
+    PALETTE_MODE,
+    SEARCH_MODE,
+    PROMPT_MODE,
+    COMPOSE_MODE,
+    PICKER_MODE,
+    MENU_MODE,
+    INTEGRATE_MODE,
+    GIT_MODE,
+    LOGS_MODE,
+    TERMINAL_MODE,
+    DETAIL_MODE,
+    QUEUE_MODE,
+    NOTIFICATIONS_MODE,
+    MESSAGES_MODE,
+    BULK_RESULTS_MODE,
+    METRICS_MODE,
+    DIFF_MODE,
+];
+
+/// Keys of popups, forms and focused panes; the same name can mean a different
+/// action in each mode (`confirm` commits in `git` and jumps in `logs`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum PopupCommand {
+    Close,
+    Confirm,
+    Down,
+    Up,
+    PageDown,
+    PageUp,
+    Top,
+    Bottom,
+    NextField,
+    PreviousField,
+    Toggle,
+    Clear,
+    Complete,
+    Paste,
+    Refresh,
+    Search,
+    NextMatch,
+    PreviousMatch,
+    Follow,
+    Level,
+    Module,
+    ToggleLogs,
+    Strategy,
+    After,
+    Preview,
+    EditMessage,
+    DraftMessage,
+    Stash,
+    Unstash,
+    Fetch,
+    Push,
+    NextRemote,
+    Reset,
+    CopyAttach,
+    Attach,
+    EnsureTmux,
+    CopyAgentAttach,
+    AgentAttach,
+    MoveUp,
+    MoveDown,
+    Remove,
+    Edit,
+    Detach,
+    ScrollDown,
+    ScrollUp,
+    Layout,
+    ToggleBase,
+    Sort,
+    NextColumn,
+    PreviousColumn,
+    Widen,
+    Narrow,
+}
+
+impl PopupCommand {
+    pub(crate) const ALL: &'static [PopupCommand] = &[
+        Self::Close,
+        Self::Confirm,
+        Self::Down,
+        Self::Up,
+        Self::PageDown,
+        Self::PageUp,
+        Self::Top,
+        Self::Bottom,
+        Self::NextField,
+        Self::PreviousField,
+        Self::Toggle,
+        Self::Clear,
+        Self::Complete,
+        Self::Paste,
+        Self::Refresh,
+        Self::Search,
+        Self::NextMatch,
+        Self::PreviousMatch,
+        Self::Follow,
+        Self::Level,
+        Self::Module,
+        Self::ToggleLogs,
+        Self::Strategy,
+        Self::After,
+        Self::Preview,
+        Self::EditMessage,
+        Self::DraftMessage,
+        Self::Stash,
+        Self::Unstash,
+        Self::Fetch,
+        Self::Push,
+        Self::NextRemote,
+        Self::Reset,
+        Self::CopyAttach,
+        Self::Attach,
+        Self::EnsureTmux,
+        Self::CopyAgentAttach,
+        Self::AgentAttach,
+        Self::MoveUp,
+        Self::MoveDown,
+        Self::Remove,
+        Self::Edit,
+        Self::Detach,
+        Self::ScrollDown,
+        Self::ScrollUp,
+        Self::Layout,
+        Self::ToggleBase,
+        Self::Sort,
+        Self::NextColumn,
+        Self::PreviousColumn,
+        Self::Widen,
+        Self::Narrow,
+    ];
+
+    /// Name used in the keymap file's popup and form tables.
+    pub(crate) fn name(self) -> &'static str {
+        match self {
+            Self::Close => "close",
+            Self::Confirm => "confirm",
+            Self::Down => "down",
+            Self::Up => "up",
+            Self::PageDown => "page-down",
+            Self::PageUp => "page-up",
+            Self::Top => "top",
+            Self::Bottom => "bottom",
+            Self::NextField => "next-field",
+            Self::PreviousField => "previous-field",
+            Self::Toggle => "toggle",
+            Self::Clear => "clear",
+            Self::Complete => "complete",
+            Self::Paste => "paste",
+            Self::Refresh => "refresh",
+            Self::Search => "search",
+            Self::NextMatch => "next-match",
+            Self::PreviousMatch => "previous-match",
+            Self::Follow => "follow",
+            Self::Level => "level",
+            Self::Module => "module",
+            Self::ToggleLogs => "logs",
+            Self::Strategy => "strategy",
+            Self::After => "after",
+            Self::Preview => "preview",
+            Self::EditMessage => "message",
+            Self::DraftMessage => "draft",
+            Self::Stash => "stash",
+            Self::Unstash => "unstash",
+            Self::Fetch => "fetch",
+            Self::Push => "push",
+            Self::NextRemote => "remote",
+            Self::Reset => "reset",
+            Self::CopyAttach => "copy-attach",
+            Self::Attach => "attach",
+            Self::EnsureTmux => "tmux",
+            Self::CopyAgentAttach => "copy-agent-attach",
+            Self::AgentAttach => "agent-attach",
+            Self::MoveUp => "move-up",
+            Self::MoveDown => "move-down",
+            Self::Remove => "remove",
+            Self::Edit => "edit",
+            Self::Detach => "detach",
+            Self::ScrollDown => "scroll-down",
+            Self::ScrollUp => "scroll-up",
+            Self::Layout => "layout",
+            Self::ToggleBase => "base",
+            Self::Sort => "sort",
+            Self::NextColumn => "next-column",
+            Self::PreviousColumn => "previous-column",
+            Self::Widen => "widen",
+            Self::Narrow => "narrow",
+        }
+    }
+}
+
+/// Popup commands that drive a `Form`.
+const FORM_COMMANDS: &[(PopupCommand, FormCommand)] = &[
+    (PopupCommand::Close, FormCommand::Cancel),
+    (PopupCommand::Confirm, FormCommand::Submit),
+    (PopupCommand::NextField, FormCommand::NextField),
+    (PopupCommand::PreviousField, FormCommand::PreviousField),
+    (PopupCommand::Toggle, FormCommand::Toggle),
+];
+
+impl PopupCommand {
+    pub(crate) fn form_command(self) -> Option<FormCommand> {
+        FORM_COMMANDS
+            .iter()
+            .find(|(popup, _)| *popup == self)
+            .map(|(_, command)| *command)
+    }
+}
+
+/// A bound command: a palette command in `main`, a popup command elsewhere.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum KeyCommand {
+    Dashboard(CommandId),
+    Popup(PopupCommand),
+}
+
+use KeyCommand::{Dashboard, Popup};
+use PopupCommand as P;
+
+const DEFAULT_BINDINGS: &[(&str, KeyCommand, &[&str])] = &[
+    (MAIN_MODE, Dashboard(CommandId::Quit), &["q", "esc"]),
+    (MAIN_MODE, Dashboard(CommandId::ToggleFocus), &["tab"]),
+    (MAIN_MODE, Dashboard(CommandId::MoveDown), &["down"]),
+    (MAIN_MODE, Dashboard(CommandId::MoveUp), &["up"]),
+    (MAIN_MODE, Dashboard(CommandId::Refresh), &["r"]),
+    (MAIN_MODE, Dashboard(CommandId::ToggleFilter), &["f"]),
+    (MAIN_MODE, Dashboard(CommandId::Search), &["/"]),
+    (
+        MAIN_MODE,
+        Dashboard(CommandId::ToggleInspector),
+        &["s", "b"],
+    ),
+    (MAIN_MODE, Dashboard(CommandId::ToggleView), &["v"]),
+    (MAIN_MODE, Dashboard(CommandId::CycleVizDensity), &["z"]),
+    (MAIN_MODE, Dashboard(CommandId::PollVariant), &["p"]),
+    (MAIN_MODE, Dashboard(CommandId::OpenBranchForm), &["w"]),
+    (MAIN_MODE, Dashboard(CommandId::PollActor), &["o"]),
+    (MAIN_MODE, Dashboard(CommandId::AbortSubAgent), &["ctrl+k"]),
+    (MAIN_MODE, Dashboard(CommandId::OpenMoveActorForm), &["g"]),
+    (MAIN_MODE, Dashboard(CommandId::OpenCloneForm), &["x"]),
+    (
+        MAIN_MODE,
+        Dashboard(CommandId::OpenDeleteVariantForm),
+        &["d"],
+    ),
+    (MAIN_MODE, Dashboard(CommandId::ImportVariantActors), &["m"]),
+    (MAIN_MODE, Dashboard(CommandId::InitProduct), &["i"]),
+    (MAIN_MODE, Dashboard(CommandId::OpenSpawnForm), &["n"]),
+    (
+        MAIN_MODE,
+        Dashboard(CommandId::OpenVariantInExplorer),
+        &["E"],
+    ),
+    (
+        MAIN_MODE,
+        Dashboard(CommandId::OpenVariantInTerminal),
+        &["T"],
+    ),
+    (MAIN_MODE, Dashboard(CommandId::OpenVariantDiff), &["D"]),
+    (MAIN_MODE, Dashboard(CommandId::OpenIntegrateForm), &["I"]),
+    (MAIN_MODE, Dashboard(CommandId::OpenGitPanel), &["G"]),
+    (MAIN_MODE, Dashboard(CommandId::RunAttach), &["a"]),
+    (MAIN_MODE, Dashboard(CommandId::BuildAttach), &["A"]),
+    (MAIN_MODE, Dashboard(CommandId::TerminalAttach), &["e"]),
+    (MAIN_MODE, Dashboard(CommandId::TerminalShell), &["S"]),
+    (MAIN_MODE, Dashboard(CommandId::FocusTerminal), &["`"]),
+    (MAIN_MODE, Dashboard(CommandId::CloseTerminal), &["C"]),
+    (MAIN_MODE, Dashboard(CommandId::ToggleChat), &["t"]),
+    (MAIN_MODE, Dashboard(CommandId::ToggleCoreLogs), &["l"]),
+    (MAIN_MODE, Dashboard(CommandId::OpenLastLogInPager), &["L"]),
+    (MAIN_MODE, Dashboard(CommandId::OpenSshPanel), &["h"]),
+    (MAIN_MODE, Dashboard(CommandId::OpenChatCompose), &["c"]),
+    (MAIN_MODE, Dashboard(CommandId::ResetPan), &["0"]),
+    (MAIN_MODE, Dashboard(CommandId::ToggleMark), &["space"]),
+    (MAIN_MODE, Dashboard(CommandId::MarkAllVisible), &["*"]),
+    (MAIN_MODE, Dashboard(CommandId::ClearMarks), &["u"]),
+    (MAIN_MODE, Dashboard(CommandId::BulkPoll), &["P"]),
+    (MAIN_MODE, Dashboard(CommandId::BulkDelete), &["X"]),
+    (MAIN_MODE, Dashboard(CommandId::BulkPrompt), &["B"]),
+    (MAIN_MODE, Dashboard(CommandId::BulkTerminate), &["K"]),
+    (MAIN_MODE, Dashboard(CommandId::OpenBulkSummary), &["R"]),
+    (MAIN_MODE, Dashboard(CommandId::OpenNotifications), &["N"]),
+    (MAIN_MODE, Dashboard(CommandId::OpenMessageLog), &["!"]),
+    (MAIN_MODE, Dashboard(CommandId::OpenMetrics), &["H"]),
+    (
+        MAIN_MODE,
+        Dashboard(CommandId::OpenCommandPalette),
+        &[":", "ctrl+p"],
+    ),
+    (FORM_MODE, Popup(P::Close), &["esc"]),
+    (FORM_MODE, Popup(P::Confirm), &["enter"]),
+    (FORM_MODE, Popup(P::NextField), &["tab"]),
+    (FORM_MODE, Popup(P::PreviousField), &["shift+tab"]),
+    (FORM_MODE, Popup(P::Toggle), &["space"]),
+    (SSH_MODE, Popup(P::Close), &["esc"]),
+    (SSH_MODE, Popup(P::Confirm), &["enter"]),
+    (SSH_MODE, Popup(P::NextField), &["tab"]),
+    (SSH_MODE, Popup(P::PreviousField), &["shift+tab"]),
+    (SSH_MODE, Popup(P::CopyAttach), &["c"]),
+    (SSH_MODE, Popup(P::Attach), &["a"]),
+    (SSH_MODE, Popup(P::EnsureTmux), &["g"]),
+    (SSH_MODE, Popup(P::CopyAgentAttach), &["o"]),
+    (SSH_MODE, Popup(P::AgentAttach), &["A"]),
+    (PALETTE_MODE, Popup(P::Close), &["esc"]),
+    (PALETTE_MODE, Popup(P::Confirm), &["enter"]),
+    (PALETTE_MODE, Popup(P::Up), &["up", "ctrl+p"]),
+    (PALETTE_MODE, Popup(P::Down), &["down", "ctrl+n"]),
+    (PALETTE_MODE, Popup(P::Complete), &["tab"]),
+    (SEARCH_MODE, Popup(P::Close), &["esc"]),
+    (SEARCH_MODE, Popup(P::Confirm), &["enter"]),
+    (SEARCH_MODE, Popup(P::Clear), &["ctrl+u"]),
+    (PROMPT_MODE, Popup(P::Close), &["esc"]),
+    (PROMPT_MODE, Popup(P::Confirm), &["enter"]),
+    (COMPOSE_MODE, Popup(P::Close), &["esc"]),
+    (COMPOSE_MODE, Popup(P::Confirm), &["enter"]),
+    (COMPOSE_MODE, Popup(P::Paste), &["ctrl+v"]),
+    (PICKER_MODE, Popup(P::Close), &["esc"]),
+    (PICKER_MODE, Popup(P::Confirm), &["enter"]),
+    (PICKER_MODE, Popup(P::Up), &["up"]),
+    (PICKER_MODE, Popup(P::Down), &["down"]),
+    (PICKER_MODE, Popup(P::Clear), &["ctrl+u"]),
+    (MENU_MODE, Popup(P::Close), &["esc"]),
+    (MENU_MODE, Popup(P::Confirm), &["enter"]),
+    (MENU_MODE, Popup(P::Up), &["up"]),
+    (MENU_MODE, Popup(P::Down), &["down"]),
+    (INTEGRATE_MODE, Popup(P::Close), &["esc"]),
+    (INTEGRATE_MODE, Popup(P::Confirm), &["enter"]),
+    (INTEGRATE_MODE, Popup(P::Strategy), &["tab", "s"]),
+    (INTEGRATE_MODE, Popup(P::After), &["a"]),
+    (INTEGRATE_MODE, Popup(P::Preview), &["r"]),
+    (GIT_MODE, Popup(P::Close), &["esc", "q"]),
+    (GIT_MODE, Popup(P::EditMessage), &["c"]),
+    (GIT_MODE, Popup(P::DraftMessage), &["A"]),
+    (GIT_MODE, Popup(P::Confirm), &["enter"]),
+    (GIT_MODE, Popup(P::Stash), &["s"]),
+    (GIT_MODE, Popup(P::Unstash), &["u"]),
+    (GIT_MODE, Popup(P::Fetch), &["f"]),
+    (GIT_MODE, Popup(P::Push), &["p"]),
+    (GIT_MODE, Popup(P::Refresh), &["r"]),
+    (GIT_MODE, Popup(P::NextRemote), &["tab"]),
+    (GIT_MODE, Popup(P::Reset), &["R"]),
+    (LOGS_MODE, Popup(P::Close), &["esc", "q"]),
+    (LOGS_MODE, Popup(P::ToggleLogs), &["l"]),
+    (LOGS_MODE, Popup(P::Down), &["down", "j"]),
+    (LOGS_MODE, Popup(P::Up), &["up", "k"]),
+    (LOGS_MODE, Popup(P::PageDown), &["pagedown"]),
+    (LOGS_MODE, Popup(P::PageUp), &["pageup"]),
+    (LOGS_MODE, Popup(P::Top), &["home", "g"]),
+    (LOGS_MODE, Popup(P::Bottom), &["end", "G"]),
+    (LOGS_MODE, Popup(P::Follow), &["f", "space"]),
+    (LOGS_MODE, Popup(P::Level), &["v"]),
+    (LOGS_MODE, Popup(P::Module), &["m"]),
+    (LOGS_MODE, Popup(P::Search), &["/"]),
+    (LOGS_MODE, Popup(P::NextMatch), &["n"]),
+    (LOGS_MODE, Popup(P::PreviousMatch), &["N"]),
+    (LOGS_MODE, Popup(P::Clear), &["c"]),
+    (LOGS_MODE, Popup(P::Confirm), &["enter"]),
+    (TERMINAL_MODE, Popup(P::Detach), &["ctrl+]", "ctrl+5"]),
+    (TERMINAL_MODE, Popup(P::PageUp), &["shift+pageup"]),
+    (TERMINAL_MODE, Popup(P::PageDown), &["shift+pagedown"]),
+    (DETAIL_MODE, Popup(P::Close), &["esc", "q"]),
+    (DETAIL_MODE, Popup(P::Up), &["up"]),
+    (DETAIL_MODE, Popup(P::Down), &["down"]),
+    (QUEUE_MODE, Popup(P::Close), &["esc", "q"]),
+    (QUEUE_MODE, Popup(P::Up), &["up", "k"]),
+    (QUEUE_MODE, Popup(P::Down), &["down", "j"]),
+    (QUEUE_MODE, Popup(P::MoveUp), &["K"]),
+    (QUEUE_MODE, Popup(P::MoveDown), &["J"]),
+    (QUEUE_MODE, Popup(P::Remove), &["d", "delete"]),
+    (QUEUE_MODE, Popup(P::Edit), &["e", "enter"]),
+    (NOTIFICATIONS_MODE, Popup(P::Close), &["esc", "q"]),
+    (NOTIFICATIONS_MODE, Popup(P::Down), &["down", "j"]),
+    (NOTIFICATIONS_MODE, Popup(P::Up), &["up", "k"]),
+    (NOTIFICATIONS_MODE, Popup(P::Confirm), &["enter"]),
+    (NOTIFICATIONS_MODE, Popup(P::Clear), &["x"]),
+    (MESSAGES_MODE, Popup(P::Close), &["esc", "q", "!"]),
+    (MESSAGES_MODE, Popup(P::Down), &["down", "j"]),
+    (MESSAGES_MODE, Popup(P::Up), &["up", "k"]),
+    (MESSAGES_MODE, Popup(P::PageDown), &["pagedown"]),
+    (MESSAGES_MODE, Popup(P::PageUp), &["pageup"]),
+    (MESSAGES_MODE, Popup(P::Clear), &["x"]),
+    (BULK_RESULTS_MODE, Popup(P::Close), &["esc", "enter"]),
+    (BULK_RESULTS_MODE, Popup(P::Down), &["down", "j"]),
+    (BULK_RESULTS_MODE, Popup(P::Up), &["up", "k"]),
+    (BULK_RESULTS_MODE, Popup(P::PageDown), &["pagedown"]),
+    (BULK_RESULTS_MODE, Popup(P::PageUp), &["pageup"]),
+    (BULK_RESULTS_MODE, Popup(P::Top), &["home", "g"]),
+    (BULK_RESULTS_MODE, Popup(P::Bottom), &["end", "G"]),
+    (BULK_RESULTS_MODE, Popup(P::NextColumn), &["right", "l"]),
+    (BULK_RESULTS_MODE, Popup(P::PreviousColumn), &["left", "h"]),
+    (BULK_RESULTS_MODE, Popup(P::Sort), &["s"]),
+    (BULK_RESULTS_MODE, Popup(P::Widen), &["+"]),
+    (BULK_RESULTS_MODE, Popup(P::Narrow), &["-"]),
+    (METRICS_MODE, Popup(P::Close), &["esc", "q", "H"]),
+    (DIFF_MODE, Popup(P::Close), &["esc", "q"]),
+    (DIFF_MODE, Popup(P::Up), &["up", "k"]),
+    (DIFF_MODE, Popup(P::Down), &["down", "j"]),
+    (DIFF_MODE, Popup(P::ScrollUp), &["K"]),
+    (DIFF_MODE, Popup(P::ScrollDown), &["J"]),
+    (DIFF_MODE, Popup(P::PageUp), &["pageup"]),
+    (DIFF_MODE, Popup(P::PageDown), &["pagedown", "space"]),
+    (DIFF_MODE, Popup(P::Top), &["home"]),
+    (DIFF_MODE, Popup(P::Layout), &["s"]),
+    (DIFF_MODE, Popup(P::ToggleBase), &["b"]),
+    (DIFF_MODE, Popup(P::Refresh), &["r"]),
+];
+
+impl KeymapCommand for KeyCommand {
+    fn name(self) -> &'static str {
+        match self {
+            Dashboard(command) => command.name(),
+            Popup(command) => command.name(),
+        }
+    }
+
+    fn from_name(name: &str) -> Option<Self> {
+        CommandId::ALL
+            .iter()
+            .copied()
+            .find(|command| command.name() == name)
+            .map(Dashboard)
+    }
+
+    fn from_mode_name(mode: &str, name: &str) -> Option<Self> {
+        if mode == MAIN_MODE {
+            return Self::from_name(name);
+        }
+        PopupCommand::ALL
+            .iter()
+            .copied()
+            .find(|command| command.name() == name)
+            .map(Popup)
+    }
+}
+
+impl KeymapProfile for KeyCommand {
+    const SECTION: &'static str = KEYMAP_SECTION;
+    const MODES: &'static [&'static str] = MODES;
+    const DEFAULT_BINDINGS: &'static [(&'static str, Self, &'static [&'static str])] =
+        DEFAULT_BINDINGS;
+    const NAVIGATION: &'static [(&'static str, Self, Self)] = &[
+        (
+            MAIN_MODE,
+            Dashboard(CommandId::MoveDown),
+            Dashboard(CommandId::MoveUp),
+        ),
+        (MENU_MODE, Popup(P::Down), Popup(P::Up)),
+        (LOGS_MODE, Popup(P::Down), Popup(P::Up)),
+        (DETAIL_MODE, Popup(P::Down), Popup(P::Up)),
+        (QUEUE_MODE, Popup(P::Down), Popup(P::Up)),
+        (NOTIFICATIONS_MODE, Popup(P::Down), Popup(P::Up)),
+        (MESSAGES_MODE, Popup(P::Down), Popup(P::Up)),
+        (DIFF_MODE, Popup(P::Down), Popup(P::Up)),
+    ];
+
+    /// Emacs claims `C-p` for moving up, so the palette moves to `M-x`.
+    fn adjust_preset(keymap: &mut Keymap<Self>, preset: KeymapPreset) -> Result<(), KeymapError> {
+        if preset == KeymapPreset::Emacs {
+            keymap.rebind(
+                MAIN_MODE,
+                Dashboard(CommandId::OpenCommandPalette),
+                &[":".to_string(), "alt+x".to_string()],
+            )?;
+        }
+        Ok(())
+    }
+}
+
+pub(crate) fn default_keymap() -> Keymap<KeyCommand> {
+    KeyCommand::default_keymap()
+}
+
+/// Narrows a match in a popup mode to its popup command.
+pub(crate) fn popup_match(matched: KeyMatch<KeyCommand>) -> KeyMatch<PopupCommand> {
+    match matched {
+        KeyMatch::Command(Popup(command)) => KeyMatch::Command(command),
+        KeyMatch::Pending => KeyMatch::Pending,
+        _ => KeyMatch::NoMatch,
+    }
+}
+
+/// Labels a form's footer with the keys bound in `mode`.
+pub(crate) fn with_form_keys(keymap: &Keymap<KeyCommand>, mode: &str, form: Form) -> Form {
+    FORM_COMMANDS.iter().fold(form, |form, (popup, command)| {
+        form.command_key(*command, keymap.hint(mode, Popup(*popup)))
+    })
+}
+
+/// Hint text for `commands` in `mode`, joined like `↑/↓`; `None` when none is bound.
+pub(crate) fn popup_hint(
+    keymap: &Keymap<KeyCommand>,
+    mode: &str,
+    commands: &[PopupCommand],
+) -> Option<String> {
+    let keys: Vec<String> = commands
+        .iter()
+        .filter_map(|command| keymap.hint(mode, Popup(*command)))
+        .collect();
+    (!keys.is_empty()).then(|| keys.join("/"))
+}
+
+#[cfg(test)]
+mod tests {
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+    use dark_tui_components::{KeyMatch, KeySequenceMatcher, parse_keymap};
+
+    use super::{
+        DIFF_MODE, FORM_MODE, KeyCommand, LOGS_MODE, MAIN_MODE, MODES, PopupCommand, TERMINAL_MODE,
+        default_keymap, popup_hint,
+    };
+    use crate::ui::command_palette::CommandId;
+
+    #[test]
+    fn default_keymap_has_no_conflicts_and_names_every_command() {
+        let keymap = default_keymap();
+        assert!(keymap.validate().is_ok());
+        for command in CommandId::ALL {
+            assert!(
+                keymap
+                    .hint(MAIN_MODE, KeyCommand::Dashboard(*command))
+                    .is_some(),
+                "{} has no default key",
+                command.name()
+            );
+        }
+        for command in PopupCommand::ALL {
+            assert!(
+                MODES
+                    .iter()
+                    .any(|mode| keymap.hint(mode, KeyCommand::Popup(*command)).is_some()),
+                "{} is bound in no mode",
+                command.name()
+            );
+        }
+    }
+
+    #[test]
+    fn keymap_file_applies_preset_overrides_and_rejects_conflicts() {
+        let keymap = parse_keymap::<KeyCommand>(
+            "preset = \"emacs\"\n[dark_tui.main]\ngit = \"g s\"\nmove-actor = \"M\"\n",
+        )
+        .expect("keymap");
+        assert_eq!(
+            keymap
+                .hint(
+                    MAIN_MODE,
+                    KeyCommand::Dashboard(CommandId::OpenCommandPalette)
+                )
+                .as_deref(),
+            Some(":")
+        );
+        assert_eq!(
+            keymap
+                .hint(MAIN_MODE, KeyCommand::Dashboard(CommandId::OpenGitPanel))
+                .as_deref(),
+            Some("g s")
+        );
+
+        let mut matcher = KeySequenceMatcher::default();
+        let ctrl_p = KeyEvent::new(KeyCode::Char('p'), KeyModifiers::CONTROL);
+        assert_eq!(
+            matcher.feed(&keymap, MAIN_MODE, ctrl_p),
+            KeyMatch::Command(KeyCommand::Dashboard(CommandId::MoveUp))
+        );
+        assert_eq!(
+            matcher.feed(&keymap, LOGS_MODE, ctrl_p),
+            KeyMatch::Command(KeyCommand::Popup(PopupCommand::Up))
+        );
+
+        let error = parse_keymap::<KeyCommand>("[dark_tui.main]\ngit = \"g s\"\n")
+            .expect_err("`g` still opens the move form")
+            .to_string();
+        assert!(error.contains("shadows"), "{error}");
+        assert!(parse_keymap::<KeyCommand>("preset = \"nano\"\n").is_err());
+    }
+
+    #[test]
+    fn popup_tables_use_popup_names_and_drive_hints() {
+        let keymap = parse_keymap::<KeyCommand>(
+            "[dark_tui.form]\nconfirm = \"ctrl+s\"\n[dark_tui.diff]\nclose = [\"esc\", \"h\"]\n",
+        )
+        .expect("keymap");
+        assert_eq!(
+            popup_hint(&keymap, FORM_MODE, &[PopupCommand::Confirm]).as_deref(),
+            Some("C-s")
+        );
+        assert_eq!(
+            popup_hint(&keymap, DIFF_MODE, &[PopupCommand::Up, PopupCommand::Down]).as_deref(),
+            Some("↑/↓")
+        );
+
+        // The terminal pane forwards every unbound key, so only the chord detaches.
+        let mut matcher = KeySequenceMatcher::default();
+        let detach = KeyEvent::new(KeyCode::Char(']'), KeyModifiers::CONTROL);
+        assert_eq!(
+            matcher.feed(&keymap, TERMINAL_MODE, detach),
+            KeyMatch::Command(KeyCommand::Popup(PopupCommand::Detach))
+        );
+        let plain = KeyEvent::new(KeyCode::Char(']'), KeyModifiers::NONE);
+        assert_eq!(
+            matcher.feed(&keymap, TERMINAL_MODE, plain),
+            KeyMatch::NoMatch
+        );
+
+        assert!(parse_keymap::<KeyCommand>("[dark_tui.form]\ngit = \"G\"\n").is_err());
+        assert!(parse_keymap::<KeyCommand>("[dark_tui.main]\nclose = \"x\"\n").is_err());
+    }
+}
@@ -1,5 +1,9 @@
-mod command_palette;
+pub(crate) mod command_palette;
+pub(crate) mod keymap;
+pub(crate) mod overlays;
+pub(crate) mod panes;
 mod render;
+pub(crate) mod snapshot;
 
 use std::env;
 use std::fs;
@@ -12,8 +16,8 @@ use std::time::{Duration, Instant, SystemTime};
 use anyhow::{Context, Result, anyhow};
 use arboard::Clipboard;
 use crossterm::event::{
-    self, DisableMouseCapture, EnableMouseCapture, Event, KeyCode, KeyEvent, KeyEventKind,
-    KeyModifiers, MouseButton, MouseEventKind,
+    self, DisableBracketedPaste, DisableMouseCapture, EnableBracketedPaste, EnableMouseCapture,
+    Event, KeyEvent, KeyEventKind, KeyModifiers, MouseButton, MouseEventKind,
 };
 use crossterm::execute;
 use crossterm::terminal::{
@@ -24,36 +28,70 @@ use ratatui::backend::CrosstermBackend;
 use ratatui::layout::Rect;
 use tracing::{error, info, warn};
 
-use crate::app::{App, ResultsViewMode, VizSelection};
+use dark_chat::core::{ChatAttachment, is_attachment_path};
+use dark_chat::framework::{
+    QueuedPrompt, collect_reference_attachments, parse_queue_directive, resolve_attachment_path,
+};
+
+use dark_rust::types::VariantIntegrateResult;
+
+use crate::app::{App, BulkOperation, ResultsViewMode, VizSelection};
 use crate::cli::Cli;
+use crate::core_logs::{CoreLogView, LogFileTail};
 use crate::logging;
-use crate::models::{ActorChatMessageRow, DashboardSnapshot, SshHostRow};
+use crate::models::{ActorChatMessageRow, ActorRow, DashboardSnapshot, SshHostRow};
+use crate::notify::{self, Notification};
 use crate::service::{CloneVariantOptions, DashboardService, SpawnOptions, SshInfo};
+use crate::system_metrics::{CoreHealth, SystemSample};
+use crate::terminal_pane::{TerminalPane, TerminalTarget};
 use crate::theme::Theme;
+use crate::variant_diff::{DiffBase, VariantDiff, load_variant_diff};
+use crate::variant_git::{GitOperation, VariantGitSummary};
+
+use dark_tui_components::{
+    Action as ComponentAction, ClearToastHistory, ComponentId, ComponentResult,
+    Event as ComponentEvent, ThemeSource, TickScheduler, centered_rect, inner_rect, rect_contains,
+};
 
-use self::command_palette::{CommandId, ContextMenuState, resolve_key_command};
+use self::command_palette::{
+    CommandId, PaletteInvocation, command_key_hint, detach_key_hint, is_command_enabled,
+    resolve_palette_line,
+};
+use self::keymap::{COMPOSE_MODE, KeyCommand, PICKER_MODE, PopupCommand, popup_hint};
+use self::overlays::{
+    BRANCH_FORM, BULK_PROMPT_FORM, CATALOG_SEARCH, CHAT_AGENT_PICKER, CHAT_DETAIL,
+    CHAT_MODEL_PICKER, CHAT_QUEUE, CLONE_FORM, CLONE_HOST_PICKER, COMMAND_PALETTE,
+    DELETE_VARIANT_FORM, FormSignal, GIT_PANEL, GitPanelOverlay, INIT_PRODUCT_FORM, INTEGRATE_FORM,
+    MESSAGE_LOG_OVERLAY, MOVE_ACTOR_FORM, NotificationsRequest, QueueRequest, ReloadVariantDiff,
+    RunCommand, SPAWN_FORM, SSH_PANEL,
+};
+use self::panes::{ComposeRequest, CoreLogsRequest, TerminalRequest};
 
 type TuiTerminal = Terminal<CrosstermBackend<Stdout>>;
 type ChatOptionsTask =
     Option<tokio::task::JoinHandle<(String, Result<(Vec<String>, Vec<String>)>)>>;
 type ChatSendTask = Option<tokio::task::JoinHandle<(String, Result<()>)>>;
 const API_TIMEOUT_SECONDS: u64 = 20;
-const CORE_LOGS_REFRESH_MILLIS: u64 = 900;
-const CORE_LOGS_CAPTURE_LINES: usize = 240;
+const CORE_LOGS_REFRESH_MILLIS: u64 = 500;
+const METRICS_SAMPLE_SECONDS: u64 = 3;
+const OVERLAY_TICK_MILLIS: u64 = 250;
+const FRAME_MILLIS: u64 = 120;
 
+/// One core log poll; the tail moves into the blocking task and back.
 #[derive(Debug)]
-struct CoreLogsSnapshot {
-    session: String,
-    lines: Vec<String>,
-    status: String,
+struct CoreLogsRead {
+    tail: LogFileTail,
+    lines: io::Result<Vec<String>>,
 }
 
+#[derive(Clone, Copy)]
 enum LoopAction {
     None,
     Quit,
     Refresh,
     OpenInitProductForm,
     OpenCloneForm,
+    OpenCloneHostPicker,
     CloneVariant,
     OpenBranchForm,
     SwitchVariantBranch,
@@ -63,12 +101,27 @@ enum LoopAction {
     MoveActor,
     PollVariant,
     PollActor,
+    AbortSubAgent,
     ImportVariantActors,
     InitProduct,
     OpenSpawnForm,
     OpenSshPanel,
     OpenVariantInExplorer,
     OpenVariantInTerminal,
+    OpenVariantDiff,
+    ReloadVariantDiff(DiffBase),
+    OpenIntegrateForm,
+    PreviewIntegrate,
+    IntegrateVariant,
+    OpenGitPanel,
+    RefreshGitPanel,
+    GitCommit,
+    GitStash,
+    GitStashPop,
+    GitFetch,
+    GitPush,
+    GitResetToUpstream,
+    DraftCommitMessage,
     SpawnSession,
     StartSshPortForward,
     CopySshAttachCommand,
@@ -78,29 +131,205 @@ enum LoopAction {
     RunRemoteAgentAttach,
     BuildAttach,
     RunAttach,
+    TerminalAttach,
+    TerminalShell,
     ToggleInspector,
     ToggleChat,
     ToggleCoreLogs,
     OpenLastLogInPager,
     OpenChatCompose,
     SendChatMessage,
+    BulkPoll,
+    OpenBulkDeleteForm,
+    OpenBulkPromptForm,
+    SendBulkPrompt,
+    BulkTerminate,
+}
+
+/// What a form or panel overlay reported, matched against [`FORM_ACTIONS`].
+#[derive(Clone, Copy)]
+enum FormTrigger {
+    /// Submitted with focus on this field; `None` matches any field.
+    Submit(Option<&'static str>),
+    /// A mode command the form does not handle itself.
+    Command(PopupCommand),
 }
 
+/// Loop action for each form or panel submission and shortcut; the first match wins.
+const FORM_ACTIONS: &[(ComponentId, FormTrigger, LoopAction)] = &[
+    (
+        SPAWN_FORM,
+        FormTrigger::Submit(None),
+        LoopAction::SpawnSession,
+    ),
+    (
+        CLONE_FORM,
+        FormTrigger::Submit(Some("remote_host")),
+        LoopAction::OpenCloneHostPicker,
+    ),
+    (
+        CLONE_FORM,
+        FormTrigger::Submit(None),
+        LoopAction::CloneVariant,
+    ),
+    (
+        BRANCH_FORM,
+        FormTrigger::Submit(None),
+        LoopAction::SwitchVariantBranch,
+    ),
+    (
+        DELETE_VARIANT_FORM,
+        FormTrigger::Submit(None),
+        LoopAction::DeleteVariant,
+    ),
+    (
+        INIT_PRODUCT_FORM,
+        FormTrigger::Submit(None),
+        LoopAction::InitProduct,
+    ),
+    (
+        MOVE_ACTOR_FORM,
+        FormTrigger::Submit(None),
+        LoopAction::MoveActor,
+    ),
+    (
+        SSH_PANEL,
+        FormTrigger::Submit(Some("host")),
+        LoopAction::EnsureRemoteAgentTmux,
+    ),
+    (
+        SSH_PANEL,
+        FormTrigger::Submit(Some("tmux")),
+        LoopAction::RunSshAttach,
+    ),
+    (
+        SSH_PANEL,
+        FormTrigger::Submit(None),
+        LoopAction::StartSshPortForward,
+    ),
+    (
+        SSH_PANEL,
+        FormTrigger::Command(PopupCommand::CopyAttach),
+        LoopAction::CopySshAttachCommand,
+    ),
+    (
+        SSH_PANEL,
+        FormTrigger::Command(PopupCommand::Attach),
+        LoopAction::RunSshAttach,
+    ),
+    (
+        SSH_PANEL,
+        FormTrigger::Command(PopupCommand::EnsureTmux),
+        LoopAction::EnsureRemoteAgentTmux,
+    ),
+    (
+        SSH_PANEL,
+        FormTrigger::Command(PopupCommand::CopyAgentAttach),
+        LoopAction::CopyRemoteAgentAttachCommand,
+    ),
+    (
+        SSH_PANEL,
+        FormTrigger::Command(PopupCommand::AgentAttach),
+        LoopAction::RunRemoteAgentAttach,
+    ),
+    (
+        INTEGRATE_FORM,
+        FormTrigger::Submit(None),
+        LoopAction::IntegrateVariant,
+    ),
+    (
+        INTEGRATE_FORM,
+        FormTrigger::Command(PopupCommand::Preview),
+        LoopAction::PreviewIntegrate,
+    ),
+    (GIT_PANEL, FormTrigger::Submit(None), LoopAction::GitCommit),
+    (
+        GIT_PANEL,
+        FormTrigger::Command(PopupCommand::DraftMessage),
+        LoopAction::DraftCommitMessage,
+    ),
+    (
+        GIT_PANEL,
+        FormTrigger::Command(PopupCommand::Stash),
+        LoopAction::GitStash,
+    ),
+    (
+        GIT_PANEL,
+        FormTrigger::Command(PopupCommand::Unstash),
+        LoopAction::GitStashPop,
+    ),
+    (
+        GIT_PANEL,
+        FormTrigger::Command(PopupCommand::Fetch),
+        LoopAction::GitFetch,
+    ),
+    (
+        GIT_PANEL,
+        FormTrigger::Command(PopupCommand::Push),
+        LoopAction::GitPush,
+    ),
+    (
+        GIT_PANEL,
+        FormTrigger::Command(PopupCommand::Refresh),
+        LoopAction::RefreshGitPanel,
+    ),
+    (
+        GIT_PANEL,
+        FormTrigger::Command(PopupCommand::Reset),
+        LoopAction::GitResetToUpstream,
+    ),
+    (
+        BULK_PROMPT_FORM,
+        FormTrigger::Submit(None),
+        LoopAction::SendBulkPrompt,
+    ),
+];
+
+/// Status shown when a form or panel is closed without submitting.
+const FORM_CLOSED_STATUS: &[(ComponentId, &str)] = &[
+    (SPAWN_FORM, "Spawn form closed."),
+    (CLONE_FORM, "Clone form closed."),
+    (BRANCH_FORM, "Branch switch form closed."),
+    (DELETE_VARIANT_FORM, "Delete confirmation closed."),
+    (INIT_PRODUCT_FORM, "Init product dialog closed."),
+    (MOVE_ACTOR_FORM, "Move actor dialog closed."),
+    (SSH_PANEL, "SSH panel closed."),
+    (INTEGRATE_FORM, "Integrate form closed."),
+    (GIT_PANEL, "Git panel closed."),
+    (BULK_PROMPT_FORM, "Bulk prompt cancelled."),
+    (CLONE_HOST_PICKER, "Host picker closed."),
+    (CHAT_MODEL_PICKER, "Chat picker closed."),
+    (CHAT_AGENT_PICKER, "Chat picker closed."),
+    (COMMAND_PALETTE, "Command palette closed."),
+    (CHAT_DETAIL, "Detail popup closed."),
+    (CHAT_QUEUE, "Prompt queue closed."),
+];
+
 enum BackgroundActionResult {
     CloneVariant(Result<String>),
     SwitchVariantBranch(Result<String>),
     DeleteVariant(Result<String>),
     PollVariant(Result<String>),
     PollActor(Result<String>),
+    AbortSubAgent(Result<String>),
     MoveActor(Result<String>),
     ImportVariantActors(Result<String>),
     InitProduct(Result<String>),
-    SpawnOptions(Result<(SpawnOptions, String)>),
+    SpawnOptions(Result<SpawnOptions>),
     SpawnSession(Result<String>),
     SshInfo(Result<SshInfo>),
     StartSshPortForward(Result<String>),
     BuildAttach(Result<String>),
     RunAttach(Result<String>),
+    /// Attach command for the actor, to run in the embedded terminal pane.
+    TerminalAttach(Result<String>),
+    ExportTranscript(Result<String>),
+    VariantDiff(Result<VariantDiff>),
+    IntegrateVariant(Result<VariantIntegrateResult>),
+    VariantGit(String, Result<(Option<String>, VariantGitSummary)>),
+    DraftCommitMessage(String, Result<String>),
+    /// One target of the active bulk run, keyed by variant or actor id.
+    Bulk(String, Result<String>),
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -110,6 +339,7 @@ enum BackgroundActionKind {
     DeleteVariant,
     PollVariant,
     PollActor,
+    AbortSubAgent,
     MoveActor,
     ImportVariantActors,
     InitProduct,
@@ -119,6 +349,13 @@ enum BackgroundActionKind {
     StartSshPortForward,
     BuildAttach,
     RunAttach,
+    TerminalAttach,
+    ExportTranscript,
+    VariantDiff,
+    IntegrateVariant,
+    VariantGit,
+    DraftCommitMessage,
+    Bulk,
 }
 
 struct ActionTask {
@@ -152,26 +389,26 @@ pub async fn run(cli: Cli, core_runtime_hint: String) -> Result<()> {
     let service =
         DashboardService::new(cli.base_url.clone(), directory.clone(), cli.poll_variants).await;
 
-    // Load theme — look for themes/default.toml relative to the executable,
-    // falling back to the compiled-in defaults.
-    let theme = {
-        let exe_dir = std::env::current_exe()
-            .ok()
-            .and_then(|p| p.parent().map(|d| d.to_path_buf()));
-        let candidates = [
-            Some(PathBuf::from("themes/default.toml")),
-            exe_dir.map(|d| d.join("themes/default.toml")),
-        ];
-        candidates
-            .iter()
-            .flatten()
-            .find(|p| p.exists())
-            .map(|p| Theme::load(p))
-            .unwrap_or_default()
+    let theme_source = ThemeSource::resolve(cli.theme.as_deref());
+    let (theme, theme_error) = match theme_source
+        .load()
+        .context("Dark TUI // Theme // Invalid theme")
+    {
+        Ok(theme) => (theme, None),
+        Err(error) => {
+            warn!(error = %format!("{error:#}"), "Dark TUI // Theme // Using default theme");
+            (Theme::default(), Some(error))
+        }
     };
 
     let mut app = App::new(directory, cli.refresh_seconds, theme);
+    app.set_theme_watcher(theme_source.watcher());
     app.set_core_runtime_hint(core_runtime_hint);
+    app.set_core_log_path(
+        cli.core_log_file
+            .clone()
+            .or_else(dark_rust::default_dark_core_log_path),
+    );
     app.configure_chat_performance(
         cli.chat_history_limit,
         cli.chat_render_limit,
@@ -193,6 +430,42 @@ pub async fn run(cli: Cli, core_runtime_hint: String) -> Result<()> {
             status.push_str(&format!(" (chat selection restore failed: {error})"));
         }
     }
+    if cli.reset_layout {
+        match app.reset_persisted_layout() {
+            Ok(true) => status.push_str(" (layout reset)"),
+            Ok(false) => {}
+            Err(error) => {
+                warn!(error = %error, "Dark TUI // Layout // Reset failed");
+                status.push_str(&format!(" (layout reset failed: {error})"));
+            }
+        }
+    } else {
+        match app.restore_layout_from_disk() {
+            Ok(true) => status.push_str(" (restored layout)"),
+            Ok(false) => {}
+            Err(error) => {
+                warn!(error = %error, "Dark TUI // Layout // Using default layout");
+                status.push_str(&format!(" (layout restore failed: {error})"));
+            }
+        }
+    }
+    match dark_tui_components::load_keymap::<KeyCommand>(cli.keymap.as_deref()) {
+        Ok(keymap) => app.set_keymap(keymap),
+        Err(error) => {
+            warn!(error = %error, "Dark TUI // Keymap // Using default keys");
+            status.push_str(&format!(" (keymap ignored: {error})"));
+        }
+    }
+    if let Some(error) = theme_error {
+        status.push_str(&format!(" (theme ignored: {error:#})"));
+    }
+    match notify::load_notification_config(cli.notifications.as_deref()) {
+        Ok(config) => app.set_notification_config(config),
+        Err(error) => {
+            warn!(error = %format!("{error:#}"), "Dark TUI // Notifications // Using default rules");
+            status.push_str(&format!(" (notification rules ignored: {error:#})"));
+        }
+    }
     app.set_status(status);
 
     let mut terminal = setup_terminal()?;
@@ -204,6 +477,9 @@ pub async fn run(cli: Cli, core_runtime_hint: String) -> Result<()> {
     };
 
     let run_result = run_loop(&mut terminal, &service, &mut app, actor_auto_poll_interval).await;
+    if let Err(error) = app.persist_layout() {
+        warn!(error = %error, "Dark TUI // Layout // Save failed");
+    }
     let restore_result = restore_terminal(&mut terminal);
 
     if let Err(error) = restore_result {
@@ -228,6 +504,11 @@ async fn run_loop(
     let mut next_actor_auto_poll_at = Instant::now();
     let mut next_actor_message_preview_at = Instant::now();
     let mut next_core_logs_poll_at = Instant::now();
+    let mut next_metrics_sample_at = Instant::now();
+    let mut scheduler = TickScheduler::new(
+        Duration::from_millis(OVERLAY_TICK_MILLIS),
+        Duration::from_millis(FRAME_MILLIS),
+    );
     let mut snapshot_task: Option<
         tokio::task::JoinHandle<Result<(DashboardSnapshot, Vec<(String, String)>)>>,
     > = None;
@@ -235,10 +516,16 @@ async fn run_loop(
         tokio::task::JoinHandle<(String, Result<Vec<ActorChatMessageRow>>)>,
     > = None;
     let mut chat_send_task: ChatSendTask = None;
+    // Queued prompt being sent; it goes back to the queue when the send fails.
+    let mut chat_send_queued: Option<QueuedPrompt> = None;
     let mut chat_options_task: ChatOptionsTask = None;
-    let mut core_logs_task: Option<tokio::task::JoinHandle<Result<CoreLogsSnapshot>>> = None;
+    let mut core_log_tail = app
+        .core_logs()
+        .and_then(CoreLogView::path)
+        .map(|path| LogFileTail::new(path.to_path_buf()));
+    let mut core_logs_task: Option<tokio::task::JoinHandle<CoreLogsRead>> = None;
+    let mut metrics_task: Option<tokio::task::JoinHandle<SystemSample>> = None;
     let mut action_tasks: Vec<ActionTask> = Vec::new();
-    let mut context_menu: Option<ContextMenuState> = None;
     let mut actor_drag_state: Option<ActorDragState> = None;
     let mut key_hint_hover_token: Option<render::KeyHoverToken> = None;
     let mut key_hint_hover: Option<String> = None;
@@ -257,22 +544,24 @@ async fn run_loop(
                 Ok(Ok((snapshot, actor_previews))) => {
                     app.apply_snapshot(snapshot);
                     app.apply_actor_last_message_previews(actor_previews);
-                    if context_menu
-                        .as_ref()
-                        .is_some_and(|menu| !context_menu_target_exists(app, &menu.target))
+                    if app
+                        .context_menu_target()
+                        .is_some_and(|target| !context_menu_target_exists(app, target))
                     {
-                        context_menu = None;
+                        app.close_context_menu();
                     }
                     app.set_status(format!(
                         "World state refreshed (directory={})",
                         service.directory()
                     ));
+                    let notifications = app.take_pending_notifications();
+                    deliver_notifications(app, notifications);
                 }
                 Ok(Err(error)) => {
-                    app.set_status(format!("Refresh failed: {error}"));
+                    app.set_error(format!("Refresh failed: {error}"));
                 }
                 Err(error) => {
-                    app.set_status(format!("Refresh task failed: {error}"));
+                    app.set_error(format!("Refresh task failed: {error}"));
                 }
             }
 
@@ -340,33 +629,46 @@ async fn run_loop(
             };
             app.set_chat_refresh_in_flight(false);
             match task.await {
-                Ok((actor_id, Ok(messages))) => {
-                    app.apply_chat_messages(&actor_id, messages);
+                Ok((transcript_id, Ok(messages))) => {
+                    app.apply_chat_messages(&transcript_id, messages);
                 }
                 Ok((_actor_id, Err(error))) => {
-                    app.set_status(format!("Chat refresh failed: {error}"));
+                    app.set_error(format!("Chat refresh failed: {error}"));
                 }
                 Err(error) => {
-                    app.set_status(format!("Chat refresh task failed: {error}"));
+                    app.set_error(format!("Chat refresh task failed: {error}"));
                 }
             }
         }
 
         if chat_refresh_task.is_none() {
-            if let Some(actor_id) = app.take_chat_refresh_request() {
+            if let Some(transcript_id) = app.take_chat_refresh_request() {
                 let Some(actor) = app.chat_actor().cloned() else {
                     app.set_status("Chat refresh skipped: actor missing.");
                     continue;
                 };
+                let sub_agent_id = app.chat_sub_agent().map(|sub_agent| sub_agent.id.clone());
                 let service = service.clone();
                 let chat_history_limit = app.chat_history_limit_query();
                 app.set_chat_refresh_in_flight(true);
                 chat_refresh_task = Some(tokio::spawn(async move {
-                    let result = run_with_api_timeout(
-                        service.fetch_actor_messages(&actor, chat_history_limit),
-                    )
-                    .await;
-                    (actor_id, result)
+                    let result = match sub_agent_id {
+                        Some(sub_agent_id) => {
+                            run_with_api_timeout(service.fetch_sub_agent_messages(
+                                &actor,
+                                &sub_agent_id,
+                                chat_history_limit,
+                            ))
+                            .await
+                        }
+                        None => {
+                            run_with_api_timeout(
+                                service.fetch_actor_messages(&actor, chat_history_limit),
+                            )
+                            .await
+                        }
+                    };
+                    (transcript_id, result)
                 }));
             }
         }
@@ -379,19 +681,61 @@ async fn run_loop(
                 unreachable!("chat send task should exist when marked finished");
             };
             app.set_chat_send_in_flight(false);
+            let queued = chat_send_queued.take();
+            let from_queue = queued.is_some();
             match task.await {
                 Ok((actor_id, Ok(()))) => {
-                    app.commit_sent_chat_prompt();
+                    if !from_queue {
+                        app.commit_sent_chat_prompt();
+                        app.clear_chat_attachments();
+                    }
                     app.request_chat_refresh();
                     app.set_status(format!(
                         "OpenCode response completed for {actor_id}; syncing chat..."
                     ));
                 }
                 Ok((_actor_id, Err(error))) => {
-                    app.set_status(format!("Chat send failed: {error}"));
+                    app.set_error(format!("Chat send failed: {error}"));
+                    if let Some(queued) = queued {
+                        requeue_failed_chat_prompt(app, queued);
+                    }
+                }
+                Err(error) => {
+                    app.set_error(format!("Chat send task failed: {error}"));
+                    if let Some(queued) = queued {
+                        requeue_failed_chat_prompt(app, queued);
+                    }
+                }
+            }
+        }
+
+        if chat_send_task.is_none() {
+            match app.take_due_chat_prompt() {
+                Ok(Some((actor, queued))) => {
+                    info!(
+                        actor_id = %actor.id,
+                        queued_id = %queued.id,
+                        "Dark TUI // Chat // Sending queued prompt"
+                    );
+                    app.set_status(format!("Sending queued prompt to {}...", actor.id));
+                    let service = service.clone();
+                    app.set_chat_send_in_flight(true);
+                    chat_send_queued = Some(queued.clone());
+                    chat_send_task = Some(tokio::spawn(async move {
+                        let result = run_with_api_timeout(service.send_actor_prompt(
+                            &actor,
+                            &queued.prompt,
+                            &[],
+                            queued.model.as_deref(),
+                            queued.agent.as_deref(),
+                        ))
+                        .await;
+                        (actor.id.clone(), result)
+                    }));
                 }
+                Ok(None) => {}
                 Err(error) => {
-                    app.set_status(format!("Chat send task failed: {error}"));
+                    app.set_error(format!("Prompt queue save failed: {error}"));
                 }
             }
         }
@@ -411,10 +755,10 @@ async fn run_loop(
                     }
                 }
                 Ok((_actor_id, Err(error))) => {
-                    app.set_status(format!("Chat options failed: {error}"));
+                    app.set_error(format!("Chat options failed: {error}"));
                 }
                 Err(error) => {
-                    app.set_status(format!("Chat options task failed: {error}"));
+                    app.set_error(format!("Chat options task failed: {error}"));
                 }
             }
         }
@@ -428,13 +772,19 @@ async fn run_loop(
 
             let task = action_tasks.swap_remove(action_index);
             match task.handle.await {
+                Ok(BackgroundActionResult::Bulk(target_id, result)) => {
+                    let outcome = result.map_err(|error| format!("{error:#}"));
+                    if let Some(operation) = app.record_bulk_result(&target_id, outcome) {
+                        force_refresh |= operation.refreshes_catalog();
+                    }
+                }
                 Ok(BackgroundActionResult::PollVariant(result)) => match result {
                     Ok(message) => {
                         app.set_status(message);
                         force_refresh = true;
                     }
                     Err(error) => {
-                        app.set_status(format!("Variant poll failed: {error}"));
+                        app.set_error(format!("Variant poll failed: {error}"));
                     }
                 },
                 Ok(BackgroundActionResult::PollActor(result)) => match result {
@@ -443,7 +793,17 @@ async fn run_loop(
                         force_refresh = true;
                     }
                     Err(error) => {
-                        app.set_status(format!("Actor poll failed: {error}"));
+                        app.set_error(format!("Actor poll failed: {error}"));
+                    }
+                },
+                Ok(BackgroundActionResult::AbortSubAgent(result)) => match result {
+                    Ok(message) => {
+                        app.set_status(message);
+                        app.request_chat_refresh();
+                        force_refresh = true;
+                    }
+                    Err(error) => {
+                        app.set_error(format!("Sub-agent abort failed: {error}"));
                     }
                 },
                 Ok(BackgroundActionResult::MoveActor(result)) => match result {
@@ -452,7 +812,7 @@ async fn run_loop(
                         force_refresh = true;
                     }
                     Err(error) => {
-                        app.set_status(format!("Move actor failed: {error}"));
+                        app.set_error(format!("Move actor failed: {error}"));
                     }
                 },
                 Ok(BackgroundActionResult::CloneVariant(result)) => match result {
@@ -461,7 +821,7 @@ async fn run_loop(
                         force_refresh = true;
                     }
                     Err(error) => {
-                        app.set_status(format!("Clone failed: {error}"));
+                        app.set_error(format!("Clone failed: {error}"));
                     }
                 },
                 Ok(BackgroundActionResult::SwitchVariantBranch(result)) => match result {
@@ -470,7 +830,7 @@ async fn run_loop(
                         force_refresh = true;
                     }
                     Err(error) => {
-                        app.set_status(format!("Branch switch failed: {error}"));
+                        app.set_error(format!("Branch switch failed: {error}"));
                     }
                 },
                 Ok(BackgroundActionResult::DeleteVariant(result)) => match result {
@@ -479,7 +839,7 @@ async fn run_loop(
                         force_refresh = true;
                     }
                     Err(error) => {
-                        app.set_status(format_delete_variant_error(&error));
+                        app.set_error(format_delete_variant_error(&error));
                     }
                 },
                 Ok(BackgroundActionResult::ImportVariantActors(result)) => match result {
@@ -488,7 +848,7 @@ async fn run_loop(
                         force_refresh = true;
                     }
                     Err(error) => {
-                        app.set_status(format!("Import failed: {error}"));
+                        app.set_error(format!("Import failed: {error}"));
                     }
                 },
                 Ok(BackgroundActionResult::InitProduct(result)) => match result {
@@ -497,20 +857,28 @@ async fn run_loop(
                         force_refresh = true;
                     }
                     Err(error) => {
-                        app.set_status(format!("Init failed: {error}"));
+                        app.set_error(format!("Init failed: {error}"));
                     }
                 },
                 Ok(BackgroundActionResult::SpawnOptions(result)) => match result {
-                    Ok((options, variant_id)) => {
-                        app.open_spawn_form(
-                            &variant_id,
+                    Ok(_) if !app.is_spawn_form_open() => app.clear_spawn_prefill(),
+                    Ok(options) => {
+                        app.set_spawn_form_providers(
                             options.providers,
                             options.default_provider.as_deref(),
                         );
-                        app.set_status("Spawn form open. Choose provider and prompt.");
+                        match app.apply_spawn_prefill() {
+                            Some(Ok(())) => app.set_status(
+                                "Spawn form prefilled from the command line. Press Enter to spawn.",
+                            ),
+                            Some(Err(message)) => app.set_status(message),
+                            None => app.set_status("Spawn form open. Choose provider and prompt."),
+                        }
                     }
                     Err(error) => {
-                        app.set_status(format!("Spawn options failed: {error}"));
+                        app.clear_spawn_prefill();
+                        app.close_spawn_form();
+                        app.set_error(format!("Spawn options failed: {error}"));
                     }
                 },
                 Ok(BackgroundActionResult::SpawnSession(result)) => match result {
@@ -519,7 +887,7 @@ async fn run_loop(
                         force_refresh = true;
                     }
                     Err(error) => {
-                        app.set_status(format!("Spawn failed: {error}"));
+                        app.set_error(format!("Spawn failed: {error}"));
                     }
                 },
                 Ok(BackgroundActionResult::SshInfo(result)) => match result {
@@ -540,7 +908,81 @@ async fn run_loop(
                         ));
                     }
                     Err(error) => {
-                        app.set_status(format!("SSH info failed: {error}"));
+                        app.set_error(format!("SSH info failed: {error}"));
+                    }
+                },
+                Ok(BackgroundActionResult::VariantDiff(result)) => match result {
+                    Ok(diff) => {
+                        let status = format!(
+                            "Diff vs {}: {} files +{}/-{}",
+                            diff.base_label,
+                            diff.files.len(),
+                            diff.additions(),
+                            diff.deletions()
+                        );
+                        app.open_variant_diff(diff);
+                        app.set_status(status);
+                    }
+                    Err(error) => {
+                        app.set_error(format!("Variant diff failed: {error}"));
+                    }
+                },
+                Ok(BackgroundActionResult::VariantGit(variant_id, result)) => {
+                    match &result {
+                        Ok((Some(message), _)) => {
+                            app.set_status(message.clone());
+                            force_refresh = true;
+                        }
+                        Ok((None, summary)) => {
+                            app.set_status(format!(
+                                "Git: {} ({} staged, {} unstaged, {} untracked)",
+                                summary.branch, summary.staged, summary.unstaged, summary.untracked
+                            ));
+                        }
+                        Err(error) => {
+                            app.set_error(format!("Git failed: {error}"));
+                        }
+                    }
+                    if let Some(panel) = app.git_panel_mut() {
+                        panel.apply_outcome(&variant_id, result.map_err(|error| error.to_string()));
+                    }
+                }
+                Ok(BackgroundActionResult::DraftCommitMessage(variant_id, result)) => {
+                    match result {
+                        Ok(message) => {
+                            if let Some(panel) = app.git_panel_mut() {
+                                panel.set_commit_message(&variant_id, message);
+                            }
+                            app.set_status("Commit message drafted. Edit, then Enter to commit.");
+                        }
+                        Err(error) => {
+                            if let Some(panel) = app.git_panel_mut() {
+                                panel.set_busy(false);
+                            }
+                            app.set_error(format!("Commit draft failed: {error}"));
+                        }
+                    }
+                }
+                Ok(BackgroundActionResult::IntegrateVariant(result)) => match result {
+                    Ok(result) => {
+                        app.set_status(format_integrate_status(&result));
+                        match result.status.as_str() {
+                            "integrated" | "up_to_date" => {
+                                app.close_integrate_form();
+                                force_refresh = true;
+                            }
+                            _ => {
+                                if let Some(form) = app.integrate_form_mut() {
+                                    form.set_result(result);
+                                }
+                            }
+                        }
+                    }
+                    Err(error) => {
+                        app.set_error(format!("Integrate failed: {error}"));
+                        if let Some(form) = app.integrate_form_mut() {
+                            form.set_error(error.to_string());
+                        }
                     }
                 },
                 Ok(BackgroundActionResult::StartSshPortForward(result)) => match result {
@@ -548,7 +990,7 @@ async fn run_loop(
                         app.set_status(message);
                     }
                     Err(error) => {
-                        app.set_status(format!("SSH forward failed: {error}"));
+                        app.set_error(format!("SSH forward failed: {error}"));
                     }
                 },
                 Ok(BackgroundActionResult::BuildAttach(result)) => match result {
@@ -572,9 +1014,27 @@ async fn run_loop(
                         app.set_status(status);
                     }
                     Err(error) => {
-                        app.set_status(format!("Attach command failed: {error}"));
+                        app.set_error(format!("Attach command failed: {error}"));
                     }
                 },
+                Ok(BackgroundActionResult::TerminalAttach(result)) => {
+                    match result.and_then(|command| parse_tmux_attach_target(&command)) {
+                        Ok(session) => {
+                            info!(session = %session, "Dark TUI // Terminal // Attaching in pane");
+                            let target = TerminalTarget::TmuxSession { session };
+                            match open_terminal_pane(terminal, app, target) {
+                                Ok(title) => app.set_status(terminal_opened_status(app, &title)),
+                                Err(error) => {
+                                    error!(error = %format!("{error:#}"), "Dark TUI // Terminal // Attach failed");
+                                    app.set_error(format!("Terminal attach failed: {error:#}"));
+                                }
+                            }
+                        }
+                        Err(error) => {
+                            app.set_error(format!("Terminal attach failed: {error:#}"));
+                        }
+                    }
+                }
                 Ok(BackgroundActionResult::RunAttach(result)) => match result {
                     Ok(command) => {
                         info!(command = %command, "Dark TUI // Attach // Running tmux attach");
@@ -591,17 +1051,25 @@ async fn run_loop(
                             }
                             Err(error) => {
                                 error!(error = %error, "Dark TUI // Attach // tmux attach failed");
-                                app.set_status(format!("Attach run failed: {error}"));
+                                app.set_error(format!("Attach run failed: {error}"));
                             }
                         }
                         force_refresh = true;
                     }
                     Err(error) => {
-                        app.set_status(format!("Attach command failed: {error}"));
+                        app.set_error(format!("Attach command failed: {error}"));
+                    }
+                },
+                Ok(BackgroundActionResult::ExportTranscript(result)) => match result {
+                    Ok(message) => {
+                        app.set_status(message);
+                    }
+                    Err(error) => {
+                        app.set_error(format!("Export failed: {error}"));
                     }
                 },
                 Err(error) => {
-                    app.set_status(format!("Action task failed: {error}"));
+                    app.set_error(format!("Action task failed: {error}"));
                 }
             }
         }
@@ -615,39 +1083,71 @@ async fn run_loop(
                 unreachable!("core logs task should exist when marked finished");
             };
             match task.await {
-                Ok(Ok(snapshot)) => {
-                    app.set_core_logs_snapshot(snapshot.session, snapshot.lines, snapshot.status);
-                }
-                Ok(Err(error)) => {
-                    app.set_core_logs_snapshot(
-                        app.core_logs_session().to_string(),
-                        Vec::new(),
-                        format!("error: {error}"),
-                    );
+                Ok(CoreLogsRead { tail, lines }) => {
+                    apply_core_logs_read(app, lines);
+                    core_log_tail = Some(tail);
                 }
                 Err(error) => {
-                    app.set_core_logs_snapshot(
-                        app.core_logs_session().to_string(),
-                        Vec::new(),
-                        format!("task failed: {error}"),
-                    );
+                    if let Some(logs) = app.core_logs_mut() {
+                        logs.set_status(format!("task failed: {error}"));
+                    }
+                    core_log_tail = app
+                        .core_logs()
+                        .and_then(CoreLogView::path)
+                        .map(|path| LogFileTail::new(path.to_path_buf()));
                 }
             }
         }
 
-        if app.is_core_logs_visible()
-            && core_logs_task.is_none()
-            && Instant::now() >= next_core_logs_poll_at
-        {
-            let session = app.core_logs_session().to_string();
-            core_logs_task = Some(tokio::spawn(fetch_core_logs_snapshot(
-                session,
-                CORE_LOGS_CAPTURE_LINES,
-            )));
+        // Tail even while hidden so the viewer opens on current lines.
+        if core_logs_task.is_none() && Instant::now() >= next_core_logs_poll_at {
+            if let Some(mut tail) = core_log_tail.take() {
+                core_logs_task = Some(tokio::task::spawn_blocking(move || {
+                    let lines = tail.read_new_lines();
+                    CoreLogsRead { tail, lines }
+                }));
+            }
             next_core_logs_poll_at =
                 Instant::now() + Duration::from_millis(CORE_LOGS_REFRESH_MILLIS);
         }
 
+        if metrics_task.as_ref().is_some_and(|task| task.is_finished()) {
+            let Some(task) = metrics_task.take() else {
+                unreachable!("metrics task should exist when marked finished");
+            };
+            let sample = task.await.unwrap_or_else(|error| {
+                let reason = format!("metrics task failed: {error}");
+                SystemSample {
+                    health: CoreHealth::Down(reason.clone()),
+                    metrics: Err(reason),
+                }
+            });
+            if let Some(health) = app.record_core_health(&sample.health) {
+                apply_core_health_change(app, &health);
+            }
+            let actions = app.overlays_mut().dispatch(ComponentAction::custom(sample));
+            apply_overlay_actions(app, actions);
+        }
+
+        // Sample even while the view is closed so sparklines have history.
+        if metrics_task.is_none() && Instant::now() >= next_metrics_sample_at {
+            let service = service.clone();
+            metrics_task = Some(tokio::spawn(async move {
+                let timeout = Duration::from_secs(API_TIMEOUT_SECONDS);
+                match tokio::time::timeout(timeout, service.fetch_system_sample()).await {
+                    Ok(sample) => sample,
+                    Err(_) => {
+                        let reason = format!("request timed out after {API_TIMEOUT_SECONDS}s");
+                        SystemSample {
+                            health: CoreHealth::Down(reason.clone()),
+                            metrics: Err(reason),
+                        }
+                    }
+                }
+            }));
+            next_metrics_sample_at = Instant::now() + Duration::from_secs(METRICS_SAMPLE_SECONDS);
+        }
+
         let drag_preview = actor_drag_state.as_ref().map(|state| render::DragPreview {
             col: state.current_col,
             row: state.current_row,
@@ -658,11 +1158,26 @@ async fn run_loop(
                 .is_some_and(|variant_id| variant_id != &state.source_variant_id),
         });
 
+        if let Some(message) = app.reap_terminal_pane() {
+            app.set_status(message);
+        }
+        sync_terminal_pane_size(terminal, app);
+        sync_overlay_size(terminal, app);
+        sync_chat_popups(terminal, app);
+
+        if scheduler.advance(Instant::now()).tick {
+            app.toasts_mut().tick(Instant::now());
+            app.poll_theme();
+            let actions = app.overlays_mut().handle_event(&ComponentEvent::Tick);
+            if matches!(apply_overlay_actions(app, actions), LoopAction::Quit) {
+                break;
+            }
+        }
+
         terminal.draw(|frame| {
             render::render_dashboard(
                 frame,
                 app,
-                context_menu.as_ref(),
                 drag_preview.as_ref(),
                 key_hint_hover_token.as_ref(),
                 key_hint_hover.as_deref(),
@@ -672,12 +1187,27 @@ async fn run_loop(
         let ev = if let Some(ev) = pending_event.take() {
             ev
         } else {
-            if !event::poll(Duration::from_millis(120))? {
+            if !event::poll(scheduler.poll_timeout(Instant::now()))? {
                 continue;
             }
             event::read()?
         };
-        let mut injected_key: Option<KeyEvent> = None;
+        let mut injected: Option<render::KeyHintAction> = None;
+
+        if let Event::Paste(text) = &ev {
+            if app.is_terminal_pane_focused() {
+                let result = match app.terminal_pane_mut() {
+                    Some(pane) => pane.send_paste(text),
+                    None => Ok(()),
+                };
+                if let Err(error) = result {
+                    app.set_error(format!("Terminal paste failed: {error}"));
+                }
+                continue;
+            }
+            paste_into_chat_composer(app, text);
+            continue;
+        }
 
         // --- Mouse events ---
         if let Event::Mouse(mouse) = &ev {
@@ -691,6 +1221,35 @@ async fn run_loop(
             key_hint_hover_token = render::key_bar_hover_token(root, app, mouse.row, mouse.column);
             key_hint_hover = render::key_bar_hover_hint(root, app, mouse.row, mouse.column);
 
+            // Toasts draw above panels and popups, so they take clicks first.
+            if matches!(mouse.kind, MouseEventKind::Down(MouseButton::Left))
+                && render::toast_click(root, app, mouse.column, mouse.row)
+            {
+                continue;
+            }
+
+            // A modal overlay owns the mouse, except over the key bar.
+            if let Some(modal) = app
+                .modal_overlay()
+                .filter(|_| key_hint_hover_token.is_none())
+            {
+                let action = modal_overlay_event(app, modal, &ComponentEvent::Mouse(*mouse));
+                if matches!(action, LoopAction::Quit) {
+                    break;
+                }
+                process_loop_action(
+                    action,
+                    terminal,
+                    app,
+                    service,
+                    &mut action_tasks,
+                    &mut chat_options_task,
+                    &mut chat_send_task,
+                    &mut force_refresh,
+                );
+                continue;
+            }
+
             if let Some(target) = app.resizing_target() {
                 match mouse.kind {
                     MouseEventKind::Drag(MouseButton::Left) => {
@@ -708,67 +1267,21 @@ async fn run_loop(
                 continue;
             }
 
-            if let Some(menu) = context_menu.as_mut() {
-                match mouse.kind {
-                    MouseEventKind::Down(MouseButton::Left) => {
-                        match render::context_menu_hit_test(root, menu, mouse.column, mouse.row) {
-                            render::ContextMenuHit::Item(index) => {
-                                menu.set_selected(index);
-                                if let Some(command) = menu.selected_command() {
-                                    injected_key = command_key_event(command);
-                                }
-                                context_menu = None;
-                            }
-                            render::ContextMenuHit::Menu => {}
-                            render::ContextMenuHit::Outside => {
-                                context_menu = None;
-                            }
-                        }
-                    }
-                    MouseEventKind::ScrollUp => {
-                        if !matches!(
-                            render::context_menu_hit_test(root, menu, mouse.column, mouse.row),
-                            render::ContextMenuHit::Outside
-                        ) {
-                            menu.move_up();
-                        }
-                    }
-                    MouseEventKind::ScrollDown => {
-                        if !matches!(
-                            render::context_menu_hit_test(root, menu, mouse.column, mouse.row),
-                            render::ContextMenuHit::Outside
-                        ) {
-                            menu.move_down();
-                        }
-                    }
-                    MouseEventKind::Down(MouseButton::Right) => {
-                        context_menu = None;
-                    }
-                    _ => {}
-                }
-
-                if injected_key.is_none() {
-                    continue;
-                }
-            }
-
             if let MouseEventKind::Down(MouseButton::Left) = mouse.kind {
                 if let Some(key_hint_action) =
                     render::key_bar_hit_test(root, app, mouse.row, mouse.column)
                 {
-                    injected_key = key_event_from_key_hint(key_hint_action);
-
-                    if injected_key.is_none() {
-                        continue;
-                    }
+                    injected = Some(key_hint_action);
                 }
             }
 
-            if injected_key.is_some() {
+            if let Some(target) = injected {
                 // Key-bar clicks should behave like button presses only.
                 // Skip other mouse handlers (chat/viz resize, pan/drag, selection).
-                let key = injected_key.expect("injected key should be set");
-                let action = handle_key(app, key);
+                let action = match target {
+                    render::KeyHintAction::Command(command) => dispatch_command(app, command),
+                    render::KeyHintAction::Key(key) => route_key(app, key),
+                };
                 if matches!(action, LoopAction::Quit) {
                     break;
                 }
@@ -793,109 +1306,61 @@ async fn run_loop(
                 }
             }
 
-            if app.is_branch_form_open() {
-                match render::branch_form_hit_test(root, app, mouse.column, mouse.row) {
-                    render::BranchFormHit::Suggestion(index) => {
-                        if let MouseEventKind::Down(MouseButton::Left) = mouse.kind {
-                            app.branch_form_set_selected(index);
-                            app.branch_form_apply_suggestion();
-                        }
-                        continue;
-                    }
-                    render::BranchFormHit::Input | render::BranchFormHit::Popup => {
-                        continue;
-                    }
-                    render::BranchFormHit::Outside => {
-                        if let MouseEventKind::Down(MouseButton::Left) = mouse.kind {
-                            app.close_branch_form();
-                            app.set_status("Branch switch form closed.");
-                        }
-                        continue;
-                    }
-                }
-            }
-
-            if app.is_clone_form_open() {
-                match render::clone_form_hit_test(root, app, mouse.column, mouse.row) {
-                    render::CloneFormHit::Field(index) => {
-                        if let MouseEventKind::Down(MouseButton::Left) = mouse.kind {
-                            app.clone_form_set_selected_field(index);
-                            if index == 2 {
-                                app.open_clone_host_picker();
-                                app.set_status(
-                                    "Host picker open. Type to filter, Enter/click to select.",
-                                );
-                            }
+            if let Some(pane_area) = render::terminal_pane_area(root, app) {
+                if rect_contains(pane_area, mouse.column, mouse.row) {
+                    match mouse.kind {
+                        MouseEventKind::Down(MouseButton::Left) => {
+                            app.focus_terminal_pane();
                         }
-                        continue;
-                    }
-                    render::CloneFormHit::PickerItem(index) => {
-                        if let MouseEventKind::Down(MouseButton::Left) = mouse.kind {
-                            app.clone_host_picker_set_selected(index);
-                            if let Some(host) = app.apply_clone_host_picker_selection() {
-                                app.set_status(format!("Clone host selected: {host}"));
+                        MouseEventKind::ScrollUp => {
+                            if let Some(pane) = app.terminal_pane_mut() {
+                                pane.scroll(3);
                             }
                         }
-                        continue;
-                    }
-                    render::CloneFormHit::PickerPopup => {
-                        match mouse.kind {
-                            MouseEventKind::ScrollUp => app.clone_host_picker_move_up(),
-                            MouseEventKind::ScrollDown => app.clone_host_picker_move_down(),
-                            MouseEventKind::Down(MouseButton::Left) => {}
-                            _ => {}
-                        }
-                        continue;
-                    }
-                    render::CloneFormHit::Popup => {
-                        continue;
-                    }
-                    render::CloneFormHit::Outside => {
-                        if let MouseEventKind::Down(MouseButton::Left) = mouse.kind {
-                            if app.clone_host_picker_open() {
-                                app.close_clone_host_picker();
-                                app.set_status("Host picker closed.");
-                            } else {
-                                app.close_clone_form();
-                                app.set_status("Clone form closed.");
+                        MouseEventKind::ScrollDown => {
+                            if let Some(pane) = app.terminal_pane_mut() {
+                                pane.scroll(-3);
                             }
                         }
-                        continue;
+                        _ => {}
                     }
+                    continue;
+                }
+                if matches!(mouse.kind, MouseEventKind::Down(_)) {
+                    app.detach_terminal_pane();
                 }
             }
 
-            match render::chat_hit_test(root, app, mouse.column, mouse.row) {
-                render::ChatPanelHit::DetailPopup => {
+            if let Some(logs_area) = render::core_logs_area(root, app) {
+                if rect_contains(logs_area, mouse.column, mouse.row) {
                     match mouse.kind {
-                        MouseEventKind::ScrollUp => app.scroll_chat_detail_popup_up(3),
-                        MouseEventKind::ScrollDown => app.scroll_chat_detail_popup_down(3),
-                        MouseEventKind::Down(MouseButton::Left) => {}
+                        MouseEventKind::Down(MouseButton::Left) => app.focus_core_logs(),
+                        MouseEventKind::ScrollUp => scroll_core_logs(app, -3),
+                        MouseEventKind::ScrollDown => scroll_core_logs(app, 3),
                         _ => {}
                     }
                     continue;
                 }
-                render::ChatPanelHit::DetailPopupBackground => {
-                    if matches!(
-                        mouse.kind,
-                        MouseEventKind::Down(MouseButton::Left)
-                            | MouseEventKind::Down(MouseButton::Right)
-                    ) {
-                        app.close_chat_detail_popup();
-                        app.set_status("Detail popup closed.");
-                    }
-                    continue;
+                if matches!(mouse.kind, MouseEventKind::Down(_)) {
+                    app.unfocus_core_logs();
                 }
-                render::ChatPanelHit::ModelLabel => {
-                    if let MouseEventKind::Down(MouseButton::Left) = mouse.kind {
-                        app.open_chat_model_picker();
+            }
+
+            match render::chat_hit_test(root, app, mouse.column, mouse.row) {
+                render::ChatPanelHit::ModelLabel(label) => {
+                    if let (MouseEventKind::Down(MouseButton::Left), Some(area)) =
+                        (mouse.kind, render::chat_area(root, app))
+                    {
+                        app.open_chat_model_picker(area, label);
                         app.set_status("Model picker opened. Type to filter.");
                     }
                     continue;
                 }
-                render::ChatPanelHit::AgentLabel => {
-                    if let MouseEventKind::Down(MouseButton::Left) = mouse.kind {
-                        app.open_chat_agent_picker();
+                render::ChatPanelHit::AgentLabel(label) => {
+                    if let (MouseEventKind::Down(MouseButton::Left), Some(area)) =
+                        (mouse.kind, render::chat_area(root, app))
+                    {
+                        app.open_chat_agent_picker(area, label);
                         app.set_status("Agent picker opened. Type to filter.");
                     }
                     continue;
@@ -997,29 +1462,14 @@ async fn run_loop(
                         if app.open_chat_composer() {
                             app.set_status("Chat compose mode enabled.");
                         } else {
-                            app.set_status("Chat compose unavailable: select an actor first.");
-                        }
-                    }
-                    continue;
-                }
-                render::ChatPanelHit::PickerItem(index) => {
-                    if let MouseEventKind::Down(MouseButton::Left) = mouse.kind {
-                        app.chat_picker_set_selected(index);
-                        if let Some(value) = app.apply_chat_picker_selection() {
-                            app.set_status(format!("Chat option selected: {value}"));
+                            app.set_status(format!(
+                                "Chat compose unavailable: {}.",
+                                chat_compose_blocker(app)
+                            ));
                         }
                     }
                     continue;
                 }
-                render::ChatPanelHit::PickerPopup => {
-                    match mouse.kind {
-                        MouseEventKind::ScrollUp => app.chat_picker_move_up(),
-                        MouseEventKind::ScrollDown => app.chat_picker_move_down(),
-                        MouseEventKind::Down(MouseButton::Left) => {}
-                        _ => {}
-                    }
-                    continue;
-                }
                 render::ChatPanelHit::AutocompleteItem(index) => {
                     if let MouseEventKind::Down(MouseButton::Left) = mouse.kind {
                         app.chat_autocomplete_set_selected(index);
@@ -1036,15 +1486,25 @@ async fn run_loop(
                     }
                     continue;
                 }
-                render::ChatPanelHit::Outside => {
-                    if let MouseEventKind::Down(MouseButton::Left) = mouse.kind {
-                        app.close_chat_picker();
-                    }
-                }
+                render::ChatPanelHit::Outside => {}
             }
 
             if app.results_view_mode().is_spatial() {
                 match mouse.kind {
+                    MouseEventKind::Down(MouseButton::Left)
+                        if mouse.modifiers.contains(KeyModifiers::SHIFT) =>
+                    {
+                        actor_drag_state = None;
+                        if let Some(selection) =
+                            render::viz_hit_test(root, app, mouse.column, mouse.row)
+                        {
+                            let added = app.mark_range_to(selection);
+                            app.set_status(format!(
+                                "Marked {added} ({} marked).",
+                                app.marked_count()
+                            ));
+                        }
+                    }
                     MouseEventKind::Down(MouseButton::Left) => {
                         if let Some(selection) =
                             render::viz_hit_test(root, app, mouse.column, mouse.row)
@@ -1083,11 +1543,8 @@ async fn run_loop(
                         app.end_drag();
                         if render::try_select_viz_node(root, app, mouse.column, mouse.row) {
                             if let Some(target) = app.viz_selection().cloned() {
-                                context_menu =
-                                    ContextMenuState::open(app, target, mouse.column, mouse.row);
+                                app.open_context_menu(target, mouse.column, mouse.row);
                             }
-                        } else {
-                            context_menu = None;
                         }
                     }
                     MouseEventKind::Drag(_) => {
@@ -1179,7 +1636,15 @@ async fn run_loop(
                         if let Some(selection) =
                             render::tree_hit_test(root, app, mouse.column, mouse.row)
                         {
-                            app.set_viz_selection(selection);
+                            if mouse.modifiers.contains(KeyModifiers::SHIFT) {
+                                let added = app.mark_range_to(selection);
+                                app.set_status(format!(
+                                    "Marked {added} ({} marked).",
+                                    app.marked_count()
+                                ));
+                            } else {
+                                app.set_viz_selection(selection);
+                            }
                         }
                     }
                     MouseEventKind::Down(MouseButton::Right) => {
@@ -1187,85 +1652,25 @@ async fn run_loop(
                             render::tree_hit_test(root, app, mouse.column, mouse.row)
                         {
                             app.set_viz_selection(selection.clone());
-                            context_menu =
-                                ContextMenuState::open(app, selection, mouse.column, mouse.row);
-                        } else {
-                            context_menu = None;
+                            app.open_context_menu(selection, mouse.column, mouse.row);
                         }
                     }
                     _ => {}
                 }
             }
 
-            if injected_key.is_none() {
-                continue;
-            }
+            continue;
         }
 
-        let key = match injected_key {
-            Some(key) => key,
-            None => {
-                let Event::Key(key) = ev else {
-                    continue;
-                };
-                key
-            }
+        let Event::Key(key) = ev else {
+            continue;
         };
 
         if key.kind != KeyEventKind::Press {
             continue;
         }
 
-        if let Some(menu) = context_menu.as_mut() {
-            match handle_context_menu_key(menu, key) {
-                ContextMenuKeyOutcome::Consumed => {
-                    continue;
-                }
-                ContextMenuKeyOutcome::Close => {
-                    context_menu = None;
-                    continue;
-                }
-                ContextMenuKeyOutcome::Dispatch(command) => {
-                    context_menu = None;
-                    if let Some(injected) = command_key_event(command) {
-                        let action = handle_key(app, injected);
-                        if matches!(
-                            action,
-                            LoopAction::OpenCloneForm
-                                | LoopAction::OpenBranchForm
-                                | LoopAction::OpenDeleteVariantForm
-                                | LoopAction::OpenMoveActorForm
-                                | LoopAction::OpenSpawnForm
-                        ) {
-                            context_menu = None;
-                        }
-                        process_loop_action(
-                            action,
-                            terminal,
-                            app,
-                            service,
-                            &mut action_tasks,
-                            &mut chat_options_task,
-                            &mut chat_send_task,
-                            &mut force_refresh,
-                        );
-                    }
-                    continue;
-                }
-            }
-        }
-
-        let action = handle_key(app, key);
-        if matches!(
-            action,
-            LoopAction::OpenCloneForm
-                | LoopAction::OpenBranchForm
-                | LoopAction::OpenDeleteVariantForm
-                | LoopAction::OpenMoveActorForm
-                | LoopAction::OpenSpawnForm
-        ) {
-            context_menu = None;
-        }
+        let action = route_key(app, key);
         if matches!(action, LoopAction::Quit) {
             break;
         }
@@ -1292,36 +1697,104 @@ async fn run_with_api_timeout<T>(future: impl Future<Output = Result<T>>) -> Res
     }
 }
 
-async fn fetch_core_logs_snapshot(session: String, max_lines: usize) -> Result<CoreLogsSnapshot> {
-    tokio::task::spawn_blocking(move || {
-        let start_arg = format!("-{}", max_lines.max(1));
-        let output = Command::new("tmux")
-            .args(["capture-pane", "-pt", &session, "-S", &start_arg])
-            .output()
-            .with_context(|| format!("failed to read tmux session {session}"))?;
+fn apply_core_health_change(app: &mut App, health: &CoreHealth) {
+    match health {
+        CoreHealth::Healthy => {
+            info!("Dark TUI // Metrics // dark_core healthy");
+            app.set_status("dark_core health: ok");
+        }
+        CoreHealth::Degraded(reason) | CoreHealth::Down(reason) => {
+            warn!(health = health.label(), reason = %reason, "Dark TUI // Metrics // dark_core unhealthy");
+            app.set_status(format!("dark_core health: {} ({reason})", health.label()));
+        }
+        CoreHealth::Unknown => {}
+    }
+}
 
-        if !output.status.success() {
-            let stderr = String::from_utf8_lossy(&output.stderr).trim().to_string();
-            return Ok(CoreLogsSnapshot {
-                session,
-                lines: vec![format!("(tmux unavailable: {stderr})")],
-                status: "tmux unavailable".to_string(),
-            });
+fn scroll_core_logs(app: &mut App, lines: isize) {
+    if let Some(logs) = app.core_logs_mut() {
+        logs.scroll(lines);
+    }
+}
+
+fn apply_core_logs_read(app: &mut App, lines: io::Result<Vec<String>>) {
+    let Some(logs) = app.core_logs_mut() else {
+        return;
+    };
+    match lines {
+        Ok(lines) => {
+            logs.ingest(lines);
+            logs.set_status("tailing");
         }
+        Err(error) if error.kind() == io::ErrorKind::NotFound => {
+            logs.set_status("waiting for file");
+        }
+        Err(error) => logs.set_status(format!("error: {error}")),
+    }
+}
+
+/// Parses `/attach [path]` from the chat composer. `None` attaches the clipboard image.
+fn parse_attach_command(prompt: &str) -> Option<Option<String>> {
+    let rest = prompt.trim().strip_prefix("/attach")?;
+    if !rest.is_empty() && !rest.starts_with(char::is_whitespace) {
+        return None;
+    }
 
-        let lines = String::from_utf8_lossy(&output.stdout)
-            .lines()
-            .map(|line| line.trim_end_matches('\r').to_string())
-            .collect::<Vec<_>>();
+    Some(Some(rest.trim().to_string()).filter(|value| !value.is_empty()))
+}
 
-        Ok(CoreLogsSnapshot {
-            session,
-            status: format!("streaming {} lines", lines.len()),
-            lines,
-        })
-    })
-    .await
-    .context("core logs capture task join failed")?
+/// Stages an attachment for the next chat prompt and reports the outcome.
+fn stage_chat_attachment(app: &mut App, attachment: Result<ChatAttachment>) {
+    let staged = attachment.and_then(|attachment| {
+        let label = attachment.chip_label();
+        app.add_chat_attachment(attachment)
+            .map(|count| (label, count))
+    });
+
+    match staged {
+        Ok((label, count)) => app.set_status(format!("Attached {label} ({count} staged).")),
+        Err(error) => app.set_error(format!("Attachment failed: {error}")),
+    }
+}
+
+/// Bracketed paste: a pasted or dropped path to an image/PDF/binary becomes an
+/// attachment; anything else is typed into the chat draft.
+fn paste_into_chat_composer(app: &mut App, text: &str) {
+    if !app.is_chat_composing() {
+        return;
+    }
+
+    if let Some(path) =
+        resolve_attachment_path(app.directory(), text).filter(|path| is_attachment_path(path))
+    {
+        stage_chat_attachment(app, ChatAttachment::from_path(&path));
+        return;
+    }
+
+    for ch in text.chars().filter(|ch| *ch != '\r') {
+        app.chat_insert_char(if ch == '\n' { ' ' } else { ch });
+    }
+}
+
+/// Parses `/queue [+delay] [prompt]` from the chat composer. The inner value is the
+/// directive after the command; `None` opens the queue panel.
+fn parse_queue_command(prompt: &str) -> Option<Option<String>> {
+    let rest = prompt.trim().strip_prefix("/queue")?;
+    if !rest.is_empty() && !rest.starts_with(char::is_whitespace) {
+        return None;
+    }
+
+    Some(Some(rest.trim().to_string()).filter(|value| !value.is_empty()))
+}
+
+/// Parses `/export [path]` from the chat composer. The inner value is the optional path.
+fn parse_export_command(prompt: &str) -> Option<Option<String>> {
+    let rest = prompt.trim().strip_prefix("/export")?;
+    if !rest.is_empty() && !rest.starts_with(char::is_whitespace) {
+        return None;
+    }
+
+    Some(Some(rest.trim().to_string()).filter(|value| !value.is_empty()))
 }
 
 fn resolve_drop_target_variant(root: Rect, app: &App, col: u16, row: u16) -> Option<String> {
@@ -1342,6 +1815,122 @@ fn format_delete_variant_error(error: &anyhow::Error) -> String {
     format!("Delete failed: {message}")
 }
 
+fn format_integrate_status(result: &VariantIntegrateResult) -> String {
+    let commit_count = result.commits.len();
+    match result.status.as_str() {
+        "preview" if result.conflicts.is_empty() => format!(
+            "Integrate preview: {commit_count} commits from {} onto {}.",
+            result.source_branch, result.base_branch
+        ),
+        "preview" => format!(
+            "Integrate preview: {commit_count} commits from {} onto {}; {} files would conflict.",
+            result.source_branch,
+            result.base_branch,
+            result.conflicts.len()
+        ),
+        "up_to_date" => format!(
+            "Nothing to integrate: {} is already on {}.",
+            result.source_branch, result.base_branch
+        ),
+        "conflicts" => format!(
+            "Integrate stopped: {} conflicting files ({}); {} left unchanged.",
+            result.conflicts.len(),
+            result.conflicts.join(", "),
+            result.base_branch
+        ),
+        _ => {
+            let head = result
+                .integrated_head
+                .as_deref()
+                .map(|head| head.chars().take(8).collect::<String>())
+                .unwrap_or_else(|| "-".to_string());
+            let pushed = if result.pushed { ", pushed" } else { "" };
+            format!(
+                "Integrated {commit_count} commits from {} into {} via {} at {head}{pushed} (after: {}).",
+                result.source_branch, result.base_branch, result.strategy, result.after
+            )
+        }
+    }
+}
+
+/// Starts one background request per target and tracks them as a single bulk run.
+///
+/// Only one bulk run is tracked at a time, so this refuses while another is in flight.
+fn spawn_bulk_run<F, Fut>(
+    app: &mut App,
+    action_tasks: &mut Vec<ActionTask>,
+    operation: BulkOperation,
+    targets: Vec<(String, String)>,
+    mut request: F,
+) where
+    F: FnMut(String) -> Fut,
+    Fut: Future<Output = Result<String>> + Send + 'static,
+{
+    if has_action_in_flight(action_tasks, BackgroundActionKind::Bulk) {
+        app.set_status("A bulk action is already in progress.");
+        return;
+    }
+
+    if targets.is_empty() {
+        app.set_status(format!("{}: nothing marked.", operation.label()));
+        return;
+    }
+
+    for (target_id, _) in &targets {
+        let future = request(target_id.clone());
+        let target_id = target_id.clone();
+        action_tasks.push(ActionTask {
+            kind: BackgroundActionKind::Bulk,
+            handle: tokio::spawn(async move {
+                BackgroundActionResult::Bulk(target_id, run_with_api_timeout(future).await)
+            }),
+        });
+    }
+    app.start_bulk_run(operation, targets);
+    app.set_action_requests_in_flight(action_tasks.len());
+}
+
+fn bulk_variant_targets(app: &App, variant_ids: Vec<String>) -> Vec<(String, String)> {
+    variant_ids
+        .into_iter()
+        .map(|variant_id| {
+            let label = app
+                .variants()
+                .iter()
+                .find(|variant| variant.id == variant_id)
+                .map(|variant| format!("variant {}", variant.name))
+                .unwrap_or_else(|| format!("variant {variant_id}"));
+            (variant_id, label)
+        })
+        .collect()
+}
+
+fn bulk_actor_targets(app: &App, actor_ids: &[String]) -> Vec<(String, String)> {
+    actor_ids
+        .iter()
+        .map(|actor_id| {
+            let label = app
+                .actors()
+                .iter()
+                .find(|actor| &actor.id == actor_id)
+                .map(|actor| format!("actor {}", actor.title))
+                .unwrap_or_else(|| format!("actor {actor_id}"));
+            (actor_id.clone(), label)
+        })
+        .collect()
+}
+
+fn requeue_failed_chat_prompt(app: &mut App, queued: QueuedPrompt) {
+    let queued_id = queued.id.clone();
+    match app.requeue_failed_chat_prompt(queued) {
+        Ok(()) => warn!(
+            queued_id = %queued_id,
+            "Dark TUI // Chat // Queued prompt send failed, retrying later"
+        ),
+        Err(error) => app.set_error(format!("Prompt queue save failed: {error}")),
+    }
+}
+
 fn has_action_in_flight(tasks: &[ActionTask], kind: BackgroundActionKind) -> bool {
     tasks.iter().any(|task| task.kind == kind)
 }
@@ -1350,8 +1939,13 @@ fn setup_terminal() -> Result<TuiTerminal> {
     enable_raw_mode().context("Dark TUI // Terminal // Failed to enable raw mode")?;
 
     let mut stdout = io::stdout();
-    execute!(stdout, EnterAlternateScreen, EnableMouseCapture)
-        .context("Dark TUI // Terminal // Failed to enter alternate screen")?;
+    execute!(
+        stdout,
+        EnterAlternateScreen,
+        EnableMouseCapture,
+        EnableBracketedPaste
+    )
+    .context("Dark TUI // Terminal // Failed to enter alternate screen")?;
 
     let backend = CrosstermBackend::new(stdout);
     Terminal::new(backend).context("Dark TUI // Terminal // Failed to create terminal")
@@ -1361,6 +1955,7 @@ fn restore_terminal(terminal: &mut TuiTerminal) -> Result<()> {
     disable_raw_mode().context("Dark TUI // Terminal // Failed to disable raw mode")?;
     execute!(
         terminal.backend_mut(),
+        DisableBracketedPaste,
         DisableMouseCapture,
         LeaveAlternateScreen
     )
@@ -1432,6 +2027,177 @@ fn selected_variant_local_path(app: &App) -> Result<PathBuf> {
     Ok(path)
 }
 
+/// Runs a git panel operation off the UI thread; `None` only refreshes the panel's status.
+fn spawn_variant_git(
+    app: &mut App,
+    service: &DashboardService,
+    action_tasks: &mut Vec<ActionTask>,
+    operation: Option<GitOperation>,
+) {
+    if has_action_in_flight(action_tasks, BackgroundActionKind::VariantGit) {
+        app.set_status("Git operation already in progress.");
+        return;
+    }
+
+    let Some((variant_id, directory)) = app.git_panel().map(GitPanelOverlay::target) else {
+        app.set_status("Git operation skipped: panel not open.");
+        return;
+    };
+
+    match operation.as_ref() {
+        Some(operation) => app.set_status(format!(
+            "Git {} running in {}...",
+            operation.label(),
+            directory.display()
+        )),
+        None => app.set_status("Reading git status..."),
+    }
+    if let Some(panel) = app.git_panel_mut() {
+        panel.set_busy(true);
+    }
+    let service = service.clone();
+    action_tasks.push(ActionTask {
+        kind: BackgroundActionKind::VariantGit,
+        handle: tokio::spawn(async move {
+            let result = service
+                .run_variant_git(&variant_id, directory, operation)
+                .await;
+            BackgroundActionResult::VariantGit(variant_id, result)
+        }),
+    });
+    app.set_action_requests_in_flight(action_tasks.len());
+}
+
+/// Actor the git panel drafts commit messages with: the selected actor when it belongs to the
+/// variant, otherwise the variant's first actor.
+fn bound_actor_for_variant(app: &App, variant_id: &str) -> Option<ActorRow> {
+    app.selected_actor()
+        .filter(|actor| actor.variant_id == variant_id)
+        .or_else(|| app.actors_for_variant(variant_id).into_iter().next())
+        .cloned()
+}
+
+fn spawn_commit_draft(
+    app: &mut App,
+    service: &DashboardService,
+    action_tasks: &mut Vec<ActionTask>,
+) {
+    if has_action_in_flight(action_tasks, BackgroundActionKind::DraftCommitMessage) {
+        app.set_status("Commit draft already in progress.");
+        return;
+    }
+
+    let Some((variant_id, directory)) = app.git_panel().map(GitPanelOverlay::target) else {
+        return;
+    };
+    let Some(actor) = bound_actor_for_variant(app, &variant_id) else {
+        app.set_status("Commit draft unavailable: no actor is bound to this variant.");
+        return;
+    };
+
+    app.set_status(format!(
+        "Asking actor {} to draft a commit message...",
+        actor.id
+    ));
+    if let Some(panel) = app.git_panel_mut() {
+        panel.set_busy(true);
+    }
+    let service = service.clone();
+    action_tasks.push(ActionTask {
+        kind: BackgroundActionKind::DraftCommitMessage,
+        handle: tokio::spawn(async move {
+            let result = service.draft_commit_message(&actor, directory).await;
+            BackgroundActionResult::DraftCommitMessage(variant_id, result)
+        }),
+    });
+    app.set_action_requests_in_flight(action_tasks.len());
+}
+
+/// Sends the integrate form's request to core; previews leave the base branch untouched.
+fn spawn_integrate_variant(
+    app: &mut App,
+    service: &DashboardService,
+    action_tasks: &mut Vec<ActionTask>,
+    preview: bool,
+) {
+    if has_action_in_flight(action_tasks, BackgroundActionKind::IntegrateVariant) {
+        app.set_status("Integrate already in progress.");
+        return;
+    }
+
+    let Some(request) = app
+        .integrate_form_mut()
+        .map(|form| form.begin_request(preview))
+    else {
+        app.set_status("Integrate skipped: form not open.");
+        return;
+    };
+
+    if preview {
+        app.set_status(format!(
+            "Previewing integrate for variant {}...",
+            request.variant_id
+        ));
+    } else {
+        app.set_status(format!(
+            "Integrating variant {} ({}, after: {})...",
+            request.variant_id, request.strategy, request.after
+        ));
+    }
+    let service = service.clone();
+    action_tasks.push(ActionTask {
+        kind: BackgroundActionKind::IntegrateVariant,
+        handle: tokio::spawn(async move {
+            BackgroundActionResult::IntegrateVariant(
+                run_with_api_timeout(service.integrate_variant(
+                    &request.variant_id,
+                    request.strategy,
+                    request.after,
+                    request.preview,
+                ))
+                .await,
+            )
+        }),
+    });
+    app.set_action_requests_in_flight(action_tasks.len());
+}
+
+/// Loads the selected variant's diff off the UI thread; the panel opens (or refreshes)
+/// when the result lands.
+fn spawn_variant_diff(app: &mut App, action_tasks: &mut Vec<ActionTask>, base: DiffBase) {
+    if has_action_in_flight(action_tasks, BackgroundActionKind::VariantDiff) {
+        app.set_status("Variant diff already loading.");
+        return;
+    }
+
+    let path = match selected_variant_local_path(app) {
+        Ok(path) => path,
+        Err(error) => {
+            app.set_error(format!("Variant diff unavailable: {error}"));
+            return;
+        }
+    };
+    let Some(variant_id) = app.selected_variant_id().map(ToString::to_string) else {
+        return;
+    };
+    let product_branch = app.product_branch_for_variant(&variant_id);
+
+    app.set_status("Loading variant diff...");
+    action_tasks.push(ActionTask {
+        kind: BackgroundActionKind::VariantDiff,
+        handle: tokio::spawn(async move {
+            let result = tokio::task::spawn_blocking(move || {
+                load_variant_diff(&variant_id, &path, base, product_branch.as_deref())
+            })
+            .await
+            .map_err(|error| anyhow!("Dark TUI // Diff // Task failed: {error}"))
+            .and_then(|result| result);
+            BackgroundActionResult::VariantDiff(result)
+        }),
+    });
+    app.set_action_requests_in_flight(action_tasks.len());
+}
+
 fn open_directory_in_explorer(path: &Path) -> Result<()> {
     let mut command = if cfg!(target_os = "macos") {
         let mut command = Command::new("open");
@@ -1600,6 +2366,47 @@ fn run_command_with_terminal_handoff(
     status_result.context("failed to run shell handoff command")
 }
 
+/// Starts `target` in the embedded pane, sized to the chat column.
+fn open_terminal_pane(
+    terminal: &TuiTerminal,
+    app: &mut App,
+    target: TerminalTarget,
+) -> Result<String> {
+    let title = target.title();
+    let pane = TerminalPane::spawn(target, 24, 80)?;
+    app.open_terminal_pane(pane);
+    sync_terminal_pane_size(terminal, app);
+    Ok(title)
+}
+
+fn terminal_opened_status(app: &App, title: &str) -> String {
+    let close = command_key_hint(app, CommandId::CloseTerminal)
+        .unwrap_or_else(|| CommandId::CloseTerminal.name().to_string());
+    format!(
+        "Terminal {title} open. {} detaches, {close} closes.",
+        detach_key_hint(app)
+    )
+}
+
+/// Keeps the PTY size in step with the pane's inner area.
+fn sync_terminal_pane_size(terminal: &TuiTerminal, app: &mut App) {
+    let Ok(size) = terminal.size() else {
+        return;
+    };
+    let root = Rect::new(0, 0, size.width, size.height);
+    let Some(area) = render::terminal_pane_area(root, app) else {
+        return;
+    };
+    let inner = inner_rect(area);
+    let result = match app.terminal_pane_mut() {
+        Some(pane) => pane.resize(inner.height, inner.width),
+        None => Ok(()),
+    };
+    if let Err(error) = result {
+        app.set_error(format!("Terminal resize failed: {error}"));
+    }
+}
+
 fn run_os_command(command: &mut Command, action: &str) -> Result<()> {
     let status = command
         .status()
@@ -1725,112 +2532,20 @@ fn parse_tmux_attach_target(command: &str) -> Result<String> {
     ))
 }
 
-enum ContextMenuKeyOutcome {
-    Consumed,
-    Close,
-    Dispatch(CommandId),
-}
-
-fn handle_context_menu_key(menu: &mut ContextMenuState, key: KeyEvent) -> ContextMenuKeyOutcome {
-    match key.code {
-        KeyCode::Esc => ContextMenuKeyOutcome::Close,
-        KeyCode::Up => {
-            menu.move_up();
-            ContextMenuKeyOutcome::Consumed
-        }
-        KeyCode::Down => {
-            menu.move_down();
-            ContextMenuKeyOutcome::Consumed
-        }
-        KeyCode::Enter => menu
-            .selected_command()
-            .map(ContextMenuKeyOutcome::Dispatch)
-            .unwrap_or(ContextMenuKeyOutcome::Close),
-        KeyCode::Char(_) => menu
-            .shortcut_command(key)
-            .map(ContextMenuKeyOutcome::Dispatch)
-            .unwrap_or(ContextMenuKeyOutcome::Consumed),
-        _ => ContextMenuKeyOutcome::Close,
-    }
-}
-
-fn key_event_from_key_hint(action: render::KeyHintAction) -> Option<KeyEvent> {
-    let key = match action {
-        render::KeyHintAction::Quit => KeyEvent::new(KeyCode::Char('q'), KeyModifiers::NONE),
-        render::KeyHintAction::Focus => KeyEvent::new(KeyCode::Tab, KeyModifiers::NONE),
-        render::KeyHintAction::Select => KeyEvent::new(KeyCode::Down, KeyModifiers::NONE),
-        render::KeyHintAction::Refresh => KeyEvent::new(KeyCode::Char('r'), KeyModifiers::NONE),
-        render::KeyHintAction::View => KeyEvent::new(KeyCode::Char('v'), KeyModifiers::NONE),
-        render::KeyHintAction::Density => KeyEvent::new(KeyCode::Char('z'), KeyModifiers::NONE),
-        render::KeyHintAction::Filter => KeyEvent::new(KeyCode::Char('f'), KeyModifiers::NONE),
-        render::KeyHintAction::ToggleInspector => {
-            KeyEvent::new(KeyCode::Char('s'), KeyModifiers::NONE)
-        }
-        render::KeyHintAction::Poll => KeyEvent::new(KeyCode::Char('p'), KeyModifiers::NONE),
-        render::KeyHintAction::SwitchBranch => {
-            KeyEvent::new(KeyCode::Char('w'), KeyModifiers::NONE)
-        }
-        render::KeyHintAction::PollActor => KeyEvent::new(KeyCode::Char('o'), KeyModifiers::NONE),
-        render::KeyHintAction::Move => KeyEvent::new(KeyCode::Char('g'), KeyModifiers::NONE),
-        render::KeyHintAction::Clone => KeyEvent::new(KeyCode::Char('x'), KeyModifiers::NONE),
-        render::KeyHintAction::Delete => KeyEvent::new(KeyCode::Char('d'), KeyModifiers::NONE),
-        render::KeyHintAction::Import => KeyEvent::new(KeyCode::Char('m'), KeyModifiers::NONE),
-        render::KeyHintAction::Init => KeyEvent::new(KeyCode::Char('i'), KeyModifiers::NONE),
-        render::KeyHintAction::Spawn => KeyEvent::new(KeyCode::Char('n'), KeyModifiers::NONE),
-        render::KeyHintAction::Attach => KeyEvent::new(KeyCode::Char('a'), KeyModifiers::NONE),
-        render::KeyHintAction::Chat => KeyEvent::new(KeyCode::Char('t'), KeyModifiers::NONE),
-        render::KeyHintAction::CoreLogs => KeyEvent::new(KeyCode::Char('l'), KeyModifiers::NONE),
-        render::KeyHintAction::LastLog => KeyEvent::new(KeyCode::Char('L'), KeyModifiers::SHIFT),
-        render::KeyHintAction::SshPanel => KeyEvent::new(KeyCode::Char('h'), KeyModifiers::NONE),
-        render::KeyHintAction::Compose => KeyEvent::new(KeyCode::Char('c'), KeyModifiers::NONE),
-        render::KeyHintAction::ResetPan => KeyEvent::new(KeyCode::Char('0'), KeyModifiers::NONE),
-        render::KeyHintAction::Send => KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE),
-        render::KeyHintAction::Cancel => KeyEvent::new(KeyCode::Esc, KeyModifiers::NONE),
-        render::KeyHintAction::ToggleRemove => {
-            KeyEvent::new(KeyCode::Char(' '), KeyModifiers::NONE)
-        }
-        render::KeyHintAction::FieldNav => KeyEvent::new(KeyCode::Tab, KeyModifiers::NONE),
-    };
-
-    Some(key)
-}
-
-fn command_key_event(command: CommandId) -> Option<KeyEvent> {
-    let key = match command {
-        CommandId::Quit => KeyEvent::new(KeyCode::Char('q'), KeyModifiers::NONE),
-        CommandId::ToggleFocus => KeyEvent::new(KeyCode::Tab, KeyModifiers::NONE),
-        CommandId::MoveDown => KeyEvent::new(KeyCode::Down, KeyModifiers::NONE),
-        CommandId::MoveUp => KeyEvent::new(KeyCode::Up, KeyModifiers::NONE),
-        CommandId::Refresh => KeyEvent::new(KeyCode::Char('r'), KeyModifiers::NONE),
-        CommandId::ToggleFilter => KeyEvent::new(KeyCode::Char('f'), KeyModifiers::NONE),
-        CommandId::ToggleInspector => KeyEvent::new(KeyCode::Char('s'), KeyModifiers::NONE),
-        CommandId::ToggleView => KeyEvent::new(KeyCode::Char('v'), KeyModifiers::NONE),
-        CommandId::CycleVizDensity => KeyEvent::new(KeyCode::Char('z'), KeyModifiers::NONE),
-        CommandId::PollVariant => KeyEvent::new(KeyCode::Char('p'), KeyModifiers::NONE),
-        CommandId::OpenBranchForm => KeyEvent::new(KeyCode::Char('w'), KeyModifiers::NONE),
-        CommandId::PollActor => KeyEvent::new(KeyCode::Char('o'), KeyModifiers::NONE),
-        CommandId::OpenMoveActorForm => KeyEvent::new(KeyCode::Char('g'), KeyModifiers::NONE),
-        CommandId::OpenCloneForm => KeyEvent::new(KeyCode::Char('x'), KeyModifiers::NONE),
-        CommandId::OpenDeleteVariantForm => KeyEvent::new(KeyCode::Char('d'), KeyModifiers::NONE),
-        CommandId::ImportVariantActors => KeyEvent::new(KeyCode::Char('m'), KeyModifiers::NONE),
-        CommandId::InitProduct => KeyEvent::new(KeyCode::Char('i'), KeyModifiers::NONE),
-        CommandId::OpenSpawnForm => KeyEvent::new(KeyCode::Char('n'), KeyModifiers::NONE),
-        CommandId::OpenVariantInExplorer => KeyEvent::new(KeyCode::Char('E'), KeyModifiers::SHIFT),
-        CommandId::OpenVariantInTerminal => KeyEvent::new(KeyCode::Char('T'), KeyModifiers::SHIFT),
-        CommandId::BuildAttach => KeyEvent::new(KeyCode::Char('A'), KeyModifiers::SHIFT),
-        CommandId::RunAttach => KeyEvent::new(KeyCode::Char('a'), KeyModifiers::NONE),
-        CommandId::ToggleChat => KeyEvent::new(KeyCode::Char('t'), KeyModifiers::NONE),
-        CommandId::ToggleCoreLogs => KeyEvent::new(KeyCode::Char('l'), KeyModifiers::NONE),
-        CommandId::OpenLastLogInPager => KeyEvent::new(KeyCode::Char('L'), KeyModifiers::SHIFT),
-        CommandId::OpenSshPanel => KeyEvent::new(KeyCode::Char('h'), KeyModifiers::NONE),
-        CommandId::OpenChatCompose => KeyEvent::new(KeyCode::Char('c'), KeyModifiers::NONE),
-        CommandId::ResetPan => KeyEvent::new(KeyCode::Char('0'), KeyModifiers::NONE),
-    };
-
-    Some(key)
+/// Runs a command picked from the key bar or a context menu, bypassing key resolution.
+fn dispatch_command(app: &mut App, command: CommandId) -> LoopAction {
+    if is_command_enabled(app, command) {
+        apply_command(app, command)
+    } else {
+        LoopAction::None
+    }
 }
 
 fn apply_command(app: &mut App, command: CommandId) -> LoopAction {
+    if command != CommandId::BulkTerminate {
+        app.disarm_bulk_terminate();
+    }
+
     match command {
         CommandId::Quit => LoopAction::Quit,
         CommandId::ToggleFocus => {
@@ -1850,6 +2565,11 @@ fn apply_command(app: &mut App, command: CommandId) -> LoopAction {
             app.toggle_variant_filter();
             LoopAction::None
         }
+        CommandId::Search => {
+            app.open_catalog_search();
+            app.set_status("Search: type to filter, Enter keeps it, Esc restores.");
+            LoopAction::None
+        }
         CommandId::ToggleInspector => LoopAction::ToggleInspector,
         CommandId::CycleVizDensity => {
             app.cycle_viz_density();
@@ -1867,6 +2587,7 @@ fn apply_command(app: &mut App, command: CommandId) -> LoopAction {
         CommandId::PollVariant => LoopAction::PollVariant,
         CommandId::OpenBranchForm => LoopAction::OpenBranchForm,
         CommandId::PollActor => LoopAction::PollActor,
+        CommandId::AbortSubAgent => LoopAction::AbortSubAgent,
         CommandId::OpenMoveActorForm => LoopAction::OpenMoveActorForm,
         CommandId::OpenCloneForm => LoopAction::OpenCloneForm,
         CommandId::OpenDeleteVariantForm => LoopAction::OpenDeleteVariantForm,
@@ -1875,8 +2596,28 @@ fn apply_command(app: &mut App, command: CommandId) -> LoopAction {
         CommandId::OpenSpawnForm => LoopAction::OpenSpawnForm,
         CommandId::OpenVariantInExplorer => LoopAction::OpenVariantInExplorer,
         CommandId::OpenVariantInTerminal => LoopAction::OpenVariantInTerminal,
+        CommandId::OpenVariantDiff => LoopAction::OpenVariantDiff,
+        CommandId::OpenIntegrateForm => LoopAction::OpenIntegrateForm,
+        CommandId::OpenGitPanel => LoopAction::OpenGitPanel,
         CommandId::BuildAttach => LoopAction::BuildAttach,
         CommandId::RunAttach => LoopAction::RunAttach,
+        CommandId::TerminalAttach => LoopAction::TerminalAttach,
+        CommandId::TerminalShell => LoopAction::TerminalShell,
+        CommandId::FocusTerminal => {
+            if app.focus_terminal_pane() {
+                app.set_status(format!(
+                    "Terminal focused. {} returns to the dashboard.",
+                    detach_key_hint(app)
+                ));
+            }
+            LoopAction::None
+        }
+        CommandId::CloseTerminal => {
+            if let Some(title) = app.close_terminal_pane() {
+                app.set_status(format!("Closed terminal {title}."));
+            }
+            LoopAction::None
+        }
         CommandId::ToggleChat => LoopAction::ToggleChat,
         CommandId::ToggleCoreLogs => LoopAction::ToggleCoreLogs,
         CommandId::OpenLastLogInPager => LoopAction::OpenLastLogInPager,
@@ -1887,6 +2628,86 @@ fn apply_command(app: &mut App, command: CommandId) -> LoopAction {
             app.set_status("Reset pan to origin.");
             LoopAction::None
         }
+        CommandId::ToggleMark => {
+            if app.toggle_mark_selected() {
+                app.set_status(format!("{} marked.", app.marked_count()));
+            }
+            LoopAction::None
+        }
+        CommandId::MarkAllVisible => {
+            let added = app.mark_all_visible();
+            app.set_status(format!(
+                "Marked {added} variants and actors ({} marked).",
+                app.marked_count()
+            ));
+            LoopAction::None
+        }
+        CommandId::ClearMarks => {
+            app.clear_marks();
+            app.set_status("Marks cleared.");
+            LoopAction::None
+        }
+        CommandId::BulkPoll => LoopAction::BulkPoll,
+        CommandId::BulkDelete => LoopAction::OpenBulkDeleteForm,
+        CommandId::BulkPrompt => LoopAction::OpenBulkPromptForm,
+        CommandId::BulkTerminate => LoopAction::BulkTerminate,
+        CommandId::OpenBulkSummary => {
+            app.open_bulk_summary();
+            LoopAction::None
+        }
+        CommandId::OpenNotifications => {
+            app.open_notifications();
+            LoopAction::None
+        }
+        CommandId::OpenMessageLog => {
+            app.open_message_log();
+            LoopAction::None
+        }
+        CommandId::OpenMetrics => {
+            app.open_metrics();
+            LoopAction::None
+        }
+        CommandId::OpenCommandPalette => {
+            app.open_command_palette();
+            app.set_status("Command palette open. Type to search, Enter to run.");
+            LoopAction::None
+        }
+    }
+}
+
+/// Rings the bell / raises desktop notifications and runs the command hook;
+/// the newest notification replaces the status line.
+fn deliver_notifications(app: &mut App, notifications: Vec<Notification>) {
+    let Some(newest) = notifications.last() else {
+        return;
+    };
+
+    let mut stdout = io::stdout();
+    for notification in &notifications {
+        if let Err(error) = notify::write_terminal_alert(notification, &mut stdout) {
+            warn!(error = %format!("{error:#}"), "Dark TUI // Notifications // Terminal alert failed");
+        }
+        if let Err(error) = notify::run_command_hook(notification) {
+            warn!(error = %format!("{error:#}"), "Dark TUI // Notifications // Command hook failed");
+        }
+    }
+
+    let mut status = newest.event.message();
+    if notifications.len() > 1 {
+        let more = notifications.len() - 1;
+        status.push_str(&match command_key_hint(app, CommandId::OpenNotifications) {
+            Some(key) => format!(" (+{more} more, {key} to view)"),
+            None => format!(" (+{more} more)"),
+        });
+    }
+    app.set_status(status);
+}
+
+fn chat_compose_blocker(app: &App) -> &'static str {
+    if app.chat_sub_agent().is_some() {
+        "sub-agent transcripts are read-only"
+    } else {
+        "select an actor first"
     }
 }
 
@@ -1900,6 +2721,14 @@ fn context_menu_target_exists(app: &App, target: &VizSelection) -> bool {
         VizSelection::Actor { actor_id, .. } => {
             app.actors().iter().any(|actor| &actor.id == actor_id)
         }
+        VizSelection::SubAgent {
+            actor_id,
+            sub_agent_id,
+            ..
+        } => app
+            .actors()
+            .iter()
+            .any(|actor| &actor.id == actor_id && actor.sub_agent(sub_agent_id).is_some()),
     }
 }
 
@@ -1968,6 +2797,32 @@ fn process_loop_action(
             });
             app.set_action_requests_in_flight(action_tasks.len());
         }
+        LoopAction::AbortSubAgent => {
+            let Some((actor, sub_agent)) = app
+                .selected_sub_agent()
+                .map(|(actor, sub_agent)| (actor.clone(), sub_agent.id.clone()))
+            else {
+                app.set_status("Sub-agent abort skipped: no sub-agent selected.");
+                return;
+            };
+
+            if has_action_in_flight(action_tasks, BackgroundActionKind::AbortSubAgent) {
+                app.set_status("Sub-agent abort already in progress.");
+                return;
+            }
+
+            app.set_status(format!("Aborting sub-agent {sub_agent}..."));
+            let service = service.clone();
+            action_tasks.push(ActionTask {
+                kind: BackgroundActionKind::AbortSubAgent,
+                handle: tokio::spawn(async move {
+                    BackgroundActionResult::AbortSubAgent(
+                        run_with_api_timeout(service.abort_sub_agent(&actor, &sub_agent)).await,
+                    )
+                }),
+            });
+            app.set_action_requests_in_flight(action_tasks.len());
+        }
         LoopAction::OpenMoveActorForm => {
             if app.open_move_actor_form() {
                 app.set_status("Move actor dialog open. Choose destination variant.");
@@ -2007,6 +2862,27 @@ fn process_loop_action(
             });
             app.set_action_requests_in_flight(action_tasks.len());
         }
+        LoopAction::OpenCloneHostPicker => {
+            if !app.open_clone_host_picker() {
+                // Without hosts to pick from, confirming submits the form.
+                process_loop_action(
+                    LoopAction::CloneVariant,
+                    terminal,
+                    app,
+                    service,
+                    action_tasks,
+                    chat_options_task,
+                    chat_send_task,
+                    force_refresh,
+                );
+                return;
+            }
+            let select = popup_hint(app.keymap(), PICKER_MODE, &[PopupCommand::Confirm])
+                .unwrap_or_else(|| PopupCommand::Confirm.name().to_string());
+            app.set_status(format!(
+                "Host picker open. Type to filter, {select} to select."
+            ));
+        }
         LoopAction::CloneVariant => {
             let Some(product_id) = app.selected_product().map(|product| product.id.to_string())
             else {
@@ -2105,7 +2981,25 @@ fn process_loop_action(
             }
 
             let dry = request.dry;
-            let variant_id = request.variant_id;
+            let mut variant_ids = request.variant_ids;
+            if variant_ids.len() > 1 {
+                let targets = bulk_variant_targets(app, variant_ids);
+                spawn_bulk_run(
+                    app,
+                    action_tasks,
+                    BulkOperation::DeleteVariants { dry },
+                    targets,
+                    |target_id| {
+                        let service = service.clone();
+                        async move { service.delete_variant(&target_id, dry).await }
+                    },
+                );
+                return;
+            }
+            let Some(variant_id) = variant_ids.pop() else {
+                app.set_status("Delete skipped: no variant to delete.");
+                return;
+            };
             app.set_status(format!("Deleting variant {variant_id} (dry={dry})..."));
             let service = service.clone();
             action_tasks.push(ActionTask {
@@ -2118,50 +3012,245 @@ fn process_loop_action(
             });
             app.set_action_requests_in_flight(action_tasks.len());
         }
-        LoopAction::OpenCloneForm => {
-            if app.selected_product().is_none() {
-                app.set_status("Clone form unavailable: select a product first.");
-                return;
-            }
-
-            app.open_clone_form();
-            app.set_status(
-                "Clone form open. On Remote host, press Enter for searchable host picker.",
+        LoopAction::BulkPoll => {
+            let variant_targets = bulk_variant_targets(app, app.marked_variant_ids());
+            let actor_ids: Vec<String> = app
+                .marked_actors()
+                .into_iter()
+                .map(|actor| actor.id.clone())
+                .collect();
+            let mut targets = variant_targets.clone();
+            targets.extend(bulk_actor_targets(app, &actor_ids));
+            spawn_bulk_run(
+                app,
+                action_tasks,
+                BulkOperation::Poll,
+                targets,
+                |target_id| {
+                    let service = service.clone();
+                    let is_variant = variant_targets.iter().any(|(id, _)| *id == target_id);
+                    async move {
+                        if is_variant {
+                            service.poll_variant(&target_id).await
+                        } else {
+                            service.poll_actor(&target_id).await
+                        }
+                    }
+                },
             );
         }
-        LoopAction::ImportVariantActors => {
-            let Some(variant_id) = app.selected_variant_id().map(ToString::to_string) else {
-                app.set_status("Import skipped: no variant selected.");
+        LoopAction::OpenBulkDeleteForm => {
+            let variant_ids = app.marked_variant_ids();
+            if variant_ids.is_empty() {
+                app.set_status("Bulk delete unavailable: mark variants first.");
                 return;
-            };
+            }
 
-            if has_action_in_flight(action_tasks, BackgroundActionKind::ImportVariantActors) {
-                app.set_status("Actor import already in progress.");
+            let count = variant_ids.len();
+            app.open_bulk_delete_variant_form(variant_ids);
+            app.set_status(format!(
+                "Delete confirmation open for {count} variants. Toggle clone removal with Space."
+            ));
+        }
+        LoopAction::OpenBulkPromptForm => {
+            app.open_bulk_prompt_form(String::new());
+            app.set_status(format!(
+                "Prompt {} marked actors. Enter sends, Esc cancels.",
+                app.marked_actors().len()
+            ));
+        }
+        LoopAction::SendBulkPrompt => {
+            // Checked before taking the prompt so the form keeps the text for a retry.
+            if has_action_in_flight(action_tasks, BackgroundActionKind::Bulk) {
+                app.set_status("A bulk action is already in progress.");
                 return;
             }
 
-            app.set_status(format!("Importing provider actors for {variant_id}..."));
-            let service = service.clone();
-            action_tasks.push(ActionTask {
-                kind: BackgroundActionKind::ImportVariantActors,
-                handle: tokio::spawn(async move {
-                    BackgroundActionResult::ImportVariantActors(
-                        run_with_api_timeout(service.import_variant_actors(&variant_id, None))
-                            .await,
-                    )
-                }),
-            });
-            app.set_action_requests_in_flight(action_tasks.len());
-        }
-        LoopAction::InitProduct => {
-            let Some(request) = app.take_init_product_request() else {
-                app.set_status("Init product skipped: directory is required.");
+            let Some(prompt) = app.take_bulk_prompt() else {
+                app.set_status("Bulk prompt skipped: prompt is empty.");
                 return;
             };
 
-            if has_action_in_flight(action_tasks, BackgroundActionKind::InitProduct) {
-                app.set_status("Product init already in progress.");
-                return;
+            let actors: Vec<ActorRow> = app.marked_actors().into_iter().cloned().collect();
+            let actor_ids: Vec<String> = actors.iter().map(|actor| actor.id.clone()).collect();
+            let targets = bulk_actor_targets(app, &actor_ids);
+            spawn_bulk_run(
+                app,
+                action_tasks,
+                BulkOperation::Prompt,
+                targets,
+                |target_id| {
+                    let service = service.clone();
+                    let actor = actors.iter().find(|actor| actor.id == target_id).cloned();
+                    let prompt = prompt.clone();
+                    async move {
+                        let actor = actor.ok_or_else(|| {
+                            anyhow!("Dark TUI // Bulk // Actor {target_id} is no longer listed")
+                        })?;
+                        service
+                            .send_actor_prompt(&actor, &prompt, &[], None, None)
+                            .await?;
+                        Ok("Prompt sent".to_string())
+                    }
+                },
+            );
+        }
+        LoopAction::BulkTerminate => {
+            if has_action_in_flight(action_tasks, BackgroundActionKind::Bulk) {
+                app.set_status("A bulk action is already in progress.");
+                return;
+            }
+
+            let Some(actor_ids) = app.confirm_bulk_terminate() else {
+                let key = command_key_hint(app, CommandId::BulkTerminate).unwrap_or_default();
+                app.set_status(format!(
+                    "Terminate {} marked actors? Press {key} again to confirm.",
+                    app.marked_actors().len()
+                ));
+                return;
+            };
+
+            let targets = bulk_actor_targets(app, &actor_ids);
+            spawn_bulk_run(
+                app,
+                action_tasks,
+                BulkOperation::TerminateActors,
+                targets,
+                |target_id| {
+                    let service = service.clone();
+                    async move { service.terminate_actor(&target_id).await }
+                },
+            );
+        }
+        LoopAction::OpenIntegrateForm => {
+            let Some(variant_id) = app.selected_variant_id().map(ToString::to_string) else {
+                app.set_status("Integrate unavailable: select a variant first.");
+                return;
+            };
+
+            app.open_integrate_form(&variant_id);
+            spawn_integrate_variant(app, service, action_tasks, true);
+        }
+        LoopAction::PreviewIntegrate => {
+            spawn_integrate_variant(app, service, action_tasks, true);
+        }
+        LoopAction::IntegrateVariant => {
+            spawn_integrate_variant(app, service, action_tasks, false);
+        }
+        LoopAction::OpenGitPanel => {
+            let path = match selected_variant_local_path(app) {
+                Ok(path) => path,
+                Err(error) => {
+                    app.set_error(format!("Git panel unavailable: {error}"));
+                    return;
+                }
+            };
+            let Some(variant_id) = app.selected_variant_id().map(ToString::to_string) else {
+                return;
+            };
+
+            app.open_git_panel(&variant_id, path);
+            spawn_variant_git(app, service, action_tasks, None);
+        }
+        LoopAction::RefreshGitPanel => {
+            spawn_variant_git(app, service, action_tasks, None);
+        }
+        LoopAction::GitCommit => {
+            let Some(message) = app
+                .git_panel()
+                .map(|panel| panel.commit_message().trim().to_string())
+            else {
+                return;
+            };
+
+            spawn_variant_git(
+                app,
+                service,
+                action_tasks,
+                Some(GitOperation::Commit { message }),
+            );
+        }
+        LoopAction::GitStash => {
+            spawn_variant_git(app, service, action_tasks, Some(GitOperation::Stash));
+        }
+        LoopAction::GitStashPop => {
+            spawn_variant_git(app, service, action_tasks, Some(GitOperation::StashPop));
+        }
+        LoopAction::GitFetch => {
+            spawn_variant_git(app, service, action_tasks, Some(GitOperation::Fetch));
+        }
+        LoopAction::GitPush => {
+            let Some(remote) = app
+                .git_panel()
+                .and_then(GitPanelOverlay::remote)
+                .map(ToString::to_string)
+            else {
+                app.set_status("Push unavailable: the variant has no git remote.");
+                return;
+            };
+
+            spawn_variant_git(
+                app,
+                service,
+                action_tasks,
+                Some(GitOperation::Push { remote }),
+            );
+        }
+        LoopAction::GitResetToUpstream => {
+            spawn_variant_git(
+                app,
+                service,
+                action_tasks,
+                Some(GitOperation::ResetToUpstream),
+            );
+        }
+        LoopAction::DraftCommitMessage => {
+            spawn_commit_draft(app, service, action_tasks);
+        }
+        LoopAction::OpenCloneForm => {
+            if app.selected_product().is_none() {
+                app.set_status("Clone form unavailable: select a product first.");
+                return;
+            }
+
+            app.open_clone_form();
+            app.set_status(
+                "Clone form open. On Remote host, press Enter for searchable host picker.",
+            );
+        }
+        LoopAction::ImportVariantActors => {
+            let Some(variant_id) = app.selected_variant_id().map(ToString::to_string) else {
+                app.set_status("Import skipped: no variant selected.");
+                return;
+            };
+
+            if has_action_in_flight(action_tasks, BackgroundActionKind::ImportVariantActors) {
+                app.set_status("Actor import already in progress.");
+                return;
+            }
+
+            app.set_status(format!("Importing provider actors for {variant_id}..."));
+            let service = service.clone();
+            action_tasks.push(ActionTask {
+                kind: BackgroundActionKind::ImportVariantActors,
+                handle: tokio::spawn(async move {
+                    BackgroundActionResult::ImportVariantActors(
+                        run_with_api_timeout(service.import_variant_actors(&variant_id, None))
+                            .await,
+                    )
+                }),
+            });
+            app.set_action_requests_in_flight(action_tasks.len());
+        }
+        LoopAction::InitProduct => {
+            let Some(request) = app.take_init_product_request() else {
+                app.set_status("Init product skipped: directory is required.");
+                return;
+            };
+
+            if has_action_in_flight(action_tasks, BackgroundActionKind::InitProduct) {
+                app.set_status("Product init already in progress.");
+                return;
             }
 
             app.set_status(format!(
@@ -2185,20 +3274,20 @@ fn process_loop_action(
                 return;
             };
 
+            // The form opens at once; its provider field fills in when the
+            // options request (possibly one already in flight) lands.
+            app.open_spawn_form(&variant_id);
+            app.set_status("Loading spawn provider options...");
             if has_action_in_flight(action_tasks, BackgroundActionKind::SpawnOptions) {
-                app.set_status("Spawn options request already in progress.");
                 return;
             }
 
-            app.set_status("Loading spawn provider options...");
             let service = service.clone();
             action_tasks.push(ActionTask {
                 kind: BackgroundActionKind::SpawnOptions,
                 handle: tokio::spawn(async move {
                     BackgroundActionResult::SpawnOptions(
-                        run_with_api_timeout(service.fetch_spawn_options())
-                            .await
-                            .map(|options| (options, variant_id)),
+                        run_with_api_timeout(service.fetch_spawn_options()).await,
                     )
                 }),
             });
@@ -2222,12 +3311,14 @@ fn process_loop_action(
             });
             app.set_action_requests_in_flight(action_tasks.len());
         }
+        LoopAction::OpenVariantDiff => spawn_variant_diff(app, action_tasks, DiffBase::Head),
+        LoopAction::ReloadVariantDiff(base) => spawn_variant_diff(app, action_tasks, base),
         LoopAction::OpenVariantInExplorer => {
             match selected_variant_local_path(app)
                 .and_then(|path| open_directory_in_explorer(path.as_path()))
             {
                 Ok(()) => app.set_status("Opened variant path in explorer."),
-                Err(error) => app.set_status(format!("Open in explorer failed: {error}")),
+                Err(error) => app.set_error(format!("Open in explorer failed: {error}")),
             }
         }
         LoopAction::OpenVariantInTerminal => {
@@ -2235,7 +3326,7 @@ fn process_loop_action(
                 .and_then(|path| open_directory_in_terminal(path.as_path()))
             {
                 Ok(()) => app.set_status("Opened terminal at variant path."),
-                Err(error) => app.set_status(format!("Open terminal failed: {error}")),
+                Err(error) => app.set_error(format!("Open terminal failed: {error}")),
             }
         }
         LoopAction::SpawnSession => {
@@ -2329,7 +3420,7 @@ fn process_loop_action(
                     app.set_status(format!("SSH attach finished (exit={code})."));
                 }
                 Err(error) => {
-                    app.set_status(format!("SSH attach failed: {error}"));
+                    app.set_error(format!("SSH attach failed: {error}"));
                 }
             }
             *force_refresh = true;
@@ -2349,7 +3440,7 @@ fn process_loop_action(
                     ));
                 }
                 Err(error) => {
-                    app.set_status(format!("Remote tmux bootstrap failed: {error}"));
+                    app.set_error(format!("Remote tmux bootstrap failed: {error}"));
                 }
             }
         }
@@ -2389,7 +3480,7 @@ fn process_loop_action(
                     app.set_status(format!("Remote attach finished (exit={code})."));
                 }
                 Err(error) => {
-                    app.set_status(format!("Remote attach failed: {error}"));
+                    app.set_error(format!("Remote attach failed: {error}"));
                 }
             }
             *force_refresh = true;
@@ -2440,6 +3531,38 @@ fn process_loop_action(
             });
             app.set_action_requests_in_flight(action_tasks.len());
         }
+        LoopAction::TerminalAttach => {
+            let Some(actor_id) = app.selected_actor_id().map(ToString::to_string) else {
+                app.set_status("Terminal attach skipped: no actor selected.");
+                return;
+            };
+
+            if has_action_in_flight(action_tasks, BackgroundActionKind::TerminalAttach) {
+                app.set_status("Terminal attach already in progress.");
+                return;
+            }
+
+            app.set_status(format!("Preparing terminal attach for {actor_id}..."));
+            let service = service.clone();
+            action_tasks.push(ActionTask {
+                kind: BackgroundActionKind::TerminalAttach,
+                handle: tokio::spawn(async move {
+                    BackgroundActionResult::TerminalAttach(
+                        run_with_api_timeout(service.build_attach_command(&actor_id)).await,
+                    )
+                }),
+            });
+            app.set_action_requests_in_flight(action_tasks.len());
+        }
+        LoopAction::TerminalShell => {
+            let result = selected_variant_local_path(app).and_then(|directory| {
+                open_terminal_pane(terminal, app, TerminalTarget::Shell { directory })
+            });
+            match result {
+                Ok(title) => app.set_status(terminal_opened_status(app, &title)),
+                Err(error) => app.set_error(format!("Terminal shell failed: {error:#}")),
+            }
+        }
         LoopAction::ToggleChat => {
             app.toggle_chat_visibility();
             let status = if app.is_chat_visible() {
@@ -2464,12 +3587,7 @@ fn process_loop_action(
         LoopAction::ToggleCoreLogs => {
             app.toggle_core_logs_visibility();
             let status = if app.is_core_logs_visible() {
-                app.set_core_logs_snapshot(
-                    app.core_logs_session().to_string(),
-                    Vec::new(),
-                    "connecting",
-                );
-                "Core logs shown."
+                "Core logs shown (Esc returns to the dashboard)."
             } else {
                 "Core logs hidden."
             };
@@ -2482,7 +3600,7 @@ fn process_loop_action(
                 Ok(display)
             }) {
                 Ok(path) => app.set_status(format!("Opened log in pager: {path}")),
-                Err(error) => app.set_status(format!("Open log pager failed: {error}")),
+                Err(error) => app.set_error(format!("Open log pager failed: {error}")),
             }
         }
         LoopAction::ToggleInspector => {
@@ -2509,7 +3627,10 @@ fn process_loop_action(
                 }
                 app.set_status("Compose chat enabled.");
             } else {
-                app.set_status("Compose chat skipped: select an actor first.");
+                app.set_status(format!(
+                    "Compose chat skipped: {}.",
+                    chat_compose_blocker(app)
+                ));
             }
         }
         LoopAction::SendChatMessage => {
@@ -2519,7 +3640,10 @@ fn process_loop_action(
                 return;
             };
             let actor_id = actor.id.clone();
-            let Some(prompt) = app.current_chat_prompt() else {
+            // Attachments alone make a valid prompt.
+            let Some(prompt) = app.current_chat_prompt().or_else(|| {
+                (app.is_chat_composing() && !app.chat_attachments().is_empty()).then(String::new)
+            }) else {
                 info!(
                     draft_len = app.chat_draft().len(),
                     composing = app.is_chat_composing(),
@@ -2529,12 +3653,104 @@ fn process_loop_action(
                 return;
             };
 
-            if chat_send_task.is_some() {
+            if app.is_editing_queued_chat_prompt() {
+                match app.finish_queued_chat_prompt_edit(&prompt) {
+                    Ok(true) => app.set_status("Queued prompt updated."),
+                    Ok(false) => app.set_status("Queued prompt no longer exists."),
+                    Err(error) => app.set_error(format!("Prompt queue save failed: {error}")),
+                }
+                return;
+            }
+
+            if let Some(directive) = parse_queue_command(&prompt) {
+                let Some(directive) = directive else {
+                    app.commit_sent_chat_prompt();
+                    app.open_chat_queue();
+                    app.set_status("Prompt queue opened.");
+                    return;
+                };
+
+                let (delay, queued_prompt) = parse_queue_directive(&directive);
+                if queued_prompt.is_empty() {
+                    app.set_status("Queue skipped: prompt is empty.");
+                    return;
+                }
+                match app.enqueue_chat_prompt(&queued_prompt, delay) {
+                    Ok(position) => {
+                        app.commit_sent_chat_prompt();
+                        app.set_status(format!("Prompt queued (#{position}) for {actor_id}."));
+                    }
+                    Err(error) => app.set_error(format!("Prompt queue failed: {error}")),
+                }
+                return;
+            }
+
+            if let Some(path) = parse_attach_command(&prompt) {
+                app.commit_sent_chat_prompt();
+                match path {
+                    None => stage_chat_attachment(app, ChatAttachment::from_clipboard_image()),
+                    Some(path) => match resolve_attachment_path(app.directory(), &path) {
+                        Some(resolved) => {
+                            stage_chat_attachment(app, ChatAttachment::from_path(&resolved));
+                        }
+                        None => {
+                            app.set_error(format!("Attachment failed: file not found ({path})."));
+                        }
+                    },
+                }
+                return;
+            }
+
+            if prompt == "/detach" {
+                let count = app.chat_attachments().len();
+                app.commit_sent_chat_prompt();
+                app.clear_chat_attachments();
+                app.set_status(format!("Removed {count} attachments."));
+                return;
+            }
+
+            if let Some(target) = parse_export_command(&prompt) {
+                if has_action_in_flight(action_tasks, BackgroundActionKind::ExportTranscript) {
+                    app.set_status("Export already in progress.");
+                    return;
+                }
+
+                app.commit_sent_chat_prompt();
+                app.set_status("Exporting session transcript...");
+                let service = service.clone();
+                action_tasks.push(ActionTask {
+                    kind: BackgroundActionKind::ExportTranscript,
+                    handle: tokio::spawn(async move {
+                        BackgroundActionResult::ExportTranscript(
+                            run_with_api_timeout(
+                                service.export_actor_transcript(&actor, target.as_deref()),
+                            )
+                            .await,
+                        )
+                    }),
+                });
+                app.set_action_requests_in_flight(action_tasks.len());
+                return;
+            }
+
+            if chat_send_task.is_some() || app.chat_actor_busy() {
+                if !app.chat_attachments().is_empty() {
+                    app.set_status("Actor busy; attachments are not queued. Send again once idle.");
+                    return;
+                }
                 info!(
                     actor_id = %actor_id,
-                    "Dark TUI // Chat // Send skipped: task already in progress"
+                    "Dark TUI // Chat // Actor busy; queueing prompt for later"
                 );
-                app.set_status("Chat send already in progress.");
+                match app.enqueue_chat_prompt(&prompt, None) {
+                    Ok(position) => {
+                        app.commit_sent_chat_prompt();
+                        app.set_status(format!(
+                            "Actor busy; prompt queued (#{position}). Use /queue to manage."
+                        ));
+                    }
+                    Err(error) => app.set_error(format!("Prompt queue failed: {error}")),
+                }
                 return;
             }
 
@@ -2549,11 +3765,19 @@ fn process_loop_action(
             let service = service.clone();
             let selected_model = app.chat_active_model().map(ToString::to_string);
             let selected_agent = app.chat_active_agent().map(ToString::to_string);
+            let directory = app.directory().to_string();
+            let staged_attachments = app.chat_attachments().to_vec();
             app.set_chat_send_in_flight(true);
             *chat_send_task = Some(tokio::spawn(async move {
+                let attachments =
+                    match collect_reference_attachments(&directory, &prompt, &staged_attachments) {
+                        Ok(attachments) => attachments,
+                        Err(error) => return (actor_id, Err(error)),
+                    };
                 let result = run_with_api_timeout(service.send_actor_prompt(
                     &actor,
                     &prompt,
+                    &attachments,
                     selected_model.as_deref(),
                     selected_agent.as_deref(),
                 ))
@@ -2564,412 +3788,414 @@ fn process_loop_action(
     }
 }
 
-fn handle_key(app: &mut App, key: KeyEvent) -> LoopAction {
-    if app.is_init_product_form_open() {
-        return handle_init_product_form_key(app, key);
-    }
-
-    if app.is_delete_variant_form_open() {
-        return handle_delete_variant_form_key(app, key);
-    }
-
-    if app.is_ssh_panel_open() {
-        return handle_ssh_panel_key(app, key);
-    }
-
-    if app.is_clone_form_open() {
-        return handle_clone_form_key(app, key);
-    }
-
-    if app.is_branch_form_open() {
-        return handle_branch_form_key(app, key);
-    }
-
-    if app.is_spawn_form_open() {
-        return handle_spawn_form_key(app, key);
-    }
-
-    if app.is_move_actor_form_open() {
-        return handle_move_actor_form_key(app, key);
-    }
-
-    if key.modifiers.contains(KeyModifiers::CONTROL) && key.code == KeyCode::Char('c') {
-        return LoopAction::Quit;
+/// Routes a key press through the component runtime: the modal overlay if
+/// one is open, else the focused pane.
+fn route_key(app: &mut App, key: KeyEvent) -> LoopAction {
+    let event = ComponentEvent::Key(key);
+    if let Some(modal) = app.modal_overlay() {
+        return modal_overlay_event(app, modal, &event);
     }
+    let actions = app.overlay_event(&event);
+    apply_overlay_actions(app, actions)
+}
 
-    if key.code == KeyCode::BackTab {
-        app.focus_previous();
+/// Runs the line typed into the palette, or its highlighted row.
+fn run_command_palette(app: &mut App) -> LoopAction {
+    let Some(palette) = app.command_palette() else {
         return LoopAction::None;
-    }
-
-    if app.is_chat_detail_popup_open() {
-        return handle_chat_detail_popup_key(app, key);
-    }
-
-    if app.chat_picker_open().is_some() {
-        return handle_chat_picker_key(app, key);
-    }
+    };
+    let line = palette.input().trim().to_string();
+    let highlighted = palette.highlighted().cloned();
+    let invocation = match resolve_palette_line(app, &line, highlighted.as_ref()) {
+        Ok(invocation) => invocation,
+        Err(message) => {
+            app.set_status(message);
+            return LoopAction::None;
+        }
+    };
 
-    if app.is_chat_composing() {
-        return handle_chat_compose_key(app, key);
+    let recorded = if line.is_empty() {
+        highlighted
+            .map(|item| item.completion().trim().to_string())
+            .unwrap_or_default()
+    } else {
+        line
+    };
+    app.close_command_palette();
+    if let Err(error) = app.record_command_history(&recorded) {
+        warn!(error = %error, "Dark TUI // Palette // Failed to persist command history");
     }
 
-    resolve_key_command(app, key)
-        .map(|command| apply_command(app, command))
-        .unwrap_or(LoopAction::None)
+    apply_palette_invocation(app, invocation)
 }
 
-fn handle_delete_variant_form_key(app: &mut App, key: KeyEvent) -> LoopAction {
-    match key.code {
-        KeyCode::Esc => {
-            app.close_delete_variant_form();
-            app.set_status("Delete confirmation closed.");
-            LoopAction::None
-        }
-        KeyCode::Enter => LoopAction::DeleteVariant,
-        KeyCode::Char(' ') => {
-            app.toggle_delete_variant_remove_clone_directory();
-            let remove = app.delete_variant_form_remove_clone_directory();
-            app.set_status(format!(
-                "Delete mode: {}",
-                if remove {
-                    "remove clone directory"
-                } else {
-                    "keep clone directory"
-                }
-            ));
-            LoopAction::None
+fn apply_palette_invocation(app: &mut App, invocation: PaletteInvocation) -> LoopAction {
+    let mut args = invocation.args.into_iter();
+    match invocation.command {
+        CommandId::OpenBranchForm => {
+            let Some(branch_name) = args.next() else {
+                return apply_command(app, CommandId::OpenBranchForm);
+            };
+            if !app.open_branch_form() {
+                app.set_status("Branch switch unavailable: select a variant first.");
+                return LoopAction::None;
+            }
+            app.set_branch_form_branch(&branch_name);
+            LoopAction::SwitchVariantBranch
         }
-        _ => LoopAction::None,
-    }
-}
-
-fn handle_init_product_form_key(app: &mut App, key: KeyEvent) -> LoopAction {
-    match key.code {
-        KeyCode::Esc => {
-            app.close_init_product_form();
-            app.set_status("Init product dialog closed.");
-            LoopAction::None
+        CommandId::OpenSpawnForm => {
+            let provider = args.next();
+            let prompt = args.next();
+            if provider.is_some() || prompt.is_some() {
+                app.set_spawn_prefill(provider, prompt);
+            }
+            LoopAction::OpenSpawnForm
         }
-        KeyCode::Enter => LoopAction::InitProduct,
-        KeyCode::Backspace => {
-            app.init_product_form_backspace();
-            LoopAction::None
+        CommandId::BulkPrompt => {
+            let Some(prompt) = args.next() else {
+                return apply_command(app, CommandId::BulkPrompt);
+            };
+            app.open_bulk_prompt_form(prompt);
+            LoopAction::SendBulkPrompt
         }
-        KeyCode::Char(value)
-            if !key.modifiers.contains(KeyModifiers::CONTROL)
-                && !key.modifiers.contains(KeyModifiers::ALT) =>
-        {
-            app.init_product_form_insert_char(value);
+        CommandId::Search => {
+            let Some(query) = args.next() else {
+                return apply_command(app, CommandId::Search);
+            };
+            match app.set_catalog_query(&query) {
+                Ok(()) => set_catalog_search_status(app),
+                Err(error) => app.set_status(format!("Search not applied: {error}.")),
+            }
             LoopAction::None
         }
-        _ => LoopAction::None,
+        command => apply_command(app, command),
     }
 }
 
-fn handle_ssh_panel_key(app: &mut App, key: KeyEvent) -> LoopAction {
-    match key.code {
-        KeyCode::Esc => {
-            app.close_ssh_panel();
-            app.set_status("SSH panel closed.");
-            LoopAction::None
+fn set_catalog_search_status(app: &mut App) {
+    let status = match app.catalog_query() {
+        Some(query) => format!(
+            "Search `{}`: {} matching rows.",
+            query.text(),
+            app.catalog_match_count()
+        ),
+        None => "Search cleared.".to_string(),
+    };
+    app.set_status(status);
+}
+
+/// Applies actions the overlay runtime hands back to the dashboard.
+fn apply_overlay_actions(
+    app: &mut App,
+    actions: ComponentResult<Vec<ComponentAction>>,
+) -> LoopAction {
+    let actions = match actions {
+        Ok(actions) => actions,
+        Err(error) => {
+            error!(error = %error, "Dark TUI // Overlays // Event failed");
+            app.set_error(format!("Overlay error: {error}"));
+            return LoopAction::None;
         }
-        KeyCode::Enter => {
-            if app.ssh_panel_focus_is_hosts() {
-                LoopAction::EnsureRemoteAgentTmux
-            } else if app.ssh_panel_focus_is_tmux() {
-                LoopAction::RunSshAttach
-            } else {
-                LoopAction::StartSshPortForward
+    };
+
+    let mut result = LoopAction::None;
+    for action in actions {
+        match action {
+            ComponentAction::Quit => result = LoopAction::Quit,
+            ComponentAction::StatusMessage(message) => app.set_status(message),
+            ComponentAction::Error(message) => app.set_error(format!("Error: {message}")),
+            ComponentAction::Custom(_) => {
+                if let Some(request) = action.downcast_ref::<NotificationsRequest>() {
+                    apply_notifications_request(app, request);
+                } else if action.downcast_ref::<ClearToastHistory>().is_some() {
+                    app.clear_message_log();
+                    app.set_status("Message log cleared.");
+                } else if let Some(ReloadVariantDiff(base)) = action.downcast_ref() {
+                    result = LoopAction::ReloadVariantDiff(*base);
+                } else if let Some(signal) = action.downcast_ref::<FormSignal>() {
+                    result = apply_form_signal(app, signal);
+                } else if let Some(&request) = action.downcast_ref::<QueueRequest>() {
+                    apply_queue_request(app, request);
+                } else if let Some(&request) = action.downcast_ref::<ComposeRequest>() {
+                    result = apply_compose_request(app, request);
+                } else if let Some(&request) = action.downcast_ref::<CoreLogsRequest>() {
+                    result = apply_core_logs_request(app, request);
+                } else if let Some(&request) = action.downcast_ref::<TerminalRequest>() {
+                    apply_terminal_request(app, request);
+                } else if let Some(&RunCommand(command)) = action.downcast_ref() {
+                    app.close_context_menu();
+                    result = dispatch_command(app, command);
+                }
             }
+            _ => {}
         }
-        KeyCode::Tab => {
-            app.ssh_panel_toggle_focus();
-            LoopAction::None
-        }
-        KeyCode::Up => {
-            app.ssh_panel_move_up();
-            LoopAction::None
-        }
-        KeyCode::Down => {
-            app.ssh_panel_move_down();
-            LoopAction::None
-        }
-        KeyCode::Char('c') => LoopAction::CopySshAttachCommand,
-        KeyCode::Char('a') => LoopAction::RunSshAttach,
-        KeyCode::Char('g') => LoopAction::EnsureRemoteAgentTmux,
-        KeyCode::Char('o') => LoopAction::CopyRemoteAgentAttachCommand,
-        KeyCode::Char('A') => LoopAction::RunRemoteAgentAttach,
-        _ => LoopAction::None,
     }
+    result
 }
 
-fn handle_clone_form_key(app: &mut App, key: KeyEvent) -> LoopAction {
-    if app.clone_host_picker_open() {
-        return handle_clone_host_picker_key(app, key);
-    }
-
-    match key.code {
-        KeyCode::Esc => {
-            app.close_clone_form();
-            app.set_status("Clone form closed.");
-            LoopAction::None
-        }
-        KeyCode::Enter => {
-            if app.clone_form_selected_field() == Some(2) {
-                app.open_clone_host_picker();
-                app.set_status("Host picker open. Type to filter, Enter to select.");
-                LoopAction::None
-            } else {
-                LoopAction::CloneVariant
+/// Applies a prompt queue key or click to the chat actor's queue.
+fn apply_queue_request(app: &mut App, request: QueueRequest) {
+    match request {
+        QueueRequest::SelectPrevious => app.chat_queue_move_up(),
+        QueueRequest::SelectNext => app.chat_queue_move_down(),
+        QueueRequest::Reorder(up) => match app.chat_queue_reorder_selected(up) {
+            Ok(true) => app.set_status("Queued prompt moved."),
+            Ok(false) => {}
+            Err(error) => app.set_error(format!("Prompt queue save failed: {error}")),
+        },
+        QueueRequest::Cancel => match app.chat_queue_cancel_selected() {
+            Ok(Some(_)) => app.set_status("Queued prompt cancelled."),
+            Ok(None) => {}
+            Err(error) => app.set_error(format!("Prompt queue save failed: {error}")),
+        },
+        QueueRequest::Edit(row) => {
+            if let Some(row) = row {
+                app.chat_queue_set_selected(row);
             }
-        }
-        KeyCode::Up | KeyCode::BackTab => {
-            app.clone_form_move_up();
-            LoopAction::None
-        }
-        KeyCode::Down | KeyCode::Tab => {
-            app.clone_form_move_down();
-            LoopAction::None
-        }
-        KeyCode::Left => {
-            if app.clone_form_selected_field() == Some(2) {
-                app.clone_form_select_previous_remote_host();
+            if app.chat_queue_edit_selected() {
+                let keymap = app.keymap();
+                let save = popup_hint(keymap, COMPOSE_MODE, &[PopupCommand::Confirm])
+                    .unwrap_or_else(|| PopupCommand::Confirm.name().to_string());
+                let discard = popup_hint(keymap, COMPOSE_MODE, &[PopupCommand::Close])
+                    .unwrap_or_else(|| PopupCommand::Close.name().to_string());
+                app.set_status(format!(
+                    "Editing queued prompt. {save} saves, {discard} discards."
+                ));
             }
-            LoopAction::None
-        }
-        KeyCode::Right => {
-            if app.clone_form_selected_field() == Some(2) {
-                app.clone_form_select_next_remote_host();
-            }
-            LoopAction::None
-        }
-        KeyCode::Backspace => {
-            app.clone_form_backspace();
-            LoopAction::None
         }
-        KeyCode::Char(value)
-            if !key.modifiers.contains(KeyModifiers::CONTROL)
-                && !key.modifiers.contains(KeyModifiers::ALT) =>
-        {
-            app.clone_form_insert_char(value);
-            LoopAction::None
-        }
-        _ => LoopAction::None,
     }
 }
 
-fn handle_clone_host_picker_key(app: &mut App, key: KeyEvent) -> LoopAction {
-    match key.code {
-        KeyCode::Esc => {
-            app.close_clone_host_picker();
-            app.set_status("Host picker closed.");
-            LoopAction::None
-        }
-        KeyCode::Enter => {
-            if let Some(host) = app.apply_clone_host_picker_selection() {
-                app.set_status(format!("Clone host selected: {host}"));
-            } else {
-                app.set_status("No host selected.");
+/// Applies a composer key to the chat draft.
+fn apply_compose_request(app: &mut App, request: ComposeRequest) -> LoopAction {
+    match request {
+        ComposeRequest::Insert(value) => app.chat_insert_char(value),
+        ComposeRequest::Backspace
+            if app.chat_draft().is_empty() && !app.chat_attachments().is_empty() =>
+        {
+            if let Some(removed) = app.remove_last_chat_attachment() {
+                app.set_status(format!("Removed attachment {}.", removed.filename));
             }
-            LoopAction::None
-        }
-        KeyCode::Up => {
-            app.clone_host_picker_move_up();
-            LoopAction::None
-        }
-        KeyCode::Down => {
-            app.clone_host_picker_move_down();
-            LoopAction::None
         }
-        KeyCode::Backspace => {
-            app.clone_host_picker_backspace();
-            LoopAction::None
+        ComposeRequest::Backspace => app.chat_backspace(),
+        ComposeRequest::PasteImage => {
+            stage_chat_attachment(app, ChatAttachment::from_clipboard_image());
         }
-        KeyCode::Char(value)
-            if !key.modifiers.contains(KeyModifiers::CONTROL)
-                && !key.modifiers.contains(KeyModifiers::ALT) =>
-        {
-            app.clone_host_picker_insert_char(value);
-            LoopAction::None
+        ComposeRequest::Send => return LoopAction::SendChatMessage,
+        ComposeRequest::Cancel => {
+            app.cancel_chat_composer();
+            app.set_status("Chat compose cancelled.");
         }
-        _ => LoopAction::None,
     }
+    LoopAction::None
 }
 
-fn handle_branch_form_key(app: &mut App, key: KeyEvent) -> LoopAction {
-    match key.code {
-        KeyCode::Esc => {
-            app.close_branch_form();
-            app.set_status("Branch switch form closed.");
-            LoopAction::None
-        }
-        KeyCode::Enter => LoopAction::SwitchVariantBranch,
-        KeyCode::Up => {
-            app.branch_form_move_up();
-            LoopAction::None
-        }
-        KeyCode::Down => {
-            app.branch_form_move_down();
-            LoopAction::None
+fn apply_core_logs_request(app: &mut App, request: CoreLogsRequest) -> LoopAction {
+    match request {
+        CoreLogsRequest::Unfocus => {
+            app.unfocus_core_logs();
+            app.set_status("Core logs unfocused (click the panel to focus it again).");
         }
-        KeyCode::Tab => {
-            app.branch_form_apply_suggestion();
-            LoopAction::None
-        }
-        KeyCode::Backspace => {
-            app.branch_form_backspace();
-            LoopAction::None
-        }
-        KeyCode::Char(value)
-            if !key.modifiers.contains(KeyModifiers::CONTROL)
-                && !key.modifiers.contains(KeyModifiers::ALT) =>
-        {
-            app.branch_form_insert_char(value);
-            LoopAction::None
-        }
-        _ => LoopAction::None,
+        CoreLogsRequest::Toggle => return LoopAction::ToggleCoreLogs,
+        CoreLogsRequest::JumpToEntity => match app.jump_to_core_log_entity() {
+            Some(entity_id) => app.set_status(format!("Selected {entity_id} from core logs.")),
+            None => app.set_status("Selected log line mentions no entity in the catalog."),
+        },
     }
+    LoopAction::None
 }
 
-fn handle_spawn_form_key(app: &mut App, key: KeyEvent) -> LoopAction {
-    match key.code {
-        KeyCode::Esc => {
-            app.close_spawn_form();
-            app.set_status("Spawn form closed.");
-            LoopAction::None
-        }
-        KeyCode::Enter => LoopAction::SpawnSession,
-        KeyCode::Up => {
-            app.spawn_form_move_provider_up();
-            LoopAction::None
-        }
-        KeyCode::Down => {
-            app.spawn_form_move_provider_down();
-            LoopAction::None
-        }
-        KeyCode::Backspace => {
-            app.spawn_form_backspace();
-            LoopAction::None
-        }
-        KeyCode::Char(value)
-            if !key.modifiers.contains(KeyModifiers::CONTROL)
-                && !key.modifiers.contains(KeyModifiers::ALT) =>
-        {
-            app.spawn_form_insert_char(value);
-            LoopAction::None
-        }
-        _ => LoopAction::None,
+fn apply_terminal_request(app: &mut App, request: TerminalRequest) {
+    if request == TerminalRequest::Detach {
+        app.detach_terminal_pane();
+        let focus = command_key_hint(app, CommandId::FocusTerminal)
+            .unwrap_or_else(|| CommandId::FocusTerminal.name().to_string());
+        app.set_status(format!("Terminal detached. {focus} focuses it again."));
+        return;
     }
-}
 
-fn handle_move_actor_form_key(app: &mut App, key: KeyEvent) -> LoopAction {
-    match key.code {
-        KeyCode::Esc => {
-            app.close_move_actor_form();
-            app.set_status("Move actor dialog closed.");
-            LoopAction::None
-        }
-        KeyCode::Enter => LoopAction::MoveActor,
-        KeyCode::Up | KeyCode::BackTab => {
-            app.move_actor_form_move_up();
-            LoopAction::None
-        }
-        KeyCode::Down | KeyCode::Tab => {
-            app.move_actor_form_move_down();
-            LoopAction::None
+    let Some(pane) = app.terminal_pane_mut() else {
+        return;
+    };
+    let page = (pane.rows() / 2).max(1) as isize;
+    match request {
+        TerminalRequest::Page(up) => pane.scroll(if up { page } else { -page }),
+        TerminalRequest::Send(key) => {
+            if let Err(error) = pane.send_key(key) {
+                app.set_error(format!("Terminal write failed: {error}"));
+            }
         }
-        _ => LoopAction::None,
+        TerminalRequest::Detach => {}
     }
 }
 
-fn handle_chat_compose_key(app: &mut App, key: KeyEvent) -> LoopAction {
-    match key.code {
-        KeyCode::Esc => {
-            app.cancel_chat_composer();
-            app.set_status("Chat compose cancelled.");
-            LoopAction::None
-        }
-        KeyCode::Enter => LoopAction::SendChatMessage,
-        KeyCode::Backspace => {
-            app.chat_backspace();
-            LoopAction::None
-        }
-        KeyCode::Char(value)
-            if !key.modifiers.contains(KeyModifiers::CONTROL)
-                && !key.modifiers.contains(KeyModifiers::ALT) =>
-        {
-            app.chat_insert_char(value);
-            LoopAction::None
-        }
-        _ => LoopAction::None,
+/// Runs what the palette and the search bar report, which only touch the
+/// app; other forms go through [`form_action`].
+fn apply_form_signal(app: &mut App, signal: &FormSignal) -> LoopAction {
+    match *signal {
+        FormSignal::Submitted {
+            form: COMMAND_PALETTE,
+            ..
+        } => return run_command_palette(app),
+        FormSignal::Submitted {
+            form: CATALOG_SEARCH,
+            ..
+        } => app.close_catalog_search(),
+        FormSignal::Command(CATALOG_SEARCH, PopupCommand::Close) => app.cancel_catalog_search(),
+        _ => return form_action(signal),
     }
+    set_catalog_search_status(app);
+    LoopAction::None
 }
 
-fn handle_chat_detail_popup_key(app: &mut App, key: KeyEvent) -> LoopAction {
-    match key.code {
-        KeyCode::Esc | KeyCode::Char('q') => {
-            app.close_chat_detail_popup();
-            app.set_status("Detail popup closed.");
-            LoopAction::None
-        }
-        KeyCode::Up => {
-            app.scroll_chat_detail_popup_up(3);
-            LoopAction::None
-        }
-        KeyCode::Down => {
-            app.scroll_chat_detail_popup_down(3);
-            LoopAction::None
-        }
-        _ => LoopAction::None,
+/// Looks up the loop action for a form's submission or shortcut.
+fn form_action(signal: &FormSignal) -> LoopAction {
+    FORM_ACTIONS
+        .iter()
+        .find(|(form, trigger, _)| match (*signal, *trigger) {
+            (FormSignal::Submitted { form: id, field }, FormTrigger::Submit(expected)) => {
+                id == *form && expected.is_none_or(|expected| field == Some(expected))
+            }
+            (FormSignal::Command(id, command), FormTrigger::Command(expected)) => {
+                id == *form && command == expected
+            }
+            _ => false,
+        })
+        .map_or(LoopAction::None, |&(_, _, action)| action)
+}
+
+/// Routes a key or mouse event to the `modal` overlay. A close is reported
+/// before the overlay's own actions, so a status it answered with wins.
+fn modal_overlay_event(app: &mut App, modal: ComponentId, event: &ComponentEvent) -> LoopAction {
+    let actions = app.overlay_event(event);
+    report_form_closed(app, modal);
+    apply_overlay_actions(app, actions)
+}
+
+/// Reports a form the last event closed without submitting it.
+fn report_form_closed(app: &mut App, form: ComponentId) {
+    if app.overlays().is_visible(form) {
+        return;
+    }
+    if let Some((_, status)) = FORM_CLOSED_STATUS.iter().find(|(id, _)| *id == form) {
+        app.set_status(*status);
     }
 }
 
-fn handle_chat_picker_key(app: &mut App, key: KeyEvent) -> LoopAction {
-    match key.code {
-        KeyCode::Esc => {
-            app.close_chat_picker();
-            app.set_status("Chat picker closed.");
-            LoopAction::None
-        }
-        KeyCode::Enter => {
-            if let Some(value) = app.apply_chat_picker_selection() {
-                app.set_status(format!("Chat option selected: {value}"));
+fn apply_notifications_request(app: &mut App, request: &NotificationsRequest) {
+    match request {
+        NotificationsRequest::Jump(target_id) => {
+            if app.jump_to_notification_target(target_id) {
+                app.set_status("Selected the notification target.");
             } else {
-                app.set_status("No matching option selected.");
+                app.set_status("Notification target is no longer in the catalog.");
             }
-            LoopAction::None
-        }
-        KeyCode::Up => {
-            app.chat_picker_move_up();
-            LoopAction::None
-        }
-        KeyCode::Down => {
-            app.chat_picker_move_down();
-            LoopAction::None
-        }
-        KeyCode::Backspace => {
-            app.chat_picker_backspace();
-            LoopAction::None
         }
-        KeyCode::Char('u') if key.modifiers.contains(KeyModifiers::CONTROL) => {
-            app.clear_chat_picker_query();
-            LoopAction::None
+        NotificationsRequest::Clear => {
+            app.clear_notifications();
+            app.set_status("Notification history cleared.");
         }
-        KeyCode::Char(value)
-            if !key.modifiers.contains(KeyModifiers::CONTROL)
-                && !key.modifiers.contains(KeyModifiers::ALT) =>
-        {
-            app.chat_picker_insert_char(value);
-            LoopAction::None
-        }
-        _ => LoopAction::None,
     }
 }
 
+/// Sends the terminal size to the overlays whenever it changes, so they can
+/// hit-test the mouse against the layout they draw, and re-places the ones
+/// drawn into a fixed popup.
+fn sync_overlay_size(terminal: &TuiTerminal, app: &mut App) {
+    let Ok(size) = terminal.size() else {
+        return;
+    };
+    if app.overlays().size() == size {
+        return;
+    }
+    let root = Rect::new(0, 0, size.width, size.height);
+    app.overlays_mut()
+        .place(MESSAGE_LOG_OVERLAY, centered_rect(root, 72, 60));
+    let actions = app
+        .overlays_mut()
+        .handle_event(&ComponentEvent::Resize(size.width, size.height));
+    apply_overlay_actions(app, actions);
+}
+
+/// Hands the chat popups the chat panel layout they hit-test against; see
+/// [`App::sync_chat_popups`].
+fn sync_chat_popups(terminal: &TuiTerminal, app: &mut App) {
+    let Ok(size) = terminal.size() else {
+        return;
+    };
+    let root = Rect::new(0, 0, size.width, size.height);
+    let chat_area = render::chat_area(root, app);
+    let detail_popup = render::chat_detail_popup_area(root, app);
+    app.sync_chat_popups(chat_area, detail_popup);
+}
+
 #[cfg(test)]
 mod tests {
-    use super::parse_tmux_attach_target;
+    use dark_rust::types::{VariantIntegrateCommit, VariantIntegrateResult};
+
+    use super::{
+        CLONE_FORM, FormSignal, LoopAction, SSH_PANEL, form_action, format_integrate_status,
+        parse_attach_command, parse_export_command, parse_queue_command, parse_tmux_attach_target,
+    };
+    use crate::ui::keymap::PopupCommand;
+
+    #[test]
+    fn form_submissions_map_by_focused_field_and_command() {
+        let submitted = |form, field| FormSignal::Submitted { form, field };
+        assert!(matches!(
+            form_action(&submitted(SSH_PANEL, Some("tmux"))),
+            LoopAction::RunSshAttach
+        ));
+        assert!(matches!(
+            form_action(&submitted(SSH_PANEL, Some("forward"))),
+            LoopAction::StartSshPortForward
+        ));
+        assert!(matches!(
+            form_action(&submitted(CLONE_FORM, Some("remote_host"))),
+            LoopAction::OpenCloneHostPicker
+        ));
+        assert!(matches!(
+            form_action(&submitted(CLONE_FORM, Some("name"))),
+            LoopAction::CloneVariant
+        ));
+        assert!(matches!(
+            form_action(&FormSignal::Command(
+                SSH_PANEL,
+                PopupCommand::CopyAgentAttach
+            )),
+            LoopAction::CopyRemoteAgentAttachCommand
+        ));
+        assert!(matches!(
+            form_action(&FormSignal::Command(CLONE_FORM, PopupCommand::Attach)),
+            LoopAction::None
+        ));
+    }
+
+    #[test]
+    fn integrate_status_lists_conflicting_files() {
+        let result = VariantIntegrateResult {
+            variant_id: "var_1".to_string(),
+            status: "conflicts".to_string(),
+            strategy: "merge".to_string(),
+            base_branch: "main".to_string(),
+            source_branch: "df/demo".to_string(),
+            target_path: "/tmp/demo".to_string(),
+            commits: vec![VariantIntegrateCommit {
+                sha: "abc".to_string(),
+                author: "dev".to_string(),
+                subject: "Add feature".to_string(),
+            }],
+            conflicts: vec!["src/a.rs".to_string(), "src/b.rs".to_string()],
+            integrated_head: None,
+            pushed: false,
+            after: "keep".to_string(),
+        };
+
+        assert_eq!(
+            format_integrate_status(&result),
+            "Integrate stopped: 2 conflicting files (src/a.rs, src/b.rs); main left unchanged."
+        );
+    }
 
     #[test]
     fn parse_tmux_attach_target_accepts_attach_session() {
@@ -2984,4 +4210,35 @@ mod tests {
             .expect_err("non tmux attach command should fail");
         assert!(error.to_string().contains("not a tmux attach command"));
     }
+
+    #[test]
+    fn parse_export_command_reads_optional_path() {
+        assert_eq!(parse_export_command("/export"), Some(None));
+        assert_eq!(
+            parse_export_command("/export notes/review.json "),
+            Some(Some("notes/review.json".to_string()))
+        );
+        assert_eq!(parse_export_command("/exports"), None);
+        assert_eq!(parse_export_command("export"), None);
+    }
+
+    #[test]
+    fn parse_queue_command_reads_optional_directive() {
+        assert_eq!(parse_queue_command("/queue"), Some(None));
+        assert_eq!(
+            parse_queue_command("/queue +30m rerun the tests"),
+            Some(Some("+30m rerun the tests".to_string()))
+        );
+        assert_eq!(parse_queue_command("/queued"), None);
+    }
+
+    #[test]
+    fn parse_attach_command_reads_optional_path() {
+        assert_eq!(parse_attach_command("/attach"), Some(None));
+        assert_eq!(
+            parse_attach_command("/attach ~/Pictures/error.png"),
+            Some(Some("~/Pictures/error.png".to_string()))
+        );
+        assert_eq!(parse_attach_command("/attachment"), None);
+    }
 }
@@ -0,0 +1,160 @@
+use crossterm::event::{KeyEvent, MouseEvent};
+use ratatui::Frame;
+use ratatui::layout::{Rect, Size};
+use ratatui::style::Style;
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Clear, Paragraph, Wrap};
+
+use dark_tui_components::{
+    Action, Component, ComponentResult, ComponentThemeLike, KeyMatch, PaneBlockComponent,
+    centered_rect,
+};
+
+use super::{FormSignal, OverlayKeys};
+use crate::ui::keymap::{PROMPT_MODE, PopupCommand};
+
+pub(crate) const BULK_PROMPT_FORM: &str = "bulk-prompt-form";
+
+/// One prompt typed for every marked actor; the recipients are listed above it.
+#[derive(Debug)]
+pub(crate) struct BulkPromptOverlay {
+    recipients: Vec<String>,
+    draft: String,
+    keys: OverlayKeys,
+    popup: Rect,
+    focused: bool,
+}
+
+impl BulkPromptOverlay {
+    pub(crate) fn new() -> Self {
+        Self {
+            recipients: Vec::new(),
+            draft: String::new(),
+            keys: OverlayKeys::new(PROMPT_MODE),
+            popup: Rect::default(),
+            focused: false,
+        }
+    }
+
+    /// Starts a prompt for `recipients`, one display line per actor.
+    pub(crate) fn open(&mut self, recipients: Vec<String>, draft: String) {
+        self.recipients = recipients;
+        self.draft = draft;
+    }
+
+    /// The trimmed prompt, or `None` when it is empty.
+    pub(crate) fn take_prompt(&mut self) -> Option<String> {
+        let prompt = std::mem::take(&mut self.draft);
+        let prompt = prompt.trim();
+        (!prompt.is_empty()).then(|| prompt.to_string())
+    }
+
+    fn lines(&self, theme: &dyn ComponentThemeLike, height: u16) -> Vec<Line<'_>> {
+        let mut lines = vec![Line::from(Span::styled(
+            format!("Send to {} actors:", self.recipients.len()),
+            Style::default().fg(theme.text_muted()),
+        ))];
+
+        // Keep the prompt and hint rows visible below the recipient list.
+        let list_rows = usize::from(height).saturating_sub(6).max(1);
+        let shown = if self.recipients.len() > list_rows {
+            list_rows.saturating_sub(1)
+        } else {
+            self.recipients.len()
+        };
+        for recipient in self.recipients.iter().take(shown) {
+            lines.push(Line::from(Span::styled(
+                format!("  {recipient}"),
+                Style::default().fg(theme.entity_actor()),
+            )));
+        }
+        if self.recipients.len() > shown {
+            lines.push(Line::from(Span::styled(
+                format!("  +{} more", self.recipients.len() - shown),
+                Style::default().fg(theme.text_muted()),
+            )));
+        }
+
+        lines.push(Line::raw(""));
+        lines.push(Line::from(if self.draft.is_empty() {
+            Span::styled("> _", Style::default().fg(theme.text_muted()))
+        } else {
+            Span::styled(
+                format!("> {}_", self.draft),
+                Style::default().fg(theme.text_primary()),
+            )
+        }));
+        lines.push(Line::raw(""));
+        lines.push(Line::from(Span::styled(
+            self.keys.footer(&[
+                (&[PopupCommand::Confirm], "send to all"),
+                (&[PopupCommand::Close], "cancel"),
+            ]),
+            Style::default().fg(theme.text_muted()),
+        )));
+        lines
+    }
+}
+
+impl Component for BulkPromptOverlay {
+    fn init(&mut self, area: Size) -> ComponentResult {
+        self.popup = popup(Rect::new(0, 0, area.width, area.height));
+        Ok(())
+    }
+
+    fn handle_key_event(&mut self, key: KeyEvent) -> ComponentResult<Option<Action>> {
+        let action = match self.keys.feed(key) {
+            KeyMatch::Command(PopupCommand::Close) => Action::Cancel,
+            KeyMatch::Command(PopupCommand::Confirm) => Action::custom(FormSignal::Submitted {
+                form: BULK_PROMPT_FORM,
+                field: None,
+            }),
+            KeyMatch::NoMatch if super::edit_text(&mut self.draft, key) => Action::Render,
+            _ => return Ok(None),
+        };
+        Ok(Some(action))
+    }
+
+    fn handle_mouse_event(&mut self, mouse: MouseEvent) -> ComponentResult<Option<Action>> {
+        Ok(super::cancel_on_outside_click(self.popup, mouse))
+    }
+
+    fn update(&mut self, action: &Action) -> ComponentResult<Option<Action>> {
+        self.keys.update(action);
+        Ok(None)
+    }
+
+    fn draw(
+        &self,
+        frame: &mut Frame,
+        area: Rect,
+        theme: &dyn ComponentThemeLike,
+    ) -> ComponentResult {
+        let popup = popup(area);
+        frame.render_widget(Clear, popup);
+        let block = PaneBlockComponent::build("Prompt Marked Actors", self.focused, theme);
+        let inner = block.inner(popup);
+        frame.render_widget(block, popup);
+        frame.render_widget(
+            Paragraph::new(self.lines(theme, inner.height)).wrap(Wrap { trim: false }),
+            inner,
+        );
+        Ok(())
+    }
+
+    fn wants_focus(&self) -> bool {
+        true
+    }
+
+    fn is_focused(&self) -> bool {
+        self.focused
+    }
+
+    fn set_focused(&mut self, focused: bool) {
+        self.focused = focused;
+    }
+}
+
+fn popup(area: Rect) -> Rect {
+    centered_rect(area, 72, 50)
+}
@@ -0,0 +1,240 @@
+use crossterm::event::KeyEvent;
+use ratatui::Frame;
+use ratatui::layout::{Constraint, Layout, Rect, Size};
+use ratatui::style::{Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Clear, Paragraph};
+
+use dark_tui_components::{
+    Action, Component, ComponentResult, ComponentThemeLike, DataColumn, DataTable,
+    DataTableCommand, PaneBlockComponent, centered_rect,
+};
+
+use super::OverlayKeys;
+use crate::app::BulkRun;
+use crate::ui::keymap::{BULK_RESULTS_MODE, PopupCommand};
+
+pub(crate) const BULK_SUMMARY_OVERLAY: &str = "bulk-summary";
+
+/// Popup commands that drive the results table.
+const TABLE_COMMANDS: &[(PopupCommand, DataTableCommand)] = &[
+    (PopupCommand::Down, DataTableCommand::Down),
+    (PopupCommand::Up, DataTableCommand::Up),
+    (PopupCommand::PageDown, DataTableCommand::PageDown),
+    (PopupCommand::PageUp, DataTableCommand::PageUp),
+    (PopupCommand::Top, DataTableCommand::First),
+    (PopupCommand::Bottom, DataTableCommand::Last),
+    (PopupCommand::NextColumn, DataTableCommand::NextColumn),
+    (
+        PopupCommand::PreviousColumn,
+        DataTableCommand::PreviousColumn,
+    ),
+    (PopupCommand::Sort, DataTableCommand::Sort),
+    (PopupCommand::Widen, DataTableCommand::Widen),
+    (PopupCommand::Narrow, DataTableCommand::Narrow),
+];
+
+/// Rows the popup spends on the summary, spacing and footer around the table.
+const CHROME_ROWS: u16 = 4;
+
+/// Live progress and per-target results of the latest bulk run.
+///
+/// The app dispatches the run as an `Action::custom` payload each time a
+/// target reports. Closing the overlay never cancels the run.
+#[derive(Debug)]
+pub(crate) struct BulkSummaryOverlay {
+    run: Option<BulkRun>,
+    table: DataTable,
+    keys: OverlayKeys,
+    focused: bool,
+}
+
+impl BulkSummaryOverlay {
+    pub(crate) fn new() -> Self {
+        Self {
+            run: None,
+            table: DataTable::new(vec![
+                DataColumn::new("status", "Status", 8),
+                DataColumn::new("target", "Target", 24).min_width(8),
+                DataColumn::new("result", "Result", 20).fill(),
+            ]),
+            keys: OverlayKeys::new(BULK_RESULTS_MODE),
+            focused: false,
+        }
+    }
+
+    fn table_rows(run: &BulkRun) -> Vec<Vec<String>> {
+        run.entries
+            .iter()
+            .map(|entry| {
+                let (status, detail) = match &entry.outcome {
+                    None => ("running", String::new()),
+                    Some(Ok(message)) => ("✓ done", message.clone()),
+                    Some(Err(error)) => ("✗ failed", error.clone()),
+                };
+                vec![status.to_string(), entry.label.clone(), detail]
+            })
+            .collect()
+    }
+}
+
+impl Component for BulkSummaryOverlay {
+    fn init(&mut self, area: Size) -> ComponentResult {
+        let popup = centered_rect(Rect::new(0, 0, area.width, area.height), 72, 60);
+        // Borders, chrome and the table header.
+        let table_rows = popup.height.saturating_sub(2 + CHROME_ROWS + 1);
+        self.table.set_page_rows(table_rows as usize);
+        Ok(())
+    }
+
+    fn handle_key_event(&mut self, key: KeyEvent) -> ComponentResult<Option<Action>> {
+        let Some(command) = self.keys.resolve(key) else {
+            return Ok(None);
+        };
+        if command == PopupCommand::Close {
+            return Ok(Some(Action::Cancel));
+        }
+        let action = TABLE_COMMANDS
+            .iter()
+            .find(|(popup, _)| *popup == command)
+            .and_then(|(_, table)| self.table.apply(*table));
+        // The selected row is only highlighted here; nothing else listens.
+        Ok(action.map(|_| Action::Render))
+    }
+
+    fn update(&mut self, action: &Action) -> ComponentResult<Option<Action>> {
+        if self.keys.update(action) {
+            return Ok(None);
+        }
+        let Some(run) = action.downcast_ref::<BulkRun>() else {
+            return Ok(None);
+        };
+        self.table.set_rows(Self::table_rows(run));
+        self.run = Some(run.clone());
+        Ok(Some(Action::Render))
+    }
+
+    fn draw(
+        &self,
+        frame: &mut Frame,
+        area: Rect,
+        theme: &dyn ComponentThemeLike,
+    ) -> ComponentResult {
+        let Some(run) = self.run.as_ref() else {
+            return Ok(());
+        };
+        let popup = centered_rect(area, 72, 60);
+
+        frame.render_widget(Clear, popup);
+
+        let title = format!("Bulk {}", run.operation.label());
+        let block = PaneBlockComponent::build(&title, self.focused, theme);
+        let inner = block.inner(popup);
+        frame.render_widget(block, popup);
+
+        let [summary_area, table_area, footer_area] = Layout::vertical([
+            Constraint::Length(2),
+            Constraint::Min(1),
+            Constraint::Length(CHROME_ROWS - 2),
+        ])
+        .areas(inner);
+
+        let summary_style = if run.failed() > 0 {
+            Style::default().fg(theme.text_error())
+        } else {
+            Style::default().fg(theme.text_primary())
+        };
+        frame.render_widget(
+            Paragraph::new(Line::from(Span::styled(
+                run.summary(),
+                summary_style.add_modifier(Modifier::BOLD),
+            ))),
+            summary_area,
+        );
+
+        self.table.render(frame, table_area, theme);
+
+        let footer = self.keys.footer(&[
+            (&[PopupCommand::Down, PopupCommand::Up], "select"),
+            (&[PopupCommand::Sort], "sort"),
+            (&[PopupCommand::Widen, PopupCommand::Narrow], "resize"),
+            (
+                &[PopupCommand::Close],
+                "close (the run continues in the background)",
+            ),
+        ]);
+        let footer_lines = vec![
+            Line::raw(""),
+            Line::from(Span::styled(
+                footer,
+                Style::default().fg(theme.text_muted()),
+            )),
+        ];
+        frame.render_widget(Paragraph::new(footer_lines), footer_area);
+        Ok(())
+    }
+
+    fn wants_focus(&self) -> bool {
+        true
+    }
+
+    fn is_focused(&self) -> bool {
+        self.focused
+    }
+
+    fn set_focused(&mut self, focused: bool) {
+        self.focused = focused;
+        self.table.set_focused(focused);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+    use dark_tui_components::{Action, Component, Event};
+
+    use super::BulkSummaryOverlay;
+    use crate::app::{BulkOperation, BulkRun};
+
+    fn press(overlay: &mut BulkSummaryOverlay, code: KeyCode) -> Option<Action> {
+        overlay
+            .handle_event(&Event::Key(KeyEvent::new(code, KeyModifiers::NONE)))
+            .expect("key handled")
+    }
+
+    #[test]
+    fn results_table_selects_and_sorts_through_the_keymap() {
+        let mut run = BulkRun::new(
+            BulkOperation::Poll,
+            vec![
+                ("v1".to_string(), "zeta".to_string()),
+                ("v2".to_string(), "alpha".to_string()),
+            ],
+        );
+        run.record("v2", Err("offline".to_string()));
+        let mut overlay = BulkSummaryOverlay::new();
+        overlay
+            .update(&Action::custom(run))
+            .expect("run dispatched");
+
+        assert!(matches!(
+            press(&mut overlay, KeyCode::Down),
+            Some(Action::Render)
+        ));
+        assert_eq!(overlay.table.selected_row(), Some(1));
+
+        // Sort the target column; the selection follows its row.
+        press(&mut overlay, KeyCode::Right);
+        press(&mut overlay, KeyCode::Char('s'));
+        assert_eq!(overlay.table.selected_row(), Some(1));
+        press(&mut overlay, KeyCode::Char('g'));
+        let first = overlay.table.selected_cells().expect("row");
+        assert_eq!(first[1], "alpha");
+        assert_eq!(first[2], "offline");
+
+        assert!(matches!(
+            press(&mut overlay, KeyCode::Esc),
+            Some(Action::Cancel)
+        ));
+    }
+}
@@ -0,0 +1,126 @@
+use crossterm::event::{KeyEvent, MouseButton, MouseEvent, MouseEventKind};
+use ratatui::Frame;
+use ratatui::layout::Rect;
+
+use dark_tui_components::{Action, Component, ComponentResult, ComponentThemeLike, KeyMatch};
+
+use super::{FormSignal, OverlayKeys};
+use crate::app::CatalogQuery;
+use crate::ui::keymap::{PopupCommand, SEARCH_MODE};
+
+pub(crate) const CATALOG_SEARCH: &str = "catalog-search";
+
+/// `/` search bar. The header draws the draft, so the overlay only edits it.
+///
+/// Every edit answers with `FormSignal::Edited` and the app live-filters on
+/// the draft; confirming submits the search and keeps the last query that
+/// parsed, while closing reports `PopupCommand::Close` so the app can restore
+/// the query that was active before editing.
+#[derive(Debug)]
+pub(crate) struct CatalogSearchOverlay {
+    draft: String,
+    previous: Option<CatalogQuery>,
+    error: Option<String>,
+    keys: OverlayKeys,
+    focused: bool,
+}
+
+impl CatalogSearchOverlay {
+    pub(crate) fn new() -> Self {
+        Self {
+            draft: String::new(),
+            previous: None,
+            error: None,
+            keys: OverlayKeys::new(SEARCH_MODE),
+            focused: false,
+        }
+    }
+
+    /// Starts editing `previous`, which cancelling restores.
+    pub(crate) fn open(&mut self, previous: Option<CatalogQuery>) {
+        self.draft = previous
+            .as_ref()
+            .map(|query| query.text().to_string())
+            .unwrap_or_default();
+        self.previous = previous;
+        self.error = None;
+    }
+
+    pub(crate) fn draft(&self) -> &str {
+        &self.draft
+    }
+
+    pub(crate) fn error(&self) -> Option<&str> {
+        self.error.as_deref()
+    }
+
+    pub(crate) fn set_error(&mut self, error: Option<String>) {
+        self.error = error;
+    }
+
+    /// The query to restore when editing is cancelled.
+    pub(crate) fn take_previous(&mut self) -> Option<CatalogQuery> {
+        self.previous.take()
+    }
+
+    fn edited() -> Action {
+        Action::custom(FormSignal::Edited(CATALOG_SEARCH))
+    }
+
+    fn submitted() -> Action {
+        Action::custom(FormSignal::Submitted {
+            form: CATALOG_SEARCH,
+            field: None,
+        })
+    }
+}
+
+impl Component for CatalogSearchOverlay {
+    fn handle_key_event(&mut self, key: KeyEvent) -> ComponentResult<Option<Action>> {
+        let action = match self.keys.feed(key) {
+            KeyMatch::Command(PopupCommand::Close) => {
+                Action::custom(FormSignal::Command(CATALOG_SEARCH, PopupCommand::Close))
+            }
+            KeyMatch::Command(PopupCommand::Confirm) => Self::submitted(),
+            KeyMatch::Command(PopupCommand::Clear) => {
+                self.draft.clear();
+                Self::edited()
+            }
+            KeyMatch::NoMatch if super::edit_text(&mut self.draft, key) => Self::edited(),
+            _ => return Ok(None),
+        };
+        Ok(Some(action))
+    }
+
+    /// A click anywhere keeps the search, as if it was confirmed.
+    fn handle_mouse_event(&mut self, mouse: MouseEvent) -> ComponentResult<Option<Action>> {
+        let clicked = matches!(mouse.kind, MouseEventKind::Down(MouseButton::Left));
+        Ok(clicked.then(Self::submitted))
+    }
+
+    fn update(&mut self, action: &Action) -> ComponentResult<Option<Action>> {
+        self.keys.update(action);
+        Ok(None)
+    }
+
+    fn draw(
+        &self,
+        _frame: &mut Frame,
+        _area: Rect,
+        _theme: &dyn ComponentThemeLike,
+    ) -> ComponentResult {
+        Ok(())
+    }
+
+    fn wants_focus(&self) -> bool {
+        true
+    }
+
+    fn is_focused(&self) -> bool {
+        self.focused
+    }
+
+    fn set_focused(&mut self, focused: bool) {
+        self.focused = focused;
+    }
+}
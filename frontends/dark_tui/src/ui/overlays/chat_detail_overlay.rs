@@ -0,0 +1,123 @@
+use crossterm::event::{KeyEvent, MouseButton, MouseEvent, MouseEventKind};
+use ratatui::Frame;
+use ratatui::layout::Rect;
+
+use dark_tui_components::{Action, Component, ComponentResult, ComponentThemeLike, rect_contains};
+
+use super::OverlayKeys;
+use crate::ui::keymap::{DETAIL_MODE, PopupCommand};
+
+pub(crate) const CHAT_DETAIL: &str = "chat-detail";
+
+/// Lines one key press or wheel notch scrolls the popup by.
+const SCROLL_STEP: u16 = 3;
+
+/// Full view of one chat message. The chat panel draws the message this
+/// overlay points at; the overlay owns the scroll offset and closes on its
+/// close key or a click outside the popup.
+#[derive(Debug)]
+pub(crate) struct ChatDetailOverlay {
+    message_index: usize,
+    scroll_lines: u16,
+    /// Where the chat panel draws the popup; `None` when the panel is too small.
+    popup: Option<Rect>,
+    keys: OverlayKeys,
+    focused: bool,
+}
+
+impl ChatDetailOverlay {
+    pub(crate) fn new() -> Self {
+        Self {
+            message_index: 0,
+            scroll_lines: 0,
+            popup: None,
+            keys: OverlayKeys::new(DETAIL_MODE),
+            focused: false,
+        }
+    }
+
+    /// Shows `message_index` from the top.
+    pub(crate) fn open(&mut self, message_index: usize) {
+        self.message_index = message_index;
+        self.scroll_lines = 0;
+    }
+
+    pub(crate) fn message_index(&self) -> usize {
+        self.message_index
+    }
+
+    /// Keeps the message on the transcript after it was pruned.
+    pub(crate) fn clamp_message(&mut self, last: usize) {
+        self.message_index = self.message_index.min(last);
+    }
+
+    pub(crate) fn scroll_lines(&self) -> u16 {
+        self.scroll_lines
+    }
+
+    pub(crate) fn set_popup(&mut self, popup: Option<Rect>) {
+        self.popup = popup;
+    }
+
+    fn scroll(&mut self, down: bool) -> Action {
+        self.scroll_lines = if down {
+            self.scroll_lines.saturating_add(SCROLL_STEP)
+        } else {
+            self.scroll_lines.saturating_sub(SCROLL_STEP)
+        };
+        Action::Render
+    }
+}
+
+impl Component for ChatDetailOverlay {
+    fn handle_key_event(&mut self, key: KeyEvent) -> ComponentResult<Option<Action>> {
+        let action = match self.keys.resolve(key) {
+            Some(PopupCommand::Close) => Action::Cancel,
+            Some(PopupCommand::Up) => self.scroll(false),
+            Some(PopupCommand::Down) => self.scroll(true),
+            _ => return Ok(None),
+        };
+        Ok(Some(action))
+    }
+
+    fn handle_mouse_event(&mut self, mouse: MouseEvent) -> ComponentResult<Option<Action>> {
+        let inside = self
+            .popup
+            .is_some_and(|popup| rect_contains(popup, mouse.column, mouse.row));
+        let action = match mouse.kind {
+            MouseEventKind::ScrollUp if inside => self.scroll(false),
+            MouseEventKind::ScrollDown if inside => self.scroll(true),
+            MouseEventKind::Down(MouseButton::Left | MouseButton::Right) if !inside => {
+                Action::Cancel
+            }
+            _ => return Ok(None),
+        };
+        Ok(Some(action))
+    }
+
+    fn update(&mut self, action: &Action) -> ComponentResult<Option<Action>> {
+        self.keys.update(action);
+        Ok(None)
+    }
+
+    fn draw(
+        &self,
+        _frame: &mut Frame,
+        _area: Rect,
+        _theme: &dyn ComponentThemeLike,
+    ) -> ComponentResult {
+        Ok(())
+    }
+
+    fn wants_focus(&self) -> bool {
+        true
+    }
+
+    fn is_focused(&self) -> bool {
+        self.focused
+    }
+
+    fn set_focused(&mut self, focused: bool) {
+        self.focused = focused;
+    }
+}
@@ -0,0 +1,166 @@
+use crossterm::event::{KeyEvent, MouseButton, MouseEvent, MouseEventKind};
+use ratatui::Frame;
+use ratatui::layout::Rect;
+
+use dark_chat::framework::{QueuedPrompt, now_unix};
+use dark_tui_components::{
+    Action, Component, ComponentResult, ComponentThemeLike, PopupAnchor, PopupHit, PopupItem,
+    PopupOverlay, PopupOverlayProps, compact_text_normalized, inner_rect,
+};
+
+use super::OverlayKeys;
+use crate::ui::keymap::{PopupCommand, QUEUE_MODE};
+
+pub(crate) const CHAT_QUEUE: &str = "chat-queue";
+
+/// Work the prompt queue hands back to the dashboard, which owns the queue.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum QueueRequest {
+    SelectPrevious,
+    SelectNext,
+    /// Move the selected prompt one slot earlier (`true`) or later.
+    Reorder(bool),
+    Cancel,
+    /// Load the prompt at this row, or the selected one, into the composer.
+    Edit(Option<usize>),
+}
+
+/// Prompts queued for the chat actor, over the chat panel.
+///
+/// The app refreshes the rows through `set_prompts` every frame, so the
+/// schedule labels count down; keys and clicks answer with [`QueueRequest`].
+#[derive(Debug)]
+pub(crate) struct ChatQueueOverlay {
+    rows: Vec<PopupItem>,
+    queued: usize,
+    selected: usize,
+    parent: Rect,
+    keys: OverlayKeys,
+    focused: bool,
+}
+
+impl ChatQueueOverlay {
+    pub(crate) fn new() -> Self {
+        Self {
+            rows: Vec::new(),
+            queued: 0,
+            selected: 0,
+            parent: Rect::default(),
+            keys: OverlayKeys::new(QUEUE_MODE),
+            focused: false,
+        }
+    }
+
+    /// Shows `prompts` inside the chat panel `parent`.
+    pub(crate) fn set_prompts(&mut self, prompts: &[&QueuedPrompt], selected: usize, parent: Rect) {
+        let now = now_unix();
+        self.rows = prompts
+            .iter()
+            .map(|prompt| PopupItem {
+                label: compact_text_normalized(&prompt.prompt, 60),
+                tag: Some(prompt.schedule_label(now)),
+                active: false,
+            })
+            .collect();
+        if self.rows.is_empty() {
+            self.rows.push(PopupItem {
+                label: "Queue is empty. Use /queue [+30m] <prompt>.".to_string(),
+                tag: None,
+                active: false,
+            });
+        }
+        self.queued = prompts.len();
+        self.selected = selected;
+        self.parent = parent;
+    }
+
+    /// Popup props, or `None` when the chat panel is too small for it.
+    fn props(&self) -> Option<PopupOverlayProps> {
+        let inner = inner_rect(self.parent);
+        if inner.width < 16 || inner.height < 8 {
+            return None;
+        }
+        Some(PopupOverlayProps {
+            title: format!("Prompt Queue // {}", self.queued),
+            items: self.rows.clone(),
+            selected: self.selected,
+            query: None,
+            query_label: None,
+            hint: Some(self.keys.footer(&[
+                (&[PopupCommand::Edit], "edit"),
+                (&[PopupCommand::MoveUp, PopupCommand::MoveDown], "move"),
+                (&[PopupCommand::Remove], "cancel"),
+                (&[PopupCommand::Close], "close"),
+            ])),
+            anchor: PopupAnchor::Center,
+            max_visible: 8,
+            min_width: 32,
+            max_width: inner.width.min(72),
+        })
+    }
+}
+
+impl Component for ChatQueueOverlay {
+    fn handle_key_event(&mut self, key: KeyEvent) -> ComponentResult<Option<Action>> {
+        let request = match self.keys.resolve(key) {
+            Some(PopupCommand::Close) => return Ok(Some(Action::Cancel)),
+            Some(PopupCommand::Up) => QueueRequest::SelectPrevious,
+            Some(PopupCommand::Down) => QueueRequest::SelectNext,
+            Some(PopupCommand::MoveUp) => QueueRequest::Reorder(true),
+            Some(PopupCommand::MoveDown) => QueueRequest::Reorder(false),
+            Some(PopupCommand::Remove) => QueueRequest::Cancel,
+            Some(PopupCommand::Edit) => QueueRequest::Edit(None),
+            _ => return Ok(None),
+        };
+        Ok(Some(Action::custom(request)))
+    }
+
+    fn handle_mouse_event(&mut self, mouse: MouseEvent) -> ComponentResult<Option<Action>> {
+        let hit = self.props().map_or(PopupHit::Outside, |props| {
+            PopupOverlay::hit_test(self.parent, &props, mouse.column, mouse.row)
+        });
+        let action = match (mouse.kind, hit) {
+            (MouseEventKind::Down(MouseButton::Left), PopupHit::ListItem(index)) => {
+                Action::custom(QueueRequest::Edit(Some(index)))
+            }
+            (MouseEventKind::Down(MouseButton::Left), PopupHit::Outside) => Action::Cancel,
+            (MouseEventKind::ScrollUp, PopupHit::ListItem(_) | PopupHit::Popup) => {
+                Action::custom(QueueRequest::SelectPrevious)
+            }
+            (MouseEventKind::ScrollDown, PopupHit::ListItem(_) | PopupHit::Popup) => {
+                Action::custom(QueueRequest::SelectNext)
+            }
+            _ => return Ok(None),
+        };
+        Ok(Some(action))
+    }
+
+    fn update(&mut self, action: &Action) -> ComponentResult<Option<Action>> {
+        self.keys.update(action);
+        Ok(None)
+    }
+
+    fn draw(
+        &self,
+        frame: &mut Frame,
+        _area: Rect,
+        theme: &dyn ComponentThemeLike,
+    ) -> ComponentResult {
+        if let Some(props) = self.props() {
+            PopupOverlay::render(frame, self.parent, &props, theme);
+        }
+        Ok(())
+    }
+
+    fn wants_focus(&self) -> bool {
+        true
+    }
+
+    fn is_focused(&self) -> bool {
+        self.focused
+    }
+
+    fn set_focused(&mut self, focused: bool) {
+        self.focused = focused;
+    }
+}
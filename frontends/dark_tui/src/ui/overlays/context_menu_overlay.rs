@@ -0,0 +1,245 @@
+use crossterm::event::{KeyCode, KeyEvent, MouseButton, MouseEvent, MouseEventKind};
+use ratatui::Frame;
+use ratatui::layout::{Rect, Size};
+use ratatui::style::{Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, Clear, Paragraph};
+
+use dark_tui_components::{
+    Action, Component, ComponentResult, ComponentThemeLike, KeyMatch, rect_contains,
+};
+
+use super::OverlayKeys;
+use crate::app::VizSelection;
+use crate::ui::command_palette::{CommandId, ContextMenuState};
+use crate::ui::keymap::{MENU_MODE, PopupCommand};
+
+pub(crate) const CONTEXT_MENU: &str = "context-menu";
+
+/// A dashboard command picked in an overlay; the host runs it as if its key
+/// had been pressed on the dashboard.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct RunCommand(pub(crate) CommandId);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum MenuHit {
+    Item(usize),
+    Menu,
+    Outside,
+}
+
+/// Right-click menu of the commands that apply to a catalog node.
+///
+/// Menu keys move and pick; any other character runs the entry bound to it
+/// on the dashboard. Picking an entry answers with [`RunCommand`].
+#[derive(Debug)]
+pub(crate) struct ContextMenuOverlay {
+    menu: Option<ContextMenuState>,
+    screen: Rect,
+    keys: OverlayKeys,
+    focused: bool,
+}
+
+impl ContextMenuOverlay {
+    pub(crate) fn new() -> Self {
+        Self {
+            menu: None,
+            screen: Rect::default(),
+            keys: OverlayKeys::new(MENU_MODE),
+            focused: false,
+        }
+    }
+
+    pub(crate) fn open(&mut self, menu: ContextMenuState) {
+        self.menu = Some(menu);
+    }
+
+    /// Node the open menu acts on.
+    pub(crate) fn target(&self) -> Option<&VizSelection> {
+        self.menu.as_ref().map(|menu| &menu.target)
+    }
+
+    fn run_selected(&self) -> Action {
+        self.menu
+            .as_ref()
+            .and_then(ContextMenuState::selected_command)
+            .map_or(Action::Cancel, |command| {
+                Action::custom(RunCommand(command))
+            })
+    }
+
+    fn hit_test(&self, menu: &ContextMenuState, col: u16, row: u16) -> MenuHit {
+        let rect = menu_rect(self.screen, menu);
+        if !rect_contains(rect, col, row) {
+            return MenuHit::Outside;
+        }
+        let inner = Block::default().borders(Borders::ALL).inner(rect);
+        if !rect_contains(inner, col, row) {
+            return MenuHit::Menu;
+        }
+        let index = usize::from(row - inner.y);
+        if index < menu.entries.len() {
+            MenuHit::Item(index)
+        } else {
+            MenuHit::Menu
+        }
+    }
+}
+
+impl Component for ContextMenuOverlay {
+    fn init(&mut self, area: Size) -> ComponentResult {
+        self.screen = Rect::new(0, 0, area.width, area.height);
+        Ok(())
+    }
+
+    fn handle_key_event(&mut self, key: KeyEvent) -> ComponentResult<Option<Action>> {
+        let matched = self.keys.feed(key);
+        let Some(menu) = self.menu.as_mut() else {
+            return Ok(Some(Action::Cancel));
+        };
+        let action = match matched {
+            KeyMatch::Command(PopupCommand::Close) => Action::Cancel,
+            KeyMatch::Command(PopupCommand::Up) => {
+                menu.move_up();
+                Action::Render
+            }
+            KeyMatch::Command(PopupCommand::Down) => {
+                menu.move_down();
+                Action::Render
+            }
+            KeyMatch::Command(PopupCommand::Confirm) => self.run_selected(),
+            KeyMatch::Pending => return Ok(None),
+            _ => match key.code {
+                KeyCode::Char(_) => {
+                    let Some(command) = menu.shortcut_command(self.keys.keymap(), key) else {
+                        return Ok(None);
+                    };
+                    Action::custom(RunCommand(command))
+                }
+                _ => Action::Cancel,
+            },
+        };
+        Ok(Some(action))
+    }
+
+    fn handle_mouse_event(&mut self, mouse: MouseEvent) -> ComponentResult<Option<Action>> {
+        let Some(menu) = self.menu.as_ref() else {
+            return Ok(None);
+        };
+        let hit = self.hit_test(menu, mouse.column, mouse.row);
+        let action = match (mouse.kind, hit) {
+            (MouseEventKind::Down(MouseButton::Left), MenuHit::Item(index)) => {
+                if let Some(menu) = self.menu.as_mut() {
+                    menu.set_selected(index);
+                }
+                Some(self.run_selected())
+            }
+            (MouseEventKind::Down(MouseButton::Left), MenuHit::Outside)
+            | (MouseEventKind::Down(MouseButton::Right), _) => Some(Action::Cancel),
+            (
+                MouseEventKind::ScrollUp | MouseEventKind::ScrollDown,
+                MenuHit::Item(_) | MenuHit::Menu,
+            ) => {
+                if let Some(menu) = self.menu.as_mut() {
+                    if mouse.kind == MouseEventKind::ScrollUp {
+                        menu.move_up();
+                    } else {
+                        menu.move_down();
+                    }
+                }
+                Some(Action::Render)
+            }
+            _ => None,
+        };
+        Ok(action)
+    }
+
+    fn update(&mut self, action: &Action) -> ComponentResult<Option<Action>> {
+        self.keys.update(action);
+        Ok(None)
+    }
+
+    fn draw(
+        &self,
+        frame: &mut Frame,
+        area: Rect,
+        theme: &dyn ComponentThemeLike,
+    ) -> ComponentResult {
+        let Some(menu) = self.menu.as_ref() else {
+            return Ok(());
+        };
+        let rect = menu_rect(area, menu);
+        if rect.width < 4 || rect.height < 3 {
+            return Ok(());
+        }
+
+        let block = Block::default()
+            .title("Actions")
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(theme.pane_focused_border()));
+        let inner = block.inner(rect);
+        frame.render_widget(Clear, rect);
+        frame.render_widget(block, rect);
+
+        let lines: Vec<Line> = menu
+            .entries
+            .iter()
+            .enumerate()
+            .map(|(index, entry)| {
+                let line_style = if index == menu.selected {
+                    Style::default()
+                        .fg(theme.table_highlight_fg())
+                        .bg(theme.pill_accent_bg())
+                        .add_modifier(Modifier::BOLD)
+                } else {
+                    Style::default().fg(theme.text_primary())
+                };
+                let key_style = if index == menu.selected {
+                    line_style
+                } else {
+                    Style::default().fg(theme.key_hint_key_fg())
+                };
+                Line::from(vec![
+                    Span::styled(format!(" {} ", entry.key), key_style),
+                    Span::styled(format!(" {}", entry.label), line_style),
+                ])
+            })
+            .collect();
+        frame.render_widget(Paragraph::new(lines), inner);
+        Ok(())
+    }
+
+    fn wants_focus(&self) -> bool {
+        true
+    }
+
+    fn is_focused(&self) -> bool {
+        self.focused
+    }
+
+    fn set_focused(&mut self, focused: bool) {
+        self.focused = focused;
+    }
+}
+
+/// Menu box at the click, sized to its entries and kept on `screen`.
+fn menu_rect(screen: Rect, menu: &ContextMenuState) -> Rect {
+    let width = menu
+        .entries
+        .iter()
+        .map(|entry| entry.key.chars().count() + entry.label.len() + 4)
+        .max()
+        .unwrap_or(16)
+        .saturating_add(2) as u16;
+    let height = menu.entries.len().saturating_add(2) as u16;
+
+    let width = width.min(screen.width.max(1));
+    let height = height.min(screen.height.max(1));
+    let x = menu
+        .anchor_col
+        .min(screen.x + screen.width.saturating_sub(width));
+    let y = menu
+        .anchor_row
+        .min(screen.y + screen.height.saturating_sub(height));
+    Rect::new(x, y, width, height)
+}
@@ -0,0 +1,147 @@
+use crossterm::event::{KeyEvent, MouseEvent};
+use ratatui::Frame;
+use ratatui::layout::{Rect, Size};
+
+use dark_tui_components::{
+    Action, Component, ComponentId, ComponentResult, ComponentThemeLike, Form, FormOutcome,
+    KeyMatch, centered_rect,
+};
+
+use super::OverlayKeys;
+use crate::ui::keymap::PopupCommand;
+
+pub(crate) const SPAWN_FORM: &str = "spawn-form";
+pub(crate) const CLONE_FORM: &str = "clone-form";
+pub(crate) const BRANCH_FORM: &str = "branch-form";
+pub(crate) const DELETE_VARIANT_FORM: &str = "delete-variant-form";
+pub(crate) const INIT_PRODUCT_FORM: &str = "init-product-form";
+pub(crate) const MOVE_ACTOR_FORM: &str = "move-actor-form";
+pub(crate) const SSH_PANEL: &str = "ssh-panel";
+
+/// What a form overlay reports to the app, as an `Action::custom` payload.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum FormSignal {
+    /// A key changed the form; the app re-derives fields that depend on others.
+    Edited(ComponentId),
+    /// The form validated and was submitted with focus on `field`.
+    Submitted {
+        form: ComponentId,
+        field: Option<&'static str>,
+    },
+    /// A mode command that is not a form command, e.g. the SSH panel's attach keys.
+    Command(ComponentId, PopupCommand),
+}
+
+/// A declarative `Form` drawn as a centered popup.
+///
+/// The app builds the form and shows the overlay with `App::open_form`, and
+/// reads the values back through `ComponentRuntime::component`. Keys resolve
+/// through the overlay's keymap mode; cancelling, or clicking outside the
+/// popup, hides it.
+#[derive(Debug)]
+pub(crate) struct FormOverlay {
+    id: ComponentId,
+    form: Form,
+    keys: OverlayKeys,
+    /// Popup width and height, in percent of the screen.
+    size: (u16, u16),
+    popup: Rect,
+}
+
+impl FormOverlay {
+    pub(crate) fn new(id: ComponentId, mode: &'static str, width: u16, height: u16) -> Self {
+        Self {
+            id,
+            form: Form::new(""),
+            keys: OverlayKeys::new(mode),
+            size: (width, height),
+            popup: Rect::default(),
+        }
+    }
+
+    pub(crate) fn form(&self) -> &Form {
+        &self.form
+    }
+
+    pub(crate) fn form_mut(&mut self) -> &mut Form {
+        &mut self.form
+    }
+
+    /// Replaces the form, keeping the overlay's focus.
+    pub(crate) fn set_form(&mut self, mut form: Form) {
+        form.set_focused(self.form.is_focused());
+        self.form = form;
+    }
+
+    pub(crate) fn take_form(&mut self) -> Form {
+        std::mem::replace(&mut self.form, Form::new(""))
+    }
+
+    /// Popup rectangle inside `area`.
+    pub(crate) fn popup(&self, area: Rect) -> Rect {
+        centered_rect(area, self.size.0, self.size.1)
+    }
+
+    fn signal(&self, outcome: FormOutcome) -> Option<Action> {
+        match outcome {
+            FormOutcome::Ignored => None,
+            FormOutcome::Changed => Some(Action::custom(FormSignal::Edited(self.id))),
+            FormOutcome::Submit => Some(Action::custom(FormSignal::Submitted {
+                form: self.id,
+                field: self.form.focused_key(),
+            })),
+            FormOutcome::Cancel => Some(Action::Cancel),
+        }
+    }
+}
+
+impl Component for FormOverlay {
+    fn init(&mut self, area: Size) -> ComponentResult {
+        self.popup = self.popup(Rect::new(0, 0, area.width, area.height));
+        Ok(())
+    }
+
+    fn handle_key_event(&mut self, key: KeyEvent) -> ComponentResult<Option<Action>> {
+        let outcome = match self.keys.feed(key) {
+            KeyMatch::Command(command) => match command.form_command() {
+                Some(form_command) => self.form.handle_command(Some(form_command), key),
+                None => {
+                    return Ok(Some(Action::custom(FormSignal::Command(self.id, command))));
+                }
+            },
+            KeyMatch::Pending => FormOutcome::Ignored,
+            KeyMatch::NoMatch => self.form.handle_input(key),
+        };
+        Ok(self.signal(outcome))
+    }
+
+    fn handle_mouse_event(&mut self, mouse: MouseEvent) -> ComponentResult<Option<Action>> {
+        Ok(super::cancel_on_outside_click(self.popup, mouse))
+    }
+
+    fn update(&mut self, action: &Action) -> ComponentResult<Option<Action>> {
+        self.keys.update(action);
+        Ok(None)
+    }
+
+    fn draw(
+        &self,
+        frame: &mut Frame,
+        area: Rect,
+        theme: &dyn ComponentThemeLike,
+    ) -> ComponentResult {
+        self.form.draw(frame, self.popup(area), theme)
+    }
+
+    fn wants_focus(&self) -> bool {
+        true
+    }
+
+    fn is_focused(&self) -> bool {
+        self.form.is_focused()
+    }
+
+    fn set_focused(&mut self, focused: bool) {
+        self.form.set_focused(focused);
+    }
+}
@@ -0,0 +1,510 @@
+use std::path::PathBuf;
+
+use crossterm::event::{KeyEvent, MouseEvent};
+use ratatui::Frame;
+use ratatui::layout::{Rect, Size};
+use ratatui::style::Style;
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Clear, Paragraph, Wrap};
+
+use dark_tui_components::{
+    Action, Component, ComponentResult, ComponentThemeLike, KeyMatch, PaneBlockComponent,
+    centered_rect, next_index,
+};
+
+use super::{FormSignal, OverlayKeys};
+use crate::ui::keymap::{GIT_MODE, PROMPT_MODE, PopupCommand};
+use crate::variant_git::VariantGitSummary;
+
+pub(crate) const GIT_PANEL: &str = "git-panel";
+
+/// Status, commit message and stash/fetch/push/reset for one variant's worktree.
+///
+/// Git commands leave as [`FormSignal`]s for the dashboard to run; the
+/// dashboard reports back through [`GitPanelOverlay::apply_outcome`]. Reset to
+/// upstream discards work, so its first press only arms it.
+#[derive(Debug)]
+pub(crate) struct GitPanelOverlay {
+    variant_id: String,
+    directory: PathBuf,
+    summary: Option<VariantGitSummary>,
+    commit_message: String,
+    editing_message: bool,
+    remote_index: usize,
+    reset_armed: bool,
+    busy: bool,
+    last_outcome: Option<(bool, String)>,
+    keys: OverlayKeys,
+    message_keys: OverlayKeys,
+    popup: Rect,
+    focused: bool,
+}
+
+impl GitPanelOverlay {
+    pub(crate) fn new() -> Self {
+        Self {
+            variant_id: String::new(),
+            directory: PathBuf::new(),
+            summary: None,
+            commit_message: String::new(),
+            editing_message: false,
+            remote_index: 0,
+            reset_armed: false,
+            busy: false,
+            last_outcome: None,
+            keys: OverlayKeys::new(GIT_MODE),
+            message_keys: OverlayKeys::new(PROMPT_MODE),
+            popup: Rect::default(),
+            focused: false,
+        }
+    }
+
+    /// Starts over on `variant_id`; the summary arrives with the first git read.
+    pub(crate) fn open(&mut self, variant_id: &str, directory: PathBuf) {
+        self.variant_id = variant_id.to_string();
+        self.directory = directory;
+        self.summary = None;
+        self.commit_message.clear();
+        self.editing_message = false;
+        self.remote_index = 0;
+        self.reset_armed = false;
+        self.busy = false;
+        self.last_outcome = None;
+    }
+
+    /// Variant id and worktree the panel operates on.
+    pub(crate) fn target(&self) -> (String, PathBuf) {
+        (self.variant_id.clone(), self.directory.clone())
+    }
+
+    pub(crate) fn set_busy(&mut self, busy: bool) {
+        self.busy = busy;
+    }
+
+    /// Records an operation result; summaries for a different variant are ignored.
+    pub(crate) fn apply_outcome(
+        &mut self,
+        variant_id: &str,
+        outcome: Result<(Option<String>, VariantGitSummary), String>,
+    ) {
+        if self.variant_id != variant_id {
+            return;
+        }
+
+        self.busy = false;
+        self.reset_armed = false;
+        match outcome {
+            Ok((message, summary)) => {
+                if message.is_some() {
+                    self.commit_message.clear();
+                    self.editing_message = false;
+                }
+                if self.remote_index >= summary.remotes.len() {
+                    self.remote_index = 0;
+                }
+                self.summary = Some(summary);
+                self.last_outcome = message.map(|message| (true, message));
+            }
+            Err(error) => {
+                self.last_outcome = Some((false, error));
+            }
+        }
+    }
+
+    pub(crate) fn commit_message(&self) -> &str {
+        &self.commit_message
+    }
+
+    /// Puts a drafted message up for editing.
+    pub(crate) fn set_commit_message(&mut self, variant_id: &str, message: String) {
+        if self.variant_id != variant_id {
+            return;
+        }
+
+        self.busy = false;
+        self.commit_message = message;
+        self.editing_message = true;
+    }
+
+    pub(crate) fn remote(&self) -> Option<&str> {
+        self.summary
+            .as_ref()?
+            .remotes
+            .get(self.remote_index)
+            .map(String::as_str)
+    }
+
+    fn signal(&self, command: PopupCommand) -> Action {
+        Action::custom(FormSignal::Command(GIT_PANEL, command))
+    }
+
+    fn commit(&mut self) -> Action {
+        if !self.commit_message.trim().is_empty() {
+            return Action::custom(FormSignal::Submitted {
+                form: GIT_PANEL,
+                field: None,
+            });
+        }
+
+        self.editing_message = true;
+        let draft = self
+            .keys
+            .hint(&[PopupCommand::DraftMessage])
+            .map(|keys| format!(" or press {keys} to draft it"))
+            .unwrap_or_default();
+        Action::StatusMessage(format!("Commit needs a message: type one{draft}."))
+    }
+
+    fn handle_message_key(&mut self, key: KeyEvent) -> Option<Action> {
+        match self.message_keys.feed(key) {
+            KeyMatch::Command(PopupCommand::Close) => self.editing_message = false,
+            KeyMatch::Command(PopupCommand::Confirm) => return Some(self.commit()),
+            KeyMatch::Command(_) | KeyMatch::Pending => return None,
+            KeyMatch::NoMatch => {
+                if !super::edit_text(&mut self.commit_message, key) {
+                    return None;
+                }
+            }
+        }
+        Some(Action::Render)
+    }
+
+    /// First press arms the reset and explains how to confirm it.
+    fn reset(&mut self) -> Action {
+        if self.reset_armed {
+            self.reset_armed = false;
+            return self.signal(PopupCommand::Reset);
+        }
+
+        self.reset_armed = true;
+        let reset = self
+            .keys
+            .hint(&[PopupCommand::Reset])
+            .unwrap_or_else(|| PopupCommand::Reset.name().to_string());
+        Action::StatusMessage(format!(
+            "Press {reset} again to hard-reset the variant to its upstream."
+        ))
+    }
+
+    fn lines(&self, theme: &dyn ComponentThemeLike) -> Vec<Line<'_>> {
+        let mut lines = vec![
+            Line::from(vec![
+                Span::styled("Variant: ", Style::default().fg(theme.text_muted())),
+                Span::styled(
+                    self.variant_id.as_str(),
+                    Style::default().fg(theme.entity_variant()),
+                ),
+            ]),
+            Line::from(vec![
+                Span::styled("Path:    ", Style::default().fg(theme.text_muted())),
+                Span::styled(
+                    self.directory.display().to_string(),
+                    Style::default().fg(theme.text_secondary()),
+                ),
+            ]),
+        ];
+
+        match self.summary.as_ref() {
+            Some(summary) => self.push_summary_lines(&mut lines, summary, theme),
+            None => lines.push(Line::from(Span::styled(
+                "Reading git status...",
+                Style::default().fg(theme.text_muted()),
+            ))),
+        }
+
+        lines.push(Line::raw(""));
+
+        let editing = self.editing_message;
+        let message_hint = if editing {
+            self.message_keys.footer(&[
+                (&[PopupCommand::Confirm], "commit all changes"),
+                (&[PopupCommand::Close], "stop editing"),
+            ])
+        } else {
+            self.keys.footer(&[
+                (&[PopupCommand::EditMessage], "edit"),
+                (&[PopupCommand::DraftMessage], "draft with bound actor"),
+            ])
+        };
+        lines.push(Line::from(Span::styled(
+            format!("Commit message ({message_hint}):"),
+            Style::default().fg(theme.text_muted()),
+        )));
+        let message_style = Style::default().fg(if editing {
+            theme.text_primary()
+        } else {
+            theme.text_secondary()
+        });
+        if self.commit_message.is_empty() {
+            lines.push(Line::from(Span::styled(
+                if editing { "> _" } else { ">" },
+                message_style,
+            )));
+        } else {
+            for (index, text) in self.commit_message.lines().enumerate() {
+                let prefix = if index == 0 { "> " } else { "  " };
+                lines.push(Line::from(Span::styled(
+                    format!("{prefix}{text}"),
+                    message_style,
+                )));
+            }
+        }
+
+        lines.push(Line::raw(""));
+
+        if self.busy {
+            lines.push(Line::from(Span::styled(
+                "Working...",
+                Style::default().fg(theme.text_muted()),
+            )));
+        } else if let Some((ok, outcome)) = self.last_outcome.as_ref() {
+            lines.push(Line::from(Span::styled(
+                outcome.as_str(),
+                Style::default().fg(if *ok {
+                    theme.pill_ok_fg()
+                } else {
+                    theme.text_error()
+                }),
+            )));
+        }
+
+        if self.reset_armed {
+            let reset = self
+                .keys
+                .hint(&[PopupCommand::Reset])
+                .unwrap_or_else(|| PopupCommand::Reset.name().to_string());
+            lines.push(Line::from(Span::styled(
+                format!(
+                    "Reset armed: press {reset} again to discard local work and match upstream."
+                ),
+                Style::default().fg(theme.text_error()),
+            )));
+        }
+
+        lines.push(Line::raw(""));
+        for footer in [
+            self.keys.footer(&[
+                (&[PopupCommand::Stash], "stash"),
+                (&[PopupCommand::Unstash], "unstash"),
+                (&[PopupCommand::Fetch], "fetch"),
+                (&[PopupCommand::Push], "push"),
+                (&[PopupCommand::Reset], "reset to upstream"),
+            ]),
+            self.keys.footer(&[
+                (&[PopupCommand::Refresh], "reload"),
+                (&[PopupCommand::Close], "close"),
+            ]),
+        ] {
+            lines.push(Line::from(Span::styled(
+                footer,
+                Style::default().fg(theme.text_muted()),
+            )));
+        }
+        lines
+    }
+
+    fn push_summary_lines<'a>(
+        &'a self,
+        lines: &mut Vec<Line<'a>>,
+        summary: &'a VariantGitSummary,
+        theme: &dyn ComponentThemeLike,
+    ) {
+        let upstream = summary.upstream.as_deref().unwrap_or("no upstream");
+        lines.push(Line::from(vec![
+            Span::styled("Branch:  ", Style::default().fg(theme.text_muted())),
+            Span::styled(
+                summary.branch.as_str(),
+                Style::default().fg(theme.text_primary()),
+            ),
+            Span::styled(
+                format!("  -> {upstream}  +{} -{}", summary.ahead, summary.behind),
+                Style::default().fg(theme.text_secondary()),
+            ),
+        ]));
+        lines.push(Line::from(vec![
+            Span::styled("Changes: ", Style::default().fg(theme.text_muted())),
+            Span::styled(
+                format!(
+                    "{} staged  {} unstaged  {} untracked  {} stashed",
+                    summary.staged, summary.unstaged, summary.untracked, summary.stashes
+                ),
+                Style::default().fg(if summary.has_changes() {
+                    theme.pill_info_fg()
+                } else {
+                    theme.pill_ok_fg()
+                }),
+            ),
+        ]));
+        if summary.conflicted > 0 {
+            lines.push(Line::from(Span::styled(
+                format!("         {} conflicted files", summary.conflicted),
+                Style::default().fg(theme.text_error()),
+            )));
+        }
+        lines.push(Line::from(vec![
+            Span::styled("Last:    ", Style::default().fg(theme.text_muted())),
+            Span::styled(
+                summary.last_commit.as_deref().unwrap_or("-"),
+                Style::default().fg(theme.text_secondary()),
+            ),
+        ]));
+        let next_remote = self
+            .keys
+            .hint(&[PopupCommand::NextRemote])
+            .filter(|_| summary.remotes.len() > 1)
+            .map(|keys| format!("  ({keys}: next remote)"))
+            .unwrap_or_default();
+        lines.push(Line::from(vec![
+            Span::styled("Remote:  ", Style::default().fg(theme.text_muted())),
+            Span::styled(
+                self.remote().unwrap_or("none"),
+                Style::default().fg(theme.pill_accent_fg()),
+            ),
+            Span::styled(next_remote, Style::default().fg(theme.text_muted())),
+        ]));
+    }
+}
+
+impl Component for GitPanelOverlay {
+    fn init(&mut self, area: Size) -> ComponentResult {
+        self.popup = popup(Rect::new(0, 0, area.width, area.height));
+        Ok(())
+    }
+
+    fn handle_key_event(&mut self, key: KeyEvent) -> ComponentResult<Option<Action>> {
+        if self.editing_message {
+            return Ok(self.handle_message_key(key));
+        }
+
+        let action = match self.keys.resolve(key) {
+            Some(PopupCommand::Close) => Action::Cancel,
+            Some(PopupCommand::EditMessage) => {
+                self.editing_message = true;
+                Action::Render
+            }
+            Some(PopupCommand::Confirm) => self.commit(),
+            Some(PopupCommand::NextRemote) => {
+                let remote_count = self
+                    .summary
+                    .as_ref()
+                    .map_or(0, |summary| summary.remotes.len());
+                self.remote_index = next_index(self.remote_index, remote_count);
+                match self.remote() {
+                    Some(remote) => Action::StatusMessage(format!("Push remote: {remote}")),
+                    None => Action::Render,
+                }
+            }
+            Some(PopupCommand::Reset) => self.reset(),
+            Some(
+                command @ (PopupCommand::DraftMessage
+                | PopupCommand::Stash
+                | PopupCommand::Unstash
+                | PopupCommand::Fetch
+                | PopupCommand::Push
+                | PopupCommand::Refresh),
+            ) => self.signal(command),
+            _ => return Ok(None),
+        };
+        Ok(Some(action))
+    }
+
+    fn handle_mouse_event(&mut self, mouse: MouseEvent) -> ComponentResult<Option<Action>> {
+        Ok(super::cancel_on_outside_click(self.popup, mouse))
+    }
+
+    fn update(&mut self, action: &Action) -> ComponentResult<Option<Action>> {
+        if !self.keys.update(action) {
+            return Ok(None);
+        }
+        self.message_keys.update(action);
+        Ok(None)
+    }
+
+    fn draw(
+        &self,
+        frame: &mut Frame,
+        area: Rect,
+        theme: &dyn ComponentThemeLike,
+    ) -> ComponentResult {
+        let popup = popup(area);
+        frame.render_widget(Clear, popup);
+        let block = PaneBlockComponent::build("Variant Git", self.focused, theme);
+        let inner = block.inner(popup);
+        frame.render_widget(block, popup);
+        frame.render_widget(
+            Paragraph::new(self.lines(theme)).wrap(Wrap { trim: false }),
+            inner,
+        );
+        Ok(())
+    }
+
+    fn wants_focus(&self) -> bool {
+        true
+    }
+
+    fn is_focused(&self) -> bool {
+        self.focused
+    }
+
+    fn set_focused(&mut self, focused: bool) {
+        self.focused = focused;
+    }
+}
+
+fn popup(area: Rect) -> Rect {
+    centered_rect(area, 68, 60)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+    use dark_tui_components::{Action, Component};
+
+    use super::{GIT_PANEL, GitPanelOverlay};
+    use crate::ui::keymap::PopupCommand;
+    use crate::ui::overlays::FormSignal;
+    use crate::variant_git::VariantGitSummary;
+
+    #[test]
+    fn git_panel_reset_needs_confirmation_and_outcome_clears_message() {
+        let mut panel = GitPanelOverlay::new();
+        panel.open("var_1", PathBuf::from("/tmp/var_1"));
+        panel.set_commit_message("var_1", "Add feature".to_string());
+        panel.editing_message = false;
+
+        let mut reset = || {
+            panel
+                .handle_key_event(KeyEvent::new(KeyCode::Char('R'), KeyModifiers::SHIFT))
+                .expect("key handled")
+                .expect("reset answered")
+        };
+        assert!(matches!(reset(), Action::StatusMessage(_)));
+        let confirmed = reset();
+        assert_eq!(
+            confirmed.downcast_ref::<FormSignal>(),
+            Some(&FormSignal::Command(GIT_PANEL, PopupCommand::Reset))
+        );
+        assert!(!panel.reset_armed);
+
+        let summary = VariantGitSummary {
+            branch: "df/demo".to_string(),
+            remotes: vec!["origin".to_string()],
+            ..VariantGitSummary::default()
+        };
+        panel.apply_outcome("var_2", Ok((Some("ignored".to_string()), summary.clone())));
+        assert_eq!(panel.commit_message(), "Add feature");
+
+        panel.apply_outcome(
+            "var_1",
+            Ok((Some("Committed abc123 Add feature".to_string()), summary)),
+        );
+        assert_eq!(panel.commit_message(), "");
+        assert_eq!(panel.remote(), Some("origin"));
+        assert_eq!(
+            panel.last_outcome,
+            Some((true, "Committed abc123 Add feature".to_string()))
+        );
+    }
+}
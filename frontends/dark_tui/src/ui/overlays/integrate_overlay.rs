@@ -0,0 +1,394 @@
+use crossterm::event::{KeyEvent, MouseEvent};
+use ratatui::Frame;
+use ratatui::layout::{Rect, Size};
+use ratatui::style::Style;
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Clear, Paragraph, Wrap};
+
+use dark_rust::types::VariantIntegrateResult;
+use dark_tui_components::{
+    Action, Component, ComponentResult, ComponentThemeLike, PaneBlockComponent, centered_rect,
+    next_index,
+};
+
+use super::{FormSignal, OverlayKeys};
+use crate::ui::keymap::{INTEGRATE_MODE, PopupCommand};
+
+pub(crate) const INTEGRATE_FORM: &str = "integrate-form";
+
+const STRATEGIES: [(&str, &str); 3] = [
+    ("merge", "merge commit on the base branch"),
+    ("rebase", "replay commits, fast-forward base"),
+    ("squash", "one commit on the base branch"),
+];
+const AFTER_ACTIONS: [(&str, &str); 3] = [
+    ("keep", "keep variant as-is"),
+    ("reset", "reset variant onto the integrated base"),
+    ("delete", "delete variant + clone directory"),
+];
+
+#[derive(Debug, Clone)]
+pub(crate) struct IntegrateVariantRequest {
+    pub(crate) variant_id: String,
+    pub(crate) strategy: &'static str,
+    pub(crate) after: &'static str,
+    pub(crate) preview: bool,
+}
+
+/// Strategy and after-action pickers for integrating a variant into its base
+/// branch, with the latest preview or per-file conflicts from core.
+///
+/// Enter submits and the preview key re-runs the preview; both go out as
+/// [`FormSignal`]s and the form stays open until core answers.
+#[derive(Debug)]
+pub(crate) struct IntegrateOverlay {
+    variant_id: String,
+    strategy_index: usize,
+    after_index: usize,
+    result: Option<VariantIntegrateResult>,
+    loading: bool,
+    error: Option<String>,
+    keys: OverlayKeys,
+    popup: Rect,
+    focused: bool,
+}
+
+impl IntegrateOverlay {
+    pub(crate) fn new() -> Self {
+        Self {
+            variant_id: String::new(),
+            strategy_index: 0,
+            after_index: 0,
+            result: None,
+            loading: false,
+            error: None,
+            keys: OverlayKeys::new(INTEGRATE_MODE),
+            popup: Rect::default(),
+            focused: false,
+        }
+    }
+
+    /// Starts over on merge/keep for `variant_id`.
+    pub(crate) fn open(&mut self, variant_id: &str) {
+        self.variant_id = variant_id.to_string();
+        self.strategy_index = 0;
+        self.after_index = 0;
+        self.result = None;
+        self.loading = false;
+        self.error = None;
+    }
+
+    /// Marks the form as waiting on core and returns the request to send. The form stays
+    /// open so the preview or per-file conflicts can be shown when the response lands.
+    pub(crate) fn begin_request(&mut self, preview: bool) -> IntegrateVariantRequest {
+        self.loading = true;
+        self.error = None;
+        IntegrateVariantRequest {
+            variant_id: self.variant_id.clone(),
+            strategy: STRATEGIES[self.strategy_index].0,
+            after: AFTER_ACTIONS[self.after_index].0,
+            preview,
+        }
+    }
+
+    /// Shows a preview or conflict result; results for another variant are ignored.
+    pub(crate) fn set_result(&mut self, result: VariantIntegrateResult) {
+        if self.variant_id != result.variant_id {
+            return;
+        }
+
+        self.loading = false;
+        self.error = None;
+        self.result = Some(result);
+    }
+
+    pub(crate) fn set_error(&mut self, message: String) {
+        self.loading = false;
+        self.error = Some(message);
+    }
+
+    fn lines(&self, theme: &dyn ComponentThemeLike) -> Vec<Line<'_>> {
+        let (strategy, strategy_hint) = STRATEGIES[self.strategy_index];
+        let (after, after_label) = AFTER_ACTIONS[self.after_index];
+
+        let mut lines = vec![Line::from(vec![
+            Span::styled("Variant: ", Style::default().fg(theme.text_muted())),
+            Span::styled(
+                self.variant_id.as_str(),
+                Style::default().fg(theme.entity_variant()),
+            ),
+        ])];
+
+        if let Some(result) = self.result.as_ref() {
+            lines.push(Line::from(vec![
+                Span::styled("Branch:  ", Style::default().fg(theme.text_muted())),
+                Span::styled(
+                    result.source_branch.as_str(),
+                    Style::default().fg(theme.text_primary()),
+                ),
+                Span::styled(" -> ", Style::default().fg(theme.text_muted())),
+                Span::styled(
+                    result.base_branch.as_str(),
+                    Style::default().fg(theme.pill_info_fg()),
+                ),
+            ]));
+            lines.push(Line::from(vec![
+                Span::styled("Target:  ", Style::default().fg(theme.text_muted())),
+                Span::styled(
+                    result.target_path.as_str(),
+                    Style::default().fg(theme.text_secondary()),
+                ),
+            ]));
+        }
+
+        lines.push(Line::raw(""));
+
+        let mut strategy_spans = vec![Span::styled(
+            "Strategy: ",
+            Style::default().fg(theme.text_muted()),
+        )];
+        for (name, _) in STRATEGIES {
+            let (label, color) = if name == strategy {
+                (format!("[{name}] "), theme.pill_accent_fg())
+            } else {
+                (format!(" {name}  "), theme.text_muted())
+            };
+            strategy_spans.push(Span::styled(label, Style::default().fg(color)));
+        }
+        lines.push(Line::from(strategy_spans));
+        lines.push(Line::from(Span::styled(
+            format!("          {strategy_hint}"),
+            Style::default().fg(theme.text_secondary()),
+        )));
+
+        let after_color = match after {
+            "delete" => theme.text_error(),
+            "reset" => theme.pill_info_fg(),
+            _ => theme.pill_ok_fg(),
+        };
+        lines.push(Line::from(vec![
+            Span::styled("After:    ", Style::default().fg(theme.text_muted())),
+            Span::styled(format!("[{after}] "), Style::default().fg(after_color)),
+            Span::styled(after_label, Style::default().fg(theme.text_primary())),
+        ]));
+        lines.push(Line::raw(""));
+
+        if self.loading {
+            lines.push(Line::from(Span::styled(
+                "Waiting for dark_core...",
+                Style::default().fg(theme.text_muted()),
+            )));
+        }
+
+        if let Some(error) = self.error.as_deref() {
+            lines.push(Line::from(Span::styled(
+                error,
+                Style::default().fg(theme.text_error()),
+            )));
+        }
+
+        if let Some(result) = self.result.as_ref() {
+            push_result_lines(&mut lines, result, theme);
+        }
+
+        lines.push(Line::raw(""));
+        for footer in [
+            self.keys.footer(&[
+                (&[PopupCommand::Strategy], "strategy"),
+                (&[PopupCommand::After], "after"),
+                (&[PopupCommand::Preview], "re-preview"),
+            ]),
+            self.keys.footer(&[
+                (&[PopupCommand::Confirm], "integrate"),
+                (&[PopupCommand::Close], "close"),
+            ]),
+        ] {
+            lines.push(Line::from(Span::styled(
+                footer,
+                Style::default().fg(theme.text_muted()),
+            )));
+        }
+        lines
+    }
+}
+
+impl Component for IntegrateOverlay {
+    fn init(&mut self, area: Size) -> ComponentResult {
+        self.popup = popup(Rect::new(0, 0, area.width, area.height));
+        Ok(())
+    }
+
+    fn handle_key_event(&mut self, key: KeyEvent) -> ComponentResult<Option<Action>> {
+        let action = match self.keys.resolve(key) {
+            Some(PopupCommand::Close) => Action::Cancel,
+            Some(PopupCommand::Confirm) => Action::custom(FormSignal::Submitted {
+                form: INTEGRATE_FORM,
+                field: None,
+            }),
+            Some(PopupCommand::Preview) => {
+                Action::custom(FormSignal::Command(INTEGRATE_FORM, PopupCommand::Preview))
+            }
+            Some(PopupCommand::Strategy) => {
+                self.strategy_index = next_index(self.strategy_index, STRATEGIES.len());
+                Action::StatusMessage(format!(
+                    "Integrate strategy: {}",
+                    STRATEGIES[self.strategy_index].0
+                ))
+            }
+            Some(PopupCommand::After) => {
+                self.after_index = next_index(self.after_index, AFTER_ACTIONS.len());
+                Action::StatusMessage(format!(
+                    "After integrate: {}",
+                    AFTER_ACTIONS[self.after_index].0
+                ))
+            }
+            _ => return Ok(None),
+        };
+        Ok(Some(action))
+    }
+
+    fn handle_mouse_event(&mut self, mouse: MouseEvent) -> ComponentResult<Option<Action>> {
+        Ok(super::cancel_on_outside_click(self.popup, mouse))
+    }
+
+    fn update(&mut self, action: &Action) -> ComponentResult<Option<Action>> {
+        self.keys.update(action);
+        Ok(None)
+    }
+
+    fn draw(
+        &self,
+        frame: &mut Frame,
+        area: Rect,
+        theme: &dyn ComponentThemeLike,
+    ) -> ComponentResult {
+        let popup = popup(area);
+        frame.render_widget(Clear, popup);
+        let block = PaneBlockComponent::build("Integrate Variant", self.focused, theme);
+        let inner = block.inner(popup);
+        frame.render_widget(block, popup);
+        frame.render_widget(
+            Paragraph::new(self.lines(theme)).wrap(Wrap { trim: false }),
+            inner,
+        );
+        Ok(())
+    }
+
+    fn wants_focus(&self) -> bool {
+        true
+    }
+
+    fn is_focused(&self) -> bool {
+        self.focused
+    }
+
+    fn set_focused(&mut self, focused: bool) {
+        self.focused = focused;
+    }
+}
+
+fn popup(area: Rect) -> Rect {
+    centered_rect(area, 72, 64)
+}
+
+fn push_result_lines<'a>(
+    lines: &mut Vec<Line<'a>>,
+    result: &'a VariantIntegrateResult,
+    theme: &dyn ComponentThemeLike,
+) {
+    if !result.conflicts.is_empty() {
+        let title = if result.status == "conflicts" {
+            "Conflicting files (integrate aborted):"
+        } else {
+            "Predicted conflicts:"
+        };
+        lines.push(Line::from(Span::styled(
+            title,
+            Style::default().fg(theme.text_error()),
+        )));
+        for path in &result.conflicts {
+            lines.push(Line::from(Span::styled(
+                format!("  ! {path}"),
+                Style::default().fg(theme.text_error()),
+            )));
+        }
+        lines.push(Line::raw(""));
+    }
+
+    lines.push(Line::from(Span::styled(
+        format!("Commits to integrate ({}):", result.commits.len()),
+        Style::default().fg(theme.text_muted()),
+    )));
+    if result.commits.is_empty() {
+        lines.push(Line::from(Span::styled(
+            "  none - variant is already on the base branch",
+            Style::default().fg(theme.text_secondary()),
+        )));
+    }
+    for commit in &result.commits {
+        let short_sha: String = commit.sha.chars().take(8).collect();
+        lines.push(Line::from(vec![
+            Span::styled(
+                format!("  {short_sha} "),
+                Style::default().fg(theme.pill_accent_fg()),
+            ),
+            Span::styled(
+                commit.subject.as_str(),
+                Style::default().fg(theme.text_primary()),
+            ),
+            Span::styled(
+                format!("  ({})", commit.author),
+                Style::default().fg(theme.text_muted()),
+            ),
+        ]));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+    use dark_rust::types::VariantIntegrateResult;
+    use dark_tui_components::Component;
+
+    use super::IntegrateOverlay;
+
+    #[test]
+    fn integrate_form_keeps_conflicts_from_matching_variant_only() {
+        let mut form = IntegrateOverlay::new();
+        form.open("var_1");
+        for code in [KeyCode::Tab, KeyCode::Char('a')] {
+            form.handle_key_event(KeyEvent::new(code, KeyModifiers::NONE))
+                .expect("key handled");
+        }
+
+        let request = form.begin_request(false);
+        assert_eq!(request.strategy, "rebase");
+        assert_eq!(request.after, "reset");
+        assert!(!request.preview);
+        assert!(form.loading);
+
+        let result = |variant_id: &str| VariantIntegrateResult {
+            variant_id: variant_id.to_string(),
+            status: "conflicts".to_string(),
+            strategy: "rebase".to_string(),
+            base_branch: "main".to_string(),
+            source_branch: "df/demo".to_string(),
+            target_path: "/tmp/demo".to_string(),
+            commits: vec![],
+            conflicts: vec!["src/lib.rs".to_string()],
+            integrated_head: None,
+            pushed: false,
+            after: "reset".to_string(),
+        };
+
+        form.set_result(result("var_2"));
+        assert!(form.result.is_none());
+
+        form.set_result(result("var_1"));
+        assert!(!form.loading);
+        assert_eq!(
+            form.result.map(|result| result.conflicts),
+            Some(vec!["src/lib.rs".to_string()])
+        );
+    }
+}
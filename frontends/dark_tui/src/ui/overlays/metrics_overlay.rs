@@ -0,0 +1,379 @@
+use crossterm::event::KeyEvent;
+use ratatui::Frame;
+use ratatui::layout::{Constraint, Direction, Layout, Rect};
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Clear, Gauge, Paragraph, Sparkline};
+
+use dark_tui_components::{
+    Action, Component, ComponentResult, ComponentThemeLike, PaneBlockComponent, StatusPill,
+    centered_rect,
+};
+
+use super::OverlayKeys;
+use crate::system_metrics::{CoreHealth, MetricsHistory, SystemSample, format_bytes};
+use crate::ui::keymap::{METRICS_MODE, PopupCommand};
+
+pub(crate) const METRICS_OVERLAY: &str = "metrics";
+
+const SPARKLINE_HEIGHT: u16 = 3;
+const MAX_STATUS_GAUGES: usize = 6;
+
+/// Sparklines and gauges over the rolling `/system/metrics` history.
+///
+/// Owns the history: the run loop dispatches every `SystemSample` as an
+/// `Action::custom` payload, so samples accumulate while the overlay is hidden.
+#[derive(Debug)]
+pub(crate) struct MetricsOverlay {
+    history: MetricsHistory,
+    keys: OverlayKeys,
+    focused: bool,
+}
+
+impl MetricsOverlay {
+    pub(crate) fn new() -> Self {
+        Self {
+            history: MetricsHistory::default(),
+            keys: OverlayKeys::new(METRICS_MODE),
+            focused: false,
+        }
+    }
+}
+
+impl Component for MetricsOverlay {
+    fn handle_key_event(&mut self, key: KeyEvent) -> ComponentResult<Option<Action>> {
+        match self.keys.resolve(key) {
+            Some(PopupCommand::Close) => Ok(Some(Action::Cancel)),
+            _ => Ok(None),
+        }
+    }
+
+    fn update(&mut self, action: &Action) -> ComponentResult<Option<Action>> {
+        if self.keys.update(action) {
+            return Ok(None);
+        }
+        let Some(sample) = action.downcast_ref::<SystemSample>() else {
+            return Ok(None);
+        };
+        self.history.record(sample.clone());
+        Ok(Some(Action::Render))
+    }
+
+    fn draw(
+        &self,
+        frame: &mut Frame,
+        area: Rect,
+        theme: &dyn ComponentThemeLike,
+    ) -> ComponentResult {
+        let metrics = &self.history;
+        let popup = centered_rect(area, 80, 80);
+
+        frame.render_widget(Clear, popup);
+
+        let title = format!("Core Metrics ({} samples)", metrics.len());
+        let block = PaneBlockComponent::build(&title, self.focused, theme);
+        let inner = block.inner(popup);
+        frame.render_widget(block, popup);
+
+        let statuses = metrics.actor_status_counts();
+        let gauge_rows = statuses.len().clamp(1, MAX_STATUS_GAUGES) as u16;
+        let rows = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(1),
+                Constraint::Length(1),
+                Constraint::Length(SPARKLINE_HEIGHT + 1),
+                Constraint::Length(SPARKLINE_HEIGHT + 1),
+                Constraint::Length(1),
+                Constraint::Length(gauge_rows + 1),
+                Constraint::Min(0),
+                Constraint::Length(1),
+            ])
+            .split(inner);
+
+        frame.render_widget(Paragraph::new(health_line(metrics, theme)), rows[0]);
+        frame.render_widget(Paragraph::new(totals_line(metrics, theme)), rows[1]);
+
+        let charts = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+            .split(rows[2]);
+        let requests = metrics.rate_series("httpRequestsTotal");
+        let errors = metrics.rate_series("httpErrorsTotal");
+        render_sparkline(
+            frame,
+            charts[0],
+            &format!("Requests {:.1}/s", requests.last().copied().unwrap_or(0.0)),
+            &scaled_rates(&requests),
+            theme.pill_info_fg(),
+            theme,
+        );
+        render_sparkline(
+            frame,
+            charts[1],
+            &format!("Errors {:.1}/s", errors.last().copied().unwrap_or(0.0)),
+            &scaled_rates(&errors),
+            theme.pill_err_fg(),
+            theme,
+        );
+
+        let charts = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+            .split(rows[3]);
+        render_sparkline(
+            frame,
+            charts[0],
+            &format!(
+                "Clone queue {}",
+                metrics.latest("cloneQueueDepth").unwrap_or(0.0)
+            ),
+            &metrics.series("cloneQueueDepth"),
+            theme.pill_accent_fg(),
+            theme,
+        );
+        render_sparkline(
+            frame,
+            charts[1],
+            &format!(
+                "Memory RSS {}",
+                format_bytes(metrics.latest("memoryRssBytes").unwrap_or(0.0))
+            ),
+            &metrics.series("memoryRssBytes"),
+            theme.pill_warn_fg(),
+            theme,
+        );
+
+        frame.render_widget(
+            Paragraph::new(Line::from(Span::styled(
+                "Actors by status",
+                Style::default()
+                    .fg(theme.text_secondary())
+                    .add_modifier(Modifier::BOLD),
+            ))),
+            rows[4],
+        );
+        render_status_gauges(frame, rows[5], metrics, &statuses, theme);
+
+        frame.render_widget(
+            Paragraph::new(Line::from(Span::styled(
+                self.keys.footer(&[(&[PopupCommand::Close], "close")]),
+                Style::default().fg(theme.text_muted()),
+            ))),
+            rows[7],
+        );
+        Ok(())
+    }
+
+    fn wants_focus(&self) -> bool {
+        true
+    }
+
+    fn is_focused(&self) -> bool {
+        self.focused
+    }
+
+    fn set_focused(&mut self, focused: bool) {
+        self.focused = focused;
+    }
+}
+
+fn health_line(
+    metrics: &MetricsHistory,
+    theme: &(impl ComponentThemeLike + ?Sized),
+) -> Line<'static> {
+    let health = metrics.health();
+    let mut spans = vec![health_pill(health, theme), Span::raw(" ")];
+    if let Some(reason) = health.reason() {
+        spans.push(Span::styled(
+            reason.to_string(),
+            Style::default().fg(theme.pill_err_fg()),
+        ));
+    }
+    if let Some(error) = metrics.last_error() {
+        spans.push(Span::styled(
+            format!("  metrics: {error}"),
+            Style::default().fg(theme.pill_warn_fg()),
+        ));
+    }
+    Line::from(spans)
+}
+
+fn totals_line(
+    metrics: &MetricsHistory,
+    theme: &(impl ComponentThemeLike + ?Sized),
+) -> Line<'static> {
+    let count = |key: &str| {
+        metrics
+            .latest(key)
+            .map_or_else(|| "-".to_string(), |value| format!("{value:.0}"))
+    };
+    let bytes = |key: &str| {
+        metrics
+            .latest(key)
+            .map_or_else(|| "-".to_string(), format_bytes)
+    };
+    let uptime = metrics
+        .latest("uptimeSeconds")
+        .map_or_else(|| "-".to_string(), |seconds| uptime_label(seconds as u64));
+
+    Line::from(Span::styled(
+        format!(
+            "uptime {uptime}  products {}  variants {}  actors {}  db {}  heap {}",
+            count("productsTotal"),
+            count("variantsTotal"),
+            count("actorsTotal"),
+            bytes("dbSizeBytes"),
+            bytes("memoryHeapUsedBytes"),
+        ),
+        Style::default().fg(theme.text_secondary()),
+    ))
+}
+
+/// Header-style pill for the current dark_core health.
+pub(crate) fn health_pill(
+    health: &CoreHealth,
+    theme: &(impl ComponentThemeLike + ?Sized),
+) -> Span<'static> {
+    match health {
+        CoreHealth::Healthy => StatusPill::ok(health.label(), theme).span(),
+        CoreHealth::Degraded(_) | CoreHealth::Down(_) => {
+            StatusPill::error(health.label(), theme).span()
+        }
+        CoreHealth::Unknown => StatusPill::muted(health.label(), theme).span(),
+    }
+}
+
+fn render_sparkline(
+    frame: &mut Frame,
+    area: Rect,
+    label: &str,
+    data: &[u64],
+    color: Color,
+    theme: &(impl ComponentThemeLike + ?Sized),
+) {
+    let rows = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(1), Constraint::Min(1)])
+        .split(area);
+    frame.render_widget(
+        Paragraph::new(Line::from(Span::styled(
+            label.to_string(),
+            Style::default().fg(theme.text_secondary()),
+        ))),
+        rows[0],
+    );
+
+    // Newest samples win when the history is wider than the chart.
+    let width = rows[1].width as usize;
+    let visible = &data[data.len().saturating_sub(width)..];
+    frame.render_widget(
+        Sparkline::default()
+            .data(visible)
+            .style(Style::default().fg(color)),
+        rows[1],
+    );
+}
+
+fn render_status_gauges(
+    frame: &mut Frame,
+    area: Rect,
+    metrics: &MetricsHistory,
+    statuses: &[(String, u64)],
+    theme: &(impl ComponentThemeLike + ?Sized),
+) {
+    if statuses.is_empty() {
+        frame.render_widget(
+            Paragraph::new(Line::from(Span::styled(
+                "No actor metrics yet.",
+                Style::default().fg(theme.text_muted()),
+            ))),
+            area,
+        );
+        return;
+    }
+
+    let total = metrics
+        .latest("actorsTotal")
+        .map(|value| value.max(0.0) as u64)
+        .unwrap_or_else(|| statuses.iter().map(|(_, count)| count).sum())
+        .max(1);
+    let constraints = vec![Constraint::Length(1); statuses.len().min(MAX_STATUS_GAUGES)];
+    let rows = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints(constraints)
+        .split(area);
+
+    for ((status, count), row) in statuses.iter().zip(rows.iter()) {
+        let color = if matches!(status.as_str(), "error" | "failed") {
+            theme.pill_err_fg()
+        } else {
+            theme.pill_ok_fg()
+        };
+        frame.render_widget(
+            Gauge::default()
+                .gauge_style(Style::default().fg(color))
+                .ratio((*count as f64 / total as f64).clamp(0.0, 1.0))
+                .label(format!("{status} {count}")),
+            *row,
+        );
+    }
+}
+
+/// Counter rates as centi-units so sub-1/s traffic still draws a bar.
+fn scaled_rates(rates: &[f64]) -> Vec<u64> {
+    rates
+        .iter()
+        .map(|rate| (rate * 100.0).round() as u64)
+        .collect()
+}
+
+fn uptime_label(seconds: u64) -> String {
+    match seconds {
+        0..=59 => format!("{seconds}s"),
+        60..=3_599 => format!("{}m", seconds / 60),
+        3_600..=86_399 => format!("{}h{}m", seconds / 3_600, (seconds % 3_600) / 60),
+        _ => format!("{}d{}h", seconds / 86_400, (seconds % 86_400) / 3_600),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+    use dark_tui_components::{Action, ComponentRuntime, Event, Layer};
+
+    use super::{METRICS_OVERLAY, MetricsOverlay};
+    use crate::system_metrics::{CoreHealth, SystemSample};
+
+    #[test]
+    fn overlay_keeps_samples_while_hidden_and_closes_on_escape() {
+        let mut runtime = ComponentRuntime::new();
+        runtime
+            .mount(
+                METRICS_OVERLAY,
+                Layer::Overlay,
+                Box::new(MetricsOverlay::new()),
+            )
+            .expect("mount metrics overlay");
+
+        let sample = SystemSample {
+            health: CoreHealth::Healthy,
+            metrics: Ok(BTreeMap::from([("httpRequestsTotal".to_string(), 4.0)])),
+        };
+        let outbound = runtime
+            .dispatch(Action::custom(sample))
+            .expect("sample dispatched");
+        assert_eq!(outbound.len(), 1);
+        assert!(runtime.take_render_request());
+
+        runtime.show(METRICS_OVERLAY);
+        assert_eq!(runtime.modal(), Some(METRICS_OVERLAY));
+
+        let escape = Event::Key(KeyEvent::new(KeyCode::Esc, KeyModifiers::NONE));
+        let outbound = runtime.handle_event(&escape).expect("escape handled");
+        assert!(outbound.is_empty());
+        assert!(!runtime.is_visible(METRICS_OVERLAY));
+    }
+}
@@ -0,0 +1,173 @@
+//! Overlays driven by the shared component runtime.
+//!
+//! Each overlay is a `dark_tui_components::Component` mounted on the app's
+//! `ComponentRuntime`; it owns its state, handles its own keys, and closes
+//! itself by answering `Action::Cancel`. Keys resolve through the overlay's
+//! keymap mode (see `OverlayKeys`), so rebinding them also rewrites the
+//! footers. Adding one means a mount here plus a command that calls
+//! `App::show_overlay`; the run loop and the renderer route to whichever
+//! overlay is visible, and keys fall through to the focused pane (see
+//! `ui::panes`) while none is.
+//!
+//! Declarative forms share one component, `FormOverlay`: the app fills it
+//! through `App::open_form`, and `ui::FORM_ACTIONS` maps what each form
+//! submits to the loop action that runs it.
+
+mod bulk_prompt_overlay;
+mod bulk_summary_overlay;
+mod catalog_search_overlay;
+mod chat_detail_overlay;
+mod chat_queue_overlay;
+mod context_menu_overlay;
+mod form_overlay;
+mod git_panel_overlay;
+mod integrate_overlay;
+mod metrics_overlay;
+mod notifications_overlay;
+mod overlay_keys;
+mod palette_overlay;
+mod picker_overlay;
+mod variant_diff_overlay;
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers, MouseButton, MouseEvent, MouseEventKind};
+use ratatui::layout::Rect;
+
+use dark_tui_components::{
+    Action, ComponentId, ComponentRuntime, DynComponent, KeymapProfile, Layer, ToastHistoryCommand,
+    ToastHistoryOverlay, rect_contains,
+};
+use tracing::error;
+
+pub(crate) use self::overlay_keys::OverlayKeys;
+use crate::ui::keymap::{FORM_MODE, KeyCommand, MESSAGES_MODE, PopupCommand, SSH_MODE};
+
+pub(crate) use bulk_prompt_overlay::{BULK_PROMPT_FORM, BulkPromptOverlay};
+pub(crate) use bulk_summary_overlay::{BULK_SUMMARY_OVERLAY, BulkSummaryOverlay};
+pub(crate) use catalog_search_overlay::{CATALOG_SEARCH, CatalogSearchOverlay};
+pub(crate) use chat_detail_overlay::{CHAT_DETAIL, ChatDetailOverlay};
+pub(crate) use chat_queue_overlay::{CHAT_QUEUE, ChatQueueOverlay, QueueRequest};
+pub(crate) use context_menu_overlay::{CONTEXT_MENU, ContextMenuOverlay, RunCommand};
+pub(crate) use form_overlay::{
+    BRANCH_FORM, CLONE_FORM, DELETE_VARIANT_FORM, FormOverlay, FormSignal, INIT_PRODUCT_FORM,
+    MOVE_ACTOR_FORM, SPAWN_FORM, SSH_PANEL,
+};
+pub(crate) use git_panel_overlay::{GIT_PANEL, GitPanelOverlay};
+pub(crate) use integrate_overlay::{INTEGRATE_FORM, IntegrateOverlay};
+pub(crate) use metrics_overlay::{METRICS_OVERLAY, MetricsOverlay, health_pill};
+pub(crate) use notifications_overlay::{
+    NOTIFICATIONS_OVERLAY, NotificationHistory, NotificationsOverlay, NotificationsRequest,
+};
+pub(crate) use palette_overlay::{COMMAND_PALETTE, PaletteOverlay};
+pub(crate) use picker_overlay::{
+    CHAT_AGENT_PICKER, CHAT_MODEL_PICKER, CLONE_HOST_PICKER, Picked, PickerOption, PickerOverlay,
+};
+pub(crate) use variant_diff_overlay::{
+    LoadedVariantDiff, ReloadVariantDiff, VARIANT_DIFF_OVERLAY, VariantDiffOverlay,
+};
+
+/// Toast history; placed by the run loop and fed the app's `ToastStack`.
+pub(crate) const MESSAGE_LOG_OVERLAY: &str = "message-log";
+
+const MESSAGE_LOG_COMMANDS: &[(KeyCommand, ToastHistoryCommand)] = &[
+    (
+        KeyCommand::Popup(PopupCommand::Close),
+        ToastHistoryCommand::Close,
+    ),
+    (
+        KeyCommand::Popup(PopupCommand::Down),
+        ToastHistoryCommand::Down,
+    ),
+    (KeyCommand::Popup(PopupCommand::Up), ToastHistoryCommand::Up),
+    (
+        KeyCommand::Popup(PopupCommand::PageDown),
+        ToastHistoryCommand::PageDown,
+    ),
+    (
+        KeyCommand::Popup(PopupCommand::PageUp),
+        ToastHistoryCommand::PageUp,
+    ),
+    (
+        KeyCommand::Popup(PopupCommand::Clear),
+        ToastHistoryCommand::Clear,
+    ),
+];
+
+/// Form overlays: id, keymap mode and popup size in percent of the screen.
+const FORMS: &[(ComponentId, &str, u16, u16)] = &[
+    (SPAWN_FORM, FORM_MODE, 72, 58),
+    (CLONE_FORM, FORM_MODE, 76, 62),
+    (BRANCH_FORM, FORM_MODE, 64, 48),
+    (DELETE_VARIANT_FORM, FORM_MODE, 64, 44),
+    (INIT_PRODUCT_FORM, FORM_MODE, 76, 38),
+    (MOVE_ACTOR_FORM, FORM_MODE, 62, 52),
+    (SSH_PANEL, SSH_MODE, 82, 72),
+];
+
+/// Pickers: id, title, and minimum and maximum popup width.
+const PICKERS: &[(ComponentId, &str, u16, u16)] = &[
+    (CLONE_HOST_PICKER, "Select Remote Host", 34, 56),
+    (CHAT_MODEL_PICKER, "Select Model", 24, 42),
+    (CHAT_AGENT_PICKER, "Select Agent", 24, 42),
+];
+
+/// Mounts every runtime overlay; they start hidden.
+pub(crate) fn mount_overlays(runtime: &mut ComponentRuntime) {
+    let forms = FORMS.iter().map(|&(id, mode, width, height)| {
+        let form: DynComponent = Box::new(FormOverlay::new(id, mode, width, height));
+        (id, form)
+    });
+    let overlays: [(ComponentId, DynComponent); 13] = [
+        (METRICS_OVERLAY, Box::new(MetricsOverlay::new())),
+        (NOTIFICATIONS_OVERLAY, Box::new(NotificationsOverlay::new())),
+        (
+            MESSAGE_LOG_OVERLAY,
+            Box::new(ToastHistoryOverlay::new(
+                KeyCommand::default_keymap(),
+                MESSAGES_MODE,
+                MESSAGE_LOG_COMMANDS,
+            )),
+        ),
+        (BULK_SUMMARY_OVERLAY, Box::new(BulkSummaryOverlay::new())),
+        (VARIANT_DIFF_OVERLAY, Box::new(VariantDiffOverlay::new())),
+        (INTEGRATE_FORM, Box::new(IntegrateOverlay::new())),
+        (GIT_PANEL, Box::new(GitPanelOverlay::new())),
+        (BULK_PROMPT_FORM, Box::new(BulkPromptOverlay::new())),
+        (COMMAND_PALETTE, Box::new(PaletteOverlay::new())),
+        (CATALOG_SEARCH, Box::new(CatalogSearchOverlay::new())),
+        (CONTEXT_MENU, Box::new(ContextMenuOverlay::new())),
+        (CHAT_DETAIL, Box::new(ChatDetailOverlay::new())),
+        (CHAT_QUEUE, Box::new(ChatQueueOverlay::new())),
+    ];
+    let pickers = PICKERS.iter().map(|&(id, title, min_width, max_width)| {
+        let picker: DynComponent = Box::new(PickerOverlay::new(id, title, min_width, max_width));
+        (id, picker)
+    });
+    for (id, component) in overlays.into_iter().chain(forms).chain(pickers) {
+        if let Err(error) = runtime.mount(id, Layer::Overlay, component) {
+            error!(id, error = %error, "Dark TUI // Overlays // Mount failed");
+        }
+    }
+}
+
+/// `Action::Cancel` for a left click outside `popup`, which closes the overlay.
+fn cancel_on_outside_click(popup: Rect, mouse: MouseEvent) -> Option<Action> {
+    let clicked = matches!(mouse.kind, MouseEventKind::Down(MouseButton::Left));
+    (clicked && !rect_contains(popup, mouse.column, mouse.row)).then_some(Action::Cancel)
+}
+
+/// Types `key` into a single-line `text`: Backspace deletes, characters typed
+/// without Ctrl or Alt are appended. Returns whether `text` changed.
+fn edit_text(text: &mut String, key: KeyEvent) -> bool {
+    match key.code {
+        KeyCode::Backspace => text.pop().is_some(),
+        KeyCode::Char(value)
+            if !key
+                .modifiers
+                .intersects(KeyModifiers::CONTROL | KeyModifiers::ALT) =>
+        {
+            text.push(value);
+            true
+        }
+        _ => false,
+    }
+}
@@ -0,0 +1,194 @@
+use crossterm::event::KeyEvent;
+use ratatui::Frame;
+use ratatui::layout::{Constraint, Direction, Layout, Rect};
+use ratatui::style::{Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Clear, List, ListItem, ListState, Paragraph};
+
+use dark_chat::framework::now_unix;
+use dark_tui_components::{
+    Action, Component, ComponentResult, ComponentThemeLike, PaneBlockComponent, centered_rect,
+    compact_text_normalized, next_index, previous_index,
+};
+
+use super::OverlayKeys;
+use crate::notify::Notification;
+use crate::ui::keymap::{NOTIFICATIONS_MODE, PopupCommand};
+
+pub(crate) const NOTIFICATIONS_OVERLAY: &str = "notifications";
+
+/// Notification history, newest first, dispatched whenever it changes.
+#[derive(Debug, Clone)]
+pub(crate) struct NotificationHistory(pub(crate) Vec<Notification>);
+
+/// Work the overlay hands back to the dashboard.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum NotificationsRequest {
+    /// Select the actor or variant with this id.
+    Jump(String),
+    Clear,
+}
+
+/// History of actor status and clone notifications, newest first.
+#[derive(Debug)]
+pub(crate) struct NotificationsOverlay {
+    history: Vec<Notification>,
+    selected: usize,
+    keys: OverlayKeys,
+    focused: bool,
+}
+
+impl NotificationsOverlay {
+    pub(crate) fn new() -> Self {
+        Self {
+            history: Vec::new(),
+            selected: 0,
+            keys: OverlayKeys::new(NOTIFICATIONS_MODE),
+            focused: false,
+        }
+    }
+}
+
+impl Component for NotificationsOverlay {
+    fn handle_key_event(&mut self, key: KeyEvent) -> ComponentResult<Option<Action>> {
+        let Some(command) = self.keys.resolve(key) else {
+            return Ok(None);
+        };
+        let action = match command {
+            PopupCommand::Close => Some(Action::Cancel),
+            PopupCommand::Down => {
+                self.selected = next_index(self.selected, self.history.len());
+                Some(Action::Render)
+            }
+            PopupCommand::Up => {
+                self.selected = previous_index(self.selected, self.history.len());
+                Some(Action::Render)
+            }
+            PopupCommand::Confirm => self.history.get(self.selected).map(|notification| {
+                Action::custom(NotificationsRequest::Jump(
+                    notification.event.target_id().to_string(),
+                ))
+            }),
+            PopupCommand::Clear => Some(Action::custom(NotificationsRequest::Clear)),
+            _ => None,
+        };
+        Ok(action)
+    }
+
+    fn update(&mut self, action: &Action) -> ComponentResult<Option<Action>> {
+        if self.keys.update(action) {
+            return Ok(None);
+        }
+        let Some(NotificationHistory(history)) = action.downcast_ref::<NotificationHistory>()
+        else {
+            return Ok(None);
+        };
+        self.history = history.clone();
+        self.selected = 0;
+        Ok(Some(Action::Render))
+    }
+
+    fn draw(
+        &self,
+        frame: &mut Frame,
+        area: Rect,
+        theme: &dyn ComponentThemeLike,
+    ) -> ComponentResult {
+        let popup = centered_rect(area, 72, 60);
+
+        frame.render_widget(Clear, popup);
+
+        let title = format!("Notifications ({})", self.history.len());
+        let block = PaneBlockComponent::build(&title, self.focused, theme);
+        let inner = block.inner(popup);
+        frame.render_widget(block, popup);
+
+        let rows = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Min(1), Constraint::Length(1)])
+            .split(inner);
+
+        let now = now_unix();
+        let message_width = (rows[0].width as usize).saturating_sub(14).max(8);
+        let items: Vec<ListItem<'static>> = self
+            .history
+            .iter()
+            .map(|notification| {
+                let (glyph, glyph_style) = if notification.event.is_failure() {
+                    ("✗", Style::default().fg(theme.text_error()))
+                } else if notification.event.kind() == "actor.status" {
+                    ("●", Style::default().fg(theme.entity_actor()))
+                } else {
+                    ("✓", Style::default().fg(theme.pill_ok_fg()))
+                };
+                ListItem::new(Line::from(vec![
+                    Span::styled(
+                        format!("{:>4} ", age_label(now, notification.at)),
+                        Style::default().fg(theme.text_muted()),
+                    ),
+                    Span::styled(format!("{glyph} "), glyph_style),
+                    Span::styled(
+                        compact_text_normalized(&notification.event.message(), message_width),
+                        Style::default().fg(theme.text_primary()),
+                    ),
+                ]))
+            })
+            .collect();
+
+        if items.is_empty() {
+            frame.render_widget(
+                Paragraph::new(Line::from(Span::styled(
+                    "No notifications yet. Actor status changes and finished clones show up here.",
+                    Style::default().fg(theme.text_muted()),
+                ))),
+                rows[0],
+            );
+        } else {
+            let mut state = ListState::default();
+            state.select(Some(self.selected));
+            let list = List::new(items).highlight_symbol("> ").highlight_style(
+                Style::default()
+                    .fg(theme.table_highlight_fg())
+                    .add_modifier(Modifier::BOLD),
+            );
+            frame.render_stateful_widget(list, rows[0], &mut state);
+        }
+
+        let footer = self.keys.footer(&[
+            (&[PopupCommand::Confirm], "jump to target"),
+            (&[PopupCommand::Clear], "clear"),
+            (&[PopupCommand::Close], "close"),
+        ]);
+        frame.render_widget(
+            Paragraph::new(Line::from(Span::styled(
+                footer,
+                Style::default().fg(theme.text_muted()),
+            ))),
+            rows[1],
+        );
+        Ok(())
+    }
+
+    fn wants_focus(&self) -> bool {
+        true
+    }
+
+    fn is_focused(&self) -> bool {
+        self.focused
+    }
+
+    fn set_focused(&mut self, focused: bool) {
+        self.focused = focused;
+    }
+}
+
+/// Compact age such as `12s`, `4m`, `3h` or `2d`.
+fn age_label(now: u64, at: u64) -> String {
+    let seconds = now.saturating_sub(at);
+    match seconds {
+        0..=59 => format!("{seconds}s"),
+        60..=3_599 => format!("{}m", seconds / 60),
+        3_600..=86_399 => format!("{}h", seconds / 3_600),
+        _ => format!("{}d", seconds / 86_400),
+    }
+}
@@ -0,0 +1,68 @@
+use crossterm::event::KeyEvent;
+use dark_tui_components::{Action, KeyMatch, KeySequenceMatcher, Keymap};
+
+use crate::ui::keymap::{KeyCommand, PopupCommand, default_keymap, popup_hint, popup_match};
+
+/// An overlay's keymap mode. Starts on the default keys; the app dispatches
+/// the loaded `Keymap<KeyCommand>` to every overlay, which hands it here.
+#[derive(Debug)]
+pub(crate) struct OverlayKeys {
+    keymap: Keymap<KeyCommand>,
+    mode: &'static str,
+    matcher: KeySequenceMatcher,
+}
+
+impl OverlayKeys {
+    pub(crate) fn new(mode: &'static str) -> Self {
+        Self {
+            keymap: default_keymap(),
+            mode,
+            matcher: KeySequenceMatcher::default(),
+        }
+    }
+
+    /// Popup command bound to `key`; `None` for unbound keys and pending sequences.
+    pub(crate) fn resolve(&mut self, key: KeyEvent) -> Option<PopupCommand> {
+        match self.feed(key) {
+            KeyMatch::Command(command) => Some(command),
+            _ => None,
+        }
+    }
+
+    /// Like [`Self::resolve`], but tells unbound keys (`NoMatch`, which text
+    /// fields type) from a pending sequence prefix.
+    pub(crate) fn feed(&mut self, key: KeyEvent) -> KeyMatch<PopupCommand> {
+        popup_match(self.matcher.feed(&self.keymap, self.mode, key))
+    }
+
+    /// Takes the keymap if `action` carries one.
+    pub(crate) fn update(&mut self, action: &Action) -> bool {
+        let Some(keymap) = action.downcast_ref::<Keymap<KeyCommand>>() else {
+            return false;
+        };
+        self.keymap = keymap.clone();
+        self.matcher.reset();
+        true
+    }
+
+    /// The keymap this overlay resolves against, for lookups in other modes.
+    pub(crate) fn keymap(&self) -> &Keymap<KeyCommand> {
+        &self.keymap
+    }
+
+    /// Keys bound to `commands`, such as `up/down`; `None` when none is bound.
+    pub(crate) fn hint(&self, commands: &[PopupCommand]) -> Option<String> {
+        popup_hint(&self.keymap, self.mode, commands)
+    }
+
+    /// Footer such as `Enter: jump   Esc: close`; entries without a bound key are left out.
+    pub(crate) fn footer(&self, entries: &[(&[PopupCommand], &str)]) -> String {
+        entries
+            .iter()
+            .filter_map(|(commands, label)| {
+                self.hint(commands).map(|keys| format!("{keys}: {label}"))
+            })
+            .collect::<Vec<_>>()
+            .join("   ")
+    }
+}
@@ -0,0 +1,256 @@
+use crossterm::event::{KeyEvent, MouseEvent};
+use ratatui::Frame;
+use ratatui::layout::{Rect, Size};
+use ratatui::style::{Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Clear, Paragraph};
+
+use dark_tui_components::{
+    Action, Component, ComponentResult, ComponentThemeLike, KeyMatch, PaneBlockComponent,
+    centered_rect, next_index, previous_index,
+};
+
+use super::{FormSignal, OverlayKeys};
+use crate::ui::command_palette::PaletteItem;
+use crate::ui::keymap::{PALETTE_MODE, PopupCommand};
+
+pub(crate) const COMMAND_PALETTE: &str = "command-palette";
+
+/// `:` command line over the command catalog and recent lines.
+///
+/// Edits answer with `FormSignal::Edited`, after which the app refills the
+/// rows through `set_rows`; confirming submits the palette and the host runs
+/// the typed line, or the highlighted row when the line is empty.
+#[derive(Debug)]
+pub(crate) struct PaletteOverlay {
+    input: String,
+    /// Rows for `input`, each with the key its command is bound to.
+    rows: Vec<(PaletteItem, String)>,
+    selected: usize,
+    keys: OverlayKeys,
+    popup: Rect,
+    focused: bool,
+}
+
+impl PaletteOverlay {
+    pub(crate) fn new() -> Self {
+        Self {
+            input: String::new(),
+            rows: Vec::new(),
+            selected: 0,
+            keys: OverlayKeys::new(PALETTE_MODE),
+            popup: Rect::default(),
+            focused: false,
+        }
+    }
+
+    /// Starts an empty command line.
+    pub(crate) fn open(&mut self) {
+        self.input.clear();
+        self.selected = 0;
+    }
+
+    pub(crate) fn input(&self) -> &str {
+        &self.input
+    }
+
+    pub(crate) fn set_rows(&mut self, rows: Vec<(PaletteItem, String)>) {
+        self.rows = rows;
+        self.selected = self.selected.min(self.rows.len().saturating_sub(1));
+    }
+
+    pub(crate) fn highlighted(&self) -> Option<&PaletteItem> {
+        self.rows.get(self.selected).map(|(item, _)| item)
+    }
+
+    fn edited(&mut self) -> Action {
+        self.selected = 0;
+        Action::custom(FormSignal::Edited(COMMAND_PALETTE))
+    }
+
+    fn row_line<'a>(
+        item: &PaletteItem,
+        key: &str,
+        highlight: bool,
+        theme: &dyn ComponentThemeLike,
+    ) -> Line<'a> {
+        let row_style = |style: Style| {
+            if highlight {
+                style
+                    .fg(theme.table_highlight_fg())
+                    .bg(theme.pill_accent_bg())
+                    .add_modifier(Modifier::BOLD)
+            } else {
+                style
+            }
+        };
+
+        let spans = match item {
+            PaletteItem::Recent(line) => vec![
+                Span::styled(
+                    format!(" {:<5}", "↺"),
+                    row_style(Style::default().fg(theme.text_muted())),
+                ),
+                Span::styled(
+                    line.clone(),
+                    row_style(Style::default().fg(theme.text_secondary())),
+                ),
+            ],
+            PaletteItem::Command {
+                id,
+                disabled_reason,
+            } => {
+                let name_color = if disabled_reason.is_some() {
+                    theme.text_muted()
+                } else {
+                    theme.text_primary()
+                };
+                let mut spans = vec![
+                    Span::styled(
+                        format!(" {key:<5}"),
+                        row_style(Style::default().fg(theme.key_hint_key_fg())),
+                    ),
+                    Span::styled(
+                        format!("{:<12}", id.name()),
+                        row_style(Style::default().fg(name_color)),
+                    ),
+                    Span::styled(
+                        id.description(),
+                        row_style(Style::default().fg(theme.text_secondary())),
+                    ),
+                ];
+                if !id.usage().is_empty() {
+                    spans.push(Span::styled(
+                        format!("  {}", id.usage()),
+                        row_style(Style::default().fg(theme.pill_accent_fg())),
+                    ));
+                }
+                if let Some(reason) = disabled_reason {
+                    spans.push(Span::styled(
+                        format!("  ({reason})"),
+                        row_style(Style::default().fg(theme.text_error())),
+                    ));
+                }
+                spans
+            }
+        };
+        Line::from(spans)
+    }
+}
+
+impl Component for PaletteOverlay {
+    fn init(&mut self, area: Size) -> ComponentResult {
+        self.popup = popup(Rect::new(0, 0, area.width, area.height));
+        Ok(())
+    }
+
+    fn handle_key_event(&mut self, key: KeyEvent) -> ComponentResult<Option<Action>> {
+        let action = match self.keys.feed(key) {
+            KeyMatch::Command(PopupCommand::Close) => Action::Cancel,
+            KeyMatch::Command(PopupCommand::Confirm) => Action::custom(FormSignal::Submitted {
+                form: COMMAND_PALETTE,
+                field: None,
+            }),
+            KeyMatch::Command(PopupCommand::Up) => {
+                self.selected = previous_index(self.selected, self.rows.len());
+                Action::Render
+            }
+            KeyMatch::Command(PopupCommand::Down) => {
+                self.selected = next_index(self.selected, self.rows.len());
+                Action::Render
+            }
+            KeyMatch::Command(PopupCommand::Complete) => {
+                let Some(completion) = self.highlighted().map(PaletteItem::completion) else {
+                    return Ok(None);
+                };
+                self.input = completion;
+                self.edited()
+            }
+            KeyMatch::NoMatch if super::edit_text(&mut self.input, key) => self.edited(),
+            _ => return Ok(None),
+        };
+        Ok(Some(action))
+    }
+
+    fn handle_mouse_event(&mut self, mouse: MouseEvent) -> ComponentResult<Option<Action>> {
+        Ok(super::cancel_on_outside_click(self.popup, mouse))
+    }
+
+    fn update(&mut self, action: &Action) -> ComponentResult<Option<Action>> {
+        self.keys.update(action);
+        Ok(None)
+    }
+
+    fn draw(
+        &self,
+        frame: &mut Frame,
+        area: Rect,
+        theme: &dyn ComponentThemeLike,
+    ) -> ComponentResult {
+        let popup = popup(area);
+        frame.render_widget(Clear, popup);
+        let block = PaneBlockComponent::build("Commands", self.focused, theme);
+        let inner = block.inner(popup);
+        frame.render_widget(block, popup);
+
+        let mut lines = vec![
+            Line::from(vec![
+                Span::styled(": ", Style::default().fg(theme.key_hint_key_fg())),
+                Span::styled(
+                    format!("{}_", self.input),
+                    Style::default().fg(theme.text_primary()),
+                ),
+            ]),
+            Line::raw(""),
+        ];
+        if self.rows.is_empty() {
+            lines.push(Line::from(Span::styled(
+                "  no matching commands",
+                Style::default().fg(theme.text_muted()),
+            )));
+        }
+
+        // Keep the highlighted row visible once the list outgrows the popup.
+        let visible_rows = usize::from(inner.height.saturating_sub(lines.len() as u16 + 2)).max(1);
+        let first_row = self.selected.saturating_sub(visible_rows - 1);
+        for (index, (item, key)) in self
+            .rows
+            .iter()
+            .enumerate()
+            .skip(first_row)
+            .take(visible_rows)
+        {
+            lines.push(Self::row_line(item, key, index == self.selected, theme));
+        }
+
+        lines.push(Line::raw(""));
+        lines.push(Line::from(Span::styled(
+            self.keys.footer(&[
+                (&[PopupCommand::Confirm], "run"),
+                (&[PopupCommand::Complete], "complete"),
+                (&[PopupCommand::Up, PopupCommand::Down], "select"),
+                (&[PopupCommand::Close], "close"),
+            ]),
+            Style::default().fg(theme.text_muted()),
+        )));
+
+        frame.render_widget(Paragraph::new(lines), inner);
+        Ok(())
+    }
+
+    fn wants_focus(&self) -> bool {
+        true
+    }
+
+    fn is_focused(&self) -> bool {
+        self.focused
+    }
+
+    fn set_focused(&mut self, focused: bool) {
+        self.focused = focused;
+    }
+}
+
+fn popup(area: Rect) -> Rect {
+    centered_rect(area, 64, 60)
+}
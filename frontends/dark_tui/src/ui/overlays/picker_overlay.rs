@@ -0,0 +1,237 @@
+use crossterm::event::{KeyEvent, MouseButton, MouseEvent, MouseEventKind};
+use ratatui::Frame;
+use ratatui::layout::Rect;
+
+use dark_tui_components::{
+    Action, Component, ComponentId, ComponentResult, ComponentThemeLike, KeyMatch, PopupAnchor,
+    PopupHit, PopupItem, PopupOverlay, PopupOverlayProps, inner_rect, next_index, previous_index,
+};
+
+use super::OverlayKeys;
+use crate::ui::keymap::{PICKER_MODE, PopupCommand};
+
+pub(crate) const CLONE_HOST_PICKER: &str = "clone-host-picker";
+pub(crate) const CHAT_MODEL_PICKER: &str = "chat-model-picker";
+pub(crate) const CHAT_AGENT_PICKER: &str = "chat-agent-picker";
+
+/// The option a picker was confirmed on, as an `Action::custom` payload.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Picked {
+    pub(crate) picker: ComponentId,
+    pub(crate) value: String,
+}
+
+/// One pickable value; the filter matches its label and search terms.
+#[derive(Debug, Clone)]
+pub(crate) struct PickerOption {
+    value: String,
+    label: String,
+    terms: String,
+}
+
+impl PickerOption {
+    pub(crate) fn new(value: impl Into<String>, label: impl Into<String>) -> Self {
+        let value = value.into();
+        let label = label.into();
+        let terms = format!("{value} {label}").to_ascii_lowercase();
+        Self {
+            value,
+            label,
+            terms,
+        }
+    }
+
+    /// Also matches the filter against `terms`.
+    pub(crate) fn searching(mut self, terms: &str) -> Self {
+        self.terms.push(' ');
+        self.terms.push_str(&terms.to_ascii_lowercase());
+        self
+    }
+}
+
+/// Filterable list that opens next to the field or label it picks for.
+///
+/// The host fills it through `open` with the options, the current value and
+/// where to draw; confirming or clicking an option answers with [`Picked`],
+/// and the host applies the value and hides the picker.
+#[derive(Debug)]
+pub(crate) struct PickerOverlay {
+    id: ComponentId,
+    title: &'static str,
+    min_width: u16,
+    max_width: u16,
+    options: Vec<PickerOption>,
+    query: String,
+    selected: usize,
+    parent: Rect,
+    anchor: PopupAnchor,
+    keys: OverlayKeys,
+    focused: bool,
+}
+
+impl PickerOverlay {
+    pub(crate) fn new(
+        id: ComponentId,
+        title: &'static str,
+        min_width: u16,
+        max_width: u16,
+    ) -> Self {
+        Self {
+            id,
+            title,
+            min_width,
+            max_width,
+            options: Vec::new(),
+            query: String::new(),
+            selected: 0,
+            parent: Rect::default(),
+            anchor: PopupAnchor::Center,
+            keys: OverlayKeys::new(PICKER_MODE),
+            focused: false,
+        }
+    }
+
+    /// Starts an empty filter on `current`, drawn inside `parent` from `anchor`.
+    pub(crate) fn open(
+        &mut self,
+        options: Vec<PickerOption>,
+        current: Option<&str>,
+        parent: Rect,
+        anchor: PopupAnchor,
+    ) {
+        self.selected = current
+            .and_then(|current| options.iter().position(|option| option.value == current))
+            .unwrap_or(0);
+        self.options = options;
+        self.query.clear();
+        self.parent = parent;
+        self.anchor = anchor;
+    }
+
+    fn matches(&self) -> Vec<&PickerOption> {
+        let query = self.query.trim().to_ascii_lowercase();
+        self.options
+            .iter()
+            .filter(|option| option.terms.contains(&query))
+            .collect()
+    }
+
+    fn pick(&self) -> Option<Action> {
+        let option = self.matches().get(self.selected).copied()?;
+        Some(Action::custom(Picked {
+            picker: self.id,
+            value: option.value.clone(),
+        }))
+    }
+
+    fn move_selection(&mut self, forward: bool) {
+        let len = self.matches().len();
+        self.selected = if forward {
+            next_index(self.selected, len)
+        } else {
+            previous_index(self.selected, len)
+        };
+    }
+
+    /// Keeps the selection on the list after the filter changed.
+    fn clamp_selection(&mut self) {
+        let len = self.matches().len();
+        self.selected = self.selected.min(len.saturating_sub(1));
+    }
+
+    fn props(&self) -> PopupOverlayProps {
+        PopupOverlayProps {
+            title: self.title.to_string(),
+            items: self
+                .matches()
+                .into_iter()
+                .map(|option| PopupItem {
+                    label: option.label.clone(),
+                    tag: None,
+                    active: false,
+                })
+                .collect(),
+            selected: self.selected,
+            query: Some(self.query.clone()),
+            query_label: Some("FILTER".to_string()),
+            hint: Some(self.keys.footer(&[
+                (&[PopupCommand::Confirm], "select"),
+                (&[PopupCommand::Up, PopupCommand::Down], "scroll"),
+                (&[PopupCommand::Close], "close"),
+            ])),
+            anchor: self.anchor.clone(),
+            max_visible: 8,
+            min_width: self.min_width,
+            max_width: inner_rect(self.parent).width.min(self.max_width),
+        }
+    }
+}
+
+impl Component for PickerOverlay {
+    fn handle_key_event(&mut self, key: KeyEvent) -> ComponentResult<Option<Action>> {
+        match self.keys.feed(key) {
+            KeyMatch::Command(PopupCommand::Close) => return Ok(Some(Action::Cancel)),
+            KeyMatch::Command(PopupCommand::Confirm) => {
+                return Ok(Some(self.pick().unwrap_or_else(|| {
+                    Action::StatusMessage("No option matches the filter.".to_string())
+                })));
+            }
+            KeyMatch::Command(PopupCommand::Up) => self.move_selection(false),
+            KeyMatch::Command(PopupCommand::Down) => self.move_selection(true),
+            KeyMatch::Command(PopupCommand::Clear) => {
+                self.query.clear();
+                self.clamp_selection();
+            }
+            KeyMatch::NoMatch if super::edit_text(&mut self.query, key) => self.clamp_selection(),
+            _ => return Ok(None),
+        }
+        Ok(Some(Action::Render))
+    }
+
+    fn handle_mouse_event(&mut self, mouse: MouseEvent) -> ComponentResult<Option<Action>> {
+        let hit = PopupOverlay::hit_test(self.parent, &self.props(), mouse.column, mouse.row);
+        let action = match mouse.kind {
+            MouseEventKind::Down(MouseButton::Left) => match hit {
+                PopupHit::ListItem(index) => {
+                    self.selected = index;
+                    self.pick()
+                }
+                PopupHit::Outside => Some(Action::Cancel),
+                PopupHit::Popup | PopupHit::Query => None,
+            },
+            MouseEventKind::ScrollUp | MouseEventKind::ScrollDown if hit != PopupHit::Outside => {
+                self.move_selection(mouse.kind == MouseEventKind::ScrollDown);
+                Some(Action::Render)
+            }
+            _ => None,
+        };
+        Ok(action)
+    }
+
+    fn update(&mut self, action: &Action) -> ComponentResult<Option<Action>> {
+        self.keys.update(action);
+        Ok(None)
+    }
+
+    fn draw(
+        &self,
+        frame: &mut Frame,
+        _area: Rect,
+        theme: &dyn ComponentThemeLike,
+    ) -> ComponentResult {
+        PopupOverlay::render(frame, self.parent, &self.props(), theme);
+        Ok(())
+    }
+
+    fn wants_focus(&self) -> bool {
+        true
+    }
+
+    fn is_focused(&self) -> bool {
+        self.focused
+    }
+
+    fn set_focused(&mut self, focused: bool) {
+        self.focused = focused;
+    }
+}
@@ -0,0 +1,676 @@
+use crossterm::event::{KeyEvent, MouseButton, MouseEvent, MouseEventKind};
+use ratatui::Frame;
+use ratatui::layout::{Constraint, Direction, Layout, Rect, Size};
+use ratatui::style::{Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Clear, Paragraph};
+
+use dark_tui_components::{
+    Action, Component, ComponentResult, ComponentThemeLike, PaneBlockComponent, centered_rect,
+    next_index, previous_index, rect_contains,
+};
+
+use super::OverlayKeys;
+use crate::ui::keymap::{DIFF_MODE, PopupCommand};
+use crate::variant_diff::{
+    DiffBase, DiffFile, DiffLine, DiffLineKind, VariantDiff, side_by_side_rows,
+};
+
+pub(crate) const VARIANT_DIFF_OVERLAY: &str = "variant-diff";
+
+const FILE_LIST_MAX_WIDTH: u16 = 44;
+
+/// A freshly loaded diff and the display name of its variant.
+#[derive(Debug, Clone)]
+pub(crate) struct LoadedVariantDiff {
+    pub(crate) variant_name: String,
+    pub(crate) diff: VariantDiff,
+}
+
+/// Asks the dashboard to reload the selected variant's diff against `base`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct ReloadVariantDiff(pub(crate) DiffBase);
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub(crate) enum DiffLayout {
+    #[default]
+    Unified,
+    SideBySide,
+}
+
+#[derive(Debug, Clone)]
+struct DiffView {
+    variant_name: String,
+    diff: VariantDiff,
+    selected_file: usize,
+    scroll: u16,
+    layout: DiffLayout,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum DiffHit {
+    File(usize),
+    FileList,
+    Hunks,
+    Panel,
+    Outside,
+}
+
+struct DiffAreas {
+    popup: Rect,
+    summary: Rect,
+    files: Rect,
+    hunks: Rect,
+    help: Rect,
+}
+
+/// File list and hunks of a variant's working-tree diff.
+///
+/// Closing drops the diff, so the next open starts unified on the first file;
+/// a reload of the same variant keeps the layout and the selected file.
+#[derive(Debug)]
+pub(crate) struct VariantDiffOverlay {
+    view: Option<DiffView>,
+    screen: Rect,
+    keys: OverlayKeys,
+    focused: bool,
+}
+
+impl VariantDiffOverlay {
+    pub(crate) fn new() -> Self {
+        Self {
+            view: None,
+            screen: Rect::default(),
+            keys: OverlayKeys::new(DIFF_MODE),
+            focused: false,
+        }
+    }
+
+    fn load(&mut self, loaded: &LoadedVariantDiff) {
+        let (layout, selected_path) = match self.view.as_ref() {
+            Some(current) if current.diff.variant_id == loaded.diff.variant_id => (
+                current.layout,
+                current
+                    .diff
+                    .files
+                    .get(current.selected_file)
+                    .map(|file| (file.section, file.path.clone())),
+            ),
+            Some(current) => (current.layout, None),
+            None => (DiffLayout::Unified, None),
+        };
+        let selected_file = selected_path
+            .and_then(|(section, path)| {
+                loaded
+                    .diff
+                    .files
+                    .iter()
+                    .position(|file| file.section == section && file.path == path)
+            })
+            .unwrap_or(0);
+
+        self.view = Some(DiffView {
+            variant_name: loaded.variant_name.clone(),
+            diff: loaded.diff.clone(),
+            selected_file,
+            scroll: 0,
+            layout,
+        });
+    }
+
+    fn close(&mut self) -> Action {
+        self.view = None;
+        Action::Cancel
+    }
+
+    fn select(&mut self, index: usize) {
+        let Some(view) = self.view.as_mut() else {
+            return;
+        };
+        if index < view.diff.files.len() {
+            view.selected_file = index;
+            view.scroll = 0;
+        }
+    }
+
+    fn move_selection(&mut self, forward: bool) {
+        if let Some(view) = self.view.as_mut() {
+            let len = view.diff.files.len();
+            view.selected_file = if forward {
+                next_index(view.selected_file, len)
+            } else {
+                previous_index(view.selected_file, len)
+            };
+            view.scroll = 0;
+        }
+    }
+
+    fn scroll_by(&mut self, delta: i32) {
+        if let Some(view) = self.view.as_mut() {
+            view.scroll = (i32::from(view.scroll) + delta).clamp(0, i32::from(u16::MAX)) as u16;
+        }
+    }
+
+    fn toggle_layout(&mut self) {
+        if let Some(view) = self.view.as_mut() {
+            view.layout = match view.layout {
+                DiffLayout::Unified => DiffLayout::SideBySide,
+                DiffLayout::SideBySide => DiffLayout::Unified,
+            };
+            view.scroll = 0;
+        }
+    }
+
+    fn reload(&self, toggle_base: bool) -> Option<Action> {
+        let base = self.view.as_ref()?.diff.base;
+        let base = if toggle_base { base.toggled() } else { base };
+        Some(Action::custom(ReloadVariantDiff(base)))
+    }
+
+    fn hit_test(&self, col: u16, row: u16) -> DiffHit {
+        let areas = areas(self.screen);
+        if !rect_contains(areas.popup, col, row) {
+            return DiffHit::Outside;
+        }
+
+        if rect_contains(areas.files, col, row) {
+            let Some(view) = self.view.as_ref() else {
+                return DiffHit::FileList;
+            };
+            let rows = file_list_rows(&view.diff.files);
+            let offset = list_offset(&rows, view.selected_file, areas.files.height);
+            let index = offset + usize::from(row - areas.files.y);
+            return match rows.get(index) {
+                Some(Some(file_index)) => DiffHit::File(*file_index),
+                _ => DiffHit::FileList,
+            };
+        }
+
+        if rect_contains(areas.hunks, col, row) {
+            return DiffHit::Hunks;
+        }
+
+        DiffHit::Panel
+    }
+}
+
+impl Component for VariantDiffOverlay {
+    fn init(&mut self, area: Size) -> ComponentResult {
+        self.screen = Rect::new(0, 0, area.width, area.height);
+        Ok(())
+    }
+
+    fn handle_key_event(&mut self, key: KeyEvent) -> ComponentResult<Option<Action>> {
+        match self.keys.resolve(key) {
+            Some(PopupCommand::Close) => return Ok(Some(self.close())),
+            Some(PopupCommand::Up) => self.move_selection(false),
+            Some(PopupCommand::Down) => self.move_selection(true),
+            Some(PopupCommand::ScrollUp) => self.scroll_by(-1),
+            Some(PopupCommand::ScrollDown) => self.scroll_by(1),
+            Some(PopupCommand::PageUp) => self.scroll_by(-20),
+            Some(PopupCommand::PageDown) => self.scroll_by(20),
+            Some(PopupCommand::Top) => self.scroll_by(-i32::from(u16::MAX)),
+            Some(PopupCommand::Layout) => self.toggle_layout(),
+            Some(PopupCommand::ToggleBase) => return Ok(self.reload(true)),
+            Some(PopupCommand::Refresh) => return Ok(self.reload(false)),
+            _ => return Ok(None),
+        }
+        Ok(Some(Action::Render))
+    }
+
+    fn handle_mouse_event(&mut self, mouse: MouseEvent) -> ComponentResult<Option<Action>> {
+        let click = matches!(mouse.kind, MouseEventKind::Down(MouseButton::Left));
+        match (self.hit_test(mouse.column, mouse.row), mouse.kind) {
+            (DiffHit::Outside, _) if click => return Ok(Some(self.close())),
+            (DiffHit::File(index), _) if click => self.select(index),
+            (DiffHit::File(_) | DiffHit::FileList, MouseEventKind::ScrollUp) => {
+                self.move_selection(false)
+            }
+            (DiffHit::File(_) | DiffHit::FileList, MouseEventKind::ScrollDown) => {
+                self.move_selection(true)
+            }
+            (DiffHit::Hunks, MouseEventKind::ScrollUp) => self.scroll_by(-3),
+            (DiffHit::Hunks, MouseEventKind::ScrollDown) => self.scroll_by(3),
+            _ => return Ok(None),
+        }
+        Ok(Some(Action::Render))
+    }
+
+    fn update(&mut self, action: &Action) -> ComponentResult<Option<Action>> {
+        if self.keys.update(action) {
+            return Ok(None);
+        }
+        let Some(loaded) = action.downcast_ref::<LoadedVariantDiff>() else {
+            return Ok(None);
+        };
+        self.load(loaded);
+        Ok(Some(Action::Render))
+    }
+
+    fn draw(
+        &self,
+        frame: &mut Frame,
+        area: Rect,
+        theme: &dyn ComponentThemeLike,
+    ) -> ComponentResult {
+        let Some(view) = self.view.as_ref() else {
+            return Ok(());
+        };
+        let diff = &view.diff;
+        let areas = areas(area);
+
+        frame.render_widget(Clear, areas.popup);
+        let title = format!("Diff // {}", view.variant_name);
+        let block = PaneBlockComponent::build(&title, self.focused, theme);
+        frame.render_widget(block, areas.popup);
+
+        let base = match diff.base {
+            DiffBase::Head => "HEAD".to_string(),
+            DiffBase::ProductBranch => format!("product branch {}", diff.base_label),
+        };
+        let layout = match view.layout {
+            DiffLayout::Unified => "unified",
+            DiffLayout::SideBySide => "side-by-side",
+        };
+        let summary = Line::from(vec![
+            Span::styled(
+                format!("vs {base}"),
+                Style::default().fg(theme.entity_variant()),
+            ),
+            Span::styled(
+                format!("  {} files  ", diff.files.len()),
+                Style::default().fg(theme.text_secondary()),
+            ),
+            Span::styled(
+                format!("+{}", diff.additions()),
+                Style::default().fg(theme.pill_ok_fg()),
+            ),
+            Span::styled(
+                format!(" -{}", diff.deletions()),
+                Style::default().fg(theme.text_error()),
+            ),
+            Span::styled(
+                format!("  [{layout}]"),
+                Style::default().fg(theme.text_muted()),
+            ),
+        ]);
+        frame.render_widget(Paragraph::new(summary), areas.summary);
+
+        render_file_list(frame, areas.files, view, theme);
+
+        match diff.files.get(view.selected_file) {
+            Some(file) => match view.layout {
+                DiffLayout::Unified => render_unified(frame, areas.hunks, file, view.scroll, theme),
+                DiffLayout::SideBySide => {
+                    render_side_by_side(frame, areas.hunks, file, view.scroll, theme)
+                }
+            },
+            None => {
+                frame.render_widget(
+                    Paragraph::new(Span::styled(
+                        "No changes.",
+                        Style::default().fg(theme.text_muted()),
+                    )),
+                    areas.hunks,
+                );
+            }
+        }
+
+        frame.render_widget(
+            Paragraph::new(Span::styled(
+                self.keys.footer(&[
+                    (&[PopupCommand::Up, PopupCommand::Down], "file"),
+                    (
+                        &[
+                            PopupCommand::ScrollUp,
+                            PopupCommand::ScrollDown,
+                            PopupCommand::PageUp,
+                            PopupCommand::PageDown,
+                        ],
+                        "scroll",
+                    ),
+                    (&[PopupCommand::Layout], "unified/side-by-side"),
+                    (&[PopupCommand::ToggleBase], "HEAD/product branch"),
+                    (&[PopupCommand::Refresh], "reload"),
+                    (&[PopupCommand::Close], "close"),
+                ]),
+                Style::default().fg(theme.text_muted()),
+            )),
+            areas.help,
+        );
+        Ok(())
+    }
+
+    fn wants_focus(&self) -> bool {
+        true
+    }
+
+    fn is_focused(&self) -> bool {
+        self.focused
+    }
+
+    fn set_focused(&mut self, focused: bool) {
+        self.focused = focused;
+    }
+}
+
+fn areas(area: Rect) -> DiffAreas {
+    let popup = centered_rect(area, 92, 86);
+    let inner = Rect {
+        x: popup.x.saturating_add(1),
+        y: popup.y.saturating_add(1),
+        width: popup.width.saturating_sub(2),
+        height: popup.height.saturating_sub(2),
+    };
+
+    let rows = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(1),
+            Constraint::Length(1),
+            Constraint::Min(1),
+            Constraint::Length(1),
+        ])
+        .split(inner);
+    let file_width = (rows[2].width / 3).min(FILE_LIST_MAX_WIDTH);
+    let columns = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([
+            Constraint::Length(file_width),
+            Constraint::Length(1),
+            Constraint::Min(1),
+        ])
+        .split(rows[2]);
+
+    DiffAreas {
+        popup,
+        summary: rows[0],
+        files: columns[0],
+        hunks: columns[2],
+        help: rows[3],
+    }
+}
+
+fn render_file_list(
+    frame: &mut Frame,
+    area: Rect,
+    view: &DiffView,
+    theme: &(impl ComponentThemeLike + ?Sized),
+) {
+    let files = &view.diff.files;
+    let selected = view.selected_file;
+    let rows = file_list_rows(files);
+    let offset = list_offset(&rows, selected, area.height);
+
+    let lines: Vec<Line> = rows
+        .iter()
+        .enumerate()
+        .skip(offset)
+        .take(usize::from(area.height))
+        .map(|(row_index, row)| match row {
+            Some(file_index) => {
+                let file = &files[*file_index];
+                let is_selected = *file_index == selected;
+                let style = if is_selected {
+                    Style::default()
+                        .fg(theme.entity_variant())
+                        .add_modifier(Modifier::BOLD)
+                } else {
+                    Style::default().fg(theme.text_primary())
+                };
+                Line::from(vec![
+                    Span::styled(if is_selected { "> " } else { "  " }, style),
+                    Span::styled(
+                        format!("{} ", file.status.marker()),
+                        Style::default().fg(theme.text_muted()),
+                    ),
+                    Span::styled(file.path.clone(), style),
+                    Span::styled(
+                        format!(" +{}", file.additions),
+                        Style::default().fg(theme.pill_ok_fg()),
+                    ),
+                    Span::styled(
+                        format!(" -{}", file.deletions),
+                        Style::default().fg(theme.text_error()),
+                    ),
+                ])
+            }
+            None => {
+                let section = files
+                    .get(section_file_index(&rows, row_index))
+                    .map(|file| file.section.label())
+                    .unwrap_or_default();
+                Line::from(Span::styled(
+                    section.to_uppercase(),
+                    Style::default()
+                        .fg(theme.text_muted())
+                        .add_modifier(Modifier::BOLD),
+                ))
+            }
+        })
+        .collect();
+
+    frame.render_widget(Paragraph::new(lines), area);
+}
+
+fn render_unified(
+    frame: &mut Frame,
+    area: Rect,
+    file: &DiffFile,
+    scroll: u16,
+    theme: &(impl ComponentThemeLike + ?Sized),
+) {
+    let mut lines = file_header_lines(file, theme);
+    for hunk in &file.hunks {
+        lines.push(Line::from(Span::styled(
+            hunk.header.clone(),
+            Style::default().fg(theme.pill_info_fg()),
+        )));
+        for line in &hunk.lines {
+            let mut spans = vec![Span::styled(
+                format!(
+                    "{} {} ",
+                    line_number(line.old_line),
+                    line_number(line.new_line)
+                ),
+                Style::default().fg(theme.text_muted()),
+            )];
+            spans.push(diff_text_span(Some(line), theme));
+            lines.push(Line::from(spans));
+        }
+    }
+
+    let scroll = clamp_scroll(scroll, lines.len());
+    frame.render_widget(Paragraph::new(lines).scroll((scroll, 0)), area);
+}
+
+fn render_side_by_side(
+    frame: &mut Frame,
+    area: Rect,
+    file: &DiffFile,
+    scroll: u16,
+    theme: &(impl ComponentThemeLike + ?Sized),
+) {
+    let header = file_header_lines(file, theme);
+    let mut left = header.clone();
+    let mut right = header;
+    for hunk in &file.hunks {
+        let header = Line::from(Span::styled(
+            hunk.header.clone(),
+            Style::default().fg(theme.pill_info_fg()),
+        ));
+        left.push(header.clone());
+        right.push(header);
+        for (old, new) in side_by_side_rows(hunk) {
+            left.push(side_line(old, old.and_then(|line| line.old_line), theme));
+            right.push(side_line(new, new.and_then(|line| line.new_line), theme));
+        }
+    }
+
+    let scroll = clamp_scroll(scroll, left.len());
+    let columns = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([
+            Constraint::Percentage(50),
+            Constraint::Length(1),
+            Constraint::Percentage(50),
+        ])
+        .split(area);
+    frame.render_widget(Paragraph::new(left).scroll((scroll, 0)), columns[0]);
+    frame.render_widget(Paragraph::new(right).scroll((scroll, 0)), columns[2]);
+}
+
+/// File list rows: `None` marks a section heading, `Some(index)` a file.
+fn file_list_rows(files: &[DiffFile]) -> Vec<Option<usize>> {
+    let mut rows = Vec::with_capacity(files.len() + 3);
+    let mut section = None;
+    for (index, file) in files.iter().enumerate() {
+        if section != Some(file.section) {
+            section = Some(file.section);
+            rows.push(None);
+        }
+        rows.push(Some(index));
+    }
+    rows
+}
+
+fn section_file_index(rows: &[Option<usize>], heading_row: usize) -> usize {
+    rows.get(heading_row + 1).copied().flatten().unwrap_or(0)
+}
+
+/// First visible row, keeping the selected file on screen.
+fn list_offset(rows: &[Option<usize>], selected: usize, height: u16) -> usize {
+    let height = usize::from(height.max(1));
+    let selected_row = rows
+        .iter()
+        .position(|row| *row == Some(selected))
+        .unwrap_or(0);
+    (selected_row + 1).saturating_sub(height)
+}
+
+fn file_header_lines(
+    file: &DiffFile,
+    theme: &(impl ComponentThemeLike + ?Sized),
+) -> Vec<Line<'static>> {
+    let title = match file.old_path.as_deref() {
+        Some(old_path) => format!("{old_path} -> {}", file.path),
+        None => file.path.clone(),
+    };
+    let mut lines = vec![Line::from(Span::styled(
+        format!("{title} ({})", file.section.label()),
+        Style::default()
+            .fg(theme.text_primary())
+            .add_modifier(Modifier::BOLD),
+    ))];
+
+    let note = if file.binary {
+        Some("(binary file)")
+    } else if file.hunks.is_empty() {
+        Some("(no textual changes)")
+    } else {
+        None
+    };
+    if let Some(note) = note {
+        lines.push(Line::from(Span::styled(
+            note,
+            Style::default().fg(theme.text_muted()),
+        )));
+    }
+
+    lines
+}
+
+fn side_line(
+    line: Option<&DiffLine>,
+    number: Option<u32>,
+    theme: &(impl ComponentThemeLike + ?Sized),
+) -> Line<'static> {
+    Line::from(vec![
+        Span::styled(
+            format!("{} ", line_number(number)),
+            Style::default().fg(theme.text_muted()),
+        ),
+        diff_text_span(line, theme),
+    ])
+}
+
+fn diff_text_span(
+    line: Option<&DiffLine>,
+    theme: &(impl ComponentThemeLike + ?Sized),
+) -> Span<'static> {
+    let Some(line) = line else {
+        return Span::raw("");
+    };
+    let (marker, color) = match line.kind {
+        DiffLineKind::Added => ('+', theme.pill_ok_fg()),
+        DiffLineKind::Removed => ('-', theme.text_error()),
+        DiffLineKind::Context => (' ', theme.text_secondary()),
+    };
+    Span::styled(
+        format!("{marker}{}", line.text.replace('\t', "    ")),
+        Style::default().fg(color),
+    )
+}
+
+fn line_number(value: Option<u32>) -> String {
+    value
+        .map(|number| format!("{number:>4}"))
+        .unwrap_or_else(|| "    ".to_string())
+}
+
+fn clamp_scroll(scroll: u16, line_count: usize) -> u16 {
+    let max = u16::try_from(line_count.saturating_sub(1)).unwrap_or(u16::MAX);
+    scroll.min(max)
+}
+
+#[cfg(test)]
+mod tests {
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+    use dark_tui_components::{Action, Component};
+
+    use super::{DiffLayout, LoadedVariantDiff, ReloadVariantDiff, VariantDiffOverlay};
+    use crate::variant_diff::{DiffBase, DiffSection, VariantDiff, parse_unified_diff};
+
+    #[test]
+    fn reload_keeps_selected_file_and_layout() {
+        let text = "diff --git a/a.rs b/a.rs\n@@ -1 +1 @@\n-a\n+b\ndiff --git a/b.rs b/b.rs\n@@ -1 +1 @@\n-c\n+d\n";
+        let loaded = |files| {
+            Action::custom(LoadedVariantDiff {
+                variant_name: "feature".to_string(),
+                diff: VariantDiff {
+                    variant_id: "var_1".to_string(),
+                    base: DiffBase::Head,
+                    base_label: "HEAD".to_string(),
+                    files,
+                },
+            })
+        };
+        let key = |code| KeyEvent::new(code, KeyModifiers::NONE);
+
+        let mut overlay = VariantDiffOverlay::new();
+        overlay
+            .update(&loaded(parse_unified_diff(DiffSection::Unstaged, text)))
+            .expect("diff loaded");
+        for code in [KeyCode::Char('j'), KeyCode::Char('s'), KeyCode::Char('J')] {
+            overlay.handle_key_event(key(code)).expect("key handled");
+        }
+
+        let mut reloaded = parse_unified_diff(DiffSection::Unstaged, text);
+        reloaded.reverse();
+        overlay.update(&loaded(reloaded)).expect("diff reloaded");
+
+        let view = overlay.view.as_ref().expect("diff shown");
+        assert_eq!(view.diff.files[view.selected_file].path, "b.rs");
+        assert_eq!(view.layout, DiffLayout::SideBySide);
+        assert_eq!(view.scroll, 0);
+
+        let toggle = overlay
+            .handle_key_event(key(KeyCode::Char('b')))
+            .expect("key handled")
+            .expect("reload requested");
+        assert_eq!(
+            toggle.downcast_ref::<ReloadVariantDiff>(),
+            Some(&ReloadVariantDiff(DiffBase::ProductBranch))
+        );
+    }
+}
@@ -0,0 +1,92 @@
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use ratatui::Frame;
+use ratatui::layout::Rect;
+
+use dark_tui_components::{Action, Component, ComponentResult, ComponentThemeLike, KeyMatch};
+
+use crate::ui::keymap::{COMPOSE_MODE, PopupCommand};
+use crate::ui::overlays::OverlayKeys;
+
+pub(crate) const CHAT_COMPOSER: &str = "chat-composer";
+
+/// Edit the app makes to the chat draft, which the composer, its
+/// autocomplete and the prompt queue share.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum ComposeRequest {
+    Insert(char),
+    /// Deletes the last character, or the last attachment once the draft is empty.
+    Backspace,
+    /// Attaches the image on the clipboard.
+    PasteImage,
+    Send,
+    Cancel,
+}
+
+/// Chat composer while it has focus; the chat panel draws the draft.
+#[derive(Debug)]
+pub(crate) struct ComposerPane {
+    keys: OverlayKeys,
+    focused: bool,
+}
+
+impl ComposerPane {
+    pub(crate) fn new() -> Self {
+        Self {
+            keys: OverlayKeys::new(COMPOSE_MODE),
+            focused: false,
+        }
+    }
+}
+
+impl Component for ComposerPane {
+    fn handle_key_event(&mut self, key: KeyEvent) -> ComponentResult<Option<Action>> {
+        if super::is_quit(key) {
+            return Ok(Some(Action::Quit));
+        }
+
+        let request = match self.keys.feed(key) {
+            KeyMatch::Command(PopupCommand::Close) => ComposeRequest::Cancel,
+            KeyMatch::Command(PopupCommand::Confirm) => ComposeRequest::Send,
+            KeyMatch::Command(PopupCommand::Paste) => ComposeRequest::PasteImage,
+            KeyMatch::Command(_) | KeyMatch::Pending => return Ok(None),
+            KeyMatch::NoMatch => match key.code {
+                KeyCode::Backspace => ComposeRequest::Backspace,
+                KeyCode::Char(value)
+                    if !key
+                        .modifiers
+                        .intersects(KeyModifiers::CONTROL | KeyModifiers::ALT) =>
+                {
+                    ComposeRequest::Insert(value)
+                }
+                _ => return Ok(None),
+            },
+        };
+        Ok(Some(Action::custom(request)))
+    }
+
+    fn update(&mut self, action: &Action) -> ComponentResult<Option<Action>> {
+        self.keys.update(action);
+        Ok(None)
+    }
+
+    fn draw(
+        &self,
+        _frame: &mut Frame,
+        _area: Rect,
+        _theme: &dyn ComponentThemeLike,
+    ) -> ComponentResult {
+        Ok(())
+    }
+
+    fn wants_focus(&self) -> bool {
+        true
+    }
+
+    fn is_focused(&self) -> bool {
+        self.focused
+    }
+
+    fn set_focused(&mut self, focused: bool) {
+        self.focused = focused;
+    }
+}
@@ -0,0 +1,185 @@
+use std::path::PathBuf;
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use ratatui::Frame;
+use ratatui::layout::Rect;
+
+use dark_tui_components::{Action, Component, ComponentResult, ComponentThemeLike, KeyMatch};
+
+use crate::core_logs::CoreLogView;
+use crate::ui::keymap::{LOGS_MODE, PopupCommand, SEARCH_MODE};
+use crate::ui::overlays::OverlayKeys;
+
+pub(crate) const CORE_LOGS: &str = "core-logs";
+
+/// Lines a page key scrolls by.
+const PAGE_LINES: isize = 10;
+
+/// What the focused log viewer asks of the dashboard.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum CoreLogsRequest {
+    /// Hand keys back to the dashboard.
+    Unfocus,
+    /// Hide the viewer.
+    Toggle,
+    /// Select the first catalog node the selected line mentions.
+    JumpToEntity,
+}
+
+/// Docked viewer of the tailed dark_core log: scrollback, follow/pause,
+/// level and module filters, and incremental search. Owns the
+/// [`CoreLogView`] the run loop feeds and `CoreLogsPanel` draws.
+#[derive(Debug)]
+pub(crate) struct CoreLogsPane {
+    view: CoreLogView,
+    keys: OverlayKeys,
+    search_keys: OverlayKeys,
+    focused: bool,
+}
+
+impl CoreLogsPane {
+    pub(crate) fn new(path: Option<PathBuf>) -> Self {
+        Self {
+            view: CoreLogView::new(path),
+            keys: OverlayKeys::new(LOGS_MODE),
+            search_keys: OverlayKeys::new(SEARCH_MODE),
+            focused: false,
+        }
+    }
+
+    pub(crate) fn view(&self) -> &CoreLogView {
+        &self.view
+    }
+
+    pub(crate) fn view_mut(&mut self) -> &mut CoreLogView {
+        &mut self.view
+    }
+
+    /// Points the viewer at `path`, dropping buffered lines.
+    pub(crate) fn set_path(&mut self, path: Option<PathBuf>) {
+        self.view = CoreLogView::new(path);
+    }
+
+    fn edit_search(&mut self, key: KeyEvent) -> Option<Action> {
+        match self.search_keys.feed(key) {
+            KeyMatch::Command(PopupCommand::Close) => self.view.cancel_search(),
+            KeyMatch::Command(PopupCommand::Confirm) => self.view.finish_search(),
+            KeyMatch::Command(PopupCommand::Clear) => self.view.begin_search(),
+            KeyMatch::Command(_) | KeyMatch::Pending => return None,
+            KeyMatch::NoMatch => match key.code {
+                KeyCode::Backspace => self.view.search_backspace(),
+                KeyCode::Char(value) if !key.modifiers.contains(KeyModifiers::CONTROL) => {
+                    self.view.search_insert_char(value);
+                }
+                _ => return None,
+            },
+        }
+        Some(Action::Render)
+    }
+
+    fn run(&mut self, command: PopupCommand) -> Option<Action> {
+        let logs = &mut self.view;
+        let action = match command {
+            PopupCommand::Close => Action::custom(CoreLogsRequest::Unfocus),
+            PopupCommand::ToggleLogs => Action::custom(CoreLogsRequest::Toggle),
+            PopupCommand::Confirm => Action::custom(CoreLogsRequest::JumpToEntity),
+            PopupCommand::Down => {
+                logs.scroll(1);
+                Action::Render
+            }
+            PopupCommand::Up => {
+                logs.scroll(-1);
+                Action::Render
+            }
+            PopupCommand::PageDown => {
+                logs.scroll(PAGE_LINES);
+                Action::Render
+            }
+            PopupCommand::PageUp => {
+                logs.scroll(-PAGE_LINES);
+                Action::Render
+            }
+            PopupCommand::Top => {
+                logs.scroll_to_top();
+                Action::Render
+            }
+            PopupCommand::Bottom => {
+                logs.follow();
+                Action::Render
+            }
+            PopupCommand::Follow => {
+                logs.toggle_follow();
+                Action::Render
+            }
+            PopupCommand::Level => {
+                logs.cycle_level_filter();
+                let level = logs.min_level().map_or("all", |level| level.label());
+                Action::StatusMessage(format!("Core logs level filter: {level}."))
+            }
+            PopupCommand::Module => {
+                logs.cycle_module_filter();
+                let module = logs.module_filter().unwrap_or("all");
+                Action::StatusMessage(format!("Core logs module filter: {module}."))
+            }
+            PopupCommand::Search => {
+                logs.begin_search();
+                Action::Render
+            }
+            PopupCommand::NextMatch | PopupCommand::PreviousMatch => {
+                let older = command == PopupCommand::NextMatch;
+                if logs.jump_to_match(older) {
+                    Action::Render
+                } else {
+                    let direction = if older { "older" } else { "newer" };
+                    Action::StatusMessage(format!("No {direction} match in core logs."))
+                }
+            }
+            PopupCommand::Clear => {
+                logs.clear();
+                Action::StatusMessage("Core logs cleared.".to_string())
+            }
+            _ => return None,
+        };
+        Some(action)
+    }
+}
+
+impl Component for CoreLogsPane {
+    fn handle_key_event(&mut self, key: KeyEvent) -> ComponentResult<Option<Action>> {
+        if super::is_quit(key) {
+            return Ok(Some(Action::Quit));
+        }
+        if self.view.is_search_editing() {
+            return Ok(self.edit_search(key));
+        }
+        Ok(self.keys.resolve(key).and_then(|command| self.run(command)))
+    }
+
+    fn update(&mut self, action: &Action) -> ComponentResult<Option<Action>> {
+        if self.keys.update(action) {
+            self.search_keys.update(action);
+        }
+        Ok(None)
+    }
+
+    fn draw(
+        &self,
+        _frame: &mut Frame,
+        _area: Rect,
+        _theme: &dyn ComponentThemeLike,
+    ) -> ComponentResult {
+        Ok(())
+    }
+
+    fn wants_focus(&self) -> bool {
+        true
+    }
+
+    fn is_focused(&self) -> bool {
+        self.focused
+    }
+
+    fn set_focused(&mut self, focused: bool) {
+        self.focused = focused;
+    }
+}
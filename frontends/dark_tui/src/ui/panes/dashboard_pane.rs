@@ -0,0 +1,89 @@
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::Frame;
+use ratatui::layout::Rect;
+
+use dark_tui_components::{
+    Action, Component, ComponentResult, ComponentThemeLike, KeyMatch, KeySequenceMatcher, Keymap,
+};
+
+use crate::ui::command_palette::CommandId;
+use crate::ui::keymap::{KeyCommand, MAIN_MODE, default_keymap};
+use crate::ui::overlays::RunCommand;
+
+pub(crate) const DASHBOARD: &str = "dashboard";
+
+/// Catalog, inspector and chat column while no other pane has the keys.
+///
+/// Keys resolve in `main` mode and answer with [`RunCommand`]; the host runs
+/// the command when it is enabled. The panels themselves are drawn by
+/// `ui::render`.
+#[derive(Debug)]
+pub(crate) struct DashboardPane {
+    keymap: Keymap<KeyCommand>,
+    matcher: KeySequenceMatcher,
+    focused: bool,
+}
+
+impl DashboardPane {
+    pub(crate) fn new() -> Self {
+        Self {
+            keymap: default_keymap(),
+            matcher: KeySequenceMatcher::default(),
+            focused: false,
+        }
+    }
+
+    /// Keys typed so far toward a multi-key binding, for the footer.
+    pub(crate) fn pending(&self) -> Option<String> {
+        self.matcher.pending()
+    }
+}
+
+impl Component for DashboardPane {
+    fn handle_key_event(&mut self, key: KeyEvent) -> ComponentResult<Option<Action>> {
+        if super::is_quit(key) {
+            self.matcher.reset();
+            return Ok(Some(Action::Quit));
+        }
+        // Two catalog panes, so focusing the previous one toggles too.
+        if key.code == KeyCode::BackTab {
+            return Ok(Some(Action::custom(RunCommand(CommandId::ToggleFocus))));
+        }
+
+        match self.matcher.feed(&self.keymap, MAIN_MODE, key) {
+            KeyMatch::Command(KeyCommand::Dashboard(command)) => {
+                Ok(Some(Action::custom(RunCommand(command))))
+            }
+            _ => Ok(None),
+        }
+    }
+
+    fn update(&mut self, action: &Action) -> ComponentResult<Option<Action>> {
+        if let Some(keymap) = action.downcast_ref::<Keymap<KeyCommand>>() {
+            self.keymap = keymap.clone();
+            self.matcher.reset();
+        }
+        Ok(None)
+    }
+
+    fn draw(
+        &self,
+        _frame: &mut Frame,
+        _area: Rect,
+        _theme: &dyn ComponentThemeLike,
+    ) -> ComponentResult {
+        Ok(())
+    }
+
+    fn wants_focus(&self) -> bool {
+        true
+    }
+
+    fn is_focused(&self) -> bool {
+        self.focused
+    }
+
+    fn set_focused(&mut self, focused: bool) {
+        self.focused = focused;
+    }
+}
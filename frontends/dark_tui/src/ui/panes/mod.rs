@@ -0,0 +1,42 @@
+//! Dashboard panes that take keys, mounted on the app's `ComponentRuntime`
+//! under the overlays (see `ui::overlays`).
+//!
+//! Each pane is a `Layer::Base` component; the app moves keyboard focus
+//! between them with `ComponentRuntime::focus`, and whichever has it gets the
+//! keys no modal overlay takes. The dashboard pane runs `main` mode commands;
+//! the others answer with a request the run loop applies to the state they
+//! edit, except the core log viewer, which owns its `CoreLogView`.
+
+mod composer_pane;
+mod core_logs_pane;
+mod dashboard_pane;
+mod pty_pane;
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use dark_tui_components::{ComponentId, ComponentRuntime, DynComponent, Layer};
+use tracing::error;
+
+pub(crate) use composer_pane::{CHAT_COMPOSER, ComposeRequest, ComposerPane};
+pub(crate) use core_logs_pane::{CORE_LOGS, CoreLogsPane, CoreLogsRequest};
+pub(crate) use dashboard_pane::{DASHBOARD, DashboardPane};
+pub(crate) use pty_pane::{PtyPane, TERMINAL_PANE, TerminalRequest};
+
+/// Mounts every pane; the dashboard is mounted first, so it starts focused.
+pub(crate) fn mount_panes(runtime: &mut ComponentRuntime) {
+    let panes: [(ComponentId, DynComponent); 4] = [
+        (DASHBOARD, Box::new(DashboardPane::new())),
+        (CHAT_COMPOSER, Box::new(ComposerPane::new())),
+        (CORE_LOGS, Box::new(CoreLogsPane::new(None))),
+        (TERMINAL_PANE, Box::new(PtyPane::new())),
+    ];
+    for (id, component) in panes {
+        if let Err(error) = runtime.mount(id, Layer::Base, component) {
+            error!(id, error = %error, "Dark TUI // Panes // Mount failed");
+        }
+    }
+}
+
+/// Ctrl+C, which quits from every pane but the terminal.
+fn is_quit(key: KeyEvent) -> bool {
+    key.modifiers.contains(KeyModifiers::CONTROL) && key.code == KeyCode::Char('c')
+}
@@ -0,0 +1,77 @@
+use crossterm::event::KeyEvent;
+use ratatui::Frame;
+use ratatui::layout::Rect;
+
+use dark_tui_components::{Action, Component, ComponentResult, ComponentThemeLike, KeyMatch};
+
+use crate::ui::keymap::{PopupCommand, TERMINAL_MODE};
+use crate::ui::overlays::OverlayKeys;
+
+pub(crate) const TERMINAL_PANE: &str = "terminal-pane";
+
+/// What the focused terminal asks of the pane the app owns.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum TerminalRequest {
+    /// Hand keys back to the dashboard; the process keeps running.
+    Detach,
+    /// Scroll the scrollback half a screen up (`true`) or down.
+    Page(bool),
+    /// Write the key to the PTY.
+    Send(KeyEvent),
+}
+
+/// Embedded terminal while it has focus. Everything but the terminal mode's
+/// detach and scrollback keys goes to the PTY, Ctrl+C included.
+#[derive(Debug)]
+pub(crate) struct PtyPane {
+    keys: OverlayKeys,
+    focused: bool,
+}
+
+impl PtyPane {
+    pub(crate) fn new() -> Self {
+        Self {
+            keys: OverlayKeys::new(TERMINAL_MODE),
+            focused: false,
+        }
+    }
+}
+
+impl Component for PtyPane {
+    fn handle_key_event(&mut self, key: KeyEvent) -> ComponentResult<Option<Action>> {
+        let request = match self.keys.feed(key) {
+            KeyMatch::Command(PopupCommand::Detach) => TerminalRequest::Detach,
+            KeyMatch::Command(PopupCommand::PageUp) => TerminalRequest::Page(true),
+            KeyMatch::Command(PopupCommand::PageDown) => TerminalRequest::Page(false),
+            KeyMatch::Pending => return Ok(None),
+            _ => TerminalRequest::Send(key),
+        };
+        Ok(Some(Action::custom(request)))
+    }
+
+    fn update(&mut self, action: &Action) -> ComponentResult<Option<Action>> {
+        self.keys.update(action);
+        Ok(None)
+    }
+
+    fn draw(
+        &self,
+        _frame: &mut Frame,
+        _area: Rect,
+        _theme: &dyn ComponentThemeLike,
+    ) -> ComponentResult {
+        Ok(())
+    }
+
+    fn wants_focus(&self) -> bool {
+        true
+    }
+
+    fn is_focused(&self) -> bool {
+        self.focused
+    }
+
+    fn set_focused(&mut self, focused: bool) {
+        self.focused = focused;
+    }
+}
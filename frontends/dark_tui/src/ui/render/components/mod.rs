@@ -1,7 +1,9 @@
+pub(crate) mod search_match;
 pub(crate) mod sub_agent_badge;
 pub(crate) mod sub_agent_grid;
 pub(crate) mod sub_agent_line;
 
+pub(crate) use search_match::{match_ranges, search_match_spans};
 pub(crate) use sub_agent_badge::sub_agent_badge;
 pub(crate) use sub_agent_grid::{render_sub_agent_grid, sub_agent_grid_container_height};
 pub(crate) use sub_agent_line::sub_agent_tree_line;
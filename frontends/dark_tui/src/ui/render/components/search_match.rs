@@ -0,0 +1,104 @@
+use ratatui::style::{Modifier, Style};
+use ratatui::text::Span;
+
+use crate::app::{App, VizSelection};
+
+/// Renders a catalog label, highlighting the search terms it contains when
+/// `node` matched the active catalog search.
+///
+/// Matches without a literal hit in the label (e.g. `status:busy`) underline
+/// the whole label so they still stand out from rows shown only as context.
+pub(crate) fn search_match_spans(
+    app: &App,
+    node: &VizSelection,
+    text: String,
+    base: Style,
+) -> Vec<Span<'static>> {
+    let Some(query) = app.catalog_query().filter(|_| app.is_catalog_match(node)) else {
+        return vec![Span::styled(text, base)];
+    };
+
+    let ranges = match_ranges(&text, &query.highlight_terms());
+    if ranges.is_empty() {
+        return vec![Span::styled(text, base.add_modifier(Modifier::UNDERLINED))];
+    }
+
+    let theme = app.theme();
+    let highlight = base
+        .fg(theme.pill_accent_fg)
+        .bg(theme.pill_accent_bg)
+        .add_modifier(Modifier::BOLD);
+    let chars: Vec<char> = text.chars().collect();
+    let mut spans = Vec::new();
+    let mut cursor = 0;
+    for (start, end) in ranges {
+        if start > cursor {
+            spans.push(Span::styled(
+                chars[cursor..start].iter().collect::<String>(),
+                base,
+            ));
+        }
+        spans.push(Span::styled(
+            chars[start..end].iter().collect::<String>(),
+            highlight,
+        ));
+        cursor = end;
+    }
+    if cursor < chars.len() {
+        spans.push(Span::styled(
+            chars[cursor..].iter().collect::<String>(),
+            base,
+        ));
+    }
+    spans
+}
+
+/// Merged, case-insensitive char ranges of `terms` inside `text`.
+pub(crate) fn match_ranges(text: &str, terms: &[&str]) -> Vec<(usize, usize)> {
+    let haystack: Vec<char> = text.chars().map(lower_char).collect();
+    let mut hit = vec![false; haystack.len()];
+
+    for term in terms {
+        let needle: Vec<char> = term.chars().map(lower_char).collect();
+        if needle.is_empty() || needle.len() > haystack.len() {
+            continue;
+        }
+        for start in 0..=haystack.len() - needle.len() {
+            if haystack[start..start + needle.len()] == needle[..] {
+                hit[start..start + needle.len()].fill(true);
+            }
+        }
+    }
+
+    let mut ranges = Vec::new();
+    let mut start = None;
+    for (index, is_hit) in hit.iter().chain([&false]).enumerate() {
+        match (start, *is_hit) {
+            (None, true) => start = Some(index),
+            (Some(open), false) => {
+                ranges.push((open, index));
+                start = None;
+            }
+            _ => {}
+        }
+    }
+    ranges
+}
+
+fn lower_char(ch: char) -> char {
+    ch.to_lowercase().next().unwrap_or(ch)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::match_ranges;
+
+    #[test]
+    fn match_ranges_merge_overlapping_terms_case_insensitively() {
+        assert_eq!(
+            match_ranges("Feature-API api", &["api", "e-a"]),
+            vec![(6, 11), (12, 15)]
+        );
+        assert!(match_ranges("short", &["longer than text"]).is_empty());
+    }
+}
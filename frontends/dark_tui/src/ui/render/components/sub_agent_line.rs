@@ -10,8 +10,8 @@ use crate::theme::Theme;
 ///
 /// The `base_indent` controls the leading whitespace before the tree
 /// connector. Each sub-agent's `depth` adds additional indentation to
-/// visualize nesting. Lines use muted styling and are purely decorative
-/// — they must never be selectable or clickable.
+/// visualize nesting. Lines use muted styling so selectable sub-agent rows
+/// stay visually subordinate to their actor.
 pub(crate) fn sub_agent_tree_line<'a>(
     agent: &SubAgentRow,
     base_indent: &str,
@@ -2,27 +2,25 @@ mod components;
 mod panels;
 mod views;
 
+use std::rc::Rc;
+
 use ratatui::Frame;
 use ratatui::layout::Rect;
 use ratatui::layout::{Constraint, Direction, Layout};
 use ratatui::style::{Modifier, Style};
 use ratatui::text::Line;
 use ratatui::widgets::{Block, Borders, Clear, Paragraph};
+use tracing::error;
 
 use crate::app::{App, ResizeTarget, ResultsViewMode};
-use crate::ui::command_palette::ContextMenuState;
+use crate::cli::SnapshotView;
 
 use panels::{
-    BranchFormPanel, ChatPanel, CloneFormPanel, ContextMenuPanel, CoreLogsPanel,
-    DeleteVariantFormPanel, DetailsPanel, FooterPanel, HeaderPanel, InitProductFormPanel,
-    KeyBarPanel, MoveActorFormPanel, SpawnFormPanel, SshPanel,
+    ChatPanel, CoreLogsPanel, DetailsPanel, FooterPanel, HeaderPanel, KeyBarPanel, TerminalPanel,
 };
 use views::{CatalogTreeView, UnifiedCatalogView};
 
-pub(crate) use panels::BranchFormHit;
 pub(crate) use panels::ChatPanelHit;
-pub(crate) use panels::CloneFormHit;
-pub(crate) use panels::ContextMenuHit;
 pub(crate) use panels::KeyHintAction;
 pub(crate) use panels::KeyHoverToken;
 
@@ -37,24 +35,12 @@ pub(crate) struct DragPreview {
 pub fn render_dashboard(
     frame: &mut Frame,
     app: &App,
-    context_menu: Option<&ContextMenuState>,
     drag_preview: Option<&DragPreview>,
     key_hover_token: Option<&KeyHoverToken>,
     key_hover_hint: Option<&str>,
 ) {
     let root = frame.area();
-    let logs_height = if app.is_core_logs_visible() { 8 } else { 0 };
-
-    let vertical = Layout::default()
-        .direction(Direction::Vertical)
-        .constraints([
-            Constraint::Length(2), // header (compact title)
-            Constraint::Length(2), // key-hint bar
-            Constraint::Min(10),   // body (catalog + sidebar)
-            Constraint::Length(logs_height),
-            Constraint::Length(3), // footer/status
-        ])
-        .split(root);
+    let vertical = dashboard_rows(root, app);
 
     HeaderPanel::render(frame, vertical[0], app);
     KeyBarPanel::render(frame, vertical[1], app);
@@ -64,37 +50,11 @@ pub fn render_dashboard(
     }
     FooterPanel::render(frame, vertical[4], app);
 
-    if let Some(menu) = context_menu {
-        ContextMenuPanel::render(frame, root, app, menu);
-    }
-
-    if app.is_spawn_form_open() {
-        SpawnFormPanel::render(frame, root, app);
-    }
-
-    if app.is_ssh_panel_open() {
-        SshPanel::render(frame, root, app);
-    }
-
-    if app.is_init_product_form_open() {
-        InitProductFormPanel::render(frame, root, app);
-    }
-
-    if app.is_clone_form_open() {
-        CloneFormPanel::render(frame, root, app);
+    if let Err(error) = app.overlays().draw(frame, root, app.theme()) {
+        error!(error = %error, "Dark TUI // Overlays // Draw failed");
     }
 
-    if app.is_branch_form_open() {
-        BranchFormPanel::render(frame, root, app);
-    }
-
-    if app.is_delete_variant_form_open() {
-        DeleteVariantFormPanel::render(frame, root, app);
-    }
-
-    if app.is_move_actor_form_open() {
-        MoveActorFormPanel::render(frame, root, app);
-    }
+    app.toasts().render(frame, vertical[2], app.theme());
 
     if let Some(preview) = drag_preview {
         render_drag_preview(frame, root, app, preview);
@@ -109,6 +69,19 @@ pub fn render_dashboard(
     }
 }
 
+/// Draws a single view over the whole frame for `dark_tui snapshot`.
+pub(crate) fn render_snapshot_view(frame: &mut Frame, app: &App, view: SnapshotView) {
+    let area = frame.area();
+    match view {
+        SnapshotView::Dashboard | SnapshotView::Viz => {
+            render_dashboard(frame, app, None, None, None)
+        }
+        SnapshotView::Tree => CatalogTreeView::render(frame, area, app),
+        SnapshotView::Catalog => UnifiedCatalogView::render(frame, area, app),
+        SnapshotView::Details => DetailsPanel::render(frame, area, app),
+    }
+}
+
 fn render_body(frame: &mut Frame, area: ratatui::layout::Rect, app: &App) {
     match app.results_view_mode() {
         ResultsViewMode::Table => render_body_table(frame, area, app),
@@ -116,15 +89,28 @@ fn render_body(frame: &mut Frame, area: ratatui::layout::Rect, app: &App) {
     }
 }
 
+/// The chat column hosts either the chat panel or the embedded terminal.
+fn chat_column_visible(app: &App) -> bool {
+    app.is_chat_visible() || app.is_terminal_pane_open()
+}
+
+fn render_chat_column(frame: &mut Frame, area: Rect, app: &App) {
+    if app.is_terminal_pane_open() {
+        TerminalPanel::render(frame, area, app);
+    } else {
+        ChatPanel::render(frame, area, app);
+    }
+}
+
 fn resolve_columns(area: Rect, app: &App) -> Vec<Rect> {
     if app.is_inspector_visible() {
-        if app.is_chat_visible() {
+        if chat_column_visible(app) {
             return app.body_split_with_chat().resolve(area);
         }
         return app.body_split_without_chat().resolve(area);
     }
 
-    if app.is_chat_visible() {
+    if chat_column_visible(app) {
         return app.body_split_without_chat().resolve(area);
     }
 
@@ -134,16 +120,16 @@ fn resolve_columns(area: Rect, app: &App) -> Vec<Rect> {
 /// Table mode: main/sidebar split. Details panel fills the entire sidebar.
 fn render_body_table(frame: &mut Frame, area: ratatui::layout::Rect, app: &App) {
     let columns = resolve_columns(area, app);
-    if app.is_inspector_visible() && app.is_chat_visible() && columns.len() >= 3 {
+    if app.is_inspector_visible() && chat_column_visible(app) && columns.len() >= 3 {
         CatalogTreeView::render(frame, columns[0], app);
-        ChatPanel::render(frame, columns[1], app);
+        render_chat_column(frame, columns[1], app);
         DetailsPanel::render(frame, columns[2], app);
     } else if app.is_inspector_visible() && columns.len() >= 2 {
         CatalogTreeView::render(frame, columns[0], app);
         DetailsPanel::render(frame, columns[1], app);
-    } else if app.is_chat_visible() && columns.len() >= 2 {
+    } else if chat_column_visible(app) && columns.len() >= 2 {
         CatalogTreeView::render(frame, columns[0], app);
-        ChatPanel::render(frame, columns[1], app);
+        render_chat_column(frame, columns[1], app);
     } else if let Some(main) = columns.first() {
         CatalogTreeView::render(frame, *main, app);
     }
@@ -152,16 +138,16 @@ fn render_body_table(frame: &mut Frame, area: ratatui::layout::Rect, app: &App)
 /// Viz mode: main/sidebar split. Details panel fills the entire sidebar.
 fn render_body_viz(frame: &mut Frame, area: ratatui::layout::Rect, app: &App) {
     let columns = resolve_columns(area, app);
-    if app.is_inspector_visible() && app.is_chat_visible() && columns.len() >= 3 {
+    if app.is_inspector_visible() && chat_column_visible(app) && columns.len() >= 3 {
         UnifiedCatalogView::render(frame, columns[0], app);
-        ChatPanel::render(frame, columns[1], app);
+        render_chat_column(frame, columns[1], app);
         DetailsPanel::render(frame, columns[2], app);
     } else if app.is_inspector_visible() && columns.len() >= 2 {
         UnifiedCatalogView::render(frame, columns[0], app);
         DetailsPanel::render(frame, columns[1], app);
-    } else if app.is_chat_visible() && columns.len() >= 2 {
+    } else if chat_column_visible(app) && columns.len() >= 2 {
         UnifiedCatalogView::render(frame, columns[0], app);
-        ChatPanel::render(frame, columns[1], app);
+        render_chat_column(frame, columns[1], app);
     } else if let Some(main) = columns.first() {
         UnifiedCatalogView::render(frame, *main, app);
     }
@@ -231,7 +217,17 @@ pub(crate) fn viz_hit_test(
 }
 
 pub(crate) fn chat_area(root: Rect, app: &App) -> Option<Rect> {
-    if !app.is_chat_visible() {
+    if !app.is_chat_visible() || app.is_terminal_pane_open() {
+        return None;
+    }
+
+    let body = body_area(root, app);
+    resolve_columns(body, app).get(1).copied()
+}
+
+/// Outer area of the embedded terminal pane, borders included.
+pub(crate) fn terminal_pane_area(root: Rect, app: &App) -> Option<Rect> {
+    if !app.is_terminal_pane_open() {
         return None;
     }
 
@@ -246,7 +242,7 @@ pub(crate) fn divider_hit(root: Rect, app: &App, col: u16) -> Option<ResizeTarge
     }
 
     if !app.is_inspector_visible() {
-        if app.is_chat_visible() {
+        if chat_column_visible(app) {
             let divider = app.body_split_without_chat().divider_hit(body, col, 1)?;
             return Some(ResizeTarget::BodyWithoutChat(divider));
         }
@@ -254,7 +250,7 @@ pub(crate) fn divider_hit(root: Rect, app: &App, col: u16) -> Option<ResizeTarge
         return None;
     }
 
-    if app.is_chat_visible() {
+    if chat_column_visible(app) {
         let divider = app.body_split_with_chat().divider_hit(body, col, 1)?;
         return Some(ResizeTarget::BodyWithChat(divider));
     }
@@ -287,6 +283,12 @@ pub(crate) fn chat_hit_test(root: Rect, app: &App, col: u16, row: u16) -> ChatPa
     ChatPanel::hit_test(chat, app, col, row)
 }
 
+/// Chat detail popup box, while it is open.
+pub(crate) fn chat_detail_popup_area(root: Rect, app: &App) -> Option<Rect> {
+    let chat = chat_area(root, app)?;
+    ChatPanel::detail_popup_rect(chat, app)
+}
+
 pub(crate) fn chat_message_index_at_point(
     root: Rect,
     app: &App,
@@ -297,26 +299,39 @@ pub(crate) fn chat_message_index_at_point(
     ChatPanel::message_index_at_point(chat, app, col, row)
 }
 
-pub(crate) fn branch_form_hit_test(root: Rect, app: &App, col: u16, row: u16) -> BranchFormHit {
-    BranchFormPanel::hit_test(root, app, col, row)
-}
-
-pub(crate) fn clone_form_hit_test(root: Rect, app: &App, col: u16, row: u16) -> CloneFormHit {
-    CloneFormPanel::hit_test(root, app, col, row)
-}
-
-fn body_area(root: Rect, app: &App) -> Rect {
-    let logs_height = if app.is_core_logs_visible() { 8 } else { 0 };
+fn dashboard_rows(root: Rect, app: &App) -> Rc<[Rect]> {
+    // The focused log viewer grows so filters and scrollback have room.
+    let logs_height = match (app.is_core_logs_visible(), app.is_core_logs_focused()) {
+        (false, _) => 0,
+        (true, false) => 8,
+        (true, true) => 14,
+    };
     Layout::default()
         .direction(Direction::Vertical)
         .constraints([
-            Constraint::Length(2),
-            Constraint::Length(2),
-            Constraint::Min(10),
+            Constraint::Length(2), // header (compact title)
+            Constraint::Length(2), // key-hint bar
+            Constraint::Min(10),   // body (catalog + sidebar)
             Constraint::Length(logs_height),
-            Constraint::Length(3),
+            Constraint::Length(3), // footer/status
         ])
-        .split(root)[2]
+        .split(root)
+}
+
+fn body_area(root: Rect, app: &App) -> Rect {
+    dashboard_rows(root, app)[2]
+}
+
+/// Expands or dismisses the toast under a click.
+pub(crate) fn toast_click(root: Rect, app: &mut App, col: u16, row: u16) -> bool {
+    let area = body_area(root, app);
+    app.toasts_mut().click(area, col, row)
+}
+
+/// Docked core log viewer, when shown.
+pub(crate) fn core_logs_area(root: Rect, app: &App) -> Option<Rect> {
+    app.is_core_logs_visible()
+        .then(|| dashboard_rows(root, app)[3])
 }
 
 /// Compute the key bar area for hit testing.
@@ -353,15 +368,6 @@ pub(crate) fn key_bar_hover_token(
     KeyBarPanel::hover_token(area, app, row, col)
 }
 
-pub(crate) fn context_menu_hit_test(
-    root: Rect,
-    menu: &ContextMenuState,
-    col: u16,
-    row: u16,
-) -> ContextMenuHit {
-    ContextMenuPanel::hit_test(root, menu, col, row)
-}
-
 fn render_drag_preview(frame: &mut Frame, root: Rect, app: &App, preview: &DragPreview) {
     if root.width < 12 || root.height < 4 {
         return;
@@ -18,7 +18,7 @@ use ratatui::widgets::{
 use serde_json::Value;
 use std::borrow::Cow;
 
-use crate::app::{App, ChatPickerKind};
+use crate::app::App;
 
 pub(crate) struct ChatPanel;
 
@@ -26,22 +26,23 @@ pub(crate) struct ChatPanel;
 pub(crate) enum ChatPanelHit {
     Outside,
     MessageBody,
-    ModelLabel,
-    AgentLabel,
+    ModelLabel(Rect),
+    AgentLabel(Rect),
     DetailButton,
     ComposerBody,
-    PickerItem(usize),
-    PickerPopup,
     AutocompleteItem(usize),
     AutocompletePopup,
-    DetailPopup,
-    DetailPopupBackground,
 }
 
 impl ChatPanel {
     pub(crate) fn render(frame: &mut Frame, area: Rect, app: &App) {
         let theme = app.theme();
         let header = Self::header(app);
+        let attachments = app
+            .chat_attachments()
+            .iter()
+            .map(|attachment| compact_text(&attachment.chip_label(), 28))
+            .collect::<Vec<_>>();
         let messages = app
             .chat_messages()
             .iter()
@@ -75,7 +76,11 @@ impl ChatPanel {
             area,
             theme,
             ConversationPanelProps {
-                title: "Actor Chat",
+                title: if app.chat_sub_agent().is_some() {
+                    "Sub-Agent Transcript"
+                } else {
+                    "Actor Chat"
+                },
                 focused: true,
                 active_model_label: active_model,
                 active_agent_label: active_agent,
@@ -85,41 +90,17 @@ impl ChatPanel {
                 max_messages: app.chat_render_limit(),
                 max_body_lines_per_message: app.chat_max_body_lines(),
                 scroll_offset_lines: app.chat_scroll_lines(),
-                composer: Self::composer(app),
+                composer: Self::composer(app, &attachments),
                 palette: Self::palette(app),
             },
         );
 
         render_detail_button(frame, area, app);
-        render_picker_popup(frame, area, app);
         render_autocomplete_popup(frame, area, app);
         render_detail_popup(frame, area, app);
     }
 
     pub(crate) fn hit_test(area: Rect, app: &App, col: u16, row: u16) -> ChatPanelHit {
-        // Detail popup overlays everything — check first.
-        if app.is_chat_detail_popup_open() {
-            if let Some(msg) = app.chat_detail_popup_message() {
-                if let Some(popup_rect) = detail_popup_area(area, &msg.text) {
-                    if rect_contains(popup_rect, col, row) {
-                        return ChatPanelHit::DetailPopup;
-                    }
-                    // Click outside the popup but inside chat area = close popup
-                    if rect_contains(area, col, row) {
-                        return ChatPanelHit::DetailPopupBackground;
-                    }
-                }
-            }
-        }
-
-        if let Some(props) = picker_popup_props(area, app) {
-            match PopupOverlay::hit_test(area, &props, col, row) {
-                PopupHit::Outside => {}
-                PopupHit::ListItem(index) => return ChatPanelHit::PickerItem(index),
-                PopupHit::Popup | PopupHit::Query => return ChatPanelHit::PickerPopup,
-            }
-        }
-
         if let Some(props) = autocomplete_popup_props(area, app) {
             match PopupOverlay::hit_test(area, &props, col, row) {
                 PopupHit::Outside => {}
@@ -130,10 +111,10 @@ impl ChatPanel {
 
         if let Some((model_rect, agent_rect)) = composer_label_areas(area, app) {
             if rect_contains(model_rect, col, row) {
-                return ChatPanelHit::ModelLabel;
+                return ChatPanelHit::ModelLabel(model_rect);
             }
             if rect_contains(agent_rect, col, row) {
-                return ChatPanelHit::AgentLabel;
+                return ChatPanelHit::AgentLabel(agent_rect);
             }
         }
 
@@ -158,6 +139,12 @@ impl ChatPanel {
         ChatPanelHit::Outside
     }
 
+    /// Box the detail popup is drawn in while it is open.
+    pub(crate) fn detail_popup_rect(area: Rect, app: &App) -> Option<Rect> {
+        let message = app.chat_detail_popup_message()?;
+        detail_popup_area(area, &message.text)
+    }
+
     pub(crate) fn message_index_at_point(
         area: Rect,
         app: &App,
@@ -226,6 +213,24 @@ impl ChatPanel {
             };
         };
 
+        if let Some(sub_agent) = app.chat_sub_agent() {
+            let status_label = if app.is_chat_refresh_in_flight() {
+                Cow::Owned(format!("syncing {}", LoadingSpinner::glyph()))
+            } else {
+                Cow::Borrowed(sub_agent.status.as_str())
+            };
+            return ConversationHeader {
+                title: Cow::Owned(format!("\u{2699} {}", sub_agent.title)),
+                subtitle: Some(Cow::Owned(format!(
+                    "sub-agent of {} session:{}",
+                    actor.title,
+                    compact_session_id(&sub_agent.id)
+                ))),
+                status_label: Some(status_label),
+                status_tone: status_tone_for_status(&sub_agent.status),
+            };
+        }
+
         let subtitle = if actor.description.trim().is_empty() || actor.description.trim() == "-" {
             let session = actor
                 .provider_session_id
@@ -251,10 +256,13 @@ impl ChatPanel {
                 ConversationStatusTone::Info,
             )
         } else {
-            (
-                Cow::Borrowed(actor.status.as_str()),
-                status_tone_for_status(&actor.status),
-            )
+            let queued = app.chat_queued_prompts().len();
+            let label = if queued > 0 {
+                Cow::Owned(format!("{} queued:{queued}", actor.status))
+            } else {
+                Cow::Borrowed(actor.status.as_str())
+            };
+            (label, status_tone_for_status(&actor.status))
         };
 
         ConversationHeader {
@@ -277,31 +285,24 @@ impl ChatPanel {
         }
     }
 
-    fn composer(app: &App) -> ConversationComposer<'_> {
+    fn composer<'a>(app: &'a App, attachments: &'a [String]) -> ConversationComposer<'a> {
+        let read_only = app.chat_sub_agent().is_some();
         ConversationComposer {
-            enabled: app.chat_actor().is_some(),
+            enabled: app.chat_actor().is_some() && !read_only,
             composing: app.is_chat_composing(),
             draft: app.chat_draft(),
             cursor_index: app.chat_draft().chars().count(),
             idle_hint: "Press c to compose, Enter to send, t to hide chat.",
-            disabled_hint: "Input disabled until an actor is selected.",
+            disabled_hint: if read_only {
+                "Sub-agent transcripts are read-only; select the actor to compose."
+            } else {
+                "Input disabled until an actor is selected."
+            },
+            attachments,
         }
     }
 }
 
-fn render_picker_popup(frame: &mut Frame, area: Rect, app: &App) {
-    let Some(_kind) = app.chat_picker_open() else {
-        return;
-    };
-
-    let Some(props) = picker_popup_props(area, app) else {
-        return;
-    };
-
-    let theme = app.theme();
-    PopupOverlay::render(frame, area, &props, theme);
-}
-
 fn render_autocomplete_popup(frame: &mut Frame, area: Rect, app: &App) {
     let Some(props) = autocomplete_popup_props(area, app) else {
         return;
@@ -311,64 +312,8 @@ fn render_autocomplete_popup(frame: &mut Frame, area: Rect, app: &App) {
     PopupOverlay::render(frame, area, &props, theme);
 }
 
-fn picker_items(app: &App) -> &[String] {
-    match app.chat_picker_open() {
-        Some(ChatPickerKind::Model) => app.chat_model_options(),
-        Some(ChatPickerKind::Agent) => app.chat_agent_options(),
-        None => &[],
-    }
-}
-
-fn picker_popup_props(area: Rect, app: &App) -> Option<PopupOverlayProps> {
-    let items = picker_items(app);
-    if items.is_empty() {
-        return None;
-    }
-
-    let inner = inner_rect(area);
-    if inner.width < 16 || inner.height < 8 {
-        return None;
-    }
-
-    let (model_rect, agent_rect) = composer_label_areas(area, app)?;
-    let anchor = match app.chat_picker_open() {
-        Some(ChatPickerKind::Model) => model_rect,
-        Some(ChatPickerKind::Agent) => agent_rect,
-        None => model_rect,
-    };
-
-    let title = match app.chat_picker_open() {
-        Some(ChatPickerKind::Model) => "Select Model",
-        Some(ChatPickerKind::Agent) => "Select Agent",
-        None => "Select",
-    };
-
-    Some(PopupOverlayProps {
-        title: title.to_string(),
-        items: items
-            .iter()
-            .map(|item| PopupItem {
-                label: item.clone(),
-                tag: None,
-                active: false,
-            })
-            .collect(),
-        selected: app.chat_picker_selected(),
-        query: Some(app.chat_picker_query().to_string()),
-        query_label: Some("FILTER".to_string()),
-        hint: Some("enter select  bksp delete  esc close".to_string()),
-        anchor: PopupAnchor::At {
-            x: anchor.x,
-            y: anchor.y,
-        },
-        max_visible: 8,
-        min_width: 24,
-        max_width: inner.width.min(42),
-    })
-}
-
 fn autocomplete_popup_props(area: Rect, app: &App) -> Option<PopupOverlayProps> {
-    if !app.chat_autocomplete_open() || app.chat_picker_open().is_some() {
+    if !app.chat_autocomplete_open() || app.is_chat_picker_open() {
         return None;
     }
 
@@ -466,7 +411,7 @@ fn composer_label_areas(area: Rect, app: &App) -> Option<(Rect, Rect)> {
 }
 
 fn should_show_context_labels(app: &App) -> bool {
-    app.is_chat_composing() || app.chat_picker_open().is_some()
+    app.is_chat_composing() || app.is_chat_picker_open()
 }
 
 fn composer_body_area(area: Rect) -> Option<Rect> {
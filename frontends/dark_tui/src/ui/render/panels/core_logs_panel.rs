@@ -1,25 +1,29 @@
 use ratatui::Frame;
 use ratatui::layout::Rect;
-use ratatui::style::Style;
-use ratatui::text::Line;
-use ratatui::widgets::{Block, Borders, Paragraph};
+use ratatui::style::{Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::Paragraph;
+
+use dark_tui_components::PaneBlockComponent;
 
 use crate::app::App;
+use crate::core_logs::{CoreLogView, LogEntry, LogLevel};
+use crate::theme::Theme;
+use crate::ui::render::components::match_ranges;
 
+/// Docked viewer for the tailed dark_core log file.
 pub(crate) struct CoreLogsPanel;
 
 impl CoreLogsPanel {
     pub(crate) fn render(frame: &mut Frame, area: Rect, app: &App) {
         let theme = app.theme();
-        let title = format!(
-            "Core Logs // {} // {}",
-            app.core_logs_session(),
-            app.core_logs_status()
-        );
-        let block = Block::default()
-            .title(title)
-            .borders(Borders::ALL)
-            .border_style(Style::default().fg(theme.footer_border));
+        let Some(logs) = app.core_logs() else {
+            return;
+        };
+        let focused = app.is_core_logs_focused();
+
+        let title = title(logs, focused);
+        let block = PaneBlockComponent::build(&title, focused, theme);
         let inner = block.inner(area);
         frame.render_widget(block, area);
 
@@ -27,26 +31,166 @@ impl CoreLogsPanel {
             return;
         }
 
-        let mut lines: Vec<Line<'static>> = app
-            .core_logs_lines()
+        let prompt = logs.is_search_editing() || (focused && !logs.search().is_empty());
+        let log_rows = inner.height.saturating_sub(u16::from(prompt)) as usize;
+        let window = logs.window(log_rows);
+        let selected_seq = (!logs.is_following())
+            .then(|| window.last().map(|entry| entry.seq))
+            .flatten();
+
+        let mut lines: Vec<Line<'static>> = window
             .iter()
-            .rev()
-            .take(inner.height as usize)
-            .cloned()
-            .map(Line::from)
+            .map(|entry| entry_line(logs, entry, selected_seq == Some(entry.seq), theme))
             .collect();
-        lines.reverse();
 
         if lines.is_empty() {
-            lines.push(Line::styled(
-                "(no log lines yet)",
-                Style::default().fg(theme.text_muted),
-            ));
+            let empty = match logs.path() {
+                Some(path) if logs.len() == 0 => {
+                    format!("(no log lines yet in {})", path.display())
+                }
+                Some(_) => "(no lines match the active filters)".to_string(),
+                None => "(no dark_core log file configured; set --core-log-file)".to_string(),
+            };
+            lines.push(Line::styled(empty, Style::default().fg(theme.text_muted)));
+        }
+        while prompt && lines.len() < log_rows {
+            lines.insert(0, Line::default());
+        }
+
+        if prompt {
+            let cursor = if logs.is_search_editing() {
+                "\u{2588}"
+            } else {
+                ""
+            };
+            let matches = logs
+                .visible_entries()
+                .into_iter()
+                .filter(|entry| logs.is_search_match(entry))
+                .count();
+            lines.push(Line::from(vec![
+                Span::styled("/", Style::default().fg(theme.key_hint_key_fg)),
+                Span::styled(
+                    format!("{}{cursor}", logs.search()),
+                    Style::default().fg(theme.text_primary),
+                ),
+                Span::styled(
+                    format!("  {matches} matches  (n older / N newer)"),
+                    Style::default().fg(theme.text_muted),
+                ),
+            ]));
         }
 
-        frame.render_widget(
-            Paragraph::new(lines).style(Style::default().fg(theme.text_secondary)),
-            inner,
-        );
+        frame.render_widget(Paragraph::new(lines), inner);
+    }
+}
+
+fn title(logs: &CoreLogView, focused: bool) -> String {
+    let source = logs.path().and_then(|path| path.file_name()).map_or_else(
+        || "no file".to_string(),
+        |name| name.to_string_lossy().into_owned(),
+    );
+    let mode = if logs.is_following() {
+        "following".to_string()
+    } else if logs.unseen_while_paused() > 0 {
+        format!("paused +{} new", logs.unseen_while_paused())
+    } else {
+        "paused".to_string()
+    };
+
+    let mut title = format!("Core Logs // {source} // {} // {mode}", logs.status());
+    if let Some(level) = logs.min_level() {
+        title.push_str(&format!(" // level>={}", level.label()));
+    }
+    if let Some(module) = logs.module_filter() {
+        title.push_str(&format!(" // module:{module}"));
+    }
+    if focused {
+        title.push_str(" (focused)");
+    }
+    title
+}
+
+fn entry_line(
+    logs: &CoreLogView,
+    entry: &LogEntry,
+    selected: bool,
+    theme: &Theme,
+) -> Line<'static> {
+    let muted = Style::default().fg(theme.text_muted);
+    let mut spans = vec![
+        Span::styled(if selected { "> " } else { "  " }, muted),
+        Span::styled(format!("{:<9}", entry.short_time().unwrap_or("")), muted),
+    ];
+
+    let (label, level_style) = match entry.level {
+        Some(LogLevel::Error) => ("ERROR", Style::default().fg(theme.text_error)),
+        Some(LogLevel::Warn) => ("WARN", Style::default().fg(theme.pill_warn_fg)),
+        Some(LogLevel::Info) => ("INFO", Style::default().fg(theme.pill_info_fg)),
+        Some(LogLevel::Debug) => ("DEBUG", muted),
+        None => ("", muted),
+    };
+    spans.push(Span::styled(
+        format!("{label:<6}"),
+        level_style.add_modifier(Modifier::BOLD),
+    ));
+
+    let mut base = Style::default().fg(theme.text_secondary);
+    if selected {
+        base = base.add_modifier(Modifier::BOLD);
+    }
+    spans.extend(message_spans(logs, entry, base, theme));
+    Line::from(spans)
+}
+
+/// Message text with search hits highlighted and entity ids underlined in
+/// their catalog color.
+fn message_spans(
+    logs: &CoreLogView,
+    entry: &LogEntry,
+    base: Style,
+    theme: &Theme,
+) -> Vec<Span<'static>> {
+    let chars: Vec<char> = entry.message.chars().collect();
+    let mut styles = vec![base; chars.len()];
+
+    for id in &entry.entity_ids {
+        let color = if id.starts_with("prd_") {
+            theme.entity_product
+        } else if id.starts_with("var_") {
+            theme.entity_variant
+        } else {
+            theme.entity_actor
+        };
+        for (start, end) in match_ranges(&entry.message, &[id.as_str()]) {
+            for style in &mut styles[start..end] {
+                *style = style.fg(color).add_modifier(Modifier::UNDERLINED);
+            }
+        }
+    }
+
+    if logs.is_search_match(entry) {
+        let highlight = Style::default()
+            .fg(theme.pill_accent_fg)
+            .bg(theme.pill_accent_bg)
+            .add_modifier(Modifier::BOLD);
+        for (start, end) in match_ranges(&entry.message, &[logs.search()]) {
+            for style in &mut styles[start..end] {
+                *style = style.patch(highlight);
+            }
+        }
+    }
+
+    let mut spans = Vec::new();
+    let mut start = 0;
+    for index in 1..=chars.len() {
+        if index == chars.len() || styles[index] != styles[start] {
+            spans.push(Span::styled(
+                chars[start..index].iter().collect::<String>(),
+                styles[start],
+            ));
+            start = index;
+        }
     }
+    spans
 }
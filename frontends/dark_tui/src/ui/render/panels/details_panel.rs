@@ -5,14 +5,16 @@ use ratatui::text::{Line, Span};
 use ratatui::widgets::Paragraph;
 
 use crate::app::{App, VizSelection};
-use crate::models::{ActorRow, ProductRow, SubAgentRow, VariantRow, compact_timestamp};
+use crate::models::{
+    ActorRow, MAX_SUB_AGENT_ROWS, ProductRow, SubAgentRow, VariantRow, compact_timestamp,
+};
 use crate::theme::{EntityKind, Theme};
+use crate::ui::command_palette::{CommandId, command_key_hint};
 use crate::ui::render::components::sub_agent_badge;
 
 use dark_tui_components::{SectionHeader, StatusPill, compact_text_normalized};
 
 pub(crate) struct DetailsPanel;
-const MAX_SUB_AGENT_ROWS: usize = 12;
 
 impl DetailsPanel {
     pub(crate) fn render(frame: &mut Frame, area: Rect, app: &App) {
@@ -30,7 +32,9 @@ impl DetailsPanel {
                 }
             })
             .unwrap_or(theme.entity_product);
+        let sub_agent = app.selected_sub_agent();
         let (title, border_color) = match entity_kind {
+            EntityKind::Actor if sub_agent.is_some() => ("\u{2699} Sub-Agent", theme.entity_actor),
             EntityKind::Product => ("\u{25a0} Product", product_border),
             EntityKind::Variant => ("\u{25b6} Variant", theme.entity_variant),
             EntityKind::Actor => ("\u{25cf} Actor", theme.entity_actor),
@@ -49,7 +53,10 @@ impl DetailsPanel {
             return;
         }
 
-        let lines = Self::build_detail_lines(app, entity_kind, inner.width, theme);
+        let lines = match sub_agent {
+            Some((actor, agent)) => Self::sub_agent_lines(app, actor, agent, inner.width, theme),
+            None => Self::build_detail_lines(app, entity_kind, inner.width, theme),
+        };
 
         let widget = Paragraph::new(lines);
         frame.render_widget(widget, inner);
@@ -61,7 +68,7 @@ impl DetailsPanel {
             return match sel {
                 VizSelection::Product { .. } => EntityKind::Product,
                 VizSelection::Variant { .. } => EntityKind::Variant,
-                VizSelection::Actor { .. } => EntityKind::Actor,
+                VizSelection::Actor { .. } | VizSelection::SubAgent { .. } => EntityKind::Actor,
             };
         }
         match app.focus() {
@@ -211,6 +218,81 @@ impl DetailsPanel {
         lines
     }
 
+    fn sub_agent_lines(
+        app: &App,
+        actor: &ActorRow,
+        agent: &SubAgentRow,
+        width: u16,
+        theme: &Theme,
+    ) -> Vec<Line<'static>> {
+        let mut lines: Vec<Line<'static>> = Vec::new();
+
+        // --- Status row: pills ---
+        lines.push(Line::from(vec![
+            Span::raw(" "),
+            sub_agent_status_pill(&agent.status, theme).span(),
+            Span::raw(" "),
+            StatusPill::info(format!("depth {}", agent.depth), theme).span(),
+        ]));
+        lines.push(Line::raw(""));
+
+        // --- Identity section ---
+        lines.push(SectionHeader::new("Identity", theme.entity_actor).line(width, theme));
+        Self::push_stacked_field(&mut lines, "Title", &agent.title, width, theme);
+        Self::push_stacked_field(&mut lines, "Session", &agent.id, width, theme);
+        Self::push_stacked_field(&mut lines, "Actor", &actor.title, width, theme);
+        Self::push_stacked_field(
+            &mut lines,
+            "Parent",
+            agent.parent_id.as_deref().unwrap_or("-"),
+            width,
+            theme,
+        );
+        let children = actor
+            .sub_agents
+            .iter()
+            .filter(|child| child.parent_id.as_deref() == Some(agent.id.as_str()))
+            .count();
+        Self::push_stacked_field(&mut lines, "Children", children.to_string(), width, theme);
+        lines.push(Line::raw(""));
+
+        // --- Summary section ---
+        if !agent.summary.trim().is_empty() && agent.summary != "-" {
+            lines.push(SectionHeader::new("Summary", theme.entity_actor).line(width, theme));
+            Self::push_wrapped_text(&mut lines, &agent.summary, width, theme);
+            lines.push(Line::raw(""));
+        }
+
+        // --- Status history, newest first ---
+        lines.push(SectionHeader::new("Status History", theme.text_muted).line(width, theme));
+        let history = app.sub_agent_status_history(&agent.id);
+        if history.is_empty() {
+            lines.push(Line::styled(
+                "  No status changes seen yet",
+                Style::default().fg(theme.text_muted),
+            ));
+        }
+        for change in history.iter().rev() {
+            lines.push(Line::from(vec![
+                Span::styled(
+                    format!("  {:<12}", compact_timestamp(&change.at)),
+                    Style::default().fg(theme.text_muted),
+                ),
+                sub_agent_status_pill(&change.status, theme).span(),
+            ]));
+        }
+        lines.push(Line::raw(""));
+
+        if let Some(key) = command_key_hint(app, CommandId::AbortSubAgent) {
+            lines.push(Line::styled(
+                format!("  {key} aborts this sub-agent's run"),
+                Style::default().fg(theme.text_muted),
+            ));
+        }
+
+        lines
+    }
+
     fn actor_lines(app: &App, width: u16, theme: &Theme) -> Vec<Line<'static>> {
         let actor = if let Some(VizSelection::Actor { actor_id, .. }) = app.viz_selection() {
             app.actors().iter().find(|a| a.id == *actor_id)
@@ -410,6 +492,31 @@ impl DetailsPanel {
         ));
     }
 
+    /// Word-wraps `text` into indented lines that fit the panel.
+    fn push_wrapped_text(lines: &mut Vec<Line<'static>>, text: &str, width: u16, theme: &Theme) {
+        let max = width.saturating_sub(4).max(8) as usize;
+        let mut current = String::new();
+        for word in text.split_whitespace() {
+            if !current.is_empty() && current.chars().count() + 1 + word.chars().count() > max {
+                lines.push(Line::styled(
+                    format!("  {current}"),
+                    Style::default().fg(theme.text_secondary),
+                ));
+                current.clear();
+            }
+            if !current.is_empty() {
+                current.push(' ');
+            }
+            current.push_str(word);
+        }
+        if !current.is_empty() {
+            lines.push(Line::styled(
+                format!("  {current}"),
+                Style::default().fg(theme.text_secondary),
+            ));
+        }
+    }
+
     /// Render flattened sub-agent rows with depth-aware indentation and status pills.
     fn push_sub_agent_rows(
         lines: &mut Vec<Line<'static>>,
@@ -443,18 +550,10 @@ impl DetailsPanel {
 
             let title_text = format!("  {indent}{connector}{}", agent.title);
 
-            let status_pill = match agent.status.as_str() {
-                "active" | "running" => StatusPill::ok(&agent.status, theme),
-                "error" | "failed" | "dead" => StatusPill::error(&agent.status, theme),
-                "idle" | "waiting" => StatusPill::warn(&agent.status, theme),
-                "-" => StatusPill::muted("--", theme),
-                _ => StatusPill::muted(&agent.status, theme),
-            };
-
             let mut spans = vec![
                 Span::styled(title_text, Style::default().fg(theme.text_secondary)),
                 Span::raw("  "),
-                status_pill.span(),
+                sub_agent_status_pill(&agent.status, theme).span(),
             ];
 
             // Add summary hint if non-trivial.
@@ -475,3 +574,13 @@ impl DetailsPanel {
         }
     }
 }
+
+fn sub_agent_status_pill(status: &str, theme: &Theme) -> StatusPill {
+    match status {
+        "active" | "running" => StatusPill::ok(status, theme),
+        "error" | "failed" | "dead" => StatusPill::error(status, theme),
+        "idle" | "waiting" => StatusPill::warn(status, theme),
+        "-" => StatusPill::muted("--", theme),
+        _ => StatusPill::muted(status, theme),
+    }
+}
@@ -79,6 +79,27 @@ impl FooterPanel {
             StatusPill::muted(activity_label, theme)
         };
 
+        let pending_keys_pill = app
+            .pending_key_sequence()
+            .map(|pending| StatusPill::accent(format!("keys:{pending} …"), theme));
+
+        let marked_count = app.marked_count();
+        let marked_pill =
+            (marked_count > 0).then(|| StatusPill::accent(format!("marked:{marked_count}"), theme));
+        let bulk_pill = app.bulk_run().filter(|run| !run.is_finished()).map(|run| {
+            StatusPill::info(
+                format!(
+                    "bulk:{} {}/{}",
+                    run.operation.label().to_lowercase(),
+                    run.completed(),
+                    run.entries.len()
+                ),
+                theme,
+            )
+        });
+        let unread = app.notifications().unread();
+        let notify_pill = (unread > 0).then(|| StatusPill::warn(format!("notify:{unread}"), theme));
+
         // --- Status message ---
         let status_text = app.status_message();
         let status_span = if status_text.contains("failed") || status_text.contains("error") {
@@ -115,6 +136,12 @@ impl FooterPanel {
         if let Some(pill) = density_pill {
             segments.insert(1, pill.span());
         }
+        for pill in [marked_pill, bulk_pill, notify_pill].into_iter().flatten() {
+            segments.insert(segments.len() - 1, pill.span());
+        }
+        if let Some(pill) = pending_keys_pill {
+            segments.insert(segments.len() - 1, pill.span());
+        }
 
         FooterBar::render(
             frame,
@@ -4,15 +4,19 @@ use ratatui::style::{Modifier, Style};
 use ratatui::text::{Line, Span};
 use ratatui::widgets::Paragraph;
 
+use dark_tui_components::StatusPill;
+
 use crate::app::App;
 
+use crate::ui::overlays::health_pill;
+
 pub(crate) struct HeaderPanel;
 
 impl HeaderPanel {
     pub(crate) fn render(frame: &mut Frame, area: Rect, app: &App) {
         let theme = app.theme();
 
-        // Compact single-line title with brand + view mode pill.
+        // Compact single-line title with brand + search bar / active filter.
         let brand = Span::styled(
             " Dark Factory ",
             Style::default()
@@ -20,7 +24,10 @@ impl HeaderPanel {
                 .add_modifier(Modifier::BOLD),
         );
 
-        let line = Line::from(vec![brand]);
+        // dark_core health badge turns red when /system/health degrades.
+        let mut spans = vec![brand, health_pill(app.core_health(), theme), Span::raw(" ")];
+        spans.extend(Self::search_spans(app));
+        let line = Line::from(spans);
 
         // Second line: thin horizontal rule for visual separation.
         let rule_len = area.width as usize;
@@ -32,4 +39,49 @@ impl HeaderPanel {
         let widget = Paragraph::new(vec![line, rule]);
         frame.render_widget(widget, area);
     }
+
+    /// Search bar while editing, otherwise the active catalog filter.
+    fn search_spans(app: &App) -> Vec<Span<'static>> {
+        let theme = app.theme();
+        let muted = Style::default().fg(theme.text_muted);
+
+        if app.is_catalog_search_open() {
+            let mut spans = vec![
+                Span::styled(
+                    " / ",
+                    Style::default()
+                        .fg(theme.pill_accent_fg)
+                        .add_modifier(Modifier::BOLD),
+                ),
+                Span::styled(
+                    format!("{}\u{2588}", app.catalog_search_draft()),
+                    Style::default().fg(theme.text_primary),
+                ),
+            ];
+            match app.catalog_search_error() {
+                Some(error) => spans.push(Span::styled(
+                    format!("  {error}"),
+                    Style::default().fg(theme.text_error),
+                )),
+                None if app.catalog_query().is_some() => spans.push(Span::styled(
+                    format!("  {} matches", app.catalog_match_count()),
+                    muted,
+                )),
+                None => spans.push(Span::styled(
+                    "  status: branch: dirty: provider: name: id:",
+                    muted,
+                )),
+            }
+            return spans;
+        }
+
+        let Some(query) = app.catalog_query() else {
+            return Vec::new();
+        };
+        vec![
+            Span::styled(" filter ", muted),
+            StatusPill::accent(query.text().to_string(), theme).span(),
+            Span::styled(format!("  {} matches", app.catalog_match_count()), muted),
+        ]
+    }
 }
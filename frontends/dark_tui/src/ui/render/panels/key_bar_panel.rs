@@ -3,71 +3,174 @@ use ratatui::layout::Rect;
 use ratatui::style::Style;
 use ratatui::widgets::Paragraph;
 
+use crossterm::event::KeyEvent;
+
 use crate::app::App;
-use crate::ui::command_palette::toolbar_bindings;
+use crate::ui::command_palette::{CommandId, command_key_hint, toolbar_bindings};
+use crate::ui::keymap::{
+    BULK_RESULTS_MODE, COMPOSE_MODE, FORM_MODE, GIT_MODE, INTEGRATE_MODE, KeyCommand, LOGS_MODE,
+    MESSAGES_MODE, METRICS_MODE, NOTIFICATIONS_MODE, PALETTE_MODE, PROMPT_MODE, PopupCommand as P,
+    SEARCH_MODE, TERMINAL_MODE, popup_hint,
+};
 
 use dark_tui_components::{KeyBind, KeyHintBar};
 
-/// Extra key hints while composing a chat message.
-const CHAT_COMPOSE_KEYS: &[KeyBind] =
-    &[KeyBind::new("Enter", "Send"), KeyBind::new("Esc", "Cancel")];
-
-/// Extra key hints while editing the clone variant form.
-const CLONE_FORM_KEYS: &[KeyBind] = &[
-    KeyBind::new("Enter", "Clone"),
-    KeyBind::new("Tab", "Field"),
-    KeyBind::new("Esc", "Cancel"),
-];
-
-const BRANCH_FORM_KEYS: &[KeyBind] = &[
-    KeyBind::new("Enter", "Switch"),
-    KeyBind::new("Tab", "Complete"),
-    KeyBind::new("Esc", "Cancel"),
-];
-
-const DELETE_FORM_KEYS: &[KeyBind] = &[
-    KeyBind::new("Space", "Toggle remove"),
-    KeyBind::new("Enter", "Delete"),
-    KeyBind::new("Esc", "Cancel"),
-];
-
-const MOVE_FORM_KEYS: &[KeyBind] = &[KeyBind::new("Enter", "Move"), KeyBind::new("Esc", "Cancel")];
-
-const INIT_PRODUCT_FORM_KEYS: &[KeyBind] =
-    &[KeyBind::new("Enter", "Init"), KeyBind::new("Esc", "Cancel")];
+/// Extra key hints for a popup, form or focused pane: its keymap mode and the
+/// commands to show there. Key text comes from the effective keymap; commands
+/// sharing a hint are joined like `↑/↓`.
+type ModeKeys = (&'static str, &'static [(&'static [P], &'static str)]);
+
+const CHAT_COMPOSE_KEYS: ModeKeys = (
+    COMPOSE_MODE,
+    &[(&[P::Confirm], "Send"), (&[P::Close], "Cancel")],
+);
+
+const CLONE_FORM_KEYS: ModeKeys = (
+    FORM_MODE,
+    &[
+        (&[P::Confirm], "Clone"),
+        (&[P::NextField], "Field"),
+        (&[P::Close], "Cancel"),
+    ],
+);
+
+const BRANCH_FORM_KEYS: ModeKeys = (
+    FORM_MODE,
+    &[
+        (&[P::Confirm], "Switch"),
+        (&[P::NextField], "Suggestions"),
+        (&[P::Close], "Cancel"),
+    ],
+);
+
+const DELETE_FORM_KEYS: ModeKeys = (
+    FORM_MODE,
+    &[
+        (&[P::Toggle], "Toggle remove"),
+        (&[P::Confirm], "Delete"),
+        (&[P::Close], "Cancel"),
+    ],
+);
+
+const INTEGRATE_FORM_KEYS: ModeKeys = (
+    INTEGRATE_MODE,
+    &[
+        (&[P::Strategy], "Strategy"),
+        (&[P::After], "After"),
+        (&[P::Preview], "Preview"),
+        (&[P::Confirm], "Integrate"),
+        (&[P::Close], "Cancel"),
+    ],
+);
+
+const GIT_PANEL_KEYS: ModeKeys = (
+    GIT_MODE,
+    &[
+        (&[P::EditMessage], "Message"),
+        (&[P::DraftMessage], "Draft"),
+        (&[P::Confirm], "Commit"),
+        (&[P::Stash], "Stash"),
+        (&[P::Unstash], "Unstash"),
+        (&[P::Fetch], "Fetch"),
+        (&[P::Push], "Push"),
+        (&[P::Reset], "Reset"),
+        (&[P::Close], "Close"),
+    ],
+);
+
+const COMMAND_PALETTE_KEYS: ModeKeys = (
+    PALETTE_MODE,
+    &[
+        (&[P::Confirm], "Run"),
+        (&[P::Complete], "Complete"),
+        (&[P::Up, P::Down], "Select"),
+        (&[P::Close], "Close"),
+    ],
+);
+
+const BULK_PROMPT_KEYS: ModeKeys = (
+    PROMPT_MODE,
+    &[(&[P::Confirm], "Send to marked"), (&[P::Close], "Cancel")],
+);
+
+const CATALOG_SEARCH_KEYS: ModeKeys = (
+    SEARCH_MODE,
+    &[
+        (&[P::Confirm], "Keep filter"),
+        (&[P::Clear], "Clear"),
+        (&[P::Close], "Restore"),
+    ],
+);
+
+const BULK_SUMMARY_KEYS: ModeKeys = (BULK_RESULTS_MODE, &[(&[P::Close], "Close")]);
+
+const NOTIFICATIONS_KEYS: ModeKeys = (
+    NOTIFICATIONS_MODE,
+    &[
+        (&[P::Confirm], "Jump"),
+        (&[P::Clear], "Clear"),
+        (&[P::Close], "Close"),
+    ],
+);
+
+const MESSAGE_LOG_KEYS: ModeKeys = (
+    MESSAGES_MODE,
+    &[
+        (&[P::Up, P::Down], "Scroll"),
+        (&[P::Clear], "Clear"),
+        (&[P::Close], "Close"),
+    ],
+);
+
+const METRICS_KEYS: ModeKeys = (METRICS_MODE, &[(&[P::Close], "Close")]);
+
+const TERMINAL_PANE_KEYS: ModeKeys = (
+    TERMINAL_MODE,
+    &[
+        (&[P::Detach], "Detach"),
+        (&[P::PageUp], "Scroll up"),
+        (&[P::PageDown], "Scroll down"),
+    ],
+);
+
+const CORE_LOGS_KEYS: ModeKeys = (
+    LOGS_MODE,
+    &[
+        (&[P::Search], "Search"),
+        (&[P::Level], "Level"),
+        (&[P::Module], "Module"),
+        (&[P::Follow], "Follow"),
+        (&[P::Confirm], "Jump"),
+        (&[P::Close], "Back"),
+    ],
+);
+
+const MOVE_FORM_KEYS: ModeKeys = (
+    FORM_MODE,
+    &[(&[P::Confirm], "Move"), (&[P::Close], "Cancel")],
+);
+
+const SPAWN_FORM_KEYS: ModeKeys = (
+    FORM_MODE,
+    &[
+        (&[P::Confirm], "Spawn"),
+        (&[P::NextField], "Next field"),
+        (&[P::Close], "Cancel"),
+    ],
+);
+
+const INIT_PRODUCT_FORM_KEYS: ModeKeys = (
+    FORM_MODE,
+    &[(&[P::Confirm], "Init"), (&[P::Close], "Cancel")],
+);
 
 /// Result of clicking on a key hint - maps to app action.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum KeyHintAction {
-    Quit,
-    Focus,
-    Select,
-    Refresh,
-    View,
-    Density,
-    Filter,
-    ToggleInspector,
-    Poll,
-    SwitchBranch,
-    PollActor,
-    Clone,
-    Delete,
-    Import,
-    Move,
-    Init,
-    Spawn,
-    Attach,
-    Chat,
-    CoreLogs,
-    LastLog,
-    SshPanel,
-    Compose,
-    ResetPan,
-    Send,
-    Cancel,
-    ToggleRemove,
-    #[allow(dead_code)]
-    FieldNav,
+    /// A toolbar command, dispatched directly so remapped keys stay clickable.
+    Command(CommandId),
+    /// A form or popup key, replayed as the key press it names.
+    Key(KeyEvent),
 }
 
 #[derive(Debug, Clone)]
@@ -78,51 +181,16 @@ pub struct KeyHoverToken {
     pub text: String,
 }
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone)]
 struct HitBind {
-    key: &'static str,
+    key: String,
     action: &'static str,
+    target: Option<KeyHintAction>,
     row: u16,
     key_start: u16,
     key_width: u16,
 }
 
-impl KeyHintAction {
-    /// Maps a key string to the corresponding action, or None if not clickable.
-    pub fn from_key(key: &str) -> Option<Self> {
-        match key {
-            "q" => Some(Self::Quit),
-            "Tab" => Some(Self::Focus),
-            "↑/↓" => Some(Self::Select),
-            "r" => Some(Self::Refresh),
-            "v" => Some(Self::View),
-            "z" => Some(Self::Density),
-            "f" => Some(Self::Filter),
-            "s" => Some(Self::ToggleInspector),
-            "p" => Some(Self::Poll),
-            "o" => Some(Self::PollActor),
-            "x" => Some(Self::Clone),
-            "w" => Some(Self::SwitchBranch),
-            "d" => Some(Self::Delete),
-            "m" => Some(Self::Import),
-            "g" => Some(Self::Move),
-            "i" => Some(Self::Init),
-            "n" => Some(Self::Spawn),
-            "a" => Some(Self::Attach),
-            "t" => Some(Self::Chat),
-            "l" => Some(Self::CoreLogs),
-            "L" => Some(Self::LastLog),
-            "h" => Some(Self::SshPanel),
-            "c" => Some(Self::Compose),
-            "0" => Some(Self::ResetPan),
-            "Enter" => Some(Self::Send),
-            "Esc" => Some(Self::Cancel),
-            "Space" => Some(Self::ToggleRemove),
-            _ => None,
-        }
-    }
-}
-
 /// Horizontal key-hint bar rendered between header and body.
 ///
 /// Combines all available key bindings into a single compact line
@@ -131,58 +199,57 @@ impl KeyHintAction {
 pub(crate) struct KeyBarPanel;
 
 impl KeyBarPanel {
-    fn active_keys(app: &App) -> Vec<KeyBind> {
-        let mut all_keys: Vec<KeyBind> = toolbar_bindings(app)
+    fn active_keys(app: &App) -> Vec<(KeyBind, Option<KeyHintAction>)> {
+        let mut all_keys: Vec<(KeyBind, Option<KeyHintAction>)> = toolbar_bindings(app)
             .into_iter()
-            .map(|binding| KeyBind::new(binding.key, binding.label))
+            .filter_map(|command| {
+                // The select hint covers both directions.
+                let key = if command == CommandId::MoveDown {
+                    let up = command_key_hint(app, CommandId::MoveUp)?;
+                    let down = command_key_hint(app, CommandId::MoveDown)?;
+                    format!("{up}/{down}")
+                } else {
+                    command_key_hint(app, command)?
+                };
+                Some((
+                    KeyBind::owned(key, command.label()),
+                    Some(KeyHintAction::Command(command)),
+                ))
+            })
             .collect();
 
-        if app.is_chat_composing() {
-            all_keys.extend(
-                CHAT_COMPOSE_KEYS
-                    .iter()
-                    .map(|binding| KeyBind::new(binding.key, binding.action)),
-            );
-        }
-
-        if app.is_clone_form_open() {
-            all_keys.extend(
-                CLONE_FORM_KEYS
-                    .iter()
-                    .map(|binding| KeyBind::new(binding.key, binding.action)),
-            );
-        }
-
-        if app.is_branch_form_open() {
-            all_keys.extend(
-                BRANCH_FORM_KEYS
-                    .iter()
-                    .map(|binding| KeyBind::new(binding.key, binding.action)),
-            );
-        }
-
-        if app.is_delete_variant_form_open() {
-            all_keys.extend(
-                DELETE_FORM_KEYS
-                    .iter()
-                    .map(|binding| KeyBind::new(binding.key, binding.action)),
-            );
-        }
-
-        if app.is_move_actor_form_open() {
-            all_keys.extend(
-                MOVE_FORM_KEYS
-                    .iter()
-                    .map(|binding| KeyBind::new(binding.key, binding.action)),
-            );
-        }
-
-        if app.is_init_product_form_open() {
-            all_keys.extend(
-                INIT_PRODUCT_FORM_KEYS
-                    .iter()
-                    .map(|binding| KeyBind::new(binding.key, binding.action)),
-            );
+        let extras: &[(bool, ModeKeys)] = &[
+            (app.is_chat_composing(), CHAT_COMPOSE_KEYS),
+            (app.is_clone_form_open(), CLONE_FORM_KEYS),
+            (app.is_branch_form_open(), BRANCH_FORM_KEYS),
+            (app.is_delete_variant_form_open(), DELETE_FORM_KEYS),
+            (app.is_integrate_form_open(), INTEGRATE_FORM_KEYS),
+            (app.is_command_palette_open(), COMMAND_PALETTE_KEYS),
+            (app.is_git_panel_open(), GIT_PANEL_KEYS),
+            (app.is_catalog_search_open(), CATALOG_SEARCH_KEYS),
+            (app.is_bulk_prompt_form_open(), BULK_PROMPT_KEYS),
+            (app.is_bulk_summary_open(), BULK_SUMMARY_KEYS),
+            (app.is_notifications_open(), NOTIFICATIONS_KEYS),
+            (app.is_message_log_open(), MESSAGE_LOG_KEYS),
+            (app.is_metrics_open(), METRICS_KEYS),
+            (app.is_terminal_pane_focused(), TERMINAL_PANE_KEYS),
+            (app.is_core_logs_focused(), CORE_LOGS_KEYS),
+            (app.is_spawn_form_open(), SPAWN_FORM_KEYS),
+            (app.is_move_actor_form_open(), MOVE_FORM_KEYS),
+            (app.is_init_product_form_open(), INIT_PRODUCT_FORM_KEYS),
+        ];
+        let keymap = app.keymap();
+        for (open, (mode, binds)) in extras {
+            if !open {
+                continue;
+            }
+            all_keys.extend(binds.iter().filter_map(|(commands, label)| {
+                let key = popup_hint(keymap, mode, commands)?;
+                let target = keymap
+                    .key_event(mode, KeyCommand::Popup(commands[0]))
+                    .map(KeyHintAction::Key);
+                Some((KeyBind::owned(key, label), target))
+            }));
         }
 
         all_keys
@@ -191,7 +258,10 @@ impl KeyBarPanel {
     pub(crate) fn render(frame: &mut Frame, area: Rect, app: &App) {
         let theme = app.theme();
 
-        let owned = Self::active_keys(app);
+        let owned: Vec<KeyBind> = Self::active_keys(app)
+            .into_iter()
+            .map(|(bind, _)| bind)
+            .collect();
 
         let bar = KeyHintBar::new(&owned);
         let lines = bar.lines_wrapped(area.width, theme);
@@ -206,7 +276,7 @@ impl KeyBarPanel {
     /// Hit test: returns the key hint action at the given position, or None if not on a key hint.
     /// The area is the key bar bounds, and row/col are absolute terminal coordinates.
     pub(crate) fn hit_test(area: Rect, app: &App, row: u16, col: u16) -> Option<KeyHintAction> {
-        Self::hit_test_bind(area, app, row, col).and_then(|bind| KeyHintAction::from_key(bind.key))
+        Self::hit_test_bind(area, app, row, col).and_then(|bind| bind.target)
     }
 
     pub(crate) fn hover_hint(area: Rect, app: &App, row: u16, col: u16) -> Option<String> {
@@ -240,8 +310,8 @@ impl KeyBarPanel {
         let mut current_width: u16 = 0;
         let mut current_row: u16 = 0;
 
-        for bind in Self::active_keys(app) {
-            let entry_width = display_width(bind.key) + 2 + display_width(bind.action) + 1;
+        for (bind, target) in Self::active_keys(app) {
+            let entry_width = display_width(&bind.key) + 2 + display_width(bind.action) + 1;
             let total_width = if current_width > 0 {
                 sep_width + entry_width
             } else {
@@ -260,12 +330,13 @@ impl KeyBarPanel {
             let text_start = current_width + if current_width > 0 { sep_width } else { 0 };
             let text_end = text_start + entry_width;
             let key_start = text_start;
-            let key_width = display_width(bind.key) + 2;
+            let key_width = display_width(&bind.key) + 2;
 
             if local_row == current_row && local_col >= text_start && local_col < text_end {
                 return Some(HitBind {
-                    key: bind.key,
+                    key: bind.key.into_owned(),
                     action: bind.action,
+                    target,
                     row: current_row,
                     key_start,
                     key_width,
@@ -1,29 +1,15 @@
-mod branch_form_panel;
 mod chat_panel;
-mod clone_form_panel;
-mod context_menu_panel;
 mod core_logs_panel;
-mod delete_variant_form_panel;
 mod details_panel;
 mod footer_panel;
 mod header_panel;
-mod init_product_form_panel;
 mod key_bar_panel;
-mod move_actor_form_panel;
-mod spawn_form_panel;
-mod ssh_panel;
+mod terminal_panel;
 
-pub(crate) use branch_form_panel::{BranchFormHit, BranchFormPanel};
 pub(crate) use chat_panel::{ChatPanel, ChatPanelHit};
-pub(crate) use clone_form_panel::{CloneFormHit, CloneFormPanel};
-pub(crate) use context_menu_panel::{ContextMenuHit, ContextMenuPanel};
 pub(crate) use core_logs_panel::CoreLogsPanel;
-pub(crate) use delete_variant_form_panel::DeleteVariantFormPanel;
 pub(crate) use details_panel::DetailsPanel;
 pub(crate) use footer_panel::FooterPanel;
 pub(crate) use header_panel::HeaderPanel;
-pub(crate) use init_product_form_panel::InitProductFormPanel;
 pub(crate) use key_bar_panel::{KeyBarPanel, KeyHintAction, KeyHoverToken};
-pub(crate) use move_actor_form_panel::MoveActorFormPanel;
-pub(crate) use spawn_form_panel::SpawnFormPanel;
-pub(crate) use ssh_panel::SshPanel;
+pub(crate) use terminal_panel::TerminalPanel;
@@ -0,0 +1,100 @@
+use ratatui::Frame;
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::style::{Color, Modifier, Style};
+
+use dark_tui_components::PaneBlockComponent;
+
+use crate::app::App;
+use crate::ui::command_palette::detach_key_hint;
+
+/// Embedded PTY pane shown in the chat column.
+pub(crate) struct TerminalPanel;
+
+impl TerminalPanel {
+    pub(crate) fn render(frame: &mut Frame, area: Rect, app: &App) {
+        let Some(pane) = app.terminal_pane() else {
+            return;
+        };
+        let theme = app.theme();
+        let focused = app.is_terminal_pane_focused();
+
+        let scrolled = pane.scroll_offset();
+        let mut title = pane.title();
+        if scrolled > 0 {
+            title.push_str(&format!(" [scrollback -{scrolled}]"));
+        }
+        if focused {
+            title.push_str(&format!(" ({} detach)", detach_key_hint(app)));
+        } else {
+            title.push_str(" (detached)");
+        }
+
+        let block = PaneBlockComponent::build(&title, focused, theme);
+        let inner = block.inner(area);
+        frame.render_widget(block, area);
+
+        let cursor = pane.with_screen(|screen| {
+            draw_screen(frame.buffer_mut(), inner, screen);
+            let (row, col) = screen.cursor_position();
+            let visible = focused && scrolled == 0 && !screen.hide_cursor();
+            (visible && row < inner.height && col < inner.width)
+                .then(|| (inner.x + col, inner.y + row))
+        });
+        if let Some(position) = cursor {
+            frame.set_cursor_position(position);
+        }
+    }
+}
+
+fn draw_screen(buffer: &mut Buffer, area: Rect, screen: &vt100::Screen) {
+    for row in 0..area.height {
+        for col in 0..area.width {
+            let Some(cell) = screen.cell(row, col) else {
+                continue;
+            };
+            if cell.is_wide_continuation() {
+                continue;
+            }
+            let Some(target) = buffer.cell_mut((area.x + col, area.y + row)) else {
+                continue;
+            };
+
+            let contents = cell.contents();
+            target.set_symbol(if contents.is_empty() { " " } else { &contents });
+            target.set_style(cell_style(cell));
+        }
+    }
+}
+
+fn cell_style(cell: &vt100::Cell) -> Style {
+    let mut style = Style::default();
+    if let Some(fg) = vt_color(cell.fgcolor()) {
+        style = style.fg(fg);
+    }
+    if let Some(bg) = vt_color(cell.bgcolor()) {
+        style = style.bg(bg);
+    }
+    if cell.bold() {
+        style = style.add_modifier(Modifier::BOLD);
+    }
+    if cell.italic() {
+        style = style.add_modifier(Modifier::ITALIC);
+    }
+    if cell.underline() {
+        style = style.add_modifier(Modifier::UNDERLINED);
+    }
+    if cell.inverse() {
+        style = style.add_modifier(Modifier::REVERSED);
+    }
+    style
+}
+
+/// Terminal default colors keep the dashboard's own foreground/background.
+fn vt_color(color: vt100::Color) -> Option<Color> {
+    match color {
+        vt100::Color::Default => None,
+        vt100::Color::Idx(index) => Some(Color::Indexed(index)),
+        vt100::Color::Rgb(r, g, b) => Some(Color::Rgb(r, g, b)),
+    }
+}
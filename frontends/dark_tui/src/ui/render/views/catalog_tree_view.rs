@@ -5,23 +5,13 @@ use ratatui::text::{Line, Span};
 use ratatui::widgets::{List, ListItem, ListState};
 
 use crate::app::{App, VizSelection};
-use crate::models::compact_id;
-use crate::theme::EntityKind;
-use crate::ui::render::components::{sub_agent_badge, sub_agent_tree_line};
+use crate::models::{ActorRow, SubAgentRow, compact_id};
+use crate::theme::{EntityKind, EntityTheme};
+use crate::ui::render::components::{search_match_spans, sub_agent_badge, sub_agent_tree_line};
 
 use dark_tui_components::{PaneBlockComponent, StatusPill, compact_text_normalized};
 
 pub(crate) struct CatalogTreeView;
-const MAX_SUB_AGENT_ROWS: usize = 12;
-
-/// A display row in the tree list — either a selectable node or a
-/// decorative (non-selectable) sub-agent line.
-enum TreeRow {
-    /// Maps to a `VizSelection` node at `node_index` in the selectable list.
-    Selectable { node_index: usize },
-    /// Decorative sub-agent entry — skipped by selection logic.
-    SubAgent,
-}
 
 impl CatalogTreeView {
     pub(crate) fn render(frame: &mut Frame, area: Rect, app: &App) {
@@ -32,29 +22,11 @@ impl CatalogTreeView {
             .map(Self::entity_kind)
             .unwrap_or(EntityKind::Product);
 
-        // Build display rows: selectable nodes interleaved with decorative
-        // sub-agent lines that sit beneath their parent actor row.
-        let mut items: Vec<ListItem<'static>> = Vec::new();
-        let mut row_map: Vec<TreeRow> = Vec::new();
-
-        for (ni, node) in nodes.iter().enumerate() {
-            items.push(Self::item_for_node(app, node));
-            row_map.push(TreeRow::Selectable { node_index: ni });
-
-            // After an actor node, append its sub-agent lines (if any).
-            if let VizSelection::Actor { actor_id, .. } = node {
-                if let Some(actor) = app.actors().iter().find(|a| a.id == *actor_id) {
-                    let sub_agents = &actor.sub_agents;
-                    let visible_sub_agents = recent_sub_agents(sub_agents);
-                    for (si, agent) in visible_sub_agents.iter().enumerate() {
-                        let is_last = si == visible_sub_agents.len() - 1;
-                        let line = sub_agent_tree_line(agent, "       ", is_last, theme);
-                        items.push(ListItem::new(line));
-                        row_map.push(TreeRow::SubAgent);
-                    }
-                }
-            }
-        }
+        // One row per node; sub-agent nodes sit beneath their parent actor.
+        let items: Vec<ListItem<'static>> = nodes
+            .iter()
+            .map(|node| Self::item_for_node(app, node))
+            .collect();
 
         let list = List::new(items)
             .block(PaneBlockComponent::build("Catalog Tree", true, theme))
@@ -66,19 +38,13 @@ impl CatalogTreeView {
                     .add_modifier(Modifier::BOLD),
             );
 
-        // Map the currently selected selectable node to its display-row index.
         let mut state = ListState::default();
         if !nodes.is_empty() {
             let selected_node = app
                 .viz_selection()
                 .and_then(|current| nodes.iter().position(|node| node == current))
                 .unwrap_or(0);
-            // Find the display-row index that corresponds to this selectable node.
-            let display_idx = row_map
-                .iter()
-                .position(|r| matches!(r, TreeRow::Selectable { node_index } if *node_index == selected_node))
-                .unwrap_or(0);
-            state.select(Some(display_idx));
+            state.select(Some(selected_node));
         }
 
         frame.render_stateful_widget(list, area, &mut state);
@@ -96,43 +62,22 @@ impl CatalogTreeView {
             return None;
         }
 
-        let nodes = app.catalog_nodes();
-        if nodes.is_empty() {
-            return None;
-        }
-
-        let row_map = Self::display_row_map(app, &nodes);
         let row_index = row.saturating_sub(inner.y) as usize;
-        let Some(row_kind) = row_map.get(row_index) else {
-            return None;
-        };
-
-        match row_kind {
-            TreeRow::Selectable { node_index } => nodes.get(*node_index).cloned(),
-            TreeRow::SubAgent => None,
-        }
-    }
-
-    fn display_row_map(app: &App, nodes: &[VizSelection]) -> Vec<TreeRow> {
-        let mut row_map: Vec<TreeRow> = Vec::new();
-
-        for (node_index, node) in nodes.iter().enumerate() {
-            row_map.push(TreeRow::Selectable { node_index });
-
-            if let VizSelection::Actor { actor_id, .. } = node {
-                if let Some(actor) = app.actors().iter().find(|actor| actor.id == *actor_id) {
-                    for _ in recent_sub_agents(&actor.sub_agents) {
-                        row_map.push(TreeRow::SubAgent);
-                    }
-                }
-            }
-        }
-
-        row_map
+        app.catalog_nodes().into_iter().nth(row_index)
     }
 
     fn item_for_node(app: &App, node: &VizSelection) -> ListItem<'static> {
-        ListItem::new(Line::from(Self::line_for_node(app, node)))
+        let mut spans = Self::line_for_node(app, node);
+        // Marked rows swap the entity glyph after the tree connector for a check mark.
+        if let Some(glyph) = spans.get_mut(1).filter(|_| app.is_marked(node)) {
+            *glyph = Span::styled(
+                "✓ ",
+                Style::default()
+                    .fg(app.theme().pill_accent_fg)
+                    .add_modifier(Modifier::BOLD),
+            );
+        }
+        ListItem::new(Line::from(spans))
     }
 
     fn line_for_node(app: &App, node: &VizSelection) -> Vec<Span<'static>> {
@@ -158,17 +103,19 @@ impl CatalogTreeView {
                         StatusPill::ok(format!("{}v clean", product.variant_total), theme)
                     };
 
-                    vec![
-                        Span::styled(
-                            "◆ ",
-                            Style::default().fg(theme.entity_color(EntityKind::Product)),
-                        ),
-                        Span::styled(
-                            product_name,
-                            Style::default()
-                                .fg(theme.text_primary)
-                                .add_modifier(Modifier::BOLD),
-                        ),
+                    let mut spans = vec![Span::styled(
+                        "◆ ",
+                        Style::default().fg(theme.entity_color(EntityKind::Product)),
+                    )];
+                    spans.extend(search_match_spans(
+                        app,
+                        node,
+                        product_name,
+                        Style::default()
+                            .fg(theme.text_primary)
+                            .add_modifier(Modifier::BOLD),
+                    ));
+                    spans.extend([
                         Span::raw("  "),
                         Span::styled(
                             compact_id(&product.id),
@@ -176,7 +123,8 @@ impl CatalogTreeView {
                         ),
                         Span::raw("  "),
                         summary.span(),
-                    ]
+                    ]);
+                    spans
                 } else {
                     vec![Span::styled(
                         "◆ <missing product>",
@@ -198,23 +146,28 @@ impl CatalogTreeView {
                     };
                     let ahead_behind = ahead_behind_pill(variant.ahead, variant.behind, theme);
 
-                    vec![
+                    let mut spans = vec![
                         Span::styled("  ├─ ", Style::default().fg(theme.catalog_connector)),
                         Span::styled(
                             "◈ ",
                             Style::default().fg(theme.entity_color(EntityKind::Variant)),
                         ),
-                        Span::styled(
-                            variant.name.clone(),
-                            Style::default().fg(theme.text_primary),
-                        ),
+                    ];
+                    spans.extend(search_match_spans(
+                        app,
+                        node,
+                        variant.name.clone(),
+                        Style::default().fg(theme.text_primary),
+                    ));
+                    spans.extend([
                         Span::raw("  "),
                         state_pill.span(),
                         Span::raw(" "),
                         StatusPill::info(format!(" {branch}"), theme).span(),
                         Span::raw(" "),
                         ahead_behind.span(),
-                    ]
+                    ]);
+                    spans
                 } else {
                     vec![Span::styled(
                         "  ├─ ◈ <missing variant>",
@@ -256,13 +209,20 @@ impl CatalogTreeView {
                             "● ",
                             Style::default().fg(theme.entity_color(EntityKind::Actor)),
                         ),
-                        Span::styled(title, Style::default().fg(theme.text_secondary)),
+                    ];
+                    spans.extend(search_match_spans(
+                        app,
+                        node,
+                        title,
+                        Style::default().fg(theme.text_secondary),
+                    ));
+                    spans.extend([
                         Span::styled(description, Style::default().fg(theme.text_muted)),
                         Span::raw("  "),
                         StatusPill::info(&actor.provider, theme).span(),
                         Span::raw(" "),
                         status_pill.span(),
-                    ];
+                    ]);
                     if let Some(badge) = sub_agent_badge(actor.sub_agent_count(), theme) {
                         spans.push(Span::raw(" "));
                         spans.push(badge);
@@ -275,6 +235,22 @@ impl CatalogTreeView {
                     )]
                 }
             }
+            VizSelection::SubAgent {
+                actor_id,
+                sub_agent_id,
+                ..
+            } => {
+                let actor = app.actors().iter().find(|actor| actor.id == *actor_id);
+                match actor.and_then(|actor| sub_agent_position(actor, sub_agent_id)) {
+                    Some((agent, is_last)) => {
+                        sub_agent_tree_line(agent, "       ", is_last, theme).spans
+                    }
+                    None => vec![Span::styled(
+                        "       └╴⚙ <missing sub-agent>",
+                        Style::default().fg(theme.text_muted),
+                    )],
+                }
+            }
         }
     }
 
@@ -282,14 +258,19 @@ impl CatalogTreeView {
         match node {
             VizSelection::Product { .. } => EntityKind::Product,
             VizSelection::Variant { .. } => EntityKind::Variant,
-            VizSelection::Actor { .. } => EntityKind::Actor,
+            VizSelection::Actor { .. } | VizSelection::SubAgent { .. } => EntityKind::Actor,
         }
     }
 }
 
-fn recent_sub_agents(sub_agents: &[crate::models::SubAgentRow]) -> &[crate::models::SubAgentRow] {
-    let start = sub_agents.len().saturating_sub(MAX_SUB_AGENT_ROWS);
-    &sub_agents[start..]
+/// The listed sub-agent and whether it is the actor's last listed one.
+fn sub_agent_position<'a>(
+    actor: &'a ActorRow,
+    sub_agent_id: &str,
+) -> Option<(&'a SubAgentRow, bool)> {
+    let listed = actor.recent_sub_agents();
+    let index = listed.iter().position(|agent| agent.id == sub_agent_id)?;
+    Some((&listed[index], index + 1 == listed.len()))
 }
 
 fn variant_state_pill(state: &str, theme: &crate::theme::Theme) -> StatusPill {
@@ -10,7 +10,9 @@ use dark_tui_components::{PaneBlockComponent, StatusPill, compact_text_normalize
 use crate::app::{App, VizDensity, VizSelection};
 use crate::models::compact_locator;
 use crate::theme::Theme;
-use crate::ui::render::components::{render_sub_agent_grid, sub_agent_grid_container_height};
+use crate::ui::render::components::{
+    render_sub_agent_grid, search_match_spans, sub_agent_grid_container_height,
+};
 
 use super::catalog_cards::ProductGroup;
 use super::catalog_tree_view::CatalogTreeView;
@@ -51,6 +53,20 @@ struct WorldRect {
     height: u16,
 }
 
+/// Check mark shown before marked variant and actor titles; empty otherwise.
+fn mark_span(marked: bool, theme: &Theme) -> Span<'static> {
+    if marked {
+        Span::styled(
+            "✓ ",
+            Style::default()
+                .fg(theme.pill_accent_fg)
+                .add_modifier(Modifier::BOLD),
+        )
+    } else {
+        Span::raw("")
+    }
+}
+
 fn variant_state_pill(state: &str, theme: &Theme) -> StatusPill {
     match state {
         "clean" => StatusPill::ok("clean", theme),
@@ -422,7 +438,7 @@ impl UnifiedCatalogView {
             let max_actors_in_group: usize = group
                 .variants
                 .iter()
-                .map(|v| app.actors_for_variant(&v.id).len())
+                .map(|v| app.visible_actors_for_variant(&v.id).len())
                 .max()
                 .unwrap_or(0);
             let mut base_variant_pitch: i32 = if wide_columns {
@@ -454,7 +470,7 @@ impl UnifiedCatalogView {
                     height: VARIANT_H,
                 };
 
-                let actors = app.actors_for_variant(&variant.id);
+                let actors = app.visible_actors_for_variant(&variant.id);
                 let visible_count = actors.len().min(STATION_MAX_ACTORS_PER_VARIANT);
                 let overflow_count = actors.len().saturating_sub(STATION_MAX_ACTORS_PER_VARIANT);
                 let mut actor_layouts = Vec::with_capacity(visible_count);
@@ -755,12 +771,16 @@ impl UnifiedCatalogView {
             StatusPill::muted("󰉋 local", theme)
         };
 
-        let row0 = Line::from(vec![Span::styled(
+        let row0 = Line::from(search_match_spans(
+            app,
+            &VizSelection::Product {
+                product_index: group.product_index,
+            },
             compact_text_normalized(&display_name, inner_w.max(8)),
             Style::default()
                 .fg(theme.text_primary)
                 .add_modifier(Modifier::BOLD),
-        )]);
+        ));
 
         let row1 = Line::from(vec![
             status_pill.span(),
@@ -882,12 +902,19 @@ impl UnifiedCatalogView {
 
         // Row 0: variant name
         let inner_w = area.width.saturating_sub(2) as usize;
-        let row0 = Line::from(vec![Span::styled(
+        let mut row0_spans = vec![mark_span(app.is_variant_marked(&variant.id), theme)];
+        row0_spans.extend(search_match_spans(
+            app,
+            &VizSelection::Variant {
+                product_index: layout.product_index,
+                variant_id: variant.id.clone(),
+            },
             variant.name.clone(),
             Style::default()
                 .fg(theme.text_primary)
                 .add_modifier(Modifier::BOLD),
-        )]);
+        ));
+        let row0 = Line::from(row0_spans);
 
         // Row 1: reusable status pills
         let branch = if variant.branch.trim().is_empty() {
@@ -975,7 +1002,8 @@ impl UnifiedCatalogView {
 
         let selected = matches!(
             app.viz_selection(),
-            Some(VizSelection::Actor { actor_id, .. }) if *actor_id == layout.actor_id
+            Some(VizSelection::Actor { actor_id, .. } | VizSelection::SubAgent { actor_id, .. })
+                if *actor_id == layout.actor_id
         );
         let border = if selected {
             Style::default()
@@ -988,12 +1016,20 @@ impl UnifiedCatalogView {
         // Row 0: actor title
         let inner_w = area.width.saturating_sub(2) as usize;
         let title_label = compact_text_normalized(&actor.title, inner_w.saturating_sub(6).max(8));
-        let row0 = Line::from(vec![Span::styled(
+        let mut row0_spans = vec![mark_span(app.is_actor_marked(&actor.id), theme)];
+        row0_spans.extend(search_match_spans(
+            app,
+            &VizSelection::Actor {
+                product_index: layout.product_index,
+                variant_id: actor.variant_id.clone(),
+                actor_id: actor.id.clone(),
+            },
             title_label,
             Style::default()
                 .fg(theme.text_primary)
                 .add_modifier(Modifier::BOLD),
-        )]);
+        ));
+        let row0 = Line::from(row0_spans);
 
         let status_pill = actor_status_pill(&actor.status, theme);
         let provider_pill = StatusPill::info(format!("󰘧 {}", actor.provider), theme);
@@ -1057,13 +1093,14 @@ impl UnifiedCatalogView {
         app.products()
             .iter()
             .enumerate()
+            .filter(|(_, product)| app.is_product_visible(&product.id))
             .map(|(product_index, product)| ProductGroup {
                 product,
                 product_index,
                 variants: app
                     .variants()
                     .iter()
-                    .filter(|v| v.product_id == product.id)
+                    .filter(|v| v.product_id == product.id && app.is_variant_visible(&v.id))
                     .collect(),
             })
             .collect()
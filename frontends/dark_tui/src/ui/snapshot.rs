@@ -0,0 +1,224 @@
+//! `dark_tui snapshot`: renders a view into an off-screen buffer and exports it
+//! as text, ANSI, SVG or HTML without touching the terminal.
+
+use std::fs;
+use std::io::Write;
+use std::path::Path;
+
+use anyhow::{Context, Result};
+use dark_tui_components::{
+    ThemeSource, buffer_to_ansi, buffer_to_html, buffer_to_svg, buffer_to_text,
+};
+use ratatui::Terminal;
+use ratatui::backend::TestBackend;
+use ratatui::buffer::Buffer;
+
+use super::render;
+use crate::app::{App, ResultsViewMode};
+use crate::cli::{Cli, SnapshotArgs, SnapshotFormat, SnapshotView};
+use crate::models::DashboardSnapshot;
+use crate::service::DashboardService;
+use crate::theme::Theme;
+
+pub(crate) async fn run(cli: &Cli, args: &SnapshotArgs) -> Result<()> {
+    let directory = super::resolve_directory(cli.directory.as_deref())?;
+    let snapshot = match &args.fixture {
+        Some(path) => load_fixture(path)?,
+        None => {
+            let service =
+                DashboardService::new(cli.base_url.clone(), directory.clone(), cli.poll_variants)
+                    .await;
+            service.fetch_snapshot().await.with_context(|| {
+                format!(
+                    "Dark TUI // Snapshot // Failed to fetch from {}",
+                    cli.base_url
+                )
+            })?
+        }
+    };
+
+    let render = || {
+        let theme = snapshot_theme(cli, args.format)?;
+        render_snapshot(
+            directory.clone(),
+            snapshot.clone(),
+            theme,
+            args.view,
+            args.width,
+            args.height,
+        )
+    };
+    let output = match args.format {
+        SnapshotFormat::Json => {
+            serde_json::to_string_pretty(&snapshot)
+                .context("Dark TUI // Snapshot // Failed to encode fixture")?
+                + "\n"
+        }
+        SnapshotFormat::Text => buffer_to_text(&render()?) + "\n",
+        SnapshotFormat::Ansi => buffer_to_ansi(&render()?),
+        SnapshotFormat::Svg => buffer_to_svg(&render()?),
+        SnapshotFormat::Html => buffer_to_html(&render()?),
+    };
+
+    match &args.output {
+        Some(path) => fs::write(path, output)
+            .with_context(|| format!("Dark TUI // Snapshot // Failed to write {}", path.display())),
+        None => std::io::stdout()
+            .write_all(output.as_bytes())
+            .context("Dark TUI // Snapshot // Failed to write stdout"),
+    }
+}
+
+fn load_fixture(path: &Path) -> Result<DashboardSnapshot> {
+    let text = fs::read_to_string(path).with_context(|| {
+        format!(
+            "Dark TUI // Snapshot // Failed to read fixture {}",
+            path.display()
+        )
+    })?;
+    serde_json::from_str(&text)
+        .with_context(|| format!("Dark TUI // Snapshot // Invalid fixture {}", path.display()))
+}
+
+/// ANSI output follows the terminal's color support; images keep full color.
+fn snapshot_theme(cli: &Cli, format: SnapshotFormat) -> Result<Theme> {
+    let source = ThemeSource::resolve(cli.theme.as_deref());
+    let theme = if format == SnapshotFormat::Ansi {
+        source.load()
+    } else {
+        source.palette()
+    };
+    theme.context("Dark TUI // Theme // Invalid theme")
+}
+
+fn render_snapshot(
+    directory: String,
+    snapshot: DashboardSnapshot,
+    theme: Theme,
+    view: SnapshotView,
+    width: u16,
+    height: u16,
+) -> Result<Buffer> {
+    let mut app = App::without_persisted_state(directory, 0, theme);
+    app.apply_snapshot(snapshot);
+    app.set_core_runtime_hint("core:snapshot");
+    app.set_status("Snapshot");
+    if view == SnapshotView::Viz && app.results_view_mode() != ResultsViewMode::Viz {
+        app.toggle_results_view_mode();
+    }
+
+    let mut terminal = Terminal::new(TestBackend::new(width.max(1), height.max(1)))
+        .context("Dark TUI // Snapshot // Failed to create off-screen terminal")?;
+    terminal
+        .draw(|frame| render::render_snapshot_view(frame, &app, view))
+        .context("Dark TUI // Snapshot // Failed to render")?;
+    Ok(terminal.backend().buffer().clone())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{ActorRow, ProductRow, VariantRow};
+
+    fn fixture() -> DashboardSnapshot {
+        DashboardSnapshot {
+            products: vec![ProductRow {
+                id: "prd_1".to_string(),
+                display_name: "dark-factory".to_string(),
+                status: "clean".to_string(),
+                variant_total: 1,
+                ..ProductRow::default()
+            }],
+            variants: vec![VariantRow {
+                id: "var_1".to_string(),
+                product_id: "prd_1".to_string(),
+                name: "default".to_string(),
+                branch: "main".to_string(),
+                ..VariantRow::default()
+            }],
+            actors: vec![ActorRow {
+                id: "act_1".to_string(),
+                variant_id: "var_1".to_string(),
+                title: "Snapshot actor".to_string(),
+                status: "running".to_string(),
+                ..ActorRow::default()
+            }],
+            ..DashboardSnapshot::default()
+        }
+    }
+
+    #[test]
+    fn fixtures_round_trip_through_json_with_defaults() {
+        let json = serde_json::to_string(&fixture()).expect("encode");
+        let decoded: DashboardSnapshot = serde_json::from_str(&json).expect("decode");
+        assert_eq!(decoded.actors[0].title, "Snapshot actor");
+
+        let sparse: DashboardSnapshot =
+            serde_json::from_str(r#"{"products":[{"id":"prd_9","display_name":"solo"}]}"#)
+                .expect("sparse fixture");
+        assert_eq!(sparse.products[0].display_name, "solo");
+        assert!(sparse.variants.is_empty());
+    }
+
+    #[test]
+    fn views_render_fixture_rows_headlessly() {
+        for view in [
+            SnapshotView::Dashboard,
+            SnapshotView::Viz,
+            SnapshotView::Tree,
+            SnapshotView::Catalog,
+        ] {
+            let buffer =
+                render_snapshot(".".to_string(), fixture(), Theme::default(), view, 140, 40)
+                    .expect("render");
+            let text = buffer_to_text(&buffer);
+            assert!(text.contains("dark-factory"), "{view:?}:\n{text}");
+        }
+
+        let buffer = render_snapshot(
+            ".".to_string(),
+            fixture(),
+            Theme::default(),
+            SnapshotView::Tree,
+            100,
+            20,
+        )
+        .expect("render");
+        assert!(buffer_to_svg(&buffer).contains("dark-factory"));
+        assert!(buffer_to_html(&buffer).contains("dark-factory"));
+    }
+
+    #[test]
+    fn snapshots_ignore_the_directory_persisted_state() {
+        let directory =
+            std::env::temp_dir().join(format!("dark_tui-snapshot-{}", std::process::id()));
+        let state = directory.join(".darkfactory");
+        let _ = std::fs::remove_dir_all(&directory);
+        std::fs::create_dir_all(&directory).expect("snapshot dir");
+        let render = || {
+            let buffer = render_snapshot(
+                directory.to_string_lossy().to_string(),
+                fixture(),
+                Theme::default(),
+                SnapshotView::Dashboard,
+                140,
+                40,
+            )
+            .expect("render");
+            buffer_to_text(&buffer)
+        };
+        let clean = render();
+
+        std::fs::create_dir_all(&state).expect("state dir");
+        std::fs::write(state.join("darktui_queue.json"), "{not json").expect("queue");
+        std::fs::write(
+            state.join("darktui_layout.toml"),
+            "results_view = \"viz\"\n",
+        )
+        .expect("layout");
+        let populated = render();
+        let _ = std::fs::remove_dir_all(&directory);
+
+        assert_eq!(clean, populated);
+    }
+}
@@ -0,0 +1,497 @@
+use std::path::Path;
+
+use anyhow::{Result, anyhow};
+
+use crate::variant_git::{git, git_with_codes};
+
+/// Untracked files beyond this count are listed by name only.
+const MAX_UNTRACKED_DIFFS: usize = 200;
+
+/// What the variant's changes are compared against.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DiffBase {
+    /// Staged and unstaged changes on top of the variant's own HEAD.
+    Head,
+    /// Everything since the merge-base with the product's branch, including commits.
+    ProductBranch,
+}
+
+impl DiffBase {
+    pub fn toggled(self) -> Self {
+        match self {
+            Self::Head => Self::ProductBranch,
+            Self::ProductBranch => Self::Head,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DiffSection {
+    Committed,
+    Staged,
+    Unstaged,
+}
+
+impl DiffSection {
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Committed => "committed",
+            Self::Staged => "staged",
+            Self::Unstaged => "unstaged",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DiffFileStatus {
+    Added,
+    Deleted,
+    Modified,
+    Renamed,
+    Untracked,
+}
+
+impl DiffFileStatus {
+    pub fn marker(self) -> char {
+        match self {
+            Self::Added => 'A',
+            Self::Deleted => 'D',
+            Self::Modified => 'M',
+            Self::Renamed => 'R',
+            Self::Untracked => '?',
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DiffLineKind {
+    Context,
+    Added,
+    Removed,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DiffLine {
+    pub kind: DiffLineKind,
+    pub old_line: Option<u32>,
+    pub new_line: Option<u32>,
+    pub text: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DiffHunk {
+    pub header: String,
+    pub lines: Vec<DiffLine>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DiffFile {
+    pub section: DiffSection,
+    pub path: String,
+    pub old_path: Option<String>,
+    pub status: DiffFileStatus,
+    pub binary: bool,
+    pub hunks: Vec<DiffHunk>,
+    pub additions: usize,
+    pub deletions: usize,
+}
+
+#[derive(Debug, Clone)]
+pub struct VariantDiff {
+    pub variant_id: String,
+    pub base: DiffBase,
+    /// Human-readable comparison target (`HEAD`, `main@1a2b3c4`).
+    pub base_label: String,
+    pub files: Vec<DiffFile>,
+}
+
+impl VariantDiff {
+    pub fn additions(&self) -> usize {
+        self.files.iter().map(|file| file.additions).sum()
+    }
+
+    pub fn deletions(&self) -> usize {
+        self.files.iter().map(|file| file.deletions).sum()
+    }
+}
+
+/// Collects the variant's changes with local `git`, split into committed (product
+/// branch base only), staged, and unstaged sections. Untracked files count as unstaged.
+pub fn load_variant_diff(
+    variant_id: &str,
+    directory: &Path,
+    base: DiffBase,
+    product_branch: Option<&str>,
+) -> Result<VariantDiff> {
+    let mut files = Vec::new();
+
+    let base_label = match base {
+        DiffBase::Head => "HEAD".to_string(),
+        DiffBase::ProductBranch => {
+            let base_ref = resolve_base_ref(directory, product_branch)?;
+            let merge_base = git(directory, &["merge-base", "HEAD", &base_ref])?
+                .trim()
+                .to_string();
+            let committed = git(
+                directory,
+                &[
+                    "diff",
+                    "--no-color",
+                    "--no-ext-diff",
+                    "-M",
+                    &merge_base,
+                    "HEAD",
+                ],
+            )?;
+            files.extend(parse_unified_diff(DiffSection::Committed, &committed));
+
+            let short = merge_base.get(..7).unwrap_or(&merge_base);
+            format!("{base_ref}@{short}")
+        }
+    };
+
+    let staged = git(
+        directory,
+        &["diff", "--no-color", "--no-ext-diff", "-M", "--cached"],
+    )?;
+    files.extend(parse_unified_diff(DiffSection::Staged, &staged));
+
+    let unstaged = git(directory, &["diff", "--no-color", "--no-ext-diff", "-M"])?;
+    files.extend(parse_unified_diff(DiffSection::Unstaged, &unstaged));
+
+    files.extend(untracked_files(directory)?);
+
+    Ok(VariantDiff {
+        variant_id: variant_id.to_string(),
+        base,
+        base_label,
+        files,
+    })
+}
+
+/// Picks the first product branch candidate that exists in the variant clone, falling
+/// back to the remote default branch when the product branch is unknown.
+fn resolve_base_ref(directory: &Path, product_branch: Option<&str>) -> Result<String> {
+    let mut candidates = Vec::new();
+    if let Some(branch) = product_branch
+        .map(str::trim)
+        .filter(|branch| !branch.is_empty() && *branch != "-")
+    {
+        candidates.push(branch.to_string());
+        candidates.push(format!("origin/{branch}"));
+    }
+    candidates.extend(["origin/HEAD", "main", "master"].map(ToString::to_string));
+
+    candidates
+        .into_iter()
+        .find(|candidate| {
+            git(
+                directory,
+                &[
+                    "rev-parse",
+                    "--verify",
+                    "--quiet",
+                    &format!("{candidate}^{{commit}}"),
+                ],
+            )
+            .is_ok()
+        })
+        .ok_or_else(|| {
+            anyhow!(
+                "Dark TUI // Diff // No base branch found (product_branch={})",
+                product_branch.unwrap_or("-")
+            )
+        })
+}
+
+fn untracked_files(directory: &Path) -> Result<Vec<DiffFile>> {
+    let listing = git(
+        directory,
+        &["ls-files", "--others", "--exclude-standard", "-z"],
+    )?;
+
+    let mut files = Vec::new();
+    for (index, path) in listing
+        .split('\0')
+        .filter(|path| !path.is_empty())
+        .enumerate()
+    {
+        let parsed = if index < MAX_UNTRACKED_DIFFS {
+            git_with_codes(
+                directory,
+                &[
+                    "diff",
+                    "--no-color",
+                    "--no-ext-diff",
+                    "--no-index",
+                    "--",
+                    "/dev/null",
+                    path,
+                ],
+                &[0, 1],
+            )
+            .map(|text| parse_unified_diff(DiffSection::Unstaged, &text))
+            .unwrap_or_default()
+        } else {
+            Vec::new()
+        };
+
+        let mut file = parsed.into_iter().next().unwrap_or_else(|| DiffFile {
+            section: DiffSection::Unstaged,
+            path: path.to_string(),
+            old_path: None,
+            status: DiffFileStatus::Untracked,
+            binary: false,
+            hunks: Vec::new(),
+            additions: 0,
+            deletions: 0,
+        });
+        file.path = path.to_string();
+        file.status = DiffFileStatus::Untracked;
+        files.push(file);
+    }
+
+    Ok(files)
+}
+
+/// Parses `git diff` output into per-file hunks with old/new line numbers.
+pub fn parse_unified_diff(section: DiffSection, text: &str) -> Vec<DiffFile> {
+    let mut files: Vec<DiffFile> = Vec::new();
+    let mut old_line = 0u32;
+    let mut new_line = 0u32;
+
+    for line in text.lines() {
+        if let Some(header) = line.strip_prefix("diff --git ") {
+            let path = header
+                .rsplit_once(" b/")
+                .map(|(_, path)| path)
+                .unwrap_or(header);
+            files.push(DiffFile {
+                section,
+                path: unquote_path(path),
+                old_path: None,
+                status: DiffFileStatus::Modified,
+                binary: false,
+                hunks: Vec::new(),
+                additions: 0,
+                deletions: 0,
+            });
+            continue;
+        }
+
+        let Some(file) = files.last_mut() else {
+            continue;
+        };
+
+        if let Some(header) = line.strip_prefix("@@") {
+            let (old_start, new_start) = parse_hunk_starts(header);
+            old_line = old_start;
+            new_line = new_start;
+            file.hunks.push(DiffHunk {
+                header: line.to_string(),
+                lines: Vec::new(),
+            });
+            continue;
+        }
+
+        let Some(hunk) = file.hunks.last_mut() else {
+            if line.starts_with("new file mode") {
+                file.status = DiffFileStatus::Added;
+            } else if line.starts_with("deleted file mode") {
+                file.status = DiffFileStatus::Deleted;
+            } else if let Some(path) = line.strip_prefix("rename from ") {
+                file.status = DiffFileStatus::Renamed;
+                file.old_path = Some(unquote_path(path));
+            } else if let Some(path) = line.strip_prefix("rename to ") {
+                file.path = unquote_path(path);
+            } else if let Some(path) = line.strip_prefix("+++ b/") {
+                file.path = unquote_path(path);
+            } else if line.starts_with("Binary files ") || line == "GIT binary patch" {
+                file.binary = true;
+            }
+            continue;
+        };
+
+        let (kind, text) = match line.chars().next() {
+            Some('+') => (DiffLineKind::Added, &line[1..]),
+            Some('-') => (DiffLineKind::Removed, &line[1..]),
+            Some(' ') => (DiffLineKind::Context, &line[1..]),
+            // "\ No newline at end of file" and blank trailers.
+            _ => continue,
+        };
+
+        let (old, new) = match kind {
+            DiffLineKind::Added => {
+                file.additions += 1;
+                new_line += 1;
+                (None, Some(new_line - 1))
+            }
+            DiffLineKind::Removed => {
+                file.deletions += 1;
+                old_line += 1;
+                (Some(old_line - 1), None)
+            }
+            DiffLineKind::Context => {
+                old_line += 1;
+                new_line += 1;
+                (Some(old_line - 1), Some(new_line - 1))
+            }
+        };
+        hunk.lines.push(DiffLine {
+            kind,
+            old_line: old,
+            new_line: new,
+            text: text.to_string(),
+        });
+    }
+
+    files
+}
+
+/// Pairs removed/added runs so a hunk can render as old | new columns.
+pub fn side_by_side_rows(hunk: &DiffHunk) -> Vec<SideBySideRow<'_>> {
+    let mut rows = Vec::new();
+    let mut removed: Vec<&DiffLine> = Vec::new();
+    let mut added: Vec<&DiffLine> = Vec::new();
+
+    for line in &hunk.lines {
+        match line.kind {
+            DiffLineKind::Removed => {
+                if !added.is_empty() {
+                    flush_changed_run(&mut rows, &mut removed, &mut added);
+                }
+                removed.push(line);
+            }
+            DiffLineKind::Added => added.push(line),
+            DiffLineKind::Context => {
+                flush_changed_run(&mut rows, &mut removed, &mut added);
+                rows.push((Some(line), Some(line)));
+            }
+        }
+    }
+    flush_changed_run(&mut rows, &mut removed, &mut added);
+
+    rows
+}
+
+type SideBySideRow<'a> = (Option<&'a DiffLine>, Option<&'a DiffLine>);
+
+fn flush_changed_run<'a>(
+    rows: &mut Vec<SideBySideRow<'a>>,
+    removed: &mut Vec<&'a DiffLine>,
+    added: &mut Vec<&'a DiffLine>,
+) {
+    let count = removed.len().max(added.len());
+    for index in 0..count {
+        rows.push((removed.get(index).copied(), added.get(index).copied()));
+    }
+    removed.clear();
+    added.clear();
+}
+
+fn parse_hunk_starts(header: &str) -> (u32, u32) {
+    let mut old_start = 0;
+    let mut new_start = 0;
+    for token in header.split_whitespace() {
+        let start = |value: &str| {
+            value
+                .split(',')
+                .next()
+                .and_then(|start| start.parse::<u32>().ok())
+                .unwrap_or(0)
+        };
+        if let Some(value) = token.strip_prefix('-') {
+            old_start = start(value);
+        } else if let Some(value) = token.strip_prefix('+') {
+            new_start = start(value);
+            break;
+        }
+    }
+
+    (old_start, new_start)
+}
+
+fn unquote_path(path: &str) -> String {
+    path.trim().trim_matches('"').to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{DiffFileStatus, DiffLineKind, DiffSection, parse_unified_diff, side_by_side_rows};
+
+    const SAMPLE: &str = "\
+diff --git a/src/lib.rs b/src/lib.rs
+index 1111111..2222222 100644
+--- a/src/lib.rs
++++ b/src/lib.rs
+@@ -10,4 +10,5 @@ fn main() {
+ keep
+-old one
+-old two
++new one
++new two
++new three
+ tail
+diff --git a/old name.txt b/new name.txt
+similarity index 90%
+rename from old name.txt
+rename to new name.txt
+diff --git a/logo.png b/logo.png
+new file mode 100644
+index 0000000..3333333
+Binary files /dev/null and b/logo.png differ
+";
+
+    #[test]
+    fn parses_files_hunks_and_line_numbers() {
+        let files = parse_unified_diff(DiffSection::Staged, SAMPLE);
+        assert_eq!(files.len(), 3);
+
+        let lib = &files[0];
+        assert_eq!(lib.path, "src/lib.rs");
+        assert_eq!(lib.status, DiffFileStatus::Modified);
+        assert_eq!(lib.section, DiffSection::Staged);
+        assert_eq!((lib.additions, lib.deletions), (3, 2));
+        assert_eq!(lib.hunks.len(), 1);
+
+        let lines = &lib.hunks[0].lines;
+        assert_eq!(lines[0].kind, DiffLineKind::Context);
+        assert_eq!((lines[0].old_line, lines[0].new_line), (Some(10), Some(10)));
+        assert_eq!((lines[2].old_line, lines[2].new_line), (Some(12), None));
+        assert_eq!((lines[5].old_line, lines[5].new_line), (None, Some(13)));
+        assert_eq!((lines[6].old_line, lines[6].new_line), (Some(13), Some(14)));
+
+        assert_eq!(files[1].status, DiffFileStatus::Renamed);
+        assert_eq!(files[1].path, "new name.txt");
+        assert_eq!(files[1].old_path.as_deref(), Some("old name.txt"));
+
+        assert_eq!(files[2].status, DiffFileStatus::Added);
+        assert!(files[2].binary);
+    }
+
+    #[test]
+    fn pairs_removed_and_added_runs_side_by_side() {
+        let files = parse_unified_diff(DiffSection::Unstaged, SAMPLE);
+        let rows = side_by_side_rows(&files[0].hunks[0]);
+
+        let texts: Vec<(Option<&str>, Option<&str>)> = rows
+            .iter()
+            .map(|(left, right)| {
+                (
+                    left.map(|line| line.text.as_str()),
+                    right.map(|line| line.text.as_str()),
+                )
+            })
+            .collect();
+        assert_eq!(
+            texts,
+            vec![
+                (Some("keep"), Some("keep")),
+                (Some("old one"), Some("new one")),
+                (Some("old two"), Some("new two")),
+                (None, Some("new three")),
+                (Some("tail"), Some("tail")),
+            ]
+        );
+    }
+}
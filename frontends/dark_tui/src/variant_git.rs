@@ -0,0 +1,290 @@
+use std::path::Path;
+use std::process::Command;
+
+use anyhow::{Context, Result, anyhow};
+
+/// Snapshot of a variant worktree shown in the git panel.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct VariantGitSummary {
+    pub branch: String,
+    pub upstream: Option<String>,
+    pub ahead: u32,
+    pub behind: u32,
+    pub staged: usize,
+    pub unstaged: usize,
+    pub untracked: usize,
+    pub conflicted: usize,
+    pub stashes: usize,
+    pub remotes: Vec<String>,
+    pub last_commit: Option<String>,
+}
+
+impl VariantGitSummary {
+    pub fn has_changes(&self) -> bool {
+        self.staged + self.unstaged + self.untracked + self.conflicted > 0
+    }
+}
+
+/// Routine git chores the panel can run against a local variant worktree.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GitOperation {
+    /// Stages everything (`git add -A`) and commits with the message.
+    Commit {
+        message: String,
+    },
+    Stash,
+    StashPop,
+    Fetch,
+    Push {
+        remote: String,
+    },
+    ResetToUpstream,
+}
+
+impl GitOperation {
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Commit { .. } => "commit",
+            Self::Stash => "stash",
+            Self::StashPop => "unstash",
+            Self::Fetch => "fetch",
+            Self::Push { .. } => "push",
+            Self::ResetToUpstream => "reset to upstream",
+        }
+    }
+}
+
+pub fn read_variant_git_summary(directory: &Path) -> Result<VariantGitSummary> {
+    let status = git(directory, &["status", "--porcelain=v2", "--branch"])?;
+    let mut summary = parse_status_porcelain_v2(&status);
+
+    summary.stashes = git(directory, &["stash", "list"])
+        .map(|listing| listing.lines().filter(|line| !line.is_empty()).count())
+        .unwrap_or(0);
+    summary.remotes = git(directory, &["remote"])?
+        .lines()
+        .map(str::trim)
+        .filter(|remote| !remote.is_empty())
+        .map(ToString::to_string)
+        .collect();
+    summary.last_commit = git(directory, &["log", "-1", "--format=%h %s"])
+        .ok()
+        .map(|line| line.trim().to_string())
+        .filter(|line| !line.is_empty());
+
+    Ok(summary)
+}
+
+/// Runs one operation and returns a one-line outcome for the status bar.
+pub fn run_git_operation(directory: &Path, operation: &GitOperation) -> Result<String> {
+    match operation {
+        GitOperation::Commit { message } => {
+            let message = message.trim();
+            if message.is_empty() {
+                return Err(anyhow!("Dark TUI // Git // Commit message cannot be empty"));
+            }
+
+            git(directory, &["add", "-A"])?;
+            git(directory, &["commit", "-m", message])?;
+            let head = git(directory, &["log", "-1", "--format=%h %s"])?;
+            Ok(format!("Committed {}", head.trim()))
+        }
+        GitOperation::Stash => {
+            let output = git(directory, &["stash", "push", "--include-untracked"])?;
+            if output.contains("No local changes to save") {
+                Ok("Nothing to stash.".to_string())
+            } else {
+                Ok("Stashed local changes.".to_string())
+            }
+        }
+        GitOperation::StashPop => {
+            git(directory, &["stash", "pop"])?;
+            Ok("Restored the latest stash.".to_string())
+        }
+        GitOperation::Fetch => {
+            git(directory, &["fetch", "--all", "--prune"])?;
+            Ok("Fetched all remotes.".to_string())
+        }
+        GitOperation::Push { remote } => {
+            let branch = current_branch(directory)?;
+            if upstream_ref(directory).is_some() {
+                git(directory, &["push", remote, "HEAD"])?;
+                Ok(format!("Pushed {branch} to {remote}."))
+            } else {
+                git(directory, &["push", "--set-upstream", remote, &branch])?;
+                Ok(format!("Pushed {branch} to {remote} and set upstream."))
+            }
+        }
+        GitOperation::ResetToUpstream => {
+            let upstream = upstream_ref(directory).ok_or_else(|| {
+                anyhow!("Dark TUI // Git // Reset needs an upstream branch; push first")
+            })?;
+            git(directory, &["reset", "--hard", "@{u}"])?;
+            Ok(format!("Reset to {upstream}."))
+        }
+    }
+}
+
+/// Prompt asking the bound actor to draft a commit message for the worktree's changes.
+pub fn commit_draft_prompt(directory: &Path) -> Result<String> {
+    let status = git(directory, &["status", "--short"])?;
+    if status.trim().is_empty() {
+        return Err(anyhow!("Dark TUI // Git // No changes to describe"));
+    }
+    let stat = git(directory, &["diff", "--stat", "HEAD"]).unwrap_or_default();
+
+    Ok(format!(
+        "Draft a git commit message for the uncommitted changes in this worktree. \
+Reply with only the message: a subject line under 72 characters, optionally followed by a \
+blank line and a short body. Do not run any commands.\n\n```\n{}\n{}\n```",
+        status.trim_end(),
+        stat.trim_end()
+    ))
+}
+
+/// Strips code fences and surrounding whitespace from an actor's drafted message.
+pub fn extract_commit_draft(reply: &str) -> String {
+    let trimmed = reply.trim();
+    let unfenced = trimmed
+        .strip_prefix("```")
+        .and_then(|rest| rest.strip_suffix("```"))
+        .map(|inner| {
+            inner
+                .split_once('\n')
+                .map(|(_, body)| body)
+                .unwrap_or(inner)
+        })
+        .unwrap_or(trimmed);
+
+    unfenced.trim().to_string()
+}
+
+pub fn parse_status_porcelain_v2(text: &str) -> VariantGitSummary {
+    let mut summary = VariantGitSummary::default();
+
+    for line in text.lines() {
+        if let Some(head) = line.strip_prefix("# branch.head ") {
+            summary.branch = if head == "(detached)" {
+                "HEAD (detached)".to_string()
+            } else {
+                head.to_string()
+            };
+        } else if let Some(upstream) = line.strip_prefix("# branch.upstream ") {
+            summary.upstream = Some(upstream.to_string());
+        } else if let Some(counts) = line.strip_prefix("# branch.ab ") {
+            for part in counts.split_whitespace() {
+                if let Some(value) = part.strip_prefix('+') {
+                    summary.ahead = value.parse().unwrap_or(0);
+                } else if let Some(value) = part.strip_prefix('-') {
+                    summary.behind = value.parse().unwrap_or(0);
+                }
+            }
+        } else if line.starts_with("1 ") || line.starts_with("2 ") {
+            let mut flags = line[2..].chars();
+            if flags.next().is_some_and(|flag| flag != '.') {
+                summary.staged += 1;
+            }
+            if flags.next().is_some_and(|flag| flag != '.') {
+                summary.unstaged += 1;
+            }
+        } else if line.starts_with("u ") {
+            summary.conflicted += 1;
+        } else if line.starts_with("? ") {
+            summary.untracked += 1;
+        }
+    }
+
+    summary
+}
+
+fn current_branch(directory: &Path) -> Result<String> {
+    let branch = git(directory, &["rev-parse", "--abbrev-ref", "HEAD"])?;
+    let branch = branch.trim();
+    if branch == "HEAD" {
+        return Err(anyhow!("Dark TUI // Git // Cannot push a detached HEAD"));
+    }
+
+    Ok(branch.to_string())
+}
+
+fn upstream_ref(directory: &Path) -> Option<String> {
+    git(
+        directory,
+        &["rev-parse", "--abbrev-ref", "--symbolic-full-name", "@{u}"],
+    )
+    .ok()
+    .map(|upstream| upstream.trim().to_string())
+    .filter(|upstream| !upstream.is_empty())
+}
+
+/// Runs git without a terminal prompt so credential requests fail instead of hanging the TUI.
+pub(crate) fn git(directory: &Path, args: &[&str]) -> Result<String> {
+    git_with_codes(directory, args, &[0])
+}
+
+/// `git diff --no-index` exits 1 when the inputs differ, so callers list accepted codes.
+pub(crate) fn git_with_codes(directory: &Path, args: &[&str], ok_codes: &[i32]) -> Result<String> {
+    let output = Command::new("git")
+        .arg("-C")
+        .arg(directory)
+        .args(args)
+        .env("GIT_TERMINAL_PROMPT", "0")
+        .output()
+        .with_context(|| format!("Dark TUI // Git // Failed to run git {}", args.join(" ")))?;
+
+    let accepted = output
+        .status
+        .code()
+        .is_some_and(|code| ok_codes.contains(&code));
+    if !accepted {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        let detail = if stderr.trim().is_empty() {
+            stdout.trim().to_string()
+        } else {
+            stderr.trim().to_string()
+        };
+        return Err(anyhow!(
+            "Dark TUI // Git // git {} failed: {detail}",
+            args.first().copied().unwrap_or_default()
+        ));
+    }
+
+    Ok(String::from_utf8_lossy(&output.stdout).to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{extract_commit_draft, parse_status_porcelain_v2};
+
+    #[test]
+    fn parses_branch_tracking_and_change_counts() {
+        let summary = parse_status_porcelain_v2(
+            "# branch.oid 1234\n\
+# branch.head df/demo\n\
+# branch.upstream origin/df/demo\n\
+# branch.ab +2 -1\n\
+1 M. N... 100644 100644 100644 a a src/staged.rs\n\
+1 .M N... 100644 100644 100644 a a src/unstaged.rs\n\
+1 MM N... 100644 100644 100644 a a src/both.rs\n\
+u UU N... 100644 100644 100644 100644 a a a src/conflict.rs\n\
+? notes.txt\n",
+        );
+
+        assert_eq!(summary.branch, "df/demo");
+        assert_eq!(summary.upstream.as_deref(), Some("origin/df/demo"));
+        assert_eq!((summary.ahead, summary.behind), (2, 1));
+        assert_eq!((summary.staged, summary.unstaged), (2, 2));
+        assert_eq!((summary.untracked, summary.conflicted), (1, 1));
+        assert!(summary.has_changes());
+    }
+
+    #[test]
+    fn strips_fences_from_drafted_commit_message() {
+        assert_eq!(
+            extract_commit_draft("```text\nAdd git panel\n\nBody line\n```\n"),
+            "Add git panel\n\nBody line"
+        );
+        assert_eq!(extract_commit_draft("  Fix typo  "), "Fix typo");
+    }
+}
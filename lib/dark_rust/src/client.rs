@@ -7,7 +7,7 @@ use crate::types::{
     ActorMessageInput, ActorMessagesQuery, ActorUpdateInput, ProductCreateInput,
     ProductIncludeQuery, ProductListQuery, ProductUpdateInput, ProductVariantCloneInput,
     VariantBranchSwitchInput, VariantCreateInput, VariantDeleteQuery, VariantImportActorsInput,
-    VariantListQuery, VariantUpdateInput,
+    VariantIntegrateInput, VariantListQuery, VariantUpdateInput,
 };
 
 #[derive(Debug, Clone)]
@@ -296,6 +296,18 @@ impl DarkCoreClient {
         .await
     }
 
+    pub async fn variants_integrate(
+        &self,
+        variant_id: &str,
+        input: &VariantIntegrateInput,
+    ) -> Result<RawApiResponse, DarkRustError> {
+        self.post(
+            &format!("/variants/{variant_id}/integrate"),
+            serde_json::to_value(input)?,
+        )
+        .await
+    }
+
     pub async fn variants_update(
         &self,
         variant_id: &str,
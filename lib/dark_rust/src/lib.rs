@@ -9,8 +9,8 @@ pub use client::{DarkCoreClient, RawApiResponse};
 pub use error::DarkRustError;
 pub use locator_id::{LocalLocator, LocatorId, LocatorKind};
 pub use runtime::{
-    DarkCoreLaunchConfig, EnsureDarkCoreState, ensure_dark_core_in_tmux_if_needed,
-    is_local_dark_core_url,
+    DarkCoreLaunchConfig, EnsureDarkCoreState, default_dark_core_log_path,
+    ensure_dark_core_in_tmux_if_needed, is_local_dark_core_url,
 };
 pub use types::{
     ProductGitInfo, SystemResetDatabaseData, SystemResetDatabaseDeletedRows, VariantGitInfo,
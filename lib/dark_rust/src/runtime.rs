@@ -9,6 +9,7 @@ use crate::{DarkCoreClient, DarkRustError};
 
 const BUILD_DARK_CORE_EXECUTABLE: &str = env!("DARKFACTORY_DARK_CORE_EXECUTABLE");
 const BUILD_DARK_CORE_WORKDIR: &str = env!("DARKFACTORY_DARK_CORE_WORKDIR");
+const DARK_CORE_LOG_FILE_ENV: &str = "DARKFACTORY_LOG_FILE";
 
 #[derive(Debug, Clone)]
 pub struct DarkCoreLaunchConfig {
@@ -16,6 +17,8 @@ pub struct DarkCoreLaunchConfig {
     pub executable_path: Option<PathBuf>,
     pub workdir: Option<PathBuf>,
     pub restart_existing_session: bool,
+    /// File dark_core mirrors its log lines into; defaults to [`default_dark_core_log_path`].
+    pub log_file: Option<PathBuf>,
     pub wait_timeout: Duration,
     pub wait_interval: Duration,
 }
@@ -27,6 +30,7 @@ impl Default for DarkCoreLaunchConfig {
             executable_path: None,
             workdir: None,
             restart_existing_session: true,
+            log_file: None,
             wait_timeout: Duration::from_secs(30),
             wait_interval: Duration::from_millis(350),
         }
@@ -49,6 +53,18 @@ pub fn is_local_dark_core_url(base_url: &str) -> bool {
         || normalized.starts_with("https://127.0.0.1:")
 }
 
+/// Log file a locally managed dark_core writes to: `DARKFACTORY_LOG_FILE` when set,
+/// otherwise `.darkfactory/logs/dark_core.log` next to the dark_core workdir.
+pub fn default_dark_core_log_path() -> Option<PathBuf> {
+    if let Some(path) = env::var_os(DARK_CORE_LOG_FILE_ENV).filter(|path| !path.is_empty()) {
+        return Some(PathBuf::from(path));
+    }
+
+    let workdir = resolve_workdir(None, None).ok()?;
+    let root = workdir.parent().unwrap_or(&workdir);
+    Some(root.join(".darkfactory").join("logs").join("dark_core.log"))
+}
+
 pub async fn ensure_dark_core_in_tmux_if_needed(
     base_url: &str,
     launch_config: DarkCoreLaunchConfig,
@@ -75,6 +91,7 @@ pub async fn ensure_dark_core_in_tmux_if_needed(
     )?;
     let executable_path =
         resolve_or_build_executable_path(launch_config.executable_path, &workdir)?;
+    let log_file = launch_config.log_file.or_else(default_dark_core_log_path);
 
     if session_exists {
         // Prefer a clean restart when a managed session already exists so each
@@ -86,6 +103,7 @@ pub async fn ensure_dark_core_in_tmux_if_needed(
         &launch_config.tmux_session_name,
         &workdir,
         &executable_path,
+        log_file.as_ref(),
         base_url,
     )?;
 
@@ -174,10 +192,19 @@ fn launch_dark_core_tmux(
     session_name: &str,
     workdir: &PathBuf,
     executable_path: &PathBuf,
+    log_file: Option<&PathBuf>,
     base_url: &str,
 ) -> Result<(), DarkRustError> {
+    let log_env = log_file
+        .map(|path| {
+            format!(
+                "{DARK_CORE_LOG_FILE_ENV}='{}' ",
+                shell_escape_single_quotes(&path.display().to_string())
+            )
+        })
+        .unwrap_or_default();
     let launch_command = format!(
-        "cd '{}' && '{}'",
+        "cd '{}' && {log_env}'{}'",
         shell_escape_single_quotes(&workdir.display().to_string()),
         shell_escape_single_quotes(&executable_path.display().to_string())
     );
@@ -14,5 +14,6 @@ pub use system::{SystemResetDatabaseData, SystemResetDatabaseDeletedRows};
 pub use variants::{
     ProductVariantCloneInput, VariantBranchSwitchInput, VariantCreateInput, VariantDeleteQuery,
     VariantGitInfo, VariantGitStatus, VariantGitWorktree, VariantImportActorsInput,
-    VariantListQuery, VariantProductConnectInput, VariantProductRelationInput, VariantUpdateInput,
+    VariantIntegrateCommit, VariantIntegrateInput, VariantIntegrateResult, VariantListQuery,
+    VariantProductConnectInput, VariantProductRelationInput, VariantUpdateInput,
 };
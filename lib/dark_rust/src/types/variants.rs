@@ -39,6 +39,42 @@ pub struct VariantBranchSwitchInput {
     pub branch_name: String,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct VariantIntegrateInput {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub strategy: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub preview: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub after: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub base_branch: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VariantIntegrateCommit {
+    pub sha: String,
+    pub author: String,
+    pub subject: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VariantIntegrateResult {
+    pub variant_id: String,
+    pub status: String,
+    pub strategy: String,
+    pub base_branch: String,
+    pub source_branch: String,
+    pub target_path: String,
+    pub commits: Vec<VariantIntegrateCommit>,
+    pub conflicts: Vec<String>,
+    pub integrated_head: Option<String>,
+    pub pushed: bool,
+    pub after: String,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 #[serde(rename_all = "camelCase")]
 pub struct ProductVariantCloneInput {
@@ -1,5 +1,6 @@
 use dark_tui_components::{
-    ComponentTheme, KeyBind, KeyHintBar, LabeledField, SectionHeader, StatusPill,
+    ComponentTheme, DataColumn, DataTable, KeyBind, KeyHintBar, LabeledField, SectionHeader,
+    StatusPill,
 };
 
 fn main() {
@@ -16,8 +17,19 @@ fn main() {
     let header = SectionHeader::new("Identity", theme.pill_info_fg).line(28, &theme);
     let pill = StatusPill::ok("clean", &theme).span();
 
+    let mut table = DataTable::new(vec![
+        DataColumn::new("name", "Name", 12),
+        DataColumn::new("size", "Size", 6).numeric(),
+    ]);
+    table.set_rows(vec![
+        vec!["alpha".to_string(), "12".to_string()],
+        vec!["beta".to_string(), "3".to_string()],
+    ]);
+    table.toggle_sort(1);
+
     println!("{}", header);
     println!("{}", label);
     println!("{}", pill.content);
     println!("wrapped hint lines: {}", lines.len());
+    println!("smallest row: {:?}", table.selected_cells());
 }
@@ -34,6 +34,21 @@ pub enum Action {
     Noop,
 }
 
+impl Action {
+    /// Wraps an app-specific payload for the action bus.
+    pub fn custom<T: Any + Send + Sync>(payload: T) -> Self {
+        Self::Custom(Arc::new(payload))
+    }
+
+    /// Returns the payload of a `Custom` action when it has type `T`.
+    pub fn downcast_ref<T: Any>(&self) -> Option<&T> {
+        match self {
+            Self::Custom(payload) => payload.downcast_ref::<T>(),
+            _ => None,
+        }
+    }
+}
+
 impl PartialEq for Action {
     fn eq(&self, other: &Self) -> bool {
         match (self, other) {
@@ -1,3 +1,5 @@
+use std::any::Any;
+
 use crossterm::event::{KeyEvent, MouseEvent};
 use ratatui::Frame;
 use ratatui::layout::{Rect, Size};
@@ -11,7 +13,10 @@ use crate::theme::ComponentThemeLike;
 pub type ComponentResult<T = ()> = Result<T, Box<dyn std::error::Error + Send + Sync>>;
 
 /// Shared component lifecycle trait for Ratatui surfaces.
-pub trait Component: Send + Sync {
+///
+/// Components are `Any` so a host can read a mounted component's state back
+/// through [`crate::ComponentRuntime::component`].
+pub trait Component: Any + Send + Sync {
     /// Registers a channel used to emit follow-up actions.
     fn register_action_handler(&mut self, _tx: UnboundedSender<Action>) -> ComponentResult {
         Ok(())
@@ -1,9 +1,10 @@
 use ratatui::Frame;
 use ratatui::layout::Rect;
 use ratatui::style::Style;
-use ratatui::text::Line;
+use ratatui::text::{Line, Span};
 use ratatui::widgets::{Paragraph, Wrap};
 
+use crate::components::status_pill::StatusPill;
 use crate::theme::ComponentThemeLike;
 
 /// Props for rendering the chat composer body.
@@ -15,6 +16,8 @@ pub struct ChatComposerProps<'a> {
     pub cursor_index: usize,
     pub idle_hint: &'a str,
     pub disabled_hint: &'a str,
+    /// Chip labels for files staged with the next prompt.
+    pub attachments: &'a [String],
 }
 
 impl<'a> ChatComposerProps<'a> {
@@ -27,6 +30,7 @@ impl<'a> ChatComposerProps<'a> {
             cursor_index: draft.chars().count(),
             idle_hint: "Press c to compose, Enter to send.",
             disabled_hint: "Input disabled.",
+            attachments: &[],
         }
     }
 }
@@ -46,6 +50,21 @@ impl ChatComposerComponent {
         frame.render_widget(Paragraph::new(lines).wrap(Wrap { trim: false }), area);
     }
 
+    /// Attachment chips (`+ shot.png 182K`) for composers that render their own editor.
+    pub fn attachment_spans(
+        theme: &impl ComponentThemeLike,
+        attachments: &[String],
+    ) -> Vec<Span<'static>> {
+        let mut spans = Vec::with_capacity(attachments.len() * 2);
+        for (index, label) in attachments.iter().enumerate() {
+            if index > 0 {
+                spans.push(Span::raw(" "));
+            }
+            spans.push(StatusPill::info(format!("+ {label}"), theme).span_compact());
+        }
+        spans
+    }
+
     fn lines(theme: &impl ComponentThemeLike, props: ChatComposerProps<'_>) -> Vec<Line<'static>> {
         if !props.enabled {
             return vec![
@@ -81,13 +100,16 @@ impl ChatComposerComponent {
 
         let char_count = props.draft.chars().count();
 
-        vec![
-            Line::styled(line, Style::default()),
-            Line::styled(
-                format!("Enter=send  Esc=cancel  chars={char_count}"),
-                Style::default().fg(theme.text_muted()),
-            ),
-        ]
+        let mut lines = Vec::with_capacity(3);
+        if !props.attachments.is_empty() {
+            lines.push(Line::from(Self::attachment_spans(theme, props.attachments)));
+        }
+        lines.push(Line::styled(line, Style::default()));
+        lines.push(Line::styled(
+            format!("Enter=send  Esc=cancel  chars={char_count}"),
+            Style::default().fg(theme.text_muted()),
+        ));
+        lines
     }
 }
 
@@ -1,5 +1,5 @@
 use crate::compact_text;
-use pulldown_cmark::{CodeBlockKind, Event, HeadingLevel, Options, Parser, Tag, TagEnd};
+use pulldown_cmark::{Alignment, CodeBlockKind, Event, HeadingLevel, Options, Parser, Tag, TagEnd};
 use ratatui::Frame;
 use ratatui::layout::Rect;
 use ratatui::style::{Color, Modifier, Style};
@@ -7,6 +7,7 @@ use ratatui::text::{Line, Span};
 use ratatui::widgets::Paragraph;
 
 use crate::components::chat_types::{ChatMessageEntry, ChatMessageRole};
+use crate::components::code_highlight::{CodeLanguage, HighlightState, highlight_spans};
 use crate::theme::ComponentThemeLike;
 
 /// Message-list palette used to color per-role text.
@@ -135,6 +136,31 @@ impl ChatMessageListComponent {
         rows.get(absolute_row).and_then(|row| row.message_index)
     }
 
+    /// Returns the `scroll_offset_lines` that puts `message_index` at the top of the
+    /// viewport, or `None` when the message falls outside the rendered window.
+    pub fn scroll_offset_for_message(
+        theme: &impl ComponentThemeLike,
+        props: &ChatMessageListProps<'_>,
+        area_width: usize,
+        area_height: usize,
+        message_index: usize,
+    ) -> Option<u16> {
+        let rows = Self::rendered_rows(theme, props, area_width);
+        let first_row = rows
+            .iter()
+            .position(|row| row.message_index == Some(message_index))?;
+        // Message separators belong to the following card; skip past them.
+        let first_row = if first_row > 0 && rows[first_row].line.width() == 0 {
+            first_row + 1
+        } else {
+            first_row
+        };
+
+        let base_scroll = rows.len().saturating_sub(area_height);
+        let offset = base_scroll.saturating_sub(first_row.min(base_scroll));
+        Some(offset.min(u16::MAX as usize) as u16)
+    }
+
     fn rendered_rows(
         theme: &impl ComponentThemeLike,
         props: &ChatMessageListProps<'_>,
@@ -797,15 +823,19 @@ fn render_markdown_lines(
                 handle_end_tag(tag, &mut state, &mut current, &mut lines, theme);
             }
             Event::Text(value) => {
-                push_text(
-                    value.as_ref(),
-                    &mut state,
-                    &mut current,
-                    &mut lines,
-                    palette,
-                    theme,
-                    None,
-                );
+                if let Some(block) = state.code_block.as_mut() {
+                    block.text.push_str(value.as_ref());
+                } else {
+                    push_text(
+                        value.as_ref(),
+                        &mut state,
+                        &mut current,
+                        &mut lines,
+                        palette,
+                        theme,
+                        None,
+                    );
+                }
             }
             Event::Code(value) => {
                 state.inline_code_depth += 1;
@@ -912,14 +942,34 @@ fn handle_start_tag(
             push_block_break(current, lines);
             state.code_block_depth += 1;
 
-            if let CodeBlockKind::Fenced(language) = kind {
-                let language = language.trim();
-                if !language.is_empty() {
+            let mut language = None;
+            if let CodeBlockKind::Fenced(info) = kind {
+                let info = info.trim();
+                if !info.is_empty() {
                     lines.push(Line::from(Span::styled(
-                        format!("  [code:{language}]"),
+                        format!("  [code:{info}]"),
                         Style::default().fg(theme.text_secondary()),
                     )));
                 }
+                language = CodeLanguage::from_fence(info);
+            }
+            state.code_block = Some(CodeBlockBuffer {
+                language,
+                text: String::new(),
+            });
+        }
+        Tag::Table(alignments) => {
+            push_block_break(current, lines);
+            state.table = Some(TableBuffer::new(alignments));
+        }
+        Tag::TableHead => {
+            if let Some(table) = state.table.as_mut() {
+                table.in_head = true;
+            }
+        }
+        Tag::TableCell => {
+            if let Some(table) = state.table.as_mut() {
+                table.cell.clear();
             }
         }
         Tag::List(start_index) => {
@@ -946,8 +996,12 @@ fn handle_start_tag(
         Tag::Strikethrough => {
             state.strikethrough_depth += 1;
         }
-        Tag::Link { .. } => {
+        Tag::Link { dest_url, .. } | Tag::Image { dest_url, .. } => {
             state.link_depth += 1;
+            state.links.push(LinkTarget {
+                url: dest_url.to_string(),
+                text: String::new(),
+            });
         }
         _ => {}
     }
@@ -958,7 +1012,7 @@ fn handle_end_tag(
     state: &mut MarkdownState,
     current: &mut Vec<Span<'static>>,
     lines: &mut Vec<Line<'static>>,
-    _theme: &impl ComponentThemeLike,
+    theme: &impl ComponentThemeLike,
 ) {
     match tag {
         TagEnd::Paragraph => {
@@ -973,9 +1027,34 @@ fn handle_end_tag(
             push_block_break(current, lines);
         }
         TagEnd::CodeBlock => {
+            if let Some(block) = state.code_block.take() {
+                push_code_block(&block, state, current, lines, theme);
+            }
             state.code_block_depth = state.code_block_depth.saturating_sub(1);
             push_block_break(current, lines);
         }
+        TagEnd::TableCell => {
+            if let Some(table) = state.table.as_mut() {
+                let cell = std::mem::take(&mut table.cell);
+                table.row.push(cell);
+            }
+        }
+        TagEnd::TableHead | TagEnd::TableRow => {
+            if let Some(table) = state.table.as_mut() {
+                let row = std::mem::take(&mut table.row);
+                table.rows.push(row);
+                if table.in_head {
+                    table.in_head = false;
+                    table.header_rows = table.rows.len();
+                }
+            }
+        }
+        TagEnd::Table => {
+            if let Some(table) = state.table.take() {
+                push_table(&table, state, current, lines, theme);
+            }
+            push_block_break(current, lines);
+        }
         TagEnd::List(_) => {
             state.list_stack.pop();
             push_block_break(current, lines);
@@ -992,8 +1071,24 @@ fn handle_end_tag(
         TagEnd::Strikethrough => {
             state.strikethrough_depth = state.strikethrough_depth.saturating_sub(1);
         }
-        TagEnd::Link => {
+        TagEnd::Link | TagEnd::Image => {
             state.link_depth = state.link_depth.saturating_sub(1);
+            // Show the target unless the visible text already is the URL.
+            let target = state
+                .links
+                .pop()
+                .filter(|link| !link.url.is_empty())
+                .filter(|link| link.url.trim_start_matches("mailto:") != link.text.trim());
+            if let Some(link) = target {
+                push_styled(
+                    &format!(" ({})", link.url),
+                    Style::default().fg(theme.text_muted()),
+                    state,
+                    current,
+                    lines,
+                    theme,
+                );
+            }
         }
         _ => {}
     }
@@ -1009,6 +1104,30 @@ fn push_text(
     style_override: Option<Style>,
 ) {
     let style = style_override.unwrap_or_else(|| active_text_style(state, palette, theme));
+    push_styled(value, style, state, current, lines, theme);
+}
+
+/// Appends text to the current line, or to the open table cell.
+fn push_styled(
+    value: &str,
+    style: Style,
+    state: &mut MarkdownState,
+    current: &mut Vec<Span<'static>>,
+    lines: &mut Vec<Line<'static>>,
+    theme: &impl ComponentThemeLike,
+) {
+    if let Some(link) = state.links.last_mut() {
+        link.text.push_str(value);
+    }
+
+    if let Some(table) = state.table.as_mut() {
+        if !value.is_empty() {
+            table
+                .cell
+                .push(Span::styled(value.replace('\n', " "), style));
+        }
+        return;
+    }
 
     for (index, chunk) in value.split('\n').enumerate() {
         if index > 0 {
@@ -1024,6 +1143,95 @@ fn push_text(
     }
 }
 
+/// Emits a buffered fenced block line by line, highlighted when the fence
+/// names a known language.
+fn push_code_block(
+    block: &CodeBlockBuffer,
+    state: &mut MarkdownState,
+    current: &mut Vec<Span<'static>>,
+    lines: &mut Vec<Line<'static>>,
+    theme: &impl ComponentThemeLike,
+) {
+    let text = block.text.strip_suffix('\n').unwrap_or(&block.text);
+    let mut highlight = HighlightState::default();
+
+    for line in text.split('\n') {
+        ensure_line_prefix(state, current, theme);
+        match block.language {
+            Some(language) => {
+                current.extend(highlight_spans(language, line, &mut highlight, theme));
+            }
+            None if !line.is_empty() => current.push(Span::styled(
+                line.to_string(),
+                Style::default().fg(theme.text_secondary()),
+            )),
+            None => {}
+        }
+        finish_line(current, lines);
+    }
+}
+
+/// Renders a buffered table with padded, aligned columns.
+fn push_table(
+    table: &TableBuffer,
+    state: &mut MarkdownState,
+    current: &mut Vec<Span<'static>>,
+    lines: &mut Vec<Line<'static>>,
+    theme: &impl ComponentThemeLike,
+) {
+    let columns = table.rows.iter().map(Vec::len).max().unwrap_or(0);
+    if columns == 0 {
+        return;
+    }
+
+    let mut widths = vec![0usize; columns];
+    for row in &table.rows {
+        for (column, cell) in row.iter().enumerate() {
+            widths[column] = widths[column].max(spans_width(cell));
+        }
+    }
+
+    let border = Style::default().fg(theme.text_muted());
+    for (row_index, row) in table.rows.iter().enumerate() {
+        ensure_line_prefix(state, current, theme);
+        for (column, width) in widths.iter().enumerate() {
+            if column > 0 {
+                current.push(Span::styled(" │ ", border));
+            }
+            let cell = row.get(column).map(Vec::as_slice).unwrap_or(&[]);
+            let padding = width.saturating_sub(spans_width(cell));
+            let (left, right) = match table.alignments.get(column) {
+                Some(Alignment::Right) => (padding, 0),
+                Some(Alignment::Center) => (padding / 2, padding - padding / 2),
+                _ => (0, padding),
+            };
+            if left > 0 {
+                current.push(Span::raw(" ".repeat(left)));
+            }
+            current.extend(cell.iter().cloned());
+            if right > 0 && column + 1 < columns {
+                current.push(Span::raw(" ".repeat(right)));
+            }
+        }
+        finish_line(current, lines);
+
+        if row_index + 1 == table.header_rows {
+            ensure_line_prefix(state, current, theme);
+            let rule = widths
+                .iter()
+                .map(|width| "─".repeat(*width))
+                .collect::<Vec<_>>()
+                .join("─┼─");
+            current.push(Span::styled(rule, border));
+            finish_line(current, lines);
+        }
+    }
+}
+
+fn spans_width(spans: &[Span<'_>]) -> usize {
+    spans.iter().map(|span| span.content.chars().count()).sum()
+}
+
 fn ensure_line_prefix(
     state: &mut MarkdownState,
     current: &mut Vec<Span<'static>>,
@@ -1102,6 +1310,10 @@ fn active_text_style(
             .add_modifier(Modifier::UNDERLINED);
     }
 
+    if state.table.as_ref().is_some_and(|table| table.in_head) {
+        style = style.add_modifier(Modifier::BOLD);
+    }
+
     style
 }
 
@@ -1187,6 +1399,7 @@ fn markdown_options() -> Options {
     let mut options = Options::empty();
     options.insert(Options::ENABLE_STRIKETHROUGH);
     options.insert(Options::ENABLE_TASKLISTS);
+    options.insert(Options::ENABLE_TABLES);
     options
 }
 
@@ -1202,6 +1415,43 @@ struct MarkdownState {
     link_depth: usize,
     list_stack: Vec<ListState>,
     pending_item_prefix: Option<String>,
+    code_block: Option<CodeBlockBuffer>,
+    table: Option<TableBuffer>,
+    links: Vec<LinkTarget>,
+}
+
+/// Fenced/indented code collected until the block ends so it can be
+/// highlighted line by line.
+#[derive(Debug)]
+struct CodeBlockBuffer {
+    language: Option<CodeLanguage>,
+    text: String,
+}
+
+/// Table cells collected until the table ends so columns can be aligned.
+#[derive(Debug, Default)]
+struct TableBuffer {
+    alignments: Vec<Alignment>,
+    rows: Vec<Vec<Vec<Span<'static>>>>,
+    header_rows: usize,
+    in_head: bool,
+    row: Vec<Vec<Span<'static>>>,
+    cell: Vec<Span<'static>>,
+}
+
+impl TableBuffer {
+    fn new(alignments: Vec<Alignment>) -> Self {
+        Self {
+            alignments,
+            ..Self::default()
+        }
+    }
+}
+
+#[derive(Debug)]
+struct LinkTarget {
+    url: String,
+    text: String,
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -1410,6 +1660,50 @@ mod tests {
         assert!(rendered.contains("let x = 1;"));
     }
 
+    #[test]
+    fn markdown_highlights_code_and_renders_tables_and_links() {
+        let theme = ComponentTheme::default();
+        let message = ChatMessageEntry::new(
+            ChatMessageRole::Assistant,
+            "See [docs](https://example.com/docs) or <https://example.com>.\n\n| name | count |\n| :--- | ---: |\n| alpha | 1 |\n| beta | 12 |\n\n```rust\nfn main() {}\n```\n",
+            None,
+        );
+        let props = ChatMessageListProps {
+            messages: &[message],
+            empty_label: "No messages",
+            max_messages: 10,
+            max_body_lines_per_message: 20,
+            scroll_offset_lines: 0,
+            palette: ChatPalette::from_theme(&theme),
+        };
+
+        let lines = ChatMessageListComponent::lines(&theme, &props, 80);
+        let rendered = lines
+            .iter()
+            .map(|line| {
+                line.spans
+                    .iter()
+                    .map(|span| span.content.as_ref())
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        assert!(rendered.contains("docs (https://example.com/docs)"));
+        assert!(!rendered.contains("https://example.com (https://example.com)"));
+        assert!(rendered.contains("name  │ count"));
+        assert!(rendered.contains("──────┼──────"));
+        assert!(rendered.contains("alpha │     1"));
+        assert!(rendered.contains("beta  │    12"));
+
+        let keyword = lines
+            .iter()
+            .flat_map(|line| line.spans.iter())
+            .find(|span| span.content == "fn")
+            .expect("rust keyword span");
+        assert_eq!(keyword.style.fg, Some(theme.pill_accent_fg));
+    }
+
     #[test]
     fn markdown_body_obeys_line_cap() {
         let theme = ComponentTheme::default();
@@ -1517,4 +1811,36 @@ mod tests {
         // No box chrome for empty state
         assert!(!rendered.contains("╭"));
     }
+
+    #[test]
+    fn scroll_offset_for_message_targets_card_top() {
+        let theme = ComponentTheme::default();
+        let messages = (0..6)
+            .map(|index| {
+                ChatMessageEntry::new(ChatMessageRole::User, format!("message {index}"), None)
+            })
+            .collect::<Vec<_>>();
+        let props = ChatMessageListProps {
+            messages: &messages,
+            empty_label: "No messages",
+            max_messages: 4,
+            max_body_lines_per_message: 20,
+            scroll_offset_lines: 0,
+            palette: ChatPalette::from_theme(&theme),
+        };
+
+        // Each card is 4 rows plus a blank separator; 4 cards rendered = 19 rows.
+        assert_eq!(
+            ChatMessageListComponent::scroll_offset_for_message(&theme, &props, 40, 5, 2),
+            Some(14)
+        );
+        assert_eq!(
+            ChatMessageListComponent::scroll_offset_for_message(&theme, &props, 40, 5, 5),
+            Some(0)
+        );
+        assert_eq!(
+            ChatMessageListComponent::scroll_offset_for_message(&theme, &props, 40, 5, 1),
+            None
+        );
+    }
 }
@@ -0,0 +1,715 @@
+//! Lightweight line-based syntax highlighting for fenced markdown code.
+//!
+//! This is intentionally a small lexer rather than a grammar engine: it knows
+//! keywords, strings, comments, numbers and a few language-specific shapes,
+//! which is enough to make agent-produced code readable in a terminal.
+
+use ratatui::style::{Modifier, Style};
+use ratatui::text::Span;
+
+use crate::theme::ComponentThemeLike;
+
+/// Languages with dedicated highlighting rules.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum CodeLanguage {
+    Rust,
+    TypeScript,
+    Python,
+    Shell,
+    Json,
+    Toml,
+    Diff,
+}
+
+impl CodeLanguage {
+    /// Resolves a fence info string (`rust`, `ts`, `bash`, `rust,ignore`, ...).
+    pub(crate) fn from_fence(info: &str) -> Option<Self> {
+        let tag = info
+            .split(|ch: char| ch == ',' || ch.is_whitespace())
+            .next()
+            .unwrap_or("")
+            .to_ascii_lowercase();
+
+        match tag.as_str() {
+            "rust" | "rs" => Some(Self::Rust),
+            "ts" | "typescript" | "tsx" | "js" | "javascript" | "jsx" | "mjs" | "cjs" => {
+                Some(Self::TypeScript)
+            }
+            "py" | "python" | "python3" => Some(Self::Python),
+            "sh" | "bash" | "zsh" | "shell" | "console" | "shellsession" => Some(Self::Shell),
+            "json" | "jsonc" | "json5" => Some(Self::Json),
+            "toml" => Some(Self::Toml),
+            "diff" | "patch" => Some(Self::Diff),
+            _ => None,
+        }
+    }
+
+    fn spec(self) -> &'static LexSpec {
+        match self {
+            Self::Rust => &RUST,
+            Self::TypeScript => &TYPESCRIPT,
+            Self::Python => &PYTHON,
+            Self::Shell => &SHELL,
+            Self::Json => &JSON,
+            Self::Toml => &TOML,
+            Self::Diff => &PLAIN,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum TokenKind {
+    Plain,
+    Keyword,
+    Type,
+    Function,
+    String,
+    Number,
+    Comment,
+    Key,
+    Section,
+    Meta,
+    Added,
+    Removed,
+}
+
+/// Multi-line constructs that carry over between lines of one block.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub(crate) enum HighlightState {
+    #[default]
+    Normal,
+    BlockComment,
+    /// Inside a string that spans lines, closed by this delimiter.
+    String(&'static str),
+}
+
+struct LexSpec {
+    keywords: &'static [&'static str],
+    types: &'static [&'static str],
+    constants: &'static [&'static str],
+    line_comment: Option<&'static str>,
+    block_comment: Option<(&'static str, &'static str)>,
+    quotes: &'static [char],
+    /// Delimiters of strings that may span lines (`"""`, `` ` ``).
+    multiline_strings: &'static [&'static str],
+    capitalized_types: bool,
+}
+
+const PLAIN: LexSpec = LexSpec {
+    keywords: &[],
+    types: &[],
+    constants: &[],
+    line_comment: None,
+    block_comment: None,
+    quotes: &[],
+    multiline_strings: &[],
+    capitalized_types: false,
+};
+
+const RUST: LexSpec = LexSpec {
+    keywords: &[
+        "as", "async", "await", "break", "const", "continue", "crate", "dyn", "else", "enum",
+        "extern", "fn", "for", "if", "impl", "in", "let", "loop", "match", "mod", "move", "mut",
+        "pub", "ref", "return", "self", "Self", "static", "struct", "super", "trait", "type",
+        "unsafe", "use", "where", "while",
+    ],
+    types: &[
+        "bool", "char", "str", "u8", "u16", "u32", "u64", "u128", "usize", "i8", "i16", "i32",
+        "i64", "i128", "isize", "f32", "f64",
+    ],
+    constants: &["true", "false", "None", "Some", "Ok", "Err"],
+    line_comment: Some("//"),
+    block_comment: Some(("/*", "*/")),
+    quotes: &['"'],
+    multiline_strings: &[],
+    capitalized_types: true,
+};
+
+const TYPESCRIPT: LexSpec = LexSpec {
+    keywords: &[
+        "abstract",
+        "as",
+        "async",
+        "await",
+        "break",
+        "case",
+        "catch",
+        "class",
+        "const",
+        "continue",
+        "declare",
+        "default",
+        "delete",
+        "do",
+        "else",
+        "enum",
+        "export",
+        "extends",
+        "finally",
+        "for",
+        "from",
+        "function",
+        "if",
+        "implements",
+        "import",
+        "in",
+        "instanceof",
+        "interface",
+        "keyof",
+        "let",
+        "new",
+        "of",
+        "private",
+        "protected",
+        "public",
+        "readonly",
+        "return",
+        "satisfies",
+        "static",
+        "switch",
+        "this",
+        "throw",
+        "try",
+        "type",
+        "typeof",
+        "var",
+        "void",
+        "while",
+        "yield",
+    ],
+    types: &[
+        "any", "boolean", "never", "number", "object", "string", "symbol", "unknown", "bigint",
+    ],
+    constants: &["true", "false", "null", "undefined", "NaN", "Infinity"],
+    line_comment: Some("//"),
+    block_comment: Some(("/*", "*/")),
+    quotes: &['"', '\''],
+    multiline_strings: &["`"],
+    capitalized_types: true,
+};
+
+const PYTHON: LexSpec = LexSpec {
+    keywords: &[
+        "and", "as", "assert", "async", "await", "break", "class", "continue", "def", "del",
+        "elif", "else", "except", "finally", "for", "from", "global", "if", "import", "in", "is",
+        "lambda", "match", "case", "nonlocal", "not", "or", "pass", "raise", "return", "try",
+        "while", "with", "yield", "self",
+    ],
+    types: &[
+        "int", "float", "str", "bytes", "bool", "list", "dict", "set", "tuple", "object",
+    ],
+    constants: &["True", "False", "None"],
+    line_comment: Some("#"),
+    block_comment: None,
+    quotes: &['"', '\''],
+    multiline_strings: &["\"\"\"", "'''"],
+    capitalized_types: true,
+};
+
+const SHELL: LexSpec = LexSpec {
+    keywords: &[
+        "if", "then", "else", "elif", "fi", "for", "while", "until", "do", "done", "case", "esac",
+        "in", "function", "return", "export", "local", "readonly", "set", "unset", "source",
+        "exit", "cd", "echo",
+    ],
+    types: &[],
+    constants: &["true", "false"],
+    line_comment: Some("#"),
+    block_comment: None,
+    quotes: &['"', '\''],
+    multiline_strings: &[],
+    capitalized_types: false,
+};
+
+const JSON: LexSpec = LexSpec {
+    keywords: &[],
+    types: &[],
+    constants: &["true", "false", "null"],
+    line_comment: Some("//"),
+    block_comment: Some(("/*", "*/")),
+    quotes: &['"'],
+    multiline_strings: &[],
+    capitalized_types: false,
+};
+
+const TOML: LexSpec = LexSpec {
+    keywords: &[],
+    types: &[],
+    constants: &["true", "false", "inf", "nan"],
+    line_comment: Some("#"),
+    block_comment: None,
+    quotes: &['"', '\''],
+    multiline_strings: &["\"\"\"", "'''"],
+    capitalized_types: false,
+};
+
+/// Splits one line of code into styled tokens, updating `state` for
+/// constructs that continue on the next line.
+pub(crate) fn highlight_line(
+    language: CodeLanguage,
+    line: &str,
+    state: &mut HighlightState,
+) -> Vec<(TokenKind, String)> {
+    match language {
+        CodeLanguage::Diff => vec![(diff_line_kind(line), line.to_string())],
+        CodeLanguage::Toml if *state == HighlightState::Normal => {
+            let trimmed = line.trim_start();
+            if trimmed.starts_with('[') {
+                return vec![(TokenKind::Section, line.to_string())];
+            }
+            match toml_key_split(line) {
+                Some(split) => {
+                    let mut tokens = vec![(TokenKind::Key, line[..split].to_string())];
+                    tokens.extend(lex(language, &line[split..], state));
+                    tokens
+                }
+                None => lex(language, line, state),
+            }
+        }
+        _ => lex(language, line, state),
+    }
+}
+
+/// Maps a token kind onto the shared component theme.
+pub(crate) fn token_style(kind: TokenKind, theme: &impl ComponentThemeLike) -> Style {
+    let style = Style::default();
+    match kind {
+        TokenKind::Plain => style.fg(theme.text_secondary()),
+        TokenKind::Keyword => style
+            .fg(theme.pill_accent_fg())
+            .add_modifier(Modifier::BOLD),
+        TokenKind::Type | TokenKind::Key => style.fg(theme.pill_info_fg()),
+        TokenKind::Function => style.fg(theme.key_hint_key_fg()),
+        TokenKind::String | TokenKind::Added => style.fg(theme.pill_ok_fg()),
+        TokenKind::Number => style.fg(theme.pill_warn_fg()),
+        TokenKind::Comment => style.fg(theme.text_muted()).add_modifier(Modifier::ITALIC),
+        TokenKind::Section => style
+            .fg(theme.pill_accent_fg())
+            .add_modifier(Modifier::BOLD),
+        TokenKind::Meta => style.fg(theme.pill_muted_fg()).add_modifier(Modifier::BOLD),
+        TokenKind::Removed => style.fg(theme.pill_err_fg()),
+    }
+}
+
+/// Highlights a line straight into spans, merging adjacent same-style tokens.
+pub(crate) fn highlight_spans(
+    language: CodeLanguage,
+    line: &str,
+    state: &mut HighlightState,
+    theme: &impl ComponentThemeLike,
+) -> Vec<Span<'static>> {
+    let mut spans: Vec<Span<'static>> = Vec::new();
+    for (kind, text) in highlight_line(language, line, state) {
+        if text.is_empty() {
+            continue;
+        }
+        let style = token_style(kind, theme);
+        match spans.last_mut() {
+            Some(last) if last.style == style => last.content.to_mut().push_str(&text),
+            _ => spans.push(Span::styled(text, style)),
+        }
+    }
+    spans
+}
+
+fn diff_line_kind(line: &str) -> TokenKind {
+    if line.starts_with("+++")
+        || line.starts_with("---")
+        || line.starts_with("diff ")
+        || line.starts_with("index ")
+    {
+        TokenKind::Meta
+    } else if line.starts_with("@@") {
+        TokenKind::Section
+    } else if line.starts_with('+') {
+        TokenKind::Added
+    } else if line.starts_with('-') {
+        TokenKind::Removed
+    } else {
+        TokenKind::Plain
+    }
+}
+
+/// Byte offset just past a leading `key =` / `"quoted.key" =` in a TOML line.
+fn toml_key_split(line: &str) -> Option<usize> {
+    let eq = line.find('=')?;
+    let key = line[..eq].trim();
+    let bare = key
+        .chars()
+        .all(|ch| ch.is_ascii_alphanumeric() || matches!(ch, '_' | '-' | '.'));
+    let quoted = key.len() >= 2 && key.starts_with('"') && key.ends_with('"');
+    (!key.is_empty() && (bare || quoted)).then_some(eq)
+}
+
+fn lex(language: CodeLanguage, line: &str, state: &mut HighlightState) -> Vec<(TokenKind, String)> {
+    let spec = language.spec();
+    let chars: Vec<char> = line.chars().collect();
+    let mut tokens: Vec<(TokenKind, String)> = Vec::new();
+    let mut index = 0;
+    let mut previous_word = String::new();
+
+    let push = |tokens: &mut Vec<(TokenKind, String)>, kind: TokenKind, text: &[char]| match tokens
+        .last_mut()
+    {
+        Some((last_kind, last_text)) if *last_kind == kind => last_text.extend(text),
+        _ => tokens.push((kind, text.iter().collect())),
+    };
+
+    while index < chars.len() {
+        match state.clone() {
+            HighlightState::BlockComment => {
+                let close = spec.block_comment.map_or("*/", |(_, close)| close);
+                let end = match find(&chars, index, close) {
+                    Some(at) => {
+                        *state = HighlightState::Normal;
+                        at + close.chars().count()
+                    }
+                    None => chars.len(),
+                };
+                push(&mut tokens, TokenKind::Comment, &chars[index..end]);
+                index = end;
+                continue;
+            }
+            HighlightState::String(delimiter) => {
+                let end = match find_unescaped(&chars, index, delimiter) {
+                    Some(at) => {
+                        *state = HighlightState::Normal;
+                        at + delimiter.chars().count()
+                    }
+                    None => chars.len(),
+                };
+                push(&mut tokens, TokenKind::String, &chars[index..end]);
+                index = end;
+                continue;
+            }
+            HighlightState::Normal => {}
+        }
+
+        let ch = chars[index];
+        let rest = &chars[index..];
+
+        // `#` only opens a comment at a word boundary (`$#`, `a#b` are not comments).
+        if spec.line_comment.is_some_and(|marker| {
+            starts_with(rest, marker)
+                && (marker != "#" || index == 0 || chars[index - 1].is_whitespace())
+        }) {
+            push(&mut tokens, TokenKind::Comment, rest);
+            break;
+        }
+
+        if let Some((open, _)) = spec
+            .block_comment
+            .filter(|(open, _)| starts_with(rest, open))
+        {
+            *state = HighlightState::BlockComment;
+            push(
+                &mut tokens,
+                TokenKind::Comment,
+                &rest[..open.chars().count()],
+            );
+            index += open.chars().count();
+            continue;
+        }
+
+        if let Some(delimiter) = spec
+            .multiline_strings
+            .iter()
+            .copied()
+            .find(|delimiter| starts_with(rest, delimiter))
+        {
+            *state = HighlightState::String(delimiter);
+            let width = delimiter.chars().count();
+            push(&mut tokens, TokenKind::String, &rest[..width]);
+            index += width;
+            continue;
+        }
+
+        if spec.quotes.contains(&ch) {
+            let end = string_end(&chars, index, ch);
+            let kind = if language == CodeLanguage::Json && next_non_space(&chars, end) == Some(':')
+            {
+                TokenKind::Key
+            } else {
+                TokenKind::String
+            };
+            push(&mut tokens, kind, &chars[index..end]);
+            index = end;
+            continue;
+        }
+
+        // Rust: char literals vs lifetimes, and `#[attributes]`.
+        if language == CodeLanguage::Rust {
+            if ch == '\'' {
+                let end = rust_quote_end(&chars, index);
+                let kind = if chars[end - 1] == '\'' && end - index > 1 {
+                    TokenKind::String
+                } else {
+                    TokenKind::Type
+                };
+                push(&mut tokens, kind, &chars[index..end]);
+                index = end;
+                continue;
+            }
+            if ch == '#' && matches!(chars.get(index + 1), Some('[') | Some('!')) {
+                let end = chars[index..]
+                    .iter()
+                    .position(|value| *value == ']')
+                    .map_or(chars.len(), |offset| index + offset + 1);
+                push(&mut tokens, TokenKind::Meta, &chars[index..end]);
+                index = end;
+                continue;
+            }
+        }
+
+        // Shell variables and Python decorators.
+        if language == CodeLanguage::Shell && ch == '$' {
+            let end = shell_variable_end(&chars, index);
+            push(&mut tokens, TokenKind::Type, &chars[index..end]);
+            index = end;
+            continue;
+        }
+        if language == CodeLanguage::Python
+            && ch == '@'
+            && tokens.iter().all(|(_, text)| text.trim().is_empty())
+        {
+            let end = identifier_end(&chars, index + 1);
+            push(&mut tokens, TokenKind::Function, &chars[index..end]);
+            index = end;
+            continue;
+        }
+
+        if ch.is_ascii_digit() {
+            let end = index
+                + chars[index..]
+                    .iter()
+                    .take_while(|value| value.is_ascii_alphanumeric() || matches!(value, '_' | '.'))
+                    .count();
+            push(&mut tokens, TokenKind::Number, &chars[index..end]);
+            index = end;
+            continue;
+        }
+
+        if is_identifier_start(ch) {
+            let end = identifier_end(&chars, index);
+            let word: String = chars[index..end].iter().collect();
+            let mut end_with_suffix = end;
+            let kind = if spec.keywords.contains(&word.as_str()) {
+                TokenKind::Keyword
+            } else if spec.constants.contains(&word.as_str()) {
+                TokenKind::Number
+            } else if spec.types.contains(&word.as_str())
+                || (spec.capitalized_types && word.starts_with(|c: char| c.is_ascii_uppercase()))
+            {
+                TokenKind::Type
+            } else if language == CodeLanguage::Rust && chars.get(end) == Some(&'!') {
+                end_with_suffix = end + 1;
+                TokenKind::Function
+            } else if matches!(previous_word.as_str(), "fn" | "function" | "def")
+                || (language != CodeLanguage::Shell && next_non_space(&chars, end) == Some('('))
+            {
+                TokenKind::Function
+            } else {
+                TokenKind::Plain
+            };
+            push(&mut tokens, kind, &chars[index..end_with_suffix]);
+            previous_word = word;
+            index = end_with_suffix;
+            continue;
+        }
+
+        push(&mut tokens, TokenKind::Plain, &chars[index..index + 1]);
+        index += 1;
+    }
+
+    tokens
+}
+
+fn starts_with(chars: &[char], pattern: &str) -> bool {
+    pattern
+        .chars()
+        .enumerate()
+        .all(|(index, expected)| chars.get(index) == Some(&expected))
+}
+
+fn find(chars: &[char], from: usize, pattern: &str) -> Option<usize> {
+    (from..chars.len()).find(|&at| starts_with(&chars[at..], pattern))
+}
+
+fn find_unescaped(chars: &[char], from: usize, pattern: &str) -> Option<usize> {
+    let mut at = from;
+    while at < chars.len() {
+        if chars[at] == '\\' {
+            at += 2;
+            continue;
+        }
+        if starts_with(&chars[at..], pattern) {
+            return Some(at);
+        }
+        at += 1;
+    }
+    None
+}
+
+/// End (exclusive) of a single-line string opened at `start`.
+fn string_end(chars: &[char], start: usize, quote: char) -> usize {
+    let mut at = start + 1;
+    while at < chars.len() {
+        match chars[at] {
+            '\\' => at += 2,
+            value if value == quote => return at + 1,
+            _ => at += 1,
+        }
+    }
+    chars.len()
+}
+
+fn rust_quote_end(chars: &[char], start: usize) -> usize {
+    if chars.get(start + 1) == Some(&'\\') {
+        return chars[start + 2..]
+            .iter()
+            .position(|value| *value == '\'')
+            .map_or(chars.len(), |offset| start + 2 + offset + 1);
+    }
+    if chars.get(start + 2) == Some(&'\'') {
+        return start + 3;
+    }
+    identifier_end(chars, start + 1).max(start + 1)
+}
+
+fn shell_variable_end(chars: &[char], start: usize) -> usize {
+    match chars.get(start + 1) {
+        Some('{') => chars[start..]
+            .iter()
+            .position(|value| *value == '}')
+            .map_or(chars.len(), |offset| start + offset + 1),
+        Some(value) if is_identifier_start(*value) => identifier_end(chars, start + 1),
+        Some(value) if value.is_ascii_digit() || matches!(value, '?' | '@' | '#' | '*' | '$') => {
+            start + 2
+        }
+        _ => start + 1,
+    }
+}
+
+fn is_identifier_start(ch: char) -> bool {
+    ch.is_alphabetic() || ch == '_'
+}
+
+fn identifier_end(chars: &[char], start: usize) -> usize {
+    start
+        + chars[start.min(chars.len())..]
+            .iter()
+            .take_while(|value| value.is_alphanumeric() || **value == '_')
+            .count()
+}
+
+fn next_non_space(chars: &[char], from: usize) -> Option<char> {
+    chars[from.min(chars.len())..]
+        .iter()
+        .copied()
+        .find(|value| !value.is_whitespace())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CodeLanguage, HighlightState, TokenKind, highlight_line};
+
+    fn kinds_of(language: CodeLanguage, lines: &[&str]) -> Vec<Vec<(TokenKind, String)>> {
+        let mut state = HighlightState::default();
+        lines
+            .iter()
+            .map(|line| {
+                highlight_line(language, line, &mut state)
+                    .into_iter()
+                    .filter(|(kind, _)| *kind != TokenKind::Plain)
+                    .collect()
+            })
+            .collect()
+    }
+
+    fn has(tokens: &[(TokenKind, String)], kind: TokenKind, text: &str) -> bool {
+        tokens
+            .iter()
+            .any(|(token_kind, token)| *token_kind == kind && token == text)
+    }
+
+    #[test]
+    fn resolves_fence_aliases() {
+        assert_eq!(CodeLanguage::from_fence("rs"), Some(CodeLanguage::Rust));
+        assert_eq!(
+            CodeLanguage::from_fence("rust,ignore"),
+            Some(CodeLanguage::Rust)
+        );
+        assert_eq!(
+            CodeLanguage::from_fence("tsx"),
+            Some(CodeLanguage::TypeScript)
+        );
+        assert_eq!(CodeLanguage::from_fence("bash"), Some(CodeLanguage::Shell));
+        assert_eq!(CodeLanguage::from_fence("patch"), Some(CodeLanguage::Diff));
+        assert_eq!(CodeLanguage::from_fence("haskell"), None);
+    }
+
+    #[test]
+    fn rust_tokens_cover_keywords_strings_comments_and_macros() {
+        let lines = kinds_of(
+            CodeLanguage::Rust,
+            &[
+                "pub fn run<'a>(name: &'a str) -> Result<u32> { // entry",
+                "    println!(\"hi {name}\", ); /* start",
+                "    still comment */ let c = 'x';",
+            ],
+        );
+
+        assert!(has(&lines[0], TokenKind::Keyword, "pub"));
+        assert!(has(&lines[0], TokenKind::Function, "run"));
+        assert!(has(&lines[0], TokenKind::Type, "'a"));
+        assert!(has(&lines[0], TokenKind::Type, "Result"));
+        assert!(has(&lines[0], TokenKind::Comment, "// entry"));
+        assert!(has(&lines[1], TokenKind::Function, "println!"));
+        assert!(has(&lines[1], TokenKind::String, "\"hi {name}\""));
+        assert!(has(&lines[1], TokenKind::Comment, "/* start"));
+        assert!(has(&lines[2], TokenKind::Comment, "    still comment */"));
+        assert!(has(&lines[2], TokenKind::String, "'x'"));
+    }
+
+    #[test]
+    fn data_and_shell_languages_mark_keys_sections_and_variables() {
+        let json = kinds_of(
+            CodeLanguage::Json,
+            &[r#"{"name": "dark", "count": 3, "ok": true}"#],
+        );
+        assert!(has(&json[0], TokenKind::Key, "\"name\""));
+        assert!(has(&json[0], TokenKind::String, "\"dark\""));
+        assert!(has(&json[0], TokenKind::Number, "3"));
+        assert!(has(&json[0], TokenKind::Number, "true"));
+
+        let toml = kinds_of(
+            CodeLanguage::Toml,
+            &["[dark_tui.main]", "git = \"g s\" # sequence"],
+        );
+        assert!(has(&toml[0], TokenKind::Section, "[dark_tui.main]"));
+        assert!(has(&toml[1], TokenKind::Key, "git "));
+        assert!(has(&toml[1], TokenKind::String, "\"g s\""));
+        assert!(has(&toml[1], TokenKind::Comment, "# sequence"));
+
+        let shell = kinds_of(
+            CodeLanguage::Shell,
+            &["export DIR=${HOME}/x; echo \"$DIR\" # done"],
+        );
+        assert!(has(&shell[0], TokenKind::Keyword, "export"));
+        assert!(has(&shell[0], TokenKind::Type, "${HOME}"));
+        assert!(has(&shell[0], TokenKind::Comment, "# done"));
+
+        let diff = kinds_of(CodeLanguage::Diff, &["@@ -1 +1 @@", "-old", "+new"]);
+        assert!(has(&diff[0], TokenKind::Section, "@@ -1 +1 @@"));
+        assert!(has(&diff[1], TokenKind::Removed, "-old"));
+        assert!(has(&diff[2], TokenKind::Added, "+new"));
+
+        let python = kinds_of(
+            CodeLanguage::Python,
+            &["@cache", "def f():", "    \"\"\"doc", "    end\"\"\""],
+        );
+        assert!(has(&python[0], TokenKind::Function, "@cache"));
+        assert!(has(&python[1], TokenKind::Keyword, "def"));
+        assert!(has(&python[3], TokenKind::String, "    end\"\"\""));
+    }
+}
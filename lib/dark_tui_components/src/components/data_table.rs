@@ -0,0 +1,701 @@
+use std::cmp::Ordering;
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use ratatui::Frame;
+use ratatui::layout::{Rect, Size};
+use ratatui::style::{Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::Paragraph;
+
+use crate::action::Action;
+use crate::component::{Component, ComponentResult};
+use crate::theme::ComponentThemeLike;
+use crate::utils::index::{next_index, previous_index};
+use crate::utils::rect::rect_contains;
+use crate::utils::viewport::ListViewport;
+
+/// Widest a column can be resized to.
+const MAX_COLUMN_WIDTH: u16 = 240;
+
+/// Cells a column grows or shrinks per `Widen` / `Narrow`.
+const RESIZE_STEP: i16 = 2;
+
+/// How a column compares its cells when sorted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SortKind {
+    /// Case-insensitive text order.
+    Text,
+    /// Leading number of the cell (`12`, `3.5 MB`, `-4`); cells without one sort last.
+    Numeric,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SortDirection {
+    Ascending,
+    Descending,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ColumnAlign {
+    Left,
+    Right,
+}
+
+/// Column definition for a [`DataTable`].
+#[derive(Debug, Clone)]
+pub struct DataColumn {
+    pub id: &'static str,
+    pub title: String,
+    pub width: u16,
+    pub min_width: u16,
+    pub align: ColumnAlign,
+    pub sort: SortKind,
+    pub hidden: bool,
+    /// Takes the space left over by the other columns when it is the last one shown.
+    pub fill: bool,
+}
+
+impl DataColumn {
+    pub fn new(id: &'static str, title: impl Into<String>, width: u16) -> Self {
+        Self {
+            id,
+            title: title.into(),
+            width: width.max(1),
+            min_width: 3,
+            align: ColumnAlign::Left,
+            sort: SortKind::Text,
+            hidden: false,
+            fill: false,
+        }
+    }
+
+    /// Sorts numerically and right-aligns cells.
+    pub fn numeric(mut self) -> Self {
+        self.sort = SortKind::Numeric;
+        self.align = ColumnAlign::Right;
+        self
+    }
+
+    pub fn min_width(mut self, min_width: u16) -> Self {
+        self.min_width = min_width.max(1);
+        self.width = self.width.max(self.min_width);
+        self
+    }
+
+    pub fn hidden(mut self) -> Self {
+        self.hidden = true;
+        self
+    }
+
+    pub fn fill(mut self) -> Self {
+        self.fill = true;
+        self
+    }
+}
+
+/// Hit-test result for table interactions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DataTableHit {
+    Outside,
+    /// Header cell of the column at this index in the column list.
+    Header(usize),
+    /// Separator right of the column at this index; drag it to resize.
+    ColumnDivider(usize),
+    /// Data row at this index in the rows passed to [`DataTable::set_rows`].
+    Row(usize),
+    /// Inside the table but not on a row (e.g. below the last one).
+    Body,
+}
+
+/// Table actions a host can bind to keys of its choosing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DataTableCommand {
+    Down,
+    Up,
+    PageDown,
+    PageUp,
+    First,
+    Last,
+    /// Moves the column focus that `Sort`, `Widen` and `Narrow` act on.
+    NextColumn,
+    PreviousColumn,
+    /// Sorts by the focused column, or flips its direction.
+    Sort,
+    Widen,
+    Narrow,
+}
+
+impl DataTableCommand {
+    /// The command the [`Component`] impl runs for `key`.
+    pub fn from_default_key(key: KeyEvent) -> Option<Self> {
+        if key
+            .modifiers
+            .intersects(KeyModifiers::CONTROL | KeyModifiers::ALT)
+        {
+            return None;
+        }
+        Some(match key.code {
+            KeyCode::Down | KeyCode::Char('j') => Self::Down,
+            KeyCode::Up | KeyCode::Char('k') => Self::Up,
+            KeyCode::PageDown => Self::PageDown,
+            KeyCode::PageUp => Self::PageUp,
+            KeyCode::Home | KeyCode::Char('g') => Self::First,
+            KeyCode::End | KeyCode::Char('G') => Self::Last,
+            KeyCode::Right | KeyCode::Char('l') => Self::NextColumn,
+            KeyCode::Left | KeyCode::Char('h') => Self::PreviousColumn,
+            KeyCode::Char('s') => Self::Sort,
+            KeyCode::Char('+') => Self::Widen,
+            KeyCode::Char('-') => Self::Narrow,
+            _ => return None,
+        })
+    }
+}
+
+/// Sortable table with a sticky header that only renders the visible rows.
+///
+/// Rows are kept in insertion order; sorting permutes an index, so re-sorting
+/// thousands of rows never clones cells and the selection follows its row.
+#[derive(Debug, Clone)]
+pub struct DataTable {
+    columns: Vec<DataColumn>,
+    rows: Vec<Vec<String>>,
+    order: Vec<usize>,
+    sort: Option<(usize, SortDirection)>,
+    selected: usize,
+    focused_column: usize,
+    page_rows: usize,
+    focused: bool,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct ColumnSpan {
+    column: usize,
+    x: u16,
+    width: u16,
+}
+
+impl DataTable {
+    pub fn new(columns: Vec<DataColumn>) -> Self {
+        Self {
+            columns,
+            rows: Vec::new(),
+            order: Vec::new(),
+            sort: None,
+            selected: 0,
+            focused_column: 0,
+            page_rows: 10,
+            focused: false,
+        }
+    }
+
+    /// Replaces the rows, keeping the current sort and the selected row when
+    /// it is still present at the same index.
+    pub fn set_rows(&mut self, rows: Vec<Vec<String>>) {
+        let selected = self.selected_row();
+        self.rows = rows;
+        self.order = (0..self.rows.len()).collect();
+        self.apply_sort(selected);
+    }
+
+    pub fn columns(&self) -> &[DataColumn] {
+        &self.columns
+    }
+
+    pub fn len(&self) -> usize {
+        self.rows.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.rows.is_empty()
+    }
+
+    pub fn column_index(&self, id: &str) -> Option<usize> {
+        self.columns.iter().position(|column| column.id == id)
+    }
+
+    /// Current sort column and direction.
+    pub fn sort(&self) -> Option<(usize, SortDirection)> {
+        self.sort
+    }
+
+    pub fn sort_by(&mut self, column: usize, direction: SortDirection) {
+        if column >= self.columns.len() {
+            return;
+        }
+        let selected = self.selected_row();
+        self.sort = Some((column, direction));
+        self.apply_sort(selected);
+    }
+
+    /// Sorts by `column` ascending, or flips the direction if it is already
+    /// the sort column.
+    pub fn toggle_sort(&mut self, column: usize) {
+        let direction = match self.sort {
+            Some((current, SortDirection::Ascending)) if current == column => {
+                SortDirection::Descending
+            }
+            _ => SortDirection::Ascending,
+        };
+        self.sort_by(column, direction);
+    }
+
+    /// Back to insertion order.
+    pub fn clear_sort(&mut self) {
+        let selected = self.selected_row();
+        self.sort = None;
+        self.order = (0..self.rows.len()).collect();
+        self.apply_sort(selected);
+    }
+
+    /// Hides or shows a column. The last visible column cannot be hidden.
+    pub fn set_column_hidden(&mut self, column: usize, hidden: bool) -> bool {
+        let visible = self.columns.iter().filter(|column| !column.hidden).count();
+        let Some(target) = self.columns.get_mut(column) else {
+            return false;
+        };
+        if hidden && !target.hidden && visible <= 1 {
+            return false;
+        }
+        target.hidden = hidden;
+        true
+    }
+
+    pub fn toggle_column(&mut self, column: usize) -> bool {
+        let hidden = self
+            .columns
+            .get(column)
+            .is_some_and(|column| !column.hidden);
+        self.set_column_hidden(column, hidden)
+    }
+
+    /// Grows (positive) or shrinks (negative) a column within its limits.
+    pub fn resize_column(&mut self, column: usize, delta: i16) {
+        let Some(width) = self.columns.get(column).map(|column| column.width) else {
+            return;
+        };
+        self.resize_column_to(column, width.saturating_add_signed(delta));
+    }
+
+    pub fn resize_column_to(&mut self, column: usize, width: u16) {
+        if let Some(column) = self.columns.get_mut(column) {
+            column.width = width.clamp(column.min_width, MAX_COLUMN_WIDTH);
+        }
+    }
+
+    /// Resizes `column` so its divider lands on pointer column `col`.
+    pub fn resize_from_pointer(&mut self, area: Rect, column: usize, col: u16) -> bool {
+        let Some(span) = self
+            .spans(area)
+            .into_iter()
+            .find(|span| span.column == column)
+        else {
+            return false;
+        };
+        let before = self.columns[column].width;
+        self.resize_column_to(column, col.saturating_sub(span.x));
+        self.columns[column].width != before
+    }
+
+    /// Index of the selected row in the rows passed to [`Self::set_rows`].
+    pub fn selected_row(&self) -> Option<usize> {
+        self.order.get(self.selected).copied()
+    }
+
+    pub fn selected_cells(&self) -> Option<&[String]> {
+        self.selected_row()
+            .and_then(|row| self.rows.get(row))
+            .map(Vec::as_slice)
+    }
+
+    /// Selects a data row by its index in the rows passed to [`Self::set_rows`].
+    pub fn select_row(&mut self, row: usize) {
+        if let Some(position) = self.order.iter().position(|candidate| *candidate == row) {
+            self.selected = position;
+        }
+    }
+
+    pub fn select_next(&mut self) {
+        self.selected = next_index(self.selected, self.order.len());
+    }
+
+    pub fn select_previous(&mut self) {
+        self.selected = previous_index(self.selected, self.order.len());
+    }
+
+    pub fn select_first(&mut self) {
+        self.selected = 0;
+    }
+
+    pub fn select_last(&mut self) {
+        self.selected = self.order.len().saturating_sub(1);
+    }
+
+    /// Moves the selection by `rows` (e.g. a page), clamping at the ends.
+    pub fn select_by(&mut self, rows: isize) {
+        let last = self.order.len().saturating_sub(1);
+        self.selected = self.selected.saturating_add_signed(rows).min(last);
+    }
+
+    /// Rows that fit under the header in `area`, i.e. one page.
+    pub fn page_size(area: Rect) -> usize {
+        area.height.saturating_sub(1) as usize
+    }
+
+    /// Rows `PageDown` / `PageUp` move by; hosts set it from the area they draw into.
+    pub fn set_page_rows(&mut self, rows: usize) {
+        self.page_rows = rows.max(1);
+    }
+
+    /// Column that `Sort`, `Widen` and `Narrow` act on.
+    pub fn focused_column(&self) -> usize {
+        self.focused_column
+    }
+
+    /// Moves the column focus by `step` visible columns, wrapping around.
+    pub fn focus_column_by(&mut self, step: isize) {
+        let visible: Vec<usize> = self
+            .columns
+            .iter()
+            .enumerate()
+            .filter(|(_, column)| !column.hidden)
+            .map(|(index, _)| index)
+            .collect();
+        if visible.is_empty() {
+            return;
+        }
+        let slot = visible
+            .iter()
+            .position(|index| *index == self.focused_column)
+            .unwrap_or(0);
+        let slot = (slot as isize + step).rem_euclid(visible.len() as isize) as usize;
+        self.focused_column = visible[slot];
+    }
+
+    /// Runs a command the host resolved from its keymap. Selection moves
+    /// answer `Select` with the newly selected data row (nothing when it did
+    /// not move); column focus, sort and resize answer `Render`.
+    pub fn apply(&mut self, command: DataTableCommand) -> Option<Action> {
+        let before = self.selected_row();
+        match command {
+            DataTableCommand::Down => self.select_by(1),
+            DataTableCommand::Up => self.select_by(-1),
+            DataTableCommand::PageDown => self.select_by(self.page_rows as isize),
+            DataTableCommand::PageUp => self.select_by(-(self.page_rows as isize)),
+            DataTableCommand::First => self.select_first(),
+            DataTableCommand::Last => self.select_last(),
+            DataTableCommand::NextColumn => {
+                self.focus_column_by(1);
+                return Some(Action::Render);
+            }
+            DataTableCommand::PreviousColumn => {
+                self.focus_column_by(-1);
+                return Some(Action::Render);
+            }
+            DataTableCommand::Sort => {
+                self.toggle_sort(self.focused_column);
+                return Some(Action::Render);
+            }
+            DataTableCommand::Widen => {
+                self.resize_column(self.focused_column, RESIZE_STEP);
+                return Some(Action::Render);
+            }
+            DataTableCommand::Narrow => {
+                self.resize_column(self.focused_column, -RESIZE_STEP);
+                return Some(Action::Render);
+            }
+        }
+        let after = self.selected_row();
+        (after != before)
+            .then_some(after)
+            .flatten()
+            .map(Action::Select)
+    }
+
+    /// Draws the header and only the rows inside the viewport.
+    pub fn render(
+        &self,
+        frame: &mut Frame,
+        area: Rect,
+        theme: &(impl ComponentThemeLike + ?Sized),
+    ) {
+        if area.width == 0 || area.height == 0 {
+            return;
+        }
+
+        let spans = self.spans(area);
+        let muted = Style::default().fg(theme.text_muted());
+        let mut lines = Vec::with_capacity(area.height as usize);
+        lines.push(self.header_line(&spans, theme));
+
+        if self.order.is_empty() {
+            lines.push(Line::from(Span::styled("(no rows)", muted)));
+        } else {
+            let viewport = self.viewport(area);
+            let cell_style = Style::default().fg(theme.text_secondary());
+            let selected_style = Style::default()
+                .fg(theme.pill_accent_fg())
+                .bg(theme.pill_accent_bg())
+                .add_modifier(Modifier::BOLD);
+            for position in viewport.start..viewport.end {
+                let style = if position == self.selected {
+                    selected_style
+                } else {
+                    cell_style
+                };
+                let cells = &self.rows[self.order[position]];
+                lines.push(Self::row_line(&spans, cells, &self.columns, style));
+            }
+        }
+
+        frame.render_widget(Paragraph::new(lines), area);
+    }
+
+    /// Maps a terminal cell to the header, a column divider, or a data row.
+    pub fn hit_test(&self, area: Rect, col: u16, row: u16) -> DataTableHit {
+        if !rect_contains(area, col, row) {
+            return DataTableHit::Outside;
+        }
+
+        if row == area.y {
+            for span in self.spans(area) {
+                let end = span.x.saturating_add(span.width);
+                if col >= span.x && col < end {
+                    return DataTableHit::Header(span.column);
+                }
+                if col == end {
+                    return DataTableHit::ColumnDivider(span.column);
+                }
+            }
+            return DataTableHit::Body;
+        }
+
+        let viewport = self.viewport(area);
+        let position = viewport
+            .start
+            .saturating_add(row.saturating_sub(area.y.saturating_add(1)) as usize);
+        if position < viewport.end {
+            DataTableHit::Row(self.order[position])
+        } else {
+            DataTableHit::Body
+        }
+    }
+
+    fn viewport(&self, area: Rect) -> ListViewport {
+        ListViewport::new(self.order.len(), Self::page_size(area), self.selected)
+    }
+
+    fn spans(&self, area: Rect) -> Vec<ColumnSpan> {
+        let visible: Vec<usize> = self
+            .columns
+            .iter()
+            .enumerate()
+            .filter(|(_, column)| !column.hidden)
+            .map(|(index, _)| index)
+            .collect();
+
+        let right = area.x.saturating_add(area.width);
+        let mut spans = Vec::with_capacity(visible.len());
+        let mut x = area.x;
+        for (slot, index) in visible.iter().enumerate() {
+            if x >= right {
+                break;
+            }
+            let column = &self.columns[*index];
+            let remaining = right - x;
+            let width = if column.fill && slot == visible.len() - 1 {
+                remaining
+            } else {
+                column.width.min(remaining)
+            };
+            spans.push(ColumnSpan {
+                column: *index,
+                x,
+                width,
+            });
+            // One cell of separator between columns.
+            x = x.saturating_add(width).saturating_add(1);
+        }
+        spans
+    }
+
+    fn header_line(
+        &self,
+        spans: &[ColumnSpan],
+        theme: &(impl ComponentThemeLike + ?Sized),
+    ) -> Line<'static> {
+        let header = Style::default()
+            .fg(theme.text_secondary())
+            .add_modifier(Modifier::BOLD);
+        let sorted = Style::default()
+            .fg(theme.pill_accent_fg())
+            .add_modifier(Modifier::BOLD);
+        let divider = Style::default().fg(theme.text_muted());
+
+        let mut parts = Vec::with_capacity(spans.len() * 2);
+        for (slot, span) in spans.iter().enumerate() {
+            let column = &self.columns[span.column];
+            let (title, style) = match self.sort {
+                Some((index, direction)) if index == span.column => {
+                    let arrow = match direction {
+                        SortDirection::Ascending => "▲",
+                        SortDirection::Descending => "▼",
+                    };
+                    (format!("{} {arrow}", column.title), sorted)
+                }
+                _ => (column.title.clone(), header),
+            };
+            let style = if self.focused && span.column == self.focused_column {
+                style.add_modifier(Modifier::UNDERLINED)
+            } else {
+                style
+            };
+            parts.push(Span::styled(fit(&title, span.width, column.align), style));
+            if slot + 1 < spans.len() {
+                parts.push(Span::styled("│", divider));
+            }
+        }
+        Line::from(parts)
+    }
+
+    fn row_line(
+        spans: &[ColumnSpan],
+        cells: &[String],
+        columns: &[DataColumn],
+        style: Style,
+    ) -> Line<'static> {
+        let mut text = String::new();
+        for (slot, span) in spans.iter().enumerate() {
+            let cell = cells.get(span.column).map(String::as_str).unwrap_or("");
+            text.push_str(&fit(cell, span.width, columns[span.column].align));
+            if slot + 1 < spans.len() {
+                text.push(' ');
+            }
+        }
+        Line::from(Span::styled(text, style))
+    }
+
+    fn apply_sort(&mut self, selected: Option<usize>) {
+        if let Some((column, direction)) = self.sort {
+            let kind = self.columns[column].sort;
+            let rows = &self.rows;
+            let cell = |row: usize| rows[row].get(column).map(String::as_str).unwrap_or("");
+            self.order.sort_by(|left, right| {
+                let ordering = compare_cells(cell(*left), cell(*right), kind);
+                match direction {
+                    SortDirection::Ascending => ordering,
+                    SortDirection::Descending => ordering.reverse(),
+                }
+            });
+        }
+
+        self.selected = 0;
+        if let Some(row) = selected {
+            self.select_row(row);
+        }
+    }
+}
+
+/// Standalone use with the default keys; hosts with a keymap call
+/// [`DataTable::apply`] instead.
+impl Component for DataTable {
+    fn init(&mut self, area: Size) -> ComponentResult {
+        self.set_page_rows(area.height.saturating_sub(1) as usize);
+        Ok(())
+    }
+
+    fn handle_key_event(&mut self, key: KeyEvent) -> ComponentResult<Option<Action>> {
+        Ok(DataTableCommand::from_default_key(key).and_then(|command| self.apply(command)))
+    }
+
+    fn draw(
+        &self,
+        frame: &mut Frame,
+        area: Rect,
+        theme: &dyn ComponentThemeLike,
+    ) -> ComponentResult {
+        self.render(frame, area, theme);
+        Ok(())
+    }
+
+    fn wants_focus(&self) -> bool {
+        true
+    }
+
+    fn is_focused(&self) -> bool {
+        self.focused
+    }
+
+    fn set_focused(&mut self, focused: bool) {
+        self.focused = focused;
+    }
+}
+
+fn compare_cells(left: &str, right: &str, kind: SortKind) -> Ordering {
+    match kind {
+        SortKind::Text => left.to_lowercase().cmp(&right.to_lowercase()),
+        SortKind::Numeric => match (leading_number(left), leading_number(right)) {
+            (Some(left), Some(right)) => left.total_cmp(&right),
+            (Some(_), None) => Ordering::Less,
+            (None, Some(_)) => Ordering::Greater,
+            (None, None) => left.cmp(right),
+        },
+    }
+}
+
+fn leading_number(cell: &str) -> Option<f64> {
+    let trimmed = cell.trim_start();
+    let end = trimmed
+        .char_indices()
+        .find(|(index, ch)| !(ch.is_ascii_digit() || *ch == '.' || (*index == 0 && *ch == '-')))
+        .map(|(index, _)| index)
+        .unwrap_or(trimmed.len());
+    trimmed[..end].parse().ok()
+}
+
+/// Pads or truncates `text` to exactly `width` characters.
+fn fit(text: &str, width: u16, align: ColumnAlign) -> String {
+    let width = width as usize;
+    let length = text.chars().count();
+    if length > width {
+        if width == 0 {
+            return String::new();
+        }
+        let mut clipped: String = text.chars().take(width - 1).collect();
+        clipped.push('…');
+        return clipped;
+    }
+
+    let padding = " ".repeat(width - length);
+    match align {
+        ColumnAlign::Left => format!("{text}{padding}"),
+        ColumnAlign::Right => format!("{padding}{text}"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fit_pads_truncates_and_aligns() {
+        assert_eq!(fit("abc", 5, ColumnAlign::Left), "abc  ");
+        assert_eq!(fit("42", 4, ColumnAlign::Right), "  42");
+        assert_eq!(fit("variant-long", 6, ColumnAlign::Left), "varia…");
+    }
+
+    #[test]
+    fn numeric_sort_reads_leading_numbers() {
+        assert_eq!(leading_number("3.5 MB"), Some(3.5));
+        assert_eq!(leading_number("-4"), Some(-4.0));
+        assert_eq!(leading_number("n/a"), None);
+        assert_eq!(
+            compare_cells("10", "9", SortKind::Numeric),
+            Ordering::Greater
+        );
+        assert_eq!(compare_cells("10", "9", SortKind::Text), Ordering::Less);
+        assert_eq!(
+            compare_cells("n/a", "1", SortKind::Numeric),
+            Ordering::Greater
+        );
+    }
+}
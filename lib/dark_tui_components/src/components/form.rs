@@ -0,0 +1,977 @@
+//! Declarative forms: fields described as data, one key handler, inline errors.
+//!
+//! A [`Form`] owns its field values. Hosts build it once, feed it key events
+//! through [`Form::handle_key`] (or resolve them through a keymap and pass
+//! the [`FormCommand`] to [`Form::handle_command`]), and read values back on
+//! [`FormOutcome::Submit`]. Select options can arrive later through
+//! [`Form::set_options`]; until then the field shows a loading state and
+//! blocks submission.
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use ratatui::Frame;
+use ratatui::layout::Rect;
+use ratatui::style::{Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Clear, Paragraph, Wrap};
+
+use crate::action::Action;
+use crate::component::{Component, ComponentResult};
+use crate::components::loading_spinner::LoadingSpinner;
+use crate::components::pane_block_component::PaneBlockComponent;
+use crate::theme::ComponentThemeLike;
+use crate::utils::index::{next_index, previous_index};
+
+/// Options listed at once by a focused select field.
+const MAX_VISIBLE_OPTIONS: usize = 8;
+
+/// Checks a field value; `Err` carries the message shown under the field.
+pub type Validator = Box<dyn Fn(&FieldValue) -> Result<(), String> + Send + Sync>;
+
+/// Current value of a field, as seen by validators and hosts.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FieldValue {
+    Text(String),
+    /// Value of the selected option, if there is one.
+    Choice(Option<String>),
+    Flag(bool),
+}
+
+impl FieldValue {
+    /// Whether the value is blank text, no choice, or an unset flag.
+    pub fn is_empty(&self) -> bool {
+        match self {
+            Self::Text(text) => text.trim().is_empty(),
+            Self::Choice(choice) => choice.is_none(),
+            Self::Flag(flag) => !flag,
+        }
+    }
+}
+
+/// One entry of a select field.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SelectOption {
+    pub value: String,
+    pub label: String,
+}
+
+impl SelectOption {
+    pub fn new(value: impl Into<String>, label: impl Into<String>) -> Self {
+        Self {
+            value: value.into(),
+            label: label.into(),
+        }
+    }
+}
+
+impl From<String> for SelectOption {
+    fn from(value: String) -> Self {
+        Self {
+            label: value.clone(),
+            value,
+        }
+    }
+}
+
+impl From<&str> for SelectOption {
+    fn from(value: &str) -> Self {
+        Self::from(value.to_string())
+    }
+}
+
+enum FieldInput {
+    Text {
+        value: String,
+        multiline: bool,
+    },
+    Select {
+        options: Vec<SelectOption>,
+        selected: usize,
+        loading: bool,
+    },
+    Toggle {
+        checked: bool,
+    },
+}
+
+/// A form field description. Build with [`FormField::text`],
+/// [`FormField::multiline`], [`FormField::select`] or [`FormField::toggle`].
+pub struct FormField {
+    key: &'static str,
+    label: String,
+    hint: Option<String>,
+    input: FieldInput,
+    validators: Vec<Validator>,
+    error: Option<String>,
+}
+
+impl FormField {
+    fn new(key: &'static str, label: impl Into<String>, input: FieldInput) -> Self {
+        Self {
+            key,
+            label: label.into(),
+            hint: None,
+            input,
+            validators: Vec::new(),
+            error: None,
+        }
+    }
+
+    /// Single-line text input.
+    pub fn text(key: &'static str, label: impl Into<String>) -> Self {
+        Self::new(
+            key,
+            label,
+            FieldInput::Text {
+                value: String::new(),
+                multiline: false,
+            },
+        )
+    }
+
+    /// Text input where `Shift+Enter` / `Alt+Enter` insert a newline.
+    pub fn multiline(key: &'static str, label: impl Into<String>) -> Self {
+        Self::new(
+            key,
+            label,
+            FieldInput::Text {
+                value: String::new(),
+                multiline: true,
+            },
+        )
+    }
+
+    /// Picker over a list of options.
+    pub fn select(key: &'static str, label: impl Into<String>) -> Self {
+        Self::new(
+            key,
+            label,
+            FieldInput::Select {
+                options: Vec::new(),
+                selected: 0,
+                loading: false,
+            },
+        )
+    }
+
+    /// On/off switch flipped with `Space`.
+    pub fn toggle(key: &'static str, label: impl Into<String>) -> Self {
+        Self::new(key, label, FieldInput::Toggle { checked: false })
+    }
+
+    /// Initial text of a text field.
+    pub fn value(mut self, value: impl Into<String>) -> Self {
+        if let FieldInput::Text { value: current, .. } = &mut self.input {
+            *current = value.into();
+        }
+        self
+    }
+
+    /// Options of a select field.
+    pub fn options<T: Into<SelectOption>>(mut self, options: impl IntoIterator<Item = T>) -> Self {
+        if let FieldInput::Select {
+            options: current,
+            selected,
+            ..
+        } = &mut self.input
+        {
+            *current = options.into_iter().map(Into::into).collect();
+            *selected = 0;
+        }
+        self
+    }
+
+    /// Preselects the option with this value, if present.
+    pub fn selected(mut self, value: &str) -> Self {
+        select_by_value(&mut self.input, value);
+        self
+    }
+
+    /// Marks a select field as waiting for [`Form::set_options`].
+    pub fn loading(mut self) -> Self {
+        if let FieldInput::Select { loading, .. } = &mut self.input {
+            *loading = true;
+        }
+        self
+    }
+
+    /// Initial state of a toggle field.
+    pub fn checked(mut self, checked: bool) -> Self {
+        if let FieldInput::Toggle { checked: current } = &mut self.input {
+            *current = checked;
+        }
+        self
+    }
+
+    /// Muted help line shown under the field.
+    pub fn hint(mut self, hint: impl Into<String>) -> Self {
+        self.hint = Some(hint.into());
+        self
+    }
+
+    /// Adds a validator; validators run in order and the first error wins.
+    pub fn validate(
+        mut self,
+        validator: impl Fn(&FieldValue) -> Result<(), String> + Send + Sync + 'static,
+    ) -> Self {
+        self.validators.push(Box::new(validator));
+        self
+    }
+
+    /// Rejects blank text and empty selects.
+    pub fn required(self) -> Self {
+        let message = format!("{} is required.", self.label);
+        self.validate(move |value| {
+            if value.is_empty() {
+                Err(message.clone())
+            } else {
+                Ok(())
+            }
+        })
+    }
+
+    fn current(&self) -> FieldValue {
+        match &self.input {
+            FieldInput::Text { value, .. } => FieldValue::Text(value.clone()),
+            FieldInput::Select {
+                options, selected, ..
+            } => FieldValue::Choice(options.get(*selected).map(|option| option.value.clone())),
+            FieldInput::Toggle { checked } => FieldValue::Flag(*checked),
+        }
+    }
+
+    fn check(&mut self) -> bool {
+        if matches!(self.input, FieldInput::Select { loading: true, .. }) {
+            self.error = Some("Options are still loading.".to_string());
+            return false;
+        }
+
+        let value = self.current();
+        self.error = self
+            .validators
+            .iter()
+            .find_map(|validator| validator(&value).err());
+        self.error.is_none()
+    }
+}
+
+impl std::fmt::Debug for FormField {
+    fn fmt(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        formatter
+            .debug_struct("FormField")
+            .field("key", &self.key)
+            .field("value", &self.current())
+            .field("error", &self.error)
+            .finish()
+    }
+}
+
+/// What a key press did to the form.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FormOutcome {
+    /// The key is not bound in the form.
+    Ignored,
+    /// Focus, a value, or an error changed; redraw.
+    Changed,
+    /// Every field validated; read the values and close the form.
+    Submit,
+    Cancel,
+}
+
+/// Form-level actions a host can bind to keys of its choosing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FormCommand {
+    Submit,
+    Cancel,
+    NextField,
+    PreviousField,
+    /// Flips the focused toggle field.
+    Toggle,
+}
+
+impl FormCommand {
+    /// The key [`Form::handle_key`] uses, as shown in the footer.
+    pub fn default_key(self) -> &'static str {
+        match self {
+            Self::Submit => "Enter",
+            Self::Cancel => "Esc",
+            Self::NextField => "Tab",
+            Self::PreviousField => "S-Tab",
+            Self::Toggle => "Space",
+        }
+    }
+}
+
+/// A titled set of fields with tab order and inline validation.
+#[derive(Debug)]
+pub struct Form {
+    title: String,
+    notes: Vec<String>,
+    fields: Vec<FormField>,
+    focus: usize,
+    submit_label: String,
+    command_keys: Vec<(FormCommand, Option<String>)>,
+    focused: bool,
+}
+
+impl Form {
+    pub fn new(title: impl Into<String>) -> Self {
+        Self {
+            title: title.into(),
+            notes: Vec::new(),
+            fields: Vec::new(),
+            focus: 0,
+            submit_label: "submit".to_string(),
+            command_keys: Vec::new(),
+            focused: true,
+        }
+    }
+
+    /// Read-only context line shown above the fields.
+    pub fn note(mut self, line: impl Into<String>) -> Self {
+        self.notes.push(line.into());
+        self
+    }
+
+    /// Appends a field; tab order follows insertion order.
+    pub fn field(mut self, field: FormField) -> Self {
+        self.fields.push(field);
+        self
+    }
+
+    /// Verb shown next to `Enter` in the footer.
+    pub fn submit_label(mut self, label: impl Into<String>) -> Self {
+        self.submit_label = label.into();
+        self
+    }
+
+    /// Key shown in the footer for `command` when the host rebinds it;
+    /// `None` drops the hint for an unbound command.
+    pub fn command_key(mut self, command: FormCommand, key: Option<String>) -> Self {
+        self.command_keys.retain(|(bound, _)| *bound != command);
+        self.command_keys.push((command, key));
+        self
+    }
+
+    /// Starts with focus on the given field.
+    pub fn focus_on(mut self, key: &str) -> Self {
+        self.focus_field(key);
+        self
+    }
+
+    pub fn title(&self) -> &str {
+        &self.title
+    }
+
+    pub fn focus_field(&mut self, key: &str) {
+        if let Some(index) = self.index_of(key) {
+            self.focus = index;
+        }
+    }
+
+    pub fn focused_key(&self) -> Option<&'static str> {
+        self.fields.get(self.focus).map(|field| field.key)
+    }
+
+    pub fn value(&self, key: &str) -> Option<FieldValue> {
+        self.get(key).map(FormField::current)
+    }
+
+    /// Text of a text field; empty for unknown keys.
+    pub fn text(&self, key: &str) -> &str {
+        match self.get(key).map(|field| &field.input) {
+            Some(FieldInput::Text { value, .. }) => value,
+            _ => "",
+        }
+    }
+
+    /// Value of the selected option of a select field.
+    pub fn choice(&self, key: &str) -> Option<&str> {
+        match self.get(key).map(|field| &field.input) {
+            Some(FieldInput::Select {
+                options, selected, ..
+            }) => options.get(*selected).map(|option| option.value.as_str()),
+            _ => None,
+        }
+    }
+
+    /// State of a toggle field; `false` for unknown keys.
+    pub fn flag(&self, key: &str) -> bool {
+        matches!(
+            self.get(key).map(|field| &field.input),
+            Some(FieldInput::Toggle { checked: true })
+        )
+    }
+
+    /// Options of a select field.
+    pub fn options(&self, key: &str) -> &[SelectOption] {
+        match self.get(key).map(|field| &field.input) {
+            Some(FieldInput::Select { options, .. }) => options,
+            _ => &[],
+        }
+    }
+
+    pub fn set_text(&mut self, key: &str, text: impl Into<String>) {
+        if let Some(FieldInput::Text { value, .. }) =
+            self.get_mut(key).map(|field| &mut field.input)
+        {
+            *value = text.into();
+        }
+    }
+
+    /// Selects the option with this value; returns whether it exists.
+    pub fn select_value(&mut self, key: &str, value: &str) -> bool {
+        self.get_mut(key)
+            .is_some_and(|field| select_by_value(&mut field.input, value))
+    }
+
+    /// Fills a select field once its options have loaded. Keeps the current
+    /// choice when it is still offered, else falls back to `default`.
+    pub fn set_options<T: Into<SelectOption>>(
+        &mut self,
+        key: &str,
+        options: impl IntoIterator<Item = T>,
+        default: Option<&str>,
+    ) {
+        let Some(field) = self.get_mut(key) else {
+            return;
+        };
+        let FieldInput::Select {
+            options: current,
+            selected,
+            loading,
+        } = &mut field.input
+        else {
+            return;
+        };
+
+        let previous = current.get(*selected).map(|option| option.value.clone());
+        *current = options.into_iter().map(Into::into).collect();
+        *loading = false;
+        *selected = previous
+            .as_deref()
+            .or(default)
+            .and_then(|value| current.iter().position(|option| option.value == value))
+            .unwrap_or(0);
+
+        if field.error.is_some() {
+            field.check();
+        }
+    }
+
+    /// Shows an error under a field, e.g. one reported by the server.
+    pub fn set_error(&mut self, key: &str, message: impl Into<String>) {
+        if let Some(field) = self.get_mut(key) {
+            field.error = Some(message.into());
+        }
+    }
+
+    pub fn error(&self, key: &str) -> Option<&str> {
+        self.get(key).and_then(|field| field.error.as_deref())
+    }
+
+    /// Whether any select field is still waiting for its options.
+    pub fn is_loading(&self) -> bool {
+        self.fields
+            .iter()
+            .any(|field| matches!(field.input, FieldInput::Select { loading: true, .. }))
+    }
+
+    /// Runs every validator; focus moves to the first invalid field.
+    pub fn validate(&mut self) -> bool {
+        let mut first_invalid = None;
+        for (index, field) in self.fields.iter_mut().enumerate() {
+            if !field.check() && first_invalid.is_none() {
+                first_invalid = Some(index);
+            }
+        }
+
+        match first_invalid {
+            Some(index) => {
+                self.focus = index;
+                false
+            }
+            None => true,
+        }
+    }
+
+    /// Applies a key press with the default keys: `Tab`/`Shift+Tab` move
+    /// between fields, `Space` flips toggles, `Enter` validates and submits,
+    /// `Esc` cancels. Everything else goes to [`Form::handle_input`].
+    pub fn handle_key(&mut self, key: KeyEvent) -> FormOutcome {
+        let command = match key.code {
+            KeyCode::Esc => Some(FormCommand::Cancel),
+            KeyCode::Enter if !self.inserts_newline(key) => Some(FormCommand::Submit),
+            KeyCode::Tab => Some(FormCommand::NextField),
+            KeyCode::BackTab => Some(FormCommand::PreviousField),
+            KeyCode::Char(' ') => Some(FormCommand::Toggle),
+            _ => None,
+        };
+        self.handle_command(command, key)
+    }
+
+    /// Runs the command a host resolved for `key` from its keymap; a key
+    /// without a command, or whose command does not apply here, goes to
+    /// [`Form::handle_input`].
+    pub fn handle_command(&mut self, command: Option<FormCommand>, key: KeyEvent) -> FormOutcome {
+        match command.map(|command| self.apply(command)) {
+            Some(outcome) if outcome != FormOutcome::Ignored => outcome,
+            _ => self.handle_input(key),
+        }
+    }
+
+    /// Runs a command the host resolved from its keymap. `Ignored` means the
+    /// command does not apply here (e.g. `Toggle` on a text field); hosts then
+    /// pass the key on to [`Form::handle_input`].
+    pub fn apply(&mut self, command: FormCommand) -> FormOutcome {
+        let count = self.fields.len();
+        match command {
+            FormCommand::Cancel => FormOutcome::Cancel,
+            FormCommand::Submit if count == 0 || self.validate() => FormOutcome::Submit,
+            FormCommand::Submit => FormOutcome::Changed,
+            FormCommand::NextField if count > 0 => {
+                self.focus = next_index(self.focus, count);
+                FormOutcome::Changed
+            }
+            FormCommand::PreviousField if count > 0 => {
+                self.focus = previous_index(self.focus, count);
+                FormOutcome::Changed
+            }
+            FormCommand::Toggle => {
+                let Some(field) = self.fields.get_mut(self.focus) else {
+                    return FormOutcome::Ignored;
+                };
+                let FieldInput::Toggle { checked } = &mut field.input else {
+                    return FormOutcome::Ignored;
+                };
+                *checked = !*checked;
+                if field.error.is_some() {
+                    field.check();
+                }
+                FormOutcome::Changed
+            }
+            FormCommand::NextField | FormCommand::PreviousField => FormOutcome::Ignored,
+        }
+    }
+
+    /// Applies an editing key: arrows pick select options (and move between
+    /// other fields), letters type or jump to a matching option, and
+    /// `Shift+Enter` / `Alt+Enter` add a line to a multiline field.
+    pub fn handle_input(&mut self, key: KeyEvent) -> FormOutcome {
+        if self.fields.is_empty() {
+            return FormOutcome::Ignored;
+        }
+
+        let newline = self.inserts_newline(key);
+        let typing = !key
+            .modifiers
+            .intersects(KeyModifiers::CONTROL | KeyModifiers::ALT);
+        let count = self.fields.len();
+        let field = &mut self.fields[self.focus];
+
+        let edited = match (&mut field.input, key.code) {
+            (FieldInput::Text { value, .. }, KeyCode::Enter) if newline => {
+                value.push('\n');
+                true
+            }
+            (
+                FieldInput::Select {
+                    options, selected, ..
+                },
+                KeyCode::Up | KeyCode::Left,
+            ) => {
+                *selected = previous_index(*selected, options.len());
+                true
+            }
+            (
+                FieldInput::Select {
+                    options, selected, ..
+                },
+                KeyCode::Down | KeyCode::Right,
+            ) => {
+                *selected = next_index(*selected, options.len());
+                true
+            }
+            (
+                FieldInput::Select {
+                    options, selected, ..
+                },
+                KeyCode::Char(ch),
+            ) if typing => {
+                // Type-ahead: jump to the next option starting with `ch`.
+                let len = options.len();
+                let found = (1..=len)
+                    .map(|offset| (*selected + offset) % len.max(1))
+                    .find(|index| {
+                        options[*index]
+                            .label
+                            .chars()
+                            .next()
+                            .is_some_and(|first| first.eq_ignore_ascii_case(&ch))
+                    });
+                match found {
+                    Some(index) => {
+                        *selected = index;
+                        true
+                    }
+                    None => return FormOutcome::Ignored,
+                }
+            }
+            (_, KeyCode::Up) => {
+                self.focus = previous_index(self.focus, count);
+                return FormOutcome::Changed;
+            }
+            (_, KeyCode::Down) => {
+                self.focus = next_index(self.focus, count);
+                return FormOutcome::Changed;
+            }
+            (FieldInput::Text { value, .. }, KeyCode::Backspace) => value.pop().is_some(),
+            (FieldInput::Text { value, .. }, KeyCode::Char(ch)) if typing => {
+                value.push(ch);
+                true
+            }
+            _ => return FormOutcome::Ignored,
+        };
+
+        // Re-check a field that already shows an error so it clears as it is fixed.
+        if edited && field.error.is_some() {
+            field.check();
+        }
+        FormOutcome::Changed
+    }
+
+    /// `Shift+Enter` or `Alt+Enter` on a focused multiline field.
+    fn inserts_newline(&self, key: KeyEvent) -> bool {
+        key.code == KeyCode::Enter
+            && key
+                .modifiers
+                .intersects(KeyModifiers::SHIFT | KeyModifiers::ALT)
+            && matches!(
+                self.fields.get(self.focus).map(|field| &field.input),
+                Some(FieldInput::Text {
+                    multiline: true,
+                    ..
+                })
+            )
+    }
+
+    /// Draws the form as a popup filling `area`.
+    pub fn render(
+        &self,
+        frame: &mut Frame,
+        area: Rect,
+        theme: &(impl ComponentThemeLike + ?Sized),
+    ) {
+        frame.render_widget(Clear, area);
+        let block = PaneBlockComponent::build(&self.title, self.focused, theme);
+        let inner = block.inner(area);
+        frame.render_widget(block, area);
+
+        if inner.width == 0 || inner.height == 0 {
+            return;
+        }
+
+        let (lines, focus_line) = self.lines(theme);
+        // Keep the focused field on screen when the form is taller than the popup.
+        let overflow = (lines.len() as u16).saturating_sub(inner.height);
+        let scroll = (focus_line as u16).saturating_sub(1).min(overflow);
+
+        frame.render_widget(
+            Paragraph::new(lines)
+                .wrap(Wrap { trim: false })
+                .scroll((scroll, 0)),
+            inner,
+        );
+    }
+
+    /// Form content and the line index where the focused field starts.
+    pub fn lines(&self, theme: &(impl ComponentThemeLike + ?Sized)) -> (Vec<Line<'static>>, usize) {
+        let muted = Style::default().fg(theme.text_muted());
+        let value_style = Style::default().fg(theme.text_secondary());
+        let accent = Style::default().fg(theme.pill_accent_fg());
+
+        let mut lines: Vec<Line<'static>> = self
+            .notes
+            .iter()
+            .map(|note| Line::from(Span::styled(note.clone(), value_style)))
+            .collect();
+        if !lines.is_empty() {
+            lines.push(Line::raw(""));
+        }
+
+        let mut focus_line = 0;
+        for (index, field) in self.fields.iter().enumerate() {
+            let focused = index == self.focus;
+            if focused {
+                focus_line = lines.len();
+            }
+
+            let marker = if focused { "› " } else { "  " };
+            let label_style = if focused {
+                accent.add_modifier(Modifier::BOLD)
+            } else {
+                muted
+            };
+
+            match &field.input {
+                FieldInput::Toggle { checked } => {
+                    let mark = if *checked { "[x]" } else { "[ ]" };
+                    lines.push(Line::from(vec![
+                        Span::styled(marker, label_style),
+                        Span::styled(format!("{mark} "), value_style),
+                        Span::styled(field.label.clone(), label_style),
+                    ]));
+                }
+                FieldInput::Text { value, multiline } => {
+                    lines.push(Line::from(Span::styled(
+                        format!("{marker}{}", field.label),
+                        label_style,
+                    )));
+                    let cursor = if focused { "_" } else { "" };
+                    let mut rows: Vec<&str> = if *multiline {
+                        value.split('\n').collect()
+                    } else {
+                        vec![value.as_str()]
+                    };
+                    if rows.is_empty() {
+                        rows.push("");
+                    }
+                    let last = rows.len() - 1;
+                    for (row, text) in rows.into_iter().enumerate() {
+                        let prefix = if row == 0 { "  > " } else { "    " };
+                        let tail = if row == last { cursor } else { "" };
+                        lines.push(Line::from(Span::styled(
+                            format!("{prefix}{text}{tail}"),
+                            value_style,
+                        )));
+                    }
+                }
+                FieldInput::Select {
+                    options,
+                    selected,
+                    loading,
+                } => {
+                    lines.push(Line::from(Span::styled(
+                        format!("{marker}{}", field.label),
+                        label_style,
+                    )));
+                    if *loading {
+                        lines.push(Line::from(Span::styled(
+                            format!("  {} loading options...", LoadingSpinner::glyph()),
+                            Style::default().fg(theme.pill_warn_fg()),
+                        )));
+                    } else if options.is_empty() {
+                        lines.push(Line::from(Span::styled("  (no options)", muted)));
+                    } else if focused {
+                        let start = selected
+                            .saturating_sub(MAX_VISIBLE_OPTIONS / 2)
+                            .min(options.len().saturating_sub(MAX_VISIBLE_OPTIONS));
+                        for (offset, option) in options
+                            .iter()
+                            .enumerate()
+                            .skip(start)
+                            .take(MAX_VISIBLE_OPTIONS)
+                        {
+                            let (pointer, style) = if offset == *selected {
+                                (">", accent)
+                            } else {
+                                (" ", value_style)
+                            };
+                            lines.push(Line::from(Span::styled(
+                                format!("  {pointer} {}", option.label),
+                                style,
+                            )));
+                        }
+                    } else if let Some(option) = options.get(*selected) {
+                        lines.push(Line::from(Span::styled(
+                            format!("  > {}", option.label),
+                            value_style,
+                        )));
+                    }
+                }
+            }
+
+            if let Some(hint) = &field.hint {
+                lines.push(Line::from(Span::styled(format!("    {hint}"), muted)));
+            }
+            if let Some(error) = &field.error {
+                lines.push(Line::from(Span::styled(
+                    format!("  ! {error}"),
+                    Style::default().fg(theme.pill_err_fg()),
+                )));
+            }
+            lines.push(Line::raw(""));
+        }
+
+        lines.push(Line::from(Span::styled(self.footer(), muted)));
+        (lines, focus_line)
+    }
+
+    fn footer(&self) -> String {
+        let mut parts = Vec::new();
+        let several = self.fields.len() > 1;
+        if let Some(key) = self.key_for(FormCommand::NextField).filter(|_| several) {
+            parts.push(format!("{key}: next field"));
+        }
+        match self.fields.get(self.focus).map(|field| &field.input) {
+            Some(FieldInput::Select { .. }) => parts.push("arrows: choose".to_string()),
+            Some(FieldInput::Toggle { .. }) => {
+                if let Some(key) = self.key_for(FormCommand::Toggle) {
+                    parts.push(format!("{key}: toggle"));
+                }
+            }
+            Some(FieldInput::Text {
+                multiline: true, ..
+            }) => parts.push("Shift+Enter: newline".to_string()),
+            _ => {}
+        }
+        if let Some(key) = self.key_for(FormCommand::Submit) {
+            parts.push(format!("{key}: {}", self.submit_label));
+        }
+        if let Some(key) = self.key_for(FormCommand::Cancel) {
+            parts.push(format!("{key}: cancel"));
+        }
+        parts.join("   ")
+    }
+
+    fn key_for(&self, command: FormCommand) -> Option<&str> {
+        match self
+            .command_keys
+            .iter()
+            .find(|(bound, _)| *bound == command)
+        {
+            Some((_, key)) => key.as_deref(),
+            None => Some(command.default_key()),
+        }
+    }
+
+    fn get(&self, key: &str) -> Option<&FormField> {
+        self.fields.iter().find(|field| field.key == key)
+    }
+
+    fn get_mut(&mut self, key: &str) -> Option<&mut FormField> {
+        self.fields.iter_mut().find(|field| field.key == key)
+    }
+
+    fn index_of(&self, key: &str) -> Option<usize> {
+        self.fields.iter().position(|field| field.key == key)
+    }
+}
+
+impl Component for Form {
+    fn handle_key_event(&mut self, key: KeyEvent) -> ComponentResult<Option<Action>> {
+        Ok(match self.handle_key(key) {
+            FormOutcome::Ignored => None,
+            FormOutcome::Changed => Some(Action::Render),
+            FormOutcome::Submit => Some(Action::Confirm),
+            FormOutcome::Cancel => Some(Action::Cancel),
+        })
+    }
+
+    fn draw(
+        &self,
+        frame: &mut Frame,
+        area: Rect,
+        theme: &dyn ComponentThemeLike,
+    ) -> ComponentResult {
+        self.render(frame, area, theme);
+        Ok(())
+    }
+
+    fn wants_focus(&self) -> bool {
+        true
+    }
+
+    fn is_focused(&self) -> bool {
+        self.focused
+    }
+
+    fn set_focused(&mut self, focused: bool) {
+        self.focused = focused;
+    }
+}
+
+fn select_by_value(input: &mut FieldInput, value: &str) -> bool {
+    let FieldInput::Select {
+        options, selected, ..
+    } = input
+    else {
+        return false;
+    };
+    match options.iter().position(|option| option.value == value) {
+        Some(index) => {
+            *selected = index;
+            true
+        }
+        None => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::theme::ComponentTheme;
+
+    fn press(form: &mut Form, code: KeyCode) -> FormOutcome {
+        form.handle_key(KeyEvent::new(code, KeyModifiers::NONE))
+    }
+
+    fn type_text(form: &mut Form, text: &str) {
+        for ch in text.chars() {
+            press(form, KeyCode::Char(ch));
+        }
+    }
+
+    #[test]
+    fn footer_follows_the_focused_field_kind() {
+        let form = Form::new("Spawn")
+            .field(FormField::select("provider", "Provider").options(["mock"]))
+            .field(FormField::multiline("prompt", "Prompt"))
+            .submit_label("spawn")
+            .focus_on("prompt");
+
+        let (lines, focus_line) = form.lines(&ComponentTheme::default());
+        let text: Vec<String> = lines.iter().map(ToString::to_string).collect();
+        assert_eq!(text[focus_line], "› Prompt");
+        assert_eq!(
+            text.last().map(String::as_str),
+            Some("Tab: next field   Shift+Enter: newline   Enter: spawn   Esc: cancel")
+        );
+    }
+
+    #[test]
+    fn commands_drive_the_form_and_rebound_keys_show_in_the_footer() {
+        let mut form = Form::new("Delete")
+            .field(FormField::text("name", "Name"))
+            .field(FormField::toggle("remove", "Remove clone"))
+            .submit_label("delete")
+            .command_key(FormCommand::Submit, Some("C-s".to_string()))
+            .command_key(FormCommand::NextField, None);
+
+        assert_eq!(form.apply(FormCommand::Toggle), FormOutcome::Ignored);
+        let space = KeyEvent::new(KeyCode::Char(' '), KeyModifiers::NONE);
+        assert_eq!(
+            form.handle_command(Some(FormCommand::Toggle), space),
+            FormOutcome::Changed
+        );
+        assert_eq!(form.text("name"), " ");
+
+        assert_eq!(form.apply(FormCommand::NextField), FormOutcome::Changed);
+        assert_eq!(form.apply(FormCommand::Toggle), FormOutcome::Changed);
+        assert!(form.flag("remove"));
+        assert_eq!(form.footer(), "Space: toggle   C-s: delete   Esc: cancel");
+        assert_eq!(form.apply(FormCommand::Submit), FormOutcome::Submit);
+    }
+
+    #[test]
+    fn type_ahead_picks_matching_option() {
+        let mut form = Form::new("Pick").field(
+            FormField::select("provider", "Provider").options(["codex", "mock", "opencode"]),
+        );
+
+        assert_eq!(press(&mut form, KeyCode::Char('o')), FormOutcome::Changed);
+        assert_eq!(form.choice("provider"), Some("opencode"));
+        assert_eq!(press(&mut form, KeyCode::Char('z')), FormOutcome::Ignored);
+        type_text(&mut form, "m");
+        assert_eq!(form.choice("provider"), Some("mock"));
+    }
+}
@@ -1,18 +1,32 @@
+use std::borrow::Cow;
+
 use ratatui::style::{Modifier, Style};
 use ratatui::text::{Line, Span};
 
 use crate::theme::ComponentThemeLike;
 
 /// Key/action pair displayed in a key hint bar.
+#[derive(Debug, Clone)]
 pub struct KeyBind {
-    pub key: &'static str,
+    pub key: Cow<'static, str>,
     pub action: &'static str,
 }
 
 impl KeyBind {
     /// Creates a key bind with static key and action text.
     pub const fn new(key: &'static str, action: &'static str) -> Self {
-        Self { key, action }
+        Self {
+            key: Cow::Borrowed(key),
+            action,
+        }
+    }
+
+    /// Creates a key bind whose key text comes from a runtime keymap.
+    pub fn owned(key: String, action: &'static str) -> Self {
+        Self {
+            key: Cow::Owned(key),
+            action,
+        }
     }
 
     /// Builds inline spans for a single key-action pair.
@@ -115,7 +129,7 @@ impl<'a> KeyHintBar<'a> {
         let mut current_width: u16 = 0;
 
         for (i, bind) in self.binds.iter().enumerate() {
-            let entry_width = (bind.key.len() + 2 + bind.action.len() + 1) as u16;
+            let entry_width = (bind.key.chars().count() + 2 + bind.action.len() + 1) as u16;
             let sep_width = if i > 0 {
                 self.separator.len() as u16
             } else {
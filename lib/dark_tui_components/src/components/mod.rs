@@ -5,7 +5,10 @@ mod chat_composer;
 mod chat_conversation_header;
 mod chat_message_list;
 mod chat_types;
+mod code_highlight;
+mod data_table;
 mod footer_bar;
+mod form;
 mod key_hint_bar;
 mod labeled_field;
 mod loading_spinner;
@@ -13,6 +16,7 @@ mod pane_block_component;
 mod popup_overlay;
 mod section_header;
 mod status_pill;
+mod toast_stack;
 
 pub use card_grid_component::CardGridComponent;
 pub use chat_composer::{ChatComposerComponent, ChatComposerProps};
@@ -21,7 +25,11 @@ pub use chat_conversation_header::{
 };
 pub use chat_message_list::{ChatMessageListComponent, ChatMessageListProps, ChatPalette};
 pub use chat_types::{ChatMessageEntry, ChatMessageRole};
+pub use data_table::{
+    ColumnAlign, DataColumn, DataTable, DataTableCommand, DataTableHit, SortDirection, SortKind,
+};
 pub use footer_bar::{FooterBar, FooterBarProps};
+pub use form::{FieldValue, Form, FormCommand, FormField, FormOutcome, SelectOption, Validator};
 pub use key_hint_bar::{KeyBind, KeyHintBar};
 pub use labeled_field::LabeledField;
 pub use loading_spinner::LoadingSpinner;
@@ -29,3 +37,7 @@ pub use pane_block_component::PaneBlockComponent;
 pub use popup_overlay::{PopupAnchor, PopupHit, PopupItem, PopupOverlay, PopupOverlayProps};
 pub use section_header::SectionHeader;
 pub use status_pill::StatusPill;
+pub use toast_stack::{
+    ClearToastHistory, Toast, ToastHistoryCommand, ToastHistoryOverlay, ToastHit, ToastSeverity,
+    ToastStack,
+};
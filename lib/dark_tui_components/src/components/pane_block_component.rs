@@ -8,7 +8,11 @@ pub struct PaneBlockComponent;
 
 impl PaneBlockComponent {
     /// Creates a bordered block with focus-aware border styling.
-    pub fn build<'a>(title: &'a str, focused: bool, theme: &impl ComponentThemeLike) -> Block<'a> {
+    pub fn build<'a>(
+        title: &'a str,
+        focused: bool,
+        theme: &(impl ComponentThemeLike + ?Sized),
+    ) -> Block<'a> {
         let border_style = if focused {
             Style::default()
                 .fg(theme.pane_focused_border())
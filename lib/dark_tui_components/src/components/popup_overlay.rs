@@ -151,7 +151,7 @@ impl PopupOverlay {
         frame: &mut Frame,
         parent: Rect,
         props: &PopupOverlayProps,
-        theme: &impl ComponentThemeLike,
+        theme: &(impl ComponentThemeLike + ?Sized),
     ) {
         let Some(area) = Self::area(parent, props) else {
             return;
@@ -283,7 +283,7 @@ fn render_list(
     frame: &mut Frame,
     area: Rect,
     props: &PopupOverlayProps,
-    theme: &impl ComponentThemeLike,
+    theme: &(impl ComponentThemeLike + ?Sized),
 ) {
     if area.width == 0 || area.height == 0 {
         return;
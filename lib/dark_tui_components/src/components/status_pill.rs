@@ -21,32 +21,32 @@ impl StatusPill {
     }
 
     /// Creates a success-status pill.
-    pub fn ok(label: impl Into<String>, theme: &impl ComponentThemeLike) -> Self {
+    pub fn ok(label: impl Into<String>, theme: &(impl ComponentThemeLike + ?Sized)) -> Self {
         Self::new(label, theme.pill_ok_fg(), theme.pill_ok_bg())
     }
 
     /// Creates a warning-status pill.
-    pub fn warn(label: impl Into<String>, theme: &impl ComponentThemeLike) -> Self {
+    pub fn warn(label: impl Into<String>, theme: &(impl ComponentThemeLike + ?Sized)) -> Self {
         Self::new(label, theme.pill_warn_fg(), theme.pill_warn_bg())
     }
 
     /// Creates an error-status pill.
-    pub fn error(label: impl Into<String>, theme: &impl ComponentThemeLike) -> Self {
+    pub fn error(label: impl Into<String>, theme: &(impl ComponentThemeLike + ?Sized)) -> Self {
         Self::new(label, theme.pill_err_fg(), theme.pill_err_bg())
     }
 
     /// Creates an informational pill.
-    pub fn info(label: impl Into<String>, theme: &impl ComponentThemeLike) -> Self {
+    pub fn info(label: impl Into<String>, theme: &(impl ComponentThemeLike + ?Sized)) -> Self {
         Self::new(label, theme.pill_info_fg(), theme.pill_info_bg())
     }
 
     /// Creates a muted-status pill.
-    pub fn muted(label: impl Into<String>, theme: &impl ComponentThemeLike) -> Self {
+    pub fn muted(label: impl Into<String>, theme: &(impl ComponentThemeLike + ?Sized)) -> Self {
         Self::new(label, theme.pill_muted_fg(), theme.pill_muted_bg())
     }
 
     /// Creates an accent pill.
-    pub fn accent(label: impl Into<String>, theme: &impl ComponentThemeLike) -> Self {
+    pub fn accent(label: impl Into<String>, theme: &(impl ComponentThemeLike + ?Sized)) -> Self {
         Self::new(label, theme.pill_accent_fg(), theme.pill_accent_bg())
     }
 
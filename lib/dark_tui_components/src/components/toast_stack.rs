@@ -0,0 +1,707 @@
+use std::collections::VecDeque;
+use std::time::{Duration, Instant};
+
+use crossterm::event::KeyEvent;
+use ratatui::Frame;
+use ratatui::layout::{Constraint, Direction, Layout, Rect};
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, Clear, Paragraph};
+
+use crate::action::Action;
+use crate::component::{Component, ComponentResult};
+use crate::components::PaneBlockComponent;
+use crate::keymap::{KeyMatch, KeySequenceMatcher, Keymap, KeymapCommand};
+use crate::theme::ComponentThemeLike;
+use crate::utils::compact::compact_text_normalized;
+use crate::utils::rect::rect_contains;
+
+const DEFAULT_MAX_VISIBLE: usize = 4;
+const DEFAULT_HISTORY_LIMIT: usize = 200;
+const DEFAULT_WIDTH: u16 = 56;
+/// Label drawn on the top-right border of each toast; clicking it dismisses.
+const DISMISS_LABEL: &str = "[x]";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ToastSeverity {
+    Info,
+    Success,
+    Warning,
+    Error,
+}
+
+impl ToastSeverity {
+    /// How long a toast stays up; errors linger so they can be read.
+    pub fn timeout(self) -> Duration {
+        match self {
+            Self::Info | Self::Success => Duration::from_secs(4),
+            Self::Warning => Duration::from_secs(8),
+            Self::Error => Duration::from_secs(15),
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Info => "info",
+            Self::Success => "ok",
+            Self::Warning => "warn",
+            Self::Error => "error",
+        }
+    }
+
+    fn glyph(self) -> &'static str {
+        match self {
+            Self::Info => "●",
+            Self::Success => "✓",
+            Self::Warning => "!",
+            Self::Error => "✗",
+        }
+    }
+
+    fn color(self, theme: &(impl ComponentThemeLike + ?Sized)) -> Color {
+        match self {
+            Self::Info => theme.pill_info_fg(),
+            Self::Success => theme.pill_ok_fg(),
+            Self::Warning => theme.pill_warn_fg(),
+            Self::Error => theme.pill_err_fg(),
+        }
+    }
+}
+
+/// One notification, live in the stack or kept in the history log.
+#[derive(Debug, Clone)]
+pub struct Toast {
+    pub id: u64,
+    pub severity: ToastSeverity,
+    pub message: String,
+    /// Times the same message was pushed back to back.
+    pub count: usize,
+    pub at: Instant,
+    pub expanded: bool,
+    expires_at: Option<Instant>,
+}
+
+/// Hit-test result for toast interactions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ToastHit {
+    Outside,
+    /// Body of the toast with this id; clicking toggles the full text.
+    Toast(u64),
+    /// Dismiss label of the toast with this id.
+    Dismiss(u64),
+}
+
+/// Stacked, auto-dismissing notifications with a history log.
+///
+/// The newest toast sits at the bottom of the stack. Expanded toasts stop
+/// their timer until collapsed or dismissed.
+#[derive(Debug, Clone)]
+pub struct ToastStack {
+    active: Vec<Toast>,
+    history: VecDeque<Toast>,
+    next_id: u64,
+    max_visible: usize,
+    history_limit: usize,
+    width: u16,
+}
+
+impl Default for ToastStack {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ToastStack {
+    pub fn new() -> Self {
+        Self {
+            active: Vec::new(),
+            history: VecDeque::new(),
+            next_id: 1,
+            max_visible: DEFAULT_MAX_VISIBLE,
+            history_limit: DEFAULT_HISTORY_LIMIT,
+            width: DEFAULT_WIDTH,
+        }
+    }
+
+    /// Toasts shown at once; older live toasts wait underneath.
+    pub fn max_visible(mut self, max_visible: usize) -> Self {
+        self.max_visible = max_visible.max(1);
+        self
+    }
+
+    pub fn history_limit(mut self, history_limit: usize) -> Self {
+        self.history_limit = history_limit.max(1);
+        self
+    }
+
+    pub fn width(mut self, width: u16) -> Self {
+        self.width = width.max(12);
+        self
+    }
+
+    pub fn info(&mut self, message: impl Into<String>) -> u64 {
+        self.push(ToastSeverity::Info, message)
+    }
+
+    pub fn success(&mut self, message: impl Into<String>) -> u64 {
+        self.push(ToastSeverity::Success, message)
+    }
+
+    pub fn warning(&mut self, message: impl Into<String>) -> u64 {
+        self.push(ToastSeverity::Warning, message)
+    }
+
+    pub fn error(&mut self, message: impl Into<String>) -> u64 {
+        self.push(ToastSeverity::Error, message)
+    }
+
+    pub fn push(&mut self, severity: ToastSeverity, message: impl Into<String>) -> u64 {
+        self.push_at(severity, message, Instant::now())
+    }
+
+    /// Pushes a toast timed from `now`. Repeating the newest live message
+    /// bumps its count and restarts its timer instead of stacking a copy.
+    pub fn push_at(
+        &mut self,
+        severity: ToastSeverity,
+        message: impl Into<String>,
+        now: Instant,
+    ) -> u64 {
+        let message = message.into();
+        let expires_at = Some(now + severity.timeout());
+
+        if let Some(newest) = self
+            .active
+            .last_mut()
+            .filter(|toast| toast.severity == severity && toast.message == message)
+        {
+            newest.count += 1;
+            newest.at = now;
+            if !newest.expanded {
+                newest.expires_at = expires_at;
+            }
+            let id = newest.id;
+            if let Some(logged) = self.history.front_mut().filter(|toast| toast.id == id) {
+                logged.count += 1;
+                logged.at = now;
+            }
+            return id;
+        }
+
+        let id = self.next_id;
+        self.next_id += 1;
+        let toast = Toast {
+            id,
+            severity,
+            message,
+            count: 1,
+            at: now,
+            expanded: false,
+            expires_at,
+        };
+
+        self.history.push_front(toast.clone());
+        self.history.truncate(self.history_limit);
+        self.active.push(toast);
+        id
+    }
+
+    /// Drops expired toasts. Returns whether any were removed.
+    pub fn tick(&mut self, now: Instant) -> bool {
+        let before = self.active.len();
+        self.active
+            .retain(|toast| toast.expires_at.is_none_or(|expires_at| expires_at > now));
+        self.active.len() != before
+    }
+
+    pub fn dismiss(&mut self, id: u64) -> bool {
+        let before = self.active.len();
+        self.active.retain(|toast| toast.id != id);
+        self.active.len() != before
+    }
+
+    /// Dismisses every live toast; the history is kept.
+    pub fn clear(&mut self) {
+        self.active.clear();
+    }
+
+    pub fn clear_history(&mut self) {
+        self.history.clear();
+    }
+
+    /// Shows the full text of a toast and pauses its timer, or collapses it
+    /// and restarts the timer.
+    pub fn toggle_expanded(&mut self, id: u64) -> bool {
+        let Some(toast) = self.active.iter_mut().find(|toast| toast.id == id) else {
+            return false;
+        };
+        toast.expanded = !toast.expanded;
+        toast.expires_at = if toast.expanded {
+            None
+        } else {
+            Some(Instant::now() + toast.severity.timeout())
+        };
+        true
+    }
+
+    /// Live toasts, oldest first.
+    pub fn active(&self) -> &[Toast] {
+        &self.active
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.active.is_empty()
+    }
+
+    /// Logged toasts, newest first.
+    pub fn history(&self) -> impl ExactSizeIterator<Item = &Toast> {
+        self.history.iter()
+    }
+
+    /// Maps a terminal cell to a toast stacked inside `parent`.
+    pub fn hit_test(&self, parent: Rect, col: u16, row: u16) -> ToastHit {
+        for (toast, area) in self.areas(parent) {
+            if !rect_contains(area, col, row) {
+                continue;
+            }
+            let dismiss_start = area
+                .x
+                .saturating_add(area.width)
+                .saturating_sub(DISMISS_LABEL.len() as u16 + 1);
+            if row == area.y && col >= dismiss_start {
+                return ToastHit::Dismiss(toast.id);
+            }
+            return ToastHit::Toast(toast.id);
+        }
+        ToastHit::Outside
+    }
+
+    /// Applies a left click: dismisses or expands the toast under it.
+    /// Returns whether the click landed on a toast.
+    pub fn click(&mut self, parent: Rect, col: u16, row: u16) -> bool {
+        match self.hit_test(parent, col, row) {
+            ToastHit::Outside => false,
+            ToastHit::Toast(id) => self.toggle_expanded(id),
+            ToastHit::Dismiss(id) => self.dismiss(id),
+        }
+    }
+
+    /// Draws the visible toasts stacked upward from the bottom-right of `parent`.
+    pub fn render(
+        &self,
+        frame: &mut Frame,
+        parent: Rect,
+        theme: &(impl ComponentThemeLike + ?Sized),
+    ) {
+        for (toast, area) in self.areas(parent) {
+            let color = toast.severity.color(theme);
+            let mut title = format!(" {} {} ", toast.severity.glyph(), toast.severity.label());
+            if toast.count > 1 {
+                title.push_str(&format!("x{} ", toast.count));
+            }
+            let block = Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(color))
+                .title(Span::styled(
+                    title,
+                    Style::default().fg(color).add_modifier(Modifier::BOLD),
+                ))
+                .title_top(
+                    Line::from(Span::styled(
+                        DISMISS_LABEL,
+                        Style::default().fg(theme.text_muted()),
+                    ))
+                    .right_aligned(),
+                );
+            let inner = block.inner(area);
+            let text = Style::default().fg(theme.text_secondary());
+            let lines: Vec<Line<'static>> = if toast.expanded {
+                wrap(&toast.message, inner.width as usize)
+                    .into_iter()
+                    .map(|line| Line::from(Span::styled(line, text)))
+                    .collect()
+            } else {
+                vec![Line::from(Span::styled(
+                    compact_text_normalized(&toast.message, inner.width as usize),
+                    text,
+                ))]
+            };
+
+            frame.render_widget(Clear, area);
+            frame.render_widget(block, area);
+            frame.render_widget(Paragraph::new(lines), inner);
+        }
+    }
+
+    /// Draws the history log as a popup over `area`, skipping the newest
+    /// `scroll` entries, with `footer` as the key hint line.
+    pub fn render_history(
+        &self,
+        frame: &mut Frame,
+        area: Rect,
+        scroll: usize,
+        footer: &str,
+        theme: &(impl ComponentThemeLike + ?Sized),
+    ) {
+        let title = format!("Messages ({})", self.history.len());
+        let block = PaneBlockComponent::build(&title, true, theme);
+        let inner = block.inner(area);
+        frame.render_widget(Clear, area);
+        frame.render_widget(block, area);
+
+        let rows = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Min(1), Constraint::Length(1)])
+            .split(inner);
+        let muted = Style::default().fg(theme.text_muted());
+
+        let lines = self.history_lines(scroll, rows[0].width as usize, Instant::now(), theme);
+        if lines.is_empty() {
+            frame.render_widget(
+                Paragraph::new(Span::styled("No messages yet.", muted)),
+                rows[0],
+            );
+        } else {
+            frame.render_widget(Paragraph::new(lines), rows[0]);
+        }
+
+        frame.render_widget(
+            Paragraph::new(Span::styled(footer.to_string(), muted)),
+            rows[1],
+        );
+    }
+
+    /// Wrapped history lines, newest entry first.
+    fn history_lines(
+        &self,
+        skip: usize,
+        width: usize,
+        now: Instant,
+        theme: &(impl ComponentThemeLike + ?Sized),
+    ) -> Vec<Line<'static>> {
+        // Age column, glyph and spaces.
+        const PREFIX: usize = 7;
+        let muted = Style::default().fg(theme.text_muted());
+        let text = Style::default().fg(theme.text_secondary());
+        let mut lines = Vec::new();
+
+        for toast in self.history.iter().skip(skip) {
+            let mut message = toast.message.clone();
+            if toast.count > 1 {
+                message.push_str(&format!(" (x{})", toast.count));
+            }
+            let glyph = Style::default().fg(toast.severity.color(theme));
+            for (index, chunk) in wrap(&message, width.saturating_sub(PREFIX).max(8))
+                .into_iter()
+                .enumerate()
+            {
+                let line = if index == 0 {
+                    Line::from(vec![
+                        Span::styled(
+                            format!("{:>4} ", age_label(now.saturating_duration_since(toast.at))),
+                            muted,
+                        ),
+                        Span::styled(format!("{} ", toast.severity.glyph()), glyph),
+                        Span::styled(chunk, text),
+                    ])
+                } else {
+                    Line::from(vec![
+                        Span::raw(" ".repeat(PREFIX)),
+                        Span::styled(chunk, text),
+                    ])
+                };
+                lines.push(line);
+            }
+        }
+        lines
+    }
+
+    /// Visible toasts with their areas, newest at the bottom of `parent`.
+    fn areas(&self, parent: Rect) -> Vec<(&Toast, Rect)> {
+        let width = self.width.min(parent.width);
+        if width < 4 {
+            return Vec::new();
+        }
+        let x = parent.x + parent.width - width;
+        let inner_width = width.saturating_sub(2) as usize;
+        let max_body = (parent.height / 2).max(1) as usize;
+
+        let mut bottom = parent.y + parent.height;
+        let mut areas = Vec::new();
+        for toast in self.active.iter().rev().take(self.max_visible) {
+            let body = if toast.expanded {
+                wrap(&toast.message, inner_width).len().clamp(1, max_body)
+            } else {
+                1
+            };
+            let height = body as u16 + 2;
+            if bottom < parent.y + height {
+                break;
+            }
+            bottom -= height;
+            areas.push((toast, Rect::new(x, bottom, width, height)));
+        }
+        areas
+    }
+}
+
+/// Asks the host to clear its toast history; sent by [`ToastHistoryOverlay`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ClearToastHistory;
+
+/// What a message log key does; hosts map their keymap commands onto these.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ToastHistoryCommand {
+    Close,
+    Down,
+    Up,
+    PageDown,
+    PageUp,
+    Clear,
+}
+
+/// Scrollable toast history for the component runtime.
+///
+/// Keeps a copy of the host's stack: dispatch the `ToastStack` as an
+/// `Action::custom` payload whenever its history changes. Keys resolve through
+/// the host's keymap in one mode, and a newly loaded `Keymap<C>` can be
+/// dispatched the same way. [`ToastHistoryCommand::Clear`] answers with
+/// [`ClearToastHistory`].
+pub struct ToastHistoryOverlay<C: 'static> {
+    toasts: ToastStack,
+    scroll: usize,
+    keymap: Keymap<C>,
+    mode: &'static str,
+    commands: &'static [(C, ToastHistoryCommand)],
+    matcher: KeySequenceMatcher,
+}
+
+impl<C: KeymapCommand> ToastHistoryOverlay<C> {
+    /// `commands` maps the host's commands bound in `mode` onto log actions.
+    pub fn new(
+        keymap: Keymap<C>,
+        mode: &'static str,
+        commands: &'static [(C, ToastHistoryCommand)],
+    ) -> Self {
+        Self {
+            toasts: ToastStack::default(),
+            scroll: 0,
+            keymap,
+            mode,
+            commands,
+            matcher: KeySequenceMatcher::default(),
+        }
+    }
+
+    fn scroll_by(&mut self, delta: isize) -> Option<Action> {
+        let max = self.toasts.history.len().saturating_sub(1);
+        self.scroll = self.scroll.saturating_add_signed(delta).min(max);
+        Some(Action::Render)
+    }
+
+    fn hint(&self, wanted: ToastHistoryCommand) -> Option<String> {
+        self.commands
+            .iter()
+            .find(|(_, command)| *command == wanted)
+            .and_then(|(bound, _)| self.keymap.hint(self.mode, *bound))
+    }
+
+    fn footer(&self) -> String {
+        let scroll = match (
+            self.hint(ToastHistoryCommand::Down),
+            self.hint(ToastHistoryCommand::Up),
+        ) {
+            (Some(down), Some(up)) => Some(format!("{down}/{up}")),
+            (down, up) => down.or(up),
+        };
+        [
+            scroll.map(|keys| format!("{keys}: scroll")),
+            self.hint(ToastHistoryCommand::Clear)
+                .map(|key| format!("{key}: clear")),
+            self.hint(ToastHistoryCommand::Close)
+                .map(|key| format!("{key}: close")),
+        ]
+        .into_iter()
+        .flatten()
+        .collect::<Vec<_>>()
+        .join("   ")
+    }
+}
+
+impl<C: KeymapCommand + Send + Sync> Component for ToastHistoryOverlay<C> {
+    fn handle_key_event(&mut self, key: KeyEvent) -> ComponentResult<Option<Action>> {
+        let KeyMatch::Command(bound) = self.matcher.feed(&self.keymap, self.mode, key) else {
+            return Ok(None);
+        };
+        let Some((_, command)) = self.commands.iter().find(|(known, _)| *known == bound) else {
+            return Ok(None);
+        };
+        Ok(match command {
+            ToastHistoryCommand::Close => Some(Action::Cancel),
+            ToastHistoryCommand::Down => self.scroll_by(1),
+            ToastHistoryCommand::Up => self.scroll_by(-1),
+            ToastHistoryCommand::PageDown => self.scroll_by(10),
+            ToastHistoryCommand::PageUp => self.scroll_by(-10),
+            ToastHistoryCommand::Clear => Some(Action::custom(ClearToastHistory)),
+        })
+    }
+
+    fn update(&mut self, action: &Action) -> ComponentResult<Option<Action>> {
+        if let Some(keymap) = action.downcast_ref::<Keymap<C>>() {
+            self.keymap = keymap.clone();
+            self.matcher.reset();
+            return Ok(Some(Action::Render));
+        }
+        let Some(toasts) = action.downcast_ref::<ToastStack>() else {
+            return Ok(None);
+        };
+        self.toasts = toasts.clone();
+        self.scroll = 0;
+        Ok(Some(Action::Render))
+    }
+
+    fn draw(
+        &self,
+        frame: &mut Frame,
+        area: Rect,
+        theme: &dyn ComponentThemeLike,
+    ) -> ComponentResult {
+        self.toasts
+            .render_history(frame, area, self.scroll, &self.footer(), theme);
+        Ok(())
+    }
+
+    fn wants_focus(&self) -> bool {
+        true
+    }
+}
+
+/// Splits text into lines of at most `width` characters, keeping newlines.
+fn wrap(text: &str, width: usize) -> Vec<String> {
+    let width = width.max(1);
+    let mut lines = Vec::new();
+    for paragraph in text.lines() {
+        let chars: Vec<char> = paragraph.chars().collect();
+        if chars.is_empty() {
+            lines.push(String::new());
+            continue;
+        }
+        lines.extend(chars.chunks(width).map(|chunk| chunk.iter().collect()));
+    }
+    if lines.is_empty() {
+        lines.push(String::new());
+    }
+    lines
+}
+
+/// Compact age such as `12s`, `4m` or `3h`.
+fn age_label(age: Duration) -> String {
+    let seconds = age.as_secs();
+    match seconds {
+        0..=59 => format!("{seconds}s"),
+        60..=3_599 => format!("{}m", seconds / 60),
+        _ => format!("{}h", seconds / 3_600),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crossterm::event::KeyCode;
+
+    use super::*;
+
+    #[test]
+    fn wrap_splits_long_lines_and_keeps_newlines() {
+        assert_eq!(wrap("abcdef", 4), ["abcd", "ef"]);
+        assert_eq!(wrap("ab\ncd", 4), ["ab", "cd"]);
+        assert_eq!(wrap("", 4), [""]);
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    enum LogCommand {
+        Close,
+        Down,
+        Clear,
+    }
+
+    impl KeymapCommand for LogCommand {
+        fn name(self) -> &'static str {
+            match self {
+                Self::Close => "close",
+                Self::Down => "down",
+                Self::Clear => "clear",
+            }
+        }
+
+        fn from_name(name: &str) -> Option<Self> {
+            [Self::Close, Self::Down, Self::Clear]
+                .into_iter()
+                .find(|command| command.name() == name)
+        }
+    }
+
+    const LOG_COMMANDS: &[(LogCommand, ToastHistoryCommand)] = &[
+        (LogCommand::Close, ToastHistoryCommand::Close),
+        (LogCommand::Down, ToastHistoryCommand::Down),
+        (LogCommand::Clear, ToastHistoryCommand::Clear),
+    ];
+
+    #[test]
+    fn history_overlay_resolves_keys_through_the_keymap() {
+        let key = |code| KeyEvent::new(code, crossterm::event::KeyModifiers::NONE);
+        let mut toasts = ToastStack::new();
+        toasts.error("first");
+        toasts.error("second");
+
+        let keymap = Keymap::from_defaults(
+            &["log"],
+            &[
+                ("log", LogCommand::Close, &["esc", "!"]),
+                ("log", LogCommand::Down, &["pagedown"]),
+                ("log", LogCommand::Clear, &["x"]),
+            ],
+        );
+        let mut overlay = ToastHistoryOverlay::new(keymap.clone(), "log", LOG_COMMANDS);
+        overlay
+            .update(&Action::custom(toasts))
+            .expect("history delivered");
+        overlay
+            .handle_key_event(key(KeyCode::PageDown))
+            .expect("scrolled");
+        assert_eq!(overlay.scroll, 1);
+        assert_eq!(overlay.footer(), "PgDn: scroll   x: clear   Esc: close");
+
+        let clear = overlay
+            .handle_key_event(key(KeyCode::Char('x')))
+            .expect("key handled")
+            .expect("clear requested");
+        assert_eq!(
+            clear.downcast_ref::<ClearToastHistory>(),
+            Some(&ClearToastHistory)
+        );
+        assert!(matches!(
+            overlay.handle_key_event(key(KeyCode::Char('!'))),
+            Ok(Some(Action::Cancel))
+        ));
+
+        let mut rebound = keymap;
+        rebound
+            .rebind("log", LogCommand::Clear, &["d".to_string()])
+            .expect("rebind");
+        overlay
+            .update(&Action::custom(rebound))
+            .expect("keymap delivered");
+        assert!(matches!(
+            overlay.handle_key_event(key(KeyCode::Char('x'))),
+            Ok(None)
+        ));
+        assert_eq!(overlay.footer(), "PgDn: scroll   d: clear   Esc: close");
+    }
+
+    #[test]
+    fn age_label_uses_largest_unit() {
+        assert_eq!(age_label(Duration::from_secs(12)), "12s");
+        assert_eq!(age_label(Duration::from_secs(240)), "4m");
+        assert_eq!(age_label(Duration::from_secs(7_200)), "2h");
+    }
+}
@@ -0,0 +1,870 @@
+//! Key chords, multi-key sequences, and per-mode keymaps.
+//!
+//! Frontends describe their defaults as `(mode, command, keys)` triples on a
+//! [`KeymapProfile`], layer the shared user TOML file on top with
+//! [`load_keymap`], and resolve incoming key events through a
+//! [`KeySequenceMatcher`]. Hint bars read the same [`Keymap`] so displayed keys
+//! always match what is bound.
+
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+/// A single key press with modifiers, e.g. `ctrl+p` or `G`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct KeyChord {
+    pub code: KeyCode,
+    pub modifiers: KeyModifiers,
+}
+
+impl KeyChord {
+    pub const fn new(code: KeyCode, modifiers: KeyModifiers) -> Self {
+        Self { code, modifiers }
+    }
+
+    /// Parses `ctrl+p`, `alt+x`, `shift+tab`, `G`, `space`, `enter`, `f5`, ...
+    ///
+    /// Character keys are case-sensitive; `shift+g` is the same chord as `G`.
+    pub fn parse(text: &str) -> Result<Self, KeymapError> {
+        let text = text.trim();
+        if text.is_empty() {
+            return Err(KeymapError::new("empty key chord"));
+        }
+
+        let mut modifiers = KeyModifiers::NONE;
+        let mut parts: Vec<&str> = text.split('+').collect();
+        // `ctrl++` and `+` name the plus key itself.
+        if text.ends_with("++") || text == "+" {
+            parts.retain(|part| !part.is_empty());
+            parts.push("+");
+        }
+        let key = parts.pop().unwrap_or_default();
+
+        for modifier in parts {
+            match modifier.to_ascii_lowercase().as_str() {
+                "ctrl" | "control" => modifiers |= KeyModifiers::CONTROL,
+                "alt" | "meta" => modifiers |= KeyModifiers::ALT,
+                "shift" => modifiers |= KeyModifiers::SHIFT,
+                other => {
+                    return Err(KeymapError::new(format!(
+                        "unknown modifier `{other}` in `{text}`"
+                    )));
+                }
+            }
+        }
+
+        let code = match key.to_ascii_lowercase().as_str() {
+            "enter" | "return" => KeyCode::Enter,
+            "esc" | "escape" => KeyCode::Esc,
+            "tab" if modifiers.contains(KeyModifiers::SHIFT) => {
+                modifiers.remove(KeyModifiers::SHIFT);
+                KeyCode::BackTab
+            }
+            "tab" => KeyCode::Tab,
+            "backtab" => KeyCode::BackTab,
+            "space" => KeyCode::Char(' '),
+            "up" => KeyCode::Up,
+            "down" => KeyCode::Down,
+            "left" => KeyCode::Left,
+            "right" => KeyCode::Right,
+            "pageup" | "pgup" => KeyCode::PageUp,
+            "pagedown" | "pgdn" => KeyCode::PageDown,
+            "home" => KeyCode::Home,
+            "end" => KeyCode::End,
+            "delete" | "del" => KeyCode::Delete,
+            "backspace" => KeyCode::Backspace,
+            "insert" => KeyCode::Insert,
+            lower if lower.len() > 1 && lower.starts_with('f') => lower[1..]
+                .parse::<u8>()
+                .ok()
+                .filter(|number| (1..=24).contains(number))
+                .map(KeyCode::F)
+                .ok_or_else(|| KeymapError::new(format!("unknown key `{key}` in `{text}`")))?,
+            _ => {
+                let mut chars = key.chars();
+                let (Some(ch), None) = (chars.next(), chars.next()) else {
+                    return Err(KeymapError::new(format!("unknown key `{key}` in `{text}`")));
+                };
+                KeyCode::Char(ch)
+            }
+        };
+
+        Ok(Self::normalized(code, modifiers))
+    }
+
+    /// Builds the chord for a terminal key event.
+    pub fn from_event(event: KeyEvent) -> Self {
+        Self::normalized(event.code, event.modifiers)
+    }
+
+    /// A key event that resolves back to this chord.
+    pub fn to_event(self) -> KeyEvent {
+        let modifiers = match self.code {
+            KeyCode::Char(ch) if ch.is_uppercase() => self.modifiers | KeyModifiers::SHIFT,
+            _ => self.modifiers,
+        };
+        KeyEvent::new(self.code, modifiers)
+    }
+
+    /// Terminals report `G` as `shift+G`; shift is folded into the character
+    /// so both spellings compare equal. Only ctrl/alt/shift are kept.
+    fn normalized(code: KeyCode, modifiers: KeyModifiers) -> Self {
+        let mut modifiers =
+            modifiers & (KeyModifiers::CONTROL | KeyModifiers::ALT | KeyModifiers::SHIFT);
+        let code = match code {
+            KeyCode::Char(ch) if modifiers.contains(KeyModifiers::SHIFT) => {
+                modifiers.remove(KeyModifiers::SHIFT);
+                KeyCode::Char(ch.to_uppercase().next().unwrap_or(ch))
+            }
+            KeyCode::Char(ch)
+                if modifiers.contains(KeyModifiers::CONTROL) && ch.is_ascii_uppercase() =>
+            {
+                KeyCode::Char(ch.to_ascii_lowercase())
+            }
+            KeyCode::BackTab => {
+                modifiers.remove(KeyModifiers::SHIFT);
+                KeyCode::BackTab
+            }
+            other => other,
+        };
+
+        Self { code, modifiers }
+    }
+}
+
+impl fmt::Display for KeyChord {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.modifiers.contains(KeyModifiers::CONTROL) {
+            write!(f, "C-")?;
+        }
+        if self.modifiers.contains(KeyModifiers::ALT) {
+            write!(f, "M-")?;
+        }
+        if self.modifiers.contains(KeyModifiers::SHIFT) {
+            write!(f, "S-")?;
+        }
+
+        match self.code {
+            KeyCode::Char(' ') => write!(f, "Space"),
+            KeyCode::Char(ch) => write!(f, "{ch}"),
+            KeyCode::Enter => write!(f, "Enter"),
+            KeyCode::Esc => write!(f, "Esc"),
+            KeyCode::Tab => write!(f, "Tab"),
+            KeyCode::BackTab => write!(f, "S-Tab"),
+            KeyCode::Up => write!(f, "↑"),
+            KeyCode::Down => write!(f, "↓"),
+            KeyCode::Left => write!(f, "←"),
+            KeyCode::Right => write!(f, "→"),
+            KeyCode::PageUp => write!(f, "PgUp"),
+            KeyCode::PageDown => write!(f, "PgDn"),
+            KeyCode::Home => write!(f, "Home"),
+            KeyCode::End => write!(f, "End"),
+            KeyCode::Delete => write!(f, "Del"),
+            KeyCode::Backspace => write!(f, "Bksp"),
+            KeyCode::Insert => write!(f, "Ins"),
+            KeyCode::F(number) => write!(f, "F{number}"),
+            other => write!(f, "{other:?}"),
+        }
+    }
+}
+
+/// One or more chords pressed in order, e.g. `g g`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct KeySequence(Vec<KeyChord>);
+
+impl KeySequence {
+    /// Parses whitespace-separated chords: `g g`, `ctrl+x ctrl+s`.
+    pub fn parse(text: &str) -> Result<Self, KeymapError> {
+        let chords = text
+            .split_whitespace()
+            .map(KeyChord::parse)
+            .collect::<Result<Vec<_>, _>>()?;
+        if chords.is_empty() {
+            return Err(KeymapError::new("empty key sequence"));
+        }
+
+        Ok(Self(chords))
+    }
+
+    pub fn chords(&self) -> &[KeyChord] {
+        &self.0
+    }
+
+    fn starts_with(&self, prefix: &[KeyChord]) -> bool {
+        self.0.starts_with(prefix)
+    }
+}
+
+impl fmt::Display for KeySequence {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (index, chord) in self.0.iter().enumerate() {
+            if index > 0 {
+                write!(f, " ")?;
+            }
+            write!(f, "{chord}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Error raised while parsing or validating a keymap.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KeymapError {
+    message: String,
+}
+
+impl KeymapError {
+    pub fn new(message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+        }
+    }
+}
+
+impl fmt::Display for KeymapError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+impl std::error::Error for KeymapError {}
+
+/// Command ids a frontend exposes to the keymap file.
+pub trait KeymapCommand: Copy + Eq + 'static {
+    /// Name used in keymap files, e.g. `open_git_panel`.
+    fn name(self) -> &'static str;
+
+    fn from_name(name: &str) -> Option<Self>;
+
+    /// Resolves a name written in `mode`'s table. Frontends whose modes use
+    /// separate command sets override this; the default ignores the mode.
+    fn from_mode_name(_mode: &str, name: &str) -> Option<Self> {
+        Self::from_name(name)
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Binding<C> {
+    mode: &'static str,
+    sequence: KeySequence,
+    command: C,
+}
+
+/// Outcome of feeding a key into a [`KeySequenceMatcher`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KeyMatch<C> {
+    Command(C),
+    /// The keys so far start a longer sequence; wait for the next key.
+    Pending,
+    NoMatch,
+}
+
+/// Two bindings in the same mode that cannot both work.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KeymapConflict {
+    pub mode: &'static str,
+    pub sequence: String,
+    pub first: &'static str,
+    pub second: &'static str,
+    /// `true` when `sequence` is a strict prefix of one of `second`'s keys.
+    pub shadows: bool,
+}
+
+impl fmt::Display for KeymapConflict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.shadows {
+            write!(
+                f,
+                "[{}] `{}` for {} shadows a longer sequence bound to {}",
+                self.mode, self.sequence, self.first, self.second
+            )
+        } else {
+            write!(
+                f,
+                "[{}] `{}` is bound to both {} and {}",
+                self.mode, self.sequence, self.first, self.second
+            )
+        }
+    }
+}
+
+/// Effective key bindings for every mode of one frontend.
+#[derive(Debug, Clone)]
+pub struct Keymap<C> {
+    modes: &'static [&'static str],
+    bindings: Vec<Binding<C>>,
+}
+
+impl<C: KeymapCommand> Keymap<C> {
+    /// Builds a keymap from `(mode, command, keys)` defaults.
+    ///
+    /// Panics on malformed defaults, which are a programming error.
+    pub fn from_defaults(
+        modes: &'static [&'static str],
+        defaults: &[(&'static str, C, &[&str])],
+    ) -> Self {
+        let mut keymap = Self {
+            modes,
+            bindings: Vec::new(),
+        };
+        for (mode, command, keys) in defaults {
+            for key in *keys {
+                let sequence = KeySequence::parse(key)
+                    .unwrap_or_else(|error| panic!("invalid default key `{key}`: {error}"));
+                keymap.bindings.push(Binding {
+                    mode,
+                    sequence,
+                    command: *command,
+                });
+            }
+        }
+        keymap
+    }
+
+    /// Replaces every binding of `command` in `mode`; an empty list unbinds it.
+    pub fn rebind(&mut self, mode: &str, command: C, keys: &[String]) -> Result<(), KeymapError> {
+        let mode = self.mode_name(mode)?;
+        let sequences = keys
+            .iter()
+            .map(|key| KeySequence::parse(key))
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|error| KeymapError::new(format!("[{mode}] {}: {error}", command.name())))?;
+
+        self.bindings
+            .retain(|binding| !(binding.mode == mode && binding.command == command));
+        self.bindings
+            .extend(sequences.into_iter().map(|sequence| Binding {
+                mode,
+                sequence,
+                command,
+            }));
+        Ok(())
+    }
+
+    /// Adds extra keys to `command` without dropping its current ones.
+    pub fn add(&mut self, mode: &str, command: C, keys: &[&str]) -> Result<(), KeymapError> {
+        let mode = self.mode_name(mode)?;
+        for key in keys {
+            let sequence = KeySequence::parse(key)?;
+            if !self.bindings.iter().any(|binding| {
+                binding.mode == mode && binding.command == command && binding.sequence == sequence
+            }) {
+                self.bindings.push(Binding {
+                    mode,
+                    sequence,
+                    command,
+                });
+            }
+        }
+        Ok(())
+    }
+
+    /// Applies a parsed keymap file section: `{ mode => { command => keys } }`.
+    pub fn apply_overrides(&mut self, table: &toml::Table) -> Result<(), KeymapError> {
+        for (mode, commands) in table {
+            let commands = commands.as_table().ok_or_else(|| {
+                KeymapError::new(format!("[{mode}] must be a table of command = keys"))
+            })?;
+
+            for (name, keys) in commands {
+                let command = C::from_mode_name(mode, name).ok_or_else(|| {
+                    KeymapError::new(format!("[{mode}] unknown command `{name}`"))
+                })?;
+                let keys = match keys {
+                    toml::Value::String(key) => vec![key.clone()],
+                    toml::Value::Array(items) => items
+                        .iter()
+                        .map(|item| {
+                            item.as_str().map(ToString::to_string).ok_or_else(|| {
+                                KeymapError::new(format!("[{mode}] {name}: keys must be strings"))
+                            })
+                        })
+                        .collect::<Result<Vec<_>, _>>()?,
+                    _ => {
+                        return Err(KeymapError::new(format!(
+                            "[{mode}] {name}: expected a key string or a list of keys"
+                        )));
+                    }
+                };
+                self.rebind(mode, command, &keys)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Duplicate and prefix-shadowing bindings, reported per mode.
+    pub fn conflicts(&self) -> Vec<KeymapConflict> {
+        let mut conflicts = Vec::new();
+
+        for (index, first) in self.bindings.iter().enumerate() {
+            for second in &self.bindings[index + 1..] {
+                if first.mode != second.mode || first.command == second.command {
+                    continue;
+                }
+
+                let (shorter, longer) = if first.sequence.0.len() <= second.sequence.0.len() {
+                    (first, second)
+                } else {
+                    (second, first)
+                };
+                if longer.sequence.starts_with(shorter.sequence.chords()) {
+                    conflicts.push(KeymapConflict {
+                        mode: first.mode,
+                        sequence: shorter.sequence.to_string(),
+                        first: shorter.command.name(),
+                        second: longer.command.name(),
+                        shadows: shorter.sequence.0.len() < longer.sequence.0.len(),
+                    });
+                }
+            }
+        }
+
+        conflicts
+    }
+
+    /// Fails with every conflict listed when the keymap is ambiguous.
+    pub fn validate(&self) -> Result<(), KeymapError> {
+        let conflicts = self.conflicts();
+        if conflicts.is_empty() {
+            return Ok(());
+        }
+
+        Err(KeymapError::new(
+            conflicts
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+                .join("; "),
+        ))
+    }
+
+    /// Keys bound to `command` in `mode`, in binding order.
+    pub fn sequences(&self, mode: &str, command: C) -> impl Iterator<Item = &KeySequence> {
+        self.bindings
+            .iter()
+            .filter(move |binding| binding.mode == mode && binding.command == command)
+            .map(|binding| &binding.sequence)
+    }
+
+    /// Display text for the primary key of `command`, if it is bound.
+    pub fn hint(&self, mode: &str, command: C) -> Option<String> {
+        self.sequences(mode, command)
+            .next()
+            .map(ToString::to_string)
+    }
+
+    /// Single-chord key event that triggers `command`, for synthesized presses.
+    pub fn key_event(&self, mode: &str, command: C) -> Option<KeyEvent> {
+        self.sequences(mode, command)
+            .find(|sequence| sequence.0.len() == 1)
+            .map(|sequence| sequence.0[0].to_event())
+    }
+
+    fn lookup(&self, mode: &str, pressed: &[KeyChord]) -> KeyMatch<C> {
+        let mut pending = false;
+        for binding in self.bindings.iter().filter(|binding| binding.mode == mode) {
+            if binding.sequence.0 == pressed {
+                return KeyMatch::Command(binding.command);
+            }
+            if binding.sequence.starts_with(pressed) {
+                pending = true;
+            }
+        }
+
+        if pending {
+            KeyMatch::Pending
+        } else {
+            KeyMatch::NoMatch
+        }
+    }
+
+    fn mode_name(&self, mode: &str) -> Result<&'static str, KeymapError> {
+        self.modes
+            .iter()
+            .copied()
+            .find(|known| *known == mode)
+            .ok_or_else(|| {
+                KeymapError::new(format!(
+                    "unknown mode `{mode}` (expected one of: {})",
+                    self.modes.join(", ")
+                ))
+            })
+    }
+}
+
+/// A keymap file split for one frontend: the shared preset plus its own table.
+///
+/// ```toml
+/// preset = "vim"
+///
+/// [dark_tui.main]
+/// open_git_panel = "g s"
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct KeymapFile {
+    pub preset: Option<String>,
+    /// `{ mode => { command => keys } }` for [`Keymap::apply_overrides`].
+    pub overrides: toml::Table,
+}
+
+impl KeymapFile {
+    pub fn parse(text: &str, frontend: &str) -> Result<Self, KeymapError> {
+        let mut table: toml::Table =
+            toml::from_str(text).map_err(|error| KeymapError::new(error.to_string()))?;
+
+        let preset = match table.remove("preset") {
+            None => None,
+            Some(toml::Value::String(preset)) => Some(preset),
+            Some(_) => return Err(KeymapError::new("`preset` must be a string")),
+        };
+        let overrides = match table.remove(frontend) {
+            None => toml::Table::new(),
+            Some(toml::Value::Table(overrides)) => overrides,
+            Some(_) => return Err(KeymapError::new(format!("[{frontend}] must be a table"))),
+        };
+
+        Ok(Self { preset, overrides })
+    }
+}
+
+/// Base layer a keymap file picks with `preset = "..."`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KeymapPreset {
+    Default,
+    /// Adds `j`/`k` movement.
+    Vim,
+    /// Adds `C-n`/`C-p` movement.
+    Emacs,
+}
+
+impl KeymapPreset {
+    pub fn parse(name: &str) -> Result<Self, KeymapError> {
+        match name {
+            "default" => Ok(Self::Default),
+            "vim" => Ok(Self::Vim),
+            "emacs" => Ok(Self::Emacs),
+            other => Err(KeymapError::new(format!(
+                "unknown preset `{other}` (expected default, vim or emacs)"
+            ))),
+        }
+    }
+
+    /// Down and up keys the preset adds to every navigable mode.
+    fn movement(self) -> Option<[&'static str; 2]> {
+        match self {
+            Self::Default => None,
+            Self::Vim => Some(["j", "k"]),
+            Self::Emacs => Some(["ctrl+n", "ctrl+p"]),
+        }
+    }
+}
+
+/// A frontend's command set together with its keymap file section and defaults.
+pub trait KeymapProfile: KeymapCommand {
+    /// Table holding this frontend's overrides, e.g. `dark_tui`.
+    const SECTION: &'static str;
+    const MODES: &'static [&'static str];
+    const DEFAULT_BINDINGS: &'static [(&'static str, Self, &'static [&'static str])];
+    /// `(mode, down, up)` pairs that presets extend with their movement keys.
+    /// Leave out modes that take typed text.
+    const NAVIGATION: &'static [(&'static str, Self, Self)];
+
+    /// Frontend-specific preset changes applied after the movement keys, such
+    /// as moving a command off a chord the preset claims.
+    fn adjust_preset(_keymap: &mut Keymap<Self>, _preset: KeymapPreset) -> Result<(), KeymapError> {
+        Ok(())
+    }
+
+    fn default_keymap() -> Keymap<Self> {
+        Keymap::from_defaults(Self::MODES, Self::DEFAULT_BINDINGS)
+    }
+}
+
+/// `$HOME/.darkfactory/keymap.toml`, shared by every frontend.
+pub fn default_keymap_path() -> Option<PathBuf> {
+    std::env::var_os("HOME")
+        .map(|home| PathBuf::from(home).join(".darkfactory").join("keymap.toml"))
+}
+
+/// Builds the effective keymap: defaults, then the file's preset, then its overrides.
+///
+/// Without an explicit path a missing default file yields the defaults. Read
+/// and parse errors and conflicts are returned so the caller can report them
+/// and keep the defaults.
+pub fn load_keymap<C: KeymapProfile>(explicit: Option<&Path>) -> Result<Keymap<C>, KeymapError> {
+    let path = match explicit {
+        Some(path) => path.to_path_buf(),
+        None => match default_keymap_path() {
+            Some(path) if path.exists() => path,
+            _ => return Ok(C::default_keymap()),
+        },
+    };
+
+    let text = fs::read_to_string(&path)
+        .map_err(|error| KeymapError::new(format!("failed to read {}: {error}", path.display())))?;
+    parse_keymap(&text)
+        .map_err(|error| KeymapError::new(format!("invalid {}: {error}", path.display())))
+}
+
+pub fn parse_keymap<C: KeymapProfile>(text: &str) -> Result<Keymap<C>, KeymapError> {
+    let file = KeymapFile::parse(text, C::SECTION)?;
+    let mut keymap = C::default_keymap();
+
+    if let Some(preset) = file.preset.as_deref() {
+        apply_preset(&mut keymap, KeymapPreset::parse(preset)?)?;
+    }
+    keymap.apply_overrides(&file.overrides)?;
+    keymap.validate()?;
+
+    Ok(keymap)
+}
+
+/// Layers the preset's movement keys onto every navigable mode, then the
+/// frontend's own adjustments.
+pub fn apply_preset<C: KeymapProfile>(
+    keymap: &mut Keymap<C>,
+    preset: KeymapPreset,
+) -> Result<(), KeymapError> {
+    if let Some([down_key, up_key]) = preset.movement() {
+        for (mode, down, up) in C::NAVIGATION {
+            keymap.add(mode, *down, &[down_key])?;
+            keymap.add(mode, *up, &[up_key])?;
+        }
+    }
+    C::adjust_preset(keymap, preset)
+}
+
+/// Buffers chords until they complete a bound sequence.
+#[derive(Debug, Clone, Default)]
+pub struct KeySequenceMatcher {
+    pending: Vec<KeyChord>,
+}
+
+impl KeySequenceMatcher {
+    /// Feeds one key press; a key that breaks a pending sequence is retried on its own.
+    ///
+    /// Esc abandons a pending sequence instead of running its own binding.
+    pub fn feed<C: KeymapCommand>(
+        &mut self,
+        keymap: &Keymap<C>,
+        mode: &str,
+        event: KeyEvent,
+    ) -> KeyMatch<C> {
+        if event.code == KeyCode::Esc && !self.pending.is_empty() {
+            self.pending.clear();
+            return KeyMatch::NoMatch;
+        }
+
+        let chord = KeyChord::from_event(event);
+        self.pending.push(chord);
+
+        match keymap.lookup(mode, &self.pending) {
+            KeyMatch::Pending => KeyMatch::Pending,
+            KeyMatch::Command(command) => {
+                self.pending.clear();
+                KeyMatch::Command(command)
+            }
+            KeyMatch::NoMatch if self.pending.len() > 1 => {
+                self.pending.clear();
+                self.feed(keymap, mode, event)
+            }
+            KeyMatch::NoMatch => {
+                self.pending.clear();
+                KeyMatch::NoMatch
+            }
+        }
+    }
+
+    /// Keys typed so far toward a multi-key sequence.
+    pub fn pending(&self) -> Option<String> {
+        if self.pending.is_empty() {
+            return None;
+        }
+
+        Some(KeySequence(self.pending.clone()).to_string())
+    }
+
+    pub fn reset(&mut self) {
+        self.pending.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+    use super::{
+        KeyChord, KeyMatch, KeySequenceMatcher, Keymap, KeymapCommand, KeymapError, KeymapFile,
+        KeymapPreset, KeymapProfile, parse_keymap,
+    };
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    enum Cmd {
+        Top,
+        Move,
+        Palette,
+        Down,
+        Up,
+    }
+
+    impl KeymapCommand for Cmd {
+        fn name(self) -> &'static str {
+            match self {
+                Self::Top => "top",
+                Self::Move => "move",
+                Self::Palette => "palette",
+                Self::Down => "down",
+                Self::Up => "up",
+            }
+        }
+
+        fn from_name(name: &str) -> Option<Self> {
+            [Self::Top, Self::Move, Self::Palette, Self::Down, Self::Up]
+                .into_iter()
+                .find(|command| command.name() == name)
+        }
+    }
+
+    impl KeymapProfile for Cmd {
+        const SECTION: &'static str = "demo";
+        const MODES: &'static [&'static str] = &["main", "input"];
+        const DEFAULT_BINDINGS: &'static [(&'static str, Self, &'static [&'static str])] = &[
+            ("main", Self::Down, &["down"]),
+            ("main", Self::Up, &["up"]),
+            ("main", Self::Palette, &["ctrl+p"]),
+            ("input", Self::Down, &["down"]),
+        ];
+        const NAVIGATION: &'static [(&'static str, Self, Self)] = &[("main", Self::Down, Self::Up)];
+
+        fn adjust_preset(
+            keymap: &mut Keymap<Self>,
+            preset: KeymapPreset,
+        ) -> Result<(), KeymapError> {
+            if preset == KeymapPreset::Emacs {
+                keymap.rebind("main", Self::Palette, &["alt+x".to_string()])?;
+            }
+            Ok(())
+        }
+    }
+
+    const MODES: &[&str] = &["main"];
+
+    fn key(code: KeyCode, modifiers: KeyModifiers) -> KeyEvent {
+        KeyEvent::new(code, modifiers)
+    }
+
+    #[test]
+    fn chords_parse_modifiers_names_and_shifted_characters() {
+        let ctrl_p = KeyChord::parse("ctrl+p").expect("parse");
+        assert_eq!(
+            ctrl_p,
+            KeyChord::new(KeyCode::Char('p'), KeyModifiers::CONTROL)
+        );
+        assert_eq!(ctrl_p.to_string(), "C-p");
+        assert_eq!(
+            KeyChord::parse("shift+g").expect("parse"),
+            KeyChord::from_event(key(KeyCode::Char('G'), KeyModifiers::SHIFT))
+        );
+        assert_eq!(
+            KeyChord::parse("shift+tab").expect("parse").code,
+            KeyCode::BackTab
+        );
+        assert_eq!(
+            KeyChord::parse("space").expect("parse").to_string(),
+            "Space"
+        );
+        assert!(KeyChord::parse("hyper+x").is_err());
+        assert!(KeyChord::parse("nope").is_err());
+    }
+
+    #[test]
+    fn matcher_resolves_multi_key_sequences_and_retries_broken_prefixes() {
+        let keymap = Keymap::from_defaults(
+            MODES,
+            &[
+                ("main", Cmd::Top, &["g g"]),
+                ("main", Cmd::Palette, &[":", "ctrl+p"]),
+            ],
+        );
+        let mut matcher = KeySequenceMatcher::default();
+        let g = key(KeyCode::Char('g'), KeyModifiers::NONE);
+
+        assert_eq!(matcher.feed(&keymap, "main", g), KeyMatch::Pending);
+        assert_eq!(matcher.pending().as_deref(), Some("g"));
+        assert_eq!(
+            matcher.feed(&keymap, "main", g),
+            KeyMatch::Command(Cmd::Top)
+        );
+        assert_eq!(matcher.pending(), None);
+
+        assert_eq!(matcher.feed(&keymap, "main", g), KeyMatch::Pending);
+        assert_eq!(
+            matcher.feed(
+                &keymap,
+                "main",
+                key(KeyCode::Char('p'), KeyModifiers::CONTROL)
+            ),
+            KeyMatch::Command(Cmd::Palette)
+        );
+        assert_eq!(keymap.hint("main", Cmd::Palette).as_deref(), Some(":"));
+    }
+
+    #[test]
+    fn overrides_rebind_commands_and_conflicts_are_reported() {
+        let mut keymap = Keymap::from_defaults(
+            MODES,
+            &[("main", Cmd::Top, &["t"]), ("main", Cmd::Move, &["g"])],
+        );
+        let file = KeymapFile::parse(
+            "preset = \"vim\"\n[demo.main]\ntop = [\"g g\"]\n[other.main]\nmove = \"x\"\n",
+            "demo",
+        )
+        .expect("parse");
+        assert_eq!(file.preset.as_deref(), Some("vim"));
+        keymap.apply_overrides(&file.overrides).expect("apply");
+
+        let conflicts = keymap.conflicts();
+        assert_eq!(conflicts.len(), 1);
+        assert!(conflicts[0].shadows);
+        assert_eq!(
+            conflicts[0].to_string(),
+            "[main] `g` for move shadows a longer sequence bound to top"
+        );
+
+        keymap
+            .rebind("main", Cmd::Move, &["m".to_string()])
+            .expect("rebind");
+        assert!(keymap.validate().is_ok());
+        assert_eq!(keymap.hint("main", Cmd::Top).as_deref(), Some("g g"));
+
+        let unknown: toml::Table = toml::from_str("[main]\njump = \"j\"\n").expect("toml");
+        assert!(keymap.apply_overrides(&unknown).is_err());
+        let bad_mode: toml::Table = toml::from_str("[visual]\ntop = \"j\"\n").expect("toml");
+        assert!(keymap.apply_overrides(&bad_mode).is_err());
+    }
+
+    #[test]
+    fn presets_extend_navigable_modes_and_let_the_profile_adjust() {
+        let vim = parse_keymap::<Cmd>("preset = \"vim\"\n").expect("vim");
+        assert!(
+            vim.sequences("main", Cmd::Down)
+                .any(|keys| keys.to_string() == "j")
+        );
+        assert!(
+            vim.sequences("input", Cmd::Down)
+                .all(|keys| keys.to_string() != "j")
+        );
+
+        let emacs = parse_keymap::<Cmd>("preset = \"emacs\"\n[demo.input]\ndown = \"tab\"\n")
+            .expect("emacs");
+        assert_eq!(emacs.hint("main", Cmd::Up).as_deref(), Some("↑"));
+        assert_eq!(emacs.hint("main", Cmd::Palette).as_deref(), Some("M-x"));
+        assert_eq!(emacs.hint("input", Cmd::Down).as_deref(), Some("Tab"));
+
+        let error = parse_keymap::<Cmd>("preset = \"nano\"\n").expect_err("unknown preset");
+        assert!(error.to_string().contains("nano"), "{error}");
+    }
+}
@@ -8,6 +8,8 @@ pub mod action;
 pub mod component;
 pub mod components;
 pub mod event;
+pub mod keymap;
+pub mod runtime;
 pub mod theme;
 pub mod utils;
 
@@ -17,13 +19,26 @@ pub use component::{Component, ComponentResult, DynComponent};
 pub use components::{
     CardGridComponent, ChatComposerComponent, ChatComposerProps, ChatConversationHeaderComponent,
     ChatConversationHeaderProps, ChatMessageEntry, ChatMessageListComponent, ChatMessageListProps,
-    ChatMessageRole, ChatPalette, ChatStatusTone, FooterBar, FooterBarProps, KeyBind, KeyHintBar,
-    LabeledField, LoadingSpinner, PaneBlockComponent, PopupAnchor, PopupHit, PopupItem,
-    PopupOverlay, PopupOverlayProps, SectionHeader, StatusPill,
+    ChatMessageRole, ChatPalette, ChatStatusTone, ClearToastHistory, ColumnAlign, DataColumn,
+    DataTable, DataTableCommand, DataTableHit, FieldValue, FooterBar, FooterBarProps, Form,
+    FormCommand, FormField, FormOutcome, KeyBind, KeyHintBar, LabeledField, LoadingSpinner,
+    PaneBlockComponent, PopupAnchor, PopupHit, PopupItem, PopupOverlay, PopupOverlayProps,
+    SectionHeader, SelectOption, SortDirection, SortKind, StatusPill, Toast, ToastHistoryCommand,
+    ToastHistoryOverlay, ToastHit, ToastSeverity, ToastStack, Validator,
 };
 pub use event::Event;
-pub use theme::{ComponentTheme, ComponentThemeLike};
+pub use keymap::{
+    KeyChord, KeyMatch, KeySequence, KeySequenceMatcher, Keymap, KeymapCommand, KeymapConflict,
+    KeymapError, KeymapFile, KeymapPreset, KeymapProfile, apply_preset, default_keymap_path,
+    load_keymap, parse_keymap,
+};
+pub use runtime::{ComponentId, ComponentRuntime, Due, Layer, TickScheduler};
+pub use theme::{
+    ColorSupport, ComponentTheme, ComponentThemeLike, ThemeError, ThemeFile, ThemePreset,
+    ThemeSource, ThemeWatcher, default_theme_path,
+};
 pub use utils::compact::*;
+pub use utils::export::*;
 pub use utils::index::*;
 pub use utils::rect::*;
 pub use utils::resizable::*;
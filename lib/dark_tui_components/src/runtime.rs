@@ -0,0 +1,497 @@
+//! Component tree runtime: focus, event routing, an action bus and
+//! tick/render scheduling on top of [`Component`].
+//!
+//! Hosts mount components under stable ids, feed terminal events through
+//! [`ComponentRuntime::handle_event`], and act on the actions the runtime
+//! hands back (`Quit`, `StatusMessage`, `Custom`, ...). Panels added this way
+//! only need a mount call and a trigger; the host dispatcher stays unchanged.
+
+use std::any::Any;
+use std::collections::VecDeque;
+use std::time::{Duration, Instant};
+
+use crossterm::event::{MouseButton, MouseEvent, MouseEventKind};
+use ratatui::Frame;
+use ratatui::layout::{Rect, Size};
+use tokio::sync::mpsc::{UnboundedReceiver, UnboundedSender, unbounded_channel};
+
+use crate::action::Action;
+use crate::component::{Component, ComponentResult, DynComponent};
+use crate::event::Event;
+use crate::theme::ComponentThemeLike;
+use crate::utils::rect::rect_contains;
+
+/// Stable identifier for a mounted component.
+pub type ComponentId = &'static str;
+
+/// Upper bound on actions processed per dispatch, to break update cycles.
+const MAX_ACTIONS_PER_DISPATCH: usize = 256;
+
+/// Where a component lives in the tree.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Layer {
+    /// Always visible; takes part in `FocusNext`/`FocusPrevious`.
+    Base,
+    /// Hidden until shown; a visible overlay that wants focus is modal and
+    /// receives every key and mouse event until it is hidden. A left click
+    /// outside a placed modal overlay dismisses it.
+    Overlay,
+}
+
+struct Mounted {
+    id: ComponentId,
+    layer: Layer,
+    visible: bool,
+    area: Option<Rect>,
+    component: DynComponent,
+}
+
+/// Owns a set of components and drives their lifecycle.
+pub struct ComponentRuntime {
+    components: Vec<Mounted>,
+    base_focus: Option<ComponentId>,
+    action_tx: UnboundedSender<Action>,
+    action_rx: UnboundedReceiver<Action>,
+    size: Size,
+    render_requested: bool,
+}
+
+impl Default for ComponentRuntime {
+    fn default() -> Self {
+        let (action_tx, action_rx) = unbounded_channel();
+        Self {
+            components: Vec::new(),
+            base_focus: None,
+            action_tx,
+            action_rx,
+            size: Size::default(),
+            render_requested: false,
+        }
+    }
+}
+
+impl std::fmt::Debug for ComponentRuntime {
+    fn fmt(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        formatter
+            .debug_struct("ComponentRuntime")
+            .field(
+                "components",
+                &self
+                    .components
+                    .iter()
+                    .map(|mounted| (mounted.id, mounted.layer, mounted.visible))
+                    .collect::<Vec<_>>(),
+            )
+            .field("focused", &self.focused())
+            .finish()
+    }
+}
+
+impl ComponentRuntime {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Mounts a component: registers the action bus and initializes it for
+    /// the current size. Overlays start hidden.
+    pub fn mount(
+        &mut self,
+        id: ComponentId,
+        layer: Layer,
+        mut component: DynComponent,
+    ) -> ComponentResult {
+        if self.index_of(id).is_some() {
+            return Err(format!("component `{id}` is already mounted").into());
+        }
+
+        component.register_action_handler(self.action_tx.clone())?;
+        component.init(self.size)?;
+        self.components.push(Mounted {
+            id,
+            layer,
+            visible: layer == Layer::Base,
+            area: None,
+            component,
+        });
+
+        if layer == Layer::Base && self.base_focus.is_none() {
+            self.focus(id);
+        }
+        Ok(())
+    }
+
+    /// Sender other tasks can use to post actions onto the bus.
+    pub fn action_sender(&self) -> UnboundedSender<Action> {
+        self.action_tx.clone()
+    }
+
+    pub fn is_mounted(&self, id: ComponentId) -> bool {
+        self.index_of(id).is_some()
+    }
+
+    pub fn is_visible(&self, id: ComponentId) -> bool {
+        self.get(id).is_some_and(|mounted| mounted.visible)
+    }
+
+    /// Mounted component `id` as its concrete type; `None` when it is not
+    /// mounted or is another type. Lets hosts read state a component owns.
+    pub fn component<T: Component>(&self, id: ComponentId) -> Option<&T> {
+        let component: &dyn Any = self.get(id)?.component.as_ref();
+        component.downcast_ref()
+    }
+
+    /// Mutable [`Self::component`], for hosts that feed state in directly.
+    pub fn component_mut<T: Component>(&mut self, id: ComponentId) -> Option<&mut T> {
+        let index = self.index_of(id)?;
+        let component: &mut dyn Any = self.components[index].component.as_mut();
+        component.downcast_mut()
+    }
+
+    /// Shows a component; a focus-wanting overlay becomes the modal target.
+    pub fn show(&mut self, id: ComponentId) {
+        let Some(index) = self.index_of(id) else {
+            return;
+        };
+        self.set_focus_flags_off();
+        // Re-showing moves an overlay to the top of the stack.
+        let mut mounted = self.components.remove(index);
+        mounted.visible = true;
+        self.components.push(mounted);
+        self.sync_focus_flags();
+        self.render_requested = true;
+    }
+
+    /// Hides a component and hands focus back to what is underneath.
+    pub fn hide(&mut self, id: ComponentId) {
+        let Some(index) = self.index_of(id) else {
+            return;
+        };
+        if !self.components[index].visible {
+            return;
+        }
+        self.set_focus_flags_off();
+        self.components[index].visible = false;
+        if self.base_focus == Some(id) {
+            self.base_focus = None;
+        }
+        self.sync_focus_flags();
+        self.render_requested = true;
+    }
+
+    pub fn toggle(&mut self, id: ComponentId) {
+        if self.is_visible(id) {
+            self.hide(id);
+        } else {
+            self.show(id);
+        }
+    }
+
+    /// Topmost visible overlay that wants focus, if any.
+    pub fn modal(&self) -> Option<ComponentId> {
+        self.components
+            .iter()
+            .rev()
+            .find(|mounted| {
+                mounted.layer == Layer::Overlay
+                    && mounted.visible
+                    && mounted.component.wants_focus()
+            })
+            .map(|mounted| mounted.id)
+    }
+
+    /// Size from the last [`Event::Resize`].
+    pub fn size(&self) -> Size {
+        self.size
+    }
+
+    /// Component that receives key events: the modal overlay, else the
+    /// focused base component.
+    pub fn focused(&self) -> Option<ComponentId> {
+        self.modal().or(self.base_focus)
+    }
+
+    /// Focused base component, which gets keys again once no overlay is modal.
+    pub fn base_focus(&self) -> Option<ComponentId> {
+        self.base_focus
+    }
+
+    /// Focuses a visible base component.
+    pub fn focus(&mut self, id: ComponentId) {
+        let focusable = self
+            .get(id)
+            .is_some_and(|mounted| mounted.layer == Layer::Base && mounted.visible);
+        if !focusable {
+            return;
+        }
+        self.set_focus_flags_off();
+        self.base_focus = Some(id);
+        self.sync_focus_flags();
+    }
+
+    pub fn focus_next(&mut self) {
+        self.cycle_focus(true);
+    }
+
+    pub fn focus_previous(&mut self) {
+        self.cycle_focus(false);
+    }
+
+    /// Sets the area a component is drawn into and hit-tested against.
+    /// Unplaced components use the full area passed to [`Self::draw`].
+    pub fn place(&mut self, id: ComponentId, area: Rect) {
+        if let Some(index) = self.index_of(id) {
+            self.components[index].area = Some(area);
+        }
+    }
+
+    /// Routes an event and processes the resulting actions. Returns the
+    /// actions the runtime did not consume, for the host to act on.
+    pub fn handle_event(&mut self, event: &Event) -> ComponentResult<Vec<Action>> {
+        let mut queue = VecDeque::new();
+
+        match event {
+            Event::Key(_) => {
+                if let Some(index) = self.focused().and_then(|id| self.index_of(id)) {
+                    let mounted = &mut self.components[index];
+                    if let Some(action) = mounted.component.handle_event(event)? {
+                        queue.push_back((Some(mounted.id), action));
+                    }
+                }
+            }
+            Event::Mouse(mouse) if self.outside_placed_modal(mouse) => {
+                // A click outside a placed modal dismisses it; the rest of the
+                // screen stays inert while it is open.
+                if matches!(mouse.kind, MouseEventKind::Down(MouseButton::Left)) {
+                    queue.push_back((self.modal(), Action::Cancel));
+                }
+            }
+            Event::Mouse(mouse) => {
+                if let Some(index) = self.mouse_target(mouse) {
+                    let mounted = &mut self.components[index];
+                    if let Some(action) = mounted.component.handle_event(event)? {
+                        queue.push_back((Some(mounted.id), action));
+                    }
+                }
+            }
+            Event::Tick | Event::Resize(_, _) => {
+                if let Event::Resize(width, height) = event {
+                    self.size = Size::new(*width, *height);
+                    self.render_requested = true;
+                }
+                for mounted in &mut self.components {
+                    if let Some(action) = mounted.component.handle_event(event)? {
+                        queue.push_back((Some(mounted.id), action));
+                    }
+                }
+            }
+        }
+
+        self.process(queue)
+    }
+
+    /// Posts an action onto the bus and processes it.
+    pub fn dispatch(&mut self, action: Action) -> ComponentResult<Vec<Action>> {
+        self.process(VecDeque::from([(None, action)]))
+    }
+
+    /// Processes actions sent through registered handlers since the last call.
+    pub fn drain(&mut self) -> ComponentResult<Vec<Action>> {
+        self.process(VecDeque::new())
+    }
+
+    /// Whether a component asked for a redraw since the last call.
+    pub fn take_render_request(&mut self) -> bool {
+        std::mem::take(&mut self.render_requested)
+    }
+
+    /// Draws visible components bottom to top.
+    pub fn draw(
+        &self,
+        frame: &mut Frame,
+        area: Rect,
+        theme: &dyn ComponentThemeLike,
+    ) -> ComponentResult {
+        for mounted in self.components.iter().filter(|mounted| mounted.visible) {
+            mounted
+                .component
+                .draw(frame, mounted.area.unwrap_or(area), theme)?;
+        }
+        Ok(())
+    }
+
+    fn process(
+        &mut self,
+        mut queue: VecDeque<(Option<ComponentId>, Action)>,
+    ) -> ComponentResult<Vec<Action>> {
+        let mut outbound = Vec::new();
+        let mut processed = 0usize;
+
+        loop {
+            while let Ok(action) = self.action_rx.try_recv() {
+                queue.push_back((None, action));
+            }
+            let Some((source, action)) = queue.pop_front() else {
+                break;
+            };
+
+            processed += 1;
+            if processed > MAX_ACTIONS_PER_DISPATCH {
+                return Err(format!(
+                    "component runtime processed more than {MAX_ACTIONS_PER_DISPATCH} actions; \
+                     likely an update cycle"
+                )
+                .into());
+            }
+
+            match action {
+                Action::Noop => {}
+                Action::Render => self.render_requested = true,
+                Action::FocusNext => self.focus_next(),
+                Action::FocusPrevious => self.focus_previous(),
+                // An overlay answering with `Cancel` dismisses itself.
+                Action::Cancel if source.is_some_and(|id| self.is_overlay(id)) => {
+                    if let Some(id) = source {
+                        self.hide(id);
+                    }
+                }
+                action => {
+                    for mounted in &mut self.components {
+                        if let Some(follow_up) = mounted.component.update(&action)? {
+                            queue.push_back((Some(mounted.id), follow_up));
+                        }
+                    }
+                    outbound.push(action);
+                }
+            }
+        }
+
+        Ok(outbound)
+    }
+
+    fn outside_placed_modal(&self, mouse: &MouseEvent) -> bool {
+        self.modal()
+            .and_then(|id| self.get(id))
+            .and_then(|mounted| mounted.area)
+            .is_some_and(|area| !rect_contains(area, mouse.column, mouse.row))
+    }
+
+    fn mouse_target(&self, mouse: &MouseEvent) -> Option<usize> {
+        if let Some(modal) = self.modal() {
+            return self.index_of(modal);
+        }
+        self.components
+            .iter()
+            .enumerate()
+            .rev()
+            .filter(|(_, mounted)| mounted.visible)
+            .find(|(_, mounted)| {
+                mounted
+                    .area
+                    .is_some_and(|area| rect_contains(area, mouse.column, mouse.row))
+            })
+            .map(|(index, _)| index)
+    }
+
+    fn cycle_focus(&mut self, forward: bool) {
+        let order: Vec<ComponentId> = self
+            .components
+            .iter()
+            .filter(|mounted| {
+                mounted.layer == Layer::Base && mounted.visible && mounted.component.wants_focus()
+            })
+            .map(|mounted| mounted.id)
+            .collect();
+        if order.is_empty() {
+            return;
+        }
+
+        let current = self
+            .base_focus
+            .and_then(|id| order.iter().position(|candidate| *candidate == id));
+        let next = match (current, forward) {
+            (Some(index), true) => (index + 1) % order.len(),
+            (Some(index), false) => (index + order.len() - 1) % order.len(),
+            (None, true) => 0,
+            (None, false) => order.len() - 1,
+        };
+        self.focus(order[next]);
+    }
+
+    fn set_focus_flags_off(&mut self) {
+        if let Some(index) = self.focused().and_then(|id| self.index_of(id)) {
+            self.components[index].component.set_focused(false);
+        }
+    }
+
+    fn sync_focus_flags(&mut self) {
+        if let Some(index) = self.focused().and_then(|id| self.index_of(id)) {
+            self.components[index].component.set_focused(true);
+        }
+    }
+
+    fn is_overlay(&self, id: ComponentId) -> bool {
+        self.get(id)
+            .is_some_and(|mounted| mounted.layer == Layer::Overlay)
+    }
+
+    fn get(&self, id: ComponentId) -> Option<&Mounted> {
+        self.components.iter().find(|mounted| mounted.id == id)
+    }
+
+    fn index_of(&self, id: ComponentId) -> Option<usize> {
+        self.components.iter().position(|mounted| mounted.id == id)
+    }
+}
+
+/// Which scheduled work is due after a poll.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Due {
+    pub tick: bool,
+    pub render: bool,
+}
+
+/// Fixed-rate tick and frame deadlines for a host event loop.
+#[derive(Debug, Clone)]
+pub struct TickScheduler {
+    tick_interval: Duration,
+    frame_interval: Duration,
+    next_tick: Instant,
+    next_frame: Instant,
+}
+
+impl TickScheduler {
+    pub fn new(tick_interval: Duration, frame_interval: Duration) -> Self {
+        Self::starting_at(Instant::now(), tick_interval, frame_interval)
+    }
+
+    pub fn starting_at(now: Instant, tick_interval: Duration, frame_interval: Duration) -> Self {
+        Self {
+            tick_interval,
+            frame_interval,
+            next_tick: now + tick_interval,
+            next_frame: now,
+        }
+    }
+
+    /// How long the host may block waiting for input.
+    pub fn poll_timeout(&self, now: Instant) -> Duration {
+        self.next_tick
+            .min(self.next_frame)
+            .saturating_duration_since(now)
+    }
+
+    /// Reports due work and moves the passed deadlines forward. Missed
+    /// intervals collapse into one tick instead of bursting.
+    pub fn advance(&mut self, now: Instant) -> Due {
+        let mut due = Due::default();
+        if now >= self.next_tick {
+            due.tick = true;
+            self.next_tick = now + self.tick_interval;
+        }
+        if now >= self.next_frame {
+            due.render = true;
+            self.next_frame = now + self.frame_interval;
+        }
+        due
+    }
+}
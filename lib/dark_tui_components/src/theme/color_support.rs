@@ -0,0 +1,229 @@
+use ratatui::style::Color;
+
+/// How many colors the terminal can display.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ColorSupport {
+    #[default]
+    TrueColor,
+    Ansi256,
+    Ansi16,
+    NoColor,
+}
+
+/// The 16 ANSI colors with their usual xterm RGB values.
+const ANSI16: [(Color, (u8, u8, u8)); 16] = [
+    (Color::Black, (0, 0, 0)),
+    (Color::Red, (205, 0, 0)),
+    (Color::Green, (0, 205, 0)),
+    (Color::Yellow, (205, 205, 0)),
+    (Color::Blue, (0, 0, 238)),
+    (Color::Magenta, (205, 0, 205)),
+    (Color::Cyan, (0, 205, 205)),
+    (Color::Gray, (229, 229, 229)),
+    (Color::DarkGray, (127, 127, 127)),
+    (Color::LightRed, (255, 0, 0)),
+    (Color::LightGreen, (0, 255, 0)),
+    (Color::LightYellow, (255, 255, 0)),
+    (Color::LightBlue, (92, 92, 255)),
+    (Color::LightMagenta, (255, 0, 255)),
+    (Color::LightCyan, (0, 255, 255)),
+    (Color::White, (255, 255, 255)),
+];
+
+impl ColorSupport {
+    /// Detects support from `NO_COLOR`, `COLORTERM` and `TERM`.
+    pub fn detect() -> Self {
+        let var = |name| std::env::var(name).ok();
+        Self::from_env(
+            var("NO_COLOR").as_deref(),
+            var("COLORTERM").as_deref(),
+            var("TERM").as_deref(),
+        )
+    }
+
+    /// Pure form of [`Self::detect`], for callers that already hold the values.
+    pub fn from_env(no_color: Option<&str>, colorterm: Option<&str>, term: Option<&str>) -> Self {
+        if no_color.is_some_and(|value| !value.is_empty()) {
+            return ColorSupport::NoColor;
+        }
+
+        let term = term.unwrap_or("").to_ascii_lowercase();
+        if term == "dumb" {
+            return ColorSupport::NoColor;
+        }
+
+        let colorterm = colorterm.unwrap_or("").to_ascii_lowercase();
+        if colorterm == "truecolor"
+            || colorterm == "24bit"
+            || term.is_empty()
+            || term.contains("truecolor")
+            || term.contains("direct")
+        {
+            return ColorSupport::TrueColor;
+        }
+        if term.contains("256color") {
+            return ColorSupport::Ansi256;
+        }
+        ColorSupport::Ansi16
+    }
+
+    /// Parses `auto`, `truecolor`, `256`, `16` or `none` as used in theme files.
+    ///
+    /// `auto` resolves to `None` so the caller can fall back to [`Self::detect`].
+    pub fn from_name(name: &str) -> Result<Option<Self>, String> {
+        match name.trim().to_ascii_lowercase().as_str() {
+            "auto" => Ok(None),
+            "truecolor" | "24bit" => Ok(Some(ColorSupport::TrueColor)),
+            "256" | "ansi256" => Ok(Some(ColorSupport::Ansi256)),
+            "16" | "ansi16" => Ok(Some(ColorSupport::Ansi16)),
+            "none" | "no-color" | "mono" => Ok(Some(ColorSupport::NoColor)),
+            other => Err(format!(
+                "unknown colors value '{other}' (expected auto, truecolor, 256, 16 or none)"
+            )),
+        }
+    }
+
+    /// Maps a color onto the closest one this terminal can show.
+    pub fn adapt(self, color: Color) -> Color {
+        match (self, color) {
+            (ColorSupport::TrueColor, color) => color,
+            (ColorSupport::NoColor, _) => Color::Reset,
+            (ColorSupport::Ansi256, Color::Rgb(r, g, b)) => Color::Indexed(rgb_to_256(r, g, b)),
+            (ColorSupport::Ansi16, Color::Rgb(r, g, b)) => nearest_ansi16(r, g, b),
+            (ColorSupport::Ansi16, Color::Indexed(index)) => {
+                let (r, g, b) = indexed_to_rgb(index);
+                nearest_ansi16(r, g, b)
+            }
+            (_, color) => color,
+        }
+    }
+}
+
+const CUBE_STEPS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+
+/// RGB value a terminal would typically show for `color`; `None` for `Reset`.
+pub(crate) fn color_to_rgb(color: Color) -> Option<(u8, u8, u8)> {
+    match color {
+        Color::Reset => None,
+        Color::Rgb(r, g, b) => Some((r, g, b)),
+        Color::Indexed(index) => Some(indexed_to_rgb(index)),
+        named => ANSI16
+            .iter()
+            .find(|(color, _)| *color == named)
+            .map(|(_, rgb)| *rgb),
+    }
+}
+
+fn rgb_to_256(r: u8, g: u8, b: u8) -> u8 {
+    let cube_index = |value: u8| -> usize {
+        CUBE_STEPS
+            .iter()
+            .enumerate()
+            .min_by_key(|(_, step)| (i32::from(**step) - i32::from(value)).abs())
+            .map(|(index, _)| index)
+            .unwrap_or(0)
+    };
+    let (ri, gi, bi) = (cube_index(r), cube_index(g), cube_index(b));
+    let cube = (CUBE_STEPS[ri], CUBE_STEPS[gi], CUBE_STEPS[bi]);
+
+    let average = (u32::from(r) + u32::from(g) + u32::from(b)) / 3;
+    let gray_index = (average.saturating_sub(3) / 10).min(23) as u8;
+    let gray_level = 8 + 10 * gray_index;
+    let gray = (gray_level, gray_level, gray_level);
+
+    if distance((r, g, b), gray) < distance((r, g, b), cube) {
+        232 + gray_index
+    } else {
+        16 + (36 * ri + 6 * gi + bi) as u8
+    }
+}
+
+fn indexed_to_rgb(index: u8) -> (u8, u8, u8) {
+    match index {
+        0..=15 => ANSI16[usize::from(index)].1,
+        16..=231 => {
+            let offset = usize::from(index - 16);
+            (
+                CUBE_STEPS[offset / 36],
+                CUBE_STEPS[(offset / 6) % 6],
+                CUBE_STEPS[offset % 6],
+            )
+        }
+        _ => {
+            let level = 8 + 10 * (index - 232);
+            (level, level, level)
+        }
+    }
+}
+
+fn nearest_ansi16(r: u8, g: u8, b: u8) -> Color {
+    ANSI16
+        .iter()
+        .min_by_key(|(_, rgb)| distance((r, g, b), *rgb))
+        .map(|(color, _)| *color)
+        .unwrap_or(Color::Reset)
+}
+
+fn distance(a: (u8, u8, u8), b: (u8, u8, u8)) -> u32 {
+    let channel = |x: u8, y: u8| (i32::from(x) - i32::from(y)).unsigned_abs().pow(2);
+    channel(a.0, b.0) + channel(a.1, b.1) + channel(a.2, b.2)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn detection_honours_no_color_and_term() {
+        assert_eq!(
+            ColorSupport::from_env(Some("1"), Some("truecolor"), Some("xterm-256color")),
+            ColorSupport::NoColor
+        );
+        assert_eq!(
+            ColorSupport::from_env(None, None, Some("dumb")),
+            ColorSupport::NoColor
+        );
+        assert_eq!(
+            ColorSupport::from_env(Some(""), Some("24bit"), Some("xterm")),
+            ColorSupport::TrueColor
+        );
+        assert_eq!(
+            ColorSupport::from_env(None, None, Some("xterm-direct")),
+            ColorSupport::TrueColor
+        );
+        assert_eq!(
+            ColorSupport::from_env(None, None, Some("alacritty-truecolor")),
+            ColorSupport::TrueColor
+        );
+        assert_eq!(
+            ColorSupport::from_env(None, None, Some("screen-256color")),
+            ColorSupport::Ansi256
+        );
+        assert_eq!(
+            ColorSupport::from_env(None, None, Some("linux")),
+            ColorSupport::Ansi16
+        );
+    }
+
+    #[test]
+    fn rgb_maps_to_cube_grayscale_and_ansi16() {
+        assert_eq!(
+            ColorSupport::Ansi256.adapt(Color::Rgb(255, 0, 0)),
+            Color::Indexed(196)
+        );
+        assert_eq!(
+            ColorSupport::Ansi256.adapt(Color::Rgb(128, 128, 128)),
+            Color::Indexed(244)
+        );
+        assert_eq!(
+            ColorSupport::Ansi16.adapt(Color::Rgb(240, 20, 10)),
+            Color::LightRed
+        );
+        assert_eq!(
+            ColorSupport::Ansi16.adapt(Color::Indexed(196)),
+            Color::LightRed
+        );
+        assert_eq!(ColorSupport::Ansi16.adapt(Color::Cyan), Color::Cyan);
+        assert_eq!(ColorSupport::NoColor.adapt(Color::Cyan), Color::Reset);
+    }
+}
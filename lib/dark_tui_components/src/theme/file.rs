@@ -0,0 +1,330 @@
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+use ratatui::style::Color;
+
+use super::{ColorSupport, ComponentTheme, ThemePreset};
+
+/// Error raised while reading or parsing a theme file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ThemeError {
+    message: String,
+}
+
+impl ThemeError {
+    pub fn new(message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+        }
+    }
+}
+
+impl fmt::Display for ThemeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+impl std::error::Error for ThemeError {}
+
+/// Parsed theme TOML: a base preset, a color-support override and per-color
+/// overrides grouped by section (`[pill]`, `[text]`, ...).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ThemeFile {
+    pub preset: ThemePreset,
+    /// `None` means detect from the terminal (`colors = "auto"` or unset).
+    pub colors: Option<ColorSupport>,
+    /// Truecolor palette with the file's overrides applied.
+    pub palette: ComponentTheme,
+}
+
+impl ThemeFile {
+    pub fn parse(text: &str) -> Result<Self, ThemeError> {
+        let mut table: toml::Table =
+            toml::from_str(text).map_err(|error| ThemeError::new(error.to_string()))?;
+
+        let preset = match table.remove("preset") {
+            None => ThemePreset::default(),
+            Some(toml::Value::String(name)) => ThemePreset::from_name(&name)
+                .ok_or_else(|| ThemeError::new(format!("unknown preset `{name}`")))?,
+            Some(_) => return Err(ThemeError::new("`preset` must be a string")),
+        };
+        let colors = match table.remove("colors") {
+            None => None,
+            Some(toml::Value::String(name)) => {
+                ColorSupport::from_name(&name).map_err(ThemeError::new)?
+            }
+            Some(_) => return Err(ThemeError::new("`colors` must be a string")),
+        };
+
+        let mut palette = preset.theme();
+        for (section, values) in &table {
+            let values = values.as_table().ok_or_else(|| {
+                ThemeError::new(format!("[{section}] must be a table of name = color"))
+            })?;
+            for (key, value) in values {
+                let color = parse_color(value)
+                    .map_err(|error| ThemeError::new(format!("[{section}] {key}: {error}")))?;
+                let mut slots = palette
+                    .slots_mut()
+                    .into_iter()
+                    .filter(|(slot_section, _, _)| slot_section == section)
+                    .peekable();
+                if slots.peek().is_none() {
+                    return Err(ThemeError::new(format!("unknown section [{section}]")));
+                }
+                let (_, _, slot) = slots
+                    .find(|(_, slot_key, _)| slot_key == key)
+                    .ok_or_else(|| ThemeError::new(format!("[{section}] unknown color `{key}`")))?;
+                *slot = color;
+            }
+        }
+
+        Ok(Self {
+            preset,
+            colors,
+            palette,
+        })
+    }
+
+    pub fn load(path: &Path) -> Result<Self, ThemeError> {
+        let text = std::fs::read_to_string(path).map_err(|error| {
+            ThemeError::new(format!("could not read {}: {error}", path.display()))
+        })?;
+        Self::parse(&text).map_err(|error| ThemeError::new(format!("{}: {error}", path.display())))
+    }
+
+    /// Palette reduced to the file's color setting, or the detected one.
+    pub fn theme(&self) -> ComponentTheme {
+        let support = self.colors.unwrap_or_else(ColorSupport::detect);
+        self.palette.clone().with_color_support(support)
+    }
+}
+
+/// Parses `[r, g, b]`, `"#rrggbb"` or a named ANSI color such as `"light_cyan"`.
+fn parse_color(value: &toml::Value) -> Result<Color, String> {
+    match value {
+        toml::Value::Array(items) => {
+            let channels = items
+                .iter()
+                .map(|item| item.as_integer().and_then(|value| u8::try_from(value).ok()))
+                .collect::<Option<Vec<_>>>();
+            match channels.as_deref() {
+                Some(&[r, g, b]) => Ok(Color::Rgb(r, g, b)),
+                _ => Err("expected [r, g, b] with values 0-255".to_string()),
+            }
+        }
+        toml::Value::String(text) => parse_color_name(text),
+        _ => Err("expected [r, g, b] or a color name".to_string()),
+    }
+}
+
+fn parse_color_name(text: &str) -> Result<Color, String> {
+    if let Some(hex) = text.strip_prefix('#') {
+        let channel = |range| {
+            hex.get(range)
+                .and_then(|digits| u8::from_str_radix(digits, 16).ok())
+        };
+        return match (hex.len(), channel(0..2), channel(2..4), channel(4..6)) {
+            (6, Some(r), Some(g), Some(b)) => Ok(Color::Rgb(r, g, b)),
+            _ => Err(format!("invalid hex color `{text}`")),
+        };
+    }
+
+    let color = match text.to_ascii_lowercase().replace('_', "").as_str() {
+        "black" => Color::Black,
+        "red" => Color::Red,
+        "green" => Color::Green,
+        "yellow" => Color::Yellow,
+        "blue" => Color::Blue,
+        "magenta" => Color::Magenta,
+        "cyan" => Color::Cyan,
+        "gray" | "grey" => Color::Gray,
+        "darkgray" | "darkgrey" => Color::DarkGray,
+        "lightred" => Color::LightRed,
+        "lightgreen" => Color::LightGreen,
+        "lightyellow" => Color::LightYellow,
+        "lightblue" => Color::LightBlue,
+        "lightmagenta" => Color::LightMagenta,
+        "lightcyan" => Color::LightCyan,
+        "white" => Color::White,
+        "reset" => Color::Reset,
+        _ => return Err(format!("unknown color `{text}`")),
+    };
+    Ok(color)
+}
+
+/// `$HOME/.darkfactory/theme.toml`, shared by every frontend.
+pub fn default_theme_path() -> Option<PathBuf> {
+    std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".darkfactory").join("theme.toml"))
+}
+
+/// Where a frontend's theme comes from: a built-in preset or a TOML file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ThemeSource {
+    Preset(ThemePreset),
+    File(PathBuf),
+}
+
+impl ThemeSource {
+    /// Picks the theme: an explicit preset or path, else `~/.darkfactory/theme.toml`,
+    /// else a `themes/default.toml` next to the working directory or executable,
+    /// else the dark preset.
+    pub fn resolve(explicit: Option<&str>) -> Self {
+        if let Some(spec) = explicit {
+            return Self::from_spec(spec);
+        }
+
+        let exe_dir = std::env::current_exe()
+            .ok()
+            .and_then(|path| path.parent().map(|dir| dir.to_path_buf()));
+        [
+            default_theme_path(),
+            Some(PathBuf::from("themes/default.toml")),
+            exe_dir.map(|dir| dir.join("themes/default.toml")),
+        ]
+        .into_iter()
+        .flatten()
+        .find(|path| path.exists())
+        .map(ThemeSource::File)
+        .unwrap_or(ThemeSource::Preset(ThemePreset::Dark))
+    }
+
+    /// A preset name such as `solarized`, otherwise a path to a theme file.
+    pub fn from_spec(spec: &str) -> Self {
+        match ThemePreset::from_name(spec) {
+            Some(preset) => ThemeSource::Preset(preset),
+            None => ThemeSource::File(PathBuf::from(spec)),
+        }
+    }
+
+    pub fn load(&self) -> Result<ComponentTheme, ThemeError> {
+        match self {
+            ThemeSource::Preset(preset) => {
+                Ok(preset.theme().with_color_support(ColorSupport::detect()))
+            }
+            ThemeSource::File(path) => ThemeFile::load(path).map(|file| file.theme()),
+        }
+    }
+
+    /// Truecolor palette without terminal fallbacks, e.g. for image exports.
+    pub fn palette(&self) -> Result<ComponentTheme, ThemeError> {
+        match self {
+            ThemeSource::Preset(preset) => Ok(preset.theme()),
+            ThemeSource::File(path) => ThemeFile::load(path).map(|file| file.palette),
+        }
+    }
+
+    /// Watcher for file sources; presets never change.
+    pub fn watcher(&self) -> Option<ThemeWatcher> {
+        match self {
+            ThemeSource::Preset(_) => None,
+            ThemeSource::File(path) => Some(ThemeWatcher::new(path.clone())),
+        }
+    }
+}
+
+/// Polls a theme file's modification time so frontends can hot reload it
+/// from their tick loop.
+#[derive(Debug, Clone)]
+pub struct ThemeWatcher {
+    path: PathBuf,
+    modified: Option<SystemTime>,
+}
+
+impl ThemeWatcher {
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        let path = path.into();
+        let modified = modified_at(&path);
+        Self { path, modified }
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Reloads the theme when the file changed since the last poll.
+    ///
+    /// Returns `None` while the file is unchanged or missing.
+    pub fn poll(&mut self) -> Option<Result<ComponentTheme, ThemeError>> {
+        let modified = modified_at(&self.path);
+        if modified == self.modified {
+            return None;
+        }
+        self.modified = modified;
+        modified?;
+        Some(ThemeFile::load(&self.path).map(|file| file.theme()))
+    }
+}
+
+fn modified_at(path: &Path) -> Option<SystemTime> {
+    std::fs::metadata(path)
+        .and_then(|metadata| metadata.modified())
+        .ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_preset_colors_and_overrides() {
+        let file = ThemeFile::parse(
+            r##"
+            preset = "solarized"
+            colors = "16"
+
+            [pill]
+            ok_fg = [1, 2, 3]
+
+            [text]
+            primary = "#ff8000"
+            error = "light_red"
+            "##,
+        )
+        .expect("theme file");
+
+        assert_eq!(file.preset, ThemePreset::Solarized);
+        assert_eq!(file.colors, Some(ColorSupport::Ansi16));
+        assert_eq!(file.palette.pill_ok_fg, Color::Rgb(1, 2, 3));
+        assert_eq!(file.palette.text_primary, Color::Rgb(255, 128, 0));
+        assert_eq!(file.palette.text_error, Color::LightRed);
+        assert_eq!(
+            file.palette.pane_focused_border,
+            ThemePreset::Solarized.theme().pane_focused_border
+        );
+        assert_eq!(file.theme().pill_ok_fg, Color::Black);
+    }
+
+    #[test]
+    fn rejects_unknown_sections_keys_and_values() {
+        let error = |text: &str| ThemeFile::parse(text).expect_err(text).to_string();
+
+        assert_eq!(error("preset = \"neon\""), "unknown preset `neon`");
+        assert_eq!(
+            error("[pill]\nsparkle = \"red\""),
+            "[pill] unknown color `sparkle`"
+        );
+        assert_eq!(error("[nope]\nok_fg = \"red\""), "unknown section [nope]");
+        assert_eq!(
+            error("[text]\nprimary = \"chartreuse\""),
+            "[text] primary: unknown color `chartreuse`"
+        );
+        assert_eq!(
+            error("[text]\nprimary = [1, 2, 300]"),
+            "[text] primary: expected [r, g, b] with values 0-255"
+        );
+    }
+    #[test]
+    fn explicit_spec_selects_preset_or_file() {
+        assert_eq!(
+            ThemeSource::resolve(Some("high-contrast")),
+            ThemeSource::Preset(ThemePreset::HighContrast)
+        );
+        assert_eq!(
+            ThemeSource::resolve(Some("/tmp/mine.toml")),
+            ThemeSource::File(PathBuf::from("/tmp/mine.toml"))
+        );
+    }
+}
@@ -0,0 +1,270 @@
+//! Theme model shared by every frontend: presets, TOML files, terminal color
+//! fallbacks and hot reload.
+
+mod color_support;
+mod file;
+mod presets;
+
+use ratatui::style::Color;
+
+pub use color_support::ColorSupport;
+pub(crate) use color_support::color_to_rgb;
+pub use file::{ThemeError, ThemeFile, ThemeSource, ThemeWatcher, default_theme_path};
+pub use presets::ThemePreset;
+
+/// Every semantic color used across the TUIs.
+///
+/// Built from a [`ThemePreset`], optionally overridden by a [`ThemeFile`], then
+/// reduced to what the terminal can show with [`Self::with_color_support`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ComponentTheme {
+    // -- StatusPill colors --
+    pub pill_ok_fg: Color,
+    pub pill_ok_bg: Color,
+    pub pill_warn_fg: Color,
+    pub pill_warn_bg: Color,
+    pub pill_err_fg: Color,
+    pub pill_err_bg: Color,
+    pub pill_info_fg: Color,
+    pub pill_info_bg: Color,
+    pub pill_muted_fg: Color,
+    pub pill_muted_bg: Color,
+    pub pill_accent_fg: Color,
+    pub pill_accent_bg: Color,
+
+    // -- KeyHintBar colors --
+    pub key_hint_key_fg: Color,
+    pub key_hint_key_bg: Color,
+    pub key_hint_action_fg: Color,
+    pub key_hint_bracket_fg: Color,
+
+    // -- Entity identity colors (product, variant, actor) --
+    pub entity_product: Color,
+    pub entity_variant: Color,
+    pub entity_actor: Color,
+
+    // -- Pane border colors --
+    pub pane_focused_border: Color,
+    pub pane_unfocused_border: Color,
+
+    // -- Table header/highlight colors --
+    pub table_header_fg: Color,
+    pub table_highlight_fg: Color,
+    pub table_highlight_bg_product: Color,
+    pub table_highlight_bg_variant: Color,
+    pub table_highlight_bg_actor: Color,
+
+    // -- Catalog tree colors --
+    pub catalog_connector: Color,
+
+    // -- General text colors --
+    pub text_primary: Color,
+    pub text_secondary: Color,
+    pub text_muted: Color,
+    pub text_error: Color,
+    pub text_status_normal: Color,
+
+    // -- Header/Footer panel borders --
+    pub header_border: Color,
+    pub footer_border: Color,
+}
+
+impl Default for ComponentTheme {
+    fn default() -> Self {
+        ThemePreset::Dark.theme()
+    }
+}
+
+impl ComponentTheme {
+    /// Reduces colors to what the terminal supports.
+    pub fn with_color_support(mut self, support: ColorSupport) -> Self {
+        for (_, _, color) in self.slots_mut() {
+            *color = support.adapt(*color);
+        }
+        self
+    }
+
+    /// `(section, key, color)` for every color, as named in theme files.
+    pub(crate) fn slots_mut(&mut self) -> [(&'static str, &'static str, &mut Color); 34] {
+        [
+            ("pill", "ok_fg", &mut self.pill_ok_fg),
+            ("pill", "ok_bg", &mut self.pill_ok_bg),
+            ("pill", "warn_fg", &mut self.pill_warn_fg),
+            ("pill", "warn_bg", &mut self.pill_warn_bg),
+            ("pill", "err_fg", &mut self.pill_err_fg),
+            ("pill", "err_bg", &mut self.pill_err_bg),
+            ("pill", "info_fg", &mut self.pill_info_fg),
+            ("pill", "info_bg", &mut self.pill_info_bg),
+            ("pill", "muted_fg", &mut self.pill_muted_fg),
+            ("pill", "muted_bg", &mut self.pill_muted_bg),
+            ("pill", "accent_fg", &mut self.pill_accent_fg),
+            ("pill", "accent_bg", &mut self.pill_accent_bg),
+            ("key_hint", "key_fg", &mut self.key_hint_key_fg),
+            ("key_hint", "key_bg", &mut self.key_hint_key_bg),
+            ("key_hint", "action_fg", &mut self.key_hint_action_fg),
+            ("key_hint", "bracket_fg", &mut self.key_hint_bracket_fg),
+            ("entity", "product", &mut self.entity_product),
+            ("entity", "variant", &mut self.entity_variant),
+            ("entity", "actor", &mut self.entity_actor),
+            ("pane", "focused_border", &mut self.pane_focused_border),
+            ("pane", "unfocused_border", &mut self.pane_unfocused_border),
+            ("table", "header_fg", &mut self.table_header_fg),
+            ("table", "highlight_fg", &mut self.table_highlight_fg),
+            (
+                "table",
+                "highlight_bg_product",
+                &mut self.table_highlight_bg_product,
+            ),
+            (
+                "table",
+                "highlight_bg_variant",
+                &mut self.table_highlight_bg_variant,
+            ),
+            (
+                "table",
+                "highlight_bg_actor",
+                &mut self.table_highlight_bg_actor,
+            ),
+            ("catalog", "connector", &mut self.catalog_connector),
+            ("text", "primary", &mut self.text_primary),
+            ("text", "secondary", &mut self.text_secondary),
+            ("text", "muted", &mut self.text_muted),
+            ("text", "error", &mut self.text_error),
+            ("text", "status_normal", &mut self.text_status_normal),
+            ("header", "border", &mut self.header_border),
+            ("footer", "border", &mut self.footer_border),
+        ]
+    }
+}
+
+/// Theme contract consumed by shared components.
+pub trait ComponentThemeLike {
+    /// Foreground color for success pills.
+    fn pill_ok_fg(&self) -> Color;
+    /// Background color for success pills.
+    fn pill_ok_bg(&self) -> Color;
+    /// Foreground color for warning pills.
+    fn pill_warn_fg(&self) -> Color;
+    /// Background color for warning pills.
+    fn pill_warn_bg(&self) -> Color;
+    /// Foreground color for error pills.
+    fn pill_err_fg(&self) -> Color;
+    /// Background color for error pills.
+    fn pill_err_bg(&self) -> Color;
+    /// Foreground color for info pills.
+    fn pill_info_fg(&self) -> Color;
+    /// Background color for info pills.
+    fn pill_info_bg(&self) -> Color;
+    /// Foreground color for muted pills.
+    fn pill_muted_fg(&self) -> Color;
+    /// Background color for muted pills.
+    fn pill_muted_bg(&self) -> Color;
+    /// Foreground color for accent pills.
+    fn pill_accent_fg(&self) -> Color;
+    /// Background color for accent pills.
+    fn pill_accent_bg(&self) -> Color;
+    /// Foreground color for key labels in key hint bars.
+    fn key_hint_key_fg(&self) -> Color;
+    /// Background color for key labels in key hint bars.
+    fn key_hint_key_bg(&self) -> Color;
+    /// Foreground color for action labels in key hint bars.
+    fn key_hint_action_fg(&self) -> Color;
+    /// Foreground color for separators in key hint bars.
+    fn key_hint_bracket_fg(&self) -> Color;
+    /// Border color for focused pane blocks.
+    fn pane_focused_border(&self) -> Color;
+    /// Border color for unfocused pane blocks.
+    fn pane_unfocused_border(&self) -> Color;
+    /// Primary text color.
+    fn text_primary(&self) -> Color;
+    /// Secondary text color.
+    fn text_secondary(&self) -> Color;
+    /// Muted text color.
+    fn text_muted(&self) -> Color;
+    /// Text color for errors and removals.
+    fn text_error(&self) -> Color;
+    /// Accent color for variant entities.
+    fn entity_variant(&self) -> Color;
+    /// Accent color for actor entities.
+    fn entity_actor(&self) -> Color;
+    /// Foreground color for the highlighted list row.
+    fn table_highlight_fg(&self) -> Color;
+}
+
+impl ComponentThemeLike for ComponentTheme {
+    fn pill_ok_fg(&self) -> Color {
+        self.pill_ok_fg
+    }
+    fn pill_ok_bg(&self) -> Color {
+        self.pill_ok_bg
+    }
+    fn pill_warn_fg(&self) -> Color {
+        self.pill_warn_fg
+    }
+    fn pill_warn_bg(&self) -> Color {
+        self.pill_warn_bg
+    }
+    fn pill_err_fg(&self) -> Color {
+        self.pill_err_fg
+    }
+    fn pill_err_bg(&self) -> Color {
+        self.pill_err_bg
+    }
+    fn pill_info_fg(&self) -> Color {
+        self.pill_info_fg
+    }
+    fn pill_info_bg(&self) -> Color {
+        self.pill_info_bg
+    }
+    fn pill_muted_fg(&self) -> Color {
+        self.pill_muted_fg
+    }
+    fn pill_muted_bg(&self) -> Color {
+        self.pill_muted_bg
+    }
+    fn pill_accent_fg(&self) -> Color {
+        self.pill_accent_fg
+    }
+    fn pill_accent_bg(&self) -> Color {
+        self.pill_accent_bg
+    }
+    fn key_hint_key_fg(&self) -> Color {
+        self.key_hint_key_fg
+    }
+    fn key_hint_key_bg(&self) -> Color {
+        self.key_hint_key_bg
+    }
+    fn key_hint_action_fg(&self) -> Color {
+        self.key_hint_action_fg
+    }
+    fn key_hint_bracket_fg(&self) -> Color {
+        self.key_hint_bracket_fg
+    }
+    fn pane_focused_border(&self) -> Color {
+        self.pane_focused_border
+    }
+    fn pane_unfocused_border(&self) -> Color {
+        self.pane_unfocused_border
+    }
+    fn text_primary(&self) -> Color {
+        self.text_primary
+    }
+    fn text_secondary(&self) -> Color {
+        self.text_secondary
+    }
+    fn text_muted(&self) -> Color {
+        self.text_muted
+    }
+    fn text_error(&self) -> Color {
+        self.text_error
+    }
+    fn entity_variant(&self) -> Color {
+        self.entity_variant
+    }
+    fn entity_actor(&self) -> Color {
+        self.entity_actor
+    }
+    fn table_highlight_fg(&self) -> Color {
+        self.table_highlight_fg
+    }
+}
@@ -0,0 +1,253 @@
+use ratatui::style::Color;
+
+use super::ComponentTheme;
+
+/// Built-in palettes selectable by name from the CLI or a theme file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ThemePreset {
+    #[default]
+    Dark,
+    Light,
+    HighContrast,
+    Solarized,
+}
+
+impl ThemePreset {
+    pub const ALL: [ThemePreset; 4] = [
+        ThemePreset::Dark,
+        ThemePreset::Light,
+        ThemePreset::HighContrast,
+        ThemePreset::Solarized,
+    ];
+
+    /// Name used in theme files and on the command line.
+    pub fn name(self) -> &'static str {
+        match self {
+            ThemePreset::Dark => "dark",
+            ThemePreset::Light => "light",
+            ThemePreset::HighContrast => "high-contrast",
+            ThemePreset::Solarized => "solarized",
+        }
+    }
+
+    /// Looks up a preset by name, accepting `_` in place of `-`.
+    pub fn from_name(name: &str) -> Option<Self> {
+        let name = name.trim().to_ascii_lowercase().replace('_', "-");
+        Self::ALL.into_iter().find(|preset| preset.name() == name)
+    }
+
+    /// Full truecolor palette for this preset.
+    pub fn theme(self) -> ComponentTheme {
+        match self {
+            ThemePreset::Dark => dark(),
+            ThemePreset::Light => light(),
+            ThemePreset::HighContrast => high_contrast(),
+            ThemePreset::Solarized => solarized(),
+        }
+    }
+}
+
+fn dark() -> ComponentTheme {
+    ComponentTheme {
+        // StatusPill — muted soft palette
+        pill_ok_fg: Color::Rgb(206, 238, 206),
+        pill_ok_bg: Color::Rgb(26, 56, 34),
+        pill_warn_fg: Color::Rgb(246, 220, 166),
+        pill_warn_bg: Color::Rgb(70, 50, 18),
+        pill_err_fg: Color::Rgb(248, 184, 184),
+        pill_err_bg: Color::Rgb(72, 22, 22),
+        pill_info_fg: Color::Rgb(244, 244, 244),
+        pill_info_bg: Color::Rgb(58, 58, 58),
+        pill_muted_fg: Color::Rgb(220, 220, 220),
+        pill_muted_bg: Color::Rgb(44, 44, 44),
+        pill_accent_fg: Color::Rgb(255, 255, 255),
+        pill_accent_bg: Color::Rgb(82, 82, 82),
+
+        // KeyHintBar
+        key_hint_key_fg: Color::Rgb(244, 244, 244),
+        key_hint_key_bg: Color::Rgb(48, 48, 48),
+        key_hint_action_fg: Color::Rgb(214, 214, 214),
+        key_hint_bracket_fg: Color::Rgb(150, 150, 150),
+
+        // Entity identity
+        entity_product: Color::Rgb(236, 236, 236),
+        entity_variant: Color::Rgb(198, 198, 198),
+        entity_actor: Color::Rgb(242, 210, 168),
+
+        // Pane borders
+        pane_focused_border: Color::Rgb(220, 220, 220),
+        pane_unfocused_border: Color::Rgb(112, 112, 112),
+
+        // Table
+        table_header_fg: Color::Rgb(248, 248, 248),
+        table_highlight_fg: Color::Black,
+        table_highlight_bg_product: Color::Rgb(104, 104, 104),
+        table_highlight_bg_variant: Color::Rgb(94, 104, 94),
+        table_highlight_bg_actor: Color::Rgb(110, 92, 78),
+
+        // Catalog
+        catalog_connector: Color::Rgb(124, 124, 124),
+
+        // Text
+        text_primary: Color::White,
+        text_secondary: Color::Rgb(224, 224, 224),
+        text_muted: Color::Rgb(176, 176, 176),
+        text_error: Color::Rgb(240, 160, 150),
+        text_status_normal: Color::Rgb(206, 206, 206),
+
+        // Header/Footer
+        header_border: Color::Rgb(220, 220, 220),
+        footer_border: Color::Rgb(112, 112, 112),
+    }
+}
+
+fn light() -> ComponentTheme {
+    ComponentTheme {
+        pill_ok_fg: Color::Rgb(22, 84, 36),
+        pill_ok_bg: Color::Rgb(210, 238, 214),
+        pill_warn_fg: Color::Rgb(110, 70, 0),
+        pill_warn_bg: Color::Rgb(248, 232, 190),
+        pill_err_fg: Color::Rgb(140, 24, 24),
+        pill_err_bg: Color::Rgb(250, 214, 214),
+        pill_info_fg: Color::Rgb(24, 56, 110),
+        pill_info_bg: Color::Rgb(214, 228, 248),
+        pill_muted_fg: Color::Rgb(80, 80, 80),
+        pill_muted_bg: Color::Rgb(226, 226, 226),
+        pill_accent_fg: Color::Rgb(255, 255, 255),
+        pill_accent_bg: Color::Rgb(60, 90, 150),
+
+        key_hint_key_fg: Color::Rgb(20, 20, 20),
+        key_hint_key_bg: Color::Rgb(214, 214, 220),
+        key_hint_action_fg: Color::Rgb(60, 60, 60),
+        key_hint_bracket_fg: Color::Rgb(140, 140, 140),
+
+        entity_product: Color::Rgb(24, 90, 150),
+        entity_variant: Color::Rgb(30, 110, 50),
+        entity_actor: Color::Rgb(150, 70, 20),
+
+        pane_focused_border: Color::Rgb(40, 40, 40),
+        pane_unfocused_border: Color::Rgb(170, 170, 170),
+
+        table_header_fg: Color::Rgb(10, 10, 10),
+        table_highlight_fg: Color::Rgb(10, 10, 10),
+        table_highlight_bg_product: Color::Rgb(198, 216, 236),
+        table_highlight_bg_variant: Color::Rgb(204, 230, 208),
+        table_highlight_bg_actor: Color::Rgb(240, 216, 196),
+
+        catalog_connector: Color::Rgb(150, 150, 150),
+
+        text_primary: Color::Rgb(16, 16, 16),
+        text_secondary: Color::Rgb(56, 56, 56),
+        text_muted: Color::Rgb(110, 110, 110),
+        text_error: Color::Rgb(170, 30, 30),
+        text_status_normal: Color::Rgb(70, 70, 70),
+
+        header_border: Color::Rgb(40, 40, 40),
+        footer_border: Color::Rgb(170, 170, 170),
+    }
+}
+
+fn high_contrast() -> ComponentTheme {
+    ComponentTheme {
+        pill_ok_fg: Color::Black,
+        pill_ok_bg: Color::LightGreen,
+        pill_warn_fg: Color::Black,
+        pill_warn_bg: Color::LightYellow,
+        pill_err_fg: Color::White,
+        pill_err_bg: Color::Red,
+        pill_info_fg: Color::Black,
+        pill_info_bg: Color::LightCyan,
+        pill_muted_fg: Color::Black,
+        pill_muted_bg: Color::Gray,
+        pill_accent_fg: Color::Black,
+        pill_accent_bg: Color::White,
+
+        key_hint_key_fg: Color::Black,
+        key_hint_key_bg: Color::White,
+        key_hint_action_fg: Color::White,
+        key_hint_bracket_fg: Color::Gray,
+
+        entity_product: Color::LightCyan,
+        entity_variant: Color::LightGreen,
+        entity_actor: Color::LightYellow,
+
+        pane_focused_border: Color::LightYellow,
+        pane_unfocused_border: Color::White,
+
+        table_header_fg: Color::White,
+        table_highlight_fg: Color::Black,
+        table_highlight_bg_product: Color::LightCyan,
+        table_highlight_bg_variant: Color::LightGreen,
+        table_highlight_bg_actor: Color::LightYellow,
+
+        catalog_connector: Color::White,
+
+        text_primary: Color::White,
+        text_secondary: Color::White,
+        text_muted: Color::Gray,
+        text_error: Color::LightRed,
+        text_status_normal: Color::White,
+
+        header_border: Color::White,
+        footer_border: Color::White,
+    }
+}
+
+fn solarized() -> ComponentTheme {
+    const BASE03: Color = Color::Rgb(0, 43, 54);
+    const BASE02: Color = Color::Rgb(7, 54, 66);
+    const BASE01: Color = Color::Rgb(88, 110, 117);
+    const BASE0: Color = Color::Rgb(131, 148, 150);
+    const BASE1: Color = Color::Rgb(147, 161, 161);
+    const YELLOW: Color = Color::Rgb(181, 137, 0);
+    const ORANGE: Color = Color::Rgb(203, 75, 22);
+    const RED: Color = Color::Rgb(220, 50, 47);
+    const MAGENTA: Color = Color::Rgb(211, 54, 130);
+    const BLUE: Color = Color::Rgb(38, 139, 210);
+    const CYAN: Color = Color::Rgb(42, 161, 152);
+    const GREEN: Color = Color::Rgb(133, 153, 0);
+
+    ComponentTheme {
+        pill_ok_fg: BASE03,
+        pill_ok_bg: GREEN,
+        pill_warn_fg: BASE03,
+        pill_warn_bg: YELLOW,
+        pill_err_fg: BASE03,
+        pill_err_bg: RED,
+        pill_info_fg: BASE03,
+        pill_info_bg: BLUE,
+        pill_muted_fg: BASE1,
+        pill_muted_bg: BASE02,
+        pill_accent_fg: BASE03,
+        pill_accent_bg: CYAN,
+
+        key_hint_key_fg: BASE1,
+        key_hint_key_bg: BASE02,
+        key_hint_action_fg: BASE0,
+        key_hint_bracket_fg: BASE01,
+
+        entity_product: BLUE,
+        entity_variant: GREEN,
+        entity_actor: MAGENTA,
+
+        pane_focused_border: CYAN,
+        pane_unfocused_border: BASE01,
+
+        table_header_fg: BASE1,
+        table_highlight_fg: BASE03,
+        table_highlight_bg_product: BLUE,
+        table_highlight_bg_variant: GREEN,
+        table_highlight_bg_actor: MAGENTA,
+
+        catalog_connector: BASE01,
+
+        text_primary: BASE1,
+        text_secondary: BASE0,
+        text_muted: BASE01,
+        text_error: ORANGE,
+        text_status_normal: BASE0,
+
+        header_border: CYAN,
+        footer_border: BASE01,
+    }
+}
@@ -0,0 +1,314 @@
+//! Converts a rendered `Buffer` to plain text, ANSI, SVG or HTML so frames can
+//! be shared or embedded in docs without a terminal.
+
+use std::fmt::Write as _;
+
+use ratatui::buffer::Buffer;
+use ratatui::style::{Color, Modifier};
+use unicode_width::UnicodeWidthStr;
+
+use crate::theme::color_to_rgb;
+
+/// Color used for `Color::Reset` foregrounds in SVG and HTML output.
+const DEFAULT_FG: (u8, u8, u8) = (224, 224, 224);
+/// Color used for `Color::Reset` backgrounds in SVG and HTML output.
+const DEFAULT_BG: (u8, u8, u8) = (16, 16, 16);
+
+const CELL_WIDTH: u32 = 9;
+const CELL_HEIGHT: u32 = 18;
+const FONT_SIZE: u32 = 15;
+const FONT_FAMILY: &str = "ui-monospace, SFMono-Regular, Menlo, Consolas, monospace";
+
+/// Consecutive cells of one row sharing a style.
+struct Run {
+    x: u16,
+    width: u16,
+    text: String,
+    fg: Color,
+    bg: Color,
+    modifier: Modifier,
+}
+
+impl Run {
+    fn colors(&self) -> ((u8, u8, u8), (u8, u8, u8)) {
+        let fg = color_to_rgb(self.fg).unwrap_or(DEFAULT_FG);
+        let bg = color_to_rgb(self.bg).unwrap_or(DEFAULT_BG);
+        if self.modifier.contains(Modifier::REVERSED) {
+            (bg, fg)
+        } else {
+            (fg, bg)
+        }
+    }
+}
+
+/// Splits row `y` into styled runs, skipping cells covered by wide glyphs.
+fn row_runs(buffer: &Buffer, y: u16) -> Vec<Run> {
+    let area = buffer.area;
+    let mut runs: Vec<Run> = Vec::new();
+    let mut x = area.x;
+    while x < area.right() {
+        let Some(cell) = buffer.cell((x, y)) else {
+            break;
+        };
+        let symbol = if cell.skip { " " } else { cell.symbol() };
+        let width = (symbol.width() as u16).max(1);
+        let offset = x - area.x;
+
+        match runs.last_mut() {
+            Some(run)
+                if run.fg == cell.fg && run.bg == cell.bg && run.modifier == cell.modifier =>
+            {
+                run.text.push_str(symbol);
+                run.width += width;
+            }
+            _ => runs.push(Run {
+                x: offset,
+                width,
+                text: symbol.to_string(),
+                fg: cell.fg,
+                bg: cell.bg,
+                modifier: cell.modifier,
+            }),
+        }
+        x = x.saturating_add(width);
+    }
+    runs
+}
+
+/// Plain text, one line per row with trailing spaces trimmed.
+pub fn buffer_to_text(buffer: &Buffer) -> String {
+    let area = buffer.area;
+    (area.top()..area.bottom())
+        .map(|y| {
+            let line: String = row_runs(buffer, y)
+                .into_iter()
+                .map(|run| run.text)
+                .collect();
+            line.trim_end().to_string()
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Text with SGR escape sequences, suitable for `cat` in a color terminal.
+pub fn buffer_to_ansi(buffer: &Buffer) -> String {
+    let area = buffer.area;
+    let mut out = String::new();
+    for y in area.top()..area.bottom() {
+        for run in row_runs(buffer, y) {
+            let codes = sgr_codes(&run);
+            if codes.is_empty() {
+                out.push_str(&run.text);
+            } else {
+                let _ = write!(out, "\x1b[{}m{}\x1b[0m", codes.join(";"), run.text);
+            }
+        }
+        out.push('\n');
+    }
+    out
+}
+
+fn sgr_codes(run: &Run) -> Vec<String> {
+    const MODIFIERS: [(Modifier, &str); 9] = [
+        (Modifier::BOLD, "1"),
+        (Modifier::DIM, "2"),
+        (Modifier::ITALIC, "3"),
+        (Modifier::UNDERLINED, "4"),
+        (Modifier::SLOW_BLINK, "5"),
+        (Modifier::RAPID_BLINK, "6"),
+        (Modifier::REVERSED, "7"),
+        (Modifier::HIDDEN, "8"),
+        (Modifier::CROSSED_OUT, "9"),
+    ];
+
+    let mut codes: Vec<String> = MODIFIERS
+        .iter()
+        .filter(|(modifier, _)| run.modifier.contains(*modifier))
+        .map(|(_, code)| (*code).to_string())
+        .collect();
+    codes.extend(sgr_color(run.fg, false));
+    codes.extend(sgr_color(run.bg, true));
+    codes
+}
+
+fn sgr_color(color: Color, background: bool) -> Option<String> {
+    let base = if background { 40 } else { 30 };
+    let code = match color {
+        Color::Reset => return None,
+        Color::Black => base.to_string(),
+        Color::Red => (base + 1).to_string(),
+        Color::Green => (base + 2).to_string(),
+        Color::Yellow => (base + 3).to_string(),
+        Color::Blue => (base + 4).to_string(),
+        Color::Magenta => (base + 5).to_string(),
+        Color::Cyan => (base + 6).to_string(),
+        Color::Gray => (base + 7).to_string(),
+        Color::DarkGray => (base + 60).to_string(),
+        Color::LightRed => (base + 61).to_string(),
+        Color::LightGreen => (base + 62).to_string(),
+        Color::LightYellow => (base + 63).to_string(),
+        Color::LightBlue => (base + 64).to_string(),
+        Color::LightMagenta => (base + 65).to_string(),
+        Color::LightCyan => (base + 66).to_string(),
+        Color::White => (base + 67).to_string(),
+        Color::Indexed(index) => format!("{};5;{index}", base + 8),
+        Color::Rgb(r, g, b) => format!("{};2;{r};{g};{b}", base + 8),
+    };
+    Some(code)
+}
+
+/// Standalone SVG image with one cell per character on a fixed grid.
+pub fn buffer_to_svg(buffer: &Buffer) -> String {
+    let area = buffer.area;
+    let width = u32::from(area.width) * CELL_WIDTH;
+    let height = u32::from(area.height) * CELL_HEIGHT;
+
+    let mut backgrounds = String::new();
+    let mut texts = String::new();
+    for (row, y) in (area.top()..area.bottom()).enumerate() {
+        let top = row as u32 * CELL_HEIGHT;
+        for run in row_runs(buffer, y) {
+            let left = u32::from(run.x) * CELL_WIDTH;
+            let run_width = u32::from(run.width) * CELL_WIDTH;
+            let (fg, bg) = run.colors();
+            if bg != DEFAULT_BG {
+                let _ = writeln!(
+                    backgrounds,
+                    r#"<rect x="{left}" y="{top}" width="{run_width}" height="{CELL_HEIGHT}" fill="{}"/>"#,
+                    hex(bg)
+                );
+            }
+            if run.text.trim().is_empty() || run.modifier.contains(Modifier::HIDDEN) {
+                continue;
+            }
+            let _ = writeln!(
+                texts,
+                r#"<text x="{left}" y="{}" textLength="{run_width}" lengthAdjust="spacingAndGlyphs" fill="{}"{}>{}</text>"#,
+                top + CELL_HEIGHT - 5,
+                hex(fg),
+                svg_font_attributes(run.modifier),
+                escape_xml(&run.text)
+            );
+        }
+    }
+
+    format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{width}\" height=\"{height}\" viewBox=\"0 0 {width} {height}\">\n\
+         <rect width=\"100%\" height=\"100%\" fill=\"{}\"/>\n\
+         {backgrounds}<g font-family=\"{FONT_FAMILY}\" font-size=\"{FONT_SIZE}\" xml:space=\"preserve\">\n\
+         {texts}</g>\n</svg>\n",
+        hex(DEFAULT_BG)
+    )
+}
+
+fn svg_font_attributes(modifier: Modifier) -> String {
+    let mut attributes = String::new();
+    if modifier.contains(Modifier::BOLD) {
+        attributes.push_str(r#" font-weight="bold""#);
+    }
+    if modifier.contains(Modifier::ITALIC) {
+        attributes.push_str(r#" font-style="italic""#);
+    }
+    if modifier.contains(Modifier::DIM) {
+        attributes.push_str(r#" opacity="0.6""#);
+    }
+    if modifier.contains(Modifier::UNDERLINED) {
+        attributes.push_str(r#" text-decoration="underline""#);
+    }
+    attributes
+}
+
+/// Standalone HTML page with the frame in a styled `<pre>`.
+pub fn buffer_to_html(buffer: &Buffer) -> String {
+    let area = buffer.area;
+    let mut body = String::new();
+    for y in area.top()..area.bottom() {
+        for run in row_runs(buffer, y) {
+            let (fg, bg) = run.colors();
+            let mut style = String::new();
+            if fg != DEFAULT_FG {
+                let _ = write!(style, "color:{};", hex(fg));
+            }
+            if bg != DEFAULT_BG {
+                let _ = write!(style, "background:{};", hex(bg));
+            }
+            style.push_str(&html_font_style(run.modifier));
+
+            let text = escape_xml(&run.text);
+            if style.is_empty() {
+                body.push_str(&text);
+            } else {
+                let _ = write!(body, r#"<span style="{style}">{text}</span>"#);
+            }
+        }
+        body.push('\n');
+    }
+
+    format!(
+        "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n</head>\n\
+         <body style=\"margin:0;background:{bg}\">\n\
+         <pre style=\"margin:0;padding:8px;font-family:{FONT_FAMILY};font-size:{FONT_SIZE}px;line-height:{CELL_HEIGHT}px;color:{fg};background:{bg}\">\n\
+         {body}</pre>\n</body>\n</html>\n",
+        fg = hex(DEFAULT_FG),
+        bg = hex(DEFAULT_BG),
+    )
+}
+
+fn html_font_style(modifier: Modifier) -> String {
+    let mut style = String::new();
+    if modifier.contains(Modifier::BOLD) {
+        style.push_str("font-weight:bold;");
+    }
+    if modifier.contains(Modifier::ITALIC) {
+        style.push_str("font-style:italic;");
+    }
+    if modifier.contains(Modifier::DIM) {
+        style.push_str("opacity:0.6;");
+    }
+    if modifier.contains(Modifier::UNDERLINED) {
+        style.push_str("text-decoration:underline;");
+    }
+    if modifier.contains(Modifier::HIDDEN) {
+        style.push_str("visibility:hidden;");
+    }
+    style
+}
+
+fn hex((r, g, b): (u8, u8, u8)) -> String {
+    format!("#{r:02x}{g:02x}{b:02x}")
+}
+
+fn escape_xml(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for ch in text.chars() {
+        match ch {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            _ => escaped.push(ch),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui::layout::Rect;
+    use ratatui::style::Style;
+
+    use super::*;
+
+    #[test]
+    fn wide_glyphs_take_two_cells_and_runs_split_on_style() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 6, 1));
+        buffer.set_string(0, 0, "界a", Style::default());
+        buffer.set_string(3, 0, "bc", Style::default().fg(Color::Red));
+
+        let runs = row_runs(&buffer, 0);
+        assert_eq!(runs.len(), 3);
+        assert_eq!(runs[0].text, "界a");
+        assert_eq!((runs[1].x, runs[1].width), (3, 2));
+        assert_eq!(buffer_to_text(&buffer), "界abc");
+    }
+}
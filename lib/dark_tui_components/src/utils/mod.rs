@@ -1,6 +1,7 @@
-//! Shared utility helpers for text, geometry, and list navigation.
+//! Shared utility helpers for text, geometry, list navigation, and frame export.
 
 pub mod compact;
+pub mod export;
 pub mod index;
 pub mod rect;
 pub mod resizable;
@@ -1,4 +1,4 @@
-use ratatui::layout::Rect;
+use ratatui::layout::{Constraint, Direction, Layout, Rect};
 
 /// Returns whether a terminal cell lies inside the rectangle.
 pub fn rect_contains(area: Rect, col: u16, row: u16) -> bool {
@@ -15,6 +15,27 @@ pub fn inner_rect(area: Rect) -> Rect {
     }
 }
 
+/// Returns a rect of the given percentage size centered inside `area`, for popups.
+pub fn centered_rect(area: Rect, width_percent: u16, height_percent: u16) -> Rect {
+    let vertical = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Percentage((100 - height_percent) / 2),
+            Constraint::Percentage(height_percent),
+            Constraint::Percentage((100 - height_percent) / 2),
+        ])
+        .split(area);
+
+    Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([
+            Constraint::Percentage((100 - width_percent) / 2),
+            Constraint::Percentage(width_percent),
+            Constraint::Percentage((100 - width_percent) / 2),
+        ])
+        .split(vertical[1])[1]
+}
+
 /// Appends a block cursor marker to the end of text.
 pub fn with_cursor_tail(text: &str) -> String {
     let trimmed = text.trim_end();
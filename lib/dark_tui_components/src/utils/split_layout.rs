@@ -38,6 +38,23 @@ impl HorizontalSplit {
         &self.percents
     }
 
+    /// Replace the split percentages, e.g. when restoring a saved layout.
+    ///
+    /// Rejects input whose length differs from the current split or whose
+    /// total is not 100; accepted values are clamped to the minimums.
+    pub fn set_percents(&mut self, percents: &[u16]) -> bool {
+        if percents.len() != self.percents.len() {
+            return false;
+        }
+        if percents.iter().map(|percent| *percent as u32).sum::<u32>() != 100 {
+            return false;
+        }
+
+        self.percents = percents.to_vec();
+        self.normalize();
+        true
+    }
+
     pub fn resolve(&self, area: Rect) -> Vec<Rect> {
         if self.percents.is_empty() || area.width == 0 {
             return Vec::new();
@@ -166,3 +183,20 @@ impl HorizontalSplit {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::HorizontalSplit;
+
+    #[test]
+    fn set_percents_validates_and_clamps() {
+        let mut split = HorizontalSplit::three(44, 32, 24, 20, 18, 16);
+
+        assert!(!split.set_percents(&[50, 50]));
+        assert!(!split.set_percents(&[40, 40, 40]));
+        assert_eq!(split.percents(), &[44, 32, 24]);
+
+        assert!(split.set_percents(&[60, 25, 15]));
+        assert_eq!(split.percents(), &[60, 24, 16]);
+    }
+}
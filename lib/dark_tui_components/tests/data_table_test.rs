@@ -0,0 +1,197 @@
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use dark_tui_components::{
+    Action, Component, ComponentTheme, DataColumn, DataTable, DataTableCommand, DataTableHit,
+    Event, SortDirection,
+};
+use ratatui::Terminal;
+use ratatui::backend::TestBackend;
+use ratatui::layout::{Rect, Size};
+
+fn row(name: &str, size: &str, path: &str) -> Vec<String> {
+    vec![name.to_string(), size.to_string(), path.to_string()]
+}
+
+fn table() -> DataTable {
+    let mut table = DataTable::new(vec![
+        DataColumn::new("name", "Name", 8),
+        DataColumn::new("size", "Size", 6).numeric(),
+        DataColumn::new("path", "Path", 10).fill(),
+    ]);
+    table.set_rows(vec![
+        row("beta", "10", "/b"),
+        row("Alpha", "9", "/a"),
+        row("gamma", "n/a", "/g"),
+        row("delta", "100", "/d"),
+    ]);
+    table
+}
+
+fn names(table: &mut DataTable) -> Vec<String> {
+    table.select_first();
+    let mut names = Vec::new();
+    for _ in 0..table.len() {
+        names.push(table.selected_cells().expect("row")[0].clone());
+        table.select_by(1);
+    }
+    names
+}
+
+fn press(table: &mut DataTable, code: KeyCode) -> Option<Action> {
+    table
+        .handle_event(&Event::Key(KeyEvent::new(code, KeyModifiers::NONE)))
+        .expect("key")
+}
+
+fn render(table: &DataTable, width: u16, height: u16) -> Vec<String> {
+    let backend = TestBackend::new(width, height);
+    let mut terminal = Terminal::new(backend).expect("terminal");
+    terminal
+        .draw(|frame| table.render(frame, frame.area(), &ComponentTheme::default()))
+        .expect("draw");
+    let buffer = terminal.backend().buffer();
+    (0..height)
+        .map(|y| {
+            (0..width)
+                .map(|x| buffer[(x, y)].symbol())
+                .collect::<String>()
+        })
+        .collect()
+}
+
+#[test]
+fn sorting_orders_text_and_numbers_and_keeps_selection() {
+    let mut table = table();
+
+    table.toggle_sort(0);
+    assert_eq!(names(&mut table), ["Alpha", "beta", "delta", "gamma"]);
+
+    table.toggle_sort(1);
+    assert_eq!(table.sort(), Some((1, SortDirection::Ascending)));
+    assert_eq!(names(&mut table), ["Alpha", "beta", "delta", "gamma"]);
+    table.toggle_sort(1);
+    assert_eq!(table.sort(), Some((1, SortDirection::Descending)));
+    assert_eq!(names(&mut table), ["gamma", "delta", "beta", "Alpha"]);
+
+    table.select_row(0);
+    table.toggle_sort(0);
+    assert_eq!(table.selected_row(), Some(0));
+    assert_eq!(table.selected_cells().expect("row")[0], "beta");
+
+    table.clear_sort();
+    assert_eq!(names(&mut table), ["beta", "Alpha", "gamma", "delta"]);
+}
+
+#[test]
+fn header_stays_put_while_only_visible_rows_render() {
+    let mut table = DataTable::new(vec![
+        DataColumn::new("id", "Id", 6).numeric(),
+        DataColumn::new("label", "Label", 10),
+    ]);
+    table.set_rows(
+        (0..10_000)
+            .map(|index| vec![index.to_string(), format!("row-{index}")])
+            .collect(),
+    );
+    table.toggle_sort(0);
+    table.select_last();
+
+    let lines = render(&table, 20, 5);
+    assert!(lines[0].contains("Id ▲│Label"));
+    assert!(lines[1].contains("row-9996"));
+    assert!(lines[4].contains("row-9999"));
+    assert_eq!(DataTable::page_size(Rect::new(0, 0, 20, 5)), 4);
+}
+
+#[test]
+fn columns_hide_and_resize_within_limits() {
+    let mut table = table();
+
+    assert!(table.set_column_hidden(0, true));
+    assert!(table.set_column_hidden(1, true));
+    assert!(!table.set_column_hidden(2, true));
+    assert!(table.columns()[0].hidden && !table.columns()[2].hidden);
+    assert!(render(&table, 20, 2)[0].starts_with("Path"));
+    assert!(table.toggle_column(0));
+
+    table.resize_column(0, 4);
+    assert_eq!(table.columns()[0].width, 12);
+    table.resize_column(0, -100);
+    assert_eq!(table.columns()[0].width, 3);
+
+    let area = Rect::new(0, 0, 30, 6);
+    assert!(table.resize_from_pointer(area, 0, 6));
+    assert_eq!(table.columns()[0].width, 6);
+}
+
+#[test]
+fn hit_test_maps_header_dividers_and_rows() {
+    let mut table = table();
+    table.toggle_sort(0);
+    let area = Rect::new(2, 1, 30, 4);
+
+    assert_eq!(table.hit_test(area, 0, 0), DataTableHit::Outside);
+    assert_eq!(table.hit_test(area, 3, 1), DataTableHit::Header(0));
+    assert_eq!(table.hit_test(area, 10, 1), DataTableHit::ColumnDivider(0));
+    assert_eq!(table.hit_test(area, 11, 1), DataTableHit::Header(1));
+    assert_eq!(table.hit_test(area, 25, 1), DataTableHit::Header(2));
+    // Rows report their index in the unsorted input: "Alpha" was passed second.
+    assert_eq!(table.hit_test(area, 3, 2), DataTableHit::Row(1));
+    assert_eq!(table.hit_test(area, 3, 4), DataTableHit::Row(3));
+
+    table.set_rows(vec![row("only", "1", "/o")]);
+    assert_eq!(table.hit_test(area, 3, 3), DataTableHit::Body);
+}
+
+#[test]
+fn keys_move_the_selection_and_emit_the_selected_row() {
+    let mut table = table();
+    table.init(Size::new(30, 3)).expect("init");
+
+    assert!(matches!(
+        press(&mut table, KeyCode::Down),
+        Some(Action::Select(1))
+    ));
+    assert!(matches!(
+        press(&mut table, KeyCode::End),
+        Some(Action::Select(3))
+    ));
+    assert!(press(&mut table, KeyCode::Char('j')).is_none());
+    assert!(matches!(
+        press(&mut table, KeyCode::PageUp),
+        Some(Action::Select(1))
+    ));
+    assert!(matches!(
+        press(&mut table, KeyCode::Char('g')),
+        Some(Action::Select(0))
+    ));
+    assert!(press(&mut table, KeyCode::Char('x')).is_none());
+}
+
+#[test]
+fn keys_sort_and_resize_the_focused_column() {
+    let mut table = table();
+    table.set_column_hidden(1, true);
+
+    assert!(matches!(
+        press(&mut table, KeyCode::Char('s')),
+        Some(Action::Render)
+    ));
+    assert_eq!(table.sort(), Some((0, SortDirection::Ascending)));
+    assert_eq!(names(&mut table), ["Alpha", "beta", "delta", "gamma"]);
+
+    // Hidden columns are skipped and the focus wraps around.
+    press(&mut table, KeyCode::Right);
+    assert_eq!(table.focused_column(), 2);
+    press(&mut table, KeyCode::Right);
+    assert_eq!(table.focused_column(), 0);
+    press(&mut table, KeyCode::Left);
+    assert_eq!(table.focused_column(), 2);
+
+    press(&mut table, KeyCode::Char('-'));
+    assert_eq!(table.columns()[2].width, 8);
+    assert!(matches!(
+        table.apply(DataTableCommand::Widen),
+        Some(Action::Render)
+    ));
+    assert_eq!(table.columns()[2].width, 10);
+}
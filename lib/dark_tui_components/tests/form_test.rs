@@ -0,0 +1,120 @@
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use dark_tui_components::{
+    Action, ComponentRuntime, FieldValue, Form, FormField, FormOutcome, Layer, SelectOption,
+};
+
+fn press(form: &mut Form, code: KeyCode) -> FormOutcome {
+    form.handle_key(KeyEvent::new(code, KeyModifiers::NONE))
+}
+
+fn type_text(form: &mut Form, text: &str) {
+    for ch in text.chars() {
+        press(form, KeyCode::Char(ch));
+    }
+}
+
+fn clone_form() -> Form {
+    Form::new("Clone Variant")
+        .field(
+            FormField::text("name", "Name")
+                .required()
+                .validate(|value| match value {
+                    FieldValue::Text(text) if text.contains(' ') => {
+                        Err("Name cannot contain spaces.".to_string())
+                    }
+                    _ => Ok(()),
+                }),
+        )
+        .field(FormField::select("host", "Host").loading())
+        .field(FormField::toggle("fetch", "Fetch after clone").checked(true))
+        .field(FormField::multiline("notes", "Notes"))
+}
+
+#[test]
+fn submit_is_blocked_until_fields_validate_and_options_load() {
+    let mut form = clone_form();
+
+    assert_eq!(press(&mut form, KeyCode::Enter), FormOutcome::Changed);
+    assert_eq!(form.error("name"), Some("Name is required."));
+    assert_eq!(form.error("host"), Some("Options are still loading."));
+    assert_eq!(form.focused_key(), Some("name"));
+
+    // Typing re-checks a field that shows an error, so fixing it clears it.
+    type_text(&mut form, "my var");
+    assert_eq!(form.error("name"), None);
+    assert_eq!(press(&mut form, KeyCode::Enter), FormOutcome::Changed);
+    assert_eq!(form.error("name"), Some("Name cannot contain spaces."));
+    press(&mut form, KeyCode::Backspace);
+    press(&mut form, KeyCode::Backspace);
+    press(&mut form, KeyCode::Backspace);
+    press(&mut form, KeyCode::Backspace);
+    assert_eq!(form.text("name"), "my");
+    assert_eq!(form.error("name"), None);
+
+    form.set_options(
+        "host",
+        [
+            SelectOption::new("local", "local machine"),
+            SelectOption::new("gpu-box", "gpu-box (ssh)"),
+        ],
+        Some("gpu-box"),
+    );
+    assert!(!form.is_loading());
+    assert_eq!(form.error("host"), None);
+    assert_eq!(form.choice("host"), Some("gpu-box"));
+
+    assert_eq!(press(&mut form, KeyCode::Enter), FormOutcome::Submit);
+}
+
+#[test]
+fn tab_order_and_field_kinds_follow_declaration() {
+    let mut form = clone_form();
+    form.set_options("host", ["a", "b"], None);
+
+    press(&mut form, KeyCode::Tab);
+    assert_eq!(form.focused_key(), Some("host"));
+    press(&mut form, KeyCode::Down);
+    assert_eq!(form.focused_key(), Some("host"));
+    assert_eq!(form.choice("host"), Some("b"));
+
+    press(&mut form, KeyCode::Tab);
+    press(&mut form, KeyCode::Char(' '));
+    assert!(!form.flag("fetch"));
+
+    press(&mut form, KeyCode::Down);
+    assert_eq!(form.focused_key(), Some("notes"));
+    type_text(&mut form, "one");
+    form.handle_key(KeyEvent::new(KeyCode::Enter, KeyModifiers::SHIFT));
+    type_text(&mut form, "two");
+    assert_eq!(form.text("notes"), "one\ntwo");
+
+    press(&mut form, KeyCode::BackTab);
+    assert_eq!(form.focused_key(), Some("fetch"));
+    press(&mut form, KeyCode::Tab);
+    press(&mut form, KeyCode::Tab);
+    assert_eq!(form.focused_key(), Some("name"));
+    assert_eq!(press(&mut form, KeyCode::Esc), FormOutcome::Cancel);
+}
+
+#[test]
+fn form_mounts_on_the_runtime_as_a_modal_overlay() {
+    let form =
+        Form::new("Init Product").field(FormField::text("directory", "Directory").required());
+
+    let mut runtime = ComponentRuntime::new();
+    runtime
+        .mount("init", Layer::Overlay, Box::new(form))
+        .expect("mount form");
+    runtime.show("init");
+
+    let enter = dark_tui_components::Event::Key(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE));
+    assert!(runtime.handle_event(&enter).expect("enter").is_empty());
+
+    let key =
+        dark_tui_components::Event::Key(KeyEvent::new(KeyCode::Char('.'), KeyModifiers::NONE));
+    runtime.handle_event(&key).expect("typed");
+    assert_eq!(
+        runtime.handle_event(&enter).expect("submitted"),
+        vec![Action::Confirm]
+    );
+}
@@ -0,0 +1,257 @@
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers, MouseButton, MouseEvent, MouseEventKind};
+use dark_tui_components::{
+    Action, Component, ComponentResult, ComponentRuntime, ComponentThemeLike, Due, Event, Form,
+    Layer, TickScheduler,
+};
+use ratatui::Frame;
+use ratatui::layout::Rect;
+use tokio::sync::mpsc::UnboundedSender;
+
+#[derive(Debug, PartialEq)]
+struct Greeting(&'static str);
+
+/// Records what it sees so tests can assert routing.
+#[derive(Default)]
+struct Probe {
+    keys: Arc<Mutex<Vec<char>>>,
+    updates: Arc<Mutex<Vec<String>>>,
+    focused: bool,
+    wants_focus: bool,
+    cancel_on_esc: bool,
+    tx: Option<UnboundedSender<Action>>,
+}
+
+impl Probe {
+    fn focusable() -> Self {
+        Self {
+            wants_focus: true,
+            ..Self::default()
+        }
+    }
+}
+
+impl Component for Probe {
+    fn register_action_handler(&mut self, tx: UnboundedSender<Action>) -> ComponentResult {
+        self.tx = Some(tx);
+        Ok(())
+    }
+
+    fn handle_key_event(&mut self, key: KeyEvent) -> ComponentResult<Option<Action>> {
+        match key.code {
+            KeyCode::Esc if self.cancel_on_esc => Ok(Some(Action::Cancel)),
+            KeyCode::Tab => Ok(Some(Action::FocusNext)),
+            KeyCode::Char('s') => {
+                if let Some(tx) = &self.tx {
+                    tx.send(Action::StatusMessage("sent on bus".to_string()))?;
+                }
+                Ok(None)
+            }
+            KeyCode::Char(ch) => {
+                self.keys.lock().unwrap().push(ch);
+                Ok(Some(Action::custom(Greeting("hi"))))
+            }
+            _ => Ok(None),
+        }
+    }
+
+    fn update(&mut self, action: &Action) -> ComponentResult<Option<Action>> {
+        if let Some(Greeting(text)) = action.downcast_ref::<Greeting>() {
+            self.updates.lock().unwrap().push(text.to_string());
+        }
+        if let Action::StatusMessage(message) = action {
+            self.updates.lock().unwrap().push(message.clone());
+        }
+        Ok(None)
+    }
+
+    fn draw(
+        &self,
+        _frame: &mut Frame,
+        _area: Rect,
+        _theme: &dyn ComponentThemeLike,
+    ) -> ComponentResult {
+        Ok(())
+    }
+
+    fn wants_focus(&self) -> bool {
+        self.wants_focus
+    }
+
+    fn is_focused(&self) -> bool {
+        self.focused
+    }
+
+    fn set_focused(&mut self, focused: bool) {
+        self.focused = focused;
+    }
+}
+
+fn key(ch: char) -> Event {
+    Event::Key(KeyEvent::new(KeyCode::Char(ch), KeyModifiers::NONE))
+}
+
+fn code(code: KeyCode) -> Event {
+    Event::Key(KeyEvent::new(code, KeyModifiers::NONE))
+}
+
+fn click(column: u16, row: u16) -> Event {
+    Event::Mouse(MouseEvent {
+        kind: MouseEventKind::Down(MouseButton::Left),
+        column,
+        row,
+        modifiers: KeyModifiers::NONE,
+    })
+}
+
+#[test]
+fn keys_go_to_focused_base_and_actions_reach_every_component() {
+    let left = Probe::focusable();
+    let right = Probe::focusable();
+    let (left_keys, left_updates) = (left.keys.clone(), left.updates.clone());
+    let (right_keys, right_updates) = (right.keys.clone(), right.updates.clone());
+
+    let mut runtime = ComponentRuntime::new();
+    runtime
+        .mount("left", Layer::Base, Box::new(left))
+        .expect("mount left");
+    runtime
+        .mount("right", Layer::Base, Box::new(right))
+        .expect("mount right");
+    assert!(
+        runtime
+            .mount("left", Layer::Base, Box::new(Probe::default()))
+            .is_err()
+    );
+    assert_eq!(runtime.focused(), Some("left"));
+
+    let outbound = runtime.handle_event(&key('a')).expect("key routed");
+    assert_eq!(left_keys.lock().unwrap().as_slice(), ['a']);
+    assert!(right_keys.lock().unwrap().is_empty());
+    assert_eq!(left_updates.lock().unwrap().as_slice(), ["hi"]);
+    assert_eq!(right_updates.lock().unwrap().as_slice(), ["hi"]);
+    assert_eq!(
+        outbound[0].downcast_ref::<Greeting>(),
+        Some(&Greeting("hi"))
+    );
+
+    runtime
+        .handle_event(&code(KeyCode::Tab))
+        .expect("focus next");
+    assert_eq!(runtime.focused(), Some("right"));
+    runtime.handle_event(&key('b')).expect("key routed");
+    assert_eq!(right_keys.lock().unwrap().as_slice(), ['b']);
+
+    // Actions posted through the registered sender are drained too.
+    let outbound = runtime.handle_event(&key('s')).expect("bus drained");
+    assert_eq!(
+        outbound,
+        vec![Action::StatusMessage("sent on bus".to_string())]
+    );
+    assert!(
+        left_updates
+            .lock()
+            .unwrap()
+            .contains(&"sent on bus".to_string())
+    );
+}
+
+#[test]
+fn visible_overlay_is_modal_and_dismisses_itself_on_cancel() {
+    let base = Probe::focusable();
+    let overlay = Probe {
+        cancel_on_esc: true,
+        ..Probe::focusable()
+    };
+    let (base_keys, overlay_keys) = (base.keys.clone(), overlay.keys.clone());
+
+    let mut runtime = ComponentRuntime::new();
+    runtime
+        .mount("base", Layer::Base, Box::new(base))
+        .expect("mount base");
+    runtime
+        .mount("popup", Layer::Overlay, Box::new(overlay))
+        .expect("mount popup");
+    assert!(!runtime.is_visible("popup"));
+
+    runtime.show("popup");
+    assert!(runtime.take_render_request());
+    assert_eq!(runtime.modal(), Some("popup"));
+    assert_eq!(runtime.base_focus(), Some("base"));
+    runtime.handle_event(&key('x')).expect("key routed");
+    assert_eq!(overlay_keys.lock().unwrap().as_slice(), ['x']);
+    assert!(base_keys.lock().unwrap().is_empty());
+
+    let outbound = runtime
+        .handle_event(&code(KeyCode::Esc))
+        .expect("cancel handled");
+    assert!(outbound.is_empty());
+    assert!(!runtime.is_visible("popup"));
+    assert_eq!(runtime.focused(), Some("base"));
+
+    // Once placed, a click outside the overlay dismisses it too.
+    runtime.place("popup", Rect::new(10, 5, 20, 10));
+    runtime.show("popup");
+    runtime
+        .handle_event(&click(12, 7))
+        .expect("click inside routed");
+    assert!(runtime.is_visible("popup"));
+    runtime
+        .handle_event(&click(2, 2))
+        .expect("click outside handled");
+    assert!(!runtime.is_visible("popup"));
+}
+
+#[test]
+fn hosts_read_and_write_mounted_components_by_type() {
+    let mut runtime = ComponentRuntime::new();
+    runtime
+        .mount("probe", Layer::Overlay, Box::new(Probe::focusable()))
+        .expect("mount probe");
+
+    assert!(runtime.component::<Probe>("probe").is_some());
+    assert!(runtime.component::<Probe>("missing").is_none());
+    assert!(runtime.component::<Form>("probe").is_none());
+
+    runtime
+        .component_mut::<Probe>("probe")
+        .expect("probe mounted")
+        .cancel_on_esc = true;
+    runtime.show("probe");
+    runtime
+        .handle_event(&code(KeyCode::Esc))
+        .expect("cancel handled");
+    assert!(!runtime.is_visible("probe"));
+}
+
+#[test]
+fn scheduler_reports_due_ticks_and_frames() {
+    let start = Instant::now();
+    let mut scheduler = TickScheduler::starting_at(
+        start,
+        Duration::from_millis(250),
+        Duration::from_millis(100),
+    );
+
+    assert_eq!(scheduler.poll_timeout(start), Duration::ZERO);
+    assert_eq!(
+        scheduler.advance(start),
+        Due {
+            tick: false,
+            render: true
+        }
+    );
+    assert_eq!(scheduler.poll_timeout(start), Duration::from_millis(100));
+
+    let later = start + Duration::from_millis(600);
+    assert_eq!(
+        scheduler.advance(later),
+        Due {
+            tick: true,
+            render: true
+        }
+    );
+    assert_eq!(scheduler.poll_timeout(later), Duration::from_millis(100));
+}
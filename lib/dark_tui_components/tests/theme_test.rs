@@ -0,0 +1,102 @@
+use std::fs::{self, File};
+use std::time::{Duration, SystemTime};
+
+use dark_tui_components::{
+    ColorSupport, ComponentTheme, ThemeFile, ThemePreset, ThemeSource, ThemeWatcher,
+};
+use ratatui::style::Color;
+
+#[test]
+fn presets_round_trip_by_name_and_differ() {
+    for preset in ThemePreset::ALL {
+        assert_eq!(ThemePreset::from_name(preset.name()), Some(preset));
+    }
+    assert_eq!(
+        ThemePreset::from_name("High_Contrast"),
+        Some(ThemePreset::HighContrast)
+    );
+    assert_eq!(ComponentTheme::default(), ThemePreset::Dark.theme());
+    assert_ne!(ThemePreset::Light.theme(), ThemePreset::Dark.theme());
+    assert_ne!(ThemePreset::Solarized.theme(), ThemePreset::Dark.theme());
+    assert_eq!(
+        ThemeFile::parse("preset = \"light\"").expect("file").preset,
+        ThemePreset::Light
+    );
+}
+
+#[test]
+fn fallbacks_strip_rgb_colors() {
+    let ansi16 = ThemePreset::Solarized
+        .theme()
+        .with_color_support(ColorSupport::Ansi16);
+    assert!(!matches!(
+        ansi16.pill_ok_bg,
+        Color::Rgb(..) | Color::Indexed(_)
+    ));
+
+    let none = ThemePreset::HighContrast
+        .theme()
+        .with_color_support(ColorSupport::NoColor);
+    assert_eq!(none.pill_err_bg, Color::Reset);
+    assert_eq!(none.text_primary, Color::Reset);
+}
+
+#[test]
+fn sources_resolve_presets_or_paths() {
+    assert_eq!(
+        ThemeSource::from_spec("solarized"),
+        ThemeSource::Preset(ThemePreset::Solarized)
+    );
+    assert!(matches!(
+        ThemeSource::from_spec("themes/mine.toml"),
+        ThemeSource::File(_)
+    ));
+    assert!(ThemeSource::from_spec("light").watcher().is_none());
+    assert!(
+        ThemeSource::from_spec("/nonexistent/theme.toml")
+            .load()
+            .is_err()
+    );
+}
+
+#[test]
+fn watcher_reloads_after_the_file_changes() {
+    let path = std::env::temp_dir().join(format!("dark-theme-{}.toml", std::process::id()));
+    fs::write(
+        &path,
+        "colors = \"truecolor\"\n[text]\nprimary = [1, 2, 3]\n",
+    )
+    .expect("write");
+    let mut watcher = ThemeWatcher::new(&path);
+    assert!(watcher.poll().is_none());
+
+    fs::write(
+        &path,
+        "colors = \"truecolor\"\n[text]\nprimary = [4, 5, 6]\n",
+    )
+    .expect("rewrite");
+    let later = SystemTime::now() + Duration::from_secs(5);
+    File::options()
+        .write(true)
+        .open(&path)
+        .and_then(|file| file.set_modified(later))
+        .expect("touch");
+    let theme = watcher.poll().expect("changed").expect("parsed");
+    assert_eq!(theme.text_primary, Color::Rgb(4, 5, 6));
+    assert!(watcher.poll().is_none());
+
+    fs::write(&path, "[text]\nprimary = \"nope\"\n").expect("break");
+    File::options()
+        .write(true)
+        .open(&path)
+        .and_then(|file| file.set_modified(later + Duration::from_secs(5)))
+        .expect("touch");
+    let error = watcher.poll().expect("changed").expect_err("invalid");
+    assert!(
+        error
+            .to_string()
+            .ends_with("[text] primary: unknown color `nope`")
+    );
+
+    fs::remove_file(&path).expect("cleanup");
+}
@@ -0,0 +1,100 @@
+use std::time::{Duration, Instant};
+
+use dark_tui_components::{ComponentTheme, ToastHit, ToastSeverity, ToastStack};
+use ratatui::Terminal;
+use ratatui::backend::TestBackend;
+use ratatui::layout::Rect;
+
+#[test]
+fn toasts_expire_by_severity_and_repeats_collapse() {
+    let start = Instant::now();
+    let mut toasts = ToastStack::new();
+
+    toasts.push_at(ToastSeverity::Info, "Refreshed", start);
+    let error = toasts.push_at(ToastSeverity::Error, "Clone failed: timeout", start);
+    assert_eq!(
+        toasts.push_at(ToastSeverity::Error, "Clone failed: timeout", start),
+        error
+    );
+    assert_eq!(toasts.active().len(), 2);
+    assert_eq!(toasts.active()[1].count, 2);
+
+    assert!(toasts.tick(start + Duration::from_secs(5)));
+    assert_eq!(toasts.active().len(), 1);
+    assert_eq!(toasts.active()[0].severity, ToastSeverity::Error);
+
+    assert!(!toasts.tick(start + Duration::from_secs(10)));
+    assert!(toasts.tick(start + Duration::from_secs(16)));
+    assert!(toasts.is_empty());
+
+    let history: Vec<_> = toasts
+        .history()
+        .map(|toast| toast.message.as_str())
+        .collect();
+    assert_eq!(history, ["Clone failed: timeout", "Refreshed"]);
+    assert_eq!(toasts.history().next().expect("logged").count, 2);
+}
+
+#[test]
+fn expanded_toasts_stay_until_collapsed_or_dismissed() {
+    let start = Instant::now();
+    let mut toasts = ToastStack::new();
+    let id = toasts.push_at(ToastSeverity::Warning, "Variant poll slow", start);
+
+    assert!(toasts.toggle_expanded(id));
+    assert!(!toasts.tick(start + Duration::from_secs(60)));
+    assert!(toasts.dismiss(id));
+    assert!(toasts.is_empty());
+    assert_eq!(toasts.history().len(), 1);
+}
+
+#[test]
+fn stack_hit_test_and_clicks_expand_or_dismiss() {
+    let start = Instant::now();
+    let mut toasts = ToastStack::new().width(30).max_visible(2);
+    let oldest = toasts.push_at(ToastSeverity::Info, "first", start);
+    let older = toasts.push_at(ToastSeverity::Info, "second", start);
+    let newest = toasts.push_at(ToastSeverity::Error, "x".repeat(80), start);
+    let parent = Rect::new(0, 0, 40, 20);
+
+    // Newest at the bottom, one line of text plus borders each.
+    assert_eq!(toasts.hit_test(parent, 20, 18), ToastHit::Toast(newest));
+    assert_eq!(toasts.hit_test(parent, 38, 17), ToastHit::Dismiss(newest));
+    assert_eq!(toasts.hit_test(parent, 20, 15), ToastHit::Toast(older));
+    assert_eq!(toasts.hit_test(parent, 20, 12), ToastHit::Outside);
+    assert_eq!(toasts.hit_test(parent, 5, 18), ToastHit::Outside);
+
+    // Expanding wraps the 80 characters over three lines.
+    assert!(toasts.click(parent, 20, 18));
+    assert!(toasts.active()[2].expanded);
+    assert_eq!(toasts.hit_test(parent, 20, 15), ToastHit::Toast(newest));
+    assert_eq!(toasts.hit_test(parent, 38, 15), ToastHit::Dismiss(newest));
+
+    assert!(toasts.click(parent, 38, 15));
+    assert_eq!(toasts.hit_test(parent, 20, 15), ToastHit::Toast(oldest));
+    assert!(!toasts.click(parent, 0, 0));
+}
+
+#[test]
+fn toasts_and_history_render_into_a_buffer() {
+    let mut toasts = ToastStack::new().width(30);
+    toasts.error("Spawn failed: provider offline");
+    toasts.success("Clone ready");
+
+    let backend = TestBackend::new(40, 12);
+    let mut terminal = Terminal::new(backend).expect("terminal");
+    let theme = ComponentTheme::default();
+    terminal
+        .draw(|frame| toasts.render(frame, frame.area(), &theme))
+        .expect("draw toasts");
+    let screen = format!("{:?}", terminal.backend().buffer());
+    assert!(screen.contains("✓ ok"));
+    assert!(screen.contains("Spawn failed"));
+
+    terminal
+        .draw(|frame| toasts.render_history(frame, frame.area(), 0, "Esc: close", &theme))
+        .expect("draw history");
+    let screen = format!("{:?}", terminal.backend().buffer());
+    assert!(screen.contains("Messages (2)"));
+    assert!(screen.contains("Clone ready"));
+}
@@ -1,9 +1,12 @@
 use dark_tui_components::{
-    HorizontalSplit, compact_id, compact_id_len, compact_label, compact_locator,
-    compact_session_id, compact_tail, compact_text, compact_text_normalized, compact_timestamp,
-    inner_rect, next_index, previous_index, rect_contains, with_cursor_tail,
+    HorizontalSplit, buffer_to_ansi, buffer_to_html, buffer_to_svg, buffer_to_text, compact_id,
+    compact_id_len, compact_label, compact_locator, compact_session_id, compact_tail, compact_text,
+    compact_text_normalized, compact_timestamp, inner_rect, next_index, previous_index,
+    rect_contains, with_cursor_tail,
 };
+use ratatui::buffer::Buffer;
 use ratatui::layout::Rect;
+use ratatui::style::{Color, Modifier, Style};
 
 #[test]
 fn compact_text_truncates_with_ellipsis() {
@@ -131,3 +134,29 @@ fn split_layout_resize_respects_minimums() {
     assert!(percents[1] >= 20);
     assert_eq!(percents.iter().copied().sum::<u16>(), 100);
 }
+
+#[test]
+fn buffer_exports_keep_text_and_styles() {
+    let mut buffer = Buffer::empty(Rect::new(0, 0, 8, 2));
+    buffer.set_string(0, 0, "ok", Style::default().fg(Color::Rgb(1, 2, 3)));
+    buffer.set_string(3, 0, "<a&b>", Style::default().add_modifier(Modifier::BOLD));
+    buffer.set_string(0, 1, "x", Style::default().bg(Color::Red));
+
+    assert_eq!(buffer_to_text(&buffer), "ok <a&b>\nx");
+
+    let ansi = buffer_to_ansi(&buffer);
+    assert!(ansi.starts_with("\x1b[38;2;1;2;3mok\x1b[0m "));
+    assert!(ansi.contains("\x1b[1m<a&b>\x1b[0m"));
+    assert!(ansi.contains("\x1b[41mx\x1b[0m"));
+
+    let svg = buffer_to_svg(&buffer);
+    assert!(svg.starts_with("<svg"));
+    assert!(svg.contains(r##"fill="#010203">ok</text>"##));
+    assert!(svg.contains("&lt;a&amp;b&gt;"));
+    assert!(svg.contains(r##"<rect x="0" y="18" width="9" height="18" fill="#cd0000"/>"##));
+
+    let html = buffer_to_html(&buffer);
+    assert!(html.contains(r#"<span style="color:#010203;">ok</span>"#));
+    assert!(html.contains(r#"<span style="font-weight:bold;">&lt;a&amp;b&gt;</span>"#));
+    assert!(html.contains(r#"<span style="background:#cd0000;">x</span>"#));
+}